@@ -0,0 +1,973 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Offline consistency check and repair of a meta raft dir, and diff of two exported files.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs::File;
+use std::io::BufRead;
+use std::io::BufReader;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use anyhow::anyhow;
+use common_meta_api::deserialize_struct;
+use common_meta_api::serialize_struct;
+use common_meta_api::KVApiKey;
+use common_meta_app::schema::CountTablesKey;
+use common_meta_app::schema::DBIdTableName;
+use common_meta_app::schema::DatabaseId;
+use common_meta_app::schema::DatabaseIdToName;
+use common_meta_app::schema::DatabaseMeta;
+use common_meta_app::schema::DatabaseNameIdent;
+use common_meta_app::schema::DbIdList;
+use common_meta_app::schema::DbIdListKey;
+use common_meta_app::schema::TableCopiedFileInfo;
+use common_meta_app::schema::TableCopiedFileLock;
+use common_meta_app::schema::TableCopiedFileLockKey;
+use common_meta_app::schema::TableCopiedFileNameIdent;
+use common_meta_app::schema::TableId;
+use common_meta_app::schema::TableIdList;
+use common_meta_app::schema::TableIdListKey;
+use common_meta_app::schema::TableIdToName;
+use common_meta_app::schema::TableLockKey;
+use common_meta_app::schema::TableLockMeta;
+use common_meta_app::schema::TableMeta;
+use common_meta_app::share::ObjectSharedByShareIds;
+use common_meta_app::share::ShareAccountMeta;
+use common_meta_app::share::ShareAccountNameIdent;
+use common_meta_app::share::ShareGrantObject;
+use common_meta_app::share::ShareId;
+use common_meta_app::share::ShareIdToName;
+use common_meta_app::share::ShareMeta;
+use common_meta_app::share::ShareNameIdent;
+use common_meta_raft_store::config::RaftConfig;
+use common_meta_raft_store::sled_key_spaces::ChangeLogs;
+use common_meta_raft_store::sled_key_spaces::ClientLastResps;
+use common_meta_raft_store::sled_key_spaces::Expires;
+use common_meta_raft_store::sled_key_spaces::GenericKV;
+use common_meta_raft_store::sled_key_spaces::KeySpaceKV;
+use common_meta_raft_store::sled_key_spaces::LeaseExpires;
+use common_meta_raft_store::sled_key_spaces::LeaseKeys;
+use common_meta_raft_store::sled_key_spaces::Leases;
+use common_meta_raft_store::sled_key_spaces::LogMeta;
+use common_meta_raft_store::sled_key_spaces::Logs;
+use common_meta_raft_store::sled_key_spaces::Nodes;
+use common_meta_raft_store::sled_key_spaces::RaftStateKV;
+use common_meta_raft_store::sled_key_spaces::Sequences;
+use common_meta_raft_store::sled_key_spaces::StateMachineMeta;
+use common_meta_raft_store::sled_key_spaces::TenantUsages;
+use common_meta_raft_store::state::RaftState;
+use common_meta_raft_store::state_machine::StateMachine;
+use common_meta_sled_store::get_sled_db;
+use common_meta_sled_store::init_sled_db;
+use common_meta_sled_store::SledKeySpace;
+use common_meta_types::Cmd;
+use common_meta_types::NodeInfo;
+use common_meta_types::PipeInfo;
+use common_meta_types::RoleInfo;
+use common_meta_types::SeqV;
+use common_meta_types::StageFile;
+use common_meta_types::TenantQuota;
+use common_meta_types::UpsertKV;
+use common_meta_types::UserDefinedFunction;
+use common_meta_types::UserInfo;
+use common_meta_types::UserSetting;
+use common_meta_types::UserStageInfo;
+use common_meta_types::TENANT_QUOTA_KEY_PREFIX;
+use databend_meta::export::deserialize_to_kv_variant;
+
+use crate::Config;
+
+/// Prefixes of all key spaces that may be found in a sled tree.
+const KEY_SPACE_PREFIXES: &[u8] = &[
+    Logs::PREFIX,
+    LogMeta::PREFIX,
+    Nodes::PREFIX,
+    StateMachineMeta::PREFIX,
+    RaftStateKV::PREFIX,
+    GenericKV::PREFIX,
+    Sequences::PREFIX,
+    ClientLastResps::PREFIX,
+    ChangeLogs::PREFIX,
+    Leases::PREFIX,
+    TenantUsages::PREFIX,
+    Expires::PREFIX,
+    LeaseExpires::PREFIX,
+    LeaseKeys::PREFIX,
+];
+
+/// A problem found in the meta data.
+#[derive(Debug, Clone)]
+pub struct Issue {
+    /// The sled record or the generic-kv key the issue is found in.
+    pub key: String,
+
+    pub reason: String,
+
+    /// The change to the state machine that fixes this issue, if it can be fixed automatically.
+    pub fix: Option<UpsertKV>,
+}
+
+impl Issue {
+    fn new(key: impl ToString, reason: impl ToString, fix: Option<UpsertKV>) -> Self {
+        Self {
+            key: key.to_string(),
+            reason: reason.to_string(),
+            fix,
+        }
+    }
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.key, self.reason)?;
+        match &self.fix {
+            None => write!(f, "; can not be repaired"),
+            Some(fix) => write!(f, "; repair: {}", fix),
+        }
+    }
+}
+
+/// Check a stopped meta node: every sled record must decode into a key space,
+/// every generic kv value must decode into the type of its key, and the schema records in the state machine must be consistent with each other.
+pub async fn check(config: &Config) -> anyhow::Result<()> {
+    let issues = collect_issues(config).await?;
+
+    for issue in issues.iter() {
+        println!("{}", issue);
+    }
+
+    if issues.is_empty() {
+        eprintln!("check passed: no issue found");
+        return Ok(());
+    }
+
+    let fixable = issues.iter().filter(|x| x.fix.is_some()).count();
+    Err(anyhow!(
+        "found {} issues, {} of them can be fixed with `--cmd repair`",
+        issues.len(),
+        fixable
+    ))
+}
+
+/// Apply the fixes found by `check` to the state machine of a stopped meta node.
+///
+/// The fixes are written to the state machine directly, without going through raft.
+/// Thus every node in a cluster has to be repaired, or be restored from a repaired node.
+pub async fn repair(config: &Config) -> anyhow::Result<()> {
+    let issues = collect_issues(config).await?;
+
+    let raft_config = raft_store_config(config);
+    let (sm_id, _prev_sm_id) = open_raft_state(&raft_config)
+        .await?
+        .read_state_machine_id()?;
+    let sm = StateMachine::open(&raft_config, sm_id).await?;
+
+    let now_ms = SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis() as u64;
+
+    let mut unfixable = 0;
+    for issue in issues.iter() {
+        let fix = match &issue.fix {
+            None => {
+                unfixable += 1;
+                println!("skip: {}", issue);
+                continue;
+            }
+            Some(fix) => fix,
+        };
+
+        let cmd = Cmd::UpsertKV(fix.clone());
+        sm.sm_tree
+            .txn(true, |txn_tree| sm.apply_cmd(&cmd, &txn_tree, None, now_ms))?;
+        println!("repaired: {}", issue);
+    }
+
+    eprintln!(
+        "repaired {} issues, {} left",
+        issues.len() - unfixable,
+        unfixable
+    );
+
+    if unfixable > 0 {
+        return Err(anyhow!("{} issues can not be repaired", unfixable));
+    }
+    Ok(())
+}
+
+/// Compare the state machine data in two exported files, e.g., exported from two nodes of a cluster.
+///
+/// Raft logs and raft state are local to a node and are not compared.
+pub fn diff(config: &Config) -> anyhow::Result<()> {
+    if config.db.is_empty() || config.diff_with.is_empty() {
+        return Err(anyhow!("--cmd diff requires --db and --diff-with"));
+    }
+
+    let left = load_exported(&config.db)?;
+    let right = load_exported(&config.diff_with)?;
+
+    let mut n = 0;
+    for (k, l) in left.iter() {
+        match right.get(k) {
+            None => println!("- {} {}: {}", k.0, k.1, l),
+            Some(r) if r != l => println!("~ {} {}: {} => {}", k.0, k.1, l, r),
+            Some(_) => continue,
+        }
+        n += 1;
+    }
+    for (k, r) in right.iter() {
+        if !left.contains_key(k) {
+            println!("+ {} {}: {}", k.0, k.1, r);
+            n += 1;
+        }
+    }
+
+    if n > 0 {
+        return Err(anyhow!(
+            "found {} differences between {} and {}",
+            n,
+            config.db,
+            config.diff_with
+        ));
+    }
+
+    eprintln!(
+        "no difference between {} and {}",
+        config.db, config.diff_with
+    );
+    Ok(())
+}
+
+/// Load state machine records from an exported file into a map of `(key_space, key) -> value`, all in json.
+///
+/// The tree name is ignored because the state machine id differs between nodes.
+fn load_exported(path: &str) -> anyhow::Result<BTreeMap<(String, String), String>> {
+    let reader = BufReader::new(File::open(path)?);
+
+    let mut res = BTreeMap::new();
+    for line in reader.lines() {
+        let l = line?;
+        let (_tree_name, kv_variant): (String, KeySpaceKV) = serde_json::from_str(&l)?;
+
+        match kv_variant {
+            KeySpaceKV::Logs { .. }
+            | KeySpaceKV::LogMeta { .. }
+            | KeySpaceKV::RaftStateKV { .. } => {
+                continue;
+            }
+            _ => {}
+        }
+
+        let v = serde_json::to_value(&kv_variant)?;
+        let (key_space, kv) = v
+            .as_object()
+            .and_then(|o| o.iter().next())
+            .ok_or_else(|| anyhow!("invalid exported line: {}", l))?;
+
+        res.insert(
+            (key_space.clone(), kv["key"].to_string()),
+            kv["value"].to_string(),
+        );
+    }
+
+    Ok(res)
+}
+
+async fn collect_issues(config: &Config) -> anyhow::Result<Vec<Issue>> {
+    eprintln!("check meta dir: {}", config.raft_config.raft_dir);
+
+    init_sled_db(config.raft_config.raft_dir.clone());
+
+    let raft_config = raft_store_config(config);
+    let (sm_id, _prev_sm_id) = open_raft_state(&raft_config)
+        .await?
+        .read_state_machine_id()?;
+    let sm_tree_name = StateMachine::tree_name(&raft_config, sm_id);
+
+    let mut issues = vec![];
+    let kvs = check_key_spaces(&sm_tree_name, &mut issues)?;
+
+    eprintln!(
+        "check schema in {}: {} generic kv records",
+        sm_tree_name,
+        kvs.len()
+    );
+    issues.extend(check_schema(&kvs)?);
+
+    Ok(issues)
+}
+
+fn raft_store_config(config: &Config) -> RaftConfig {
+    RaftConfig {
+        raft_dir: config.raft_config.raft_dir.clone(),
+        no_sync: config.raft_config.no_sync,
+        sled_tree_prefix: config.raft_config.sled_tree_prefix.clone(),
+        ..Default::default()
+    }
+}
+
+async fn open_raft_state(raft_config: &RaftConfig) -> anyhow::Result<RaftState> {
+    let db = get_sled_db();
+    let raft_state = RaftState::open_create(&db, raft_config, Some(()), None).await?;
+    Ok(raft_state)
+}
+
+/// Decode every record in every sled tree, and return the generic kv in the current state machine.
+fn check_key_spaces(
+    sm_tree_name: &str,
+    issues: &mut Vec<Issue>,
+) -> anyhow::Result<BTreeMap<String, SeqV<Vec<u8>>>> {
+    let db = get_sled_db();
+
+    let mut kvs = BTreeMap::new();
+
+    let mut tree_names = db.tree_names();
+    tree_names.sort();
+    for n in tree_names.iter() {
+        let name = String::from_utf8(n.to_vec())?;
+
+        let tree = db.open_tree(&name)?;
+        let mut cnt = 0;
+        for x in tree.iter() {
+            let (k, v) = x?;
+            cnt += 1;
+
+            let kv_variant = match decode_sled_record(&k, &v) {
+                Ok(kv_variant) => kv_variant,
+                Err(e) => {
+                    let key = format!("{}:{:?}", name, k.as_ref());
+                    issues.push(Issue::new(key, format!("undecodable record: {}", e), None));
+                    continue;
+                }
+            };
+
+            let expected_tree = tree_of_key_space(&kv_variant);
+            if !name.contains(expected_tree) {
+                let key = format!("{}:{:?}", name, k.as_ref());
+                let reason = format!("record is expected to be in tree '{}'", expected_tree);
+                issues.push(Issue::new(key, reason, None));
+                continue;
+            }
+
+            if name == sm_tree_name {
+                if let KeySpaceKV::GenericKV { key, value } = kv_variant {
+                    kvs.insert(key, value);
+                }
+            }
+        }
+
+        eprintln!("checked sled tree {}: {} records", name, cnt);
+    }
+
+    Ok(kvs)
+}
+
+fn decode_sled_record(k: &[u8], v: &[u8]) -> anyhow::Result<KeySpaceKV> {
+    let prefix = k.first().ok_or_else(|| anyhow!("empty key"))?;
+    if !KEY_SPACE_PREFIXES.contains(prefix) {
+        return Err(anyhow!("unknown key space prefix: {}", prefix));
+    }
+
+    let kv_variant = deserialize_to_kv_variant(&[k.to_vec(), v.to_vec()])?;
+    Ok(kv_variant)
+}
+
+/// Returns the name of the sled tree a key space belongs to, without the tree name prefix.
+fn tree_of_key_space(kv_variant: &KeySpaceKV) -> &'static str {
+    match kv_variant {
+        KeySpaceKV::Logs { .. } | KeySpaceKV::LogMeta { .. } => "raft_log",
+        KeySpaceKV::RaftStateKV { .. } => "raft_state",
+        _ => "state_machine/",
+    }
+}
+
+/// Schema records decoded from generic kv, indexed by the generic kv key.
+#[derive(Default)]
+struct Schema {
+    db_names: BTreeMap<String, (DatabaseNameIdent, u64)>,
+    db_metas: BTreeMap<u64, DatabaseMeta>,
+    db_id_to_names: BTreeMap<String, (DatabaseIdToName, DatabaseNameIdent)>,
+    db_id_lists: BTreeMap<String, DbIdList>,
+    table_names: BTreeMap<String, (DBIdTableName, u64)>,
+    table_metas: BTreeMap<u64, TableMeta>,
+    table_id_to_names: BTreeMap<String, (TableIdToName, DBIdTableName)>,
+    table_id_lists: BTreeMap<String, TableIdList>,
+}
+
+/// Decode schema records and cross-check:
+/// - name to id mappings refer to an existing database or table;
+/// - every database and table that has a name has an id to name mapping pointing back to the name;
+/// - every name has an id list whose last id is the current one;
+/// - id to name mappings and id lists do not refer to a removed database or table.
+fn check_schema(kvs: &BTreeMap<String, SeqV<Vec<u8>>>) -> anyhow::Result<Vec<Issue>> {
+    let mut issues = vec![];
+    let schema = decode_schema(kvs, &mut issues);
+
+    // Id lists after removing dangling ids and adding missing current ids, and the reasons of the change.
+    let mut db_id_lists = BTreeMap::new();
+    let mut table_id_lists = BTreeMap::new();
+
+    for (key, id_list) in schema.db_id_lists.iter() {
+        let mut ids = id_list.id_list.clone();
+        let mut reasons = vec![];
+        ids.retain(|id| {
+            let exist = schema.db_metas.contains_key(id);
+            if !exist {
+                reasons.push(format!("dangling db id {} in id list", id));
+            }
+            exist
+        });
+        db_id_lists.insert(key.clone(), (ids, reasons));
+    }
+
+    for (key, id_list) in schema.table_id_lists.iter() {
+        let mut ids = id_list.id_list.clone();
+        let mut reasons = vec![];
+        ids.retain(|id| {
+            let exist = schema.table_metas.contains_key(id);
+            if !exist {
+                reasons.push(format!("dangling table id {} in id list", id));
+            }
+            exist
+        });
+        table_id_lists.insert(key.clone(), (ids, reasons));
+    }
+
+    for (key, (name_ident, db_id)) in schema.db_names.iter() {
+        if !schema.db_metas.contains_key(db_id) {
+            let reason = format!("dangling db id {}", db_id);
+            issues.push(Issue::new(key, reason, Some(UpsertKV::delete(key))));
+            continue;
+        }
+
+        let id_to_name = DatabaseIdToName { db_id: *db_id };
+        let id_to_name_key = id_to_name.to_key();
+        match schema.db_id_to_names.get(&id_to_name_key) {
+            Some((_, x)) if x == name_ident => {}
+            got => {
+                let reason = match got {
+                    None => format!("db {} has no id to name mapping", db_id),
+                    Some((_, x)) => {
+                        format!("db {} is mapped to {}, expect {}", db_id, x, name_ident)
+                    }
+                };
+                let fix = UpsertKV::update(&id_to_name_key, &serialize_struct(name_ident)?);
+                issues.push(Issue::new(&id_to_name_key, reason, Some(fix)));
+            }
+        }
+
+        let id_list_key = DbIdListKey {
+            tenant: name_ident.tenant.clone(),
+            db_name: name_ident.db_name.clone(),
+        }
+        .to_key();
+        add_current_id(&mut db_id_lists, id_list_key, *db_id, "db");
+    }
+
+    for (key, (id_to_name, name_ident)) in schema.db_id_to_names.iter() {
+        if !schema.db_metas.contains_key(&id_to_name.db_id) {
+            let reason = format!(
+                "dangling db id {}, mapped to {}",
+                id_to_name.db_id, name_ident
+            );
+            issues.push(Issue::new(key, reason, Some(UpsertKV::delete(key))));
+        }
+    }
+
+    for (key, (name_ident, table_id)) in schema.table_names.iter() {
+        if !schema.db_metas.contains_key(&name_ident.db_id) {
+            let reason = format!(
+                "table {} belongs to absent db {}",
+                table_id, name_ident.db_id
+            );
+            issues.push(Issue::new(key, reason, None));
+            continue;
+        }
+
+        if !schema.table_metas.contains_key(table_id) {
+            let reason = format!("dangling table id {}", table_id);
+            issues.push(Issue::new(key, reason, Some(UpsertKV::delete(key))));
+            continue;
+        }
+
+        let id_to_name = TableIdToName {
+            table_id: *table_id,
+        };
+        let id_to_name_key = id_to_name.to_key();
+        match schema.table_id_to_names.get(&id_to_name_key) {
+            Some((_, x)) if x == name_ident => {}
+            got => {
+                let reason = match got {
+                    None => format!("table {} has no id to name mapping", table_id),
+                    Some((_, x)) => {
+                        format!(
+                            "table {} is mapped to {}, expect {}",
+                            table_id, x, name_ident
+                        )
+                    }
+                };
+                let fix = UpsertKV::update(&id_to_name_key, &serialize_struct(name_ident)?);
+                issues.push(Issue::new(&id_to_name_key, reason, Some(fix)));
+            }
+        }
+
+        let id_list_key = TableIdListKey {
+            db_id: name_ident.db_id,
+            table_name: name_ident.table_name.clone(),
+        }
+        .to_key();
+        add_current_id(&mut table_id_lists, id_list_key, *table_id, "table");
+    }
+
+    for (key, (id_to_name, name_ident)) in schema.table_id_to_names.iter() {
+        if !schema.table_metas.contains_key(&id_to_name.table_id) {
+            let reason = format!(
+                "dangling table id {}, mapped to {}",
+                id_to_name.table_id, name_ident
+            );
+            issues.push(Issue::new(key, reason, Some(UpsertKV::delete(key))));
+        } else if !schema.db_metas.contains_key(&name_ident.db_id) {
+            let reason = format!(
+                "table {} belongs to absent db {}",
+                id_to_name.table_id, name_ident.db_id
+            );
+            issues.push(Issue::new(key, reason, None));
+        }
+    }
+
+    for (key, (ids, reasons)) in db_id_lists {
+        if !reasons.is_empty() {
+            let fix = if ids.is_empty() {
+                UpsertKV::delete(&key)
+            } else {
+                UpsertKV::update(&key, &serialize_struct(&DbIdList { id_list: ids })?)
+            };
+            issues.push(Issue::new(&key, reasons.join("; "), Some(fix)));
+        }
+    }
+
+    for (key, (ids, reasons)) in table_id_lists {
+        if !reasons.is_empty() {
+            let fix = if ids.is_empty() {
+                UpsertKV::delete(&key)
+            } else {
+                UpsertKV::update(&key, &serialize_struct(&TableIdList { id_list: ids })?)
+            };
+            issues.push(Issue::new(&key, reasons.join("; "), Some(fix)));
+        }
+    }
+
+    Ok(issues)
+}
+
+/// Make sure the id that a name currently refers to is the last one in the id list of the name.
+fn add_current_id(
+    id_lists: &mut BTreeMap<String, (Vec<u64>, Vec<String>)>,
+    key: String,
+    id: u64,
+    kind: &str,
+) {
+    let (ids, reasons) = id_lists.entry(key).or_default();
+
+    if ids.last() == Some(&id) {
+        return;
+    }
+
+    if ids.contains(&id) {
+        ids.retain(|x| *x != id);
+        reasons.push(format!(
+            "current {} id {} is not the last in id list",
+            kind, id
+        ));
+    } else {
+        reasons.push(format!("current {} id {} is absent in id list", kind, id));
+    }
+    ids.push(id);
+}
+
+/// Decode every generic kv record.
+///
+/// A record that can not be decoded is reported as an issue and is excluded from further checks.
+/// Schema records are collected for the cross-checks.
+fn decode_schema(kvs: &BTreeMap<String, SeqV<Vec<u8>>>, issues: &mut Vec<Issue>) -> Schema {
+    let mut schema = Schema::default();
+
+    for (key, seq_v) in kvs.iter() {
+        if let Err(e) = decode_generic_kv(&mut schema, key, &seq_v.data) {
+            issues.push(Issue::new(key, format!("undecodable record: {}", e), None));
+        }
+    }
+
+    schema
+}
+
+fn decode_generic_kv(schema: &mut Schema, key: &str, data: &[u8]) -> anyhow::Result<()> {
+    if is_key_of::<DatabaseNameIdent>(key) {
+        let ident = DatabaseNameIdent::from_key(key)?;
+        let db_id: u64 = serde_json::from_slice(data)?;
+        schema.db_names.insert(key.to_string(), (ident, db_id));
+    } else if is_key_of::<DatabaseId>(key) {
+        let id = DatabaseId::from_key(key)?;
+        let meta: DatabaseMeta = deserialize_struct(data)?;
+        schema.db_metas.insert(id.db_id, meta);
+    } else if is_key_of::<DatabaseIdToName>(key) {
+        let id_to_name = DatabaseIdToName::from_key(key)?;
+        let ident: DatabaseNameIdent = deserialize_struct(data)?;
+        schema
+            .db_id_to_names
+            .insert(key.to_string(), (id_to_name, ident));
+    } else if is_key_of::<DbIdListKey>(key) {
+        DbIdListKey::from_key(key)?;
+        let id_list: DbIdList = deserialize_struct(data)?;
+        schema.db_id_lists.insert(key.to_string(), id_list);
+    } else if is_key_of::<DBIdTableName>(key) {
+        let ident = DBIdTableName::from_key(key)?;
+        let table_id: u64 = serde_json::from_slice(data)?;
+        schema
+            .table_names
+            .insert(key.to_string(), (ident, table_id));
+    } else if is_key_of::<TableId>(key) {
+        let id = TableId::from_key(key)?;
+        let meta: TableMeta = deserialize_struct(data)?;
+        schema.table_metas.insert(id.table_id, meta);
+    } else if is_key_of::<TableIdToName>(key) {
+        let id_to_name = TableIdToName::from_key(key)?;
+        let ident: DBIdTableName = deserialize_struct(data)?;
+        schema
+            .table_id_to_names
+            .insert(key.to_string(), (id_to_name, ident));
+    } else if is_key_of::<TableIdListKey>(key) {
+        TableIdListKey::from_key(key)?;
+        let id_list: TableIdList = deserialize_struct(data)?;
+        schema.table_id_lists.insert(key.to_string(), id_list);
+    } else if is_key_of::<CountTablesKey>(key) {
+        CountTablesKey::from_key(key)?;
+        let _count: u64 = serde_json::from_slice(data)?;
+    } else if is_key_of::<TableCopiedFileNameIdent>(key) {
+        TableCopiedFileNameIdent::from_key(key)?;
+        let _info: TableCopiedFileInfo = deserialize_struct(data)?;
+    } else if is_key_of::<TableCopiedFileLockKey>(key) {
+        TableCopiedFileLockKey::from_key(key)?;
+        let _lock: TableCopiedFileLock = deserialize_struct(data)?;
+    } else if is_key_of::<TableLockKey>(key) {
+        TableLockKey::from_key(key)?;
+        let _meta: TableLockMeta = deserialize_struct(data)?;
+    } else if is_key_of::<ShareNameIdent>(key) {
+        ShareNameIdent::from_key(key)?;
+        let _share_id: u64 = serde_json::from_slice(data)?;
+    } else if is_key_of::<ShareId>(key) {
+        ShareId::from_key(key)?;
+        let _meta: ShareMeta = deserialize_struct(data)?;
+    } else if is_key_of::<ShareIdToName>(key) {
+        ShareIdToName::from_key(key)?;
+        let _ident: ShareNameIdent = deserialize_struct(data)?;
+    } else if is_key_of::<ShareAccountNameIdent>(key) {
+        ShareAccountNameIdent::from_key(key)?;
+        let _meta: ShareAccountMeta = deserialize_struct(data)?;
+    } else if is_key_of::<ShareGrantObject>(key) {
+        ShareGrantObject::from_key(key)?;
+        let _share_ids: ObjectSharedByShareIds = deserialize_struct(data)?;
+    } else {
+        decode_management_value(key, data)?;
+    }
+
+    Ok(())
+}
+
+/// Decode the records written by the management APIs of databend-query, the keys are
+/// `<prefix>/<tenant>/...`, thus only the values are decoded.
+fn decode_management_value(key: &str, data: &[u8]) -> anyhow::Result<()> {
+    let prefix = key.split('/').next().unwrap_or_default();
+    match prefix {
+        "__fd_users" => {
+            let _user: UserInfo = deserialize_struct(data)?;
+        }
+        "__fd_stages" => {
+            let _stage: UserStageInfo = deserialize_struct(data)?;
+        }
+        "__fd_stage_files" => {
+            let _file: StageFile = deserialize_struct(data)?;
+        }
+        "__fd_roles" => {
+            let _role: RoleInfo = serde_json::from_slice(data)?;
+        }
+        "__fd_settings" => {
+            let _setting: UserSetting = serde_json::from_slice(data)?;
+        }
+        "__fd_udfs" => {
+            let _udf: UserDefinedFunction = serde_json::from_slice(data)?;
+        }
+        "__fd_pipes" => {
+            let _pipe: PipeInfo = serde_json::from_slice(data)?;
+        }
+        "__fd_clusters" => {
+            let _node: NodeInfo = serde_json::from_slice(data)?;
+        }
+        TENANT_QUOTA_KEY_PREFIX => {
+            let _quota: TenantQuota = serde_json::from_slice(data)?;
+        }
+        // Only the seq of an id generator is used, the value is always empty.
+        "__fd_id_gen" => {}
+        _ => return Err(anyhow!("unknown key prefix: {}", prefix)),
+    }
+
+    Ok(())
+}
+
+/// Returns true if the generic kv key starts with `<K::PREFIX>/`.
+///
+/// Prefixes such as `__fd_table` and `__fd_table_by_id` share leading chars,
+/// thus the delimiter has to be checked.
+fn is_key_of<K: KVApiKey>(key: &str) -> bool {
+    key.strip_prefix(K::PREFIX)
+        .map_or(false, |rest| rest.starts_with('/'))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use common_meta_api::serialize_struct;
+    use common_meta_api::KVApiKey;
+    use common_meta_app::schema::DBIdTableName;
+    use common_meta_app::schema::DatabaseId;
+    use common_meta_app::schema::DatabaseIdToName;
+    use common_meta_app::schema::DatabaseMeta;
+    use common_meta_app::schema::DatabaseNameIdent;
+    use common_meta_app::schema::DbIdList;
+    use common_meta_app::schema::DbIdListKey;
+    use common_meta_app::schema::TableId;
+    use common_meta_app::schema::TableIdList;
+    use common_meta_app::schema::TableIdListKey;
+    use common_meta_app::schema::TableIdToName;
+    use common_meta_app::schema::TableMeta;
+    use common_meta_types::SeqV;
+    use common_meta_types::UpsertKV;
+
+    use super::check_schema;
+
+    fn db_name() -> DatabaseNameIdent {
+        DatabaseNameIdent {
+            tenant: "tenant1".to_string(),
+            db_name: "db1".to_string(),
+        }
+    }
+
+    fn table_name() -> DBIdTableName {
+        DBIdTableName {
+            db_id: 1,
+            table_name: "tb1".to_string(),
+        }
+    }
+
+    /// A consistent schema with database `db1` of id 1 and table `tb1` of id 2.
+    fn consistent_kvs() -> anyhow::Result<BTreeMap<String, SeqV<Vec<u8>>>> {
+        let db_id_list_key = DbIdListKey {
+            tenant: "tenant1".to_string(),
+            db_name: "db1".to_string(),
+        };
+        let table_id_list_key = TableIdListKey {
+            db_id: 1,
+            table_name: "tb1".to_string(),
+        };
+
+        let kvs = vec![
+            (db_name().to_key(), serde_json::to_vec(&1u64)?),
+            (
+                DatabaseId { db_id: 1 }.to_key(),
+                serialize_struct(&DatabaseMeta::default())?,
+            ),
+            (
+                DatabaseIdToName { db_id: 1 }.to_key(),
+                serialize_struct(&db_name())?,
+            ),
+            (
+                db_id_list_key.to_key(),
+                serialize_struct(&DbIdList { id_list: vec![1] })?,
+            ),
+            (table_name().to_key(), serde_json::to_vec(&2u64)?),
+            (
+                TableId { table_id: 2 }.to_key(),
+                serialize_struct(&TableMeta::default())?,
+            ),
+            (
+                TableIdToName { table_id: 2 }.to_key(),
+                serialize_struct(&table_name())?,
+            ),
+            (
+                table_id_list_key.to_key(),
+                serialize_struct(&TableIdList { id_list: vec![2] })?,
+            ),
+            ("__fd_id_gen/table_id".to_string(), vec![]),
+        ];
+
+        Ok(kvs.into_iter().map(|(k, v)| (k, SeqV::new(1, v))).collect())
+    }
+
+    #[test]
+    fn test_check_schema_consistent() -> anyhow::Result<()> {
+        let issues = check_schema(&consistent_kvs()?)?;
+        assert!(issues.is_empty(), "{:?}", issues);
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_schema_dangling_table_id() -> anyhow::Result<()> {
+        let mut kvs = consistent_kvs()?;
+        kvs.remove(&TableId { table_id: 2 }.to_key());
+
+        let issues = check_schema(&kvs)?;
+        let got = issues
+            .iter()
+            .map(|x| (x.key.as_str(), x.reason.as_str(), x.fix.clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                (
+                    "__fd_table/1/tb1",
+                    "dangling table id 2",
+                    Some(UpsertKV::delete("__fd_table/1/tb1"))
+                ),
+                (
+                    "__fd_table_id_to_name/2",
+                    "dangling table id 2, mapped to 1.'tb1'",
+                    Some(UpsertKV::delete("__fd_table_id_to_name/2"))
+                ),
+                (
+                    "__fd_table_id_list/1/tb1",
+                    "dangling table id 2 in id list",
+                    Some(UpsertKV::delete("__fd_table_id_list/1/tb1"))
+                ),
+            ],
+            got
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_schema_missing_db_mappings() -> anyhow::Result<()> {
+        let mut kvs = consistent_kvs()?;
+        let id_to_name_key = DatabaseIdToName { db_id: 1 }.to_key();
+        let id_list_key = DbIdListKey {
+            tenant: "tenant1".to_string(),
+            db_name: "db1".to_string(),
+        }
+        .to_key();
+        kvs.remove(&id_to_name_key);
+        kvs.remove(&id_list_key);
+
+        let issues = check_schema(&kvs)?;
+        let got = issues
+            .iter()
+            .map(|x| (x.key.as_str(), x.reason.as_str(), x.fix.clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                (
+                    id_to_name_key.as_str(),
+                    "db 1 has no id to name mapping",
+                    Some(UpsertKV::update(
+                        &id_to_name_key,
+                        &serialize_struct(&db_name())?
+                    ))
+                ),
+                (
+                    id_list_key.as_str(),
+                    "current db id 1 is absent in id list",
+                    Some(UpsertKV::update(
+                        &id_list_key,
+                        &serialize_struct(&DbIdList { id_list: vec![1] })?
+                    ))
+                ),
+            ],
+            got
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_schema_current_id_not_last() -> anyhow::Result<()> {
+        let mut kvs = consistent_kvs()?;
+        let id_list_key = TableIdListKey {
+            db_id: 1,
+            table_name: "tb1".to_string(),
+        }
+        .to_key();
+        kvs.insert(
+            id_list_key.clone(),
+            SeqV::new(
+                1,
+                serialize_struct(&TableIdList {
+                    id_list: vec![2, 3],
+                })?,
+            ),
+        );
+        kvs.insert(
+            TableId { table_id: 3 }.to_key(),
+            SeqV::new(1, serialize_struct(&TableMeta::default())?),
+        );
+
+        let issues = check_schema(&kvs)?;
+        assert_eq!(1, issues.len(), "{:?}", issues);
+        assert_eq!(id_list_key, issues[0].key);
+        assert_eq!(
+            "current table id 2 is not the last in id list",
+            issues[0].reason
+        );
+        assert_eq!(
+            Some(UpsertKV::update(
+                &id_list_key,
+                &serialize_struct(&TableIdList {
+                    id_list: vec![3, 2]
+                })?
+            )),
+            issues[0].fix
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_schema_undecodable() -> anyhow::Result<()> {
+        let mut kvs = consistent_kvs()?;
+        kvs.insert(
+            "__fd_table/1/tb2".to_string(),
+            SeqV::new(1, b"not-an-id".to_vec()),
+        );
+        kvs.insert("__fd_foo/bar".to_string(), SeqV::new(1, b"foo".to_vec()));
+        kvs.insert("__fd_roles/tenant1/role1".to_string(), SeqV::new(1, vec![]));
+
+        let issues = check_schema(&kvs)?;
+        let got = issues
+            .iter()
+            .map(|x| (x.key.as_str(), x.fix.is_none()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                ("__fd_foo/bar", true),
+                ("__fd_roles/tenant1/role1", true),
+                ("__fd_table/1/tb2", true),
+            ],
+            got
+        );
+        assert!(
+            issues
+                .iter()
+                .all(|x| x.reason.starts_with("undecodable record: ")),
+            "{:?}",
+            issues
+        );
+        Ok(())
+    }
+}
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod check;
+
 mod grpc;
 use grpc::export_meta;
 
@@ -57,6 +59,10 @@ pub struct Config {
     #[clap(long, default_value = "")]
     pub db: String,
 
+    /// The exported file to compare with `db`, when running `--cmd diff`.
+    #[clap(long, default_value = "")]
+    pub diff_with: String,
+
     /// initial_cluster format: node_id=endpoint,grpc_api_addr
     #[clap(long, multiple_occurrences = true, multiple_values = true)]
     pub initial_cluster: Vec<String>,
@@ -209,11 +215,26 @@ async fn main() -> anyhow::Result<()> {
                 Ok(())
             }
 
+            "check" => check::check(&config).await,
+
+            "repair" => check::repair(&config).await,
+
+            "diff" => check::diff(&config),
+
             _ => {
                 eprintln!("valid commands are");
                 eprintln!("  --cmd bench-client-conn-num");
                 eprintln!("    Keep create new connections to metasrv.");
                 eprintln!("    Requires --grpc-api-address.");
+                eprintln!("  --cmd check");
+                eprintln!("    Verify the data in a stopped metasrv and report issues.");
+                eprintln!("    Requires --raft-dir.");
+                eprintln!("  --cmd repair");
+                eprintln!("    Fix the issues found by `check` in a stopped metasrv.");
+                eprintln!("    Requires --raft-dir.");
+                eprintln!("  --cmd diff");
+                eprintln!("    Compare the state machine in two exported files.");
+                eprintln!("    Requires --db and --diff-with.");
 
                 Err(anyhow::anyhow!("unknown cmd: {}", config.cmd))
             }
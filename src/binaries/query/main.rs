@@ -26,6 +26,7 @@ use databend_query::api::HttpService;
 use databend_query::api::RpcService;
 use databend_query::clusters::ClusterDiscovery;
 use databend_query::metrics::MetricService;
+use databend_query::pipes::PipeService;
 use databend_query::servers::HttpHandler;
 use databend_query::servers::HttpHandlerKind;
 use databend_query::servers::MySQLHandler;
@@ -164,6 +165,12 @@ async fn main(_global_tracker: Arc<RuntimeTracker>) -> common_exception::Result<
         );
     }
 
+    // Pipes, only run by the nodes not in management mode.
+    let mut pipe_service = PipeService::create(conf.clone());
+    if !conf.query.management_mode {
+        pipe_service.start();
+    }
+
     // Print information to users.
     println!("Databend Query");
     println!();
@@ -227,6 +234,7 @@ async fn main(_global_tracker: Arc<RuntimeTracker>) -> common_exception::Result<
 
     info!("Ready for connections.");
     shutdown_handle.wait_for_termination_request().await;
+    pipe_service.shutdown().await?;
     info!("Shutdown server.");
     Ok(())
 }
@@ -202,6 +202,9 @@ build_exceptions! {
     DropDbWithDropTime(2315),
     UndropDbWithNoDropTime(2316),
     TxnRetryMaxTimes(2317),
+    TableLockExpired(2318),
+    TableLockAcquireTimeout(2319),
+    TxnFetchAddU64Failed(2320),
 
     // Cluster error codes.
     ClusterUnknownNode(2401),
@@ -245,6 +248,11 @@ build_exceptions! {
     TenantQuotaUnknown(2902),
     TenantQuotaExceeded(2903),
 
+    // Pipe error codes.
+    IllegalPipeFormat(2951),
+    UnknownPipe(2952),
+    PipeAlreadyExists(2953),
+
 }
 
 // Storage errors [3001, 4000].
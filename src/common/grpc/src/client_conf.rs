@@ -39,6 +39,12 @@ pub struct RpcClientConf {
     /// AutoSyncInterval is the interval to update endpoints with its latest members.
     /// None disables auto-sync.
     pub auto_sync_interval: Option<Duration>,
+    /// The max staleness a read is allowed to have, so that any meta node can serve it locally.
+    /// None requires linearizable reads.
+    pub max_read_staleness: Option<Duration>,
+    /// The tenant on behalf of which this client writes, whose quota limits the writes.
+    /// None for a client that is not subject to any tenant quota.
+    pub tenant: Option<String>,
 }
 
 impl RpcClientConf {
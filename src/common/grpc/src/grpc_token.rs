@@ -20,6 +20,9 @@ use jwt_simple::prelude::*;
 #[derive(Serialize, Deserialize, Debug)]
 pub struct GrpcClaim {
     pub username: String,
+    /// The tenant the session acts on behalf of, `None` for a session that is not bound to a tenant.
+    #[serde(default)]
+    pub tenant: Option<String>,
 }
 
 #[derive(Clone)]
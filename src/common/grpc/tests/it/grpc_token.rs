@@ -23,11 +23,13 @@ fn test_flight_token() -> Result<()> {
 
     let claim = GrpcClaim {
         username: String::from(user),
+        tenant: Some("tenant1".to_string()),
     };
 
     let jwt = token.try_create_token(claim)?;
     let claim = token.try_verify_token(jwt)?;
 
     assert_eq!(claim.username, user);
+    assert_eq!(claim.tenant, Some("tenant1".to_string()));
     Ok(())
 }
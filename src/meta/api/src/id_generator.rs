@@ -17,6 +17,7 @@ use crate::kv_api_key::check_segment_absent;
 use crate::kv_api_key::check_segment_present;
 use crate::schema_api_keys::ID_GEN_DATABASE;
 use crate::schema_api_keys::ID_GEN_TABLE;
+use crate::schema_api_keys::ID_GEN_TABLE_LOCK;
 use crate::share_api_keys::ID_GEN_SHARE;
 use crate::KVApiKey;
 use crate::KVApiKeyError;
@@ -48,6 +49,13 @@ impl IdGenerator {
         }
     }
 
+    /// Create a key for generating table lock revision with KVApi
+    pub fn table_lock_id() -> Self {
+        Self {
+            resource: ID_GEN_TABLE_LOCK.to_string(),
+        }
+    }
+
     /// Create a key for generating share id with KVApi
     pub fn share_id() -> Self {
         Self {
@@ -116,6 +124,16 @@ mod t {
             assert_eq!(g, t2);
         }
 
+        // Table lock id generator
+        {
+            let g = IdGenerator::table_lock_id();
+            let k = g.to_key();
+            assert_eq!("__fd_id_gen/table_lock_id", k);
+
+            let t2 = IdGenerator::from_key(&k)?;
+            assert_eq!(g, t2);
+        }
+
         Ok(())
     }
 
@@ -21,6 +21,8 @@ use common_meta_types::GetKVReply;
 use common_meta_types::KVAppError;
 use common_meta_types::ListKVReply;
 use common_meta_types::MGetKVReply;
+use common_meta_types::RangeKVReply;
+use common_meta_types::RangeKVReq;
 use common_meta_types::TxnReply;
 use common_meta_types::TxnRequest;
 use common_meta_types::UpsertKVReply;
@@ -80,6 +82,9 @@ pub trait KVApi: Send + Sync {
 
     async fn prefix_list_kv(&self, prefix: &str) -> Result<ListKVReply, KVAppError>;
 
+    /// Read at most `req.limit` entries in a key range, see [`RangeKVReq`].
+    async fn range_kv(&self, req: RangeKVReq) -> Result<RangeKVReply, KVAppError>;
+
     async fn transaction(&self, txn: TxnRequest) -> Result<TxnReply, KVAppError>;
 }
 
@@ -101,6 +106,10 @@ impl<U: KVApi, T: Deref<Target = U> + Send + Sync> KVApi for T {
         self.deref().prefix_list_kv(prefix).await
     }
 
+    async fn range_kv(&self, req: RangeKVReq) -> Result<RangeKVReply, KVAppError> {
+        self.deref().range_kv(req).await
+    }
+
     async fn transaction(&self, txn: TxnRequest) -> Result<TxnReply, KVAppError> {
         self.deref().transaction(txn).await
     }
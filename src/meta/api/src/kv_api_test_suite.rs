@@ -19,21 +19,31 @@ use common_base::base::tokio;
 use common_meta_types::txn_condition;
 use common_meta_types::txn_op;
 use common_meta_types::txn_op_response;
+use common_meta_types::AppError;
 use common_meta_types::ConditionResult;
+use common_meta_types::KVAppError;
 use common_meta_types::KVMeta;
+use common_meta_types::KeysInRange;
 use common_meta_types::MatchSeq;
 use common_meta_types::Operation;
 use common_meta_types::PbSeqV;
+use common_meta_types::RangeKVReq;
 use common_meta_types::SeqV;
 use common_meta_types::TxnCondition;
 use common_meta_types::TxnDeleteByPrefixRequest;
 use common_meta_types::TxnDeleteByPrefixResponse;
 use common_meta_types::TxnDeleteRequest;
 use common_meta_types::TxnDeleteResponse;
+use common_meta_types::TxnFetchAddU64Request;
+use common_meta_types::TxnFetchAddU64Response;
+use common_meta_types::TxnGetRangeRequest;
+use common_meta_types::TxnGetRangeResponse;
 use common_meta_types::TxnGetRequest;
 use common_meta_types::TxnGetResponse;
 use common_meta_types::TxnOp;
 use common_meta_types::TxnOpResponse;
+use common_meta_types::TxnPutIfAbsentRequest;
+use common_meta_types::TxnPutIfAbsentResponse;
 use common_meta_types::TxnPutRequest;
 use common_meta_types::TxnPutResponse;
 use common_meta_types::TxnReply;
@@ -61,11 +71,16 @@ impl KVApiTestSuite {
         self.kv_timeout(&builder.build().await).await?;
         self.kv_meta(&builder.build().await).await?;
         self.kv_list(&builder.build().await).await?;
+        self.kv_range(&builder.build().await).await?;
         self.kv_mget(&builder.build().await).await?;
         self.kv_txn_absent_seq_0(&builder.build().await).await?;
         self.kv_transaction(&builder.build().await).await?;
         self.kv_delete_by_prefix_transaction(&builder.build().await)
             .await?;
+        self.kv_txn_range_condition_and_get_range(&builder.build().await)
+            .await?;
+        self.kv_txn_put_if_absent_and_fetch_add(&builder.build().await)
+            .await?;
 
         // Run cross node test on every 2 adjacent nodes
         let mut i = 0;
@@ -243,6 +258,8 @@ impl KVApiTestSuite {
 
         kv.upsert_kv(UpsertKVReq::update("k1", b"v1").with(KVMeta {
             expire_at: Some(now + 2),
+            lease: None,
+            tenant: None,
         }))
         .await?;
 
@@ -272,6 +289,8 @@ impl KVApiTestSuite {
                     .with(MatchSeq::Exact(0))
                     .with(KVMeta {
                         expire_at: Some(now - 1),
+                        lease: None,
+                        tenant: None,
                     }),
             )
             .await?;
@@ -280,6 +299,8 @@ impl KVApiTestSuite {
                     .with(MatchSeq::Exact(0))
                     .with(KVMeta {
                         expire_at: Some(now + 10),
+                        lease: None,
+                        tenant: None,
                     }),
             )
             .await?;
@@ -291,7 +312,9 @@ impl KVApiTestSuite {
                 Some(SeqV::with_meta(
                     3,
                     Some(KVMeta {
-                        expire_at: Some(now + 10)
+                        expire_at: Some(now + 10),
+                        lease: None,
+                        tenant: None
                     }),
                     b"v2".to_vec()
                 ))
@@ -313,6 +336,8 @@ impl KVApiTestSuite {
                     .with(MatchSeq::Exact(3))
                     .with(KVMeta {
                         expire_at: Some(now - 1),
+                        lease: None,
+                        tenant: None,
                     }),
             )
             .await?;
@@ -348,6 +373,8 @@ impl KVApiTestSuite {
                 Operation::AsIs,
                 Some(KVMeta {
                     expire_at: Some(now + 20),
+                    lease: None,
+                    tenant: None,
                 }),
             ))
             .await?;
@@ -363,6 +390,8 @@ impl KVApiTestSuite {
                 Operation::AsIs,
                 Some(KVMeta {
                     expire_at: Some(now + 20),
+                    lease: None,
+                    tenant: None,
                 }),
             ))
             .await?;
@@ -371,7 +400,9 @@ impl KVApiTestSuite {
             Some(SeqV::with_meta(
                 2,
                 Some(KVMeta {
-                    expire_at: Some(now + 20)
+                    expire_at: Some(now + 20),
+                    lease: None,
+                    tenant: None
                 }),
                 b"v1".to_vec()
             )),
@@ -385,7 +416,9 @@ impl KVApiTestSuite {
             SeqV::with_meta(
                 seq + 1,
                 Some(KVMeta {
-                    expire_at: Some(now + 20)
+                    expire_at: Some(now + 20),
+                    lease: None,
+                    tenant: None
                 }),
                 b"v1".to_vec()
             ),
@@ -426,6 +459,82 @@ impl KVApiTestSuite {
         Ok(())
     }
 
+    #[tracing::instrument(level = "info", skip(self, kv))]
+    pub async fn kv_range<KV: KVApi>(&self, kv: &KV) -> anyhow::Result<()> {
+        info!("--- KVApiTestSuite::kv_range() start");
+
+        kv.upsert_kv(UpsertKVReq::update("__range", b"")).await?;
+        for i in 0..5 {
+            let key = format!("__range/{}", i);
+            kv.upsert_kv(UpsertKVReq::update(&key, format!("v{}", i).as_bytes()))
+                .await?;
+        }
+        kv.upsert_kv(UpsertKVReq::update("__range0", b"")).await?;
+
+        let keys_of = |kvs: &[(String, SeqV)]| -> Vec<String> {
+            kvs.iter().map(|(k, _)| k.clone()).collect()
+        };
+
+        info!("--- page forward");
+        {
+            let req = RangeKVReq::prefix("__range/").with_limit(2);
+            let mut pages = vec![];
+            let mut next = Some(req);
+            while let Some(req) = next {
+                let reply = kv.range_kv(req.clone()).await?;
+                next = req.next_page(&reply);
+                pages.push((keys_of(&reply.kvs), reply.more));
+            }
+
+            assert_eq!(
+                vec![
+                    (vec!["__range/0".to_string(), "__range/1".to_string()], true),
+                    (vec!["__range/2".to_string(), "__range/3".to_string()], true),
+                    (vec!["__range/4".to_string()], false),
+                ],
+                pages
+            );
+        }
+
+        info!("--- page backward");
+        {
+            let req = RangeKVReq::prefix("__range/").with_limit(3).reverse();
+            let reply = kv.range_kv(req.clone()).await?;
+            assert_eq!(
+                vec!["__range/4", "__range/3", "__range/2"],
+                keys_of(&reply.kvs)
+            );
+            assert!(reply.more);
+
+            let reply = kv.range_kv(req.next_page(&reply).unwrap()).await?;
+            assert_eq!(vec!["__range/1", "__range/0"], keys_of(&reply.kvs));
+            assert!(!reply.more);
+        }
+
+        info!("--- bounded range, values and keys only");
+        {
+            let req = RangeKVReq::new("__range/1", Some("__range/3".to_string()));
+            let reply = kv.range_kv(req.clone()).await?;
+            assert_eq!(vec!["__range/1", "__range/2"], keys_of(&reply.kvs));
+            assert_eq!(b"v1".to_vec(), reply.kvs[0].1.data);
+            assert!(!reply.more);
+
+            let reply = kv.range_kv(req.keys_only()).await?;
+            assert_eq!(vec!["__range/1", "__range/2"], keys_of(&reply.kvs));
+            assert!(reply.kvs.iter().all(|(_, v)| v.data.is_empty()));
+        }
+
+        info!("--- empty range");
+        {
+            let req = RangeKVReq::new("__range/3", Some("__range/1".to_string()));
+            let reply = kv.range_kv(req).await?;
+            assert!(reply.kvs.is_empty());
+            assert!(!reply.more);
+        }
+
+        Ok(())
+    }
+
     #[tracing::instrument(level = "info", skip(self, kv))]
     pub async fn kv_mget<KV: KVApi>(&self, kv: &KV) -> anyhow::Result<()> {
         info!("--- KVApiTestSuite::kv_mget() start");
@@ -484,6 +593,7 @@ impl KVApiTestSuite {
                 value: b"new_v1".to_vec(),
                 prev_value: true,
                 expire_at: None,
+                lease: None,
             })),
         }];
 
@@ -618,6 +728,358 @@ impl KVApiTestSuite {
         Ok(())
     }
 
+    pub async fn kv_txn_range_condition_and_get_range<KV: KVApi>(
+        &self,
+        kv: &KV,
+    ) -> anyhow::Result<()> {
+        info!("--- KVApiTestSuite::kv_txn_range_condition_and_get_range() start");
+
+        let prefix = "txn_range/";
+        let keys = vec![
+            format!("{}a", prefix),
+            format!("{}b", prefix),
+            format!("{}c", prefix),
+        ];
+
+        let mut items = vec![];
+        for key in keys.iter().chain(["txn_rangf".to_string()].iter()) {
+            kv.upsert_kv(UpsertKVReq::update(key, b"v1")).await?;
+            let seq_v = kv.get_kv(key).await?.unwrap();
+            items.push(TxnGetResponse {
+                key: key.clone(),
+                value: Some(PbSeqV::from(seq_v)),
+            });
+        }
+
+        info!("--- count conditions match, get range with limit");
+        {
+            let condition = vec![
+                TxnCondition {
+                    key: prefix.to_string(),
+                    expected: ConditionResult::Eq as i32,
+                    target: Some(txn_condition::Target::KeysWithPrefix(3)),
+                },
+                TxnCondition {
+                    key: keys[0].clone(),
+                    expected: ConditionResult::Eq as i32,
+                    target: Some(txn_condition::Target::KeysInRange(KeysInRange {
+                        range_end: keys[2].clone(),
+                        count: 2,
+                    })),
+                },
+            ];
+
+            let if_then = vec![
+                TxnOp {
+                    request: Some(txn_op::Request::GetRange(TxnGetRangeRequest {
+                        key: prefix.to_string(),
+                        range_end: "".to_string(),
+                        limit: 2,
+                    })),
+                },
+                TxnOp {
+                    request: Some(txn_op::Request::GetRange(TxnGetRangeRequest {
+                        key: keys[1].clone(),
+                        range_end: "txn_rangz".to_string(),
+                        limit: 0,
+                    })),
+                },
+            ];
+
+            let txn = TxnRequest {
+                condition,
+                if_then,
+                else_then: vec![],
+            };
+
+            let resp = kv.transaction(txn).await?;
+
+            let expected = vec![
+                TxnOpResponse {
+                    response: Some(txn_op_response::Response::GetRange(TxnGetRangeResponse {
+                        items: items[0..2].to_vec(),
+                        more: true,
+                    })),
+                },
+                TxnOpResponse {
+                    response: Some(txn_op_response::Response::GetRange(TxnGetRangeResponse {
+                        items: items[1..4].to_vec(),
+                        more: false,
+                    })),
+                },
+            ];
+
+            self.check_transaction_responses(&resp, &expected, true);
+        }
+
+        info!("--- count condition does not match, get range of absent keys");
+        {
+            let condition = vec![TxnCondition {
+                key: prefix.to_string(),
+                expected: ConditionResult::Gt as i32,
+                target: Some(txn_condition::Target::KeysWithPrefix(3)),
+            }];
+
+            let else_then = vec![TxnOp {
+                request: Some(txn_op::Request::GetRange(TxnGetRangeRequest {
+                    key: "txn_range_absent/".to_string(),
+                    range_end: "".to_string(),
+                    limit: 0,
+                })),
+            }];
+
+            let txn = TxnRequest {
+                condition,
+                if_then: vec![],
+                else_then,
+            };
+
+            let resp = kv.transaction(txn).await?;
+
+            let expected = vec![TxnOpResponse {
+                response: Some(txn_op_response::Response::GetRange(TxnGetRangeResponse {
+                    items: vec![],
+                    more: false,
+                })),
+            }];
+
+            self.check_transaction_responses(&resp, &expected, false);
+        }
+
+        info!("--- get range sees the changes made by the earlier ops in the txn");
+        {
+            let new_key = format!("{}d", prefix);
+
+            let if_then = vec![
+                TxnOp {
+                    request: Some(txn_op::Request::Delete(TxnDeleteRequest {
+                        key: keys[0].clone(),
+                        prev_value: false,
+                    })),
+                },
+                TxnOp {
+                    request: Some(txn_op::Request::Put(TxnPutRequest {
+                        key: new_key.clone(),
+                        value: b"v1".to_vec(),
+                        prev_value: false,
+                        expire_at: None,
+                        lease: None,
+                    })),
+                },
+                TxnOp {
+                    request: Some(txn_op::Request::GetRange(TxnGetRangeRequest {
+                        key: prefix.to_string(),
+                        range_end: "".to_string(),
+                        limit: 0,
+                    })),
+                },
+            ];
+
+            let txn = TxnRequest {
+                condition: vec![],
+                if_then,
+                else_then: vec![],
+            };
+
+            let resp = kv.transaction(txn).await?;
+
+            let seq_v = kv.get_kv(&new_key).await?.unwrap();
+            let mut want = items[1..3].to_vec();
+            want.push(TxnGetResponse {
+                key: new_key.clone(),
+                value: Some(PbSeqV::from(seq_v)),
+            });
+
+            assert!(resp.success);
+            assert_eq!(
+                Some(&TxnOpResponse {
+                    response: Some(txn_op_response::Response::GetRange(TxnGetRangeResponse {
+                        items: want,
+                        more: false,
+                    })),
+                }),
+                resp.responses.get(2)
+            );
+        }
+
+        Ok(())
+    }
+
+    pub async fn kv_txn_put_if_absent_and_fetch_add<KV: KVApi>(
+        &self,
+        kv: &KV,
+    ) -> anyhow::Result<()> {
+        info!("--- KVApiTestSuite::kv_txn_put_if_absent_and_fetch_add() start");
+
+        let put_key = "txn_put_if_absent";
+        let counter_key = "txn_counter";
+
+        let put_if_absent = |value: &[u8]| TxnOp {
+            request: Some(txn_op::Request::PutIfAbsent(TxnPutIfAbsentRequest {
+                key: put_key.to_string(),
+                value: value.to_vec(),
+                expire_at: None,
+                lease: None,
+            })),
+        };
+
+        let fetch_add = |key: &str, delta: i64| TxnOp {
+            request: Some(txn_op::Request::FetchAddU64(TxnFetchAddU64Request {
+                key: key.to_string(),
+                delta,
+            })),
+        };
+
+        let fetch_add_resp = |key: &str, success: bool, before: u64, after: u64| TxnOpResponse {
+            response: Some(txn_op_response::Response::FetchAddU64(
+                TxnFetchAddU64Response {
+                    key: key.to_string(),
+                    success,
+                    before,
+                    after,
+                },
+            )),
+        };
+
+        info!("--- put if absent, fetch-add an absent key");
+        {
+            let txn = TxnRequest {
+                condition: vec![],
+                if_then: vec![put_if_absent(b"v1"), fetch_add(counter_key, 3)],
+                else_then: vec![],
+            };
+
+            let resp = kv.transaction(txn).await?;
+
+            let expected = vec![
+                TxnOpResponse {
+                    response: Some(txn_op_response::Response::PutIfAbsent(
+                        TxnPutIfAbsentResponse {
+                            key: put_key.to_string(),
+                            success: true,
+                            prev_value: None,
+                        },
+                    )),
+                },
+                fetch_add_resp(counter_key, true, 0, 3),
+            ];
+
+            self.check_transaction_responses(&resp, &expected, true);
+        }
+
+        info!("--- put if present, fetch-add, fetch-add a non-u64 value, the txn is rejected");
+        {
+            let present = kv.get_kv(put_key).await?.unwrap();
+
+            let txn = TxnRequest {
+                condition: vec![],
+                if_then: vec![
+                    put_if_absent(b"v2"),
+                    fetch_add(counter_key, -1),
+                    fetch_add(put_key, 1),
+                ],
+                else_then: vec![],
+            };
+
+            let res: Result<(bool, Vec<TxnOpResponse>), KVAppError> =
+                kv.transaction(txn).await.and_then(|reply| reply.into());
+
+            let err = res.unwrap_err();
+            assert!(
+                matches!(err, KVAppError::AppError(AppError::TxnFetchAddU64Failed(_))),
+                "got: {:?}",
+                err
+            );
+
+            let got = kv.get_kv(put_key).await?;
+            assert_eq!(Some(present), got, "the present value is not changed");
+
+            let got = kv.get_kv(counter_key).await?;
+            assert_eq!(b"3".to_vec(), got.unwrap().data, "no op is applied");
+        }
+
+        info!("--- put a non-u64 value then fetch-add it, the txn is rejected");
+        {
+            let put = |value: &[u8]| TxnOp {
+                request: Some(txn_op::Request::Put(TxnPutRequest {
+                    key: counter_key.to_string(),
+                    value: value.to_vec(),
+                    prev_value: false,
+                    expire_at: None,
+                    lease: None,
+                })),
+            };
+
+            let txn = TxnRequest {
+                condition: vec![],
+                if_then: vec![put(b"x"), fetch_add(counter_key, 1)],
+                else_then: vec![],
+            };
+
+            let res: Result<(bool, Vec<TxnOpResponse>), KVAppError> =
+                kv.transaction(txn).await.and_then(|reply| reply.into());
+
+            let err = res.unwrap_err();
+            assert!(
+                matches!(err, KVAppError::AppError(AppError::TxnFetchAddU64Failed(_))),
+                "got: {:?}",
+                err
+            );
+
+            let got = kv.get_kv(counter_key).await?;
+            assert_eq!(b"3".to_vec(), got.unwrap().data, "the put is not applied");
+
+            info!("--- put a u64 value then fetch-add it");
+
+            let txn = TxnRequest {
+                condition: vec![],
+                if_then: vec![put(b"5"), fetch_add(counter_key, -3)],
+                else_then: vec![],
+            };
+
+            let resp = kv.transaction(txn).await?;
+
+            let expected = vec![
+                TxnOpResponse {
+                    response: Some(txn_op_response::Response::Put(TxnPutResponse {
+                        key: counter_key.to_string(),
+                        prev_value: None,
+                    })),
+                },
+                fetch_add_resp(counter_key, true, 5, 2),
+            ];
+
+            self.check_transaction_responses(&resp, &expected, true);
+
+            let got = kv.get_kv(counter_key).await?;
+            assert_eq!(b"2".to_vec(), got.unwrap().data);
+        }
+
+        info!("--- fetch-add and underflow, the txn is rejected");
+        {
+            let txn = TxnRequest {
+                condition: vec![],
+                if_then: vec![fetch_add(counter_key, 1), fetch_add(counter_key, -5)],
+                else_then: vec![],
+            };
+
+            let res: Result<(bool, Vec<TxnOpResponse>), KVAppError> =
+                kv.transaction(txn).await.and_then(|reply| reply.into());
+
+            let err = res.unwrap_err();
+            assert!(
+                matches!(err, KVAppError::AppError(AppError::TxnFetchAddU64Failed(_))),
+                "got: {:?}",
+                err
+            );
+
+            let got = kv.get_kv(counter_key).await?;
+            assert_eq!(b"2".to_vec(), got.unwrap().data, "no op is applied");
+        }
+
+        Ok(())
+    }
+
     pub async fn kv_transaction<KV: KVApi>(&self, kv: &KV) -> anyhow::Result<()> {
         info!("--- KVApiTestSuite::kv_transaction() start");
         // first case: get and set one key transaction
@@ -642,6 +1104,7 @@ impl KVApiTestSuite {
                     value: b"new_v1".to_vec(),
                     prev_value: true,
                     expire_at: None,
+                    lease: None,
                 })),
             }];
 
@@ -694,6 +1157,7 @@ impl KVApiTestSuite {
                     value: b"new_v1".to_vec(),
                     prev_value: true,
                     expire_at: None,
+                    lease: None,
                 })),
             }];
 
@@ -749,6 +1213,7 @@ impl KVApiTestSuite {
                         value: val1_new.to_vec(),
                         prev_value: true,
                         expire_at: None,
+                        lease: None,
                     })),
                 },
                 // change k2
@@ -758,6 +1223,7 @@ impl KVApiTestSuite {
                         value: b"new_v2".to_vec(),
                         prev_value: true,
                         expire_at: None,
+                        lease: None,
                     })),
                 },
                 // get k1
@@ -858,6 +1324,7 @@ impl KVApiTestSuite {
                         value: val1_new.to_vec(),
                         prev_value: true,
                         expire_at: None,
+                        lease: None,
                     })),
                 },
                 // get k1
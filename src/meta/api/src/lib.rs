@@ -61,12 +61,15 @@ pub use util::is_all_db_data_removed;
 pub use util::is_db_need_to_be_remove;
 pub use util::list_keys;
 pub use util::list_u64_value;
+pub use util::range_pages;
 pub use util::send_txn;
 pub use util::serialize_struct;
 pub use util::serialize_u64;
 pub use util::table_has_to_exist;
 pub use util::txn_cond_seq;
 pub use util::txn_op_del;
+pub use util::txn_op_fetch_add_u64;
 pub use util::txn_op_put;
 pub use util::txn_op_put_with_expire;
+pub use util::LIST_PAGE_SIZE;
 pub use util::TXN_MAX_RETRY_TIMES;
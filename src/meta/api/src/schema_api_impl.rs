@@ -24,6 +24,8 @@ use common_meta_app::schema::CountTablesReply;
 use common_meta_app::schema::CountTablesReq;
 use common_meta_app::schema::CreateDatabaseReply;
 use common_meta_app::schema::CreateDatabaseReq;
+use common_meta_app::schema::CreateTableLockRevReply;
+use common_meta_app::schema::CreateTableLockRevReq;
 use common_meta_app::schema::CreateTableReply;
 use common_meta_app::schema::CreateTableReq;
 use common_meta_app::schema::DBIdTableName;
@@ -35,15 +37,18 @@ use common_meta_app::schema::DatabaseMeta;
 use common_meta_app::schema::DatabaseNameIdent;
 use common_meta_app::schema::DbIdList;
 use common_meta_app::schema::DbIdListKey;
+use common_meta_app::schema::DeleteTableLockRevReq;
 use common_meta_app::schema::DropDatabaseReply;
 use common_meta_app::schema::DropDatabaseReq;
 use common_meta_app::schema::DropTableReply;
 use common_meta_app::schema::DropTableReq;
+use common_meta_app::schema::ExtendTableLockRevReq;
 use common_meta_app::schema::GetDatabaseReq;
 use common_meta_app::schema::GetTableCopiedFileReply;
 use common_meta_app::schema::GetTableCopiedFileReq;
 use common_meta_app::schema::GetTableReq;
 use common_meta_app::schema::ListDatabaseReq;
+use common_meta_app::schema::ListTableLocksReq;
 use common_meta_app::schema::ListTableReq;
 use common_meta_app::schema::RenameDatabaseReply;
 use common_meta_app::schema::RenameDatabaseReq;
@@ -59,6 +64,9 @@ use common_meta_app::schema::TableIdListKey;
 use common_meta_app::schema::TableIdToName;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableLockInfo;
+use common_meta_app::schema::TableLockKey;
+use common_meta_app::schema::TableLockMeta;
 use common_meta_app::schema::TableMeta;
 use common_meta_app::schema::TableNameIdent;
 use common_meta_app::schema::TruncateTableReply;
@@ -86,6 +94,7 @@ use common_meta_types::errors::app_error::DropTableWithDropTime;
 use common_meta_types::errors::app_error::ShareHasNoGrantedDatabase;
 use common_meta_types::errors::app_error::ShareHasNoGrantedPrivilege;
 use common_meta_types::errors::app_error::TableAlreadyExists;
+use common_meta_types::errors::app_error::TableLockExpired;
 use common_meta_types::errors::app_error::TableVersionMismatched;
 use common_meta_types::errors::app_error::TxnRetryMaxTimes;
 use common_meta_types::errors::app_error::UndropDbHasNoHistory;
@@ -107,6 +116,7 @@ use common_meta_types::MatchSeqExt;
 use common_meta_types::MetaError;
 use common_meta_types::MetaId;
 use common_meta_types::MetaNetworkError;
+use common_meta_types::RangeKVReq;
 use common_meta_types::TxnCondition;
 use common_meta_types::TxnOp;
 use common_meta_types::TxnRequest;
@@ -125,12 +135,14 @@ use crate::get_u64_value;
 use crate::is_db_need_to_be_remove;
 use crate::list_keys;
 use crate::list_u64_value;
+use crate::range_pages;
 use crate::send_txn;
 use crate::serialize_struct;
 use crate::serialize_u64;
 use crate::table_has_to_exist;
 use crate::txn_cond_seq;
 use crate::txn_op_del;
+use crate::txn_op_fetch_add_u64;
 use crate::txn_op_put;
 use crate::txn_op_put_with_expire;
 use crate::IdGenerator;
@@ -973,7 +985,7 @@ impl<KV: KVApi> SchemaApi for KV {
                 // append new table_id into list
                 tb_id_list.append(table_id);
 
-                let txn_req = TxnRequest {
+                let mut txn_req = TxnRequest {
                     condition: vec![
                         // db has not to change, i.e., no new table is created.
                         // Renaming db is OK and does not affect the seq of db_meta.
@@ -981,9 +993,10 @@ impl<KV: KVApi> SchemaApi for KV {
                         // no other table with the same name is inserted.
                         txn_cond_seq(&dbid_tbname, Eq, 0),
                         // no other table id with the same name is append.
+                        // The id list is a serialized value and can not be appended by a
+                        // fetch-add or put-if-absent. The seq condition adds no conflict, since
+                        // the txn is already conditioned on the seq of db_meta.
                         txn_cond_seq(&dbid_tbname_idlist, Eq, tb_id_list_seq),
-                        // update table count atomicly
-                        txn_cond_seq(&tb_count_key, Eq, tb_count_seq),
                         txn_cond_seq(&table_id_to_name_key, Eq, 0),
                     ],
                     if_then: vec![
@@ -994,12 +1007,22 @@ impl<KV: KVApi> SchemaApi for KV {
                         txn_op_put(&dbid_tbname, serialize_u64(table_id)?), /* (tenant, db_id, tb_name) -> tb_id */
                         txn_op_put(&tbid, serialize_struct(&req.table_meta)?), /* (tenant, db_id, tb_id) -> tb_meta */
                         txn_op_put(&dbid_tbname_idlist, serialize_struct(&tb_id_list)?), /* _fd_table_id_list/db_id/table_name -> tb_id_list */
-                        txn_op_put(&tb_count_key, serialize_u64(tb_count + 1)?), /* _fd_table_count/tenant -> tb_count */
                         txn_op_put(&table_id_to_name_key, serialize_struct(&db_id_table_name)?), /* __fd_table_id_to_name/db_id/table_name -> DBIdTableName */
                     ],
                     else_then: vec![],
                 };
 
+                if tb_count_seq == 0 {
+                    // initialize table count, on condition that no other txn has done it.
+                    txn_req.condition.push(txn_cond_seq(&tb_count_key, Eq, 0));
+                    // _fd_table_count/tenant -> tb_count
+                    let tb_count = serialize_u64(tb_count + 1)?;
+                    txn_req.if_then.push(txn_op_put(&tb_count_key, tb_count));
+                } else {
+                    // update table count atomicly, without conflicting with other txns.
+                    txn_req.if_then.push(txn_op_fetch_add_u64(&tb_count_key, 1));
+                }
+
                 let (succ, _responses) = send_txn(self, txn_req).await?;
 
                 debug!(
@@ -1027,13 +1050,11 @@ impl<KV: KVApi> SchemaApi for KV {
     async fn list_all_tables(&self) -> Result<Vec<(TableId, u64, TableMeta)>, KVAppError> {
         debug!("SchemaApi: {}", func_name!());
 
-        let reply = self
-            .prefix_list_kv(&vec![TableId::PREFIX, ""].join("/"))
-            .await?;
+        let req = RangeKVReq::prefix(vec![TableId::PREFIX, ""].join("/"));
 
         let mut res = vec![];
 
-        for (kk, vv) in reply.into_iter() {
+        range_pages(self, req, |kk, vv| {
             let table_id = TableId::from_key(&kk).map_err(|e| {
                 let inv = InvalidReply::new("list_all_tables", &e);
                 let meta_net_err = MetaNetworkError::InvalidReply(inv);
@@ -1043,7 +1064,10 @@ impl<KV: KVApi> SchemaApi for KV {
             let table_meta: TableMeta = deserialize_struct(&vv.data)?;
 
             res.push((table_id, vv.seq, table_meta));
-        }
+            Ok(())
+        })
+        .await?;
+
         Ok(res)
     }
 
@@ -1136,7 +1160,7 @@ impl<KV: KVApi> SchemaApi for KV {
 
                 tb_meta.drop_on = Some(Utc::now());
 
-                let txn_req = TxnRequest {
+                let mut txn_req = TxnRequest {
                     condition: vec![
                         // db has not to change, i.e., no new table is created.
                         // Renaming db is OK and does not affect the seq of db_meta.
@@ -1145,8 +1169,6 @@ impl<KV: KVApi> SchemaApi for KV {
                         txn_cond_seq(&dbid_tbname, Eq, tb_id_seq),
                         // table is not changed
                         txn_cond_seq(&tbid, Eq, tb_meta_seq),
-                        // update table count atomicly
-                        txn_cond_seq(&tb_count_key, Eq, tb_count_seq),
                     ],
                     if_then: vec![
                         // Changing a table in a db has to update the seq of db_meta,
@@ -1155,11 +1177,23 @@ impl<KV: KVApi> SchemaApi for KV {
                         txn_op_put(&DatabaseId { db_id }, serialize_struct(&db_meta)?), /* (db_id) -> db_meta */
                         txn_op_del(&dbid_tbname), // (db_id, tb_name) -> tb_id
                         txn_op_put(&tbid, serialize_struct(&tb_meta)?), /* (tenant, db_id, tb_id) -> tb_meta */
-                        txn_op_put(&tb_count_key, serialize_u64(tb_count - 1)?), /* _fd_table_count/tenant -> tb_count */
                     ],
                     else_then: vec![],
                 };
 
+                if tb_count_seq == 0 {
+                    // initialize table count, on condition that no other txn has done it.
+                    txn_req.condition.push(txn_cond_seq(&tb_count_key, Eq, 0));
+                    // _fd_table_count/tenant -> tb_count
+                    let tb_count = serialize_u64(tb_count - 1)?;
+                    txn_req.if_then.push(txn_op_put(&tb_count_key, tb_count));
+                } else {
+                    // update table count atomicly, without conflicting with other txns.
+                    txn_req
+                        .if_then
+                        .push(txn_op_fetch_add_u64(&tb_count_key, -1));
+                }
+
                 let (succ, _responses) = send_txn(self, txn_req).await?;
 
                 debug!(
@@ -1294,7 +1328,7 @@ impl<KV: KVApi> SchemaApi for KV {
                 }
                 tb_meta.drop_on = None;
 
-                let txn_req = TxnRequest {
+                let mut txn_req = TxnRequest {
                     condition: vec![
                         // db has not to change, i.e., no new table is created.
                         // Renaming db is OK and does not affect the seq of db_meta.
@@ -1303,8 +1337,6 @@ impl<KV: KVApi> SchemaApi for KV {
                         txn_cond_seq(&dbid_tbname, Eq, tb_id_seq),
                         // table is not changed
                         txn_cond_seq(&tbid, Eq, tb_meta_seq),
-                        // update table count atomicly
-                        txn_cond_seq(&tb_count_key, Eq, tb_count_seq),
                     ],
                     if_then: vec![
                         // Changing a table in a db has to update the seq of db_meta,
@@ -1314,11 +1346,21 @@ impl<KV: KVApi> SchemaApi for KV {
                         txn_op_put(&dbid_tbname, serialize_u64(table_id)?), /* (tenant, db_id, tb_name) -> tb_id */
                         // txn_op_put(&dbid_tbname_idlist, serialize_struct(&tb_id_list)?)?, // _fd_table_id_list/db_id/table_name -> tb_id_list
                         txn_op_put(&tbid, serialize_struct(&tb_meta)?), /* (tenant, db_id, tb_id) -> tb_meta */
-                        txn_op_put(&tb_count_key, serialize_u64(tb_count + 1)?), /* _fd_table_count/tenant -> tb_count */
                     ],
                     else_then: vec![],
                 };
 
+                if tb_count_seq == 0 {
+                    // initialize table count, on condition that no other txn has done it.
+                    txn_req.condition.push(txn_cond_seq(&tb_count_key, Eq, 0));
+                    // _fd_table_count/tenant -> tb_count
+                    let tb_count = serialize_u64(tb_count + 1)?;
+                    txn_req.if_then.push(txn_op_put(&tb_count_key, tb_count));
+                } else {
+                    // update table count atomicly, without conflicting with other txns.
+                    txn_req.if_then.push(txn_op_fetch_add_u64(&tb_count_key, 1));
+                }
+
                 let (succ, _responses) = send_txn(self, txn_req).await?;
 
                 debug!(
@@ -2082,11 +2124,32 @@ impl<KV: KVApi> SchemaApi for KV {
                 )));
             }
 
+            let mut condition = vec![
+                // table is not changed
+                txn_cond_seq(&tbid, Eq, tb_meta_seq),
+            ];
+
+            if let Some(revision) = req.lock_revision {
+                // the caller still holds the lock
+                let lock_key = TableLockKey {
+                    table_id: req.table_id,
+                    revision,
+                };
+                let (lock_seq, _): (_, Option<TableLockMeta>) =
+                    get_struct_value(self, &lock_key).await?;
+
+                if lock_seq == 0 {
+                    return Err(KVAppError::AppError(AppError::from(TableLockExpired::new(
+                        req.table_id,
+                        revision,
+                        "update_table_meta",
+                    ))));
+                }
+                condition.push(txn_cond_seq(&lock_key, Eq, lock_seq));
+            }
+
             let txn_req = TxnRequest {
-                condition: vec![
-                    // table is not changed
-                    txn_cond_seq(&tbid, Eq, tb_meta_seq),
-                ],
+                condition,
                 if_then: vec![
                     txn_op_put(&tbid, serialize_struct(&req.new_table_meta)?), // tb_id -> tb_meta
                 ],
@@ -2185,6 +2248,161 @@ impl<KV: KVApi> SchemaApi for KV {
         Ok(CountTablesReply { count })
     }
 
+    #[tracing::instrument(level = "debug", ret, err, skip_all)]
+    async fn create_table_lock_rev(
+        &self,
+        req: CreateTableLockRevReq,
+    ) -> Result<CreateTableLockRevReply, KVAppError> {
+        debug!(req = debug(&req), "SchemaApi: {}", func_name!());
+
+        let table_id = req.table_id;
+        let tbid = TableId { table_id };
+
+        let revision = fetch_id(self, IdGenerator::table_lock_id()).await?;
+        let lock_key = TableLockKey { table_id, revision };
+        let lock_meta = TableLockMeta {
+            user: req.user,
+            node: req.node,
+            query_id: req.query_id,
+            created_on: Utc::now(),
+        };
+
+        let txn_req = TxnRequest {
+            condition: vec![
+                // table exists
+                txn_cond_seq(&tbid, ConditionResult::Gt, 0),
+            ],
+            if_then: vec![txn_op_put_with_expire(
+                &lock_key,
+                serialize_struct(&lock_meta)?,
+                req.expire_at,
+            )],
+            else_then: vec![],
+        };
+
+        let (succ, _responses) = send_txn(self, txn_req).await?;
+
+        debug!(
+            key = display(&lock_key),
+            succ = display(succ),
+            "create_table_lock_rev"
+        );
+
+        if !succ {
+            return Err(KVAppError::AppError(AppError::UnknownTableId(
+                UnknownTableId::new(table_id, "create_table_lock_rev"),
+            )));
+        }
+
+        Ok(CreateTableLockRevReply { revision })
+    }
+
+    #[tracing::instrument(level = "debug", ret, err, skip_all)]
+    async fn extend_table_lock_rev(&self, req: ExtendTableLockRevReq) -> Result<(), KVAppError> {
+        debug!(req = debug(&req), "SchemaApi: {}", func_name!());
+
+        let lock_key = TableLockKey {
+            table_id: req.table_id,
+            revision: req.revision,
+        };
+
+        let (lock_seq, lock_meta): (_, Option<TableLockMeta>) =
+            get_struct_value(self, &lock_key).await?;
+
+        let lock_expired = || {
+            KVAppError::AppError(AppError::from(TableLockExpired::new(
+                req.table_id,
+                req.revision,
+                "extend_table_lock_rev",
+            )))
+        };
+
+        let lock_meta = lock_meta.ok_or_else(lock_expired)?;
+
+        let txn_req = TxnRequest {
+            condition: vec![txn_cond_seq(&lock_key, Eq, lock_seq)],
+            if_then: vec![txn_op_put_with_expire(
+                &lock_key,
+                serialize_struct(&lock_meta)?,
+                req.expire_at,
+            )],
+            else_then: vec![],
+        };
+
+        let (succ, _responses) = send_txn(self, txn_req).await?;
+
+        debug!(
+            key = display(&lock_key),
+            succ = display(succ),
+            "extend_table_lock_rev"
+        );
+
+        if !succ {
+            return Err(lock_expired());
+        }
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "debug", ret, err, skip_all)]
+    async fn delete_table_lock_rev(&self, req: DeleteTableLockRevReq) -> Result<(), KVAppError> {
+        debug!(req = debug(&req), "SchemaApi: {}", func_name!());
+
+        let lock_key = TableLockKey {
+            table_id: req.table_id,
+            revision: req.revision,
+        };
+
+        let txn_req = TxnRequest {
+            condition: vec![],
+            if_then: vec![txn_op_del(&lock_key)],
+            else_then: vec![],
+        };
+
+        send_txn(self, txn_req).await?;
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "debug", ret, err, skip_all)]
+    async fn list_table_locks(
+        &self,
+        req: ListTableLocksReq,
+    ) -> Result<Vec<TableLockInfo>, KVAppError> {
+        debug!(req = debug(&req), "SchemaApi: {}", func_name!());
+
+        let mut res = vec![];
+
+        for table_id in req.table_ids {
+            let prefix = format!("{}/{}/", TableLockKey::PREFIX, table_id);
+
+            let mut revisions = vec![];
+            range_pages(self, RangeKVReq::prefix(prefix), |k, v| {
+                let lock_key = TableLockKey::from_key(&k).map_err(|e| {
+                    let inv = InvalidReply::new("list_table_locks", &e);
+                    MetaNetworkError::InvalidReply(inv)
+                })?;
+                let lock_meta: TableLockMeta = deserialize_struct(&v.data)?;
+
+                revisions.push((lock_key.revision, lock_meta));
+                Ok(())
+            })
+            .await?;
+
+            // Revisions are ordered by their string form in the key, not by number.
+            revisions.sort_by_key(|(revision, _)| *revision);
+
+            for (i, (revision, meta)) in revisions.into_iter().enumerate() {
+                res.push(TableLockInfo {
+                    table_id,
+                    revision,
+                    holding: i == 0,
+                    meta,
+                });
+            }
+        }
+
+        Ok(res)
+    }
+
     fn name(&self) -> String {
         "SchemaApiImpl".to_string()
     }
@@ -25,6 +25,7 @@ use common_meta_app::schema::TableCopiedFileNameIdent;
 use common_meta_app::schema::TableId;
 use common_meta_app::schema::TableIdListKey;
 use common_meta_app::schema::TableIdToName;
+use common_meta_app::schema::TableLockKey;
 use kv_api_key::check_segment;
 use kv_api_key::check_segment_absent;
 use kv_api_key::check_segment_present;
@@ -47,9 +48,11 @@ const PREFIX_DATABASE_ID_TO_NAME: &str = "__fd_database_id_to_name";
 const PREFIX_TABLE_ID_TO_NAME: &str = "__fd_table_id_to_name";
 const PREFIX_TABLE_COPIED_FILES: &str = "__fd_table_copied_files";
 const PREFIX_TABLE_COPIED_FILES_LOCK: &str = "__fd_table_copied_file_lock";
+const PREFIX_TABLE_LOCK: &str = "__fd_table_lock";
 
 pub(crate) const ID_GEN_TABLE: &str = "table_id";
 pub(crate) const ID_GEN_DATABASE: &str = "database_id";
+pub(crate) const ID_GEN_TABLE_LOCK: &str = "table_lock_id";
 
 /// __fd_database/<tenant>/<db_name> -> <db_id>
 impl KVApiKey for DatabaseNameIdent {
@@ -346,6 +349,32 @@ impl KVApiKey for TableCopiedFileLockKey {
     }
 }
 
+// __fd_table_lock/<table_id>/<revision> -> TableLockMeta
+impl KVApiKey for TableLockKey {
+    const PREFIX: &'static str = PREFIX_TABLE_LOCK;
+
+    fn to_key(&self) -> String {
+        format!("{}/{}/{}", Self::PREFIX, self.table_id, self.revision)
+    }
+
+    fn from_key(s: &str) -> Result<Self, KVApiKeyError> {
+        let mut elts = s.split('/');
+
+        let prefix = check_segment_present(elts.next(), 0, s)?;
+        check_segment(prefix, 0, Self::PREFIX)?;
+
+        let table_id = check_segment_present(elts.next(), 1, s)?;
+        let table_id = decode_id(table_id)?;
+
+        let revision = check_segment_present(elts.next(), 2, s)?;
+        let revision = decode_id(revision)?;
+
+        check_segment_absent(elts.next(), 3, s)?;
+
+        Ok(TableLockKey { table_id, revision })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use common_meta_app::schema::TableCopiedFileNameIdent;
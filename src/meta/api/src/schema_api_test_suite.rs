@@ -23,6 +23,7 @@ use common_exception::ErrorCode;
 use common_meta_app::schema::CountTablesReq;
 use common_meta_app::schema::CreateDatabaseReply;
 use common_meta_app::schema::CreateDatabaseReq;
+use common_meta_app::schema::CreateTableLockRevReq;
 use common_meta_app::schema::CreateTableReq;
 use common_meta_app::schema::DBIdTableName;
 use common_meta_app::schema::DatabaseId;
@@ -32,12 +33,15 @@ use common_meta_app::schema::DatabaseMeta;
 use common_meta_app::schema::DatabaseNameIdent;
 use common_meta_app::schema::DbIdList;
 use common_meta_app::schema::DbIdListKey;
+use common_meta_app::schema::DeleteTableLockRevReq;
 use common_meta_app::schema::DropDatabaseReq;
 use common_meta_app::schema::DropTableReq;
+use common_meta_app::schema::ExtendTableLockRevReq;
 use common_meta_app::schema::GetDatabaseReq;
 use common_meta_app::schema::GetTableCopiedFileReq;
 use common_meta_app::schema::GetTableReq;
 use common_meta_app::schema::ListDatabaseReq;
+use common_meta_app::schema::ListTableLocksReq;
 use common_meta_app::schema::ListTableReq;
 use common_meta_app::schema::RenameDatabaseReq;
 use common_meta_app::schema::RenameTableReq;
@@ -236,6 +240,7 @@ impl SchemaApiTestSuite {
         suite.table_create_get_drop(&b.build().await).await?;
         suite.table_rename(&b.build().await).await?;
         suite.table_update_meta(&b.build().await).await?;
+        suite.table_lock(&b.build().await).await?;
         suite.table_upsert_option(&b.build().await).await?;
         suite.table_list(&b.build().await).await?;
         suite.table_list_all(&b.build().await).await?;
@@ -2051,6 +2056,7 @@ impl SchemaApiTestSuite {
                     table_id,
                     seq: MatchSeq::Exact(table_version),
                     new_table_meta: new_table_meta.clone(),
+                    lock_revision: None,
                 })
                 .await?;
 
@@ -2070,6 +2076,7 @@ impl SchemaApiTestSuite {
                         table_id,
                         seq: MatchSeq::Exact(table_version + 1),
                         new_table_meta: new_table_meta.clone(),
+                        lock_revision: None,
                     })
                     .await;
 
@@ -2082,6 +2089,161 @@ impl SchemaApiTestSuite {
         Ok(())
     }
 
+    #[tracing::instrument(level = "debug", skip_all)]
+    async fn table_lock<MT: SchemaApi>(&self, mt: &MT) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+        let db_name = "db1";
+        let tbl_name = "tb2";
+
+        info!("--- prepare db and table");
+        let table_id = {
+            let plan = CreateDatabaseReq {
+                if_not_exists: false,
+                name_ident: DatabaseNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                },
+                meta: DatabaseMeta {
+                    engine: "".to_string(),
+                    ..DatabaseMeta::default()
+                },
+            };
+            mt.create_database(plan).await?;
+
+            let req = CreateTableReq {
+                if_not_exists: false,
+                name_ident: TableNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                    table_name: tbl_name.to_string(),
+                },
+                table_meta: TableMeta {
+                    schema: Arc::new(DataSchema::new(vec![DataField::new(
+                        "number",
+                        u64::to_data_type(),
+                    )])),
+                    engine: "JSON".to_string(),
+                    ..TableMeta::default()
+                },
+            };
+            mt.create_table(req).await?.table_id
+        };
+
+        let now_secs = Utc::now().timestamp() as u64;
+        let create_req = |query_id: &str, expire_at: u64| CreateTableLockRevReq {
+            table_id,
+            expire_at,
+            user: "root".to_string(),
+            node: "node-1".to_string(),
+            query_id: query_id.to_string(),
+        };
+        let list_locks = || async move {
+            let locks = mt
+                .list_table_locks(ListTableLocksReq {
+                    table_ids: vec![table_id],
+                })
+                .await?;
+            let locks = locks
+                .into_iter()
+                .map(|l| (l.revision, l.holding, l.meta.query_id))
+                .collect::<Vec<_>>();
+            Ok::<_, KVAppError>(locks)
+        };
+
+        info!("--- the smallest revision holds the lock, the others wait");
+        let (rev1, rev2) = {
+            let rev1 = mt
+                .create_table_lock_rev(create_req("q1", now_secs + 60))
+                .await?
+                .revision;
+            let rev2 = mt
+                .create_table_lock_rev(create_req("q2", now_secs + 60))
+                .await?
+                .revision;
+            assert!(rev1 < rev2);
+
+            // Expired revisions are not listed.
+            mt.create_table_lock_rev(create_req("q3", now_secs - 10))
+                .await?;
+
+            assert_eq!(
+                vec![
+                    (rev1, true, "q1".to_string()),
+                    (rev2, false, "q2".to_string())
+                ],
+                list_locks().await?
+            );
+
+            mt.extend_table_lock_rev(ExtendTableLockRevReq {
+                table_id,
+                revision: rev2,
+                expire_at: now_secs + 120,
+            })
+            .await?;
+
+            (rev1, rev2)
+        };
+
+        info!("--- update table meta with the fencing token of the holder");
+        {
+            let table = mt.get_table((tenant, db_name, tbl_name).into()).await?;
+            mt.update_table_meta(UpdateTableMetaReq {
+                table_id,
+                seq: MatchSeq::Exact(table.ident.seq),
+                new_table_meta: table.meta.clone(),
+                lock_revision: Some(rev1),
+            })
+            .await?;
+        }
+
+        info!("--- release the lock, the next waiter holds it");
+        {
+            mt.delete_table_lock_rev(DeleteTableLockRevReq {
+                table_id,
+                revision: rev1,
+            })
+            .await?;
+
+            assert_eq!(vec![(rev2, true, "q2".to_string())], list_locks().await?);
+        }
+
+        info!("--- a released revision can not be used");
+        {
+            let table = mt.get_table((tenant, db_name, tbl_name).into()).await?;
+            let res = mt
+                .update_table_meta(UpdateTableMetaReq {
+                    table_id,
+                    seq: MatchSeq::Exact(table.ident.seq),
+                    new_table_meta: table.meta.clone(),
+                    lock_revision: Some(rev1),
+                })
+                .await;
+            let err = ErrorCode::from(res.unwrap_err());
+            assert_eq!(ErrorCode::table_lock_expired_code(), err.code());
+
+            let res = mt
+                .extend_table_lock_rev(ExtendTableLockRevReq {
+                    table_id,
+                    revision: rev1,
+                    expire_at: now_secs + 120,
+                })
+                .await;
+            let err = ErrorCode::from(res.unwrap_err());
+            assert_eq!(ErrorCode::table_lock_expired_code(), err.code());
+        }
+
+        info!("--- can not lock an unknown table");
+        {
+            let mut req = create_req("q4", now_secs + 60);
+            req.table_id = table_id + 1000;
+            let res = mt.create_table_lock_rev(req).await;
+            let err = ErrorCode::from(res.unwrap_err());
+            assert_eq!(ErrorCode::unknown_table_id_code(), err.code());
+        }
+
+        Ok(())
+    }
+
     #[tracing::instrument(level = "debug", skip_all)]
     async fn table_upsert_option<MT: SchemaApi>(&self, mt: &MT) -> anyhow::Result<()> {
         let tenant = "tenant1";
@@ -36,8 +36,11 @@ use common_meta_types::KVAppError;
 use common_meta_types::MatchSeq;
 use common_meta_types::MetaNetworkError;
 use common_meta_types::Operation;
+use common_meta_types::RangeKVReq;
+use common_meta_types::SeqV;
 use common_meta_types::TxnCondition;
 use common_meta_types::TxnDeleteRequest;
+use common_meta_types::TxnFetchAddU64Request;
 use common_meta_types::TxnOp;
 use common_meta_types::TxnOpResponse;
 use common_meta_types::TxnPutRequest;
@@ -54,6 +57,30 @@ use crate::KVApiKey;
 
 pub const TXN_MAX_RETRY_TIMES: u32 = 10;
 
+/// The number of entries a listing function reads from the meta-service at a time.
+pub const LIST_PAGE_SIZE: u64 = 1000;
+
+/// Read all entries in the range of `req` page by page and feed them to `f`,
+/// so that a large range is never returned in one reply.
+pub async fn range_pages(
+    kv_api: &(impl KVApi + ?Sized),
+    req: RangeKVReq,
+    mut f: impl FnMut(String, SeqV) -> Result<(), KVAppError>,
+) -> Result<(), KVAppError> {
+    let mut next = Some(req.with_limit(LIST_PAGE_SIZE));
+
+    while let Some(req) = next {
+        let reply = kv_api.range_kv(req.clone()).await?;
+        next = req.next_page(&reply);
+
+        for (k, v) in reply.kvs {
+            f(k, v)?;
+        }
+    }
+
+    Ok(())
+}
+
 /// Get value that its type is `u64`.
 ///
 /// It expects the kv-value's type is `u64`, such as:
@@ -102,19 +129,19 @@ pub async fn list_keys<K: KVApiKey>(
     kv_api: &(impl KVApi + ?Sized),
     key: &K,
 ) -> Result<Vec<K>, KVAppError> {
-    let res = kv_api.prefix_list_kv(&key.to_key()).await?;
-
-    let n = res.len();
+    let req = RangeKVReq::prefix(key.to_key()).keys_only();
 
-    let mut structured_keys = Vec::with_capacity(n);
+    let mut structured_keys = vec![];
 
-    for (str_key, _seq_id) in res.iter() {
-        let struct_key = K::from_key(str_key).map_err(|e| {
+    range_pages(kv_api, req, |str_key, _seq_v| {
+        let struct_key = K::from_key(&str_key).map_err(|e| {
             let inv = InvalidReply::new("fail to list_keys", &e);
             MetaNetworkError::InvalidReply(inv)
         })?;
         structured_keys.push(struct_key);
-    }
+        Ok(())
+    })
+    .await?;
 
     Ok(structured_keys)
 }
@@ -130,24 +157,24 @@ pub async fn list_u64_value<K: KVApiKey>(
     kv_api: &(impl KVApi + ?Sized),
     key: &K,
 ) -> Result<(Vec<K>, Vec<u64>), KVAppError> {
-    let res = kv_api.prefix_list_kv(&key.to_key()).await?;
-
-    let n = res.len();
+    let req = RangeKVReq::prefix(key.to_key());
 
-    let mut structured_keys = Vec::with_capacity(n);
-    let mut values = Vec::with_capacity(n);
+    let mut structured_keys = vec![];
+    let mut values = vec![];
 
-    for (str_key, seqv) in res.iter() {
+    range_pages(kv_api, req, |str_key, seqv| {
         let id = *deserialize_u64(&seqv.data)?;
         values.push(id);
 
         // Parse key
-        let struct_key = K::from_key(str_key).map_err(|e| {
+        let struct_key = K::from_key(&str_key).map_err(|e| {
             let inv = InvalidReply::new("list_u64_value", &e);
             MetaNetworkError::InvalidReply(inv)
         })?;
         structured_keys.push(struct_key);
-    }
+        Ok(())
+    })
+    .await?;
 
     Ok((structured_keys, values))
 }
@@ -248,6 +275,7 @@ pub fn txn_op_put(key: &impl KVApiKey, value: Vec<u8>) -> TxnOp {
             value,
             prev_value: true,
             expire_at: None,
+            lease: None,
         })),
     }
 }
@@ -259,6 +287,17 @@ pub fn txn_op_put_with_expire(key: &impl KVApiKey, value: Vec<u8>, expire_at: u6
             value,
             prev_value: true,
             expire_at: Some(expire_at),
+            lease: None,
+        })),
+    }
+}
+
+/// Build a txn operation that atomically adds `delta` to a u64 record.
+pub fn txn_op_fetch_add_u64(key: &impl KVApiKey, delta: i64) -> TxnOp {
+    TxnOp {
+        request: Some(Request::FetchAddU64(TxnFetchAddU64Request {
+            key: key.to_key(),
+            delta,
         })),
     }
 }
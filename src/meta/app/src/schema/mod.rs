@@ -16,6 +16,7 @@
 
 mod database;
 mod table;
+mod table_lock;
 
 pub use database::CreateDatabaseReply;
 pub use database::CreateDatabaseReq;
@@ -72,3 +73,11 @@ pub use table::UpsertTableCopiedFileReply;
 pub use table::UpsertTableCopiedFileReq;
 pub use table::UpsertTableOptionReply;
 pub use table::UpsertTableOptionReq;
+pub use table_lock::CreateTableLockRevReply;
+pub use table_lock::CreateTableLockRevReq;
+pub use table_lock::DeleteTableLockRevReq;
+pub use table_lock::ExtendTableLockRevReq;
+pub use table_lock::ListTableLocksReq;
+pub use table_lock::TableLockInfo;
+pub use table_lock::TableLockKey;
+pub use table_lock::TableLockMeta;
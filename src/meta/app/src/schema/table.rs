@@ -518,6 +518,9 @@ pub struct UpdateTableMetaReq {
     pub table_id: u64,
     pub seq: MatchSeq,
     pub new_table_meta: TableMeta,
+    /// The fencing token: if it is set, the update fails unless this lock revision
+    /// of the table is still alive.
+    pub lock_revision: Option<u64>,
 }
 
 impl UpsertTableOptionReq {
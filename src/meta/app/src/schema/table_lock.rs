@@ -0,0 +1,89 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use common_datavalues::chrono::DateTime;
+use common_datavalues::chrono::Utc;
+
+/// A lock revision of a table: `__fd_table_lock/<table_id>/<revision>`.
+///
+/// Every query that wants to lock a table creates a revision, the alive revision with the
+/// smallest `revision` holds the lock, the others wait in the order of their revisions.
+/// The `revision` is also the fencing token of the holder.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+pub struct TableLockKey {
+    pub table_id: u64,
+    pub revision: u64,
+}
+
+impl Display for TableLockKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.table_id, self.revision)
+    }
+}
+
+/// Who created a table lock revision.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct TableLockMeta {
+    pub user: String,
+    pub node: String,
+    pub query_id: String,
+    pub created_on: DateTime<Utc>,
+}
+
+/// A lock revision and the information about whether it holds the lock.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct TableLockInfo {
+    pub table_id: u64,
+    pub revision: u64,
+    /// The revision holds the lock if it is the smallest alive revision of the table.
+    pub holding: bool,
+    pub meta: TableLockMeta,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CreateTableLockRevReq {
+    pub table_id: u64,
+    /// In seconds since 1970, the revision is removed if it is not extended before it.
+    pub expire_at: u64,
+    pub user: String,
+    pub node: String,
+    pub query_id: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CreateTableLockRevReply {
+    pub revision: u64,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ExtendTableLockRevReq {
+    pub table_id: u64,
+    pub revision: u64,
+    pub expire_at: u64,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DeleteTableLockRevReq {
+    pub table_id: u64,
+    pub revision: u64,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ListTableLocksReq {
+    pub table_ids: Vec<u64>,
+}
@@ -17,6 +17,12 @@ use std::fmt::Debug;
 
 use common_meta_types::protobuf::meta_service_client::MetaServiceClient;
 use common_meta_types::protobuf::ClientInfo;
+use common_meta_types::protobuf::KvRangeItem;
+use common_meta_types::protobuf::KvRangeRequest;
+use common_meta_types::protobuf::LeaseGrantRequest;
+use common_meta_types::protobuf::LeaseKeepAliveRequest;
+use common_meta_types::protobuf::LeaseReply;
+use common_meta_types::protobuf::LeaseRevokeRequest;
 use common_meta_types::protobuf::RaftRequest;
 use common_meta_types::protobuf::WatchRequest;
 use common_meta_types::protobuf::WatchResponse;
@@ -26,6 +32,8 @@ use common_meta_types::ListKVReply;
 use common_meta_types::ListKVReq;
 use common_meta_types::MGetKVReply;
 use common_meta_types::MGetKVReq;
+use common_meta_types::RangeKVReply;
+use common_meta_types::RangeKVReq;
 use common_meta_types::TxnReply;
 use common_meta_types::TxnRequest;
 use common_meta_types::UpsertKVReply;
@@ -57,6 +65,7 @@ pub enum MetaGrpcReadReq {
     GetKV(GetKVReq),
     MGetKV(MGetKVReq),
     ListKV(ListKVReq), // since 2022-05-23
+    RangeKV(RangeKVReq),
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, derive_more::From)]
@@ -66,6 +75,7 @@ pub enum MetaGrpcReq {
     GetKV(GetKVReq),
     MGetKV(MGetKVReq),
     ListKV(ListKVReq),
+    RangeKV(RangeKVReq),
 }
 
 impl TryInto<MetaGrpcReq> for Request<RaftRequest> {
@@ -128,6 +138,10 @@ impl RequestFor for ListKVReq {
     type Reply = ListKVReply;
 }
 
+impl RequestFor for RangeKVReq {
+    type Reply = RangeKVReply;
+}
+
 impl RequestFor for UpsertKVReq {
     type Reply = UpsertKVReply;
 }
@@ -136,6 +150,10 @@ impl RequestFor for WatchRequest {
     type Reply = tonic::codec::Streaming<WatchResponse>;
 }
 
+impl RequestFor for KvRangeRequest {
+    type Reply = tonic::codec::Streaming<KvRangeItem>;
+}
+
 impl RequestFor for ExportReq {
     type Reply = tonic::codec::Streaming<WatchResponse>;
 }
@@ -155,3 +173,15 @@ impl RequestFor for TxnRequest {
 impl RequestFor for GetClientInfo {
     type Reply = ClientInfo;
 }
+
+impl RequestFor for LeaseGrantRequest {
+    type Reply = LeaseReply;
+}
+
+impl RequestFor for LeaseKeepAliveRequest {
+    type Reply = LeaseReply;
+}
+
+impl RequestFor for LeaseRevokeRequest {
+    type Reply = LeaseReply;
+}
@@ -43,6 +43,12 @@ use common_meta_types::protobuf::ClientInfo;
 use common_meta_types::protobuf::Empty;
 use common_meta_types::protobuf::ExportedChunk;
 use common_meta_types::protobuf::HandshakeRequest;
+use common_meta_types::protobuf::KvRangeItem;
+use common_meta_types::protobuf::KvRangeRequest;
+use common_meta_types::protobuf::LeaseGrantRequest;
+use common_meta_types::protobuf::LeaseKeepAliveRequest;
+use common_meta_types::protobuf::LeaseReply;
+use common_meta_types::protobuf::LeaseRevokeRequest;
 use common_meta_types::protobuf::MemberListReply;
 use common_meta_types::protobuf::MemberListRequest;
 use common_meta_types::protobuf::RaftReply;
@@ -56,6 +62,7 @@ use common_meta_types::MetaClientError;
 use common_meta_types::MetaError;
 use common_meta_types::MetaHandshakeError;
 use common_meta_types::MetaNetworkError;
+use common_meta_types::RangeKVReq;
 use common_meta_types::TxnReply;
 use common_meta_types::TxnRequest;
 use common_metrics::label_counter_with_val_and_labels;
@@ -91,6 +98,10 @@ use crate::METACLI_COMMIT_SEMVER;
 use crate::MIN_METASRV_SEMVER;
 
 const AUTH_TOKEN_KEY: &str = "auth-token-bin";
+/// The header in which a client specifies the max staleness in milliseconds a read is allowed to have.
+pub const MAX_READ_STALENESS_KEY: &str = "max-read-staleness-ms";
+/// The header in which a client specifies, when handshaking, the tenant its session writes on behalf of.
+pub const TENANT_KEY: &str = "tenant-bin";
 const META_GRPC_CLIENT_REQUEST_DURATION_MS: &str = "meta_grpc_client_request_duration_ms";
 const META_GRPC_CLIENT_REQUEST_INFLIGHT: &str = "meta_grpc_client_request_inflight";
 const META_GRPC_CLIENT_REQUEST_SUCCESS: &str = "meta_grpc_client_request_success";
@@ -216,6 +227,32 @@ impl ClientHandle {
         self.request(message::GetClientInfo {}).await
     }
 
+    /// Grant a lease that expires if it is not kept alive within `ttl`.
+    pub async fn lease_grant(&self, ttl: Duration) -> Result<LeaseReply, MetaError> {
+        self.request(LeaseGrantRequest {
+            ttl_ms: ttl.as_millis() as u64,
+        })
+        .await
+    }
+
+    /// Extend a lease by its ttl. It fails if the lease is already expired or revoked.
+    pub async fn lease_keep_alive(&self, lease_id: u64) -> Result<LeaseReply, MetaError> {
+        self.request(LeaseKeepAliveRequest { lease_id }).await
+    }
+
+    /// Revoke a lease and delete the keys attached to it.
+    pub async fn lease_revoke(&self, lease_id: u64) -> Result<LeaseReply, MetaError> {
+        self.request(LeaseRevokeRequest { lease_id }).await
+    }
+
+    /// Read a key range as a stream, the server reads it page by page.
+    pub async fn kv_range(
+        &self,
+        req: RangeKVReq,
+    ) -> Result<tonic::codec::Streaming<KvRangeItem>, MetaError> {
+        self.request(KvRangeRequest::from(req)).await
+    }
+
     pub async fn make_client(
         &self,
     ) -> Result<MetaServiceClient<InterceptedService<Channel, AuthInterceptor>>, MetaClientError>
@@ -236,6 +273,8 @@ pub struct MetaGrpcClient {
     current_endpoint: Arc<Mutex<Option<String>>>,
     unhealthy_endpoints: Mutex<TtlHashMap<String, ()>>,
     auto_sync_interval: Option<Duration>,
+    max_read_staleness: Option<Duration>,
+    tenant: Option<String>,
 
     /// Dedicated runtime to support meta client background tasks.
     ///
@@ -260,16 +299,19 @@ impl MetaGrpcClient {
     /// The worker is a singleton and the returned handle is cheap to clone.
     /// When all handles are dropped the worker will quit, then the runtime will be destroyed.
     pub fn try_new(conf: &RpcClientConf) -> Result<Arc<ClientHandle>, MetaClientError> {
-        Self::try_create(
+        Self::do_create(
             conf.get_endpoints(),
             &conf.username,
             &conf.password,
             conf.timeout,
             conf.auto_sync_interval,
+            conf.max_read_staleness,
+            conf.tenant.clone(),
             conf.tls_conf.clone(),
         )
     }
 
+    /// Create a new client of metasrv that requires linearizable reads.
     #[tracing::instrument(level = "debug", skip(password))]
     pub fn try_create(
         endpoints: Vec<String>,
@@ -278,6 +320,30 @@ impl MetaGrpcClient {
         timeout: Option<Duration>,
         auto_sync_interval: Option<Duration>,
         conf: Option<RpcClientTlsConfig>,
+    ) -> Result<Arc<ClientHandle>, MetaClientError> {
+        Self::do_create(
+            endpoints,
+            username,
+            password,
+            timeout,
+            auto_sync_interval,
+            None,
+            None,
+            conf,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[tracing::instrument(level = "debug", skip(password))]
+    fn do_create(
+        endpoints: Vec<String>,
+        username: &str,
+        password: &str,
+        timeout: Option<Duration>,
+        auto_sync_interval: Option<Duration>,
+        max_read_staleness: Option<Duration>,
+        tenant: Option<String>,
+        conf: Option<RpcClientTlsConfig>,
     ) -> Result<Arc<ClientHandle>, MetaClientError> {
         Self::endpoints_non_empty(&endpoints)?;
 
@@ -307,6 +373,8 @@ impl MetaGrpcClient {
             current_endpoint: Arc::new(Mutex::new(None)),
             unhealthy_endpoints: Mutex::new(TtlHashMap::new(Duration::from_secs(120))),
             auto_sync_interval,
+            max_read_staleness,
+            tenant,
             username: username.to_string(),
             password: password.to_string(),
             rt: rt.clone(),
@@ -363,6 +431,14 @@ impl MetaGrpcClient {
                     let resp = self.do_read(r).await;
                     message::Response::PrefixList(resp)
                 }
+                message::Request::Range(r) => {
+                    let resp = self.do_read(r).await;
+                    message::Response::Range(resp)
+                }
+                message::Request::KvRange(r) => {
+                    let resp = self.kv_range(r).await;
+                    message::Response::KvRange(resp)
+                }
                 message::Request::Upsert(r) => {
                     let resp = self.do_write(r).await;
                     message::Response::Upsert(resp)
@@ -379,6 +455,18 @@ impl MetaGrpcClient {
                     let resp = self.export(r).await;
                     message::Response::Export(resp)
                 }
+                message::Request::LeaseGrant(r) => {
+                    let resp = self.lease_grant(r).await;
+                    message::Response::Lease(resp)
+                }
+                message::Request::LeaseKeepAlive(r) => {
+                    let resp = self.lease_keep_alive(r).await;
+                    message::Response::Lease(resp)
+                }
+                message::Request::LeaseRevoke(r) => {
+                    let resp = self.lease_revoke(r).await;
+                    message::Response::Lease(resp)
+                }
                 message::Request::MakeClient(_) => {
                     let resp = self.make_client().await;
                     message::Response::MakeClient(resp)
@@ -479,12 +567,14 @@ impl MetaGrpcClient {
                         &MIN_METASRV_SEMVER,
                         &self.username,
                         &self.password,
+                        self.tenant.as_deref(),
                     )
                     .await;
                     match new_token {
                         Ok(token) => {
                             return Ok(MetaServiceClient::with_interceptor(c, AuthInterceptor {
                                 token,
+                                max_read_staleness: self.max_read_staleness,
                             }));
                         }
                         Err(handshake_err) => {
@@ -677,6 +767,7 @@ impl MetaGrpcClient {
         min_metasrv_ver: &Version,
         username: &str,
         password: &str,
+        tenant: Option<&str>,
     ) -> Result<Vec<u8>, MetaHandshakeError> {
         debug!(
             client_ver = display(client_ver),
@@ -693,13 +784,19 @@ impl MetaGrpcClient {
             .map_err(|e| MetaHandshakeError::new("encode auth payload", &e))?;
 
         let my_ver = to_digit_ver(client_ver);
-        let req = Request::new(futures::stream::once(async move {
+        let mut req = Request::new(futures::stream::once(async move {
             HandshakeRequest {
                 protocol_version: my_ver,
                 payload,
             }
         }));
 
+        // The session is bound to the tenant, every write in it is made on behalf of the tenant.
+        if let Some(tenant) = tenant {
+            req.metadata_mut()
+                .insert_bin(TENANT_KEY, MetadataValue::from_bytes(tenant.as_bytes()));
+        }
+
         let rx = client
             .handshake(req)
             .await
@@ -753,6 +850,22 @@ impl MetaGrpcClient {
         Ok(res.into_inner())
     }
 
+    /// Create a stream that receives the entries in a key range.
+    #[tracing::instrument(level = "debug", skip_all)]
+    pub(crate) async fn kv_range(
+        &self,
+        range_request: KvRangeRequest,
+    ) -> Result<tonic::codec::Streaming<KvRangeItem>, MetaError> {
+        debug!(
+            range_request = debug(&range_request),
+            "MetaGrpcClient worker: handle kv_range request"
+        );
+
+        let mut client = self.make_client().await?;
+        let res = client.kv_range(range_request).await?;
+        Ok(res.into_inner())
+    }
+
     /// Export all data in json from metasrv.
     #[tracing::instrument(level = "debug", skip_all)]
     pub(crate) async fn export(
@@ -769,6 +882,36 @@ impl MetaGrpcClient {
         Ok(res.into_inner())
     }
 
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub(crate) async fn lease_grant(
+        &self,
+        req: LeaseGrantRequest,
+    ) -> Result<LeaseReply, MetaError> {
+        let mut client = self.make_client().await?;
+        let res = client.lease_grant(req).await?;
+        Ok(res.into_inner())
+    }
+
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub(crate) async fn lease_keep_alive(
+        &self,
+        req: LeaseKeepAliveRequest,
+    ) -> Result<LeaseReply, MetaError> {
+        let mut client = self.make_client().await?;
+        let res = client.lease_keep_alive(req).await?;
+        Ok(res.into_inner())
+    }
+
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub(crate) async fn lease_revoke(
+        &self,
+        req: LeaseRevokeRequest,
+    ) -> Result<LeaseReply, MetaError> {
+        let mut client = self.make_client().await?;
+        let res = client.lease_revoke(req).await?;
+        Ok(res.into_inner())
+    }
+
     /// Export all data in json from metasrv.
     #[tracing::instrument(level = "debug", skip_all)]
     pub(crate) async fn get_client_info(&self) -> Result<ClientInfo, MetaError> {
@@ -932,12 +1075,18 @@ fn status_is_retryable(status: &Status) -> bool {
 #[derive(Clone)]
 pub struct AuthInterceptor {
     pub token: Vec<u8>,
+    /// If set, a read is allowed to be served by a meta node that is at most this stale.
+    pub max_read_staleness: Option<Duration>,
 }
 
 impl Interceptor for AuthInterceptor {
     fn call(&mut self, mut req: tonic::Request<()>) -> Result<tonic::Request<()>, tonic::Status> {
         let metadata = req.metadata_mut();
         metadata.insert_bin(AUTH_TOKEN_KEY, MetadataValue::from_bytes(&self.token));
+        if let Some(staleness) = self.max_read_staleness {
+            let ms = staleness.as_millis() as u64;
+            metadata.insert(MAX_READ_STALENESS_KEY, MetadataValue::from(ms));
+        }
         Ok(req)
     }
 }
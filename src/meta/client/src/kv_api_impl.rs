@@ -20,6 +20,8 @@ use common_meta_types::ListKVReply;
 use common_meta_types::ListKVReq;
 use common_meta_types::MGetKVReply;
 use common_meta_types::MGetKVReq;
+use common_meta_types::RangeKVReply;
+use common_meta_types::RangeKVReq;
 use common_meta_types::TxnReply;
 use common_meta_types::TxnRequest;
 use common_meta_types::UpsertKVReply;
@@ -59,6 +61,11 @@ impl KVApi for MetaGrpcClient {
         Ok(reply)
     }
 
+    async fn range_kv(&self, req: RangeKVReq) -> Result<RangeKVReply, KVAppError> {
+        let reply = self.do_read(req).await?;
+        Ok(reply)
+    }
+
     async fn transaction(&self, txn: TxnRequest) -> Result<TxnReply, KVAppError> {
         let reply = self.transaction(txn).await?;
         Ok(reply)
@@ -96,6 +103,11 @@ impl KVApi for ClientHandle {
         Ok(reply)
     }
 
+    async fn range_kv(&self, req: RangeKVReq) -> Result<RangeKVReply, KVAppError> {
+        let reply = self.request(req).await?;
+        Ok(reply)
+    }
+
     async fn transaction(&self, txn: TxnRequest) -> Result<TxnReply, KVAppError> {
         let reply = self.request(txn).await?;
         Ok(reply)
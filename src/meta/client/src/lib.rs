@@ -23,6 +23,8 @@ pub use grpc_action::MetaGrpcWriteReq;
 pub use grpc_action::RequestFor;
 pub use grpc_client::ClientHandle;
 pub use grpc_client::MetaGrpcClient;
+pub use grpc_client::MAX_READ_STALENESS_KEY;
+pub use grpc_client::TENANT_KEY;
 pub use message::ClientWorkerRequest;
 use once_cell::sync::Lazy;
 use semver::BuildMetadata;
@@ -39,10 +41,14 @@ pub static METACLI_COMMIT_SEMVER: Lazy<Version> = Lazy::new(|| {
 });
 
 /// Oldest compatible nightly metasrv version
+///
+/// - 0.8.36: `RangeKV` and `KvRange` are required to list keys.
+/// - 0.8.36: txn conditions on key counts and the `get_range`, `put_if_absent` and `fetch_add_u64`
+///   ops, which an older metasrv decodes as absent and ignores.
 pub static MIN_METASRV_SEMVER: Version = Version {
     major: 0,
     minor: 8,
-    patch: 30,
+    patch: 36,
     pre: Prerelease::EMPTY,
     build: BuildMetadata::EMPTY,
 };
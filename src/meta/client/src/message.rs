@@ -16,6 +16,12 @@ use common_base::base::tokio::sync::oneshot::Sender;
 use common_meta_types::protobuf::meta_service_client::MetaServiceClient;
 use common_meta_types::protobuf::ClientInfo;
 use common_meta_types::protobuf::ExportedChunk;
+use common_meta_types::protobuf::KvRangeItem;
+use common_meta_types::protobuf::KvRangeRequest;
+use common_meta_types::protobuf::LeaseGrantRequest;
+use common_meta_types::protobuf::LeaseKeepAliveRequest;
+use common_meta_types::protobuf::LeaseReply;
+use common_meta_types::protobuf::LeaseRevokeRequest;
 use common_meta_types::protobuf::WatchRequest;
 use common_meta_types::protobuf::WatchResponse;
 use common_meta_types::GetKVReply;
@@ -27,6 +33,8 @@ use common_meta_types::MGetKVReply;
 use common_meta_types::MGetKVReq;
 use common_meta_types::MetaClientError;
 use common_meta_types::MetaError;
+use common_meta_types::RangeKVReply;
+use common_meta_types::RangeKVReq;
 use common_meta_types::TxnReply;
 use common_meta_types::TxnRequest;
 use common_meta_types::UpsertKVReply;
@@ -58,6 +66,12 @@ pub enum Request {
     /// List KVs by key prefix
     PrefixList(ListKVReq),
 
+    /// Read a page of KVs in a key range
+    Range(RangeKVReq),
+
+    /// Read KVs in a key range, expecting a Stream of the entries
+    KvRange(KvRangeRequest),
+
     /// Update or insert KV
     Upsert(UpsertKVReq),
 
@@ -70,6 +84,15 @@ pub enum Request {
     /// Export all data
     Export(ExportReq),
 
+    /// Grant a lease
+    LeaseGrant(LeaseGrantRequest),
+
+    /// Extend a lease by its ttl
+    LeaseKeepAlive(LeaseKeepAliveRequest),
+
+    /// Revoke a lease and delete the keys attached to it
+    LeaseRevoke(LeaseRevokeRequest),
+
     /// Get a initialized grpc-client
     MakeClient(MakeClient),
 
@@ -86,10 +109,15 @@ impl Request {
             Request::Get(_) => "Get",
             Request::MGet(_) => "MGet",
             Request::PrefixList(_) => "PrefixList",
+            Request::Range(_) => "Range",
+            Request::KvRange(_) => "KvRange",
             Request::Upsert(_) => "Upsert",
             Request::Txn(_) => "Txn",
             Request::Watch(_) => "Watch",
             Request::Export(_) => "Export",
+            Request::LeaseGrant(_) => "LeaseGrant",
+            Request::LeaseKeepAlive(_) => "LeaseKeepAlive",
+            Request::LeaseRevoke(_) => "LeaseRevoke",
             Request::MakeClient(_) => "MakeClient",
             Request::GetEndpoints(_) => "GetEndpoints",
             Request::GetClientInfo(_) => "GetClientInfo",
@@ -103,10 +131,14 @@ pub enum Response {
     Get(Result<GetKVReply, KVAppError>),
     MGet(Result<MGetKVReply, KVAppError>),
     PrefixList(Result<ListKVReply, KVAppError>),
+    Range(Result<RangeKVReply, KVAppError>),
+    KvRange(Result<tonic::codec::Streaming<KvRangeItem>, MetaError>),
     Upsert(Result<UpsertKVReply, KVAppError>),
     Txn(Result<TxnReply, KVAppError>),
     Watch(Result<tonic::codec::Streaming<WatchResponse>, MetaError>),
     Export(Result<tonic::codec::Streaming<ExportedChunk>, MetaError>),
+    /// Reply to all of the lease requests.
+    Lease(Result<LeaseReply, MetaError>),
     MakeClient(
         Result<MetaServiceClient<InterceptedService<Channel, AuthInterceptor>>, MetaClientError>,
     ),
@@ -120,10 +152,13 @@ impl Response {
             Response::Get(res) => res.is_err(),
             Response::MGet(res) => res.is_err(),
             Response::PrefixList(res) => res.is_err(),
+            Response::Range(res) => res.is_err(),
+            Response::KvRange(res) => res.is_err(),
             Response::Upsert(res) => res.is_err(),
             Response::Txn(res) => res.is_err(),
             Response::Watch(res) => res.is_err(),
             Response::Export(res) => res.is_err(),
+            Response::Lease(res) => res.is_err(),
             Response::MakeClient(res) => res.is_err(),
             Response::GetEndpoints(res) => res.is_err(),
             Response::GetClientInfo(res) => res.is_err(),
@@ -144,6 +179,14 @@ impl Response {
                 .as_ref()
                 .err()
                 .map(|x| x as &(dyn std::error::Error + 'static)),
+            Response::Range(res) => res
+                .as_ref()
+                .err()
+                .map(|x| x as &(dyn std::error::Error + 'static)),
+            Response::KvRange(res) => res
+                .as_ref()
+                .err()
+                .map(|x| x as &(dyn std::error::Error + 'static)),
             Response::Upsert(res) => res
                 .as_ref()
                 .err()
@@ -160,6 +203,10 @@ impl Response {
                 .as_ref()
                 .err()
                 .map(|x| x as &(dyn std::error::Error + 'static)),
+            Response::Lease(res) => res
+                .as_ref()
+                .err()
+                .map(|x| x as &(dyn std::error::Error + 'static)),
             Response::MakeClient(res) => res
                 .as_ref()
                 .err()
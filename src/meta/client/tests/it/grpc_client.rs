@@ -65,6 +65,7 @@ async fn test_grpc_client_handshake_timeout() {
             &MIN_METASRV_SEMVER,
             "root",
             "xxx",
+            None,
         )
         .await;
 
@@ -87,6 +88,7 @@ async fn test_grpc_client_handshake_timeout() {
             &MIN_METASRV_SEMVER,
             "root",
             "xxx",
+            None,
         )
         .await;
 
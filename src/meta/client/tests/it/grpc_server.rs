@@ -23,6 +23,12 @@ use common_meta_types::protobuf::ClientInfo;
 use common_meta_types::protobuf::Empty;
 use common_meta_types::protobuf::ExportedChunk;
 use common_meta_types::protobuf::HandshakeResponse;
+use common_meta_types::protobuf::KvRangeItem;
+use common_meta_types::protobuf::KvRangeRequest;
+use common_meta_types::protobuf::LeaseGrantRequest;
+use common_meta_types::protobuf::LeaseKeepAliveRequest;
+use common_meta_types::protobuf::LeaseReply;
+use common_meta_types::protobuf::LeaseRevokeRequest;
 use common_meta_types::protobuf::MemberListReply;
 use common_meta_types::protobuf::MemberListRequest;
 use common_meta_types::protobuf::RaftReply;
@@ -95,6 +101,16 @@ impl MetaService for GrpcServiceForTestImpl {
         todo!()
     }
 
+    type KvRangeStream =
+        Pin<Box<dyn Stream<Item = Result<KvRangeItem, tonic::Status>> + Send + Sync + 'static>>;
+
+    async fn kv_range(
+        &self,
+        _request: Request<KvRangeRequest>,
+    ) -> Result<Response<Self::KvRangeStream>, Status> {
+        todo!()
+    }
+
     async fn transaction(
         &self,
         _request: Request<TxnRequest>,
@@ -102,6 +118,27 @@ impl MetaService for GrpcServiceForTestImpl {
         todo!()
     }
 
+    async fn lease_grant(
+        &self,
+        _request: Request<LeaseGrantRequest>,
+    ) -> Result<Response<LeaseReply>, Status> {
+        todo!()
+    }
+
+    async fn lease_keep_alive(
+        &self,
+        _request: Request<LeaseKeepAliveRequest>,
+    ) -> Result<Response<LeaseReply>, Status> {
+        todo!()
+    }
+
+    async fn lease_revoke(
+        &self,
+        _request: Request<LeaseRevokeRequest>,
+    ) -> Result<Response<LeaseReply>, Status> {
+        todo!()
+    }
+
     async fn member_list(
         &self,
         _request: Request<MemberListRequest>,
@@ -19,6 +19,8 @@ use common_meta_types::GetKVReply;
 use common_meta_types::KVAppError;
 use common_meta_types::ListKVReply;
 use common_meta_types::MGetKVReply;
+use common_meta_types::RangeKVReply;
+use common_meta_types::RangeKVReq;
 use common_meta_types::TxnReply;
 use common_meta_types::TxnRequest;
 use common_meta_types::UpsertKVReply;
@@ -48,6 +50,11 @@ impl KVApi for MetaEmbedded {
         sm.prefix_list_kv(prefix).await
     }
 
+    async fn range_kv(&self, req: RangeKVReq) -> Result<RangeKVReply, KVAppError> {
+        let sm = self.inner.lock().await;
+        sm.range_kv(req).await
+    }
+
     async fn transaction(&self, txn: TxnRequest) -> Result<TxnReply, KVAppError> {
         let sm = self.inner.lock().await;
         sm.transaction(txn).await
@@ -52,6 +52,12 @@ async fn test_kv_list() -> anyhow::Result<()> {
     KVApiTestSuite {}.kv_list(&kv).await
 }
 
+#[tokio::test]
+async fn test_kv_range() -> anyhow::Result<()> {
+    let kv = MetaEmbedded::new_temp().await?;
+    KVApiTestSuite {}.kv_range(&kv).await
+}
+
 #[tokio::test]
 async fn test_kv_mget() -> anyhow::Result<()> {
     let kv = MetaEmbedded::new_temp().await?;
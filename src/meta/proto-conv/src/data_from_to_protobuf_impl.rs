@@ -118,6 +118,9 @@ impl FromToProto for dv::DataTypeImpl {
             Dt::Float64Type(_) => Ok(dv::DataTypeImpl::Float64(dv::Float64Type::default())),
             Dt::DateType(_) => Ok(dv::DataTypeImpl::Date(dv::DateType {})),
             Dt::TimestampType(x) => Ok(dv::DataTypeImpl::Timestamp(dv::TimestampType::from_pb(x)?)),
+            Dt::TimestampTzType(x) => Ok(dv::DataTypeImpl::TimestampTz(
+                dv::TimestampTzType::from_pb(x)?,
+            )),
             Dt::StringType(_) => Ok(dv::DataTypeImpl::String(dv::StringType {})),
             Dt::StructType(x) => Ok(dv::DataTypeImpl::Struct(dv::StructType::from_pb(x)?)),
             Dt::ArrayType(x) => Ok(dv::DataTypeImpl::Array(dv::ArrayType::from_pb(
@@ -129,6 +132,10 @@ impl FromToProto for dv::DataTypeImpl {
                 Ok(dv::DataTypeImpl::VariantObject(dv::VariantObjectType {}))
             }
             Dt::IntervalType(x) => Ok(dv::DataTypeImpl::Interval(dv::IntervalType::from_pb(x)?)),
+            Dt::DecimalType(x) => Ok(dv::DataTypeImpl::Decimal(dv::DecimalType::from_pb(x)?)),
+            Dt::MapType(x) => Ok(dv::DataTypeImpl::Map(dv::MapType::from_pb(
+                x.as_ref().clone(),
+            )?)),
         }
     }
 
@@ -253,6 +260,16 @@ impl FromToProto for dv::DataTypeImpl {
                 };
                 Ok(v)
             }
+            dv::DataTypeImpl::TimestampTz(x) => {
+                let inn = x.to_pb()?;
+
+                let v = pb::DataType {
+                    ver: VER,
+                    min_compatible: MIN_COMPATIBLE_VER,
+                    dt: Some(Dt::TimestampTzType(inn)),
+                };
+                Ok(v)
+            }
             dv::DataTypeImpl::String(_x) => {
                 let v = pb::DataType {
                     ver: VER,
@@ -281,6 +298,16 @@ impl FromToProto for dv::DataTypeImpl {
                 };
                 Ok(v)
             }
+            dv::DataTypeImpl::Map(x) => {
+                let inn = x.to_pb()?;
+
+                let v = pb::DataType {
+                    ver: VER,
+                    min_compatible: MIN_COMPATIBLE_VER,
+                    dt: Some(Dt::MapType(Box::new(inn))),
+                };
+                Ok(v)
+            }
             dv::DataTypeImpl::Variant(x) => {
                 let inn = x.to_pb()?;
 
@@ -321,6 +348,16 @@ impl FromToProto for dv::DataTypeImpl {
                 };
                 Ok(p)
             }
+            dv::DataTypeImpl::Decimal(x) => {
+                let inn = x.to_pb()?;
+
+                let p = pb::DataType {
+                    ver: VER,
+                    min_compatible: MIN_COMPATIBLE_VER,
+                    dt: Some(Dt::DecimalType(inn)),
+                };
+                Ok(p)
+            }
         }
     }
 }
@@ -372,6 +409,45 @@ impl FromToProto for dv::TimestampType {
     }
 }
 
+impl FromToProto for dv::TimestampTzType {
+    type PB = pb::TimestampTz;
+    fn from_pb(p: pb::TimestampTz) -> Result<Self, Incompatible>
+    where Self: Sized {
+        check_ver(p.ver, p.min_compatible)?;
+        Ok(dv::TimestampTzType::default())
+    }
+
+    fn to_pb(&self) -> Result<pb::TimestampTz, Incompatible> {
+        let p = pb::TimestampTz {
+            ver: VER,
+            min_compatible: MIN_COMPATIBLE_VER,
+        };
+        Ok(p)
+    }
+}
+
+impl FromToProto for dv::DecimalType {
+    type PB = pb::Decimal;
+    fn from_pb(p: pb::Decimal) -> Result<Self, Incompatible>
+    where Self: Sized {
+        check_ver(p.ver, p.min_compatible)?;
+
+        dv::DecimalType::try_create(p.precision as u64, p.scale as u64).map_err(|e| Incompatible {
+            reason: format!("invalid DecimalType: {}", e.message()),
+        })
+    }
+
+    fn to_pb(&self) -> Result<pb::Decimal, Incompatible> {
+        let p = pb::Decimal {
+            ver: VER,
+            min_compatible: MIN_COMPATIBLE_VER,
+            precision: self.precision() as u32,
+            scale: self.scale() as u32,
+        };
+        Ok(p)
+    }
+}
+
 impl FromToProto for dv::StructType {
     type PB = pb::Struct;
     fn from_pb(p: pb::Struct) -> Result<Self, Incompatible>
@@ -446,6 +522,40 @@ impl FromToProto for dv::ArrayType {
     }
 }
 
+impl FromToProto for dv::MapType {
+    type PB = pb::Map;
+    fn from_pb(p: pb::Map) -> Result<Self, Incompatible>
+    where Self: Sized {
+        check_ver(p.ver, p.min_compatible)?;
+
+        let key = p.key.ok_or_else(|| Incompatible {
+            reason: "Map.key can not be None".to_string(),
+        })?;
+        let value = p.value.ok_or_else(|| Incompatible {
+            reason: "Map.value can not be None".to_string(),
+        })?;
+
+        let key_dt = dv::DataTypeImpl::from_pb(key.as_ref().clone())?;
+        let value_dt = dv::DataTypeImpl::from_pb(value.as_ref().clone())?;
+
+        Ok(dv::MapType::create(key_dt, value_dt))
+    }
+
+    fn to_pb(&self) -> Result<pb::Map, Incompatible> {
+        let key_pb_type = self.key_type().to_pb()?;
+        let value_pb_type = self.value_type().to_pb()?;
+
+        let p = pb::Map {
+            ver: VER,
+            min_compatible: MIN_COMPATIBLE_VER,
+            key: Some(Box::new(key_pb_type)),
+            value: Some(Box::new(value_pb_type)),
+        };
+
+        Ok(p)
+    }
+}
+
 impl FromToProto for dv::VariantArrayType {
     type PB = pb::VariantArray;
     fn from_pb(p: pb::VariantArray) -> Result<Self, Incompatible>
@@ -79,6 +79,33 @@ impl FromToProto for mt::TableCopiedFileLock {
     }
 }
 
+impl FromToProto for mt::TableLockMeta {
+    type PB = pb::TableLockMeta;
+    fn from_pb(p: pb::TableLockMeta) -> Result<Self, Incompatible> {
+        check_ver(p.ver, p.min_compatible)?;
+
+        let v = Self {
+            user: p.user,
+            node: p.node,
+            query_id: p.query_id,
+            created_on: DateTime::<Utc>::from_pb(p.created_on)?,
+        };
+        Ok(v)
+    }
+
+    fn to_pb(&self) -> Result<pb::TableLockMeta, Incompatible> {
+        let p = pb::TableLockMeta {
+            ver: VER,
+            min_compatible: MIN_COMPATIBLE_VER,
+            user: self.user.clone(),
+            node: self.node.clone(),
+            query_id: self.query_id.clone(),
+            created_on: self.created_on.to_pb()?,
+        };
+        Ok(p)
+    }
+}
+
 impl FromToProto for mt::TableNameIdent {
     type PB = pb::TableNameIdent;
     fn from_pb(p: pb::TableNameIdent) -> Result<Self, Incompatible> {
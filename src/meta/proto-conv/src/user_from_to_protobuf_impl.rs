@@ -323,6 +323,9 @@ impl FromToProto for mt::StageFileFormatType {
                 Ok(mt::StageFileFormatType::Parquet)
             }
             pb::user_stage_info::StageFileFormatType::Xml => Ok(mt::StageFileFormatType::Xml),
+            pb::user_stage_info::StageFileFormatType::FixedWidth => {
+                Ok(mt::StageFileFormatType::FixedWidth)
+            }
         }
     }
 
@@ -338,6 +341,9 @@ impl FromToProto for mt::StageFileFormatType {
                 Ok(pb::user_stage_info::StageFileFormatType::Parquet)
             }
             mt::StageFileFormatType::Xml => Ok(pb::user_stage_info::StageFileFormatType::Xml),
+            mt::StageFileFormatType::FixedWidth => {
+                Ok(pb::user_stage_info::StageFileFormatType::FixedWidth)
+            }
         }
     }
 }
@@ -496,6 +502,9 @@ impl FromToProto for mt::FileFormatOptions {
             field_delimiter: p.field_delimiter.clone(),
             record_delimiter: p.record_delimiter,
             compression,
+            column_offsets: p.column_offsets,
+            row_path: p.row_path,
+            column_mapping: p.column_mapping,
         })
     }
 
@@ -510,6 +519,9 @@ impl FromToProto for mt::FileFormatOptions {
             field_delimiter: self.field_delimiter.clone(),
             record_delimiter: self.record_delimiter.clone(),
             compression,
+            column_offsets: self.column_offsets.clone(),
+            row_path: self.row_path.clone(),
+            column_mapping: self.column_mapping.clone(),
         })
     }
 }
@@ -63,6 +63,14 @@ const META_CHANGE_LOG: &[(u64, &str)] = &[
     ),
     (15, "2022-10-12: Remove: precision in TimestampType"),
     (16, "2022-09-29: Add: CopyOptions::split_size"),
+    (
+        17,
+        "2026-10-18: Add: FileFormatOptions::{column_offsets,row_path,column_mapping}, StageFileFormatType::FixedWidth",
+    ),
+    (18, "2026-10-18: Add: datatype.proto/Decimal"),
+    (19, "2026-10-18: Add: datatype.proto/Map"),
+    (20, "2026-10-18: Add: datatype.proto/TimestampTz"),
+    (21, "2026-10-18: Add: table.proto/TableLockMeta"),
 ];
 
 pub const VER: u64 = META_CHANGE_LOG.last().unwrap().0;
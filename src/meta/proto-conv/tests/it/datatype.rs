@@ -15,7 +15,12 @@
 //! Test UserStageInfo
 
 use common_datavalues::DataTypeImpl;
+use common_datavalues::DecimalType;
+use common_datavalues::Int32Type;
+use common_datavalues::MapType;
+use common_datavalues::StringType;
 use common_datavalues::TimestampType;
+use common_datavalues::TimestampTzType;
 
 use crate::common;
 
@@ -34,6 +39,38 @@ fn test_datatype_v15() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_datatype_decimal_v18() -> anyhow::Result<()> {
+    // It is generated with common::test_pb_from_to.
+    let datatype_v18 = vec![
+        178, 1, 10, 8, 10, 16, 2, 160, 6, 18, 168, 6, 1, 160, 6, 18, 168, 6, 1,
+    ];
+    let want = DecimalType::new_impl(10, 2);
+    common::test_load_old(func_name!(), datatype_v18.as_slice(), want)?;
+    Ok(())
+}
+
+#[test]
+fn test_datatype_map_v19() -> anyhow::Result<()> {
+    // It is generated with common::test_pb_from_to.
+    let datatype_v19 = vec![
+        186, 1, 26, 10, 8, 122, 0, 160, 6, 19, 168, 6, 1, 18, 8, 42, 0, 160, 6, 19, 168, 6, 1, 160,
+        6, 19, 168, 6, 1, 160, 6, 19, 168, 6, 1,
+    ];
+    let want = MapType::new_impl(StringType::new_impl(), Int32Type::new_impl());
+    common::test_load_old(func_name!(), datatype_v19.as_slice(), want)?;
+    Ok(())
+}
+
+#[test]
+fn test_datatype_timestamp_tz_v20() -> anyhow::Result<()> {
+    // It is generated with common::test_pb_from_to.
+    let datatype_v20 = vec![194, 1, 6, 160, 6, 20, 168, 6, 1, 160, 6, 20, 168, 6, 1];
+    let want = TimestampTzType::new_impl();
+    common::test_load_old(func_name!(), datatype_v20.as_slice(), want)?;
+    Ok(())
+}
+
 fn test_datatype() -> DataTypeImpl {
     TimestampType::new_impl()
 }
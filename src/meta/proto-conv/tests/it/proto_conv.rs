@@ -354,6 +354,15 @@ fn new_table_copied_file_lock_v7() -> mt::TableCopiedFileLock {
     mt::TableCopiedFileLock {}
 }
 
+fn new_table_lock_meta_v21() -> mt::TableLockMeta {
+    mt::TableLockMeta {
+        user: "root".to_string(),
+        node: "node-1".to_string(),
+        query_id: "query-1".to_string(),
+        created_on: Utc.ymd(2014, 11, 29).and_hms(12, 0, 9),
+    }
+}
+
 #[test]
 fn test_pb_from_to() -> anyhow::Result<()> {
     let db = new_db_meta();
@@ -480,6 +489,16 @@ fn test_build_pb_buf() -> anyhow::Result<()> {
         println!("copied_file_lock:{:?}", buf);
     }
 
+    // TableLockMeta
+    {
+        let lock_meta = new_table_lock_meta_v21();
+        let p = lock_meta.to_pb()?;
+
+        let mut buf = vec![];
+        common_protos::prost::Message::encode(&p, &mut buf)?;
+        println!("table_lock_meta:{:?}", buf);
+    }
+
     Ok(())
 }
 
@@ -806,5 +825,21 @@ fn test_load_old() -> anyhow::Result<()> {
         assert_eq!(want, got);
     }
 
+    // TableLockMeta is loadable
+    {
+        let table_lock_meta_v21: Vec<u8> = vec![
+            10, 4, 114, 111, 111, 116, 18, 6, 110, 111, 100, 101, 45, 49, 26, 7, 113, 117, 101,
+            114, 121, 45, 49, 34, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 57, 32, 49, 50, 58, 48,
+            48, 58, 48, 57, 32, 85, 84, 67, 160, 6, 21, 168, 6, 1,
+        ];
+        let p: pb::TableLockMeta =
+            common_protos::prost::Message::decode(table_lock_meta_v21.as_slice())
+                .map_err(print_err)?;
+
+        let got = mt::TableLockMeta::from_pb(p).map_err(print_err)?;
+        let want = new_table_lock_meta_v21();
+        assert_eq!(want, got);
+    }
+
     Ok(())
 }
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::collections::HashSet;
 
 use common_datavalues::chrono::DateTime;
@@ -77,11 +78,14 @@ pub(crate) fn test_fs_stage_info() -> mt::UserStageInfo {
             }),
         },
         file_format_options: mt::FileFormatOptions {
-            format: mt::StageFileFormatType::Json,
+            format: mt::StageFileFormatType::FixedWidth,
             skip_header: 1024,
             field_delimiter: "|".to_string(),
             record_delimiter: "//".to_string(),
             compression: mt::StageFileCompression::Bz2,
+            column_offsets: vec![0, 4, 10],
+            row_path: "/feed/row".to_string(),
+            column_mapping: BTreeMap::from([("id".to_string(), "@id".to_string())]),
         },
         copy_options: mt::CopyOptions {
             on_error: mt::OnErrorMode::SkipFileNum(666),
@@ -117,6 +121,9 @@ pub(crate) fn test_s3_stage_info() -> mt::UserStageInfo {
             field_delimiter: "|".to_string(),
             record_delimiter: "//".to_string(),
             compression: mt::StageFileCompression::Bz2,
+            column_offsets: vec![],
+            row_path: "".to_string(),
+            column_mapping: BTreeMap::new(),
         },
         copy_options: mt::CopyOptions {
             on_error: mt::OnErrorMode::SkipFileNum(666),
@@ -154,6 +161,9 @@ pub(crate) fn test_s3_stage_info_v16() -> mt::UserStageInfo {
             field_delimiter: "|".to_string(),
             record_delimiter: "//".to_string(),
             compression: mt::StageFileCompression::Bz2,
+            column_offsets: vec![],
+            row_path: "".to_string(),
+            column_mapping: BTreeMap::new(),
         },
         copy_options: mt::CopyOptions {
             on_error: mt::OnErrorMode::SkipFileNum(666),
@@ -191,6 +201,9 @@ pub(crate) fn test_s3_stage_info_v14() -> mt::UserStageInfo {
             field_delimiter: "|".to_string(),
             record_delimiter: "//".to_string(),
             compression: mt::StageFileCompression::Bz2,
+            column_offsets: vec![],
+            row_path: "".to_string(),
+            column_mapping: BTreeMap::new(),
         },
         copy_options: mt::CopyOptions {
             on_error: mt::OnErrorMode::SkipFileNum(666),
@@ -224,6 +237,9 @@ pub(crate) fn test_gcs_stage_info() -> mt::UserStageInfo {
             field_delimiter: "|".to_string(),
             record_delimiter: "//".to_string(),
             compression: mt::StageFileCompression::Bz2,
+            column_offsets: vec![],
+            row_path: "".to_string(),
+            column_mapping: BTreeMap::new(),
         },
         copy_options: mt::CopyOptions {
             on_error: mt::OnErrorMode::SkipFileNum(666),
@@ -258,6 +274,9 @@ pub(crate) fn test_oss_stage_info() -> mt::UserStageInfo {
             field_delimiter: "|".to_string(),
             record_delimiter: "//".to_string(),
             compression: mt::StageFileCompression::Bz2,
+            column_offsets: vec![],
+            row_path: "".to_string(),
+            column_mapping: BTreeMap::new(),
         },
         copy_options: mt::CopyOptions {
             on_error: mt::OnErrorMode::SkipFileNum(666),
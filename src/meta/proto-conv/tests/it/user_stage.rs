@@ -14,6 +14,8 @@
 
 //! Test UserStageInfo
 
+use std::collections::BTreeMap;
+
 use common_meta_types as mt;
 use common_storage::StorageFsConfig;
 use common_storage::StorageGcsConfig;
@@ -51,6 +53,54 @@ fn test_user_stage_oss_latest() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_user_stage_fs_v17() -> anyhow::Result<()> {
+    // Encoded data of version 17 of user_stage_fs:
+    // It is generated with common::test_pb_from_to.
+    let user_stage_fs_v17 = vec![
+        10, 17, 102, 115, 58, 47, 47, 100, 105, 114, 47, 116, 111, 47, 102, 105, 108, 101, 115, 26,
+        25, 10, 23, 18, 21, 10, 13, 47, 100, 105, 114, 47, 116, 111, 47, 102, 105, 108, 101, 115,
+        160, 6, 17, 168, 6, 1, 34, 47, 8, 8, 16, 128, 8, 26, 1, 124, 34, 2, 47, 47, 40, 2, 50, 3,
+        0, 4, 10, 58, 9, 47, 102, 101, 101, 100, 47, 114, 111, 119, 66, 9, 10, 2, 105, 100, 18, 3,
+        64, 105, 100, 160, 6, 17, 168, 6, 1, 42, 10, 10, 3, 32, 154, 5, 16, 142, 8, 24, 1, 50, 4,
+        116, 101, 115, 116, 160, 6, 17, 168, 6, 1,
+    ];
+
+    let want = mt::UserStageInfo {
+        stage_name: "fs://dir/to/files".to_string(),
+        stage_type: mt::StageType::Internal,
+        stage_params: mt::StageParams {
+            storage: StorageParams::Fs(StorageFsConfig {
+                root: "/dir/to/files".to_string(),
+            }),
+        },
+        file_format_options: mt::FileFormatOptions {
+            format: mt::StageFileFormatType::FixedWidth,
+            skip_header: 1024,
+            field_delimiter: "|".to_string(),
+            record_delimiter: "//".to_string(),
+            compression: mt::StageFileCompression::Bz2,
+            column_offsets: vec![0, 4, 10],
+            row_path: "/feed/row".to_string(),
+            column_mapping: BTreeMap::from([("id".to_string(), "@id".to_string())]),
+        },
+        copy_options: mt::CopyOptions {
+            on_error: mt::OnErrorMode::SkipFileNum(666),
+            size_limit: 1038,
+            split_size: 0,
+            purge: true,
+            single: false,
+            max_file_size: 0,
+        },
+        comment: "test".to_string(),
+        ..Default::default()
+    };
+
+    common::test_load_old(func_name!(), user_stage_fs_v17.as_slice(), want)?;
+
+    Ok(())
+}
+
 #[test]
 fn test_user_stage_fs_v16() -> anyhow::Result<()> {
     // Encoded data of version 16 of user_stage_fs:
@@ -77,6 +127,9 @@ fn test_user_stage_fs_v16() -> anyhow::Result<()> {
             field_delimiter: "|".to_string(),
             record_delimiter: "//".to_string(),
             compression: mt::StageFileCompression::Bz2,
+            column_offsets: vec![],
+            row_path: "".to_string(),
+            column_mapping: BTreeMap::new(),
         },
         copy_options: mt::CopyOptions {
             on_error: mt::OnErrorMode::SkipFileNum(666),
@@ -132,6 +185,9 @@ fn test_user_stage_s3_v16() -> anyhow::Result<()> {
             field_delimiter: "|".to_string(),
             record_delimiter: "//".to_string(),
             compression: mt::StageFileCompression::Bz2,
+            column_offsets: vec![],
+            row_path: "".to_string(),
+            column_mapping: BTreeMap::new(),
         },
         copy_options: mt::CopyOptions {
             on_error: mt::OnErrorMode::SkipFileNum(666),
@@ -181,6 +237,9 @@ fn test_user_stage_gcs_v16() -> anyhow::Result<()> {
             field_delimiter: "|".to_string(),
             record_delimiter: "//".to_string(),
             compression: mt::StageFileCompression::Bz2,
+            column_offsets: vec![],
+            row_path: "".to_string(),
+            column_mapping: BTreeMap::new(),
         },
         copy_options: mt::CopyOptions {
             on_error: mt::OnErrorMode::SkipFileNum(666),
@@ -232,6 +291,9 @@ fn test_user_stage_oss_v16() -> anyhow::Result<()> {
             field_delimiter: "|".to_string(),
             record_delimiter: "//".to_string(),
             compression: mt::StageFileCompression::Bz2,
+            column_offsets: vec![],
+            row_path: "".to_string(),
+            column_mapping: BTreeMap::new(),
         },
         copy_options: mt::CopyOptions {
             on_error: mt::OnErrorMode::SkipFileNum(666),
@@ -285,6 +347,9 @@ fn test_user_stage_oss_v13() -> anyhow::Result<()> {
             field_delimiter: "|".to_string(),
             record_delimiter: "//".to_string(),
             compression: mt::StageFileCompression::Bz2,
+            column_offsets: vec![],
+            row_path: "".to_string(),
+            column_mapping: BTreeMap::new(),
         },
         copy_options: mt::CopyOptions {
             on_error: mt::OnErrorMode::SkipFileNum(666),
@@ -339,6 +404,9 @@ fn test_user_stage_s3_v11() -> anyhow::Result<()> {
             field_delimiter: "|".to_string(),
             record_delimiter: "//".to_string(),
             compression: mt::StageFileCompression::Bz2,
+            column_offsets: vec![],
+            row_path: "".to_string(),
+            column_mapping: BTreeMap::new(),
         },
         copy_options: mt::CopyOptions {
             on_error: mt::OnErrorMode::SkipFileNum(666),
@@ -391,6 +459,9 @@ fn test_user_stage_s3_v9() -> anyhow::Result<()> {
             field_delimiter: "|".to_string(),
             record_delimiter: "//".to_string(),
             compression: mt::StageFileCompression::Bz2,
+            column_offsets: vec![],
+            row_path: "".to_string(),
+            column_mapping: BTreeMap::new(),
         },
         copy_options: mt::CopyOptions {
             on_error: mt::OnErrorMode::SkipFileNum(666),
@@ -434,6 +505,9 @@ fn test_user_stage_fs_v6() -> anyhow::Result<()> {
             field_delimiter: "|".to_string(),
             record_delimiter: "//".to_string(),
             compression: mt::StageFileCompression::Bz2,
+            column_offsets: vec![],
+            row_path: "".to_string(),
+            column_mapping: BTreeMap::new(),
         },
         copy_options: mt::CopyOptions {
             on_error: mt::OnErrorMode::SkipFileNum(666),
@@ -487,6 +561,9 @@ fn test_user_stage_s3_v6() -> anyhow::Result<()> {
             field_delimiter: "|".to_string(),
             record_delimiter: "//".to_string(),
             compression: mt::StageFileCompression::Bz2,
+            column_offsets: vec![],
+            row_path: "".to_string(),
+            column_mapping: BTreeMap::new(),
         },
         copy_options: mt::CopyOptions {
             on_error: mt::OnErrorMode::SkipFileNum(666),
@@ -536,6 +613,9 @@ fn test_user_stage_gcs_v6() -> anyhow::Result<()> {
             field_delimiter: "|".to_string(),
             record_delimiter: "//".to_string(),
             compression: mt::StageFileCompression::Bz2,
+            column_offsets: vec![],
+            row_path: "".to_string(),
+            column_mapping: BTreeMap::new(),
         },
         copy_options: mt::CopyOptions {
             on_error: mt::OnErrorMode::SkipFileNum(666),
@@ -578,6 +658,9 @@ fn test_user_stage_fs_v4() -> anyhow::Result<()> {
             field_delimiter: "|".to_string(),
             record_delimiter: "//".to_string(),
             compression: mt::StageFileCompression::Bz2,
+            column_offsets: vec![],
+            row_path: "".to_string(),
+            column_mapping: BTreeMap::new(),
         },
         copy_options: mt::CopyOptions {
             on_error: mt::OnErrorMode::SkipFileNum(666),
@@ -631,6 +714,9 @@ fn test_user_stage_s3_v4() -> anyhow::Result<()> {
             field_delimiter: "|".to_string(),
             record_delimiter: "//".to_string(),
             compression: mt::StageFileCompression::Bz2,
+            column_offsets: vec![],
+            row_path: "".to_string(),
+            column_mapping: BTreeMap::new(),
         },
         copy_options: mt::CopyOptions {
             on_error: mt::OnErrorMode::SkipFileNum(666),
@@ -679,6 +765,9 @@ fn test_user_stage_gcs_v4() -> anyhow::Result<()> {
             field_delimiter: "|".to_string(),
             record_delimiter: "//".to_string(),
             compression: mt::StageFileCompression::Bz2,
+            column_offsets: vec![],
+            row_path: "".to_string(),
+            column_mapping: BTreeMap::new(),
         },
         copy_options: mt::CopyOptions {
             on_error: mt::OnErrorMode::SkipFileNum(666),
@@ -729,6 +818,9 @@ fn test_user_stage_s3_v1() -> anyhow::Result<()> {
             field_delimiter: "|".to_string(),
             record_delimiter: "//".to_string(),
             compression: mt::StageFileCompression::Bz2,
+            column_offsets: vec![],
+            row_path: "".to_string(),
+            column_mapping: BTreeMap::new(),
         },
         copy_options: mt::CopyOptions {
             on_error: mt::OnErrorMode::SkipFileNum(666),
@@ -79,6 +79,12 @@ pub struct RaftConfig {
     /// The maximum number of applied logs to keep before purging
     pub max_applied_log_to_keep: u64,
 
+    /// The number of snapshot files to keep on disk, older ones are removed.
+    pub max_snapshot_files_to_keep: u64,
+
+    /// The number of kv changes to keep in the state machine for a watcher to resume from.
+    pub max_change_log_to_keep: u64,
+
     /// Single node metasrv. It creates a single node cluster if meta data is not initialized.
     /// Otherwise it opens the previous one.
     /// This is mainly for testing purpose.
@@ -89,6 +95,11 @@ pub struct RaftConfig {
     /// The value is one or more addresses of a node in the cluster, to which this node sends a `join` request.
     pub join: Vec<String>,
 
+    /// Join the cluster specified by `join` as a learner.
+    ///
+    /// A learner replicates logs and serves reads, but does not vote or count in a quorum.
+    pub learner: bool,
+
     /// Do not run databend-meta, but just remove a node from its cluster.
     ///
     /// The value is one or more addresses of a node in the cluster, to which this node sends a `leave` request.
@@ -135,8 +146,11 @@ impl Default for RaftConfig {
             heartbeat_interval: 1000,
             install_snapshot_timeout: 4000,
             max_applied_log_to_keep: 1000,
+            max_snapshot_files_to_keep: 3,
+            max_change_log_to_keep: 10000,
             single: false,
             join: vec![],
+            learner: false,
             leave_via: vec![],
             leave_id: None,
             id: 0,
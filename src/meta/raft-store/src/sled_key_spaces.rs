@@ -20,13 +20,18 @@ use common_meta_types::Node;
 use common_meta_types::NodeId;
 use common_meta_types::SeqNum;
 use common_meta_types::SeqV;
+use common_meta_types::TenantUsage;
 use openraft::raft::Entry;
 use serde::Deserialize;
 use serde::Serialize;
 
 use crate::state::RaftStateKey;
 use crate::state::RaftStateValue;
+use crate::state_machine::ChangeLogValue;
 use crate::state_machine::ClientLastRespValue;
+use crate::state_machine::ExpireKey;
+use crate::state_machine::LeaseKey;
+use crate::state_machine::LeaseValue;
 use crate::state_machine::LogMetaKey;
 use crate::state_machine::LogMetaValue;
 use crate::state_machine::StateMachineMetaKey;
@@ -109,6 +114,64 @@ impl SledKeySpace for ClientLastResps {
     type V = ClientLastRespValue;
 }
 
+/// Key-Value Types for the change log of generic kv in sled::Tree, indexed by revision.
+pub struct ChangeLogs {}
+impl SledKeySpace for ChangeLogs {
+    const PREFIX: u8 = 14;
+    const NAME: &'static str = "change-log";
+    type K = u64;
+    type V = ChangeLogValue;
+}
+
+/// Key-Value Types for leases in sled::Tree, indexed by lease id.
+pub struct Leases {}
+impl SledKeySpace for Leases {
+    const PREFIX: u8 = 15;
+    const NAME: &'static str = "lease";
+    type K = u64;
+    type V = LeaseValue;
+}
+
+/// Key-Value Types for the resource usage of tenants in sled::Tree, indexed by tenant.
+pub struct TenantUsages {}
+impl SledKeySpace for TenantUsages {
+    const PREFIX: u8 = 16;
+    const NAME: &'static str = "tenant-usage";
+    type K = String;
+    type V = TenantUsage;
+}
+
+/// The index of the expire time of the tenant owned generic kv records, the value is the seq of the record.
+///
+/// An expired record is deleted by the state machine, to release the usage of its tenant.
+pub struct Expires {}
+impl SledKeySpace for Expires {
+    const PREFIX: u8 = 17;
+    const NAME: &'static str = "expire";
+    type K = ExpireKey;
+    type V = u64;
+}
+
+/// The index of the expire time of leases, the value is the lease id.
+///
+/// The expired leases are found by scanning the index up to the time of a log.
+pub struct LeaseExpires {}
+impl SledKeySpace for LeaseExpires {
+    const PREFIX: u8 = 18;
+    const NAME: &'static str = "lease-expire";
+    type K = ExpireKey;
+    type V = u64;
+}
+
+/// The keys attached to leases, indexed by lease id and then key.
+pub struct LeaseKeys {}
+impl SledKeySpace for LeaseKeys {
+    const PREFIX: u8 = 19;
+    const NAME: &'static str = "lease-key";
+    type K = LeaseKey;
+    type V = ();
+}
+
 /// Enum of key-value pair types of all key spaces.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum KeySpaceKV {
@@ -144,4 +207,28 @@ pub enum KeySpaceKV {
         key: <LogMeta as SledKeySpace>::K,
         value: <LogMeta as SledKeySpace>::V,
     },
+    ChangeLogs {
+        key: <ChangeLogs as SledKeySpace>::K,
+        value: <ChangeLogs as SledKeySpace>::V,
+    },
+    Leases {
+        key: <Leases as SledKeySpace>::K,
+        value: <Leases as SledKeySpace>::V,
+    },
+    TenantUsages {
+        key: <TenantUsages as SledKeySpace>::K,
+        value: <TenantUsages as SledKeySpace>::V,
+    },
+    Expires {
+        key: <Expires as SledKeySpace>::K,
+        value: <Expires as SledKeySpace>::V,
+    },
+    LeaseExpires {
+        key: <LeaseExpires as SledKeySpace>::K,
+        value: <LeaseExpires as SledKeySpace>::V,
+    },
+    LeaseKeys {
+        key: <LeaseKeys as SledKeySpace>::K,
+        value: <LeaseKeys as SledKeySpace>::V,
+    },
 }
@@ -0,0 +1,51 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_meta_types::SeqV;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// A change to a generic kv, stored in the change log of a state machine by its revision.
+///
+/// Revisions are assigned to every change in apply order, starting from 1, so that a watcher
+/// is able to resume from the last revision it has seen.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ChangeLogValue {
+    pub key: String,
+    pub prev: Option<SeqV>,
+    pub current: Option<SeqV>,
+}
+
+/// The requested revision is no longer in the bounded change log.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CompactedRevision {
+    pub requested: u64,
+
+    /// The oldest revision that is still available.
+    pub oldest: u64,
+}
+
+impl fmt::Display for CompactedRevision {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "revision {} is compacted, the oldest available revision is {}",
+            self.requested, self.oldest
+        )
+    }
+}
+
+impl std::error::Error for CompactedRevision {}
@@ -0,0 +1,75 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_meta_sled_store::sled;
+use common_meta_sled_store::SledBytesError;
+use common_meta_sled_store::SledOrderedSerde;
+use common_meta_types::anyerror::AnyError;
+use serde::Deserialize;
+use serde::Serialize;
+use sled::IVec;
+
+/// The key of an entry in an index of things that expire, ordered by the expire time.
+///
+/// The expired entries are found by scanning the index up to the current time.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ExpireKey {
+    /// The expire time in milliseconds since 1970-01-01.
+    pub time_ms: u64,
+
+    /// The key of the thing that expires.
+    pub key: String,
+}
+
+impl ExpireKey {
+    pub fn new(time_ms: u64, key: impl Into<String>) -> Self {
+        Self {
+            time_ms,
+            key: key.into(),
+        }
+    }
+}
+
+impl fmt::Display for ExpireKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.time_ms, self.key)
+    }
+}
+
+impl SledOrderedSerde for ExpireKey {
+    fn ser(&self) -> Result<IVec, SledBytesError> {
+        let mut buf = Vec::with_capacity(8 + self.key.len());
+        buf.extend_from_slice(&self.time_ms.to_be_bytes());
+        buf.extend_from_slice(self.key.as_bytes());
+        Ok(buf.into())
+    }
+
+    fn de<V: AsRef<[u8]>>(v: V) -> Result<Self, SledBytesError>
+    where Self: Sized {
+        let slice = v.as_ref();
+        if slice.len() < 8 {
+            return Err(SledBytesError::new(&AnyError::error(
+                "invalid expire key IVec",
+            )));
+        }
+
+        let (time, key) = slice.split_at(8);
+        let time_ms = u64::from_be_bytes(time.try_into().unwrap());
+        let key = String::from_utf8(key.to_vec())?;
+
+        Ok(Self { time_ms, key })
+    }
+}
@@ -0,0 +1,120 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_meta_sled_store::sled;
+use common_meta_sled_store::SledBytesError;
+use common_meta_sled_store::SledOrderedSerde;
+use common_meta_types::anyerror::AnyError;
+use common_meta_types::Lease;
+use serde::Deserialize;
+use serde::Serialize;
+use sled::IVec;
+
+use crate::state_machine::ExpireKey;
+
+/// A lease stored in the state machine.
+///
+/// The keys attached to a lease are stored as `LeaseKey`s, and the lease is indexed by its
+/// expire time with `expire_key()`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LeaseValue {
+    pub ttl_ms: u64,
+
+    /// When this lease expires, in milliseconds since 1970, by the time of raft log.
+    pub expire_at_ms: u64,
+}
+
+impl LeaseValue {
+    pub fn new(ttl_ms: u64, log_time_ms: u64) -> Self {
+        Self {
+            ttl_ms,
+            expire_at_ms: log_time_ms.saturating_add(ttl_ms),
+        }
+    }
+
+    pub fn is_expired(&self, now_ms: u64) -> bool {
+        self.expire_at_ms < now_ms
+    }
+
+    /// The key of this lease in the index of lease expire time.
+    pub fn expire_key(&self, lease_id: u64) -> ExpireKey {
+        ExpireKey::new(self.expire_at_ms, lease_id.to_string())
+    }
+
+    pub fn to_lease(&self, lease_id: u64) -> Lease {
+        Lease {
+            lease_id,
+            ttl_ms: self.ttl_ms,
+            expire_at_ms: self.expire_at_ms,
+        }
+    }
+}
+
+/// A key attached to a lease.
+///
+/// A `LeaseKey` is not removed when the key is updated without the lease: when the lease is
+/// revoked, only the keys that are still attached to it are deleted.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+pub struct LeaseKey {
+    pub lease_id: u64,
+
+    pub key: String,
+}
+
+impl LeaseKey {
+    pub fn new(lease_id: u64, key: impl Into<String>) -> Self {
+        Self {
+            lease_id,
+            key: key.into(),
+        }
+    }
+
+    /// The range of the keys attached to a lease.
+    pub fn range(lease_id: u64) -> std::ops::Range<LeaseKey> {
+        LeaseKey::new(lease_id, "")..LeaseKey::new(lease_id + 1, "")
+    }
+}
+
+impl fmt::Display for LeaseKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.lease_id, self.key)
+    }
+}
+
+impl SledOrderedSerde for LeaseKey {
+    fn ser(&self) -> Result<IVec, SledBytesError> {
+        let mut buf = Vec::with_capacity(8 + self.key.len());
+        buf.extend_from_slice(&self.lease_id.to_be_bytes());
+        buf.extend_from_slice(self.key.as_bytes());
+        Ok(buf.into())
+    }
+
+    fn de<V: AsRef<[u8]>>(v: V) -> Result<Self, SledBytesError>
+    where Self: Sized {
+        let slice = v.as_ref();
+        if slice.len() < 8 {
+            return Err(SledBytesError::new(&AnyError::error(
+                "invalid lease key IVec",
+            )));
+        }
+
+        let (lease_id, key) = slice.split_at(8);
+        let lease_id = u64::from_be_bytes(lease_id.try_into().unwrap());
+        let key = String::from_utf8(key.to_vec())?;
+
+        Ok(Self { lease_id, key })
+    }
+}
@@ -12,24 +12,41 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub use change_log::ChangeLogValue;
+pub use change_log::CompactedRevision;
 pub use client_last_resp::ClientLastRespValue;
+pub use expire::ExpireKey;
+pub use lease::LeaseKey;
+pub use lease::LeaseValue;
 pub use log_meta::LogMetaKey;
 pub use log_meta::LogMetaValue;
 pub use sm::SerializableSnapshot;
 pub use sm::SnapshotKeyValue;
 pub use sm::StateMachine;
 pub use sm::StateMachineSubscriber;
+pub use sm::TxnScanned;
 pub use snapshot::Snapshot;
+pub use snapshot_file::verify_snapshot_file;
+pub use snapshot_file::SnapshotFileReader;
+pub use snapshot_file::SnapshotFileSummary;
+pub use snapshot_file::SnapshotFileWriter;
+pub use snapshot_file::SNAPSHOT_FILE_MAGIC;
 pub use snapshot_id::MetaSnapshotId;
+pub use snapshot_store::SnapshotStore;
 pub use state_machine_meta::StateMachineMetaKey;
 pub use state_machine_meta::StateMachineMetaValue;
 
+pub mod change_log;
 pub mod client_last_resp;
+pub mod expire;
+pub mod lease;
 pub mod log_meta;
 pub mod sm;
 mod sm_kv_api_impl;
 pub mod snapshot;
+mod snapshot_file;
 mod snapshot_id;
+mod snapshot_store;
 pub mod state_machine_meta;
 
 // will be accessed by other crate, can not cfg(test)
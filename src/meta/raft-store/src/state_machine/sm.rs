@@ -15,11 +15,13 @@
 use std::collections::BTreeMap;
 use std::convert::TryInto;
 use std::fmt::Debug;
+use std::io::Write;
 use std::time::Duration;
 use std::time::Instant;
 use std::time::SystemTime;
 use std::time::UNIX_EPOCH;
 
+use common_base::base::escape_for_key;
 use common_exception::WithContext;
 use common_meta_sled_store::get_sled_db;
 use common_meta_sled_store::openraft;
@@ -42,27 +44,40 @@ use common_meta_types::ConditionResult;
 use common_meta_types::KVMeta;
 use common_meta_types::LogEntry;
 use common_meta_types::LogId;
+use common_meta_types::MatchSeq;
 use common_meta_types::MatchSeqExt;
 use common_meta_types::Node;
 use common_meta_types::NodeId;
 use common_meta_types::Operation;
 use common_meta_types::PbSeqV;
 use common_meta_types::SeqV;
+use common_meta_types::TenantQuota;
+use common_meta_types::TenantQuotaExceeded;
+use common_meta_types::TenantQuotaWriteDenied;
+use common_meta_types::TenantUsage;
 use common_meta_types::TxnCondition;
 use common_meta_types::TxnDeleteByPrefixRequest;
 use common_meta_types::TxnDeleteByPrefixResponse;
 use common_meta_types::TxnDeleteRequest;
 use common_meta_types::TxnDeleteResponse;
+use common_meta_types::TxnFetchAddU64Failed;
+use common_meta_types::TxnFetchAddU64Request;
+use common_meta_types::TxnFetchAddU64Response;
+use common_meta_types::TxnGetRangeRequest;
+use common_meta_types::TxnGetRangeResponse;
 use common_meta_types::TxnGetRequest;
 use common_meta_types::TxnGetResponse;
 use common_meta_types::TxnOp;
 use common_meta_types::TxnOpResponse;
+use common_meta_types::TxnPutIfAbsentRequest;
+use common_meta_types::TxnPutIfAbsentResponse;
 use common_meta_types::TxnPutRequest;
 use common_meta_types::TxnPutResponse;
 use common_meta_types::TxnReply;
 use common_meta_types::TxnRequest;
 use common_meta_types::UpsertKV;
 use common_meta_types::With;
+use common_meta_types::TENANT_QUOTA_KEY_PREFIX;
 use num::FromPrimitive;
 use openraft::raft::Entry;
 use openraft::raft::EntryPayload;
@@ -71,15 +86,29 @@ use serde::Serialize;
 use tracing::debug;
 use tracing::error;
 use tracing::info;
+use tracing::warn;
 
 use crate::config::RaftConfig;
+use crate::sled_key_spaces::ChangeLogs;
 use crate::sled_key_spaces::ClientLastResps;
+use crate::sled_key_spaces::Expires;
 use crate::sled_key_spaces::GenericKV;
+use crate::sled_key_spaces::LeaseExpires;
+use crate::sled_key_spaces::LeaseKeys;
+use crate::sled_key_spaces::Leases;
 use crate::sled_key_spaces::Nodes;
 use crate::sled_key_spaces::Sequences;
 use crate::sled_key_spaces::StateMachineMeta;
+use crate::sled_key_spaces::TenantUsages;
+use crate::state_machine::ChangeLogValue;
 use crate::state_machine::ClientLastRespValue;
+use crate::state_machine::CompactedRevision;
+use crate::state_machine::ExpireKey;
+use crate::state_machine::LeaseKey;
+use crate::state_machine::LeaseValue;
 use crate::state_machine::MetaSnapshotId;
+use crate::state_machine::SnapshotFileSummary;
+use crate::state_machine::SnapshotFileWriter;
 use crate::state_machine::StateMachineMetaKey;
 use crate::state_machine::StateMachineMetaKey::Initialized;
 use crate::state_machine::StateMachineMetaKey::LastApplied;
@@ -91,9 +120,12 @@ use crate::state_machine::StateMachineMetaValue;
 // const TREE_META: &str = "meta";
 const TREE_STATE_MACHINE: &str = "state_machine";
 
+/// The max number of expired records to delete when applying a log, to bound the time to apply it.
+const MAX_EXPIRED_RECORDS_PER_LOG: usize = 256;
+
 /// StateMachine subscriber trait
 pub trait StateMachineSubscriber: Debug + Sync + Send {
-    fn kv_changed(&self, key: &str, prev: Option<SeqV>, current: Option<SeqV>);
+    fn kv_changed(&self, revision: u64, key: &str, prev: Option<SeqV>, current: Option<SeqV>);
 }
 
 type NotifyKVEvent = (String, Option<SeqV>, Option<SeqV>);
@@ -110,12 +142,40 @@ pub struct StateMachine {
 
     /// subscriber of statemachine data
     pub subscriber: Option<Box<dyn StateMachineSubscriber>>,
+
+    /// The number of kv changes to keep in the change log.
+    max_change_log_to_keep: u64,
 }
 
 /// A key-value pair in a snapshot is a vec of two `Vec<u8>`.
 pub type SnapshotKeyValue = Vec<Vec<u8>>;
 type DeleteByPrefixKeyMap = BTreeMap<TxnDeleteByPrefixRequest, Vec<(String, SeqV)>>;
 
+/// Living key-values in range `[start, end)`, indexed by `(start, end)`.
+/// An empty `end` means all keys that start with `start`.
+type RangeKeyMap = BTreeMap<(String, String), Vec<(String, SeqV)>>;
+
+/// Keys that have been attached to a lease, indexed by lease id.
+type LeaseKeyMap = BTreeMap<u64, Vec<String>>;
+
+/// Key-values that a txn deletes by prefix or reads by range, or keys a lease revoke deletes.
+///
+/// They have to be scanned before the sled transaction, which does not support iteration.
+#[derive(Debug, Default)]
+pub struct TxnScanned {
+    /// Key-values to delete by `delete_by_prefix` ops in `if_then`.
+    if_then: DeleteByPrefixKeyMap,
+
+    /// Key-values to delete by `delete_by_prefix` ops in `else_then`.
+    else_then: DeleteByPrefixKeyMap,
+
+    /// Key-values to count by conditions and to return by `get_range` ops.
+    ranges: RangeKeyMap,
+
+    /// Keys attached to the lease to revoke by a `RevokeLease`.
+    lease_keys: LeaseKeyMap,
+}
+
 /// Snapshot data for serialization and for transport.
 #[derive(Serialize, Deserialize, Debug, Default, Clone)]
 pub struct SerializableSnapshot {
@@ -164,6 +224,7 @@ impl StateMachine {
         let sm = StateMachine {
             sm_tree,
             subscriber: None,
+            max_change_log_to_keep: config.max_change_log_to_keep,
         };
 
         let inited = {
@@ -195,14 +256,7 @@ impl StateMachine {
     pub fn build_snapshot(
         &self,
     ) -> Result<(SerializableSnapshot, Option<LogId>, MetaSnapshotId), MetaStorageError> {
-        let last_applied = self.get_last_applied()?;
-
-        let snapshot_idx = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_secs();
-
-        let snapshot_id = MetaSnapshotId::new(last_applied, snapshot_idx);
+        let (last_applied, snapshot_id) = self.new_snapshot_id()?;
 
         let view = self.sm_tree.tree.iter();
 
@@ -216,40 +270,262 @@ impl StateMachine {
         Ok((snap, last_applied, snapshot_id))
     }
 
-    fn scan_prefix_if_needed(
+    /// Stream all key values in state machine into a snapshot file in `w`, without loading
+    /// them into memory.
+    ///
+    /// The caller must prevent changes from being applied until it returns, e.g., by holding
+    /// a read lock on the state machine, because the sled iterator is not a consistent view.
+    ///
+    /// Returns the summary of the written file, the last applied log id and the snapshot id.
+    pub fn build_snapshot_file<W: Write>(
+        &self,
+        w: W,
+    ) -> Result<(W, SnapshotFileSummary, Option<LogId>, MetaSnapshotId), MetaStorageError> {
+        let (last_applied, snapshot_id) = self.new_snapshot_id()?;
+
+        let mut writer = SnapshotFileWriter::new(w)?;
+        writer.append_all(self.sm_tree.tree.iter().map(|rkv| {
+            rkv.context(|| "taking snapshot")
+                .map_err(MetaStorageError::from)
+        }))?;
+        let (w, summary) = writer.finish()?;
+
+        Ok((w, summary, last_applied, snapshot_id))
+    }
+
+    fn new_snapshot_id(&self) -> Result<(Option<LogId>, MetaSnapshotId), MetaStorageError> {
+        let last_applied = self.get_last_applied()?;
+
+        let snapshot_idx = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+
+        Ok((
+            last_applied,
+            MetaSnapshotId::new(last_applied, snapshot_idx),
+        ))
+    }
+
+    fn scan_txn_if_needed(
         &self,
         entry: &Entry<LogEntry>,
-    ) -> Result<Option<(DeleteByPrefixKeyMap, DeleteByPrefixKeyMap)>, MetaStorageError> {
+    ) -> Result<Option<TxnScanned>, MetaStorageError> {
         match entry.payload {
-            EntryPayload::Normal(ref data) => match &data.cmd {
-                Cmd::Transaction(txn) => {
-                    let kvs = self.kvs();
-                    let mut if_map = BTreeMap::new();
-                    let mut else_map = BTreeMap::new();
-                    for op in txn.if_then.iter() {
-                        if let Some(txn_op::Request::DeleteByPrefix(delete_by_prefix)) = &op.request
-                        {
-                            if_map.insert(
-                                delete_by_prefix.clone(),
-                                kvs.scan_prefix(&delete_by_prefix.prefix)?,
-                            );
-                        }
+            EntryPayload::Normal(ref data) => {
+                let mut cmd = &data.cmd;
+                while let Cmd::AsTenant { cmd: inner, .. } = cmd {
+                    cmd = inner;
+                }
+                match cmd {
+                    Cmd::Transaction(txn) => {
+                        let log_time_ms = data.time_ms.unwrap_or_default();
+                        Ok(Some(self.scan_txn(txn, log_time_ms)?))
                     }
-                    for op in txn.else_then.iter() {
-                        if let Some(txn_op::Request::DeleteByPrefix(delete_by_prefix)) = &op.request
-                        {
-                            else_map.insert(
-                                delete_by_prefix.clone(),
-                                kvs.scan_prefix(&delete_by_prefix.prefix)?,
-                            );
-                        }
+                    Cmd::RevokeLease { lease_id } => Ok(Some(TxnScanned {
+                        lease_keys: self.scan_lease_keys(&[*lease_id])?,
+                        ..Default::default()
+                    })),
+                    _ => Ok(None),
+                }
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Scan the key-values that a txn deletes by prefix or reads by range.
+    pub fn scan_txn(
+        &self,
+        txn: &TxnRequest,
+        log_time_ms: u64,
+    ) -> Result<TxnScanned, MetaStorageError> {
+        let kvs = self.kvs();
+        let mut scanned = TxnScanned::default();
+
+        for cond in txn.condition.iter() {
+            let range_end = match &cond.target {
+                Some(txn_condition::Target::KeysWithPrefix(_)) => "",
+                Some(txn_condition::Target::KeysInRange(r)) => r.range_end.as_str(),
+                _ => continue,
+            };
+            self.scan_range(&mut scanned.ranges, &cond.key, range_end, log_time_ms)?;
+        }
+
+        for (ops, del_map) in [
+            (&txn.if_then, &mut scanned.if_then),
+            (&txn.else_then, &mut scanned.else_then),
+        ] {
+            for op in ops.iter() {
+                match &op.request {
+                    Some(txn_op::Request::DeleteByPrefix(delete_by_prefix)) => {
+                        del_map.insert(
+                            delete_by_prefix.clone(),
+                            kvs.scan_prefix(&delete_by_prefix.prefix)?,
+                        );
+                    }
+                    Some(txn_op::Request::GetRange(get_range)) => {
+                        self.scan_range(
+                            &mut scanned.ranges,
+                            &get_range.key,
+                            &get_range.range_end,
+                            log_time_ms,
+                        )?;
                     }
-                    Ok(Some((if_map, else_map)))
+                    _ => {}
                 }
-                _ => Ok(None),
+            }
+        }
+
+        Ok(scanned)
+    }
+
+    /// Load living key-values in range `[start, end)`, or with prefix `start` if `end` is empty.
+    fn scan_range(
+        &self,
+        ranges: &mut RangeKeyMap,
+        start: &str,
+        end: &str,
+        log_time_ms: u64,
+    ) -> Result<(), MetaStorageError> {
+        let range_key = (start.to_string(), end.to_string());
+        if ranges.contains_key(&range_key) {
+            return Ok(());
+        }
+
+        let kvs = self.kvs();
+        let items = if end.is_empty() {
+            kvs.scan_prefix(&range_key.0)?
+        } else if start < end {
+            let mut items = vec![];
+            for item in kvs.range(range_key.0.clone()..range_key.1.clone())? {
+                items.push(item?.kv()?);
+            }
+            items
+        } else {
+            vec![]
+        };
+
+        let mut living = vec![];
+        for (k, v) in items {
+            let v = Self::unexpired_opt(Some(v), log_time_ms);
+            if let Some(v) = self.lease_alive_opt(v, log_time_ms)? {
+                living.push((k, v));
+            }
+        }
+
+        ranges.insert(range_key, living);
+        Ok(())
+    }
+
+    /// Find the leases that are expired by the time of a log entry, with the keys attached to them.
+    ///
+    /// Like `scan_txn_if_needed()`, it has to be done out of the sled transaction, which does
+    /// not support iteration. The leases are checked again in the transaction.
+    fn scan_expired_leases_if_needed(
+        &self,
+        entry: &Entry<LogEntry>,
+    ) -> Result<LeaseKeyMap, MetaStorageError> {
+        let log_time_ms = match entry.payload {
+            EntryPayload::Normal(ref data) => match data.time_ms {
+                Some(x) => x,
+                None => return Ok(LeaseKeyMap::new()),
             },
-            _ => Ok(None),
+            _ => return Ok(LeaseKeyMap::new()),
+        };
+
+        let mut expired = vec![];
+        let end = ExpireKey::new(log_time_ms, "");
+        for item in self.lease_expires().range(..end)? {
+            let (_, lease_id) = item?.kv()?;
+            expired.push(lease_id);
+        }
+        self.scan_lease_keys(&expired)
+    }
+
+    /// Find the keys that have been attached to the leases.
+    fn scan_lease_keys(&self, lease_ids: &[u64]) -> Result<LeaseKeyMap, MetaStorageError> {
+        let mut lease_keys = LeaseKeyMap::new();
+        for lease_id in lease_ids {
+            let mut keys = vec![];
+            for item in self.lease_keys().range(LeaseKey::range(*lease_id))? {
+                let (lease_key, _) = item?.kv()?;
+                keys.push(lease_key.key);
+            }
+            lease_keys.insert(*lease_id, keys);
+        }
+        Ok(lease_keys)
+    }
+
+    /// Find the tenant owned records that are expired by the time of a log entry,
+    /// at most `MAX_EXPIRED_RECORDS_PER_LOG` of them, the others are left to the following logs.
+    ///
+    /// Like `scan_expired_leases_if_needed()`, the records are checked again in the transaction.
+    fn scan_expired_records_if_needed(
+        &self,
+        entry: &Entry<LogEntry>,
+    ) -> Result<Vec<(ExpireKey, u64)>, MetaStorageError> {
+        let log_time_ms = match entry.payload {
+            EntryPayload::Normal(ref data) => match data.time_ms {
+                Some(x) => x,
+                None => return Ok(vec![]),
+            },
+            _ => return Ok(vec![]),
+        };
+
+        let mut expired = vec![];
+        let end = ExpireKey::new(log_time_ms, "");
+        for item in self
+            .expires()
+            .range(..end)?
+            .take(MAX_EXPIRED_RECORDS_PER_LOG)
+        {
+            expired.push(item?.kv()?);
+        }
+        Ok(expired)
+    }
+
+    /// Find the records to backfill into the usage of a tenant, if the log entry is the first
+    /// write on behalf of the tenant.
+    ///
+    /// Records written before writes are attributed to tenants are not owned by any tenant.
+    /// Such a record is taken as a record of the tenant if the tenant is in its key,
+    /// see `is_key_of_tenant()`.
+    /// It scans every record, but only once for a tenant.
+    fn scan_tenant_backfill_if_needed(
+        &self,
+        entry: &Entry<LogEntry>,
+    ) -> Result<Option<(String, Vec<(String, SeqV)>)>, MetaStorageError> {
+        let (tenant, log_time_ms) = match entry.payload {
+            EntryPayload::Normal(ref data) => match &data.cmd {
+                Cmd::AsTenant { tenant, .. } => (tenant, data.time_ms.unwrap_or_default()),
+                _ => return Ok(None),
+            },
+            _ => return Ok(None),
+        };
+
+        if self.tenant_usages().get(tenant)?.is_some() {
+            return Ok(None);
         }
+
+        let mut records = vec![];
+        for item in self.kvs().range(..)? {
+            let (key, seq_v) = item?.kv()?;
+
+            let owned = seq_v
+                .meta
+                .as_ref()
+                .and_then(|m| m.tenant.as_ref())
+                .is_some();
+            if owned || !Self::is_key_of_tenant(&key, tenant) {
+                continue;
+            }
+
+            if let Some(seq_v) = Self::unexpired(seq_v, log_time_ms) {
+                records.push((key, seq_v));
+            }
+        }
+
+        Ok(Some((tenant.clone(), records)))
     }
 
     /// Apply an log entry to state machine.
@@ -265,7 +541,10 @@ impl StateMachine {
 
         debug!("sled tx start: {:?}", entry);
 
-        let kv_pairs = self.scan_prefix_if_needed(entry)?;
+        let kv_pairs = self.scan_txn_if_needed(entry)?;
+        let expired_leases = self.scan_expired_leases_if_needed(entry)?;
+        let expired_records = self.scan_expired_records_if_needed(entry)?;
+        let tenant_backfill = self.scan_tenant_backfill_if_needed(entry)?;
 
         let result = self.sm_tree.txn(true, move |txn_tree| {
             let txn_sm_meta = txn_tree.key_space::<StateMachineMeta>();
@@ -297,6 +576,15 @@ impl StateMachine {
                         },
                     };
 
+                    for (lease_id, keys) in expired_leases.iter() {
+                        self.txn_expire_lease(&txn_tree, *lease_id, keys, log_time_ms)?;
+                    }
+                    self.txn_clean_expired_records(&txn_tree, &expired_records, log_time_ms)?;
+
+                    if let Some((tenant, records)) = &tenant_backfill {
+                        self.txn_backfill_tenant_usage(&txn_tree, tenant, records)?;
+                    }
+
                     let res = self.apply_cmd(&data.cmd, &txn_tree, kv_pairs.as_ref(), log_time_ms);
                     if let Ok(ok) = &res {
                         info!("apply_result: summary: {}; res ok: {}", entry.summary(), ok);
@@ -392,23 +680,173 @@ impl StateMachine {
         Ok((prev, None).into())
     }
 
+    #[tracing::instrument(level = "debug", skip(self, txn_tree))]
+    fn apply_grant_lease_cmd(
+        &self,
+        ttl_ms: u64,
+        txn_tree: &TransactionSledTree,
+        log_time_ms: u64,
+    ) -> Result<AppliedState, MetaStorageError> {
+        let lease_id = self.txn_incr_seq(Leases::NAME, txn_tree)?;
+        let lease = LeaseValue::new(ttl_ms, log_time_ms);
+
+        txn_tree.key_space::<Leases>().insert(&lease_id, &lease)?;
+        txn_tree
+            .key_space::<LeaseExpires>()
+            .insert(&lease.expire_key(lease_id), &lease_id)?;
+        info!("applied GrantLease: {}={:?}", lease_id, lease);
+
+        Ok(AppliedState::Lease(Some(lease.to_lease(lease_id))))
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, txn_tree))]
+    fn apply_keep_alive_lease_cmd(
+        &self,
+        lease_id: u64,
+        txn_tree: &TransactionSledTree,
+        log_time_ms: u64,
+    ) -> Result<AppliedState, MetaStorageError> {
+        let leases = txn_tree.key_space::<Leases>();
+
+        // An expired lease is already revoked before applying this command.
+        let mut lease = match leases.get(&lease_id)? {
+            None => return Ok(AppliedState::Lease(None)),
+            Some(x) => x,
+        };
+
+        let lease_expires = txn_tree.key_space::<LeaseExpires>();
+        lease_expires.remove(&lease.expire_key(lease_id))?;
+        lease.expire_at_ms = log_time_ms.saturating_add(lease.ttl_ms);
+        lease_expires.insert(&lease.expire_key(lease_id), &lease_id)?;
+        leases.insert(&lease_id, &lease)?;
+
+        Ok(AppliedState::Lease(Some(lease.to_lease(lease_id))))
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, txn_tree, scanned))]
+    fn apply_revoke_lease_cmd(
+        &self,
+        lease_id: u64,
+        txn_tree: &TransactionSledTree,
+        scanned: Option<&TxnScanned>,
+        log_time_ms: u64,
+    ) -> Result<AppliedState, MetaStorageError> {
+        let keys = scanned
+            .and_then(|x| x.lease_keys.get(&lease_id))
+            .map(|x| x.as_slice())
+            .unwrap_or_default();
+        let prev = self.txn_revoke_lease(txn_tree, lease_id, keys, log_time_ms)?;
+        Ok(AppliedState::Lease(prev.map(|x| x.to_lease(lease_id))))
+    }
+
+    /// Revoke a lease if it is expired by the time of the log being applied.
+    fn txn_expire_lease(
+        &self,
+        txn_tree: &TransactionSledTree,
+        lease_id: u64,
+        keys: &[String],
+        log_time_ms: u64,
+    ) -> Result<(), MetaStorageError> {
+        let lease = txn_tree.key_space::<Leases>().get(&lease_id)?;
+
+        if let Some(lease) = lease {
+            if lease.is_expired(log_time_ms) {
+                info!("lease expired: {}={:?}", lease_id, lease);
+                self.txn_revoke_lease(txn_tree, lease_id, keys, log_time_ms)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Remove a lease and delete the keys that are still attached to it.
+    ///
+    /// `keys` are the keys that have been attached to the lease, scanned before the transaction.
+    /// Returns the removed lease, or `None` if it does not exist.
+    fn txn_revoke_lease(
+        &self,
+        txn_tree: &TransactionSledTree,
+        lease_id: u64,
+        keys: &[String],
+        log_time_ms: u64,
+    ) -> Result<Option<LeaseValue>, MetaStorageError> {
+        let leases = txn_tree.key_space::<Leases>();
+
+        let lease = match leases.get(&lease_id)? {
+            None => return Ok(None),
+            Some(x) => x,
+        };
+        leases.remove(&lease_id)?;
+        txn_tree
+            .key_space::<LeaseExpires>()
+            .remove(&lease.expire_key(lease_id))?;
+
+        let lease_keys = txn_tree.key_space::<LeaseKeys>();
+        let sub_tree = txn_tree.key_space::<GenericKV>();
+        for key in keys.iter() {
+            lease_keys.remove(&LeaseKey::new(lease_id, key))?;
+
+            let attached_lease = sub_tree
+                .get(key)?
+                .and_then(|seq_v| seq_v.meta)
+                .and_then(|meta| meta.lease);
+
+            // The key is deleted or updated without this lease since it is attached.
+            if attached_lease != Some(lease_id) {
+                continue;
+            }
+
+            let (prev, result) =
+                self.txn_sub_tree_upsert(&sub_tree, &UpsertKV::delete(key), None, log_time_ms)?;
+            self.txn_record_change(txn_tree, key, prev, result)?;
+        }
+
+        info!("applied RevokeLease: {}={:?}", lease_id, lease);
+        Ok(Some(lease))
+    }
+
+    /// Attach a key to a lease.
+    ///
+    /// Returns false if the lease does not exist or is expired.
+    fn txn_attach_lease(
+        &self,
+        txn_tree: &TransactionSledTree,
+        lease_id: u64,
+        key: &str,
+        log_time_ms: u64,
+    ) -> Result<bool, MetaStorageError> {
+        let leases = txn_tree.key_space::<Leases>();
+
+        let lease = match leases.get(&lease_id)? {
+            None => return Ok(false),
+            Some(x) => x,
+        };
+
+        if lease.is_expired(log_time_ms) {
+            return Ok(false);
+        }
+
+        txn_tree
+            .key_space::<LeaseKeys>()
+            .insert(&LeaseKey::new(lease_id, key), &())?;
+        Ok(true)
+    }
+
     #[tracing::instrument(level = "debug", skip_all)]
     fn apply_update_kv_cmd(
         &self,
         upsert_kv: &UpsertKV,
+        tenant: Option<&str>,
         txn_tree: &TransactionSledTree,
         log_time_ms: u64,
     ) -> Result<AppliedState, MetaStorageError> {
         debug!(upsert_kv = debug(upsert_kv), "apply_update_kv_cmd");
 
         let sub_tree = txn_tree.key_space::<GenericKV>();
-        let (prev, result) = self.txn_sub_tree_upsert(&sub_tree, upsert_kv, log_time_ms)?;
+        let (prev, result) = self.txn_sub_tree_upsert(&sub_tree, upsert_kv, tenant, log_time_ms)?;
 
         debug!("applied UpsertKV: {:?} {:?}", upsert_kv, result);
 
-        if let Some(subscriber) = &self.subscriber {
-            subscriber.kv_changed(&upsert_kv.key, prev.clone(), result.clone());
-        }
+        self.txn_record_change(txn_tree, &upsert_kv.key, prev.clone(), result.clone())?;
 
         Ok(Change::new(prev, result).into())
     }
@@ -447,11 +885,24 @@ impl StateMachine {
         }
     }
 
-    #[tracing::instrument(level = "debug", skip(self, txn_tree, cond))]
+    fn return_count_condition_result(&self, expected: i32, target_count: &u64, count: u64) -> bool {
+        match FromPrimitive::from_i32(expected) {
+            Some(ConditionResult::Eq) => count == *target_count,
+            Some(ConditionResult::Gt) => count > *target_count,
+            Some(ConditionResult::Lt) => count < *target_count,
+            Some(ConditionResult::Ne) => count != *target_count,
+            Some(ConditionResult::Ge) => count >= *target_count,
+            Some(ConditionResult::Le) => count <= *target_count,
+            _ => false,
+        }
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, txn_tree, cond, ranges))]
     fn txn_execute_one_condition(
         &self,
         txn_tree: &TransactionSledTree,
         cond: &TxnCondition,
+        ranges: Option<&RangeKeyMap>,
     ) -> Result<bool, MetaStorageError> {
         debug!(cond = display(cond), "txn_execute_one_condition");
 
@@ -483,22 +934,46 @@ impl StateMachine {
                         return Ok(false);
                     }
                 }
+                txn_condition::Target::KeysWithPrefix(target_count) => {
+                    let count = Self::range_count(ranges, &key, "");
+                    return Ok(self.return_count_condition_result(
+                        cond.expected,
+                        target_count,
+                        count,
+                    ));
+                }
+                txn_condition::Target::KeysInRange(keys_in_range) => {
+                    let count = Self::range_count(ranges, &key, &keys_in_range.range_end);
+                    return Ok(self.return_count_condition_result(
+                        cond.expected,
+                        &keys_in_range.count,
+                        count,
+                    ));
+                }
             }
         };
 
         Ok(false)
     }
 
-    #[tracing::instrument(level = "debug", skip(self, txn_tree, condition))]
+    /// Returns the number of scanned keys in a range, or 0 if the range is not scanned.
+    fn range_count(ranges: Option<&RangeKeyMap>, start: &str, end: &str) -> u64 {
+        ranges
+            .and_then(|r| r.get(&(start.to_string(), end.to_string())))
+            .map_or(0, |kvs| kvs.len() as u64)
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, txn_tree, condition, ranges))]
     fn txn_execute_condition(
         &self,
         txn_tree: &TransactionSledTree,
         condition: &Vec<TxnCondition>,
+        ranges: Option<&RangeKeyMap>,
     ) -> Result<bool, MetaStorageError> {
         for cond in condition {
             debug!(condition = display(cond), "txn_execute_condition");
 
-            if !self.txn_execute_one_condition(txn_tree, cond)? {
+            if !self.txn_execute_one_condition(txn_tree, cond, ranges)? {
                 return Ok(false);
             }
         }
@@ -531,26 +1006,24 @@ impl StateMachine {
         &self,
         txn_tree: &TransactionSledTree,
         put: &TxnPutRequest,
+        tenant: Option<&str>,
         resp: &mut TxnReply,
         events: &mut Option<Vec<NotifyKVEvent>>,
         log_time_ms: u64,
     ) -> Result<(), MetaStorageError> {
         let sub_tree = txn_tree.key_space::<GenericKV>();
 
-        let (prev, result) = match put.expire_at {
-            None => self.txn_sub_tree_upsert(
-                &sub_tree,
-                &UpsertKV::update(&put.key, &put.value),
-                log_time_ms,
-            )?,
-            Some(expire_at) => self.txn_sub_tree_upsert(
-                &sub_tree,
-                &UpsertKV::update(&put.key, &put.value).with(KVMeta {
-                    expire_at: Some(expire_at),
-                }),
-                log_time_ms,
-            )?,
-        };
+        let mut upsert_kv = UpsertKV::update(&put.key, &put.value);
+        if put.expire_at.is_some() || put.lease.is_some() {
+            upsert_kv = upsert_kv.with(KVMeta {
+                expire_at: put.expire_at,
+                lease: put.lease,
+                tenant: None,
+            });
+        }
+
+        let (prev, result) =
+            self.txn_sub_tree_upsert(&sub_tree, &upsert_kv, tenant, log_time_ms)?;
 
         if let Some(events) = events {
             events.push((put.key.to_string(), prev.clone(), result));
@@ -583,7 +1056,7 @@ impl StateMachine {
         let sub_tree = txn_tree.key_space::<GenericKV>();
 
         let (prev, result) =
-            self.txn_sub_tree_upsert(&sub_tree, &UpsertKV::delete(&delete.key), log_time_ms)?;
+            self.txn_sub_tree_upsert(&sub_tree, &UpsertKV::delete(&delete.key), None, log_time_ms)?;
 
         if let Some(events) = events {
             events.push((delete.key.to_string(), prev.clone(), result));
@@ -620,8 +1093,12 @@ impl StateMachine {
             if let Some(kv_pairs) = kv_pairs.get(delete_by_prefix) {
                 let sub_tree = txn_tree.key_space::<GenericKV>();
                 for (key, _seq) in kv_pairs.iter() {
-                    let ret =
-                        self.txn_sub_tree_upsert(&sub_tree, &UpsertKV::delete(key), log_time_ms);
+                    let ret = self.txn_sub_tree_upsert(
+                        &sub_tree,
+                        &UpsertKV::delete(key),
+                        None,
+                        log_time_ms,
+                    );
 
                     if let Ok(ret) = ret {
                         count += 1;
@@ -646,108 +1123,437 @@ impl StateMachine {
         Ok(())
     }
 
-    #[tracing::instrument(level = "debug", skip(self, txn_tree, op, resp))]
-    fn txn_execute_operation(
+    /// Read the key-values in a range, as they are when this op is executed.
+    ///
+    /// The range is scanned before the txn, and is then updated with the changes made by the
+    /// earlier ops in the same txn.
+    fn txn_execute_get_range_operation(
         &self,
-        txn_tree: &TransactionSledTree,
-        op: &TxnOp,
-        kv_pairs: Option<&DeleteByPrefixKeyMap>,
+        get_range: &TxnGetRangeRequest,
+        ranges: Option<&RangeKeyMap>,
+        events: &Option<Vec<NotifyKVEvent>>,
         resp: &mut TxnReply,
-        events: &mut Option<Vec<NotifyKVEvent>>,
-        log_time_ms: u64,
     ) -> Result<(), MetaStorageError> {
-        debug!(op = display(op), "txn execute TxnOp");
-        match &op.request {
-            Some(txn_op::Request::Get(get)) => {
-                self.txn_execute_get_operation(txn_tree, get, resp)?;
-            }
-            Some(txn_op::Request::Put(put)) => {
-                self.txn_execute_put_operation(txn_tree, put, resp, events, log_time_ms)?;
+        let range_key = (get_range.key.clone(), get_range.range_end.clone());
+        let scanned = ranges.and_then(|r| r.get(&range_key));
+
+        let mut kvs: BTreeMap<&String, &SeqV> =
+            scanned.into_iter().flatten().map(|(k, v)| (k, v)).collect();
+
+        for (key, _prev, current) in events.iter().flatten() {
+            if !Self::range_contains(&get_range.key, &get_range.range_end, key) {
+                continue;
             }
-            Some(txn_op::Request::Delete(delete)) => {
-                self.txn_execute_delete_operation(txn_tree, delete, resp, events, log_time_ms)?;
+            match current {
+                Some(seq_v) => {
+                    kvs.insert(key, seq_v);
+                }
+                None => {
+                    kvs.remove(key);
+                }
             }
-            Some(txn_op::Request::DeleteByPrefix(delete_by_prefix)) => {
-                self.txn_execute_delete_by_prefix_operation(
-                    txn_tree,
-                    delete_by_prefix,
-                    kv_pairs,
-                    resp,
-                    events,
-                    log_time_ms,
-                )?;
+        }
+
+        let mut items = vec![];
+        let mut more = false;
+        for (key, seq_v) in kvs {
+            if get_range.limit > 0 && items.len() as u64 >= get_range.limit {
+                more = true;
+                break;
             }
-            None => {}
+            items.push(TxnGetResponse {
+                key: key.clone(),
+                value: Some(PbSeqV::from(seq_v.clone())),
+            });
         }
 
+        resp.responses.push(TxnOpResponse {
+            response: Some(txn_op_response::Response::GetRange(TxnGetRangeResponse {
+                items,
+                more,
+            })),
+        });
+
         Ok(())
     }
 
-    #[tracing::instrument(level = "debug", skip(self, txn_tree, req))]
-    fn apply_txn_cmd(
+    /// Whether `key` is in range `[start, end)`, or has prefix `start` if `end` is empty.
+    fn range_contains(start: &str, end: &str, key: &str) -> bool {
+        if end.is_empty() {
+            key.starts_with(start)
+        } else {
+            start <= key && key < end
+        }
+    }
+
+    fn txn_execute_put_if_absent_operation(
         &self,
-        req: &TxnRequest,
         txn_tree: &TransactionSledTree,
-        kv_pairs: Option<&(DeleteByPrefixKeyMap, DeleteByPrefixKeyMap)>,
+        put: &TxnPutIfAbsentRequest,
+        tenant: Option<&str>,
+        resp: &mut TxnReply,
+        events: &mut Option<Vec<NotifyKVEvent>>,
         log_time_ms: u64,
-    ) -> Result<AppliedState, MetaStorageError> {
-        debug!(txn = display(req), "apply txn cmd");
+    ) -> Result<(), MetaStorageError> {
+        let sub_tree = txn_tree.key_space::<GenericKV>();
 
-        let condition = &req.condition;
+        let mut upsert_kv = UpsertKV::insert(&put.key, &put.value);
+        if put.expire_at.is_some() || put.lease.is_some() {
+            upsert_kv = upsert_kv.with(KVMeta {
+                expire_at: put.expire_at,
+                lease: put.lease,
+                tenant: None,
+            });
+        }
 
-        let ops: &Vec<TxnOp>;
-        let kv_op_pairs: Option<&DeleteByPrefixKeyMap>;
-        let success = if self.txn_execute_condition(txn_tree, condition)? {
-            ops = &req.if_then;
-            kv_op_pairs = if let Some(kv_pairs) = kv_pairs {
-                Some(&kv_pairs.0)
-            } else {
-                None
-            };
-            true
-        } else {
-            ops = &req.else_then;
-            kv_op_pairs = if let Some(kv_pairs) = kv_pairs {
-                Some(&kv_pairs.1)
-            } else {
-                None
-            };
-            false
+        let (prev, result) =
+            self.txn_sub_tree_upsert(&sub_tree, &upsert_kv, tenant, log_time_ms)?;
+
+        let success = prev.is_none() && result.is_some();
+        if success {
+            if let Some(events) = events {
+                events.push((put.key.to_string(), None, result));
+            }
+        }
+
+        let put_resp = TxnPutIfAbsentResponse {
+            key: put.key.clone(),
+            success,
+            prev_value: prev.map(PbSeqV::from),
         };
 
+        resp.responses.push(TxnOpResponse {
+            response: Some(txn_op_response::Response::PutIfAbsent(put_resp)),
+        });
+
+        Ok(())
+    }
+
+    fn txn_execute_fetch_add_u64_operation(
+        &self,
+        txn_tree: &TransactionSledTree,
+        fetch_add: &TxnFetchAddU64Request,
+        tenant: Option<&str>,
+        resp: &mut TxnReply,
+        events: &mut Option<Vec<NotifyKVEvent>>,
+        log_time_ms: u64,
+    ) -> Result<(), MetaStorageError> {
+        let sub_tree = txn_tree.key_space::<GenericKV>();
+
+        let prev = sub_tree.get(&fetch_add.key)?;
+        let prev = Self::unexpired_opt(prev, log_time_ms);
+
+        let before = match &prev {
+            None => Some(0),
+            Some(seq_v) => serde_json::from_slice::<u64>(&seq_v.data).ok(),
+        };
+
+        let after = before.and_then(|b| {
+            if fetch_add.delta >= 0 {
+                b.checked_add(fetch_add.delta as u64)
+            } else {
+                b.checked_sub(fetch_add.delta.unsigned_abs())
+            }
+        });
+
+        let fetch_add_resp = match (before, after) {
+            (Some(before), Some(after)) => {
+                // The value is json encoded and can not fail to serialize.
+                let value = serde_json::to_vec(&after).unwrap();
+
+                // Keep the expire time and lease of the present value.
+                let upsert_kv = UpsertKV::new(
+                    &fetch_add.key,
+                    MatchSeq::Any,
+                    Operation::Update(value),
+                    prev.and_then(|x| x.meta),
+                );
+
+                let (prev, result) =
+                    self.txn_sub_tree_upsert(&sub_tree, &upsert_kv, tenant, log_time_ms)?;
+
+                // Nothing is changed if the value is attached to a lease that is gone.
+                let success = result.as_ref().map(|x| x.seq) != prev.as_ref().map(|x| x.seq);
+                if success {
+                    if let Some(events) = events {
+                        events.push((fetch_add.key.to_string(), prev, result));
+                    }
+                }
+
+                TxnFetchAddU64Response {
+                    key: fetch_add.key.clone(),
+                    success,
+                    before,
+                    after: if success { after } else { before },
+                }
+            }
+            (before, _) => {
+                let before = before.unwrap_or_default();
+                TxnFetchAddU64Response {
+                    key: fetch_add.key.clone(),
+                    success: false,
+                    before,
+                    after: before,
+                }
+            }
+        };
+
+        resp.responses.push(TxnOpResponse {
+            response: Some(txn_op_response::Response::FetchAddU64(fetch_add_resp)),
+        });
+
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, txn_tree, op, resp))]
+    #[allow(clippy::too_many_arguments)]
+    fn txn_execute_operation(
+        &self,
+        txn_tree: &TransactionSledTree,
+        op: &TxnOp,
+        kv_pairs: Option<&DeleteByPrefixKeyMap>,
+        ranges: Option<&RangeKeyMap>,
+        tenant: Option<&str>,
+        resp: &mut TxnReply,
+        events: &mut Option<Vec<NotifyKVEvent>>,
+        log_time_ms: u64,
+    ) -> Result<(), MetaStorageError> {
+        debug!(op = display(op), "txn execute TxnOp");
+        match &op.request {
+            Some(txn_op::Request::Get(get)) => {
+                self.txn_execute_get_operation(txn_tree, get, resp)?;
+            }
+            Some(txn_op::Request::Put(put)) => {
+                self.txn_execute_put_operation(txn_tree, put, tenant, resp, events, log_time_ms)?;
+            }
+            Some(txn_op::Request::Delete(delete)) => {
+                self.txn_execute_delete_operation(txn_tree, delete, resp, events, log_time_ms)?;
+            }
+            Some(txn_op::Request::DeleteByPrefix(delete_by_prefix)) => {
+                self.txn_execute_delete_by_prefix_operation(
+                    txn_tree,
+                    delete_by_prefix,
+                    kv_pairs,
+                    resp,
+                    events,
+                    log_time_ms,
+                )?;
+            }
+            Some(txn_op::Request::GetRange(get_range)) => {
+                self.txn_execute_get_range_operation(get_range, ranges, events, resp)?;
+            }
+            Some(txn_op::Request::PutIfAbsent(put)) => {
+                self.txn_execute_put_if_absent_operation(
+                    txn_tree,
+                    put,
+                    tenant,
+                    resp,
+                    events,
+                    log_time_ms,
+                )?;
+            }
+            Some(txn_op::Request::FetchAddU64(fetch_add)) => {
+                self.txn_execute_fetch_add_u64_operation(
+                    txn_tree,
+                    fetch_add,
+                    tenant,
+                    resp,
+                    events,
+                    log_time_ms,
+                )?;
+            }
+            None => {}
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, txn_tree, req))]
+    fn apply_txn_cmd(
+        &self,
+        req: &TxnRequest,
+        tenant: Option<&str>,
+        txn_tree: &TransactionSledTree,
+        scanned: Option<&TxnScanned>,
+        log_time_ms: u64,
+    ) -> Result<AppliedState, MetaStorageError> {
+        debug!(txn = display(req), "apply txn cmd");
+
+        let condition = &req.condition;
+        let ranges = scanned.map(|x| &x.ranges);
+
+        let ops: &Vec<TxnOp>;
+        let kv_op_pairs: Option<&DeleteByPrefixKeyMap>;
+        let success = if self.txn_execute_condition(txn_tree, condition, ranges)? {
+            ops = &req.if_then;
+            kv_op_pairs = scanned.map(|x| &x.if_then);
+            true
+        } else {
+            ops = &req.else_then;
+            kv_op_pairs = scanned.map(|x| &x.else_then);
+            false
+        };
+
+        if let Some(failed) =
+            self.txn_check_fetch_add_u64(txn_tree, ops, kv_op_pairs, log_time_ms)?
+        {
+            info!("txn rejected: {}; txn: {}", failed, req);
+            return Ok(AppliedState::AppError(failed.into()));
+        }
+
         let mut resp: TxnReply = TxnReply {
             success,
             error: "".to_string(),
             responses: vec![],
         };
 
-        let mut events: Option<Vec<NotifyKVEvent>> = if self.subscriber.is_some() {
-            Some(vec![])
-        } else {
-            None
-        };
+        let mut events: Option<Vec<NotifyKVEvent>> = Some(vec![]);
         for op in ops {
             self.txn_execute_operation(
                 txn_tree,
                 op,
                 kv_op_pairs,
+                ranges,
+                tenant,
                 &mut resp,
                 &mut events,
                 log_time_ms,
             )?;
         }
 
-        if let Some(subscriber) = &self.subscriber {
-            if let Some(events) = events {
-                for event in events {
-                    subscriber.kv_changed(&event.0, event.1, event.2);
-                }
+        if let Some(events) = events {
+            for (key, prev, current) in events {
+                self.txn_record_change(txn_tree, &key, prev, current)?;
             }
         }
 
         Ok(AppliedState::TxnReply(resp))
     }
 
+    /// Check that every `fetch_add_u64` op in `ops` can be applied, before applying any op.
+    ///
+    /// A txn with a `fetch_add_u64` that would fail is rejected as a whole, so that no op of it
+    /// is applied. The value a `fetch_add_u64` sees is tracked through the earlier ops on the
+    /// same key, the same way they are applied: a put is ignored if its lease is not alive, a
+    /// put-if-absent only if the key is present, and a delete-by-prefix deletes the scanned keys.
+    fn txn_check_fetch_add_u64(
+        &self,
+        txn_tree: &TransactionSledTree,
+        ops: &[TxnOp],
+        kv_op_pairs: Option<&DeleteByPrefixKeyMap>,
+        log_time_ms: u64,
+    ) -> Result<Option<TxnFetchAddU64Failed>, MetaStorageError> {
+        let sub_tree = txn_tree.key_space::<GenericKV>();
+
+        // The value of the keys written by earlier ops, `None` if the key is absent,
+        // `Some(None)` if the value is not a u64.
+        let mut written: BTreeMap<&str, Option<Option<u64>>> = BTreeMap::new();
+
+        let value_of = |written: &BTreeMap<&str, Option<Option<u64>>>, key: &str| {
+            if let Some(v) = written.get(key) {
+                return Ok::<_, MetaStorageError>(*v);
+            }
+            let present = Self::unexpired_opt(sub_tree.get(key)?, log_time_ms);
+            Ok(present.map(|seq_v| serde_json::from_slice::<u64>(&seq_v.data).ok()))
+        };
+
+        // The value a put writes, as seen by the later ops.
+        let put_value = |value: &[u8], expire_at: Option<u64>, lease: Option<u64>| {
+            let meta = KVMeta {
+                expire_at,
+                lease,
+                tenant: None,
+            };
+            Self::unexpired(SeqV::with_meta(0, Some(meta), ()), log_time_ms)
+                .map(|_| serde_json::from_slice::<u64>(value).ok())
+        };
+
+        for op in ops {
+            let fetch_add = match &op.request {
+                Some(txn_op::Request::FetchAddU64(fetch_add)) => fetch_add,
+                Some(txn_op::Request::Put(put)) => {
+                    if self.txn_lease_alive(txn_tree, put.lease, log_time_ms)? {
+                        let v = put_value(&put.value, put.expire_at, put.lease);
+                        written.insert(&put.key, v);
+                    }
+                    continue;
+                }
+                Some(txn_op::Request::PutIfAbsent(put)) => {
+                    if value_of(&written, &put.key)?.is_none()
+                        && self.txn_lease_alive(txn_tree, put.lease, log_time_ms)?
+                    {
+                        let v = put_value(&put.value, put.expire_at, put.lease);
+                        written.insert(&put.key, v);
+                    }
+                    continue;
+                }
+                Some(txn_op::Request::Delete(delete)) => {
+                    written.insert(&delete.key, None);
+                    continue;
+                }
+                Some(txn_op::Request::DeleteByPrefix(delete_by_prefix)) => {
+                    let deleted = kv_op_pairs.and_then(|pairs| pairs.get(delete_by_prefix));
+                    for (key, _) in deleted.into_iter().flatten() {
+                        written.insert(key, None);
+                    }
+                    continue;
+                }
+                _ => continue,
+            };
+
+            let key = fetch_add.key.as_str();
+
+            let before = match value_of(&written, key)? {
+                None => 0,
+                Some(Some(v)) => v,
+                Some(None) => {
+                    return Ok(Some(TxnFetchAddU64Failed::new(
+                        key,
+                        fetch_add.delta,
+                        "the present value is not a u64",
+                    )));
+                }
+            };
+
+            let after = if fetch_add.delta >= 0 {
+                before.checked_add(fetch_add.delta as u64)
+            } else {
+                before.checked_sub(fetch_add.delta.unsigned_abs())
+            };
+
+            match after {
+                Some(after) => {
+                    written.insert(key, Some(Some(after)));
+                }
+                None => {
+                    return Ok(Some(TxnFetchAddU64Failed::new(
+                        key,
+                        fetch_add.delta,
+                        format!("the result overflows, the present value is {}", before),
+                    )));
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Whether a record can be attached to the lease, it is always true if there is no lease.
+    fn txn_lease_alive(
+        &self,
+        txn_tree: &TransactionSledTree,
+        lease_id: Option<u64>,
+        log_time_ms: u64,
+    ) -> Result<bool, MetaStorageError> {
+        let lease_id = match lease_id {
+            None => return Ok(true),
+            Some(x) => x,
+        };
+
+        let lease = txn_tree.key_space::<Leases>().get(&lease_id)?;
+        Ok(matches!(lease, Some(lease) if !lease.is_expired(log_time_ms)))
+    }
+
     /// Apply a `Cmd` to state machine.
     ///
     /// Already applied log should be filtered out before passing into this function.
@@ -758,14 +1564,31 @@ impl StateMachine {
         &self,
         cmd: &Cmd,
         txn_tree: &TransactionSledTree,
-        kv_pairs: Option<&(DeleteByPrefixKeyMap, DeleteByPrefixKeyMap)>,
+        kv_pairs: Option<&TxnScanned>,
         log_time_ms: u64,
     ) -> Result<AppliedState, MetaStorageError> {
         info!("apply_cmd: {}", cmd);
 
         let now = Instant::now();
 
-        let res = match cmd {
+        let res = self.apply_cmd_as(cmd, None, txn_tree, kv_pairs, log_time_ms);
+
+        let elapsed = now.elapsed().as_micros();
+        debug!("apply_cmd: elapsed: {}", elapsed);
+
+        res
+    }
+
+    /// Apply a `Cmd`, the records it writes are owned by `tenant`.
+    fn apply_cmd_as(
+        &self,
+        cmd: &Cmd,
+        tenant: Option<&str>,
+        txn_tree: &TransactionSledTree,
+        kv_pairs: Option<&TxnScanned>,
+        log_time_ms: u64,
+    ) -> Result<AppliedState, MetaStorageError> {
+        match cmd {
             Cmd::IncrSeq { ref key } => self.apply_incr_seq_cmd(key, txn_tree),
 
             Cmd::AddNode {
@@ -776,16 +1599,342 @@ impl StateMachine {
             Cmd::RemoveNode { ref node_id } => self.apply_remove_node_cmd(node_id, txn_tree),
 
             Cmd::UpsertKV(ref upsert_kv) => {
-                self.apply_update_kv_cmd(upsert_kv, txn_tree, log_time_ms)
+                self.apply_update_kv_cmd(upsert_kv, tenant, txn_tree, log_time_ms)
+            }
+
+            Cmd::Transaction(txn) => {
+                self.apply_txn_cmd(txn, tenant, txn_tree, kv_pairs, log_time_ms)
+            }
+
+            Cmd::GrantLease { ttl_ms } => {
+                self.apply_grant_lease_cmd(*ttl_ms, txn_tree, log_time_ms)
+            }
+
+            Cmd::KeepAliveLease { lease_id } => {
+                self.apply_keep_alive_lease_cmd(*lease_id, txn_tree, log_time_ms)
+            }
+
+            Cmd::RevokeLease { lease_id } => {
+                self.apply_revoke_lease_cmd(*lease_id, txn_tree, kv_pairs, log_time_ms)
             }
 
-            Cmd::Transaction(txn) => self.apply_txn_cmd(txn, txn_tree, kv_pairs, log_time_ms),
+            Cmd::AsTenant { tenant, cmd } => {
+                self.apply_tenant_cmd(tenant, cmd, txn_tree, kv_pairs, log_time_ms)
+            }
+        }
+    }
+
+    /// Apply a `Cmd` on behalf of a tenant, unless it exceeds the tenant's quota.
+    ///
+    /// The usage is checked before applying: a write that may add data is rejected once the usage
+    /// reaches a limit, thus a tenant exceeds a limit by at most one write.
+    /// A write that only deletes is always allowed, so that a tenant is able to reclaim its usage.
+    /// A tenant is not allowed to write any quota record, quotas are set by a writer that is not
+    /// bound to a tenant, e.g., a query node in management mode.
+    ///
+    /// The write rate is not limited here but by the leader before proposing a write, see
+    /// `MetaLeader::check_tenant_write_rate()`.
+    #[tracing::instrument(level = "debug", skip(self, cmd, txn_tree, kv_pairs))]
+    fn apply_tenant_cmd(
+        &self,
+        tenant: &str,
+        cmd: &Cmd,
+        txn_tree: &TransactionSledTree,
+        kv_pairs: Option<&TxnScanned>,
+        log_time_ms: u64,
+    ) -> Result<AppliedState, MetaStorageError> {
+        if let Some(key) = Self::quota_key_written(cmd) {
+            info!(
+                "tenant {} is not allowed to write quota key {}: {}",
+                tenant, key, cmd
+            );
+            return Ok(AppliedState::AppError(
+                TenantQuotaWriteDenied::new(tenant, key).into(),
+            ));
+        }
+
+        if let Some(exceeded) = self.txn_check_tenant_quota(txn_tree, tenant, cmd)? {
+            info!("tenant quota exceeded: {}; rejected: {}", exceeded, cmd);
+            return Ok(AppliedState::AppError(exceeded.into()));
+        }
+
+        self.apply_cmd_as(cmd, Some(tenant), txn_tree, kv_pairs, log_time_ms)
+    }
+
+    /// Check the usage of a tenant against its quota before applying a write of it.
+    ///
+    /// Returns the exceeded quota if the write has to be rejected.
+    fn txn_check_tenant_quota(
+        &self,
+        txn_tree: &TransactionSledTree,
+        tenant: &str,
+        cmd: &Cmd,
+    ) -> Result<Option<TenantQuotaExceeded>, MetaStorageError> {
+        if !Self::may_add_data(cmd) {
+            return Ok(None);
+        }
+
+        let quota_key = Self::tenant_quota_key(tenant);
+        let quota =
+            Self::decode_tenant_quota(tenant, txn_tree.key_space::<GenericKV>().get(&quota_key)?);
+
+        let usages = txn_tree.key_space::<TenantUsages>();
+        let usage = usages.get(&tenant.to_string())?.unwrap_or_default();
+
+        let limits = [
+            ("max_keys", usage.keys, quota.max_keys),
+            ("max_value_bytes", usage.value_bytes, quota.max_value_bytes),
+        ];
+
+        // A limit of 0 means unlimited.
+        for (name, used, limit) in limits {
+            if limit > 0 && used >= limit {
+                return Ok(Some(TenantQuotaExceeded::new(tenant, name, used, limit)));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Read the quota of a tenant, which is stored as a generic kv record.
+    ///
+    /// A tenant without a valid quota record is unlimited.
+    pub fn get_tenant_quota(&self, tenant: &str) -> Result<TenantQuota, MetaStorageError> {
+        let quota_key = Self::tenant_quota_key(tenant);
+        let seq_v = self.kvs().get(&quota_key)?;
+        Ok(Self::decode_tenant_quota(tenant, seq_v))
+    }
+
+    fn decode_tenant_quota(tenant: &str, seq_v: Option<SeqV>) -> TenantQuota {
+        let seq_v = match seq_v {
+            None => return TenantQuota::default(),
+            Some(x) => x,
         };
 
-        let elapsed = now.elapsed().as_micros();
-        debug!("apply_cmd: elapsed: {}", elapsed);
+        match TenantQuota::try_from(seq_v.data) {
+            Ok(quota) => quota,
+            Err(e) => {
+                warn!(
+                    "invalid quota of tenant {}, treated as unlimited: {}",
+                    tenant, e
+                );
+                TenantQuota::default()
+            }
+        }
+    }
 
-        res
+    /// The key of the record that stores the quota of a tenant.
+    fn tenant_quota_key(tenant: &str) -> String {
+        // An escaped key consists of only ascii chars, thus it never fails.
+        let escaped = escape_for_key(tenant).unwrap_or_else(|_| tenant.to_string());
+        format!("{}/{}", TENANT_QUOTA_KEY_PREFIX, escaped)
+    }
+
+    /// Returns a quota key, or a prefix that covers quota keys, that a command writes.
+    fn quota_key_written(cmd: &Cmd) -> Option<String> {
+        let quota_prefix = format!("{}/", TENANT_QUOTA_KEY_PREFIX);
+        let is_quota_key = |key: &String| key.starts_with(&quota_prefix).then(|| key.clone());
+
+        match cmd {
+            Cmd::UpsertKV(upsert_kv) => is_quota_key(&upsert_kv.key),
+            Cmd::Transaction(txn) => {
+                txn.if_then
+                    .iter()
+                    .chain(txn.else_then.iter())
+                    .find_map(|op| match &op.request {
+                        Some(txn_op::Request::Put(x)) => is_quota_key(&x.key),
+                        Some(txn_op::Request::Delete(x)) => is_quota_key(&x.key),
+                        Some(txn_op::Request::PutIfAbsent(x)) => is_quota_key(&x.key),
+                        Some(txn_op::Request::FetchAddU64(x)) => is_quota_key(&x.key),
+                        Some(txn_op::Request::DeleteByPrefix(x)) => {
+                            let covers = quota_prefix.starts_with(&x.prefix)
+                                || x.prefix.starts_with(&quota_prefix);
+                            covers.then(|| x.prefix.clone())
+                        }
+                        _ => None,
+                    })
+            }
+            Cmd::AsTenant { cmd, .. } => Self::quota_key_written(cmd),
+            _ => None,
+        }
+    }
+
+    /// Whether a command may add records or value bytes, i.e., it is not a pure deletion.
+    fn may_add_data(cmd: &Cmd) -> bool {
+        match cmd {
+            Cmd::UpsertKV(upsert_kv) => !matches!(upsert_kv.value, Operation::Delete),
+            Cmd::Transaction(txn) => txn.if_then.iter().chain(txn.else_then.iter()).any(|op| {
+                matches!(
+                    op.request,
+                    Some(txn_op::Request::Put(_))
+                        | Some(txn_op::Request::PutIfAbsent(_))
+                        | Some(txn_op::Request::FetchAddU64(_))
+                )
+            }),
+            Cmd::AsTenant { cmd, .. } => Self::may_add_data(cmd),
+            _ => false,
+        }
+    }
+
+    /// Move the usage of a record from the owner of its previous value to the owner of its current value.
+    ///
+    /// `prev` and `current` are the values physically stored, expired or not.
+    fn txn_account_usage(
+        &self,
+        txn_tree: &TransactionSledTree,
+        prev: Option<&SeqV>,
+        current: Option<&SeqV>,
+    ) -> Result<(), MetaStorageError> {
+        let usages = txn_tree.key_space::<TenantUsages>();
+
+        for (seq_v, added) in [(prev, false), (current, true)] {
+            let seq_v = match seq_v {
+                None => continue,
+                Some(x) => x,
+            };
+
+            let tenant = match seq_v.meta.as_ref().and_then(|m| m.tenant.as_ref()) {
+                None => continue,
+                Some(x) => x,
+            };
+
+            let mut usage: TenantUsage = usages.get(tenant)?.unwrap_or_default();
+            let bytes = seq_v.data.len() as u64;
+            if added {
+                usage.keys += 1;
+                usage.value_bytes += bytes;
+            } else {
+                usage.keys = usage.keys.saturating_sub(1);
+                usage.value_bytes = usage.value_bytes.saturating_sub(bytes);
+            }
+            usages.insert(tenant, &usage)?;
+        }
+
+        Ok(())
+    }
+
+    /// Move the expire index entry of a record from its previous value to its current value.
+    ///
+    /// `prev` and `current` are the values physically stored, expired or not.
+    fn txn_update_expire_index(
+        &self,
+        txn_tree: &TransactionSledTree,
+        key: &str,
+        prev: Option<&SeqV>,
+        current: Option<&SeqV>,
+    ) -> Result<(), MetaStorageError> {
+        let expires = txn_tree.key_space::<Expires>();
+
+        if let Some(expire_key) = prev.and_then(|x| Self::expire_key(key, x)) {
+            expires.remove(&expire_key)?;
+        }
+
+        if let Some(seq_v) = current {
+            if let Some(expire_key) = Self::expire_key(key, seq_v) {
+                expires.insert(&expire_key, &seq_v.seq)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The key in the expire index of a record.
+    ///
+    /// Only a record that expires and is owned by a tenant is indexed: it has to be deleted
+    /// when it expires, to release the usage of the tenant.
+    fn expire_key(key: &str, seq_v: &SeqV) -> Option<ExpireKey> {
+        let meta = seq_v.meta.as_ref()?;
+        meta.tenant.as_ref()?;
+        meta.expire_at?;
+        Some(ExpireKey::new(seq_v.get_expire_at(), key))
+    }
+
+    /// Delete the records found by `scan_expired_records_if_needed()`, and release the usage of their tenants.
+    ///
+    /// A read already treats an expired record as absent, thus no change is recorded for it.
+    fn txn_clean_expired_records(
+        &self,
+        txn_tree: &TransactionSledTree,
+        expired: &[(ExpireKey, u64)],
+        log_time_ms: u64,
+    ) -> Result<(), MetaStorageError> {
+        let sub_tree = txn_tree.key_space::<GenericKV>();
+
+        for (expire_key, seq) in expired {
+            let stored = sub_tree.get(&expire_key.key)?;
+            let is_expired = match stored {
+                Some(seq_v) => seq_v.seq == *seq && Self::unexpired(seq_v, log_time_ms).is_none(),
+                None => false,
+            };
+
+            if is_expired {
+                debug!("clean expired record: {}", expire_key);
+                self.txn_sub_tree_upsert(
+                    &sub_tree,
+                    &UpsertKV::delete(&expire_key.key),
+                    None,
+                    log_time_ms,
+                )?;
+            } else {
+                // The index entry is left behind by a record that is gone or changed.
+                txn_tree.key_space::<Expires>().remove(expire_key)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Attribute the records found by `scan_tenant_backfill_if_needed()` to a tenant and account
+    /// them in its usage.
+    ///
+    /// It is done only once for a tenant: when the tenant does not have a usage yet.
+    fn txn_backfill_tenant_usage(
+        &self,
+        txn_tree: &TransactionSledTree,
+        tenant: &str,
+        records: &[(String, SeqV)],
+    ) -> Result<(), MetaStorageError> {
+        let usages = txn_tree.key_space::<TenantUsages>();
+        if usages.get(&tenant.to_string())?.is_some() {
+            return Ok(());
+        }
+
+        // An empty usage marks the tenant as backfilled, even if it has no record.
+        usages.insert(&tenant.to_string(), &TenantUsage::default())?;
+
+        let sub_tree = txn_tree.key_space::<GenericKV>();
+        let mut n = 0;
+        for (key, seq_v) in records {
+            let stored = sub_tree.get(key)?;
+            if stored.as_ref().map(|x| x.seq) != Some(seq_v.seq) {
+                continue;
+            }
+
+            // The ownership is internal to the state machine, the seq is not changed.
+            let mut owned = seq_v.clone();
+            let meta = owned.meta.get_or_insert_with(KVMeta::default);
+            meta.tenant = Some(tenant.to_string());
+
+            sub_tree.insert(key, &owned)?;
+            self.txn_account_usage(txn_tree, None, Some(&owned))?;
+            self.txn_update_expire_index(txn_tree, key, None, Some(&owned))?;
+            n += 1;
+        }
+
+        info!("backfilled usage of tenant {} with {} records", tenant, n);
+        Ok(())
+    }
+
+    /// Whether a record that is not owned by any tenant is taken as a record of `tenant`:
+    /// the second segment of its key is the tenant, e.g., `__fd_database/<tenant>/<db>`.
+    fn is_key_of_tenant(key: &str, tenant: &str) -> bool {
+        if key.starts_with(&format!("{}/", TENANT_QUOTA_KEY_PREFIX)) {
+            return false;
+        }
+
+        // An escaped key consists of only ascii chars, thus it never fails.
+        let escaped = escape_for_key(tenant).unwrap_or_else(|_| tenant.to_string());
+        key.split('/').nth(1) == Some(escaped.as_str())
     }
 
     fn txn_incr_seq(
@@ -804,20 +1953,51 @@ impl StateMachine {
         Ok(curr.0)
     }
 
+    /// Append a kv change to the change log and notify the subscriber.
+    ///
+    /// The change log is bounded: the change `max_change_log_to_keep` revisions earlier is removed.
+    fn txn_record_change(
+        &self,
+        txn_tree: &TransactionSledTree,
+        key: &str,
+        prev: Option<SeqV>,
+        current: Option<SeqV>,
+    ) -> Result<(), MetaStorageError> {
+        let revision = self.txn_incr_seq(ChangeLogs::NAME, txn_tree)?;
+
+        let change_logs = txn_tree.key_space::<ChangeLogs>();
+        change_logs.insert(&revision, &ChangeLogValue {
+            key: key.to_string(),
+            prev: prev.clone(),
+            current: current.clone(),
+        })?;
+
+        if revision > self.max_change_log_to_keep {
+            change_logs.remove(&(revision - self.max_change_log_to_keep))?;
+        }
+
+        if let Some(subscriber) = &self.subscriber {
+            subscriber.kv_changed(revision, key, prev, current);
+        }
+
+        Ok(())
+    }
+
     #[allow(clippy::type_complexity)]
     fn txn_sub_tree_upsert<'s, KS>(
         &'s self,
         sub_tree: &AsTxnKeySpace<'s, KS>,
         upsert_kv: &UpsertKV,
+        tenant: Option<&str>,
         log_time_ms: u64,
     ) -> Result<(Option<SeqV>, Option<SeqV>), MetaStorageError>
     where
         KS: SledKeySpace<K = String, V = SeqV>,
     {
-        let prev = sub_tree.get(&upsert_kv.key)?;
+        let stored = sub_tree.get(&upsert_kv.key)?;
 
         // If prev is timed out, treat it as a None.
-        let prev = Self::unexpired_opt(prev, log_time_ms);
+        let prev = Self::unexpired_opt(stored.clone(), log_time_ms);
 
         if upsert_kv.seq.match_seq(&prev).is_err() {
             return Ok((prev.clone(), prev));
@@ -827,6 +2007,8 @@ impl StateMachine {
             Operation::Update(v) => SeqV::with_meta(0, upsert_kv.value_meta.clone(), v.clone()),
             Operation::Delete => {
                 sub_tree.remove(&upsert_kv.key)?;
+                self.txn_account_usage(sub_tree, stored.as_ref(), None)?;
+                self.txn_update_expire_index(sub_tree, &upsert_kv.key, stored.as_ref(), None)?;
                 return Ok((prev, None));
             }
             Operation::AsIs => match prev {
@@ -837,8 +2019,30 @@ impl StateMachine {
             },
         };
 
+        // The writer owns the record, no matter who owned it before.
+        match (&mut new_seq_v.meta, tenant) {
+            (Some(meta), _) => meta.tenant = tenant.map(|x| x.to_string()),
+            (None, Some(tenant)) => {
+                new_seq_v.meta = Some(KVMeta {
+                    tenant: Some(tenant.to_string()),
+                    ..Default::default()
+                })
+            }
+            (None, None) => {}
+        }
+
+        // A record can only be attached to a living lease.
+        // Otherwise nothing is changed, just like a seq mismatch.
+        if let Some(lease_id) = new_seq_v.meta.as_ref().and_then(|m| m.lease) {
+            if !self.txn_attach_lease(sub_tree, lease_id, &upsert_kv.key, log_time_ms)? {
+                return Ok((prev.clone(), prev));
+            }
+        }
+
         new_seq_v.seq = self.txn_incr_seq(KS::NAME, sub_tree)?;
         sub_tree.insert(&upsert_kv.key, &new_seq_v)?;
+        self.txn_account_usage(sub_tree, stored.as_ref(), Some(&new_seq_v))?;
+        self.txn_update_expire_index(sub_tree, &upsert_kv.key, stored.as_ref(), Some(&new_seq_v))?;
 
         debug!("applied upsert: {:?} res: {:?}", upsert_kv, new_seq_v);
         Ok((prev, Some(new_seq_v)))
@@ -884,6 +2088,57 @@ impl StateMachine {
         Ok(())
     }
 
+    /// The last revision assigned to a kv change, 0 if there is no change yet.
+    pub fn last_change_revision(&self) -> Result<u64, MetaStorageError> {
+        let seq = self.sequences().get(&ChangeLogs::NAME.to_string())?;
+        Ok(seq.map(|x| x.0).unwrap_or_default())
+    }
+
+    /// Returns the kv changes from revision `start`(inclusive) to the latest one.
+    ///
+    /// The inner error is returned if some of these changes are already removed from
+    /// the bounded change log.
+    #[allow(clippy::type_complexity)]
+    pub fn change_logs_since(
+        &self,
+        start: u64,
+    ) -> Result<Result<Vec<(u64, ChangeLogValue)>, CompactedRevision>, MetaStorageError> {
+        let start = std::cmp::max(start, 1);
+        let last = self.last_change_revision()?;
+        if start > last {
+            return Ok(Ok(vec![]));
+        }
+
+        let mut changes = vec![];
+        for item in self.change_logs().range(start..)? {
+            changes.push(item?.kv()?);
+        }
+
+        // Revisions are dense, the changes are complete if the first one is `start`.
+        if changes.first().map(|(revision, _)| *revision) == Some(start) {
+            return Ok(Ok(changes));
+        }
+
+        let oldest = match self.change_logs().range(..)?.next() {
+            Some(item) => item?.kv()?.0,
+            None => last + 1,
+        };
+
+        Ok(Err(CompactedRevision {
+            requested: start,
+            oldest,
+        }))
+    }
+
+    /// Returns the resource usage of every tenant that owns records.
+    pub fn get_tenant_usages(&self) -> Result<Vec<(String, TenantUsage)>, MetaStorageError> {
+        let mut usages = vec![];
+        for item in self.tenant_usages().range(..)? {
+            usages.push(item?.kv()?);
+        }
+        Ok(usages)
+    }
+
     pub fn get_client_last_resp(
         &self,
         key: &str,
@@ -922,6 +2177,30 @@ impl StateMachine {
         sm_nodes.range_values(..)
     }
 
+    /// Returns `None` if the record is attached to a lease that does not exist or is expired.
+    ///
+    /// An expired lease is revoked when the next log is applied, a read must not see the
+    /// records attached to it before that.
+    pub fn lease_alive_opt<V: Debug>(
+        &self,
+        seq_value: Option<SeqV<V>>,
+        now_ms: u64,
+    ) -> Result<Option<SeqV<V>>, MetaStorageError> {
+        let lease_id = match seq_value.as_ref().and_then(|x| x.meta.as_ref()) {
+            Some(KVMeta {
+                lease: Some(lease_id),
+                ..
+            }) => *lease_id,
+            _ => return Ok(seq_value),
+        };
+
+        let lease = self.leases().get(&lease_id)?;
+        match lease {
+            Some(lease) if !lease.is_expired(now_ms) => Ok(seq_value),
+            _ => Ok(None),
+        }
+    }
+
     pub fn unexpired_opt<V: Debug>(
         seq_value: Option<SeqV<V>>,
         log_time_ms: u64,
@@ -978,8 +2257,36 @@ impl StateMachine {
         self.sm_tree.key_space()
     }
 
+    /// The recent kv changes indexed by revision.
+    pub fn change_logs(&self) -> AsKeySpace<ChangeLogs> {
+        self.sm_tree.key_space()
+    }
+
+    /// Leases and the keys attached to them.
+    pub fn leases(&self) -> AsKeySpace<Leases> {
+        self.sm_tree.key_space()
+    }
+
     /// storage of client last resp to keep idempotent.
     pub fn client_last_resps(&self) -> AsKeySpace<ClientLastResps> {
         self.sm_tree.key_space()
     }
+
+    pub fn tenant_usages(&self) -> AsKeySpace<TenantUsages> {
+        self.sm_tree.key_space()
+    }
+
+    pub fn expires(&self) -> AsKeySpace<Expires> {
+        self.sm_tree.key_space()
+    }
+
+    /// The index of the expire time of leases.
+    pub fn lease_expires(&self) -> AsKeySpace<LeaseExpires> {
+        self.sm_tree.key_space()
+    }
+
+    /// The keys attached to leases.
+    pub fn lease_keys(&self) -> AsKeySpace<LeaseKeys> {
+        self.sm_tree.key_space()
+    }
 }
@@ -12,12 +12,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::ops::Bound;
+
 use common_meta_api::KVApi;
+use common_meta_stoerr::MetaStorageError;
 use common_meta_types::AppliedState;
 use common_meta_types::Cmd;
 use common_meta_types::GetKVReply;
 use common_meta_types::KVAppError;
 use common_meta_types::MGetKVReply;
+use common_meta_types::RangeKVReply;
+use common_meta_types::RangeKVReq;
 use common_meta_types::SeqV;
 use common_meta_types::TxnReply;
 use common_meta_types::TxnRequest;
@@ -54,12 +59,12 @@ impl KVApi for StateMachine {
     }
 
     async fn transaction(&self, txn: TxnRequest) -> Result<TxnReply, KVAppError> {
+        let now_ms = SeqV::<()>::now_ms();
+        let scanned = self.scan_txn(&txn, now_ms)?;
         let cmd = Cmd::Transaction(txn);
 
         let res = self.sm_tree.txn(true, |t| {
-            let r = self
-                .apply_cmd(&cmd, &t, None, SeqV::<()>::now_ms())
-                .unwrap();
+            let r = self.apply_cmd(&cmd, &t, Some(&scanned), now_ms).unwrap();
             Ok(r)
         })?;
 
@@ -81,7 +86,8 @@ impl KVApi for StateMachine {
         };
 
         let local_now_ms = SeqV::<()>::now_ms();
-        Ok(Self::unexpired(seq_v, local_now_ms))
+        let seq_v = Self::unexpired(seq_v, local_now_ms);
+        Ok(self.lease_alive_opt(seq_v, local_now_ms)?)
     }
 
     async fn mget_kv(&self, keys: &[String]) -> Result<MGetKVReply, KVAppError> {
@@ -93,6 +99,7 @@ impl KVApi for StateMachine {
         for x in keys.iter() {
             let v = kvs.get(x)?;
             let v = Self::unexpired_opt(v, local_now_ms);
+            let v = self.lease_alive_opt(v, local_now_ms)?;
             res.push(v)
         }
 
@@ -106,17 +113,75 @@ impl KVApi for StateMachine {
         let kvs = self.kvs();
         let kv_pairs = kvs.scan_prefix(&prefix.to_string())?;
 
-        let x = kv_pairs.into_iter();
+        let local_now_ms = SeqV::<()>::now_ms();
+
+        let mut res = vec![];
+        for (k, v) in kv_pairs {
+            // Skip expired
+            let v = Self::unexpired(v, local_now_ms);
+            if let Some(v) = self.lease_alive_opt(v, local_now_ms)? {
+                res.push((k, v));
+            }
+        }
+
+        Ok(res)
+    }
+
+    async fn range_kv(&self, req: RangeKVReq) -> Result<RangeKVReply, KVAppError> {
+        if let Some(end) = &req.end {
+            if req.start >= *end {
+                return Ok(RangeKVReply::default());
+            }
+        }
 
+        let end = match &req.end {
+            Some(end) => Bound::Excluded(end.clone()),
+            None => Bound::Unbounded,
+        };
+
+        let it = self
+            .kvs()
+            .range((Bound::Included(req.start.clone()), end))?;
+        let it = it.map(|item| -> Result<_, MetaStorageError> { Ok(item?.kv()?) });
+
+        let reply = if req.reverse {
+            self.collect_range(it.rev(), &req)?
+        } else {
+            self.collect_range(it, &req)?
+        };
+        Ok(reply)
+    }
+}
+
+impl StateMachine {
+    /// Collect at most `req.limit` alive entries from `it` and tell if there are more.
+    fn collect_range(
+        &self,
+        it: impl Iterator<Item = Result<(String, SeqV<Vec<u8>>), MetaStorageError>>,
+        req: &RangeKVReq,
+    ) -> Result<RangeKVReply, MetaStorageError> {
         let local_now_ms = SeqV::<()>::now_ms();
 
-        // Convert expired to None
-        let x = x.map(|(k, v)| (k, Self::unexpired(v, local_now_ms)));
-        // Remove None
-        let x = x.filter(|(_k, v)| v.is_some());
-        // Extract from an Option
-        let x = x.map(|(k, v)| (k, v.unwrap()));
+        let mut kvs = vec![];
+        for item in it {
+            let (k, v) = item?;
+
+            let v = Self::unexpired(v, local_now_ms);
+            let mut v = match self.lease_alive_opt(v, local_now_ms)? {
+                None => continue,
+                Some(v) => v,
+            };
+
+            if req.limit > 0 && kvs.len() as u64 == req.limit {
+                return Ok(RangeKVReply { kvs, more: true });
+            }
+
+            if req.keys_only {
+                v.data = vec![];
+            }
+            kvs.push((k, v));
+        }
 
-        Ok(x.collect())
+        Ok(RangeKVReply { kvs, more: false })
     }
 }
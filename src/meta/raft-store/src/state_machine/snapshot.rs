@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::path::PathBuf;
+
 use common_meta_sled_store::openraft;
 use openraft::SnapshotMeta;
 use serde::Deserialize;
@@ -22,6 +24,6 @@ use serde::Serialize;
 pub struct Snapshot {
     pub meta: SnapshotMeta,
 
-    /// The data of the state machine at the time of this snapshot.
-    pub data: Vec<u8>,
+    /// The snapshot file of the state machine at the time of this snapshot.
+    pub path: PathBuf,
 }
@@ -0,0 +1,290 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The on-disk format of a state machine snapshot.
+//!
+//! A snapshot file is written while iterating the state machine tree, and read back
+//! one key-value at a time, so that neither side holds the whole state machine in memory:
+//!
+//! ```text
+//! | magic: 8 bytes |
+//! | key_len: u32 | key | value_len: u32 | value |   // repeated for every key-value
+//! | END_MARK: u32 | kv_count: u64 | crc32: u32 |    // crc32 of all the bytes before it
+//! ```
+//!
+//! All integers are little endian.
+
+use std::io;
+use std::io::Read;
+use std::io::Write;
+
+use common_meta_stoerr::MetaStorageError;
+use common_meta_types::anyerror::AnyError;
+
+/// Identifies a snapshot file and its format version.
+pub const SNAPSHOT_FILE_MAGIC: &[u8; 8] = b"DMSNAP01";
+
+/// A key length that never appears in a valid record, it starts the footer.
+const END_MARK: u32 = u32::MAX;
+
+/// Summary of a snapshot file, returned when it is finished or verified.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SnapshotFileSummary {
+    /// Number of key-values in the snapshot.
+    pub kv_count: u64,
+
+    /// crc32 of the file content before the checksum itself.
+    pub checksum: u32,
+
+    /// Size of the file in bytes.
+    pub size: u64,
+}
+
+fn snapshot_err(e: impl std::error::Error + 'static, ctx: &str) -> MetaStorageError {
+    MetaStorageError::SnapshotError(AnyError::new(&e).add_context(|| ctx.to_string()))
+}
+
+/// Streams key-values into a snapshot file.
+pub struct SnapshotFileWriter<W: Write> {
+    inner: W,
+    hasher: crc32fast::Hasher,
+    kv_count: u64,
+    size: u64,
+}
+
+impl<W: Write> SnapshotFileWriter<W> {
+    pub fn new(inner: W) -> Result<Self, MetaStorageError> {
+        let mut w = Self {
+            inner,
+            hasher: crc32fast::Hasher::new(),
+            kv_count: 0,
+            size: 0,
+        };
+        w.write(SNAPSHOT_FILE_MAGIC)?;
+        Ok(w)
+    }
+
+    fn write(&mut self, buf: &[u8]) -> Result<(), MetaStorageError> {
+        self.inner
+            .write_all(buf)
+            .map_err(|e| snapshot_err(e, "write snapshot file"))?;
+        self.hasher.update(buf);
+        self.size += buf.len() as u64;
+        Ok(())
+    }
+
+    pub fn append(&mut self, key: &[u8], value: &[u8]) -> Result<(), MetaStorageError> {
+        for field in [key, value] {
+            let len = u32::try_from(field.len())
+                .ok()
+                .filter(|l| *l != END_MARK)
+                .ok_or_else(|| {
+                    MetaStorageError::SnapshotError(AnyError::error(format!(
+                        "snapshot record is too large: {} bytes",
+                        field.len()
+                    )))
+                })?;
+            self.write(&len.to_le_bytes())?;
+            self.write(field)?;
+        }
+        self.kv_count += 1;
+        Ok(())
+    }
+
+    /// Append key-values one by one as they are pulled from `kvs`, without collecting them.
+    pub fn append_all<I, K, V>(&mut self, kvs: I) -> Result<(), MetaStorageError>
+    where
+        I: IntoIterator<Item = Result<(K, V), MetaStorageError>>,
+        K: AsRef<[u8]>,
+        V: AsRef<[u8]>,
+    {
+        for kv in kvs {
+            let (k, v) = kv?;
+            self.append(k.as_ref(), v.as_ref())?;
+        }
+        Ok(())
+    }
+
+    /// Write the footer and flush. The caller is responsible for syncing the returned writer.
+    pub fn finish(mut self) -> Result<(W, SnapshotFileSummary), MetaStorageError> {
+        self.write(&END_MARK.to_le_bytes())?;
+        self.write(&self.kv_count.to_le_bytes())?;
+
+        let checksum = self.hasher.clone().finalize();
+        self.inner
+            .write_all(&checksum.to_le_bytes())
+            .and_then(|_| self.inner.flush())
+            .map_err(|e| snapshot_err(e, "finish snapshot file"))?;
+
+        let summary = SnapshotFileSummary {
+            kv_count: self.kv_count,
+            checksum,
+            size: self.size + 4,
+        };
+        Ok((self.inner, summary))
+    }
+}
+
+/// Iterates the key-values of a snapshot file.
+///
+/// The checksum is verified when the footer is reached: the last item is an error if the
+/// file is corrupted or truncated. Use [`verify_snapshot_file`] to check a file before
+/// applying any of it.
+pub struct SnapshotFileReader<R: Read> {
+    inner: R,
+    hasher: crc32fast::Hasher,
+    kv_count: u64,
+    size: u64,
+    summary: Option<SnapshotFileSummary>,
+}
+
+impl<R: Read> SnapshotFileReader<R> {
+    pub fn new(inner: R) -> Result<Self, MetaStorageError> {
+        let mut r = Self {
+            inner,
+            hasher: crc32fast::Hasher::new(),
+            kv_count: 0,
+            size: 0,
+            summary: None,
+        };
+
+        let mut magic = [0u8; 8];
+        r.read_exact(&mut magic)?;
+        if &magic != SNAPSHOT_FILE_MAGIC {
+            return Err(MetaStorageError::SnapshotError(AnyError::error(format!(
+                "invalid snapshot file magic: {:?}",
+                magic
+            ))));
+        }
+        Ok(r)
+    }
+
+    /// The summary of the file, available after all key-values are read.
+    pub fn summary(&self) -> Option<SnapshotFileSummary> {
+        self.summary
+    }
+
+    fn read_exact(&mut self, buf: &mut [u8]) -> Result<(), MetaStorageError> {
+        self.read_exact_unhashed(buf)?;
+        self.hasher.update(buf);
+        Ok(())
+    }
+
+    fn read_exact_unhashed(&mut self, buf: &mut [u8]) -> Result<(), MetaStorageError> {
+        self.inner.read_exact(buf).map_err(|e| {
+            if e.kind() == io::ErrorKind::UnexpectedEof {
+                MetaStorageError::SnapshotError(AnyError::error(format!(
+                    "snapshot file is truncated after {} bytes",
+                    self.size
+                )))
+            } else {
+                snapshot_err(e, "read snapshot file")
+            }
+        })?;
+        self.size += buf.len() as u64;
+        Ok(())
+    }
+
+    fn read_u32(&mut self) -> Result<u32, MetaStorageError> {
+        let mut buf = [0u8; 4];
+        self.read_exact(&mut buf)?;
+        Ok(u32::from_le_bytes(buf))
+    }
+
+    /// Read a key or value. The buffer grows with the data actually read, so that a
+    /// corrupted length does not allocate a huge buffer upfront.
+    fn read_field(&mut self, len: u32) -> Result<Vec<u8>, MetaStorageError> {
+        let mut buf = Vec::new();
+        (&mut self.inner)
+            .take(len as u64)
+            .read_to_end(&mut buf)
+            .map_err(|e| snapshot_err(e, "read snapshot file"))?;
+        self.size += buf.len() as u64;
+
+        if buf.len() != len as usize {
+            return Err(MetaStorageError::SnapshotError(AnyError::error(format!(
+                "snapshot file is truncated after {} bytes",
+                self.size
+            ))));
+        }
+        self.hasher.update(&buf);
+        Ok(buf)
+    }
+
+    fn read_kv(&mut self) -> Result<Option<(Vec<u8>, Vec<u8>)>, MetaStorageError> {
+        let key_len = self.read_u32()?;
+        if key_len == END_MARK {
+            self.read_footer()?;
+            return Ok(None);
+        }
+        let key = self.read_field(key_len)?;
+        let value_len = self.read_u32()?;
+        let value = self.read_field(value_len)?;
+        self.kv_count += 1;
+        Ok(Some((key, value)))
+    }
+
+    fn read_footer(&mut self) -> Result<(), MetaStorageError> {
+        let mut buf = [0u8; 8];
+        self.read_exact(&mut buf)?;
+        let kv_count = u64::from_le_bytes(buf);
+
+        let want = self.hasher.clone().finalize();
+        let mut buf = [0u8; 4];
+        self.read_exact_unhashed(&mut buf)?;
+        let checksum = u32::from_le_bytes(buf);
+
+        if checksum != want || kv_count != self.kv_count {
+            return Err(MetaStorageError::SnapshotError(AnyError::error(format!(
+                "snapshot file checksum mismatch: expect crc32 {:08x} with {} kvs, got {:08x} with {} kvs",
+                checksum, kv_count, want, self.kv_count
+            ))));
+        }
+
+        self.summary = Some(SnapshotFileSummary {
+            kv_count,
+            checksum,
+            size: self.size,
+        });
+        Ok(())
+    }
+}
+
+impl<R: Read> Iterator for SnapshotFileReader<R> {
+    type Item = Result<(Vec<u8>, Vec<u8>), MetaStorageError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.summary.is_some() {
+            return None;
+        }
+        match self.read_kv() {
+            Ok(Some(kv)) => Some(Ok(kv)),
+            Ok(None) => None,
+            Err(e) => {
+                // Stop iterating after an error.
+                self.summary = Some(SnapshotFileSummary::default());
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+/// Read through a snapshot file and check its checksum.
+pub fn verify_snapshot_file<R: Read>(r: R) -> Result<SnapshotFileSummary, MetaStorageError> {
+    let mut reader = SnapshotFileReader::new(r)?;
+    for kv in &mut reader {
+        kv?;
+    }
+    Ok(reader.summary().unwrap_or_default())
+}
@@ -0,0 +1,172 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use common_meta_stoerr::MetaStorageError;
+use common_meta_types::anyerror::AnyError;
+use tracing::info;
+use tracing::warn;
+
+use crate::config::RaftConfig;
+use crate::state_machine::MetaSnapshotId;
+
+const SNAPSHOT_SUFFIX: &str = ".snap";
+const TEMP_SUFFIX: &str = ".tmp";
+
+/// Distinguishes temp files created in the same nanosecond.
+static TEMP_SEQ: AtomicU64 = AtomicU64::new(0);
+
+/// Manages the snapshot files of a raft node in `<raft_dir>/<tree_prefix>snapshot/`.
+///
+/// A snapshot is named after its snapshot id, e.g. `1-9-1666000000.snap`. It is written
+/// or received into a temp file first and renamed when complete, so a file with the
+/// snapshot suffix is always a finished one.
+#[derive(Debug, Clone)]
+pub struct SnapshotStore {
+    dir: PathBuf,
+
+    /// The number of snapshot files to keep, older ones are removed.
+    max_files: usize,
+
+    sync: bool,
+}
+
+fn io_err(e: std::io::Error, ctx: impl FnOnce() -> String) -> MetaStorageError {
+    MetaStorageError::SnapshotError(AnyError::new(&e).add_context(ctx))
+}
+
+impl SnapshotStore {
+    pub fn open(config: &RaftConfig) -> Result<SnapshotStore, MetaStorageError> {
+        let dir = Path::new(&config.raft_dir).join(config.tree_name("snapshot"));
+        fs::create_dir_all(&dir)
+            .map_err(|e| io_err(e, || format!("create snapshot dir {}", dir.display())))?;
+
+        let store = SnapshotStore {
+            dir,
+            max_files: std::cmp::max(config.max_snapshot_files_to_keep, 1) as usize,
+            sync: config.is_sync(),
+        };
+
+        // Temp files are left by an interrupted build or transfer.
+        for path in store.temp_files()? {
+            warn!("remove unfinished snapshot file: {}", path.display());
+            fs::remove_file(&path)
+                .map_err(|e| io_err(e, || format!("remove {}", path.display())))?;
+        }
+
+        Ok(store)
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn snapshot_path(&self, snapshot_id: &str) -> PathBuf {
+        self.dir.join(format!("{}{}", snapshot_id, SNAPSHOT_SUFFIX))
+    }
+
+    /// A new unique path to write or receive a snapshot into.
+    pub fn new_temp_path(&self) -> PathBuf {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let seq = TEMP_SEQ.fetch_add(1, Ordering::Relaxed);
+        self.dir.join(format!("{}-{}{}", nanos, seq, TEMP_SUFFIX))
+    }
+
+    /// Rename a finished temp file to the snapshot file of `snapshot_id`.
+    pub fn commit(&self, temp: &Path, snapshot_id: &str) -> Result<PathBuf, MetaStorageError> {
+        let path = self.snapshot_path(snapshot_id);
+        fs::rename(temp, &path).map_err(|e| {
+            io_err(e, || {
+                format!("rename {} to {}", temp.display(), path.display())
+            })
+        })?;
+
+        if self.sync {
+            // Persist the rename.
+            fs::File::open(&self.dir)
+                .and_then(|d| d.sync_all())
+                .map_err(|e| io_err(e, || format!("sync dir {}", self.dir.display())))?;
+        }
+        Ok(path)
+    }
+
+    /// Returns all finished snapshots, the oldest first.
+    pub fn list(&self) -> Result<Vec<(MetaSnapshotId, PathBuf)>, MetaStorageError> {
+        let mut snapshots = vec![];
+        for path in self.files()? {
+            let name = path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .unwrap_or_default();
+            let id = match name.strip_suffix(SNAPSHOT_SUFFIX) {
+                Some(id) => id,
+                None => continue,
+            };
+            match MetaSnapshotId::from_str(id) {
+                Ok(snapshot_id) => snapshots.push((snapshot_id, path)),
+                Err(e) => warn!("ignore snapshot file {}: {}", path.display(), e),
+            }
+        }
+        snapshots.sort_by_key(|(id, _)| (id.last_applied, id.uniq));
+        Ok(snapshots)
+    }
+
+    pub fn latest(&self) -> Result<Option<(MetaSnapshotId, PathBuf)>, MetaStorageError> {
+        Ok(self.list()?.pop())
+    }
+
+    /// Remove the oldest snapshot files so that at most `max_files` are kept.
+    pub fn clean(&self) -> Result<(), MetaStorageError> {
+        let snapshots = self.list()?;
+        let n = snapshots.len().saturating_sub(self.max_files);
+        for (_, path) in snapshots.into_iter().take(n) {
+            info!("remove old snapshot file: {}", path.display());
+            fs::remove_file(&path)
+                .map_err(|e| io_err(e, || format!("remove {}", path.display())))?;
+        }
+        Ok(())
+    }
+
+    fn files(&self) -> Result<Vec<PathBuf>, MetaStorageError> {
+        let entries = fs::read_dir(&self.dir)
+            .map_err(|e| io_err(e, || format!("read dir {}", self.dir.display())))?;
+
+        let mut paths = vec![];
+        for entry in entries {
+            let entry =
+                entry.map_err(|e| io_err(e, || format!("read dir {}", self.dir.display())))?;
+            paths.push(entry.path());
+        }
+        Ok(paths)
+    }
+
+    fn temp_files(&self) -> Result<Vec<PathBuf>, MetaStorageError> {
+        Ok(self
+            .files()?
+            .into_iter()
+            .filter(|p| p.to_string_lossy().ends_with(TEMP_SUFFIX))
+            .collect())
+    }
+}
@@ -77,7 +77,9 @@ pub fn snapshot_logs() -> (Vec<Entry<LogEntry>>, Vec<String>) {
         "[3, 3]:{\"Membership\":{\"log_id\":{\"term\":1,\"index\":5},\"membership\":{\"configs\":[[4,5,6]],\"all_nodes\":[4,5,6]}}}", // membership
         "[6, 97]:{\"seq\":1,\"meta\":null,\"data\":[65]}", // generic kv
         "[7, 99]:1",                                       // sequence: c
+        "[7, 99, 104, 97, 110, 103, 101, 45, 108, 111, 103]:1", // sequence: change log revision
         "[7, 103, 101, 110, 101, 114, 105, 99, 45, 107, 118]:1", // sequence: by upsertkv
+        "[14, 0, 0, 0, 0, 0, 0, 0, 1]:{\"key\":\"a\",\"prev\":null,\"current\":{\"seq\":1,\"meta\":null,\"data\":[65]}}", // change log
     ]
     .iter()
     .map(|x| x.to_string())
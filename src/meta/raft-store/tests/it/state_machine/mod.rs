@@ -19,18 +19,28 @@ use common_base::base::tokio;
 use common_meta_api::KVApi;
 use common_meta_raft_store::state_machine::testing::pretty_snapshot;
 use common_meta_raft_store::state_machine::testing::snapshot_logs;
+use common_meta_raft_store::state_machine::ChangeLogValue;
+use common_meta_raft_store::state_machine::CompactedRevision;
+use common_meta_raft_store::state_machine::ExpireKey;
+use common_meta_raft_store::state_machine::LeaseKey;
 use common_meta_raft_store::state_machine::StateMachine;
 use common_meta_sled_store::openraft;
 use common_meta_types::AppliedState;
 use common_meta_types::Change;
 use common_meta_types::Cmd;
 use common_meta_types::KVMeta;
+use common_meta_types::Lease;
 use common_meta_types::LogEntry;
 use common_meta_types::MatchSeq;
 use common_meta_types::Operation;
 use common_meta_types::SeqV;
+use common_meta_types::TenantQuota;
+use common_meta_types::TenantQuotaExceeded;
+use common_meta_types::TenantQuotaWriteDenied;
+use common_meta_types::TenantUsage;
 use common_meta_types::UpsertKV;
 use common_meta_types::With;
+use common_meta_types::TENANT_QUOTA_KEY_PREFIX;
 use openraft::raft::Entry;
 use openraft::raft::EntryPayload;
 use openraft::LogId;
@@ -41,6 +51,7 @@ use crate::init_raft_store_ut;
 use crate::testing::new_raft_test_context;
 
 mod schema_api_impl;
+mod snapshot_file;
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_state_machine_apply_non_dup_incr_seq() -> anyhow::Result<()> {
@@ -142,7 +153,11 @@ async fn test_state_machine_apply_non_dup_generic_kv_upsert_get() -> anyhow::Res
         prev: Option<(u64, &'static str)>,
         result: Option<(u64, &'static str)>,
     ) -> T {
-        let m = meta.map(|x| KVMeta { expire_at: Some(x) });
+        let m = meta.map(|x| KVMeta {
+            expire_at: Some(x),
+            lease: None,
+            tenant: None,
+        });
         T {
             key: name.to_string(),
             seq,
@@ -284,6 +299,8 @@ async fn test_state_machine_apply_non_dup_generic_kv_value_meta() -> anyhow::Res
                     value: Operation::AsIs,
                     value_meta: Some(KVMeta {
                         expire_at: Some(now + 10),
+                        lease: None,
+                        tenant: None,
                     }),
                 }),
                 &t,
@@ -311,6 +328,8 @@ async fn test_state_machine_apply_non_dup_generic_kv_value_meta() -> anyhow::Res
                     value: Operation::Update(b"value_meta_bar".to_vec()),
                     value_meta: Some(KVMeta {
                         expire_at: Some(now + 10),
+                        lease: None,
+                        tenant: None,
                     }),
                 }),
                 &t,
@@ -330,6 +349,8 @@ async fn test_state_machine_apply_non_dup_generic_kv_value_meta() -> anyhow::Res
                     value: Operation::AsIs,
                     value_meta: Some(KVMeta {
                         expire_at: Some(now + 20),
+                        lease: None,
+                        tenant: None,
                     }),
                 }),
                 &t,
@@ -348,7 +369,9 @@ async fn test_state_machine_apply_non_dup_generic_kv_value_meta() -> anyhow::Res
         SeqV {
             seq: got.seq,
             meta: Some(KVMeta {
-                expire_at: Some(now + 20)
+                expire_at: Some(now + 20),
+                lease: None,
+                tenant: None
             }),
             data: b"value_meta_bar".to_vec()
         },
@@ -437,6 +460,349 @@ async fn test_state_machine_apply_non_dup_generic_kv_delete() -> anyhow::Result<
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_state_machine_change_logs() -> anyhow::Result<()> {
+    // - Every kv change is recorded with a dense revision.
+    // - Only the last `max_change_log_to_keep` changes are kept.
+
+    let (_log_guards, ut_span) = init_raft_store_ut!();
+    let _ent = ut_span.enter();
+
+    let mut tc = new_raft_test_context();
+    tc.raft_config.max_change_log_to_keep = 2;
+    let sm = StateMachine::open(&tc.raft_config, 1).await?;
+
+    let cmds = [
+        UpsertKV::update("a", b"x"),
+        UpsertKV::update("b", b"y"),
+        UpsertKV::delete("a"),
+    ];
+    for cmd in cmds {
+        sm.sm_tree.txn(true, |t| {
+            Ok(sm
+                .apply_cmd(&Cmd::UpsertKV(cmd.clone()), &t, None, 0)
+                .unwrap())
+        })?;
+    }
+
+    assert_eq!(3, sm.last_change_revision()?);
+
+    let got = sm.change_logs_since(2)?.unwrap();
+    let want = vec![
+        (2, ChangeLogValue {
+            key: "b".to_string(),
+            prev: None,
+            current: Some(SeqV::new(2, b"y".to_vec())),
+        }),
+        (3, ChangeLogValue {
+            key: "a".to_string(),
+            prev: Some(SeqV::new(1, b"x".to_vec())),
+            current: None,
+        }),
+    ];
+    assert_eq!(want, got);
+
+    assert_eq!(
+        Vec::<(u64, ChangeLogValue)>::new(),
+        sm.change_logs_since(4)?.unwrap()
+    );
+
+    let err = sm.change_logs_since(1)?.unwrap_err();
+    assert_eq!(
+        CompactedRevision {
+            requested: 1,
+            oldest: 2
+        },
+        err
+    );
+
+    Ok(())
+}
+
+fn timed_entry(index: u64, time_ms: u64, cmd: Cmd) -> Entry<LogEntry> {
+    Entry {
+        log_id: LogId { term: 1, index },
+        payload: EntryPayload::Normal(LogEntry {
+            txid: None,
+            time_ms: Some(time_ms),
+            cmd,
+        }),
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_state_machine_lease() -> anyhow::Result<()> {
+    // - Keys attached to a lease are deleted when the lease is revoked or expired.
+    // - A key updated without the lease is no longer attached to it.
+    // - A key can not be attached to an absent lease.
+
+    let (_log_guards, ut_span) = init_raft_store_ut!();
+    let _ent = ut_span.enter();
+
+    let tc = new_raft_test_context();
+    let sm = StateMachine::open(&tc.raft_config, 1).await?;
+
+    let lease = |lease_id, expire_at_ms| {
+        AppliedState::Lease(Some(Lease {
+            lease_id,
+            ttl_ms: 100,
+            expire_at_ms,
+        }))
+    };
+    let exists =
+        |key: &str| -> anyhow::Result<bool> { Ok(sm.kvs().get(&key.to_string())?.is_some()) };
+    let attached = |lease_id| -> anyhow::Result<Vec<String>> {
+        let mut keys = vec![];
+        for item in sm.lease_keys().range(LeaseKey::range(lease_id))? {
+            keys.push(item?.key()?.key);
+        }
+        Ok(keys)
+    };
+    let expire_index = || -> anyhow::Result<Vec<ExpireKey>> {
+        let mut keys = vec![];
+        for item in sm.lease_expires().range(..)? {
+            keys.push(item?.key()?);
+        }
+        Ok(keys)
+    };
+
+    let res = sm
+        .apply(&timed_entry(1, 1000, Cmd::GrantLease { ttl_ms: 100 }))
+        .await?;
+    assert_eq!(lease(1, 1100), res);
+
+    let res = sm
+        .apply(&timed_entry(2, 1000, Cmd::GrantLease { ttl_ms: 100 }))
+        .await?;
+    assert_eq!(lease(2, 1100), res);
+
+    let upserts = [
+        UpsertKV::update("a", b"a").with_lease(1),
+        UpsertKV::update("b", b"b").with_lease(2),
+        UpsertKV::update("c", b"c").with_lease(2),
+        UpsertKV::update("c", b"c2"),
+    ];
+    for (i, upsert) in upserts.into_iter().enumerate() {
+        sm.apply(&timed_entry(3 + i as u64, 1010, Cmd::UpsertKV(upsert)))
+            .await?;
+    }
+
+    // Absent lease
+    let res = sm
+        .apply(&timed_entry(
+            7,
+            1010,
+            Cmd::UpsertKV(UpsertKV::update("d", b"d").with_lease(9)),
+        ))
+        .await?;
+    assert_eq!(AppliedState::KV(Change::new(None, None)), res);
+    assert!(!exists("d")?);
+    assert_eq!(vec!["a".to_string()], attached(1)?);
+    assert_eq!(vec!["b".to_string(), "c".to_string()], attached(2)?);
+
+    let res = sm
+        .apply(&timed_entry(8, 1050, Cmd::KeepAliveLease { lease_id: 1 }))
+        .await?;
+    assert_eq!(lease(1, 1150), res);
+    assert_eq!(
+        vec![ExpireKey::new(1100, "2"), ExpireKey::new(1150, "1")],
+        expire_index()?
+    );
+
+    let res = sm
+        .apply(&timed_entry(9, 1050, Cmd::RevokeLease { lease_id: 2 }))
+        .await?;
+    assert_eq!(lease(2, 1100), res);
+    assert!(!exists("b")?);
+    assert!(exists("c")?, "c is detached from lease 2 by the update");
+    assert!(exists("a")?);
+    assert!(attached(2)?.is_empty());
+    assert_eq!(vec![ExpireKey::new(1150, "1")], expire_index()?);
+
+    // Lease 1 is kept alive until 1150.
+    let incr = || Cmd::IncrSeq {
+        key: "foo".to_string(),
+    };
+    sm.apply(&timed_entry(10, 1120, incr())).await?;
+    assert!(exists("a")?);
+
+    sm.apply(&timed_entry(11, 1200, incr())).await?;
+    assert!(!exists("a")?);
+    assert_eq!(None, sm.leases().get(&1)?);
+    assert!(attached(1)?.is_empty());
+    assert!(expire_index()?.is_empty());
+
+    let res = sm
+        .apply(&timed_entry(12, 1200, Cmd::KeepAliveLease { lease_id: 1 }))
+        .await?;
+    assert_eq!(AppliedState::Lease(None), res);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_state_machine_tenant_quota() -> anyhow::Result<()> {
+    // - Records written on behalf of a tenant are owned and accounted by the tenant.
+    // - A write that may add data is rejected once the usage reaches a limit.
+    // - A delete is allowed when the usage reaches a limit.
+    // - A tenant can not write quota records.
+
+    let (_log_guards, ut_span) = init_raft_store_ut!();
+    let _ent = ut_span.enter();
+
+    let tc = new_raft_test_context();
+    let sm = StateMachine::open(&tc.raft_config, 1).await?;
+
+    let as_t1 = |upsert: UpsertKV| Cmd::UpsertKV(upsert).with_tenant(Some("t1".to_string()));
+    let exceeded = |quota: &str, usage, limit| {
+        AppliedState::AppError(TenantQuotaExceeded::new("t1", quota, usage, limit).into())
+    };
+    let usage = || -> anyhow::Result<TenantUsage> {
+        Ok(sm
+            .tenant_usages()
+            .get(&"t1".to_string())?
+            .unwrap_or_default())
+    };
+
+    let quota = TenantQuota {
+        max_keys: 2,
+        ..Default::default()
+    };
+    let quota_key = format!("{}/t1", TENANT_QUOTA_KEY_PREFIX);
+    let quota_value = serde_json::to_vec(&quota)?;
+    sm.apply(&timed_entry(
+        1,
+        1000,
+        Cmd::UpsertKV(UpsertKV::update(&quota_key, &quota_value)),
+    ))
+    .await?;
+
+    sm.apply(&timed_entry(2, 1000, as_t1(UpsertKV::update("a", b"aaa"))))
+        .await?;
+    sm.apply(&timed_entry(3, 1000, as_t1(UpsertKV::update("b", b"bb"))))
+        .await?;
+
+    let a = sm.kvs().get(&"a".to_string())?.unwrap();
+    assert_eq!(Some("t1".to_string()), a.meta.and_then(|m| m.tenant));
+    assert_eq!(
+        TenantUsage {
+            keys: 2,
+            value_bytes: 5,
+        },
+        usage()?
+    );
+
+    let res = sm
+        .apply(&timed_entry(4, 1000, as_t1(UpsertKV::update("c", b"c"))))
+        .await?;
+    assert_eq!(exceeded("max_keys", 2, 2), res);
+    assert_eq!(None, sm.kvs().get(&"c".to_string())?);
+
+    sm.apply(&timed_entry(5, 1000, as_t1(UpsertKV::delete("a"))))
+        .await?;
+    assert_eq!(None, sm.kvs().get(&"a".to_string())?);
+    assert_eq!(
+        TenantUsage {
+            keys: 1,
+            value_bytes: 2,
+        },
+        usage()?
+    );
+
+    // A tenant can not raise its own quota.
+    let res = sm
+        .apply(&timed_entry(
+            6,
+            2000,
+            as_t1(UpsertKV::update(&quota_key, b"{}")),
+        ))
+        .await?;
+    assert_eq!(
+        AppliedState::AppError(TenantQuotaWriteDenied::new("t1", &quota_key).into()),
+        res
+    );
+    assert_eq!(Some(quota_value), sm.kvs().get(&quota_key)?.map(|x| x.data));
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_state_machine_tenant_usage_backfill_and_expire() -> anyhow::Result<()> {
+    // - At the first write of a tenant, the records that are not owned by any tenant but have
+    //   the tenant in the key are attributed to the tenant.
+    // - An expired record is deleted and released from the usage of its tenant.
+
+    let (_log_guards, ut_span) = init_raft_store_ut!();
+    let _ent = ut_span.enter();
+
+    let tc = new_raft_test_context();
+    let sm = StateMachine::open(&tc.raft_config, 1).await?;
+
+    let as_t1 = |upsert: UpsertKV| Cmd::UpsertKV(upsert).with_tenant(Some("t1".to_string()));
+    let owner = |key: &str| -> anyhow::Result<Option<String>> {
+        let seq_v = sm.kvs().get(&key.to_string())?.unwrap();
+        Ok(seq_v.meta.and_then(|m| m.tenant))
+    };
+
+    let quota_key = format!("{}/t1", TENANT_QUOTA_KEY_PREFIX);
+    let unowned = [quota_key.as_str(), "__fd_db/t1/db1", "__fd_db/t2/db1"];
+    for (i, key) in unowned.iter().enumerate() {
+        sm.apply(&timed_entry(
+            1 + i as u64,
+            1000,
+            Cmd::UpsertKV(UpsertKV::update(key, b"{}")),
+        ))
+        .await?;
+    }
+    let seq = sm.kvs().get(&"__fd_db/t1/db1".to_string())?.unwrap().seq;
+
+    let expire_at_2s = KVMeta {
+        expire_at: Some(2),
+        ..Default::default()
+    };
+    sm.apply(&timed_entry(
+        4,
+        1000,
+        as_t1(UpsertKV::update("a", b"aaa").with(expire_at_2s)),
+    ))
+    .await?;
+
+    assert_eq!(Some("t1".to_string()), owner("__fd_db/t1/db1")?);
+    assert_eq!(
+        seq,
+        sm.kvs().get(&"__fd_db/t1/db1".to_string())?.unwrap().seq,
+        "backfill does not change seq"
+    );
+    assert_eq!(None, owner("__fd_db/t2/db1")?);
+    assert_eq!(None, owner(&quota_key)?);
+    assert_eq!(
+        Some(TenantUsage {
+            keys: 2,
+            value_bytes: 5,
+        }),
+        sm.tenant_usages().get(&"t1".to_string())?
+    );
+    assert_eq!(1, sm.expires().range(..)?.count());
+
+    // Any log applied after "a" expires deletes it.
+    sm.apply(&timed_entry(5, 3000, Cmd::IncrSeq {
+        key: "foo".to_string(),
+    }))
+    .await?;
+
+    assert_eq!(None, sm.kvs().get(&"a".to_string())?);
+    assert_eq!(0, sm.expires().range(..)?.count());
+    assert_eq!(
+        Some(TenantUsage {
+            keys: 1,
+            value_bytes: 2,
+        }),
+        sm.tenant_usages().get(&"t1".to_string())?
+    );
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_state_machine_snapshot() -> anyhow::Result<()> {
     // - Feed logs into state machine.
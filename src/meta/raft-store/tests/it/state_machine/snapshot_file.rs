@@ -0,0 +1,211 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cell::Cell;
+use std::fs;
+use std::io;
+use std::io::Write;
+use std::rc::Rc;
+
+use common_base::base::tokio;
+use common_meta_raft_store::config::RaftConfig;
+use common_meta_raft_store::state_machine::testing::pretty_snapshot;
+use common_meta_raft_store::state_machine::testing::snapshot_logs;
+use common_meta_raft_store::state_machine::verify_snapshot_file;
+use common_meta_raft_store::state_machine::SnapshotFileReader;
+use common_meta_raft_store::state_machine::SnapshotFileWriter;
+use common_meta_raft_store::state_machine::SnapshotStore;
+use common_meta_raft_store::state_machine::StateMachine;
+use common_meta_sled_store::openraft;
+use common_meta_stoerr::MetaStorageError;
+use openraft::LogId;
+use pretty_assertions::assert_eq;
+
+use crate::init_raft_store_ut;
+use crate::testing::new_raft_test_context;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_state_machine_snapshot_file() -> anyhow::Result<()> {
+    // - Feed logs into state machine.
+    // - Stream it into a snapshot file and read it back
+
+    let (_log_guards, ut_span) = init_raft_store_ut!();
+    let _ent = ut_span.enter();
+
+    let tc = new_raft_test_context();
+    let sm = StateMachine::open(&tc.raft_config, 0).await?;
+
+    let (logs, want) = snapshot_logs();
+
+    for l in logs.iter() {
+        sm.apply(l).await?;
+    }
+
+    let (data, summary, last_applied, id) = sm.build_snapshot_file(Vec::new())?;
+
+    assert_eq!(Some(LogId { term: 1, index: 9 }), last_applied);
+    assert!(id.to_string().starts_with(&format!("{}-{}-", 1, 9)));
+    assert_eq!(want.len() as u64, summary.kv_count);
+    assert_eq!(data.len() as u64, summary.size);
+
+    let mut reader = SnapshotFileReader::new(data.as_slice())?;
+    let kvs = (&mut reader)
+        .map(|kv| kv.map(|(k, v)| vec![k, v]))
+        .collect::<Result<Vec<_>, _>>()?;
+    assert_eq!(want, pretty_snapshot(&kvs));
+    assert_eq!(Some(summary), reader.summary());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_snapshot_file_corrupted() -> anyhow::Result<()> {
+    let (_log_guards, ut_span) = init_raft_store_ut!();
+    let _ent = ut_span.enter();
+
+    let tc = new_raft_test_context();
+    let sm = StateMachine::open(&tc.raft_config, 0).await?;
+
+    let (logs, _want) = snapshot_logs();
+    for l in logs.iter() {
+        sm.apply(l).await?;
+    }
+
+    let (data, summary, _, _) = sm.build_snapshot_file(Vec::new())?;
+    assert_eq!(summary, verify_snapshot_file(data.as_slice())?);
+
+    // The footer is: END_MARK: u32, kv_count: u64, crc32: u32
+    let footer = 4 + 8 + 4;
+
+    let cases = vec![
+        ("flip the checksum", data.len() - 1, "checksum mismatch"),
+        ("flip the kv count", data.len() - 5, "checksum mismatch"),
+        ("flip a value", data.len() - footer - 1, "checksum mismatch"),
+        ("flip the magic", 0, "invalid snapshot file magic"),
+    ];
+
+    for (name, pos, want) in cases {
+        let mut corrupted = data.clone();
+        corrupted[pos] ^= 0xff;
+
+        let res = verify_snapshot_file(corrupted.as_slice());
+        let err = res.unwrap_err().to_string();
+        assert!(err.contains(want), "{}: {}", name, err);
+    }
+
+    for len in [8, data.len() / 2, data.len() - 1] {
+        let res = verify_snapshot_file(&data[..len]);
+        let err = res.unwrap_err().to_string();
+        assert!(err.contains("truncated"), "truncate to {}: {}", len, err);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_snapshot_store_retention() -> anyhow::Result<()> {
+    let dir = tempfile::tempdir()?;
+
+    let config = RaftConfig {
+        raft_dir: dir.path().to_string_lossy().to_string(),
+        max_snapshot_files_to_keep: 2,
+        ..Default::default()
+    };
+
+    let store = SnapshotStore::open(&config)?;
+
+    let ids = ["1-5-100", "1-9-100", "--100", "1-9-101", "2-3-100"];
+    for id in ids {
+        let temp = store.new_temp_path();
+        fs::write(&temp, id)?;
+        store.commit(&temp, id)?;
+    }
+
+    let listed = store
+        .list()?
+        .into_iter()
+        .map(|(id, _)| id.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        vec!["--100", "1-5-100", "1-9-100", "1-9-101", "2-3-100"],
+        listed
+    );
+
+    store.clean()?;
+
+    let listed = store
+        .list()?
+        .into_iter()
+        .map(|(id, _)| id.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(vec!["1-9-101", "2-3-100"], listed);
+
+    let (latest, path) = store.latest()?.unwrap();
+    assert_eq!("2-3-100", latest.to_string());
+    assert_eq!("2-3-100", fs::read_to_string(path)?);
+
+    // Unfinished files are removed when reopened.
+    let temp = store.new_temp_path();
+    fs::write(&temp, "unfinished")?;
+
+    let store = SnapshotStore::open(&config)?;
+    assert!(!temp.exists());
+    assert_eq!(2, fs::read_dir(store.dir())?.count());
+
+    Ok(())
+}
+
+/// Counts the bytes written into it.
+struct CountingWriter(Rc<Cell<usize>>);
+
+impl Write for CountingWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.set(self.0.get() + buf.len());
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn test_snapshot_file_writer_streams_kvs() -> anyhow::Result<()> {
+    // Every record must be written before the next one is pulled,
+    // i.e., the key values are never collected in memory before writing.
+
+    let written = Rc::new(Cell::new(0));
+    let mut writer = SnapshotFileWriter::new(CountingWriter(written.clone()))?;
+
+    let mut written_before_pull = written.get();
+    let kvs = (0..10u8).map(|i| {
+        if i > 0 {
+            assert!(
+                written.get() > written_before_pull,
+                "kv {} is pulled before kv {} is written",
+                i,
+                i - 1
+            );
+        }
+        written_before_pull = written.get();
+        Ok::<_, MetaStorageError>((vec![i], vec![i; 3]))
+    });
+    writer.append_all(kvs)?;
+
+    let (_, summary) = writer.finish()?;
+    assert_eq!(10, summary.kv_count);
+    assert_eq!(written.get() as u64, summary.size);
+
+    Ok(())
+}
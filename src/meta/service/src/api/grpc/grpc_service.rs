@@ -23,18 +23,35 @@ use common_grpc::GrpcClaim;
 use common_grpc::GrpcToken;
 use common_meta_api::KVApi;
 use common_meta_client::MetaGrpcReq;
+use common_meta_client::MAX_READ_STALENESS_KEY;
+use common_meta_client::TENANT_KEY;
 use common_meta_types::protobuf::meta_service_server::MetaService;
 use common_meta_types::protobuf::ClientInfo;
 use common_meta_types::protobuf::Empty;
 use common_meta_types::protobuf::ExportedChunk;
 use common_meta_types::protobuf::HandshakeRequest;
 use common_meta_types::protobuf::HandshakeResponse;
+use common_meta_types::protobuf::KvRangeItem;
+use common_meta_types::protobuf::KvRangeRequest;
+use common_meta_types::protobuf::LeaseGrantRequest;
+use common_meta_types::protobuf::LeaseKeepAliveRequest;
+use common_meta_types::protobuf::LeaseReply;
+use common_meta_types::protobuf::LeaseRevokeRequest;
 use common_meta_types::protobuf::MemberListReply;
 use common_meta_types::protobuf::MemberListRequest;
 use common_meta_types::protobuf::RaftReply;
 use common_meta_types::protobuf::RaftRequest;
 use common_meta_types::protobuf::WatchRequest;
 use common_meta_types::protobuf::WatchResponse;
+use common_meta_types::AppliedState;
+use common_meta_types::Cmd;
+use common_meta_types::GetKVReply;
+use common_meta_types::KVAppError;
+use common_meta_types::ListKVReply;
+use common_meta_types::LogEntry;
+use common_meta_types::MGetKVReply;
+use common_meta_types::RangeKVReply;
+use common_meta_types::RangeKVReq;
 use common_meta_types::TxnReply;
 use common_meta_types::TxnRequest;
 use common_metrics::counter::Count;
@@ -51,6 +68,7 @@ use tracing::info;
 
 use crate::meta_service::meta_service_impl::GrpcStream;
 use crate::meta_service::MetaNode;
+use crate::meta_service::ReadConsistency;
 use crate::metrics::network_metrics;
 use crate::metrics::RequestInFlight;
 use crate::version::from_digit_ver;
@@ -58,6 +76,9 @@ use crate::version::to_digit_ver;
 use crate::version::METASRV_SEMVER;
 use crate::version::MIN_METACLI_SEMVER;
 
+/// The number of entries a `KvRange` stream reads from the state machine at a time.
+const KV_RANGE_PAGE_SIZE: u64 = 1000;
+
 pub struct MetaServiceImpl {
     token: GrpcToken,
     pub(crate) meta_node: Arc<MetaNode>,
@@ -71,7 +92,21 @@ impl MetaServiceImpl {
         }
     }
 
+    /// Refuse client requests if this node is in maintenance,
+    /// `Unavailable` lets a client retry with another node.
+    fn check_serving(&self) -> Result<(), Status> {
+        if self.meta_node.is_maintenance() {
+            return Err(Status::unavailable(format!(
+                "meta node {} is in maintenance",
+                self.meta_node.sto.id
+            )));
+        }
+        Ok(())
+    }
+
     fn check_token(&self, metadata: &MetadataMap) -> Result<GrpcClaim, Status> {
+        self.check_serving()?;
+
         let token = metadata
             .get_bin("auth-token-bin")
             .and_then(|v| v.to_bytes().ok())
@@ -84,8 +119,48 @@ impl MetaServiceImpl {
         Ok(claim)
     }
 
-    async fn execute_txn(&self, req: TxnRequest) -> TxnReply {
-        let ret = self.meta_node.transaction(req).await;
+    /// Get the consistency a client requires for reads from the `max-read-staleness-ms` header.
+    ///
+    /// Reads are linearizable if the header is absent.
+    fn read_consistency(metadata: &MetadataMap) -> Result<ReadConsistency, Status> {
+        let v = match metadata.get(MAX_READ_STALENESS_KEY) {
+            None => return Ok(ReadConsistency::Linearizable),
+            Some(v) => v,
+        };
+
+        let ms = v
+            .to_str()
+            .ok()
+            .and_then(|x| x.parse::<u64>().ok())
+            .ok_or_else(|| {
+                Status::invalid_argument(format!("invalid {}: {:?}", MAX_READ_STALENESS_KEY, v))
+            })?;
+
+        Ok(ReadConsistency::from_max_staleness_ms(ms))
+    }
+
+    /// Get the tenant a client writes on behalf of from the `tenant-bin` header of a handshake.
+    ///
+    /// The tenant is bound to the token of the session, a request can not write on behalf of
+    /// another tenant, nor opt out of its tenant.
+    /// A session without a tenant is not subject to any tenant quota, it is meant for admins.
+    fn handshake_tenant(metadata: &MetadataMap) -> Result<Option<String>, Status> {
+        let v = match metadata.get_bin(TENANT_KEY) {
+            None => return Ok(None),
+            Some(v) => v,
+        };
+
+        let tenant = v
+            .to_bytes()
+            .ok()
+            .and_then(|b| String::from_utf8(b.to_vec()).ok())
+            .ok_or_else(|| Status::invalid_argument(format!("invalid {}: {:?}", TENANT_KEY, v)))?;
+
+        Ok(Some(tenant))
+    }
+
+    async fn execute_txn(&self, tenant: Option<String>, req: TxnRequest) -> TxnReply {
+        let ret = self.meta_node.transaction_as(tenant, req).await;
         network_metrics::incr_request_result(ret.is_ok());
 
         match ret {
@@ -97,6 +172,77 @@ impl MetaServiceImpl {
             },
         }
     }
+
+    /// Read `req` page by page and send the entries to `tx`,
+    /// until the range or `req.limit` is exhausted or the receiver is dropped.
+    async fn send_kv_range(
+        meta_node: Arc<MetaNode>,
+        consistency: ReadConsistency,
+        req: RangeKVReq,
+        tx: mpsc::Sender<Result<KvRangeItem, Status>>,
+    ) {
+        let limit = req.limit;
+        let mut sent = 0;
+        let mut page_req = Some(req);
+
+        while let Some(mut req) = page_req {
+            req.limit = if limit == 0 {
+                KV_RANGE_PAGE_SIZE
+            } else {
+                std::cmp::min(KV_RANGE_PAGE_SIZE, limit - sent)
+            };
+
+            let reply: RangeKVReply = match meta_node.read_with(consistency, req.clone()).await {
+                Ok(x) => x,
+                Err(e) => {
+                    let _ = tx.send(Err(Status::internal(e.to_string()))).await;
+                    return;
+                }
+            };
+
+            sent += reply.kvs.len() as u64;
+            page_req = if limit > 0 && sent >= limit {
+                None
+            } else {
+                req.next_page(&reply)
+            };
+
+            for (key, value) in reply.kvs {
+                let item = KvRangeItem {
+                    key,
+                    value: Some(value.into()),
+                };
+                if tx.send(Ok(item)).await.is_err() {
+                    info!("KvRange stream receiver is dropped");
+                    return;
+                }
+            }
+        }
+    }
+
+    /// Write a lease command through raft.
+    ///
+    /// A not-found error is returned if the lease does not exist.
+    async fn execute_lease_cmd(&self, cmd: Cmd) -> Result<LeaseReply, Status> {
+        let ent = LogEntry {
+            txid: None,
+            time_ms: None,
+            cmd: cmd.clone(),
+        };
+        let ret = self.meta_node.write(ent).await;
+        network_metrics::incr_request_result(ret.is_ok());
+
+        match ret.map_err(|e| Status::internal(e.to_string()))? {
+            AppliedState::Lease(Some(lease)) => Ok(lease.into()),
+            AppliedState::Lease(None) => Err(Status::not_found(format!(
+                "lease not found when applying: {}",
+                cmd
+            ))),
+            _ => {
+                unreachable!("expect type {}", "AppliedState::Lease")
+            }
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -110,6 +256,10 @@ impl MetaService for MetaServiceImpl {
         &self,
         request: Request<Streaming<HandshakeRequest>>,
     ) -> Result<Response<Self::HandshakeStream>, Status> {
+        self.check_serving()?;
+
+        let tenant = Self::handshake_tenant(request.metadata())?;
+
         let req = request
             .into_inner()
             .next()
@@ -138,6 +288,7 @@ impl MetaService for MetaServiceImpl {
         if auth.username == user {
             let claim = GrpcClaim {
                 username: user.to_string(),
+                tenant,
             };
             let token = self
                 .token
@@ -169,29 +320,39 @@ impl MetaService for MetaServiceImpl {
     async fn kv_api(&self, r: Request<RaftRequest>) -> Result<Response<RaftReply>, Status> {
         let _guard = RequestInFlight::guard();
 
-        self.check_token(r.metadata())?;
+        let claim = self.check_token(r.metadata())?;
         common_tracing::extract_remote_span_as_parent(&r);
         network_metrics::incr_recv_bytes(r.get_ref().encoded_len() as u64);
 
+        let consistency = Self::read_consistency(r.metadata())?;
+        let tenant = claim.tenant;
         let req: MetaGrpcReq = r.try_into()?;
-        info!("Received MetaGrpcReq: {:?}", req);
+        info!("Received MetaGrpcReq: {:?}, {:?}", req, consistency);
 
         let m = &self.meta_node;
         let reply = match req {
             MetaGrpcReq::UpsertKV(a) => {
-                let res = m.upsert_kv(a).await;
+                let res = m.upsert_kv_as(tenant, a).await;
                 RaftReply::from(res)
             }
             MetaGrpcReq::GetKV(a) => {
-                let res = m.get_kv(&a.key).await;
+                let res: Result<GetKVReply, KVAppError> =
+                    m.read_with(consistency, a).await.map_err(KVAppError::from);
                 RaftReply::from(res)
             }
             MetaGrpcReq::MGetKV(a) => {
-                let res = m.mget_kv(&a.keys).await;
+                let res: Result<MGetKVReply, KVAppError> =
+                    m.read_with(consistency, a).await.map_err(KVAppError::from);
                 RaftReply::from(res)
             }
             MetaGrpcReq::ListKV(a) => {
-                let res = m.prefix_list_kv(&a.prefix).await;
+                let res: Result<ListKVReply, KVAppError> =
+                    m.read_with(consistency, a).await.map_err(KVAppError::from);
+                RaftReply::from(res)
+            }
+            MetaGrpcReq::RangeKV(a) => {
+                let res: Result<RangeKVReply, KVAppError> =
+                    m.read_with(consistency, a).await.map_err(KVAppError::from);
                 RaftReply::from(res)
             }
         };
@@ -232,35 +393,119 @@ impl MetaService for MetaServiceImpl {
         &self,
         request: Request<WatchRequest>,
     ) -> Result<Response<Self::WatchStream>, Status> {
+        self.check_serving()?;
+
         let (tx, rx) = mpsc::channel(4);
 
         let meta_node = &self.meta_node;
-        meta_node.create_watcher_stream(request.into_inner(), tx);
+        meta_node
+            .create_watcher_stream(request.into_inner(), tx)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?
+            .map_err(|compacted| Status::out_of_range(compacted.to_string()))?;
 
         let output_stream = tokio_stream::wrappers::ReceiverStream::new(rx);
         Ok(Response::new(Box::pin(output_stream) as Self::WatchStream))
     }
 
+    type KvRangeStream =
+        Pin<Box<dyn Stream<Item = Result<KvRangeItem, tonic::Status>> + Send + Sync + 'static>>;
+
+    #[tracing::instrument(level = "debug", skip(self))]
+    async fn kv_range(
+        &self,
+        request: Request<KvRangeRequest>,
+    ) -> Result<Response<Self::KvRangeStream>, Status> {
+        self.check_token(request.metadata())?;
+        let _guard = RequestInFlight::guard();
+
+        let consistency = Self::read_consistency(request.metadata())?;
+        let req = RangeKVReq::from(request.into_inner());
+        let (tx, rx) = mpsc::channel(16);
+
+        let meta_node = self.meta_node.clone();
+        common_base::base::tokio::spawn(Self::send_kv_range(meta_node, consistency, req, tx));
+
+        let output_stream = tokio_stream::wrappers::ReceiverStream::new(rx);
+        Ok(Response::new(Box::pin(output_stream) as Self::KvRangeStream))
+    }
+
     async fn transaction(
         &self,
         request: Request<TxnRequest>,
     ) -> Result<Response<TxnReply>, Status> {
-        self.check_token(request.metadata())?;
+        let claim = self.check_token(request.metadata())?;
         network_metrics::incr_recv_bytes(request.get_ref().encoded_len() as u64);
         let _guard = RequestInFlight::guard();
 
         common_tracing::extract_remote_span_as_parent(&request);
 
+        let tenant = claim.tenant;
         let request = request.into_inner();
 
         info!("Receive txn_request: {}", request);
 
-        let body = self.execute_txn(request).await;
+        let body = self.execute_txn(tenant, request).await;
         network_metrics::incr_sent_bytes(body.encoded_len() as u64);
 
         Ok(Response::new(body))
     }
 
+    async fn lease_grant(
+        &self,
+        request: Request<LeaseGrantRequest>,
+    ) -> Result<Response<LeaseReply>, Status> {
+        self.check_token(request.metadata())?;
+        network_metrics::incr_recv_bytes(request.get_ref().encoded_len() as u64);
+        let _guard = RequestInFlight::guard();
+
+        let req = request.into_inner();
+        let reply = self
+            .execute_lease_cmd(Cmd::GrantLease { ttl_ms: req.ttl_ms })
+            .await?;
+        network_metrics::incr_sent_bytes(reply.encoded_len() as u64);
+
+        Ok(Response::new(reply))
+    }
+
+    async fn lease_keep_alive(
+        &self,
+        request: Request<LeaseKeepAliveRequest>,
+    ) -> Result<Response<LeaseReply>, Status> {
+        self.check_token(request.metadata())?;
+        network_metrics::incr_recv_bytes(request.get_ref().encoded_len() as u64);
+        let _guard = RequestInFlight::guard();
+
+        let req = request.into_inner();
+        let reply = self
+            .execute_lease_cmd(Cmd::KeepAliveLease {
+                lease_id: req.lease_id,
+            })
+            .await?;
+        network_metrics::incr_sent_bytes(reply.encoded_len() as u64);
+
+        Ok(Response::new(reply))
+    }
+
+    async fn lease_revoke(
+        &self,
+        request: Request<LeaseRevokeRequest>,
+    ) -> Result<Response<LeaseReply>, Status> {
+        self.check_token(request.metadata())?;
+        network_metrics::incr_recv_bytes(request.get_ref().encoded_len() as u64);
+        let _guard = RequestInFlight::guard();
+
+        let req = request.into_inner();
+        let reply = self
+            .execute_lease_cmd(Cmd::RevokeLease {
+                lease_id: req.lease_id,
+            })
+            .await?;
+        network_metrics::incr_sent_bytes(reply.encoded_len() as u64);
+
+        Ok(Response::new(reply))
+    }
+
     async fn member_list(
         &self,
         request: Request<MemberListRequest>,
@@ -14,13 +14,24 @@
 
 use std::sync::Arc;
 
+use common_meta_types::ForwardRequest;
+use common_meta_types::ForwardRequestBody;
+use common_meta_types::JoinRequest;
+use common_meta_types::LeaveRequest;
 use poem::http::StatusCode;
 use poem::web::Data;
 use poem::web::IntoResponse;
 use poem::web::Json;
+use serde::Deserialize;
+use serde::Serialize;
 
 use crate::meta_service::MetaNode;
 
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct MaintenanceRequest {
+    pub enabled: bool,
+}
+
 /// Let raft leader send snapshot to followers/learners.
 ///
 /// If this node is not a leader this request will be just ignored.
@@ -33,3 +44,65 @@ pub async fn trigger_snapshot(meta_node: Data<&Arc<MetaNode>>) -> poem::Result<i
         .map_err(|e| poem::Error::from_string(e.to_string(), StatusCode::INTERNAL_SERVER_ERROR))?;
     Ok(Json(()))
 }
+
+/// Add a node to the cluster as a voter, or as a learner if `learner` is true.
+///
+/// Adding an existing learner as a voter promotes it.
+/// The request is forwarded to the leader if this node is not the leader.
+#[poem::handler]
+pub async fn add_node(
+    meta_node: Data<&Arc<MetaNode>>,
+    Json(req): Json<JoinRequest>,
+) -> poem::Result<impl IntoResponse> {
+    meta_node
+        .handle_forwardable_request(ForwardRequest {
+            forward_to_leader: 1,
+            body: ForwardRequestBody::Join(req),
+        })
+        .await
+        .map_err(|e| {
+            poem::Error::from_string(
+                format!("failed to add node: {}", e),
+                StatusCode::INTERNAL_SERVER_ERROR,
+            )
+        })?;
+    Ok(Json(()))
+}
+
+/// Remove a voter or a learner from the cluster.
+///
+/// The request is forwarded to the leader if this node is not the leader.
+#[poem::handler]
+pub async fn remove_node(
+    meta_node: Data<&Arc<MetaNode>>,
+    Json(req): Json<LeaveRequest>,
+) -> poem::Result<impl IntoResponse> {
+    meta_node
+        .handle_forwardable_request(ForwardRequest {
+            forward_to_leader: 1,
+            body: ForwardRequestBody::Leave(req),
+        })
+        .await
+        .map_err(|e| {
+            poem::Error::from_string(
+                format!("failed to remove node: {}", e),
+                StatusCode::INTERNAL_SERVER_ERROR,
+            )
+        })?;
+    Ok(Json(()))
+}
+
+/// Put this node into or out of maintenance.
+///
+/// A node in maintenance refuses client requests, thus clients switch to other nodes.
+/// It still replicates logs and votes.
+///
+/// The state is kept in memory only: a restarted node is always out of maintenance.
+#[poem::handler]
+pub async fn maintenance(
+    meta_node: Data<&Arc<MetaNode>>,
+    Json(req): Json<MaintenanceRequest>,
+) -> poem::Result<impl IntoResponse> {
+    meta_node.set_maintenance(req.enabled);
+    Ok(Json(()))
+}
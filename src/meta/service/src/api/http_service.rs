@@ -27,6 +27,7 @@ use common_http::HttpShutdownHandler;
 use poem::get;
 use poem::listener::RustlsCertificate;
 use poem::listener::RustlsConfig;
+use poem::post;
 use poem::Endpoint;
 use poem::EndpointExt;
 use poem::Route;
@@ -52,7 +53,6 @@ impl HttpService {
     }
 
     fn build_router(&self) -> impl Endpoint {
-        #[cfg_attr(not(feature = "memory-profiling"), allow(unused_mut))]
         let mut route = Route::new()
             .at("/v1/health", get(health_handler))
             .at("/v1/config", get(super::http::v1::config::config_handler))
@@ -75,6 +75,24 @@ impl HttpService {
             .at("/debug/home", get(debug_home_handler))
             .at("/debug/pprof/profile", get(debug_pprof_handler));
 
+        // The admin API has no authentication, the endpoints changing the cluster are served
+        // only if they are enabled explicitly.
+        // There is no leadership transfer endpoint, openraft 0.7 can not make a leader step
+        // down to a chosen voter. See the documented workaround with maintenance and
+        // remove_node/add_node in `20-metasrv-add-remove-node.md`.
+        if self.cfg.admin_api_cluster_ctrl_enabled {
+            route = route
+                .at("/v1/ctrl/add_node", post(super::http::v1::ctrl::add_node))
+                .at(
+                    "/v1/ctrl/remove_node",
+                    post(super::http::v1::ctrl::remove_node),
+                )
+                .at(
+                    "/v1/ctrl/maintenance",
+                    post(super::http::v1::ctrl::maintenance),
+                );
+        }
+
         #[cfg(feature = "memory-profiling")]
         {
             route = route.at(
@@ -32,6 +32,8 @@ pub struct Config {
     pub admin_api_address: String,
     pub admin_tls_server_cert: String,
     pub admin_tls_server_key: String,
+    /// Whether the admin API that changes the cluster is served.
+    pub admin_api_cluster_ctrl_enabled: bool,
     pub grpc_api_address: String,
     /// Certificate for server to identify itself
     pub grpc_tls_server_cert: String,
@@ -53,6 +55,7 @@ impl Default for Config {
             admin_api_address: "127.0.0.1:28002".to_string(),
             admin_tls_server_cert: "".to_string(),
             admin_tls_server_key: "".to_string(),
+            admin_api_cluster_ctrl_enabled: false,
             grpc_api_address: "127.0.0.1:9191".to_string(),
             grpc_tls_server_cert: "".to_string(),
             grpc_tls_server_key: "".to_string(),
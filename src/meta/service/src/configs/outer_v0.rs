@@ -83,6 +83,12 @@ pub struct Config {
     #[clap(long, default_value = "")]
     pub admin_tls_server_key: String,
 
+    /// Serve the admin API that changes the cluster: `add_node`, `remove_node` and `maintenance`.
+    ///
+    /// The admin API has no authentication, thus it is disabled by default.
+    #[clap(long)]
+    pub admin_api_cluster_ctrl_enabled: bool,
+
     #[clap(long, default_value = "127.0.0.1:9191")]
     pub grpc_api_address: String,
 
@@ -125,6 +131,7 @@ impl From<Config> for InnerConfig {
             admin_api_address: x.admin_api_address,
             admin_tls_server_cert: x.admin_tls_server_cert,
             admin_tls_server_key: x.admin_tls_server_key,
+            admin_api_cluster_ctrl_enabled: x.admin_api_cluster_ctrl_enabled,
             grpc_api_address: x.grpc_api_address,
             grpc_tls_server_cert: x.grpc_tls_server_cert,
             grpc_tls_server_key: x.grpc_tls_server_key,
@@ -149,6 +156,7 @@ impl From<InnerConfig> for Config {
             admin_api_address: inner.admin_api_address,
             admin_tls_server_cert: inner.admin_tls_server_cert,
             admin_tls_server_key: inner.admin_tls_server_key,
+            admin_api_cluster_ctrl_enabled: inner.admin_api_cluster_ctrl_enabled,
             grpc_api_address: inner.grpc_api_address,
             grpc_tls_server_cert: inner.grpc_tls_server_cert,
             grpc_tls_server_key: inner.grpc_tls_server_key,
@@ -214,6 +222,7 @@ pub struct ConfigViaEnv {
     pub admin_api_address: String,
     pub admin_tls_server_cert: String,
     pub admin_tls_server_key: String,
+    pub admin_api_cluster_ctrl_enabled: bool,
     pub metasrv_grpc_api_address: String,
     pub grpc_tls_server_cert: String,
     pub grpc_tls_server_key: String,
@@ -228,8 +237,11 @@ pub struct ConfigViaEnv {
     pub kvsrv_heartbeat_interval: u64,
     pub kvsrv_install_snapshot_timeout: u64,
     pub raft_max_applied_log_to_keep: u64,
+    pub raft_max_snapshot_files_to_keep: u64,
+    pub raft_max_change_log_to_keep: u64,
     pub kvsrv_single: bool,
     pub metasrv_join: Vec<String>,
+    pub metasrv_learner: bool,
     pub kvsrv_id: u64,
     pub sled_tree_prefix: String,
     pub cluster_name: String,
@@ -255,6 +267,7 @@ impl From<Config> for ConfigViaEnv {
             admin_api_address: cfg.admin_api_address,
             admin_tls_server_cert: cfg.admin_tls_server_cert,
             admin_tls_server_key: cfg.admin_tls_server_key,
+            admin_api_cluster_ctrl_enabled: cfg.admin_api_cluster_ctrl_enabled,
             metasrv_grpc_api_address: cfg.grpc_api_address,
             grpc_tls_server_cert: cfg.grpc_tls_server_cert,
             grpc_tls_server_key: cfg.grpc_tls_server_key,
@@ -268,8 +281,11 @@ impl From<Config> for ConfigViaEnv {
             kvsrv_heartbeat_interval: cfg.raft_config.heartbeat_interval,
             kvsrv_install_snapshot_timeout: cfg.raft_config.install_snapshot_timeout,
             raft_max_applied_log_to_keep: cfg.raft_config.max_applied_log_to_keep,
+            raft_max_snapshot_files_to_keep: cfg.raft_config.max_snapshot_files_to_keep,
+            raft_max_change_log_to_keep: cfg.raft_config.max_change_log_to_keep,
             kvsrv_single: cfg.raft_config.single,
             metasrv_join: cfg.raft_config.join,
+            metasrv_learner: cfg.raft_config.learner,
             kvsrv_id: cfg.raft_config.id,
             sled_tree_prefix: cfg.raft_config.sled_tree_prefix,
             cluster_name: cfg.raft_config.cluster_name,
@@ -292,8 +308,11 @@ impl Into<Config> for ConfigViaEnv {
             heartbeat_interval: self.kvsrv_heartbeat_interval,
             install_snapshot_timeout: self.kvsrv_install_snapshot_timeout,
             max_applied_log_to_keep: self.raft_max_applied_log_to_keep,
+            max_snapshot_files_to_keep: self.raft_max_snapshot_files_to_keep,
+            max_change_log_to_keep: self.raft_max_change_log_to_keep,
             single: self.kvsrv_single,
             join: self.metasrv_join,
+            learner: self.metasrv_learner,
             // Do not allow to leave via environment variable
             leave_via: vec![],
             // Do not allow to leave via environment variable
@@ -329,6 +348,7 @@ impl Into<Config> for ConfigViaEnv {
             admin_api_address: self.admin_api_address,
             admin_tls_server_cert: self.admin_tls_server_cert,
             admin_tls_server_key: self.admin_tls_server_key,
+            admin_api_cluster_ctrl_enabled: self.admin_api_cluster_ctrl_enabled,
             grpc_api_address: self.metasrv_grpc_api_address,
             grpc_tls_server_cert: self.grpc_tls_server_cert,
             grpc_tls_server_key: self.grpc_tls_server_key,
@@ -390,6 +410,14 @@ pub struct RaftConfig {
     #[clap(long, default_value = "1000")]
     pub max_applied_log_to_keep: u64,
 
+    /// The number of snapshot files to keep on disk, older ones are removed.
+    #[clap(long, default_value = "3")]
+    pub max_snapshot_files_to_keep: u64,
+
+    /// The number of kv changes to keep in the state machine for a watcher to resume from.
+    #[clap(long, default_value = "10000")]
+    pub max_change_log_to_keep: u64,
+
     /// Single node metasrv. It creates a single node cluster if meta data is not initialized.
     /// Otherwise it opens the previous one.
     /// This is mainly for testing purpose.
@@ -402,6 +430,12 @@ pub struct RaftConfig {
     #[clap(long, multiple_occurrences = true, multiple_values = true)]
     pub join: Vec<String>,
 
+    /// Join the cluster specified by `join` as a learner.
+    ///
+    /// A learner replicates logs and serves reads, but does not vote or count in a quorum.
+    #[clap(long)]
+    pub learner: bool,
+
     /// Do not run databend-meta, but just remove a node from its cluster via the provided endpoints.
     ///
     /// This node will be removed by `id`.
@@ -447,8 +481,11 @@ impl From<RaftConfig> for InnerRaftConfig {
             heartbeat_interval: x.heartbeat_interval,
             install_snapshot_timeout: x.install_snapshot_timeout,
             max_applied_log_to_keep: x.max_applied_log_to_keep,
+            max_snapshot_files_to_keep: x.max_snapshot_files_to_keep,
+            max_change_log_to_keep: x.max_change_log_to_keep,
             single: x.single,
             join: x.join,
+            learner: x.learner,
             leave_via: x.leave_via,
             leave_id: x.leave_id,
             id: x.id,
@@ -471,8 +508,11 @@ impl From<InnerRaftConfig> for RaftConfig {
             heartbeat_interval: inner.heartbeat_interval,
             install_snapshot_timeout: inner.install_snapshot_timeout,
             max_applied_log_to_keep: inner.max_applied_log_to_keep,
+            max_snapshot_files_to_keep: inner.max_snapshot_files_to_keep,
+            max_change_log_to_keep: inner.max_change_log_to_keep,
             single: inner.single,
             join: inner.join,
+            learner: inner.learner,
             leave_via: inner.leave_via,
             leave_id: inner.leave_id,
             id: inner.id,
@@ -55,6 +55,12 @@ pub fn serialize_kv_variant(kv: &KeySpaceKV) -> Result<(sled::IVec, sled::IVec),
         KeySpaceKV::Sequences { key, value } => ser!(Sequences, key, value),
         KeySpaceKV::ClientLastResps { key, value } => ser!(ClientLastResps, key, value),
         KeySpaceKV::LogMeta { key, value } => ser!(LogMeta, key, value),
+        KeySpaceKV::ChangeLogs { key, value } => ser!(ChangeLogs, key, value),
+        KeySpaceKV::Leases { key, value } => ser!(Leases, key, value),
+        KeySpaceKV::TenantUsages { key, value } => ser!(TenantUsages, key, value),
+        KeySpaceKV::Expires { key, value } => ser!(Expires, key, value),
+        KeySpaceKV::LeaseExpires { key, value } => ser!(LeaseExpires, key, value),
+        KeySpaceKV::LeaseKeys { key, value } => ser!(LeaseKeys, key, value),
     }
 }
 
@@ -77,7 +83,13 @@ pub fn deserialize_to_kv_variant(d: &[Vec<u8>]) -> Result<KeySpaceKV, MetaStorag
         GenericKV,
         Sequences,
         ClientLastResps,
-        LogMeta
+        LogMeta,
+        ChangeLogs,
+        Leases,
+        TenantUsages,
+        Expires,
+        LeaseExpires,
+        LeaseKeys
     );
 
     unreachable!("unknown prefix: {}", prefix);
@@ -12,19 +12,22 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use common_meta_api::KVApi;
+use common_meta_sled_store::openraft::error::AddLearnerError;
+use common_meta_sled_store::openraft::error::CheckIsLeaderError;
 use common_meta_sled_store::openraft::error::RemoveLearnerError;
 use common_meta_types::AppliedState;
 use common_meta_types::Cmd;
 use common_meta_types::ForwardRequest;
 use common_meta_types::ForwardResponse;
 use common_meta_types::LogEntry;
+use common_meta_types::MetaDataError;
 use common_meta_types::MetaDataReadError;
 use common_meta_types::MetaOperationError;
 use common_meta_types::Node;
 use common_meta_types::RaftChangeMembershipError;
 use common_meta_types::RaftWriteError;
 use common_meta_types::SeqV;
+use common_meta_types::TenantQuotaExceeded;
 use common_metrics::counter::Count;
 use tracing::debug;
 use tracing::error;
@@ -68,41 +71,108 @@ impl<'a> MetaLeader<'a> {
                 Ok(ForwardResponse::Leave(()))
             }
             ForwardRequestBody::Write(entry) => {
+                if let Some(exceeded) = self.check_tenant_write_rate(&entry).await? {
+                    info!("tenant quota exceeded: {}; rejected: {}", exceeded, entry);
+                    let res = AppliedState::AppError(exceeded.into());
+                    return Ok(ForwardResponse::AppliedState(res));
+                }
+
                 let res = self.write(entry.clone()).await?;
                 Ok(ForwardResponse::AppliedState(res))
             }
 
-            ForwardRequestBody::GetKV(req) => {
-                let sm = self.meta_node.get_state_machine().await;
-                let res = sm
-                    .get_kv(&req.key)
-                    .await
-                    .map_err(|meta_err| MetaDataReadError::new("get_kv", "", &meta_err))?;
-                Ok(ForwardResponse::GetKV(res))
-            }
-            ForwardRequestBody::MGetKV(req) => {
-                let sm = self.meta_node.get_state_machine().await;
-                let res = sm
-                    .mget_kv(&req.keys)
-                    .await
-                    .map_err(|meta_err| MetaDataReadError::new("mget_kv", "", &meta_err))?;
-                Ok(ForwardResponse::MGetKV(res))
+            ForwardRequestBody::GetKV(_)
+            | ForwardRequestBody::MGetKV(_)
+            | ForwardRequestBody::ListKV(_)
+            | ForwardRequestBody::RangeKV(_) => {
+                let res = self.meta_node.read_local(req.body).await?;
+                Ok(res)
             }
-            ForwardRequestBody::ListKV(req) => {
-                let sm = self.meta_node.get_state_machine().await;
-                let res = sm
-                    .prefix_list_kv(&req.prefix)
-                    .await
-                    .map_err(|meta_err| MetaDataReadError::new("list_kv", "", &meta_err))?;
-                Ok(ForwardResponse::ListKV(res))
+            ForwardRequestBody::ReadIndex => {
+                let index = self.read_index().await?;
+                Ok(ForwardResponse::ReadIndex(index))
             }
         }
     }
 
+    /// Count a write made on behalf of a tenant, and check it against the write rate quota of the tenant.
+    ///
+    /// It is checked before proposing, so that a rejected write does not take a raft log.
+    /// Writes are counted in a window of one second of the wall clock on this leader.
+    /// Returns the exceeded quota if the write has to be rejected.
+    async fn check_tenant_write_rate(
+        &self,
+        entry: &LogEntry,
+    ) -> Result<Option<TenantQuotaExceeded>, MetaDataError> {
+        let tenant = match &entry.cmd {
+            Cmd::AsTenant { tenant, .. } => tenant,
+            _ => return Ok(None),
+        };
+
+        let quota = {
+            let sm = self.meta_node.sto.state_machine.read().await;
+            sm.get_tenant_quota(tenant).map_err(|e| {
+                MetaDataReadError::new("check_tenant_write_rate", "get tenant quota", &e)
+            })?
+        };
+
+        let sec = SeqV::<()>::now_ms() / 1000;
+        let limit = quota.max_write_ops_per_second;
+
+        let mut rates = self.meta_node.tenant_write_rates.lock().await;
+        let (rate_sec, count) = rates.entry(tenant.clone()).or_insert((sec, 0));
+        if *rate_sec != sec {
+            *rate_sec = sec;
+            *count = 0;
+        }
+
+        // A limit of 0 means unlimited.
+        if limit > 0 && *count >= limit {
+            return Ok(Some(TenantQuotaExceeded::new(
+                tenant,
+                "max_write_ops_per_second",
+                *count,
+                limit,
+            )));
+        }
+
+        *count += 1;
+        Ok(None)
+    }
+
+    /// Return the index a reader has to wait for its state machine to apply,
+    /// before it serves a linearizable read.
+    ///
+    /// The last log index is taken before confirming the leadership with a quorum:
+    /// by leader completeness every log committed so far is in this leader's log,
+    /// thus a follower that has applied up to this index sees every write committed before the read.
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn read_index(&self) -> Result<u64, MetaOperationError> {
+        let last_log_index = self
+            .meta_node
+            .raft
+            .metrics()
+            .borrow()
+            .last_log_index
+            .unwrap_or_default();
+
+        self.meta_node.raft.is_leader().await.map_err(|e| match e {
+            CheckIsLeaderError::ForwardToLeader(to_leader) => {
+                MetaOperationError::ForwardToLeader(to_leader)
+            }
+            _ => MetaDataReadError::new("read_index", "confirm leadership", &e).into(),
+        })?;
+
+        Ok(last_log_index)
+    }
+
     /// Join a new node to the cluster.
     ///
     /// - Adds the node to cluster as a non-voter persistently and starts replication.
-    /// - Adds the node to membership to let it become a voter.
+    /// - Adds the node to membership to let it become a voter, unless it joins as a learner.
+    ///
+    /// A learner replicates logs and serves reads but does not vote.
+    /// It can be promoted to a voter by joining again without `learner`.
     ///
     /// If the node is already in cluster membership, it still returns Ok.
     #[tracing::instrument(level = "debug", skip(self))]
@@ -134,6 +204,20 @@ impl<'a> MetaLeader<'a> {
         };
         self.write(ent).await?;
 
+        if req.learner {
+            self.meta_node
+                .raft
+                .add_learner(node_id, false)
+                .await
+                .map_err(|e| match e {
+                    AddLearnerError::ForwardToLeader(e) => {
+                        RaftChangeMembershipError::ForwardToLeader(e)
+                    }
+                    AddLearnerError::Fatal(e) => RaftChangeMembershipError::Fatal(e),
+                })?;
+            return Ok(());
+        }
+
         self.meta_node
             .raft
             .change_membership(membership, true)
@@ -143,28 +227,36 @@ impl<'a> MetaLeader<'a> {
 
     /// A node leave the cluster.
     ///
+    /// - Remove the node from membership, if it is a voter.
     /// - Remove the node from cluster.
-    /// - Remove the node from membership.
     ///
-    /// If the node is not in cluster membership, it still returns Ok.
+    /// If the node is neither a voter nor a learner, it still returns Ok.
     #[tracing::instrument(level = "debug", skip(self))]
-    pub async fn leave(&self, req: LeaveRequest) -> Result<(), RaftChangeMembershipError> {
+    pub async fn leave(&self, req: LeaveRequest) -> Result<(), MetaOperationError> {
         let node_id = req.node_id;
         let metrics = self.meta_node.raft.metrics().borrow().clone();
         let membership = metrics.membership_config.membership.clone();
 
-        if !membership.contains(&node_id) {
-            return Ok(());
-        }
+        if membership.contains(&node_id) {
+            // safe unwrap: if the first config is None, panic is the expected behavior here.
+            let mut membership = membership.get_ith_config(0).unwrap().clone();
+            membership.remove(&node_id);
 
-        // safe unwrap: if the first config is None, panic is the expected behavior here.
-        let mut membership = membership.get_ith_config(0).unwrap().clone();
-        membership.remove(&node_id);
+            self.meta_node
+                .raft
+                .change_membership(membership, true)
+                .await?;
+        } else {
+            let node = self
+                .meta_node
+                .get_node(&node_id)
+                .await
+                .map_err(|e| MetaDataReadError::new("leave", "get node", &e))?;
 
-        self.meta_node
-            .raft
-            .change_membership(membership, true)
-            .await?;
+            if node.is_none() {
+                return Ok(());
+            }
+        }
 
         let ent = LogEntry {
             txid: None,
@@ -181,7 +273,7 @@ impl<'a> MetaLeader<'a> {
         if let Err(e) = res {
             return match e {
                 RemoveLearnerError::ForwardToLeader(e) => {
-                    Err(RaftChangeMembershipError::ForwardToLeader(e))
+                    Err(MetaOperationError::ForwardToLeader(e))
                 }
                 RemoveLearnerError::NotLearner(_e) => {
                     error!("Node to leave the cluster is not a learner: {}", node_id);
@@ -191,7 +283,9 @@ impl<'a> MetaLeader<'a> {
                     info!("Node to leave the cluster does not exists: {}", node_id);
                     Ok(())
                 }
-                RemoveLearnerError::Fatal(e) => Err(RaftChangeMembershipError::Fatal(e)),
+                RemoveLearnerError::Fatal(e) => {
+                    Err(MetaOperationError::DataError(MetaDataError::WriteError(e)))
+                }
             };
         }
 
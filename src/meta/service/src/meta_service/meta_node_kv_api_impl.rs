@@ -14,6 +14,7 @@
 
 use async_trait::async_trait;
 use common_meta_api::KVApi;
+use common_meta_types::AppError;
 use common_meta_types::AppliedState;
 use common_meta_types::Cmd;
 use common_meta_types::GetKVReply;
@@ -24,6 +25,8 @@ use common_meta_types::ListKVReq;
 use common_meta_types::LogEntry;
 use common_meta_types::MGetKVReply;
 use common_meta_types::MGetKVReq;
+use common_meta_types::RangeKVReply;
+use common_meta_types::RangeKVReq;
 use common_meta_types::TxnReply;
 use common_meta_types::TxnRequest;
 use common_meta_types::UpsertKV;
@@ -32,6 +35,7 @@ use common_meta_types::UpsertKVReq;
 use tracing::info;
 
 use crate::meta_service::MetaNode;
+use crate::metrics::tenant_metrics;
 
 /// Impl KVApi for MetaNode.
 ///
@@ -41,24 +45,7 @@ use crate::meta_service::MetaNode;
 #[async_trait]
 impl KVApi for MetaNode {
     async fn upsert_kv(&self, act: UpsertKVReq) -> Result<UpsertKVReply, KVAppError> {
-        let ent = LogEntry {
-            txid: None,
-            time_ms: None,
-            cmd: Cmd::UpsertKV(UpsertKV {
-                key: act.key,
-                seq: act.seq,
-                value: act.value,
-                value_meta: act.value_meta,
-            }),
-        };
-        let rst = self.write(ent).await?;
-
-        match rst {
-            AppliedState::KV(x) => Ok(x),
-            _ => {
-                unreachable!("expect type {}", "AppliedState::KV")
-            }
-        }
+        self.upsert_kv_as(None, act).await
     }
 
     #[tracing::instrument(level = "debug", skip(self))]
@@ -94,21 +81,79 @@ impl KVApi for MetaNode {
         Ok(res)
     }
 
+    #[tracing::instrument(level = "debug", skip(self))]
+    async fn range_kv(&self, req: RangeKVReq) -> Result<RangeKVReply, KVAppError> {
+        let res = self.consistent_read(req).await?;
+        Ok(res)
+    }
+
     #[tracing::instrument(level = "debug", skip(self, txn))]
     async fn transaction(&self, txn: TxnRequest) -> Result<TxnReply, KVAppError> {
+        self.transaction_as(None, txn).await
+    }
+}
+
+impl MetaNode {
+    /// Upsert a record on behalf of `tenant`, which is subject to the quota of the tenant.
+    pub async fn upsert_kv_as(
+        &self,
+        tenant: Option<String>,
+        act: UpsertKVReq,
+    ) -> Result<UpsertKVReply, KVAppError> {
+        let cmd = Cmd::UpsertKV(UpsertKV {
+            key: act.key,
+            seq: act.seq,
+            value: act.value,
+            value_meta: act.value_meta,
+        });
+        let rst = self.write_as(tenant, cmd).await?;
+
+        match rst {
+            AppliedState::KV(x) => Ok(x),
+            _ => {
+                unreachable!("expect type {}", "AppliedState::KV")
+            }
+        }
+    }
+
+    /// Execute a transaction on behalf of `tenant`, which is subject to the quota of the tenant.
+    pub async fn transaction_as(
+        &self,
+        tenant: Option<String>,
+        txn: TxnRequest,
+    ) -> Result<TxnReply, KVAppError> {
         info!("MetaNode::transaction(): {}", txn);
+        let rst = self.write_as(tenant, Cmd::Transaction(txn)).await?;
+
+        match rst {
+            AppliedState::TxnReply(x) => Ok(x),
+            _ => {
+                unreachable!("expect type {}", "AppliedState::transaction",)
+            }
+        }
+    }
+
+    /// Write a command through raft, a command rejected by the state machine is returned as an `AppError`.
+    async fn write_as(&self, tenant: Option<String>, cmd: Cmd) -> Result<AppliedState, KVAppError> {
+        if let Some(tenant) = &tenant {
+            tenant_metrics::incr_write_ops(tenant);
+        }
+
         let ent = LogEntry {
             txid: None,
             time_ms: None,
-            cmd: Cmd::Transaction(txn),
+            cmd: cmd.with_tenant(tenant.clone()),
         };
         let rst = self.write(ent).await?;
 
         match rst {
-            AppliedState::TxnReply(x) => Ok(x),
-            _ => {
-                unreachable!("expect type {}", "AppliedState::transaction",)
+            AppliedState::AppError(e) => {
+                if let (Some(tenant), AppError::TenantQuotaExceeded(_)) = (&tenant, &e) {
+                    tenant_metrics::incr_quota_exceeded(tenant);
+                }
+                Err(KVAppError::AppError(e))
             }
+            _ => Ok(rst),
         }
     }
 }
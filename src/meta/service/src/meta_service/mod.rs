@@ -18,8 +18,10 @@ pub use common_meta_types::JoinRequest;
 pub use common_meta_types::LeaveRequest;
 pub use meta_service_impl::RaftServiceImpl;
 pub use raftmeta::MetaNode;
+pub use read_consistency::ReadConsistency;
 
 pub mod meta_leader;
 mod meta_node_kv_api_impl;
 pub mod meta_service_impl;
 pub mod raftmeta;
+mod read_consistency;
@@ -12,12 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 use std::fmt::Debug;
 use std::net::Ipv4Addr;
+use std::sync::atomic::AtomicBool;
 use std::sync::atomic::AtomicI32;
 use std::sync::Arc;
 use std::time::Duration;
+use std::time::Instant;
 
 use anyerror::AnyError;
 use common_base::base::tokio;
@@ -27,8 +30,10 @@ use common_base::base::tokio::sync::RwLockReadGuard;
 use common_base::base::tokio::task::JoinHandle;
 use common_grpc::ConnectionFactory;
 use common_grpc::DNSResolver;
+use common_meta_api::KVApi;
 use common_meta_raft_store::config::RaftConfig;
 use common_meta_raft_store::sled_key_spaces::GenericKV;
+use common_meta_raft_store::state_machine::CompactedRevision;
 use common_meta_raft_store::state_machine::StateMachine;
 use common_meta_sled_store::openraft;
 use common_meta_sled_store::openraft::error::AddLearnerError;
@@ -52,6 +57,7 @@ use common_meta_types::LeaveRequest;
 use common_meta_types::LogEntry;
 use common_meta_types::MetaAPIError;
 use common_meta_types::MetaDataError;
+use common_meta_types::MetaDataReadError;
 use common_meta_types::MetaError;
 use common_meta_types::MetaManagementError;
 use common_meta_types::MetaNetworkError;
@@ -59,6 +65,7 @@ use common_meta_types::MetaOperationError;
 use common_meta_types::MetaStartupError;
 use common_meta_types::Node;
 use common_meta_types::NodeId;
+use common_meta_types::TenantUsage;
 use openraft::Config;
 use openraft::LogId;
 use openraft::Raft;
@@ -76,10 +83,13 @@ use crate::meta_service::meta_leader::MetaLeader;
 use crate::meta_service::ForwardRequestBody;
 use crate::meta_service::JoinRequest;
 use crate::meta_service::RaftServiceImpl;
+use crate::meta_service::ReadConsistency;
 use crate::metrics::server_metrics;
+use crate::metrics::tenant_metrics;
 use crate::network::Network;
 use crate::store::RaftStore;
 use crate::store::RaftStoreBare;
+use crate::watcher::StateMachineKvData;
 use crate::watcher::WatcherManager;
 use crate::watcher::WatcherStreamSender;
 use crate::Opened;
@@ -112,8 +122,29 @@ pub struct MetaNodeStatus {
     ///
     /// `seq` is a monotonically incremental integer for every value that is inserted or updated.
     pub last_seq: u64,
+
+    /// Whether this node refuses client requests for maintenance.
+    pub maintenance: bool,
+
+    /// The replication progress of every other node.
+    ///
+    /// It is only present on the leader.
+    pub replication: Option<BTreeMap<NodeId, ReplicationStatus>>,
+}
+
+#[derive(serde::Serialize)]
+pub struct ReplicationStatus {
+    /// The index of the last log the leader knows is replicated to this node.
+    pub matched_index: Option<u64>,
+
+    /// The number of logs this node is behind the leader.
+    pub lag: u64,
 }
 
+/// The max time a follower waits for its state machine to catch up with a read index,
+/// before it gives up and forwards the read to the leader.
+const READ_INDEX_APPLY_TIMEOUT: Duration = Duration::from_millis(1_000);
+
 // MetaRaft is a impl of the generic Raft handling meta data R/W.
 pub type MetaRaft = Raft<LogEntry, AppliedState, Network, RaftStore>;
 
@@ -126,6 +157,20 @@ pub struct MetaNode {
     pub running_rx: watch::Receiver<()>,
     pub join_handles: Mutex<Vec<JoinHandle<Result<(), AnyError>>>>,
     pub joined_tasks: AtomicI32,
+
+    /// A node in maintenance refuses client requests, so that clients switch to other nodes.
+    /// It still takes part in raft.
+    pub maintenance: AtomicBool,
+
+    /// The last time this node applied logs up to a read index got from the leader.
+    ///
+    /// A stale read is served locally if it is not older than the max staleness the reader allows.
+    pub read_synced_at: Mutex<Option<Instant>>,
+
+    /// The number of writes of each tenant proposed in the current second: `tenant -> (second, count)`.
+    ///
+    /// It is only used when this node is the leader, which proposes all writes.
+    pub tenant_write_rates: Mutex<BTreeMap<String, (u64, u64)>>,
 }
 
 impl Opened for MetaNode {
@@ -179,6 +224,9 @@ impl MetaNodeBuilder {
             running_rx: rx,
             join_handles: Mutex::new(Vec::new()),
             joined_tasks: AtomicI32::new(1),
+            maintenance: AtomicBool::new(false),
+            read_synced_at: Mutex::new(None),
+            tenant_write_rates: Mutex::new(BTreeMap::new()),
         });
 
         if self.monitor_metrics {
@@ -466,6 +514,17 @@ impl MetaNode {
                         .map_err(|e| AnyError::new(&e))?,
                 );
 
+                // metrics about the resource usage of tenants.
+
+                let usages = meta_node
+                    .get_tenant_usages()
+                    .await
+                    .map_err(|e| AnyError::new(&e))?;
+                for (tenant, usage) in usages {
+                    tenant_metrics::set_keys(&tenant, usage.keys);
+                    tenant_metrics::set_value_bytes(&tenant, usage.value_bytes);
+                }
+
                 last_leader = mm.current_leader;
             }
 
@@ -607,6 +666,7 @@ impl MetaNode {
                     node_id: conf.id,
                     endpoint: advertise_endpoint.clone(),
                     grpc_api_addr: grpc_api_addr.clone(),
+                    learner: conf.learner,
                 }),
             };
 
@@ -735,6 +795,20 @@ impl MetaNode {
 
         let last_seq = self.get_last_seq().await?;
 
+        let last_log_index = metrics.last_log_index.unwrap_or(0);
+        let replication = metrics.leader_metrics.as_ref().map(|leader_metrics| {
+            leader_metrics
+                .data()
+                .replication
+                .iter()
+                .map(|(node_id, r)| {
+                    let matched_index = r.matched.map(|log_id| log_id.index);
+                    let lag = last_log_index.saturating_sub(matched_index.unwrap_or(0));
+                    (*node_id, ReplicationStatus { matched_index, lag })
+                })
+                .collect::<BTreeMap<_, _>>()
+        });
+
         Ok(MetaNodeStatus {
             id: self.sto.id,
             endpoint: endpoint.to_string(),
@@ -742,7 +816,7 @@ impl MetaNode {
             state: format!("{:?}", metrics.state),
             is_leader: metrics.state == openraft::State::Leader,
             current_term: metrics.current_term,
-            last_log_index: metrics.last_log_index.unwrap_or(0),
+            last_log_index,
             last_applied: match metrics.last_applied {
                 Some(id) => id,
                 None => LogId::new(0, 0),
@@ -751,9 +825,22 @@ impl MetaNode {
             voters,
             non_voters,
             last_seq,
+            maintenance: self.is_maintenance(),
+            replication,
         })
     }
 
+    /// Enter or exit maintenance mode.
+    pub fn set_maintenance(&self, maintenance: bool) {
+        info!("node-{} set maintenance: {}", self.sto.id, maintenance);
+        self.maintenance
+            .store(maintenance, std::sync::atomic::Ordering::Relaxed);
+    }
+
+    pub fn is_maintenance(&self) -> bool {
+        self.maintenance.load(std::sync::atomic::Ordering::Relaxed)
+    }
+
     pub(crate) async fn get_last_seq(&self) -> Result<u64, MetaStorageError> {
         let sm = self.sto.state_machine.read().await;
         let last_seq = sm.sequences().get(&GenericKV::NAME.to_string())?;
@@ -761,6 +848,13 @@ impl MetaNode {
         Ok(last_seq.unwrap_or_default().0)
     }
 
+    pub(crate) async fn get_tenant_usages(
+        &self,
+    ) -> Result<Vec<(String, TenantUsage)>, MetaStorageError> {
+        let sm = self.sto.state_machine.read().await;
+        sm.get_tenant_usages()
+    }
+
     #[tracing::instrument(level = "debug", skip(self))]
     pub async fn get_meta_addrs(&self) -> Result<Vec<String>, MetaStorageError> {
         // inconsistent get: from local state machine
@@ -791,12 +885,26 @@ impl MetaNode {
         ForwardResponse: TryInto<Reply>,
         <ForwardResponse as TryInto<Reply>>::Error: std::fmt::Display,
     {
-        let res = self
-            .handle_forwardable_request(ForwardRequest {
-                forward_to_leader: 1,
-                body: req.into(),
-            })
-            .await;
+        self.read_with(ReadConsistency::Linearizable, req).await
+    }
+
+    /// Serve a read request with the specified consistency.
+    ///
+    /// The leader reads its local state machine.
+    /// A follower or learner reads its local state machine too, after catching up with the leader as `consistency` requires.
+    /// If it fails to catch up, the read is forwarded to the leader.
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn read_with<Request, Reply>(
+        &self,
+        consistency: ReadConsistency,
+        req: Request,
+    ) -> Result<Reply, MetaAPIError>
+    where
+        Request: Into<ForwardRequestBody> + Debug,
+        ForwardResponse: TryInto<Reply>,
+        <ForwardResponse as TryInto<Reply>>::Error: std::fmt::Display,
+    {
+        let res = self.do_read(consistency, req.into()).await;
 
         match res {
             Err(e) => {
@@ -818,6 +926,124 @@ impl MetaNode {
         }
     }
 
+    async fn do_read(
+        &self,
+        consistency: ReadConsistency,
+        body: ForwardRequestBody,
+    ) -> Result<ForwardResponse, MetaAPIError> {
+        if self.as_leader().await.is_err() {
+            match self.sync_for_read(consistency).await {
+                Ok(()) => {
+                    let res = self.read_local(body).await.map_err(MetaDataError::from)?;
+                    return Ok(res);
+                }
+                Err(e) => {
+                    warn!(
+                        "fail to catch up with leader for a local read, forward it to leader: {}",
+                        e
+                    );
+                }
+            }
+        }
+
+        self.handle_forwardable_request(ForwardRequest {
+            forward_to_leader: 1,
+            body,
+        })
+        .await
+    }
+
+    /// Make the local state machine up to date enough to serve a read with `consistency`.
+    ///
+    /// It fetches a read index from the leader and waits until the local state machine has applied logs up to it,
+    /// unless a stale read is allowed and this node has caught up recently enough.
+    #[tracing::instrument(level = "debug", skip(self))]
+    async fn sync_for_read(&self, consistency: ReadConsistency) -> Result<(), MetaAPIError> {
+        if let ReadConsistency::Stale { max_staleness } = consistency {
+            let synced_at = *self.read_synced_at.lock().await;
+            if let Some(t) = synced_at {
+                if t.elapsed() <= max_staleness {
+                    return Ok(());
+                }
+            }
+        }
+
+        // The read index is at least as new as the time the request is sent.
+        let requested_at = Instant::now();
+
+        let res = self
+            .handle_forwardable_request(ForwardRequest {
+                forward_to_leader: 1,
+                body: ForwardRequestBody::ReadIndex,
+            })
+            .await?;
+
+        let read_index: u64 = res.try_into().map_err(|e| {
+            let invalid_reply =
+                InvalidReply::new("expect reply type to be ReadIndex", &AnyError::error(e));
+            MetaNetworkError::from(invalid_reply)
+        })?;
+
+        self.raft
+            .wait(Some(READ_INDEX_APPLY_TIMEOUT))
+            .metrics(
+                |m| m.last_applied.map(|l| l.index).unwrap_or_default() >= read_index,
+                format!("apply up to read index {}", read_index),
+            )
+            .await
+            .map_err(|e| MetaDataReadError::new("sync_for_read", "wait for read index", &e))
+            .map_err(MetaDataError::from)?;
+
+        let mut synced_at = self.read_synced_at.lock().await;
+        *synced_at = Some(synced_at.map_or(requested_at, |t| t.max(requested_at)));
+
+        Ok(())
+    }
+
+    /// Serve a read request from the local state machine, without checking if it is up to date.
+    pub(crate) async fn read_local(
+        &self,
+        body: ForwardRequestBody,
+    ) -> Result<ForwardResponse, MetaDataReadError> {
+        let sm = self.get_state_machine().await;
+
+        match body {
+            ForwardRequestBody::GetKV(req) => {
+                let res = sm
+                    .get_kv(&req.key)
+                    .await
+                    .map_err(|meta_err| MetaDataReadError::new("get_kv", "", &meta_err))?;
+                Ok(ForwardResponse::GetKV(res))
+            }
+            ForwardRequestBody::MGetKV(req) => {
+                let res = sm
+                    .mget_kv(&req.keys)
+                    .await
+                    .map_err(|meta_err| MetaDataReadError::new("mget_kv", "", &meta_err))?;
+                Ok(ForwardResponse::MGetKV(res))
+            }
+            ForwardRequestBody::ListKV(req) => {
+                let res = sm
+                    .prefix_list_kv(&req.prefix)
+                    .await
+                    .map_err(|meta_err| MetaDataReadError::new("list_kv", "", &meta_err))?;
+                Ok(ForwardResponse::ListKV(res))
+            }
+            ForwardRequestBody::RangeKV(req) => {
+                let res = sm
+                    .range_kv(req)
+                    .await
+                    .map_err(|meta_err| MetaDataReadError::new("range_kv", "", &meta_err))?;
+                Ok(ForwardResponse::RangeKV(res))
+            }
+            _ => Err(MetaDataReadError::new(
+                "read_local",
+                format!("{:?}", body),
+                &AnyError::error("not a read request"),
+            )),
+        }
+    }
+
     #[tracing::instrument(level = "debug", skip(self, req), fields(target=%req.forward_to_leader))]
     pub async fn handle_forwardable_request(
         &self,
@@ -1007,7 +1233,34 @@ impl MetaNode {
         Ok(resp)
     }
 
-    pub fn create_watcher_stream(&self, request: WatchRequest, tx: WatcherStreamSender) {
-        self.watcher.create_watcher_stream(request, tx)
+    /// Create a watcher stream, which replays the changes since `request.start_revision` if it is specified.
+    ///
+    /// The history is read and the watcher is registered while holding the state machine read lock,
+    /// so that no change is applied in between: a change is either replayed or sent as a new one.
+    pub async fn create_watcher_stream(
+        &self,
+        request: WatchRequest,
+        tx: WatcherStreamSender,
+    ) -> Result<Result<(), CompactedRevision>, MetaStorageError> {
+        let sm = self.sto.state_machine.read().await;
+
+        let history = match request.start_revision {
+            None => vec![],
+            Some(start) => match sm.change_logs_since(start)? {
+                Ok(changes) => changes
+                    .into_iter()
+                    .map(|(revision, c)| StateMachineKvData {
+                        revision,
+                        key: c.key,
+                        prev: c.prev,
+                        current: c.current,
+                    })
+                    .collect(),
+                Err(compacted) => return Ok(Err(compacted)),
+            },
+        };
+
+        self.watcher.create_watcher_stream(request, tx, history);
+        Ok(Ok(()))
     }
 }
@@ -0,0 +1,43 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+/// How up to date the state machine has to be for a meta node to serve a read locally.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReadConsistency {
+    /// The read sees every write committed before the read is received.
+    ///
+    /// A follower or learner asks the leader for a read index and waits until it has applied logs up to it.
+    Linearizable,
+
+    /// The read may miss writes committed in the last `max_staleness`.
+    ///
+    /// A follower or learner serves the read locally if it has caught up with the leader within `max_staleness`,
+    /// otherwise it catches up as a linearizable read does.
+    Stale { max_staleness: Duration },
+}
+
+impl ReadConsistency {
+    /// Build from a max staleness in milliseconds, `0` means linearizable.
+    pub fn from_max_staleness_ms(ms: u64) -> Self {
+        if ms == 0 {
+            ReadConsistency::Linearizable
+        } else {
+            ReadConsistency::Stale {
+                max_staleness: Duration::from_millis(ms),
+            }
+        }
+    }
+}
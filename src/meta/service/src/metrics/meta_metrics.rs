@@ -22,6 +22,7 @@
 //! - raft_network: for metrics about communication between nodes in raft protocol.
 //! - raft_storage: for metrics about the local storage of a raft node.
 //! - meta_network: for metrics about meta-service grpc api.
+//! - tenant: for metrics about the resource usage of tenants.
 //! The `field` is arbitrary string.
 
 use std::time::Instant;
@@ -237,6 +238,37 @@ pub mod network_metrics {
     }
 }
 
+pub mod tenant_metrics {
+    use metrics::counter;
+    use metrics::gauge;
+
+    macro_rules! key {
+        ($key: literal) => {
+            concat!("metasrv_tenant_", $key)
+        };
+    }
+
+    pub fn set_keys(tenant: &str, keys: u64) {
+        let labels = [("tenant", tenant.to_string())];
+        gauge!(key!("keys"), keys as f64, &labels);
+    }
+
+    pub fn set_value_bytes(tenant: &str, value_bytes: u64) {
+        let labels = [("tenant", tenant.to_string())];
+        gauge!(key!("value_bytes"), value_bytes as f64, &labels);
+    }
+
+    pub fn incr_write_ops(tenant: &str) {
+        let labels = [("tenant", tenant.to_string())];
+        counter!(key!("write_ops"), 1, &labels);
+    }
+
+    pub fn incr_quota_exceeded(tenant: &str) {
+        let labels = [("tenant", tenant.to_string())];
+        counter!(key!("quota_exceeded"), 1, &labels);
+    }
+}
+
 /// RAII metrics counter of in-flight requests count and delay.
 #[derive(Default)]
 pub(crate) struct RequestInFlight {
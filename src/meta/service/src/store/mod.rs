@@ -12,12 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod snapshot_file;
 mod store_bare;
 mod to_storage_error;
 
 use common_meta_sled_store::openraft::StoreExt;
 use common_meta_types::AppliedState;
 use common_meta_types::LogEntry;
+pub use snapshot_file::SnapshotFile;
 pub use store_bare::RaftStoreBare;
 pub use to_storage_error::ToStorageError;
 
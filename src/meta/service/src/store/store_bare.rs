@@ -13,9 +13,12 @@
 // limitations under the License.
 
 use std::fmt::Debug;
-use std::io::Cursor;
+use std::fs;
+use std::io::BufReader;
+use std::io::BufWriter;
 use std::io::ErrorKind;
 use std::ops::RangeBounds;
+use std::path::Path;
 
 use anyerror::AnyError;
 use common_base::base::tokio::sync::RwLock;
@@ -24,8 +27,11 @@ use common_exception::WithContext;
 use common_meta_raft_store::config::RaftConfig;
 use common_meta_raft_store::log::RaftLog;
 use common_meta_raft_store::state::RaftState;
-use common_meta_raft_store::state_machine::SerializableSnapshot;
+use common_meta_raft_store::state_machine::verify_snapshot_file;
+use common_meta_raft_store::state_machine::MetaSnapshotId;
 use common_meta_raft_store::state_machine::Snapshot;
+use common_meta_raft_store::state_machine::SnapshotFileReader;
+use common_meta_raft_store::state_machine::SnapshotStore;
 use common_meta_raft_store::state_machine::StateMachine;
 use common_meta_sled_store::get_sled_db;
 use common_meta_sled_store::openraft;
@@ -58,6 +64,7 @@ use tracing::info;
 use crate::export::vec_kv_to_json;
 use crate::metrics::raft_metrics;
 use crate::metrics::server_metrics;
+use crate::store::SnapshotFile;
 use crate::store::ToStorageError;
 use crate::Opened;
 
@@ -103,6 +110,9 @@ pub struct RaftStoreBare {
     /// - Acquire a write lock before installing a snapshot, to prevent any write to the db.
     pub state_machine: RwLock<StateMachine>,
 
+    /// The snapshot files on disk.
+    snapshot_store: SnapshotStore,
+
     /// The current snapshot.
     pub current_snapshot: RwLock<Option<Snapshot>>,
 }
@@ -151,7 +161,19 @@ impl RaftStoreBare {
         }
 
         let sm = RwLock::new(StateMachine::open(config, sm_id).await?);
-        let current_snapshot = RwLock::new(None);
+
+        let snapshot_store = SnapshotStore::open(config)?;
+        let current_snapshot = snapshot_store
+            .latest()?
+            .map(|(snapshot_id, path)| Snapshot {
+                meta: SnapshotMeta {
+                    last_log_id: snapshot_id.last_applied,
+                    snapshot_id: snapshot_id.to_string(),
+                },
+                path,
+            });
+        info!("current snapshot: {:?}", current_snapshot);
+        let current_snapshot = RwLock::new(current_snapshot);
 
         Ok(Self {
             id: raft_state.id,
@@ -161,6 +183,7 @@ impl RaftStoreBare {
             raft_state,
             log,
             state_machine: sm,
+            snapshot_store,
             current_snapshot,
         })
     }
@@ -181,36 +204,43 @@ impl RaftStoreBare {
     > {
         // NOTE: building snapshot is guaranteed to be serialized called by RaftCore.
 
-        // 1. Take a serialized snapshot
+        // 1. Stream the state machine into a temp file
 
-        let (snap, last_applied_log, snapshot_id) = match self
-            .state_machine
-            .write()
+        let temp_path = self.snapshot_store.new_temp_path();
+
+        let (snapshot_size, last_applied_log, snapshot_id) = match self
+            .write_snapshot_file(&temp_path)
             .await
-            .build_snapshot()
             .map_to_sto_err(ErrorSubject::StateMachine, ErrorVerb::Read)
         {
             Err(err) => {
                 raft_metrics::storage::incr_raft_storage_fail("build_snapshot", false);
+                let _ = fs::remove_file(&temp_path);
                 return Err(err);
             }
             Ok(r) => r,
         };
 
-        let data = serde_json::to_vec(&snap)
-            .map_err(MetaStorageError::from)
-            .map_to_sto_err(ErrorSubject::StateMachine, ErrorVerb::Read)?;
-
-        let snapshot_size = data.len();
-
         let snap_meta = SnapshotMeta {
             last_log_id: last_applied_log,
             snapshot_id: snapshot_id.to_string(),
         };
 
+        // 2. Make it a finished snapshot file and open it for openraft to read
+
+        let path = self
+            .snapshot_store
+            .commit(&temp_path, &snap_meta.snapshot_id)
+            .map_to_sto_err(ErrorSubject::Snapshot(snap_meta.clone()), ErrorVerb::Write)?;
+
+        let file = SnapshotFile::open(&path)
+            .await
+            .map_err(|e| MetaStorageError::SnapshotError(AnyError::new(&e)))
+            .map_to_sto_err(ErrorSubject::Snapshot(snap_meta.clone()), ErrorVerb::Read)?;
+
         let snapshot = Snapshot {
             meta: snap_meta.clone(),
-            data: data.clone(),
+            path,
         };
 
         // Update the snapshot first.
@@ -219,17 +249,69 @@ impl RaftStoreBare {
             *current_snapshot = Some(snapshot);
         }
 
+        if let Err(e) = self.snapshot_store.clean() {
+            error!("error: {:?} when cleaning old snapshot files", e);
+        }
+
         info!(snapshot_size = snapshot_size, "log compaction complete");
 
         Ok(openraft::storage::Snapshot {
             meta: snap_meta,
-            snapshot: Box::new(Cursor::new(data)),
+            snapshot: Box::new(file),
         })
     }
 
-    /// Install a snapshot to build a state machine from it and replace the old state machine with the new one.
-    #[tracing::instrument(level = "debug", skip(self, data))]
-    pub async fn install_snapshot(&self, data: &[u8]) -> Result<(), MetaStorageError> {
+    /// Write the state machine into a snapshot file at `path`.
+    ///
+    /// The key values are streamed into the file under the state machine read lock, so that no
+    /// log is applied in between. The file is synced after the lock is released.
+    ///
+    /// Returns the file size, the last applied log id and the snapshot id.
+    async fn write_snapshot_file(
+        &self,
+        path: &Path,
+    ) -> Result<(u64, Option<LogId>, MetaSnapshotId), MetaStorageError> {
+        let f = fs::File::create(path).map_err(|e| {
+            MetaStorageError::SnapshotError(
+                AnyError::new(&e).add_context(|| format!("create {}", path.display())),
+            )
+        })?;
+
+        let (w, summary, last_applied, snapshot_id) = self
+            .state_machine
+            .read()
+            .await
+            .build_snapshot_file(BufWriter::new(f))?;
+
+        let f = w
+            .into_inner()
+            .map_err(|e| MetaStorageError::SnapshotError(AnyError::new(e.error())))?;
+        if self.config.is_sync() {
+            f.sync_all()
+                .map_err(|e| MetaStorageError::SnapshotError(AnyError::new(&e)))?;
+        }
+
+        info!("snapshot file written: {}, {:?}", path.display(), summary);
+
+        Ok((summary.size, last_applied, snapshot_id))
+    }
+
+    /// Install a snapshot file to build a state machine from it and replace the old state machine with the new one.
+    ///
+    /// The checksum of the file is verified before the current state machine is touched.
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn install_snapshot(&self, path: &Path) -> Result<(), MetaStorageError> {
+        let open = || {
+            fs::File::open(path).map(BufReader::new).map_err(|e| {
+                MetaStorageError::SnapshotError(
+                    AnyError::new(&e).add_context(|| format!("open {}", path.display())),
+                )
+            })
+        };
+
+        let summary = verify_snapshot_file(open()?)?;
+        info!("snapshot file verified: {}, {:?}", path.display(), summary);
+
         let mut sm = self.state_machine.write().await;
 
         let (sm_id, prev_sm_id) = self.raft_state.read_state_machine_id()?;
@@ -242,10 +324,6 @@ impl RaftStoreBare {
 
         let new_sm_id = sm_id + 1;
 
-        info!("snapshot data len: {}", data.len());
-
-        let snap: SerializableSnapshot = serde_json::from_slice(data)?;
-
         // If not finished, clean up the new tree.
         self.raft_state
             .write_state_machine_id(&(sm_id, new_sm_id))
@@ -254,15 +332,15 @@ impl RaftStoreBare {
         let new_sm = StateMachine::open(&self.config, new_sm_id).await?;
         info!(
             "insert all key-value into new state machine, n={}",
-            snap.kvs.len()
+            summary.kv_count
         );
 
         let tree = &new_sm.sm_tree.tree;
-        let nkvs = snap.kvs.len();
-        for x in snap.kvs.into_iter() {
-            let k = &x[0];
-            let v = &x[1];
-            tree.insert(k, v.clone()).context(|| "insert snapshot")?;
+        let mut nkvs = 0;
+        for kv in SnapshotFileReader::new(open()?)? {
+            let (k, v) = kv?;
+            tree.insert(k, v).context(|| "insert snapshot")?;
+            nkvs += 1;
         }
 
         info!(
@@ -291,12 +369,43 @@ impl RaftStoreBare {
             .write_state_machine_id(&(new_sm_id, new_sm_id))
             .await?;
 
-        // TODO(xp): use checksum to check consistency?
-
         *sm = new_sm;
         Ok(())
     }
 
+    /// Sync a received snapshot file, install it and keep it as the current snapshot.
+    async fn do_install_snapshot(
+        &self,
+        meta: &SnapshotMeta,
+        snapshot: Box<SnapshotFile>,
+    ) -> Result<(), MetaStorageError> {
+        let temp_path = snapshot.path().to_path_buf();
+
+        let file = snapshot.into_file();
+        file.sync_all()
+            .await
+            .map_err(|e| MetaStorageError::SnapshotError(AnyError::new(&e)))?;
+        drop(file);
+
+        if let Err(e) = self.install_snapshot(&temp_path).await {
+            let _ = fs::remove_file(&temp_path);
+            return Err(e);
+        }
+
+        let path = self.snapshot_store.commit(&temp_path, &meta.snapshot_id)?;
+
+        // Update current snapshot.
+        {
+            let mut current_snapshot = self.current_snapshot.write().await;
+            *current_snapshot = Some(Snapshot {
+                meta: meta.clone(),
+                path,
+            });
+        }
+
+        self.snapshot_store.clean()
+    }
+
     #[tracing::instrument(level = "debug", skip(self))]
     pub async fn export(&self) -> Result<Vec<String>, std::io::Error> {
         let mut res = vec![];
@@ -345,7 +454,7 @@ impl RaftStoreBare {
 
 #[async_trait]
 impl RaftStorage<LogEntry, AppliedState> for RaftStoreBare {
-    type SnapshotData = Cursor<Vec<u8>>;
+    type SnapshotData = SnapshotFile;
 
     #[tracing::instrument(level = "debug", skip(self, hs), fields(id=self.id))]
     async fn save_hard_state(&self, hs: &HardState) -> Result<(), StorageError> {
@@ -491,7 +600,23 @@ impl RaftStorage<LogEntry, AppliedState> for RaftStoreBare {
     #[tracing::instrument(level = "debug", skip(self), fields(id=self.id))]
     async fn begin_receiving_snapshot(&self) -> Result<Box<Self::SnapshotData>, StorageError> {
         server_metrics::incr_applying_snapshot(1);
-        Ok(Box::new(Cursor::new(Vec::new())))
+
+        let path = self.snapshot_store.new_temp_path();
+        match SnapshotFile::create(&path)
+            .await
+            .map_err(|e| {
+                MetaStorageError::SnapshotError(
+                    AnyError::new(&e).add_context(|| format!("create {}", path.display())),
+                )
+            })
+            .map_to_sto_err(ErrorSubject::StateMachine, ErrorVerb::Write)
+        {
+            Err(err) => {
+                raft_metrics::storage::incr_raft_storage_fail("begin_receiving_snapshot", true);
+                Err(err)
+            }
+            Ok(f) => Ok(Box::new(f)),
+        }
     }
 
     #[tracing::instrument(level = "debug", skip(self, snapshot), fields(id=self.id))]
@@ -503,20 +628,15 @@ impl RaftStorage<LogEntry, AppliedState> for RaftStoreBare {
         // TODO(xp): disallow installing a snapshot with smaller last_applied.
 
         info!(
-            { snapshot_size = snapshot.get_ref().len() },
+            { snapshot_file = %snapshot.path().display() },
             "decoding snapshot for installation"
         );
         server_metrics::incr_applying_snapshot(-1);
 
-        let new_snapshot = Snapshot {
-            meta: meta.clone(),
-            data: snapshot.into_inner(),
-        };
-
         info!("snapshot meta: {:?}", meta);
 
         // Replace state machine with the new one
-        let res = self.install_snapshot(&new_snapshot.data).await;
+        let res = self.do_install_snapshot(meta, snapshot).await;
         match res {
             Ok(_) => {}
             Err(e) => {
@@ -525,11 +645,6 @@ impl RaftStorage<LogEntry, AppliedState> for RaftStoreBare {
             }
         };
 
-        // Update current snapshot.
-        {
-            let mut current_snapshot = self.current_snapshot.write().await;
-            *current_snapshot = Some(new_snapshot);
-        }
         Ok(StateMachineChanges {
             last_applied: meta.last_log_id,
             is_snapshot: true,
@@ -543,10 +658,21 @@ impl RaftStorage<LogEntry, AppliedState> for RaftStoreBare {
         info!("get snapshot start");
         let snap = match &*self.current_snapshot.read().await {
             Some(snapshot) => {
-                let data = snapshot.data.clone();
+                let file = SnapshotFile::open(&snapshot.path)
+                    .await
+                    .map_err(|e| {
+                        MetaStorageError::SnapshotError(
+                            AnyError::new(&e)
+                                .add_context(|| format!("open {}", snapshot.path.display())),
+                        )
+                    })
+                    .map_to_sto_err(
+                        ErrorSubject::Snapshot(snapshot.meta.clone()),
+                        ErrorVerb::Read,
+                    )?;
                 Ok(Some(openraft::storage::Snapshot {
                     meta: snapshot.meta.clone(),
-                    snapshot: Box::new(Cursor::new(data)),
+                    snapshot: Box::new(file),
                 }))
             }
             None => Ok(None),
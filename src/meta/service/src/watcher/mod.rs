@@ -15,6 +15,7 @@
 mod watcher_manager;
 mod watcher_stream;
 
+pub use watcher_manager::StateMachineKvData;
 pub use watcher_manager::WatcherEvent;
 pub use watcher_manager::WatcherId;
 pub use watcher_manager::WatcherManager;
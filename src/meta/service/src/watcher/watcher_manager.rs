@@ -19,7 +19,6 @@ use common_base::base::tokio::sync::mpsc::Sender;
 use common_base::rangemap::RangeMap;
 use common_base::rangemap::RangeMapKey;
 use common_meta_raft_store::state_machine::StateMachineSubscriber;
-use common_meta_types::protobuf::watch_request::FilterType;
 use common_meta_types::protobuf::Event;
 use common_meta_types::protobuf::WatchRequest;
 use common_meta_types::protobuf::WatchResponse;
@@ -37,10 +36,15 @@ use crate::metrics::server_metrics;
 pub type WatcherId = i64;
 pub type WatcherStreamSender = Sender<Result<WatchResponse, Status>>;
 
-type CreateWatcherEvent = (WatchRequest, WatcherStreamSender);
+/// Max number of new changes buffered for a watcher while its history is being replayed.
+const REPLAY_RELAY_BUFFER: usize = 1024;
+
+/// A watch request, the stream to send events to, and the changes to replay.
+type CreateWatcherEvent = (WatchRequest, WatcherStreamSender, Vec<StateMachineKvData>);
 
 #[derive(Clone, Debug)]
 pub struct StateMachineKvData {
+    pub revision: u64,
     pub key: String,
     pub prev: Option<SeqV>,
     pub current: Option<SeqV>,
@@ -91,8 +95,17 @@ impl WatcherManager {
         }
     }
 
-    pub fn create_watcher_stream(&self, request: WatchRequest, tx: WatcherStreamSender) {
-        let create: CreateWatcherEvent = (request, tx);
+    /// Register a watcher that receives the changes in `history` first, then the new changes.
+    ///
+    /// To not miss or duplicate a change, `history` must be read from the state machine
+    /// while holding a lock that prevents applying any new change, until this call returns.
+    pub fn create_watcher_stream(
+        &self,
+        request: WatchRequest,
+        tx: WatcherStreamSender,
+        history: Vec<StateMachineKvData>,
+    ) {
+        let create: CreateWatcherEvent = (request, tx, history);
         let _ = self.event_tx.send(WatcherEvent::CreateWatcherEvent(create));
     }
 }
@@ -103,8 +116,8 @@ impl WatcherManagerCore {
         loop {
             if let Some(event) = self.event_rx.recv().await {
                 match event {
-                    WatcherEvent::CreateWatcherEvent((req, tx)) => {
-                        self.create_watcher_stream(req, tx).await;
+                    WatcherEvent::CreateWatcherEvent((req, tx, history)) => {
+                        self.create_watcher_stream(req, tx, history);
                     }
                     WatcherEvent::StateMachineKvDataEvent(kv) => {
                         self.notify_event(kv).await;
@@ -130,32 +143,19 @@ impl WatcherManagerCore {
             return;
         }
 
-        let current = kv.current;
-        let prev = kv.prev;
-
-        let is_delete_event = current.is_none();
+        let is_delete_event = kv.current.is_none();
         let mut remove_range_keys: Vec<RangeMapKey<String, WatcherId>> = vec![];
 
         for range_key_stream in set.iter() {
-            let filter = range_key_stream.1.filter_type;
-
             // filter out event
-            if (filter == FilterType::Delete && !is_delete_event)
-                || (filter == FilterType::Update && is_delete_event)
-            {
+            if !range_key_stream.1.accepts(is_delete_event) {
                 continue;
             }
 
             let watcher_id = range_key_stream.0.key;
             let stream = range_key_stream.1;
             assert_eq!(stream.id, watcher_id);
-            let resp = WatchResponse {
-                event: Some(Event {
-                    key: kv.key.clone(),
-                    current: current.clone().map(PbSeqV::from),
-                    prev: prev.clone().map(PbSeqV::from),
-                }),
-            };
+            let resp = Self::watch_response(&kv);
 
             network_metrics::incr_sent_bytes(resp.encoded_len() as u64);
 
@@ -176,9 +176,29 @@ impl WatcherManagerCore {
         }
     }
 
-    #[tracing::instrument(level = "debug", skip(self))]
-    pub async fn create_watcher_stream(&mut self, create: WatchRequest, tx: WatcherStreamSender) {
-        info!("create_watcher_stream: {:?}", create);
+    fn watch_response(kv: &StateMachineKvData) -> WatchResponse {
+        WatchResponse {
+            event: Some(Event {
+                key: kv.key.clone(),
+                current: kv.current.clone().map(PbSeqV::from),
+                prev: kv.prev.clone().map(PbSeqV::from),
+            }),
+            revision: kv.revision,
+        }
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, history))]
+    pub fn create_watcher_stream(
+        &mut self,
+        create: WatchRequest,
+        tx: WatcherStreamSender,
+        history: Vec<StateMachineKvData>,
+    ) {
+        info!(
+            "create_watcher_stream: {:?}, replay {} changes",
+            create,
+            history.len()
+        );
 
         let range = match WatcherManagerCore::get_range_key(create.key.clone(), &create.key_end) {
             Ok(range) => range,
@@ -189,7 +209,7 @@ impl WatcherManagerCore {
         let watcher_id = self.current_watcher_id;
         let filter = create.filter_type();
 
-        let watcher_stream = WatcherStream::new(
+        let mut watcher_stream = WatcherStream::new(
             watcher_id,
             filter,
             tx,
@@ -197,12 +217,61 @@ impl WatcherManagerCore {
             range.end.clone(),
         );
 
+        let replay = history
+            .iter()
+            .filter(|kv| {
+                watcher_stream.contains(&kv.key) && watcher_stream.accepts(kv.current.is_none())
+            })
+            .map(Self::watch_response)
+            .collect::<Vec<_>>();
+
+        // The history is replayed by a task of this watcher, so that the manager is not blocked
+        // by a slow stream. New changes are buffered in the relay until the replay is done.
+        if !replay.is_empty() {
+            let (relay_tx, relay_rx) = mpsc::channel(REPLAY_RELAY_BUFFER);
+            let tx = watcher_stream.replace_tx(relay_tx);
+            tokio::spawn(Self::replay_and_relay(watcher_id, replay, relay_rx, tx));
+        }
+
         self.watcher_range_map
             .insert(range, watcher_id, watcher_stream);
 
         server_metrics::incr_watchers(1);
     }
 
+    /// Send the `replay` changes to `tx`, then forward the new changes received from `relay_rx`.
+    ///
+    /// When it returns, `relay_rx` is dropped and the manager closes the watcher on the next
+    /// change sent to it.
+    async fn replay_and_relay(
+        watcher_id: WatcherId,
+        replay: Vec<WatchResponse>,
+        mut relay_rx: mpsc::Receiver<Result<WatchResponse, Status>>,
+        tx: WatcherStreamSender,
+    ) {
+        for resp in replay {
+            network_metrics::incr_sent_bytes(resp.encoded_len() as u64);
+
+            if let Err(err) = tx.send(Ok(resp)).await {
+                warn!(
+                    "close watcher stream {:?} when replaying history, cause send err: {:?}",
+                    watcher_id, err
+                );
+                return;
+            }
+        }
+
+        while let Some(resp) = relay_rx.recv().await {
+            if let Err(err) = tx.send(resp).await {
+                warn!(
+                    "close watcher stream {:?} cause send err: {:?}",
+                    watcher_id, err
+                );
+                return;
+            }
+        }
+    }
+
     fn get_range_key(key: String, key_end: &Option<String>) -> Result<Range<String>, bool> {
         match key_end {
             Some(key_end) => {
@@ -217,10 +286,11 @@ impl WatcherManagerCore {
 }
 
 impl StateMachineSubscriber for WatcherStateMachineSubscriber {
-    fn kv_changed(&self, key: &str, prev: Option<SeqV>, current: Option<SeqV>) {
+    fn kv_changed(&self, revision: u64, key: &str, prev: Option<SeqV>, current: Option<SeqV>) {
         let _ = self
             .event_tx
             .send(WatcherEvent::StateMachineKvDataEvent(StateMachineKvData {
+                revision,
                 key: key.to_string(),
                 prev,
                 current,
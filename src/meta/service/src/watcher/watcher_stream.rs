@@ -49,6 +49,29 @@ impl WatcherStream {
         }
     }
 
+    /// Whether a change of `key` is in the watched range.
+    pub fn contains(&self, key: &str) -> bool {
+        if self.key == self.key_end {
+            key == self.key
+        } else {
+            self.key.as_str() <= key && key < self.key_end.as_str()
+        }
+    }
+
+    /// Whether the filter type allows a change, a delete or an update.
+    pub fn accepts(&self, is_delete_event: bool) -> bool {
+        match self.filter_type {
+            FilterType::All => true,
+            FilterType::Update => !is_delete_event,
+            FilterType::Delete => is_delete_event,
+        }
+    }
+
+    /// Replace the sender of this stream and return the previous one.
+    pub fn replace_tx(&mut self, tx: WatcherStreamSender) -> WatcherStreamSender {
+        std::mem::replace(&mut self.tx, tx)
+    }
+
     pub async fn send(
         &self,
         resp: WatchResponse,
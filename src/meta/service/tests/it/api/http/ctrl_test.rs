@@ -0,0 +1,175 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::base::tokio;
+use common_meta_sled_store::openraft::State;
+use common_meta_types::JoinRequest;
+use common_meta_types::LeaveRequest;
+use databend_meta::api::http::v1::cluster_state::status_handler;
+use databend_meta::api::http::v1::ctrl::add_node;
+use databend_meta::api::http::v1::ctrl::maintenance;
+use databend_meta::api::http::v1::ctrl::remove_node;
+use databend_meta::api::http::v1::ctrl::MaintenanceRequest;
+use databend_meta::meta_service::MetaNode;
+use maplit::btreeset;
+use poem::get;
+use poem::http::Method;
+use poem::http::StatusCode;
+use poem::http::Uri;
+use poem::post;
+use poem::Endpoint;
+use poem::EndpointExt;
+use poem::Request;
+use poem::Response;
+use poem::Route;
+use pretty_assertions::assert_eq;
+use serde::Serialize;
+use tracing::info;
+
+use crate::init_meta_ut;
+use crate::tests::meta_node::start_meta_node_leader;
+use crate::tests::meta_node::timeout;
+use crate::tests::service::MetaSrvTestContext;
+
+#[async_entry::test(worker_threads = 3, init = "init_meta_ut!()", tracing_span = "debug")]
+async fn test_http_ctrl_membership() -> anyhow::Result<()> {
+    // - Start a leader and an uninitialized node-1.
+    // - Add node-1 as a learner, then promote it to a voter.
+    // - Check the replication progress of node-1 in status.
+    // - Remove node-1.
+
+    let (_nid0, tc0) = start_meta_node_leader().await?;
+    let mn0 = tc0.meta_node();
+
+    let tc1 = MetaSrvTestContext::new(1);
+    let mn1 = MetaNode::open_create_boot(&tc1.config.raft_config, None, Some(()), None).await?;
+
+    let router = Route::new()
+        .at("/ctrl/add_node", post(add_node))
+        .at("/ctrl/remove_node", post(remove_node))
+        .at("/cluster/status", get(status_handler))
+        .data(mn0.clone());
+
+    let mut join = JoinRequest {
+        node_id: 1,
+        endpoint: tc1.config.raft_config.raft_api_addr().await?,
+        grpc_api_addr: tc1.config.grpc_api_address.clone(),
+        learner: true,
+    };
+
+    info!("--- add node-1 as a learner");
+    {
+        let status = post_json(&router, "/ctrl/add_node", &join).await;
+        assert_eq!(StatusCode::OK, status);
+
+        mn1.raft
+            .wait(timeout())
+            .state(State::Learner, "node-1 is a learner")
+            .await?;
+        mn0.raft
+            .wait(timeout())
+            .members(btreeset! {0}, "node-1 does not vote")
+            .await?;
+    }
+
+    info!("--- promote node-1 to a voter");
+    {
+        join.learner = false;
+        let status = post_json(&router, "/ctrl/add_node", &join).await;
+        assert_eq!(StatusCode::OK, status);
+
+        mn0.raft
+            .wait(timeout())
+            .members(btreeset! {0,1}, "node-1 is a voter")
+            .await?;
+    }
+
+    info!("--- status shows the replication progress of node-1");
+    {
+        let response = router
+            .call(
+                Request::builder()
+                    .uri(Uri::from_static("/cluster/status"))
+                    .method(Method::GET)
+                    .finish(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(StatusCode::OK, response.status());
+
+        let body = response.into_body().into_vec().await.unwrap();
+        let state: serde_json::Value = serde_json::from_slice(&body)?;
+        assert!(state["replication"]["1"]["matched_index"].is_u64());
+        assert!(state["replication"]["1"]["lag"].is_u64());
+    }
+
+    info!("--- remove node-1");
+    {
+        let status = post_json(&router, "/ctrl/remove_node", &LeaveRequest { node_id: 1 }).await;
+        assert_eq!(StatusCode::OK, status);
+
+        mn0.raft
+            .wait(timeout())
+            .members(btreeset! {0}, "node-1 is removed")
+            .await?;
+        assert!(mn0.get_node(&1).await?.is_none());
+    }
+
+    Ok(())
+}
+
+#[async_entry::test(worker_threads = 3, init = "init_meta_ut!()", tracing_span = "debug")]
+async fn test_http_ctrl_maintenance() -> anyhow::Result<()> {
+    let (_nid0, tc0) = start_meta_node_leader().await?;
+    let mn0 = tc0.meta_node();
+
+    let router = Route::new()
+        .at("/ctrl/maintenance", post(maintenance))
+        .data(mn0.clone());
+
+    let status = post_json(&router, "/ctrl/maintenance", &MaintenanceRequest {
+        enabled: true,
+    })
+    .await;
+    assert_eq!(StatusCode::OK, status);
+    assert!(mn0.is_maintenance());
+    assert!(mn0.get_status().await?.maintenance);
+
+    let status = post_json(&router, "/ctrl/maintenance", &MaintenanceRequest {
+        enabled: false,
+    })
+    .await;
+    assert_eq!(StatusCode::OK, status);
+    assert!(!mn0.is_maintenance());
+
+    Ok(())
+}
+
+async fn post_json(
+    router: &impl Endpoint<Output = Response>,
+    uri: &'static str,
+    body: &impl Serialize,
+) -> StatusCode {
+    let response = router
+        .call(
+            Request::builder()
+                .uri(Uri::from_static(uri))
+                .method(Method::POST)
+                .content_type("application/json")
+                .body(serde_json::to_vec(body).unwrap()),
+        )
+        .await
+        .unwrap();
+    response.status()
+}
@@ -45,6 +45,7 @@ metric_api_address = "127.0.0.1:8000"
 admin_api_address = "127.0.0.1:9000"
 admin_tls_server_cert = "admin tls cert"
 admin_tls_server_key = "admin tls key"
+admin_api_cluster_ctrl_enabled = true
 grpc_api_address = "127.0.0.1:10000"
 grpc_tls_server_cert = "grpc server cert"
 grpc_tls_server_key = "grpc server key"
@@ -59,6 +60,7 @@ no_sync = true
 snapshot_logs_since_last = 1000
 heartbeat_interval = 2000
 install_snapshot_timeout = 3000
+max_snapshot_files_to_keep = 5
 single = false
 join = ["j1", "j2"]
 id = 20
@@ -74,6 +76,7 @@ cluster_name = "foo_cluster"
         assert_eq!(cfg.admin_api_address, "127.0.0.1:9000");
         assert_eq!(cfg.admin_tls_server_cert, "admin tls cert");
         assert_eq!(cfg.admin_tls_server_key, "admin tls key");
+        assert!(cfg.admin_api_cluster_ctrl_enabled);
         assert_eq!(cfg.grpc_api_address, "127.0.0.1:10000");
         assert_eq!(cfg.grpc_tls_server_cert, "grpc server cert");
         assert_eq!(cfg.grpc_tls_server_key, "grpc server key");
@@ -85,6 +88,7 @@ cluster_name = "foo_cluster"
         assert_eq!(cfg.raft_config.snapshot_logs_since_last, 1000);
         assert_eq!(cfg.raft_config.heartbeat_interval, 2000);
         assert_eq!(cfg.raft_config.install_snapshot_timeout, 3000);
+        assert_eq!(cfg.raft_config.max_snapshot_files_to_keep, 5);
         assert!(!cfg.raft_config.single);
         assert_eq!(cfg.raft_config.join, vec!["j1", "j2"]);
         assert_eq!(cfg.raft_config.id, 20);
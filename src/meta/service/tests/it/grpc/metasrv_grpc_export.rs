@@ -88,7 +88,11 @@ async fn test_export() -> anyhow::Result<()> {
         r#"["test-29000-state_machine/0",{"GenericKV":{"key":"bar","value":{"seq":2,"meta":null,"data":[98,97,114]}}}]"#,
         r#"["test-29000-state_machine/0",{"GenericKV":{"key":"foo","value":{"seq":1,"meta":null,"data":[102,111,111]}}}]"#,
         r#"["test-29000-state_machine/0",{"GenericKV":{"key":"wow","value":{"seq":3,"meta":null,"data":[119,111,119]}}}]"#,
+        r#"["test-29000-state_machine/0",{"Sequences":{"key":"change-log","value":3}}]"#,
         r#"["test-29000-state_machine/0",{"Sequences":{"key":"generic-kv","value":3}}]"#,
+        r#"["test-29000-state_machine/0",{"ChangeLogs":{"key":1,"value":{"key":"foo","prev":null,"current":{"seq":1,"meta":null,"data":[102,111,111]}}}}]"#,
+        r#"["test-29000-state_machine/0",{"ChangeLogs":{"key":2,"value":{"key":"bar","prev":null,"current":{"seq":2,"meta":null,"data":[98,97,114]}}}}]"#,
+        r#"["test-29000-state_machine/0",{"ChangeLogs":{"key":3,"value":{"key":"wow","prev":null,"current":{"seq":3,"meta":null,"data":[119,111,119]}}}}]"#,
     ];
 
     // The addresses are built from random number.
@@ -61,9 +61,15 @@ async fn test_metasrv_handshake() -> anyhow::Result<()> {
         let min_client_ver = &MIN_METACLI_SEMVER;
         let cli_ver = smaller_ver(min_client_ver);
 
-        let res =
-            MetaGrpcClient::handshake(&mut client, &cli_ver, &MIN_METASRV_SEMVER, "root", "xxx")
-                .await;
+        let res = MetaGrpcClient::handshake(
+            &mut client,
+            &cli_ver,
+            &MIN_METASRV_SEMVER,
+            "root",
+            "xxx",
+            None,
+        )
+        .await;
 
         debug!("handshake res: {:?}", res);
         let e = res.unwrap_err();
@@ -87,6 +93,7 @@ async fn test_metasrv_handshake() -> anyhow::Result<()> {
             &min_srv_ver,
             "root",
             "xxx",
+            None,
         )
         .await;
 
@@ -112,7 +119,8 @@ async fn test_metasrv_handshake() -> anyhow::Result<()> {
         let zero = Version::new(0, 0, 0);
 
         let res =
-            MetaGrpcClient::handshake(&mut client, &zero, &MIN_METASRV_SEMVER, "root", "xxx").await;
+            MetaGrpcClient::handshake(&mut client, &zero, &MIN_METASRV_SEMVER, "root", "xxx", None)
+                .await;
 
         debug!("handshake res: {:?}", res);
         assert!(res.is_ok());
@@ -0,0 +1,81 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use common_base::base::tokio;
+use common_meta_api::KVApi;
+use common_meta_client::MetaGrpcClient;
+use common_meta_types::RangeKVReq;
+use common_meta_types::UpsertKVReq;
+use pretty_assertions::assert_eq;
+
+use crate::init_meta_ut;
+
+#[async_entry::test(worker_threads = 3, init = "init_meta_ut!()", tracing_span = "debug")]
+async fn test_kv_range_stream() -> anyhow::Result<()> {
+    // - Write more keys than a server side page holds.
+    // - Read them back with a KvRange stream, with and without a limit.
+
+    let (_tc, addr) = crate::tests::start_metasrv().await?;
+
+    let client = MetaGrpcClient::try_create(
+        vec![addr],
+        "root",
+        "xxx",
+        None,
+        Some(Duration::from_secs(10)),
+        None,
+    )?;
+
+    let n = 1500;
+    let keys = (0..n).map(|i| format!("r/{:05}", i)).collect::<Vec<_>>();
+    for k in keys.iter() {
+        client
+            .upsert_kv(UpsertKVReq::update(k, k.as_bytes()))
+            .await?;
+    }
+    client.upsert_kv(UpsertKVReq::update("s", b"")).await?;
+
+    let read_all = |req: RangeKVReq| {
+        let client = client.clone();
+        async move {
+            let mut strm = client.kv_range(req).await?;
+            let mut got = vec![];
+            while let Some(item) = strm.message().await? {
+                got.push((item.key, item.value.unwrap().data));
+            }
+            Ok::<_, anyhow::Error>(got)
+        }
+    };
+
+    let got = read_all(RangeKVReq::prefix("r/")).await?;
+    assert_eq!(
+        keys.iter()
+            .map(|k| (k.clone(), k.as_bytes().to_vec()))
+            .collect::<Vec<_>>(),
+        got
+    );
+
+    let got = read_all(RangeKVReq::prefix("r/").with_limit(1200).reverse()).await?;
+    assert_eq!(1200, got.len());
+    assert_eq!(keys[n - 1], got[0].0);
+    assert_eq!(keys[n - 1200], got[1199].0);
+
+    let got = read_all(RangeKVReq::new("r/", None).keys_only()).await?;
+    assert_eq!(n + 1, got.len());
+    assert!(got.iter().all(|(_, v)| v.is_empty()));
+
+    Ok(())
+}
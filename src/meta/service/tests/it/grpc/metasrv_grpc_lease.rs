@@ -0,0 +1,99 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use common_base::base::tokio;
+use common_meta_api::KVApi;
+use common_meta_client::MetaGrpcClient;
+use common_meta_types::protobuf::TxnRequest;
+use common_meta_types::txn_op;
+use common_meta_types::TxnOp;
+use common_meta_types::TxnPutRequest;
+use common_meta_types::UpsertKVReq;
+use pretty_assertions::assert_eq;
+
+use crate::init_meta_ut;
+
+#[async_entry::test(worker_threads = 3, init = "init_meta_ut!()", tracing_span = "debug")]
+async fn test_lease() -> anyhow::Result<()> {
+    // - Grant a lease and attach keys to it with upsert and txn.
+    // - Keep it alive.
+    // - Revoke it and assert the keys are deleted.
+
+    let (_tc, addr) = crate::tests::start_metasrv().await?;
+
+    let client = MetaGrpcClient::try_create(
+        vec![addr],
+        "root",
+        "xxx",
+        None,
+        Some(Duration::from_secs(10)),
+        None,
+    )?;
+
+    let granted = client.lease_grant(Duration::from_secs(60)).await?;
+    assert_eq!(60_000, granted.ttl_ms);
+    let lease_id = granted.lease_id;
+
+    client
+        .upsert_kv(UpsertKVReq::update("k1", b"v1").with_lease(lease_id))
+        .await?;
+
+    let txn = TxnRequest {
+        condition: vec![],
+        if_then: vec![TxnOp {
+            request: Some(txn_op::Request::Put(TxnPutRequest {
+                key: "k2".to_string(),
+                value: b"v2".to_vec(),
+                prev_value: false,
+                expire_at: None,
+                lease: Some(lease_id),
+            })),
+        }],
+        else_then: vec![],
+    };
+    client.transaction(txn).await?;
+
+    for key in ["k1", "k2"] {
+        let got = client.get_kv(key).await?;
+        assert_eq!(
+            Some(lease_id),
+            got.and_then(|x| x.meta).and_then(|m| m.lease)
+        );
+    }
+
+    let kept = client.lease_keep_alive(lease_id).await?;
+    assert_eq!(lease_id, kept.lease_id);
+    assert!(kept.expire_at_ms >= granted.expire_at_ms);
+
+    let revoked = client.lease_revoke(lease_id).await?;
+    assert_eq!(lease_id, revoked.lease_id);
+
+    for key in ["k1", "k2"] {
+        assert_eq!(None, client.get_kv(key).await?, "{} is deleted", key);
+    }
+
+    // The lease is gone.
+
+    let err = client.lease_keep_alive(lease_id).await.unwrap_err();
+    assert!(err.to_string().contains("lease not found"), "{}", err);
+
+    let res = client
+        .upsert_kv(UpsertKVReq::update("k3", b"v3").with_lease(lease_id))
+        .await?;
+    assert_eq!(None, res.result, "can not attach to a revoked lease");
+
+    Ok(())
+}
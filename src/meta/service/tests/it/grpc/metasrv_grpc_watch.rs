@@ -37,6 +37,7 @@ use common_meta_types::TxnPutRequest;
 use common_meta_types::UpsertKVReq;
 
 use crate::init_meta_ut;
+use crate::tests::service::MetaSrvTestContext;
 
 async fn upsert_kv_client_main(addr: String, updates: Vec<UpsertKVReq>) -> anyhow::Result<()> {
     let client = MetaGrpcClient::try_create(
@@ -163,6 +164,7 @@ async fn test_watch() -> common_exception::Result<()> {
             key: "a".to_string(),
             key_end: Some("z".to_string()),
             filter_type: FilterType::All.into(),
+            start_revision: None,
         };
 
         let key_a = "a".to_string();
@@ -236,6 +238,7 @@ async fn test_watch() -> common_exception::Result<()> {
             key_end: None,
             // filter only delete events
             filter_type: FilterType::Delete.into(),
+            start_revision: None,
         };
 
         let key = key_str.to_string();
@@ -323,6 +326,7 @@ async fn test_watch() -> common_exception::Result<()> {
             key: start,
             key_end: Some(end),
             filter_type: FilterType::All.into(),
+            start_revision: None,
         };
 
         let conditions = vec![TxnCondition {
@@ -338,6 +342,7 @@ async fn test_watch() -> common_exception::Result<()> {
                     value: txn_val.clone(),
                     prev_value: true,
                     expire_at: None,
+                    lease: None,
                 })),
             },
             TxnOp {
@@ -396,6 +401,192 @@ async fn test_watch() -> common_exception::Result<()> {
     Ok(())
 }
 
+/// Write kvs and return a client to the metasrv.
+async fn write_kvs(addr: &str, updates: Vec<UpsertKVReq>) -> anyhow::Result<MetaGrpcClient> {
+    let client = MetaGrpcClient::try_create(
+        vec![addr.to_string()],
+        "root",
+        "xxx",
+        None,
+        Some(Duration::from_secs(10)),
+        None,
+    )?;
+
+    for update in updates {
+        client.upsert_kv(update).await?;
+    }
+
+    Ok(client)
+}
+
+fn set_event(key: &str, seq: u64, val: &str, prev: Option<(u64, &str)>) -> Event {
+    Event {
+        key: key.to_string(),
+        current: Some(SeqV {
+            seq,
+            data: val.as_bytes().to_vec(),
+        }),
+        prev: prev.map(|(seq, val)| SeqV {
+            seq,
+            data: val.as_bytes().to_vec(),
+        }),
+    }
+}
+
+#[async_entry::test(worker_threads = 3, init = "init_meta_ut!()", tracing_span = "debug")]
+async fn test_watch_resume_from_revision() -> anyhow::Result<()> {
+    // - Write some kvs before watching.
+    // - Watch from a revision in the past.
+    // - Assert the watcher receives the missed changes, then the new ones, with their revisions.
+
+    let (_tc, addr) = crate::tests::start_metasrv().await?;
+
+    let client = write_kvs(&addr, vec![
+        UpsertKVReq::new("a", MatchSeq::Any, Operation::Update(b"a".to_vec()), None),
+        UpsertKVReq::new("b", MatchSeq::Any, Operation::Update(b"b".to_vec()), None),
+        UpsertKVReq::new("a", MatchSeq::Any, Operation::Update(b"a2".to_vec()), None),
+    ])
+    .await?;
+
+    let watch = WatchRequest {
+        key: "a".to_string(),
+        key_end: Some("b".to_string()),
+        filter_type: FilterType::All.into(),
+        start_revision: Some(1),
+    };
+    let mut stream = client.request(watch).await?;
+
+    client
+        .upsert_kv(UpsertKVReq::new(
+            "a",
+            MatchSeq::Any,
+            Operation::Delete,
+            None,
+        ))
+        .await?;
+
+    let want = vec![
+        (1, set_event("a", 1, "a", None)),
+        (3, set_event("a", 3, "a2", Some((1, "a")))),
+        (4, Event {
+            key: "a".to_string(),
+            prev: Some(SeqV {
+                seq: 3,
+                data: b"a2".to_vec(),
+            }),
+            current: None,
+        }),
+    ];
+
+    for (revision, event) in want {
+        let resp = stream.message().await?.unwrap();
+        assert_eq!(revision, resp.revision);
+        assert_eq!(Some(event), resp.event);
+    }
+
+    Ok(())
+}
+
+#[async_entry::test(worker_threads = 3, init = "init_meta_ut!()", tracing_span = "debug")]
+async fn test_watch_resume_from_compacted_revision() -> anyhow::Result<()> {
+    // - Keep only 2 changes.
+    // - Watching from a trimmed revision fails, watching from a kept one replays it.
+
+    let mut tc = MetaSrvTestContext::new(0);
+    tc.config.raft_config.max_change_log_to_keep = 2;
+    crate::tests::start_metasrv_with_context(&mut tc).await?;
+    let addr = tc.config.grpc_api_address.clone();
+
+    let updates = (1..=4)
+        .map(|i| {
+            let k = format!("k{}", i);
+            UpsertKVReq::new(
+                &k,
+                MatchSeq::Any,
+                Operation::Update(k.clone().into_bytes()),
+                None,
+            )
+        })
+        .collect();
+    let client = write_kvs(&addr, updates).await?;
+
+    let watch = |start_revision| WatchRequest {
+        key: "k".to_string(),
+        key_end: Some("l".to_string()),
+        filter_type: FilterType::All.into(),
+        start_revision: Some(start_revision),
+    };
+
+    let res = client.request(watch(2)).await;
+    let err = res.unwrap_err().to_string();
+    assert!(err.contains("revision 2 is compacted"), "{}", err);
+
+    let mut stream = client.request(watch(3)).await?;
+    for (revision, key) in [(3, "k3"), (4, "k4")] {
+        let resp = stream.message().await?.unwrap();
+        assert_eq!(revision, resp.revision);
+        assert_eq!(Some(set_event(key, revision, key, None)), resp.event);
+    }
+
+    Ok(())
+}
+
+#[async_entry::test(worker_threads = 3, init = "init_meta_ut!()", tracing_span = "debug")]
+async fn test_watch_replay_does_not_block_other_watchers() -> anyhow::Result<()> {
+    // - Write more history than the transport buffers of a stream.
+    // - Watch from revision 1 but never read the stream, the replay stalls.
+    // - Assert another watcher still receives new changes.
+
+    let (_tc, addr) = crate::tests::start_metasrv().await?;
+
+    let big = vec![b'x'; 4 * 1024];
+    let updates = (0..128)
+        .map(|i| {
+            UpsertKVReq::new(
+                &format!("h{:03}", i),
+                MatchSeq::Any,
+                Operation::Update(big.clone()),
+                None,
+            )
+        })
+        .collect();
+    let client = write_kvs(&addr, updates).await?;
+
+    let _stalled = client
+        .request(WatchRequest {
+            key: "h".to_string(),
+            key_end: Some("i".to_string()),
+            filter_type: FilterType::All.into(),
+            start_revision: Some(1),
+        })
+        .await?;
+
+    let mut stream = client
+        .request(WatchRequest {
+            key: "w".to_string(),
+            key_end: None,
+            filter_type: FilterType::All.into(),
+            start_revision: None,
+        })
+        .await?;
+
+    client
+        .upsert_kv(UpsertKVReq::new(
+            "w",
+            MatchSeq::Any,
+            Operation::Update(b"w".to_vec()),
+            None,
+        ))
+        .await?;
+
+    let resp = tokio::time::timeout(Duration::from_secs(5), stream.message())
+        .await??
+        .unwrap();
+    assert_eq!(Some(set_event("w", 129, "w", None)), resp.event);
+
+    Ok(())
+}
+
 #[test]
 fn prefix_of_string_test() -> common_exception::Result<()> {
     assert_eq!("b".to_string(), prefix_of_string("a")?);
@@ -66,6 +66,8 @@ async fn test_meta_node_replicate_kv_with_expire() -> anyhow::Result<()> {
                     value: Operation::Update(key.to_string().into_bytes()),
                     value_meta: Some(KVMeta {
                         expire_at: Some(now_sec + 3),
+                        lease: None,
+                        tenant: None,
                     }),
                 }),
             })
@@ -79,7 +81,9 @@ async fn test_meta_node_replicate_kv_with_expire() -> anyhow::Result<()> {
         let seq_v = resp.unwrap();
         assert_eq!(
             Some(KVMeta {
-                expire_at: Some(now_sec + 3)
+                expire_at: Some(now_sec + 3),
+                lease: None,
+                tenant: None
             }),
             seq_v.meta
         );
@@ -98,6 +102,8 @@ async fn test_meta_node_replicate_kv_with_expire() -> anyhow::Result<()> {
                     value: Operation::Update(value2.to_string().into_bytes()),
                     value_meta: Some(KVMeta {
                         expire_at: Some(now_sec + 1000),
+                        lease: None,
+                        tenant: None,
                     }),
                 }),
             })
@@ -112,6 +118,8 @@ async fn test_meta_node_replicate_kv_with_expire() -> anyhow::Result<()> {
         assert_eq!(
             Some(KVMeta {
                 expire_at: Some(now_sec + 1000),
+                lease: None,
+                tenant: None,
             }),
             seq_v.meta
         );
@@ -141,6 +149,8 @@ async fn test_meta_node_replicate_kv_with_expire() -> anyhow::Result<()> {
         assert_eq!(
             Some(KVMeta {
                 expire_at: Some(now_sec + 1000),
+                lease: None,
+                tenant: None,
             }),
             seq_v.meta
         );
@@ -21,6 +21,8 @@ use common_meta_sled_store::openraft::State;
 use common_meta_types::protobuf::raft_service_client::RaftServiceClient;
 use common_meta_types::Cmd;
 use common_meta_types::Endpoint;
+use common_meta_types::GetKVReply;
+use common_meta_types::GetKVReq;
 use common_meta_types::LogEntry;
 use common_meta_types::NodeId;
 use common_meta_types::UpsertKV;
@@ -30,6 +32,7 @@ use databend_meta::meta_service::ForwardRequestBody;
 use databend_meta::meta_service::JoinRequest;
 use databend_meta::meta_service::LeaveRequest;
 use databend_meta::meta_service::MetaNode;
+use databend_meta::meta_service::ReadConsistency;
 use maplit::btreeset;
 use pretty_assertions::assert_eq;
 use tracing::info;
@@ -335,6 +338,87 @@ async fn test_meta_node_join_rejoin() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[async_entry::test(worker_threads = 5, init = "init_meta_ut!()", tracing_span = "debug")]
+async fn test_meta_node_join_leave_learner() -> anyhow::Result<()> {
+    // - Bring up a cluster
+    // - Join a new node as a learner.
+    // - Read a just written value on the learner.
+    // - Leave the learner.
+
+    let (mut _nlog, mut tcs) = start_meta_node_cluster(btreeset![0], btreeset![]).await?;
+    let all = test_context_nodes(&tcs);
+    let _tc0 = tcs.remove(0);
+    let leader = all[0].clone();
+
+    info!("--- bring up node-1");
+
+    let node_id = 1;
+    let tc1 = MetaSrvTestContext::new(node_id);
+    let mn1 = MetaNode::open_create_boot(&tc1.config.raft_config, None, Some(()), None).await?;
+
+    info!("--- join node-1 as a learner");
+
+    let req = ForwardRequest {
+        forward_to_leader: 1,
+        body: ForwardRequestBody::Join(JoinRequest {
+            node_id,
+            endpoint: tc1.config.raft_config.raft_api_addr().await?,
+            grpc_api_addr: tc1.config.grpc_api_address.clone(),
+            learner: true,
+        }),
+    };
+    leader.handle_forwardable_request(req).await?;
+
+    mn1.raft
+        .wait(timeout())
+        .current_leader(0, "node-1 has leader")
+        .await?;
+    mn1.raft
+        .wait(timeout())
+        .state(State::Learner, "node-1 is a learner")
+        .await?;
+    leader
+        .raft
+        .wait(timeout())
+        .members(btreeset! {0}, "node-1 does not vote")
+        .await?;
+
+    info!("--- a linearizable read on the learner sees the last write");
+    {
+        leader
+            .upsert_kv(UpsertKV::update("learner-key", b"v1"))
+            .await?;
+
+        let got = mn1.get_kv("learner-key").await?;
+        assert_eq!(b"v1".to_vec(), got.unwrap().data);
+    }
+
+    info!("--- a stale read is served by the learner");
+    {
+        let consistency = ReadConsistency::from_max_staleness_ms(60_000);
+        let got: GetKVReply = mn1
+            .read_with(consistency, GetKVReq {
+                key: "learner-key".to_string(),
+            })
+            .await?;
+        assert_eq!(b"v1".to_vec(), got.unwrap().data);
+    }
+
+    info!("--- leave node-1");
+    {
+        let req = ForwardRequest {
+            forward_to_leader: 1,
+            body: ForwardRequestBody::Leave(LeaveRequest { node_id }),
+        };
+        leader.handle_forwardable_request(req).await?;
+
+        let got = leader.get_node(&node_id).await?;
+        assert!(got.is_none(), "node-1 is removed");
+    }
+
+    Ok(())
+}
+
 #[async_entry::test(worker_threads = 5, init = "init_meta_ut!()", tracing_span = "debug")]
 async fn test_meta_node_restart() -> anyhow::Result<()> {
     // TODO check restarted follower.
@@ -509,6 +593,7 @@ fn join_req(
             node_id,
             endpoint,
             grpc_api_addr,
+            learner: false,
         }),
     }
 }
@@ -12,13 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::path::Path;
 use std::sync::Arc;
 use std::sync::Mutex;
 
 use common_base::base::tokio;
 use common_meta_raft_store::state_machine::testing::pretty_snapshot;
 use common_meta_raft_store::state_machine::testing::snapshot_logs;
-use common_meta_raft_store::state_machine::SerializableSnapshot;
+use common_meta_raft_store::state_machine::SnapshotFileReader;
+use common_meta_raft_store::state_machine::SnapshotKeyValue;
 use common_meta_sled_store::openraft::async_trait::async_trait;
 use common_meta_sled_store::openraft::raft::Entry;
 use common_meta_sled_store::openraft::raft::EntryPayload;
@@ -160,10 +162,8 @@ async fn test_meta_store_build_snapshot() -> anyhow::Result<()> {
 
     info!("--- check snapshot");
     {
-        let data = curr_snap.snapshot.into_inner();
-
-        let ser_snap: SerializableSnapshot = serde_json::from_slice(&data)?;
-        let res = pretty_snapshot(&ser_snap.kvs);
+        let kvs = read_snapshot_file(curr_snap.snapshot.path())?;
+        let res = pretty_snapshot(&kvs);
         debug!("res: {:?}", res);
 
         assert_eq!(want, res);
@@ -204,10 +204,8 @@ async fn test_meta_store_current_snapshot() -> anyhow::Result<()> {
 
     info!("--- check snapshot");
     {
-        let data = curr_snap.snapshot.into_inner();
-
-        let ser_snap: SerializableSnapshot = serde_json::from_slice(&data)?;
-        let res = pretty_snapshot(&ser_snap.kvs);
+        let kvs = read_snapshot_file(curr_snap.snapshot.path())?;
+        let res = pretty_snapshot(&kvs);
         debug!("res: {:?}", res);
 
         assert_eq!(want, res);
@@ -241,7 +239,7 @@ async fn test_meta_store_install_snapshot() -> anyhow::Result<()> {
         snap = sto.build_snapshot().await?;
     }
 
-    let data = snap.snapshot.into_inner();
+    let path = snap.snapshot.path().to_path_buf();
 
     info!("--- reopen a new metasrv to install snapshot");
     {
@@ -252,7 +250,7 @@ async fn test_meta_store_install_snapshot() -> anyhow::Result<()> {
         info!("--- rejected because old sm is not cleaned");
         {
             sto.raft_state.write_state_machine_id(&(1, 2)).await?;
-            let res = sto.install_snapshot(&data).await;
+            let res = sto.install_snapshot(&path).await;
             assert!(res.is_err(), "different ids disallow installing snapshot");
             assert!(
                 res.unwrap_err()
@@ -264,7 +262,7 @@ async fn test_meta_store_install_snapshot() -> anyhow::Result<()> {
         info!("--- install snapshot");
         {
             sto.raft_state.write_state_machine_id(&(0, 0)).await?;
-            sto.install_snapshot(&data).await?;
+            sto.install_snapshot(&path).await?;
         }
 
         info!("--- check installed meta");
@@ -287,10 +285,8 @@ async fn test_meta_store_install_snapshot() -> anyhow::Result<()> {
         info!("--- check snapshot");
         {
             let curr_snap = sto.build_snapshot().await?;
-            let data = curr_snap.snapshot.into_inner();
-
-            let ser_snap: SerializableSnapshot = serde_json::from_slice(&data)?;
-            let res = pretty_snapshot(&ser_snap.kvs);
+            let kvs = read_snapshot_file(curr_snap.snapshot.path())?;
+            let res = pretty_snapshot(&kvs);
             debug!("res: {:?}", res);
 
             assert_eq!(want, res);
@@ -299,3 +295,80 @@ async fn test_meta_store_install_snapshot() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[async_entry::test(worker_threads = 3, init = "init_meta_ut!()", tracing_span = "debug")]
+async fn test_meta_store_install_corrupted_snapshot() -> anyhow::Result<()> {
+    // - Create a metasrv and build a snapshot
+    // - Corrupt the snapshot file
+    // - Installing it is rejected and the state machine is untouched
+
+    let (logs, _want) = snapshot_logs();
+
+    let id = 3;
+    let tc = MetaSrvTestContext::new(id);
+
+    let sto = RaftStoreBare::open_create(&tc.config.raft_config, None, Some(())).await?;
+
+    sto.log.append(&logs).await?;
+    for l in logs.iter() {
+        sto.state_machine.write().await.apply(l).await?;
+    }
+    let snap = sto.build_snapshot().await?;
+
+    let mut data = std::fs::read(snap.snapshot.path())?;
+    let last = data.len() - 1;
+    data[last] ^= 0xff;
+
+    let corrupted = snap.snapshot.path().with_extension("corrupted");
+    std::fs::write(&corrupted, data)?;
+
+    let res = sto.install_snapshot(&corrupted).await;
+    let err = res.unwrap_err().to_string();
+    assert!(err.contains("checksum mismatch"), "{}", err);
+
+    assert_eq!((0, 0), sto.raft_state.read_state_machine_id()?);
+
+    Ok(())
+}
+
+#[async_entry::test(worker_threads = 3, init = "init_meta_ut!()", tracing_span = "debug")]
+async fn test_meta_store_reopen_current_snapshot() -> anyhow::Result<()> {
+    // - Create a metasrv and build a snapshot
+    // - Reopen it, the snapshot file is loaded as the current snapshot
+
+    let (logs, want) = snapshot_logs();
+
+    let id = 3;
+    let tc = MetaSrvTestContext::new(id);
+
+    {
+        let sto = RaftStoreBare::open_create(&tc.config.raft_config, None, Some(())).await?;
+
+        sto.log.append(&logs).await?;
+        for l in logs.iter() {
+            sto.state_machine.write().await.apply(l).await?;
+        }
+        sto.build_snapshot().await?;
+    }
+
+    let sto = RaftStoreBare::open_create(&tc.config.raft_config, Some(()), None).await?;
+
+    let curr_snap = sto.get_current_snapshot().await?.unwrap();
+    assert_eq!(
+        Some(LogId { term: 1, index: 9 }),
+        curr_snap.meta.last_log_id
+    );
+
+    let kvs = read_snapshot_file(curr_snap.snapshot.path())?;
+    assert_eq!(want, pretty_snapshot(&kvs));
+
+    Ok(())
+}
+
+fn read_snapshot_file(path: &Path) -> anyhow::Result<Vec<SnapshotKeyValue>> {
+    let f = std::fs::File::open(path)?;
+    let kvs = SnapshotFileReader::new(f)?
+        .map(|kv| kv.map(|(k, v)| vec![k, v]))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(kvs)
+}
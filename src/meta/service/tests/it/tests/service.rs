@@ -129,6 +129,12 @@ impl MetaSrvTestContext {
         // We use a single sled db for all unit test. Every unit test need a unique prefix so that it opens different tree.
         config.raft_config.sled_tree_prefix = format!("test-{}-", config_id);
 
+        // Snapshot files are stored in `raft_dir`, in a sub dir named with the same unique prefix.
+        config.raft_config.raft_dir = std::env::temp_dir()
+            .join("databend-meta-ut")
+            .to_string_lossy()
+            .to_string();
+
         {
             let grpc_port = next_port();
             config.grpc_api_address = format!("{}:{}", host, grpc_port);
@@ -26,6 +26,8 @@ use common_meta_types::KVAppError;
 use common_meta_types::ListKVReply;
 use common_meta_types::MGetKVReply;
 use common_meta_types::MetaError;
+use common_meta_types::RangeKVReply;
+use common_meta_types::RangeKVReq;
 use common_meta_types::TxnReply;
 use common_meta_types::TxnRequest;
 use common_meta_types::UpsertKVReply;
@@ -97,6 +99,13 @@ impl KVApi for MetaStore {
         }
     }
 
+    async fn range_kv(&self, req: RangeKVReq) -> Result<RangeKVReply, KVAppError> {
+        match self {
+            MetaStore::L(x) => x.range_kv(req).await,
+            MetaStore::R(x) => x.range_kv(req).await,
+        }
+    }
+
     async fn transaction(&self, txn: TxnRequest) -> Result<TxnReply, KVAppError> {
         match self {
             MetaStore::L(x) => x.transaction(txn).await,
@@ -63,6 +63,22 @@ fn build_proto() {
             "TxnDeleteByPrefixRequest",
             "#[derive(Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]",
         )
+        .type_attribute(
+            "TxnGetRangeRequest",
+            "#[derive(Eq, serde::Serialize, serde::Deserialize)]",
+        )
+        .type_attribute(
+            "TxnPutIfAbsentRequest",
+            "#[derive(Eq, serde::Serialize, serde::Deserialize)]",
+        )
+        .type_attribute(
+            "TxnFetchAddU64Request",
+            "#[derive(Eq, serde::Serialize, serde::Deserialize)]",
+        )
+        .type_attribute(
+            "KeysInRange",
+            "#[derive(Eq, serde::Serialize, serde::Deserialize)]",
+        )
         .type_attribute(
             "TxnCondition.ConditionResult",
             "#[derive(serde::Serialize, serde::Deserialize, num_derive::FromPrimitive)]",
@@ -103,6 +119,18 @@ fn build_proto() {
             "TxnDeleteByPrefixResponse",
             "#[derive(Eq, serde::Serialize, serde::Deserialize)]",
         )
+        .type_attribute(
+            "TxnGetRangeResponse",
+            "#[derive(Eq, serde::Serialize, serde::Deserialize)]",
+        )
+        .type_attribute(
+            "TxnPutIfAbsentResponse",
+            "#[derive(Eq, serde::Serialize, serde::Deserialize)]",
+        )
+        .type_attribute(
+            "TxnFetchAddU64Response",
+            "#[derive(Eq, serde::Serialize, serde::Deserialize)]",
+        )
         .type_attribute(
             "TxnOpResponse.response",
             "#[derive(Eq, serde::Serialize, serde::Deserialize)]",
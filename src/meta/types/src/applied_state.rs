@@ -20,7 +20,9 @@ use openraft::AppDataResponse;
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::AppError;
 use crate::Change;
+use crate::Lease;
 use crate::Node;
 use crate::TxnReply;
 
@@ -43,6 +45,14 @@ pub enum AppliedState {
 
     TxnReply(TxnReply),
 
+    /// The lease after granting or keeping alive, or the removed lease after revoking.
+    /// `None` if the lease does not exist.
+    Lease(Option<Lease>),
+
+    /// The command is rejected by the state machine without changing anything,
+    /// e.g., a write that exceeds the quota of its tenant.
+    AppError(AppError),
+
     #[try_into(ignore)]
     None,
 }
@@ -65,6 +75,13 @@ impl fmt::Display for AppliedState {
             AppliedState::TxnReply(txnreply) => {
                 write!(f, "Txn: {}", txnreply)
             }
+            AppliedState::Lease(lease) => match lease {
+                None => write!(f, "Lease: None"),
+                Some(lease) => write!(f, "Lease: {}", lease),
+            },
+            AppliedState::AppError(err) => {
+                write!(f, "AppError: {}", err)
+            }
             AppliedState::None => {
                 write!(f, "None")
             }
@@ -84,6 +101,8 @@ impl AppliedState {
             AppliedState::KV(ref ch) => ch.changed(),
             AppliedState::None => false,
             AppliedState::TxnReply(txn) => txn.success,
+            AppliedState::Lease(lease) => lease.is_some(),
+            AppliedState::AppError(_) => false,
         }
     }
 
@@ -110,6 +129,8 @@ impl AppliedState {
             AppliedState::KV(Change { ref prev, .. }) => prev.is_none(),
             AppliedState::None => true,
             AppliedState::TxnReply(_txn) => true,
+            AppliedState::Lease(lease) => lease.is_none(),
+            AppliedState::AppError(_) => true,
         }
     }
 
@@ -120,6 +141,8 @@ impl AppliedState {
             AppliedState::KV(Change { ref result, .. }) => result.is_none(),
             AppliedState::None => true,
             AppliedState::TxnReply(txn) => !txn.success,
+            AppliedState::Lease(lease) => lease.is_none(),
+            AppliedState::AppError(_) => true,
         }
     }
 }
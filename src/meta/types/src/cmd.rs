@@ -43,6 +43,21 @@ pub enum Cmd {
 
     /// Update one or more kv with a transaction.
     Transaction(TxnRequest),
+
+    /// Grant a lease that expires `ttl_ms` milliseconds after the time of this log.
+    GrantLease { ttl_ms: u64 },
+
+    /// Extend a lease to expire its ttl after the time of this log.
+    KeepAliveLease { lease_id: u64 },
+
+    /// Remove a lease and delete the records attached to it.
+    RevokeLease { lease_id: u64 },
+
+    /// Apply a kv command on behalf of a tenant.
+    ///
+    /// The records it writes are owned by the tenant and are accounted in the tenant's usage.
+    /// The command is rejected if it exceeds the tenant's quota.
+    AsTenant { tenant: String, cmd: Box<Cmd> },
 }
 
 /// Update or insert a general purpose kv store
@@ -81,6 +96,31 @@ impl fmt::Display for Cmd {
             Cmd::Transaction(txn) => {
                 write!(f, "txn:{}", txn)
             }
+            Cmd::GrantLease { ttl_ms } => {
+                write!(f, "grant_lease:ttl={}ms", ttl_ms)
+            }
+            Cmd::KeepAliveLease { lease_id } => {
+                write!(f, "keep_alive_lease:{}", lease_id)
+            }
+            Cmd::RevokeLease { lease_id } => {
+                write!(f, "revoke_lease:{}", lease_id)
+            }
+            Cmd::AsTenant { tenant, cmd } => {
+                write!(f, "as_tenant:{}:{}", tenant, cmd)
+            }
+        }
+    }
+}
+
+impl Cmd {
+    /// Wrap a kv command to apply it on behalf of `tenant`, if there is one.
+    pub fn with_tenant(self, tenant: Option<String>) -> Self {
+        match tenant {
+            None => self,
+            Some(tenant) => Cmd::AsTenant {
+                tenant,
+                cmd: Box::new(self),
+            },
         }
     }
 }
@@ -145,6 +185,16 @@ impl With<MatchSeq> for UpsertKV {
     }
 }
 
+impl UpsertKV {
+    /// Attach the record to a lease, it is deleted when the lease expires or is revoked.
+    ///
+    /// The upsert does not take effect if the lease does not exist.
+    pub fn with_lease(mut self, lease_id: u64) -> Self {
+        self.value_meta.get_or_insert_with(KVMeta::default).lease = Some(lease_id);
+        self
+    }
+}
+
 impl With<KVMeta> for UpsertKV {
     fn with(mut self, meta: KVMeta) -> Self {
         self.value_meta = Some(meta);
@@ -430,6 +430,83 @@ impl TxnRetryMaxTimes {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
+#[error("TableLockExpired: {table_id}/{revision} while `{context}`")]
+pub struct TableLockExpired {
+    table_id: u64,
+    revision: u64,
+    context: String,
+}
+
+impl TableLockExpired {
+    pub fn new(table_id: u64, revision: u64, context: impl Into<String>) -> Self {
+        Self {
+            table_id,
+            revision,
+            context: context.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
+#[error("TenantQuotaExceeded: tenant `{tenant}` {quota}: usage {usage} reaches limit {limit}")]
+pub struct TenantQuotaExceeded {
+    tenant: String,
+    quota: String,
+    usage: u64,
+    limit: u64,
+}
+
+impl TenantQuotaExceeded {
+    pub fn new(
+        tenant: impl Into<String>,
+        quota: impl Into<String>,
+        usage: u64,
+        limit: u64,
+    ) -> Self {
+        Self {
+            tenant: tenant.into(),
+            quota: quota.into(),
+            usage,
+            limit,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
+#[error("TenantQuotaWriteDenied: tenant `{tenant}` can not write quota key `{key}`")]
+pub struct TenantQuotaWriteDenied {
+    tenant: String,
+    key: String,
+}
+
+impl TenantQuotaWriteDenied {
+    pub fn new(tenant: impl Into<String>, key: impl Into<String>) -> Self {
+        Self {
+            tenant: tenant.into(),
+            key: key.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
+#[error("TxnFetchAddU64Failed: can not add {delta} to `{key}`: {reason}")]
+pub struct TxnFetchAddU64Failed {
+    key: String,
+    delta: i64,
+    reason: String,
+}
+
+impl TxnFetchAddU64Failed {
+    pub fn new(key: impl Into<String>, delta: i64, reason: impl Into<String>) -> Self {
+        Self {
+            key: key.into(),
+            delta,
+            reason: reason.into(),
+        }
+    }
+}
+
 /// Application error.
 ///
 /// The application does not get expected result but there is nothing wrong with meta-service.
@@ -486,6 +563,18 @@ pub enum AppError {
     #[error(transparent)]
     TxnRetryMaxTimes(#[from] TxnRetryMaxTimes),
 
+    #[error(transparent)]
+    TableLockExpired(#[from] TableLockExpired),
+
+    #[error(transparent)]
+    TenantQuotaExceeded(#[from] TenantQuotaExceeded),
+
+    #[error(transparent)]
+    TenantQuotaWriteDenied(#[from] TenantQuotaWriteDenied),
+
+    #[error(transparent)]
+    TxnFetchAddU64Failed(#[from] TxnFetchAddU64Failed),
+
     // share api errors
     #[error(transparent)]
     ShareAlreadyExists(#[from] ShareAlreadyExists),
@@ -644,6 +733,42 @@ impl AppErrorMessage for WrongShare {
     }
 }
 
+impl AppErrorMessage for TableLockExpired {
+    fn message(&self) -> String {
+        format!(
+            "The lock revision {} of table {} is expired",
+            self.revision, self.table_id
+        )
+    }
+}
+
+impl AppErrorMessage for TenantQuotaExceeded {
+    fn message(&self) -> String {
+        format!(
+            "Tenant '{}' exceeds quota {}: usage {} reaches limit {}",
+            self.tenant, self.quota, self.usage, self.limit
+        )
+    }
+}
+
+impl AppErrorMessage for TenantQuotaWriteDenied {
+    fn message(&self) -> String {
+        format!(
+            "Tenant '{}' is not allowed to write quota key '{}'",
+            self.tenant, self.key
+        )
+    }
+}
+
+impl AppErrorMessage for TxnFetchAddU64Failed {
+    fn message(&self) -> String {
+        format!(
+            "Can not add {} to '{}': {}",
+            self.delta, self.key, self.reason
+        )
+    }
+}
+
 impl AppErrorMessage for TxnRetryMaxTimes {
     fn message(&self) -> String {
         format!(
@@ -726,6 +851,10 @@ impl From<AppError> for ErrorCode {
             }
             AppError::WrongShare(err) => ErrorCode::WrongShare(err.message()),
             AppError::TxnRetryMaxTimes(err) => ErrorCode::TxnRetryMaxTimes(err.message()),
+            AppError::TableLockExpired(err) => ErrorCode::TableLockExpired(err.message()),
+            AppError::TenantQuotaExceeded(err) => ErrorCode::TenantQuotaExceeded(err.message()),
+            AppError::TenantQuotaWriteDenied(err) => ErrorCode::PermissionDenied(err.message()),
+            AppError::TxnFetchAddU64Failed(err) => ErrorCode::TxnFetchAddU64Failed(err.message()),
         }
     }
 }
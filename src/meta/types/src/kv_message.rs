@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::protobuf::KvRangeRequest;
 use crate::Change;
 use crate::SeqV;
 use crate::UpsertKV;
@@ -33,6 +34,127 @@ pub struct ListKVReq {
     pub prefix: String,
 }
 
+/// Read the keys in range `[start, end)`, at most `limit` of them.
+///
+/// A caller pages through a large range by sending the request built by
+/// [`RangeKVReq::next_page`] until it returns `None`.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct RangeKVReq {
+    /// The first key to return, inclusive.
+    pub start: String,
+
+    /// The end of the range, exclusive. `None` means unbounded.
+    pub end: Option<String>,
+
+    /// The max number of entries to return. `0` means no limit.
+    pub limit: u64,
+
+    /// Return only keys, the values in the reply are left empty.
+    pub keys_only: bool,
+
+    /// Return entries in descending key order.
+    pub reverse: bool,
+}
+
+impl RangeKVReq {
+    pub fn new(start: impl ToString, end: Option<String>) -> Self {
+        Self {
+            start: start.to_string(),
+            end,
+            limit: 0,
+            keys_only: false,
+            reverse: false,
+        }
+    }
+
+    /// Build a request for all keys that start with `prefix`.
+    pub fn prefix(prefix: impl ToString) -> Self {
+        let prefix = prefix.to_string();
+        let end = prefix_end(&prefix);
+        Self::new(prefix, end)
+    }
+
+    pub fn with_limit(mut self, limit: u64) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    pub fn keys_only(mut self) -> Self {
+        self.keys_only = true;
+        self
+    }
+
+    pub fn reverse(mut self) -> Self {
+        self.reverse = true;
+        self
+    }
+
+    /// Build the request for the page following `reply`, or `None` if `reply` is the last page.
+    pub fn next_page(&self, reply: &RangeKVReply) -> Option<Self> {
+        if !reply.more {
+            return None;
+        }
+
+        let (last_key, _) = reply.kvs.last()?;
+        let mut next = self.clone();
+
+        if self.reverse {
+            next.end = Some(last_key.clone());
+        } else {
+            // The smallest key greater than `last_key`.
+            next.start = format!("{}\0", last_key);
+        }
+        Some(next)
+    }
+}
+
+impl From<KvRangeRequest> for RangeKVReq {
+    fn from(req: KvRangeRequest) -> Self {
+        Self {
+            start: req.start,
+            end: req.end,
+            limit: req.limit,
+            keys_only: req.keys_only,
+            reverse: req.reverse,
+        }
+    }
+}
+
+impl From<RangeKVReq> for KvRangeRequest {
+    fn from(req: RangeKVReq) -> Self {
+        Self {
+            start: req.start,
+            end: req.end,
+            limit: req.limit,
+            keys_only: req.keys_only,
+            reverse: req.reverse,
+        }
+    }
+}
+
+/// Returns the smallest string that is greater than every string starting with `prefix`,
+/// or `None` if there is no such string.
+fn prefix_end(prefix: &str) -> Option<String> {
+    let mut chars: Vec<char> = prefix.chars().collect();
+
+    while let Some(c) = chars.pop() {
+        let next = (c as u32 + 1..=char::MAX as u32).find_map(char::from_u32);
+        if let Some(next) = next {
+            chars.push(next);
+            return Some(chars.into_iter().collect());
+        }
+    }
+    None
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct RangeKVReply {
+    pub kvs: Vec<(String, SeqV<Vec<u8>>)>,
+
+    /// Whether there are more entries in the range beyond `limit`.
+    pub more: bool,
+}
+
 pub type UpsertKVReply = Change<Vec<u8>>;
 pub type GetKVReply = Option<SeqV<Vec<u8>>>;
 pub type MGetKVReply = Vec<Option<SeqV<Vec<u8>>>>;
@@ -0,0 +1,55 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::protobuf::LeaseReply;
+
+/// A lease that keeps the records attached to it alive.
+///
+/// A lease expires `ttl_ms` milliseconds after it is granted or kept alive, by the time of
+/// the raft log. The records attached to it are deleted when it expires or is revoked.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Lease {
+    pub lease_id: u64,
+
+    /// The time to live in milliseconds.
+    pub ttl_ms: u64,
+
+    /// When this lease expires if it is not kept alive, in milliseconds since 1970.
+    pub expire_at_ms: u64,
+}
+
+impl fmt::Display for Lease {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "lease-{}(ttl: {}ms, expire at: {}ms)",
+            self.lease_id, self.ttl_ms, self.expire_at_ms
+        )
+    }
+}
+
+impl From<Lease> for LeaseReply {
+    fn from(l: Lease) -> Self {
+        LeaseReply {
+            lease_id: l.lease_id,
+            ttl_ms: l.ttl_ms,
+            expire_at_ms: l.expire_at_ms,
+        }
+    }
+}
@@ -24,6 +24,7 @@ pub mod config;
 mod endpoint;
 pub mod errors;
 mod kv_message;
+mod lease;
 mod log_entry;
 mod match_seq;
 mod message;
@@ -40,6 +41,7 @@ mod user_defined_function;
 mod user_grant;
 mod user_identity;
 mod user_info;
+mod user_pipe;
 mod user_privilege;
 mod user_quota;
 mod user_setting;
@@ -77,6 +79,9 @@ pub use errors::app_error::DropTableWithDropTime;
 pub use errors::app_error::ShareAlreadyExists;
 pub use errors::app_error::TableAlreadyExists;
 pub use errors::app_error::TableVersionMismatched;
+pub use errors::app_error::TenantQuotaExceeded;
+pub use errors::app_error::TenantQuotaWriteDenied;
+pub use errors::app_error::TxnFetchAddU64Failed;
 pub use errors::app_error::UndropDbHasNoHistory;
 pub use errors::app_error::UndropDbWithNoDropTime;
 pub use errors::app_error::UndropTableAlreadyExists;
@@ -118,8 +123,11 @@ pub use kv_message::ListKVReply;
 pub use kv_message::ListKVReq;
 pub use kv_message::MGetKVReply;
 pub use kv_message::MGetKVReq;
+pub use kv_message::RangeKVReply;
+pub use kv_message::RangeKVReq;
 pub use kv_message::UpsertKVReply;
 pub use kv_message::UpsertKVReq;
+pub use lease::Lease;
 pub use log_entry::LogEntry;
 pub use match_seq::MatchSeq;
 pub use match_seq::MatchSeqExt;
@@ -137,15 +145,22 @@ pub use protobuf::txn_condition;
 pub use protobuf::txn_condition::ConditionResult;
 pub use protobuf::txn_op;
 pub use protobuf::txn_op_response;
+pub use protobuf::KeysInRange;
 pub use protobuf::TxnCondition;
 pub use protobuf::TxnDeleteByPrefixRequest;
 pub use protobuf::TxnDeleteByPrefixResponse;
 pub use protobuf::TxnDeleteRequest;
 pub use protobuf::TxnDeleteResponse;
+pub use protobuf::TxnFetchAddU64Request;
+pub use protobuf::TxnFetchAddU64Response;
+pub use protobuf::TxnGetRangeRequest;
+pub use protobuf::TxnGetRangeResponse;
 pub use protobuf::TxnGetRequest;
 pub use protobuf::TxnGetResponse;
 pub use protobuf::TxnOp;
 pub use protobuf::TxnOpResponse;
+pub use protobuf::TxnPutIfAbsentRequest;
+pub use protobuf::TxnPutIfAbsentResponse;
 pub use protobuf::TxnPutRequest;
 pub use protobuf::TxnPutResponse;
 pub use protobuf::TxnReply;
@@ -164,6 +179,8 @@ pub use seq_value::KVMeta;
 pub use seq_value::PbSeqV;
 pub use seq_value::SeqV;
 pub use tenant_quota::TenantQuota;
+pub use tenant_quota::TenantUsage;
+pub use tenant_quota::TENANT_QUOTA_KEY_PREFIX;
 pub use user_auth::AuthInfo;
 pub use user_auth::AuthType;
 pub use user_auth::PasswordHashMethod;
@@ -175,6 +192,8 @@ pub use user_identity::UserIdentity;
 pub use user_info::UserInfo;
 pub use user_info::UserOption;
 pub use user_info::UserOptionFlag;
+pub use user_pipe::PipeInfo;
+pub use user_pipe::PipeStatus;
 pub use user_privilege::UserPrivilegeSet;
 pub use user_privilege::UserPrivilegeType;
 pub use user_quota::UserQuota;
@@ -32,6 +32,8 @@ use crate::LogEntry;
 use crate::MGetKVReply;
 use crate::MGetKVReq;
 use crate::NodeId;
+use crate::RangeKVReply;
+use crate::RangeKVReq;
 use crate::TxnOpResponse;
 use crate::TxnReply;
 
@@ -48,6 +50,11 @@ pub struct JoinRequest {
     pub node_id: NodeId,
     pub endpoint: Endpoint,
     pub grpc_api_addr: String,
+
+    /// Join as a non-voting learner: it replicates logs and serves reads
+    /// but does not take part in elections or commit quorums.
+    #[serde(default)]
+    pub learner: bool,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
@@ -69,6 +76,10 @@ pub enum ForwardRequestBody {
     GetKV(GetKVReq),
     MGetKV(MGetKVReq),
     ListKV(ListKVReq),
+    RangeKV(RangeKVReq),
+
+    /// Ask the leader for the log index a follower has to apply before it can serve a linearizable read.
+    ReadIndex,
 }
 
 /// A request that is forwarded from one raft node to another
@@ -99,6 +110,9 @@ pub enum ForwardResponse {
     GetKV(GetKVReply),
     MGetKV(MGetKVReply),
     ListKV(ListKVReply),
+    RangeKV(RangeKVReply),
+
+    ReadIndex(u64),
 }
 
 impl tonic::IntoRequest<RaftRequest> for ForwardRequest {
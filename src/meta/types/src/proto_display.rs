@@ -27,10 +27,16 @@ use crate::TxnDeleteByPrefixRequest;
 use crate::TxnDeleteByPrefixResponse;
 use crate::TxnDeleteRequest;
 use crate::TxnDeleteResponse;
+use crate::TxnFetchAddU64Request;
+use crate::TxnFetchAddU64Response;
+use crate::TxnGetRangeRequest;
+use crate::TxnGetRangeResponse;
 use crate::TxnGetRequest;
 use crate::TxnGetResponse;
 use crate::TxnOp;
 use crate::TxnOpResponse;
+use crate::TxnPutIfAbsentRequest;
+use crate::TxnPutIfAbsentResponse;
 use crate::TxnPutRequest;
 use crate::TxnPutResponse;
 use crate::TxnReply;
@@ -132,6 +138,15 @@ impl Display for txn_op::Request {
             Request::DeleteByPrefix(r) => {
                 write!(f, "DeleteByPrefix({})", r)
             }
+            Request::GetRange(r) => {
+                write!(f, "GetRange({})", r)
+            }
+            Request::PutIfAbsent(r) => {
+                write!(f, "PutIfAbsent({})", r)
+            }
+            Request::FetchAddU64(r) => {
+                write!(f, "FetchAddU64({})", r)
+            }
         }
     }
 }
@@ -152,6 +167,9 @@ impl Display for TxnPutRequest {
         if let Some(expire_at) = self.expire_at {
             write!(f, " expire at: {}", expire_at)?;
         }
+        if let Some(lease) = self.lease {
+            write!(f, " lease: {}", lease)?;
+        }
         Ok(())
     }
 }
@@ -168,6 +186,35 @@ impl Display for TxnDeleteByPrefixRequest {
     }
 }
 
+impl Display for TxnGetRangeRequest {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "GetRange key={}, range_end={}, limit={}",
+            self.key, self.range_end, self.limit
+        )
+    }
+}
+
+impl Display for TxnPutIfAbsentRequest {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "PutIfAbsent key={}", self.key)?;
+        if let Some(expire_at) = self.expire_at {
+            write!(f, " expire at: {}", expire_at)?;
+        }
+        if let Some(lease) = self.lease {
+            write!(f, " lease: {}", lease)?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for TxnFetchAddU64Request {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "FetchAddU64 key={}, delta={}", self.key, self.delta)
+    }
+}
+
 impl Display for Target {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -177,6 +224,12 @@ impl Display for Target {
             Target::Seq(seq) => {
                 write!(f, "seq({})", seq)
             }
+            Target::KeysWithPrefix(count) => {
+                write!(f, "keys_with_prefix({})", count)
+            }
+            Target::KeysInRange(r) => {
+                write!(f, "keys_in_range(..{}: {})", r.range_end, r.count)
+            }
         }
     }
 }
@@ -216,6 +269,15 @@ impl Display for Response {
             Response::DeleteByPrefix(r) => {
                 write!(f, "DeleteByPrefix: {}", r)
             }
+            Response::GetRange(r) => {
+                write!(f, "GetRange: {}", r)
+            }
+            Response::PutIfAbsent(r) => {
+                write!(f, "PutIfAbsent: {}", r)
+            }
+            Response::FetchAddU64(r) => {
+                write!(f, "FetchAddU64: {}", r)
+            }
         }
     }
 }
@@ -261,3 +323,36 @@ impl Display for TxnDeleteByPrefixResponse {
         )
     }
 }
+
+impl Display for TxnGetRangeResponse {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "GetRange-resp: items={}, more={}",
+            VecDisplay { vec: &self.items },
+            self.more
+        )
+    }
+}
+
+impl Display for TxnPutIfAbsentResponse {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "PutIfAbsent-resp: success: {}, key={}, prev_seq={:?}",
+            self.success,
+            self.key,
+            self.prev_value.as_ref().map(|x| x.seq)
+        )
+    }
+}
+
+impl Display for TxnFetchAddU64Response {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "FetchAddU64-resp: success: {}, key={}, before={}, after={}",
+            self.success, self.key, self.before, self.after
+        )
+    }
+}
@@ -27,6 +27,16 @@ pub type PbSeqV = crate::protobuf::SeqV;
 pub struct KVMeta {
     /// expiration time in second since 1970
     pub expire_at: Option<u64>,
+
+    /// The lease this record is attached to.
+    /// The record is deleted when the lease expires or is revoked.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lease: Option<u64>,
+
+    /// The tenant that owns this record, set by meta-service when a tenant writes it.
+    /// The record is accounted in the owner's usage.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tenant: Option<String>,
 }
 
 /// Some value bound with a seq number
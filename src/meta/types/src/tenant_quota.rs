@@ -17,6 +17,9 @@ use common_exception::Result;
 use serde::Deserialize;
 use serde::Serialize;
 
+/// The prefix of the key of a tenant quota: `__fd_quotas/<tenant>`.
+pub const TENANT_QUOTA_KEY_PREFIX: &str = "__fd_quotas";
+
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Default)]
 #[serde(default)]
 pub struct TenantQuota {
@@ -31,6 +34,15 @@ pub struct TenantQuota {
 
     // The max files per stage can be created in the tenant.
     pub max_files_per_stage: u32,
+
+    // The max records the tenant can store in meta-service.
+    pub max_keys: u64,
+
+    // The max total bytes of values the tenant can store in meta-service.
+    pub max_value_bytes: u64,
+
+    // The max writes per second the tenant can send to meta-service.
+    pub max_write_ops_per_second: u64,
 }
 
 impl TryFrom<Vec<u8>> for TenantQuota {
@@ -46,3 +58,14 @@ impl TryFrom<Vec<u8>> for TenantQuota {
         }
     }
 }
+
+/// The resources a tenant uses in meta-service, accounted by the state machine.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+#[serde(default)]
+pub struct TenantUsage {
+    /// The number of records owned by the tenant.
+    pub keys: u64,
+
+    /// The total bytes of the values of the records owned by the tenant.
+    pub value_bytes: u64,
+}
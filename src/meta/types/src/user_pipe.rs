@@ -0,0 +1,118 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::convert::TryFrom;
+
+use chrono::DateTime;
+use chrono::Utc;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::UserIdentity;
+
+/// A pipe loads the new files of a stage into a table, by running its `COPY` periodically.
+///
+/// CREATE PIPE [ IF NOT EXISTS ] <name>
+///   [ AUTO_INGEST = TRUE | FALSE ]
+///   [ COMMENT = '<string_literal>' ]
+///   AS <copy_statement>
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+#[serde(default)]
+pub struct PipeInfo {
+    pub name: String,
+    /// `COPY INTO <table> FROM <location>`, run by the pipe.
+    pub copy_stmt: String,
+    /// Paused pipes are not run by the query nodes.
+    pub auto_ingest: bool,
+    pub comment: String,
+    pub creator: Option<UserIdentity>,
+    pub created_on: Option<DateTime<Utc>>,
+    pub status: PipeStatus,
+}
+
+/// Ingestion state of a pipe, shared by all the query nodes of the tenant.
+///
+/// The files already loaded are tracked by the copied files of the target table,
+/// so that a run only loads the files added since.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+#[serde(default)]
+pub struct PipeStatus {
+    /// When the last run started, the run is claimed by updating it.
+    pub last_run_on: Option<DateTime<Utc>>,
+    /// The query node running the pipe last time.
+    pub last_run_node: String,
+    /// Files loaded by the last successful run.
+    pub last_loaded_files: u64,
+    /// Files loaded since the pipe was created.
+    pub total_loaded_files: u64,
+    /// Error of the last run, empty if it succeeded.
+    pub last_error: String,
+}
+
+impl PipeInfo {
+    /// Whether the pipe should run at `now`, if its last run is at least `interval_secs` old.
+    pub fn is_due(&self, now: DateTime<Utc>, interval_secs: u64) -> bool {
+        if !self.auto_ingest {
+            return false;
+        }
+
+        match self.status.last_run_on {
+            None => true,
+            Some(last_run_on) => (now - last_run_on).num_seconds() >= interval_secs as i64,
+        }
+    }
+
+    /// The copy statement with the credentials in `CONNECTION = ( ... )` masked, for display.
+    pub fn masked_copy_stmt(&self) -> String {
+        const CONNECTION: &str = "CONNECTION = (";
+
+        let stmt = &self.copy_stmt;
+        let start = match stmt.find(CONNECTION) {
+            None => return stmt.clone(),
+            Some(i) => i + CONNECTION.len(),
+        };
+
+        // Find the closing parenthesis out of the quoted values.
+        let mut quoted = false;
+        let end = stmt[start..].char_indices().find_map(|(i, c)| match c {
+            '\'' => {
+                quoted = !quoted;
+                None
+            }
+            ')' if !quoted => Some(start + i),
+            _ => None,
+        });
+
+        match end {
+            Some(end) => format!("{} *** {}", &stmt[..start], &stmt[end..]),
+            None => format!("{} *** )", &stmt[..start]),
+        }
+    }
+}
+
+impl TryFrom<Vec<u8>> for PipeInfo {
+    type Error = ErrorCode;
+
+    fn try_from(value: Vec<u8>) -> Result<Self> {
+        match serde_json::from_slice(&value) {
+            Ok(pipe) => Ok(pipe),
+            Err(serialize_error) => Err(ErrorCode::IllegalPipeFormat(format!(
+                "Cannot deserialize pipe from bytes. cause {}",
+                serialize_error
+            ))),
+        }
+    }
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::fmt;
 use std::str::FromStr;
 
@@ -122,6 +123,7 @@ pub enum StageFileFormatType {
     Orc,
     Parquet,
     Xml,
+    FixedWidth,
 }
 
 impl Default for StageFileFormatType {
@@ -138,11 +140,13 @@ impl FromStr for StageFileFormatType {
             "TSV" | "TABSEPARATED" => Ok(StageFileFormatType::Tsv),
             "NDJSON" | "JSONEACHROW" => Ok(StageFileFormatType::NdJson),
             "PARQUET" => Ok(StageFileFormatType::Parquet),
-            "XML" | "ORC" | "AVRO" | "JSON" => Err(format!(
-                "File format type '{s}' not implemented yet', must be one of ( CSV | TSV | NDJSON | PARQUET)"
+            "XML" => Ok(StageFileFormatType::Xml),
+            "FIXEDWIDTH" | "FIXED_WIDTH" => Ok(StageFileFormatType::FixedWidth),
+            "ORC" | "AVRO" | "JSON" => Err(format!(
+                "File format type '{s}' not implemented yet', must be one of ( CSV | TSV | NDJSON | PARQUET | XML | FIXEDWIDTH )"
             )),
             _ => Err(format!(
-                "Unknown file format type '{s}', must be one of ( CSV | TSV | NDJSON | PARQUET)"
+                "Unknown file format type '{s}', must be one of ( CSV | TSV | NDJSON | PARQUET | XML | FIXEDWIDTH )"
             )),
         }
     }
@@ -157,6 +161,12 @@ pub struct FileFormatOptions {
     pub field_delimiter: String,
     pub record_delimiter: String,
     pub compression: StageFileCompression,
+    // FixedWidth only: start offset in bytes of each column in a record.
+    pub column_offsets: Vec<u64>,
+    // Xml only: `/` separated element path from the document root to a row element.
+    pub row_path: String,
+    // Xml only: column name to the child element or `@attribute` path in a row element.
+    pub column_mapping: BTreeMap<String, String>,
 }
 
 impl Default for FileFormatOptions {
@@ -167,6 +177,9 @@ impl Default for FileFormatOptions {
             field_delimiter: ",".to_string(),
             skip_header: 0,
             compression: StageFileCompression::default(),
+            column_offsets: vec![],
+            row_path: "".to_string(),
+            column_mapping: BTreeMap::new(),
         }
     }
 }
@@ -14,9 +14,11 @@
 
 mod cluster;
 mod match_seq;
+mod range_kv;
 mod user_defined_function;
 mod user_grant;
 mod user_info;
+mod user_pipe;
 mod user_privilege;
 mod user_quota;
 
@@ -0,0 +1,59 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_meta_types::RangeKVReply;
+use common_meta_types::RangeKVReq;
+use common_meta_types::SeqV;
+
+#[test]
+fn test_range_kv_req_prefix() -> anyhow::Result<()> {
+    assert_eq!(Some("ac".to_string()), RangeKVReq::prefix("ab").end);
+    assert_eq!(Some("b".to_string()), RangeKVReq::prefix("a\u{10FFFF}").end);
+    assert_eq!(None, RangeKVReq::prefix("\u{10FFFF}").end);
+    assert_eq!(None, RangeKVReq::prefix("").end);
+
+    // Surrogates are skipped.
+    assert_eq!(
+        Some("\u{E000}".to_string()),
+        RangeKVReq::prefix("\u{D7FF}").end
+    );
+    Ok(())
+}
+
+#[test]
+fn test_range_kv_req_next_page() -> anyhow::Result<()> {
+    let page = |keys: &[&str], more: bool| RangeKVReply {
+        kvs: keys
+            .iter()
+            .map(|k| (k.to_string(), SeqV::new(1, vec![])))
+            .collect(),
+        more,
+    };
+
+    let req = RangeKVReq::prefix("a/").with_limit(2);
+
+    assert_eq!(None, req.next_page(&page(&["a/1", "a/2"], false)));
+    assert_eq!(None, req.next_page(&page(&[], true)));
+
+    let next = req.next_page(&page(&["a/1", "a/2"], true)).unwrap();
+    assert_eq!("a/2\0", next.start);
+    assert_eq!(Some("a0".to_string()), next.end);
+    assert_eq!(2, next.limit);
+
+    let req = req.reverse();
+    let next = req.next_page(&page(&["a/9", "a/8"], true)).unwrap();
+    assert_eq!("a/", next.start);
+    assert_eq!(Some("a/8".to_string()), next.end);
+    Ok(())
+}
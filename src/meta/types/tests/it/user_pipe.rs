@@ -0,0 +1,81 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::Duration;
+use chrono::Utc;
+use common_exception::exception::Result;
+use common_meta_types::PipeInfo;
+
+#[test]
+fn test_pipe() -> Result<()> {
+    let pipe = PipeInfo {
+        name: "mypipe".to_string(),
+        copy_stmt: "COPY INTO t FROM @s/".to_string(),
+        auto_ingest: true,
+        created_on: Some(Utc::now()),
+        ..Default::default()
+    };
+    let ser = serde_json::to_string(&pipe)?;
+
+    let de = PipeInfo::try_from(ser.into_bytes())?;
+    assert_eq!(pipe, de);
+
+    Ok(())
+}
+
+#[test]
+fn test_pipe_is_due() -> Result<()> {
+    let now = Utc::now();
+    let mut pipe = PipeInfo {
+        name: "mypipe".to_string(),
+        auto_ingest: true,
+        ..Default::default()
+    };
+
+    // Never run.
+    assert!(pipe.is_due(now, 60));
+
+    pipe.status.last_run_on = Some(now - Duration::seconds(30));
+    assert!(!pipe.is_due(now, 60));
+
+    pipe.status.last_run_on = Some(now - Duration::seconds(60));
+    assert!(pipe.is_due(now, 60));
+
+    // Paused.
+    pipe.auto_ingest = false;
+    assert!(!pipe.is_due(now, 60));
+
+    Ok(())
+}
+
+#[test]
+fn test_pipe_masked_copy_stmt() -> Result<()> {
+    let mut pipe = PipeInfo {
+        name: "mypipe".to_string(),
+        copy_stmt: "COPY INTO t FROM @s/ PURGE = false".to_string(),
+        ..Default::default()
+    };
+    assert_eq!(
+        "COPY INTO t FROM @s/ PURGE = false",
+        pipe.masked_copy_stmt()
+    );
+
+    pipe.copy_stmt = "COPY INTO t FROM 's3://bucket/dir/' CONNECTION = ( aws_key_id='a)' aws_secret_key='b' ) PURGE = false".to_string();
+    assert_eq!(
+        "COPY INTO t FROM 's3://bucket/dir/' CONNECTION = ( *** ) PURGE = false",
+        pipe.masked_copy_stmt()
+    );
+
+    Ok(())
+}
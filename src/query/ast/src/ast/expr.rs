@@ -21,6 +21,7 @@ use common_exception::Result;
 use crate::ast::write_comma_separated_list;
 use crate::ast::write_period_separated_list;
 use crate::ast::Identifier;
+use crate::ast::OrderByExpr;
 use crate::ast::Query;
 use crate::parser::token::Token;
 
@@ -152,6 +153,10 @@ pub enum Expr<'a> {
         name: Identifier<'a>,
         args: Vec<Expr<'a>>,
         params: Vec<Literal>,
+        /// The order of the aggregated values, like `ARRAY_AGG(a ORDER BY b)`
+        order_by: Vec<OrderByExpr<'a>>,
+        /// The lambda of higher-order functions, like `ARRAY_TRANSFORM(a, x -> x + 1)`
+        lambda: Option<Lambda<'a>>,
     },
     /// `CASE ... WHEN ... ELSE ...` expression
     Case {
@@ -229,6 +234,13 @@ pub enum Literal {
     Null,
 }
 
+/// A lambda expression, like `x -> x + 1` or `(acc, x) -> acc + x`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lambda<'a> {
+    pub params: Vec<Identifier<'a>>,
+    pub expr: Box<Expr<'a>>,
+}
+
 /// The display style for a map access expression
 #[derive(Debug, Clone, PartialEq)]
 pub enum MapAccessor<'a> {
@@ -253,12 +265,21 @@ pub enum TypeName {
     Int64,
     Float32,
     Float64,
+    Decimal {
+        precision: u64,
+        scale: u64,
+    },
     Date,
     Timestamp,
+    TimestampTz,
     String,
     Array {
         item_type: Option<Box<TypeName>>,
     },
+    Map {
+        key_type: Box<TypeName>,
+        value_type: Box<TypeName>,
+    },
     Tuple {
         fields_name: Option<Vec<String>>,
         fields_type: Vec<TypeName>,
@@ -535,12 +556,18 @@ impl Display for TypeName {
             TypeName::Float64 => {
                 write!(f, "Float64")?;
             }
+            TypeName::Decimal { precision, scale } => {
+                write!(f, "DECIMAL({}, {})", precision, scale)?;
+            }
             TypeName::Date => {
                 write!(f, "DATE")?;
             }
             TypeName::Timestamp => {
                 write!(f, "TIMESTAMP")?;
             }
+            TypeName::TimestampTz => {
+                write!(f, "TIMESTAMP_TZ")?;
+            }
             TypeName::String => {
                 write!(f, "STRING")?;
             }
@@ -550,6 +577,12 @@ impl Display for TypeName {
                     write!(f, "({})", *item_type)?;
                 }
             }
+            TypeName::Map {
+                key_type,
+                value_type,
+            } => {
+                write!(f, "MAP({}, {})", key_type, value_type)?;
+            }
             TypeName::Tuple {
                 fields_name,
                 fields_type,
@@ -637,6 +670,19 @@ impl Display for Literal {
     }
 }
 
+impl<'a> Display for Lambda<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.params.len() == 1 {
+            write!(f, "{}", self.params[0])?;
+        } else {
+            write!(f, "(")?;
+            write_comma_separated_list(f, &self.params)?;
+            write!(f, ")")?;
+        }
+        write!(f, " -> {}", self.expr)
+    }
+}
+
 impl<'a> Display for Expr<'a> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -785,6 +831,8 @@ impl<'a> Display for Expr<'a> {
                 name,
                 args,
                 params,
+                order_by,
+                lambda,
                 ..
             } => {
                 write!(f, "{name}")?;
@@ -798,6 +846,13 @@ impl<'a> Display for Expr<'a> {
                     write!(f, "DISTINCT ")?;
                 }
                 write_comma_separated_list(f, args)?;
+                if !order_by.is_empty() {
+                    write!(f, " ORDER BY ")?;
+                    write_comma_separated_list(f, order_by)?;
+                }
+                if let Some(lambda) = lambda {
+                    write!(f, ", {lambda}")?;
+                }
                 write!(f, ")")?;
             }
             Expr::Case {
@@ -456,12 +456,30 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         name: &'ast Identifier<'ast>,
         args: &'ast [Expr<'ast>],
         _params: &'ast [Literal],
+        order_by: &'ast [OrderByExpr<'ast>],
+        lambda: &'ast Option<Lambda<'ast>>,
     ) {
-        let mut children = Vec::with_capacity(args.len());
+        let mut children = Vec::with_capacity(args.len() + order_by.len() + 1);
         for arg in args.iter() {
             self.visit_expr(arg);
             children.push(self.children.pop().unwrap());
         }
+        for order_by in order_by.iter() {
+            self.visit_order_by(order_by);
+            children.push(self.children.pop().unwrap());
+        }
+        if let Some(lambda) = lambda {
+            self.visit_expr(&lambda.expr);
+            let expr_child = self.children.pop().unwrap();
+            let params = lambda
+                .params
+                .iter()
+                .map(|param| param.to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+            let format_ctx = AstFormatContext::with_children(format!("Lambda {params}"), 1);
+            children.push(FormatTreeNode::with_children(format_ctx, vec![expr_child]));
+        }
         let node_name = if distinct {
             format!("Function {name}Distinct")
         } else {
@@ -734,6 +752,19 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         children.push(self.children.pop().unwrap());
         self.visit_copy_unit(&copy.dst);
         children.push(self.children.pop().unwrap());
+        if !copy.partition_by.is_empty() {
+            let mut partition_by_children = Vec::with_capacity(copy.partition_by.len());
+            for partition_by in copy.partition_by.iter() {
+                self.visit_expr(partition_by);
+                partition_by_children.push(self.children.pop().unwrap());
+            }
+            let partition_by_name = "PartitionByList".to_string();
+            let partition_by_format_ctx =
+                AstFormatContext::with_children(partition_by_name, partition_by_children.len());
+            let partition_by_node =
+                FormatTreeNode::with_children(partition_by_format_ctx, partition_by_children);
+            children.push(partition_by_node);
+        }
         if !copy.files.is_empty() {
             let mut files_children = Vec::with_capacity(copy.files.len());
             for file in copy.files.iter() {
@@ -1823,6 +1854,37 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_create_pipe(&mut self, stmt: &'ast CreatePipeStmt<'ast>) {
+        let mut children = Vec::new();
+        let pipe_name_format_ctx = AstFormatContext::new(format!("PipeName {}", stmt.pipe_name));
+        children.push(FormatTreeNode::new(pipe_name_format_ctx));
+        let auto_ingest_format_ctx =
+            AstFormatContext::new(format!("AutoIngest {}", stmt.auto_ingest));
+        children.push(FormatTreeNode::new(auto_ingest_format_ctx));
+        if !stmt.comments.is_empty() {
+            let comments_name = format!("Comments {}", stmt.comments);
+            let comments_format_ctx = AstFormatContext::new(comments_name);
+            children.push(FormatTreeNode::new(comments_format_ctx));
+        }
+        self.visit_copy(&stmt.copy);
+        children.push(self.children.pop().unwrap());
+
+        let name = "CreatePipe".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
+    fn visit_drop_pipe(&mut self, _if_exists: bool, pipe_name: &'ast str) {
+        let pipe_name_format_ctx = AstFormatContext::new(format!("PipeName {}", pipe_name));
+        let child = FormatTreeNode::new(pipe_name_format_ctx);
+
+        let name = "DropPipe".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
     fn visit_presign(&mut self, presign: &'ast PresignStmt) {
         let mut children = Vec::with_capacity(3);
         let action_format_ctx = AstFormatContext::new(format!("Action {}", presign.action));
@@ -2156,16 +2218,29 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
             }
             TableReference::TableFunction {
                 span: _,
+                lateral,
                 name,
                 params,
+                named_params,
                 alias,
             } => {
-                let mut children = Vec::with_capacity(params.len());
+                let mut children = Vec::with_capacity(params.len() + named_params.len());
                 for param in params.iter() {
                     self.visit_expr(param);
                     children.push(self.children.pop().unwrap());
                 }
-                let func_name = format!("TableFunction {}", name);
+                for (param_name, param) in named_params.iter() {
+                    self.visit_expr(param);
+                    let child = self.children.pop().unwrap();
+                    let format_ctx =
+                        AstFormatContext::with_children(format!("{} =>", param_name), 1);
+                    children.push(FormatTreeNode::with_children(format_ctx, vec![child]));
+                }
+                let func_name = if *lateral {
+                    format!("TableFunction LATERAL {}", name)
+                } else {
+                    format!("TableFunction {}", name)
+                };
                 let format_ctx = if let Some(alias) = alias {
                     AstFormatContext::with_children_alias(
                         func_name,
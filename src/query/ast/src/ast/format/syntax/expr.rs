@@ -220,6 +220,8 @@ pub(crate) fn pretty_expr(expr: Expr) -> RcDoc {
             name,
             args,
             params,
+            order_by,
+            lambda,
             ..
         } => RcDoc::text(name.to_string())
             .append(if !params.is_empty() {
@@ -240,6 +242,25 @@ pub(crate) fn pretty_expr(expr: Expr) -> RcDoc {
                 RcDoc::nil()
             })
             .append(inline_comma(args.into_iter().map(pretty_expr)))
+            .append(if !order_by.is_empty() {
+                RcDoc::space()
+                    .append(RcDoc::text("ORDER BY"))
+                    .append(RcDoc::space())
+                    .append(inline_comma(
+                        order_by
+                            .into_iter()
+                            .map(|order_by| RcDoc::text(order_by.to_string())),
+                    ))
+            } else {
+                RcDoc::nil()
+            })
+            .append(if let Some(lambda) = lambda {
+                RcDoc::text(",")
+                    .append(RcDoc::space())
+                    .append(RcDoc::text(lambda.to_string()))
+            } else {
+                RcDoc::nil()
+            })
             .append(RcDoc::text(")")),
         Expr::Case {
             operand,
@@ -236,18 +236,29 @@ pub(crate) fn pretty_table(table: TableReference) -> RcDoc {
         }),
         TableReference::TableFunction {
             span: _,
+            lateral,
             name,
             params,
+            named_params,
             alias,
-        } => RcDoc::text(name.to_string())
-            .append(RcDoc::text("("))
-            .append(inline_comma(params.into_iter().map(pretty_expr)))
-            .append(RcDoc::text(")"))
-            .append(if let Some(alias) = alias {
-                RcDoc::text(format!(" AS {alias}"))
-            } else {
-                RcDoc::nil()
+        } => if lateral {
+            RcDoc::text("LATERAL ")
+        } else {
+            RcDoc::nil()
+        }
+        .append(RcDoc::text(name.to_string()))
+        .append(RcDoc::text("("))
+        .append(inline_comma(params.into_iter().map(pretty_expr).chain(
+            named_params.into_iter().map(|(param_name, param)| {
+                RcDoc::text(format!("{param_name} => ")).append(pretty_expr(param))
             }),
+        )))
+        .append(RcDoc::text(")"))
+        .append(if let Some(alias) = alias {
+            RcDoc::text(format!(" AS {alias}"))
+        } else {
+            RcDoc::nil()
+        }),
         TableReference::Join { span: _, join } => pretty_table(*join.left)
             .append(RcDoc::line())
             .append(if join.condition == JoinCondition::Natural {
@@ -154,11 +154,13 @@ pub enum TableReference<'a> {
         alias: Option<TableAlias<'a>>,
         travel_point: Option<TimeTravelPoint<'a>>,
     },
-    // `TABLE(expr)[ AS alias ]`
+    // `[LATERAL] TABLE(expr, name => expr)[ AS alias ]`
     TableFunction {
         span: &'a [Token<'a>],
+        lateral: bool,
         name: Identifier<'a>,
         params: Vec<Expr<'a>>,
+        named_params: Vec<(Identifier<'a>, Expr<'a>)>,
         alias: Option<TableAlias<'a>>,
     },
     // Derived table, which can be a subquery or joined tables or combination of them
@@ -273,12 +275,23 @@ impl<'a> Display for TableReference<'a> {
             }
             TableReference::TableFunction {
                 span: _,
+                lateral,
                 name,
                 params,
+                named_params,
                 alias,
             } => {
+                if *lateral {
+                    write!(f, "LATERAL ")?;
+                }
                 write!(f, "{name}(")?;
                 write_comma_separated_list(f, params)?;
+                for (i, (param_name, param)) in named_params.iter().enumerate() {
+                    if i != 0 || !params.is_empty() {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{param_name} => {param}")?;
+                }
                 write!(f, ")")?;
                 if let Some(alias) = alias {
                     write!(f, " AS {alias}")?;
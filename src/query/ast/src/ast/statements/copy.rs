@@ -16,8 +16,10 @@ use std::collections::BTreeMap;
 use std::fmt::Display;
 use std::fmt::Formatter;
 
+use crate::ast::write_comma_separated_list;
 use crate::ast::write_quoted_comma_separated_list;
 use crate::ast::write_space_seperated_map;
+use crate::ast::Expr;
 use crate::ast::Identifier;
 use crate::ast::Query;
 
@@ -32,10 +34,12 @@ use crate::ast::Query;
 pub struct CopyStmt<'a> {
     pub src: CopyUnit<'a>,
     pub dst: CopyUnit<'a>,
+    /// Only used while unloading into a location, writes a `name=value/` directory per partition.
+    pub partition_by: Vec<Expr<'a>>,
     pub files: Vec<String>,
     pub pattern: String,
     pub file_format: BTreeMap<String, String>,
-    /// TODO(xuanwo): parse into validation_mode directly.
+    /// `RETURN_<n>_ROWS`, `RETURN_ERRORS` or `RETURN_ALL_ERRORS`, checked while binding.
     pub validation_mode: String,
     pub size_limit: usize,
     pub max_file_size: usize,
@@ -43,6 +47,9 @@ pub struct CopyStmt<'a> {
     pub single: bool,
     pub purge: bool,
     pub force: bool,
+    pub include_query_id: bool,
+    pub overwrite: bool,
+    pub header: bool,
 }
 
 impl Display for CopyStmt<'_> {
@@ -51,6 +58,11 @@ impl Display for CopyStmt<'_> {
         write!(f, " INTO {}", self.dst)?;
         write!(f, " FROM {}", self.src)?;
 
+        if !self.partition_by.is_empty() {
+            write!(f, " PARTITION BY ")?;
+            write_comma_separated_list(f, &self.partition_by)?;
+        }
+
         if !self.files.is_empty() {
             write!(f, " FILES = (")?;
             write_quoted_comma_separated_list(f, &self.files)?;
@@ -70,7 +82,7 @@ impl Display for CopyStmt<'_> {
         }
 
         if !self.validation_mode.is_empty() {
-            write!(f, "VALIDATION_MODE = {}", self.validation_mode)?;
+            write!(f, " VALIDATION_MODE = {}", self.validation_mode)?;
         }
 
         if self.size_limit != 0 {
@@ -88,6 +100,15 @@ impl Display for CopyStmt<'_> {
         write!(f, " SINGLE = {}", self.single)?;
         write!(f, " PURGE = {}", self.purge)?;
         write!(f, " FORCE = {}", self.force)?;
+        if self.include_query_id {
+            write!(f, " INCLUDE_QUERY_ID = true")?;
+        }
+        if self.overwrite {
+            write!(f, " OVERWRITE = true")?;
+        }
+        if self.header {
+            write!(f, " HEADER = true")?;
+        }
         Ok(())
     }
 }
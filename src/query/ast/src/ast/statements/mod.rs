@@ -18,6 +18,7 @@ mod database;
 mod explain;
 mod insert;
 mod kill;
+mod pipe;
 mod presign;
 mod share;
 mod show;
@@ -34,6 +35,7 @@ pub use database::*;
 pub use explain::*;
 pub use insert::*;
 pub use kill::*;
+pub use pipe::*;
 pub use presign::*;
 pub use share::*;
 pub use show::*;
@@ -0,0 +1,51 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use crate::ast::CopyStmt;
+
+/// CreatePipeStmt is the parsed statement of `CREATE PIPE`.
+///
+/// ## Examples
+///
+/// ```sql
+/// CREATE PIPE mypipe AS COPY INTO mytable FROM @my_stage
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreatePipeStmt<'a> {
+    pub if_not_exists: bool,
+    pub pipe_name: String,
+    pub auto_ingest: bool,
+    pub comments: String,
+    pub copy: CopyStmt<'a>,
+}
+
+impl Display for CreatePipeStmt<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CREATE PIPE")?;
+        if self.if_not_exists {
+            write!(f, " IF NOT EXISTS")?;
+        }
+        write!(f, " {}", self.pipe_name)?;
+        write!(f, " AUTO_INGEST = {}", self.auto_ingest)?;
+
+        if !self.comments.is_empty() {
+            write!(f, " COMMENTS = '{}'", self.comments)?;
+        }
+
+        write!(f, " AS {}", self.copy)
+    }
+}
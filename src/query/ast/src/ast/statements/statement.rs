@@ -160,6 +160,13 @@ pub enum Statement<'a> {
         pattern: String,
     },
 
+    // Pipes
+    CreatePipe(CreatePipeStmt<'a>),
+    DropPipe {
+        if_exists: bool,
+        pipe_name: String,
+    },
+
     Presign(PresignStmt),
 
     // share
@@ -376,6 +383,17 @@ impl<'a> Display for Statement<'a> {
                 }
             }
             Statement::DescribeStage { stage_name } => write!(f, "DESC STAGE {stage_name}")?,
+            Statement::CreatePipe(stmt) => write!(f, "{stmt}")?,
+            Statement::DropPipe {
+                if_exists,
+                pipe_name,
+            } => {
+                write!(f, "DROP PIPE")?;
+                if *if_exists {
+                    write!(f, " IF EXISTS")?;
+                }
+                write!(f, " {pipe_name}")?;
+            }
             Statement::Call(stmt) => write!(f, "{stmt}")?,
             Statement::Presign(stmt) => write!(f, "{stmt}")?,
             Statement::CreateShare(stmt) => write!(f, "{stmt}")?,
@@ -233,6 +233,8 @@ pub enum ExprElement<'a> {
         name: Identifier<'a>,
         args: Vec<Expr<'a>>,
         params: Vec<Literal>,
+        order_by: Vec<OrderByExpr<'a>>,
+        lambda: Option<Lambda<'a>>,
     },
     /// `CASE ... WHEN ... ELSE ...` expression
     Case {
@@ -408,12 +410,16 @@ impl<'a, I: Iterator<Item = WithSpan<'a, ExprElement<'a>>>> PrattParser<I> for E
                 name,
                 args,
                 params,
+                order_by,
+                lambda,
             } => Expr::FunctionCall {
                 span: elem.span.0,
                 distinct,
                 name,
                 args,
                 params,
+                order_by,
+                lambda,
             },
             ExprElement::Case {
                 operand,
@@ -730,13 +736,35 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             ~ "("
             ~ DISTINCT?
             ~ #comma_separated_list0(subexpr(0))?
+            ~ ( ORDER ~ ^BY ~ ^#comma_separated_list1(order_by_expr) )?
             ~ ")"
         },
-        |(name, _, opt_distinct, opt_args, _)| ExprElement::FunctionCall {
+        |(name, _, opt_distinct, opt_args, opt_order_by, _)| ExprElement::FunctionCall {
             distinct: opt_distinct.is_some(),
             name,
             args: opt_args.unwrap_or_default(),
             params: vec![],
+            order_by: opt_order_by
+                .map(|(_, _, order_by)| order_by)
+                .unwrap_or_default(),
+            lambda: None,
+        },
+    );
+    let function_call_with_lambda = map(
+        rule! {
+            #function_name
+            ~ "(" ~ #subexpr(0) ~ "," ~ #lambda_params ~ "->" ~ #subexpr(0) ~ ")"
+        },
+        |(name, _, arg, _, params, _, expr, _)| ExprElement::FunctionCall {
+            distinct: false,
+            name,
+            args: vec![arg],
+            params: vec![],
+            order_by: vec![],
+            lambda: Some(Lambda {
+                params,
+                expr: Box::new(expr),
+            }),
         },
     );
     let function_call_with_param = map(
@@ -750,6 +778,8 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             name,
             args: opt_args.unwrap_or_default(),
             params,
+            order_by: vec![],
+            lambda: None,
         },
     );
     let case = map(
@@ -878,6 +908,7 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
         rule!(
             #is_distinct_from: "`... IS [NOT] DISTINCT FROM ...`"
             | #count_all : "COUNT(*)"
+            | #function_call_with_lambda : "<function>"
             | #function_call_with_param : "<function>"
             | #function_call : "<function>"
             | #literal : "<literal>"
@@ -1092,12 +1123,32 @@ pub fn type_name(i: Input) -> IResult<TypeName> {
     );
     let ty_float32 = value(TypeName::Float32, rule! { FLOAT32 | FLOAT });
     let ty_float64 = value(TypeName::Float64, rule! { FLOAT64 | DOUBLE });
+    let ty_decimal = map(
+        rule! { ( DECIMAL | NUMERIC ) ~ ( "(" ~ #literal_u64 ~ ( "," ~ #literal_u64 )? ~ ")" )? },
+        |(_, opt_args)| match opt_args {
+            Some((_, precision, opt_scale, _)) => TypeName::Decimal {
+                precision,
+                scale: opt_scale.map(|(_, scale)| scale).unwrap_or(0),
+            },
+            None => TypeName::Decimal {
+                precision: 10,
+                scale: 0,
+            },
+        },
+    );
     let ty_array = map(
         rule! { ARRAY ~ ( "(" ~ #type_name ~ ")" )? },
         |(_, opt_item_type)| TypeName::Array {
             item_type: opt_item_type.map(|(_, opt_item_type, _)| Box::new(opt_item_type)),
         },
     );
+    let ty_map = map(
+        rule! { MAP ~ "(" ~ #type_name ~ "," ~ #type_name ~ ")" },
+        |(_, _, key_type, _, value_type, _)| TypeName::Map {
+            key_type: Box::new(key_type),
+            value_type: Box::new(value_type),
+        },
+    );
     let ty_tuple = map(
         rule! { TUPLE ~ "(" ~ #comma_separated_list1(tuple_types) ~ ")" },
         |(_, _, tuple_types, _)| {
@@ -1127,6 +1178,7 @@ pub fn type_name(i: Input) -> IResult<TypeName> {
         rule! { (DATETIME | TIMESTAMP) ~ ( "(" ~ #literal_u64 ~ ")" )? },
         |(_, _)| TypeName::Timestamp,
     );
+    let ty_timestamp_tz = value(TypeName::TimestampTz, rule! { TIMESTAMP_TZ });
     let ty_string = value(
         TypeName::String,
         rule! { ( STRING | VARCHAR | CHAR | CHARACTER | TEXT  ) ~ ( "(" ~ #literal_u64 ~ ")" )? },
@@ -1146,10 +1198,13 @@ pub fn type_name(i: Input) -> IResult<TypeName> {
             | #ty_int64
             | #ty_float32
             | #ty_float64
+            | #ty_decimal
             | #ty_array
+            | #ty_map
             | #ty_tuple
             | #ty_date
             | #ty_datetime
+            | #ty_timestamp_tz
             | #ty_string
             | #ty_object
             | #ty_variant
@@ -1270,3 +1325,23 @@ pub fn map_access(i: Input) -> IResult<MapAccessor> {
         | #colon
     )(i)
 }
+
+pub fn lambda_params(i: Input) -> IResult<Vec<Identifier>> {
+    let single_param = map(
+        rule! {
+           #ident
+        },
+        |param| vec![param],
+    );
+    let multi_params = map(
+        rule! {
+           "(" ~ #comma_separated_list1(ident) ~ ")"
+        },
+        |(_, params, _)| params,
+    );
+
+    rule!(
+        #single_param
+        | #multi_params
+    )(i)
+}
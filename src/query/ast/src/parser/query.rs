@@ -206,10 +206,12 @@ pub enum TableReferenceElement<'a> {
         alias: Option<TableAlias<'a>>,
         travel_point: Option<TimeTravelPoint<'a>>,
     },
-    // `TABLE(expr)[ AS alias ]`
+    // `[LATERAL] TABLE(expr, name => expr)[ AS alias ]`
     TableFunction {
+        lateral: bool,
         name: Identifier<'a>,
         params: Vec<Expr<'a>>,
+        named_params: Vec<(Identifier<'a>, Expr<'a>)>,
         alias: Option<TableAlias<'a>>,
     },
     // Derived table, which can be a subquery or joined tables or combination of them
@@ -242,12 +244,26 @@ pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceEleme
     );
     let table_function = map(
         rule! {
-            #ident ~ "(" ~ #comma_separated_list0(expr) ~ ")" ~ #table_alias?
+            LATERAL? ~ #ident ~ "(" ~ #comma_separated_list0(table_function_param) ~ ")" ~ #table_alias?
         },
-        |(name, _, params, _, alias)| TableReferenceElement::TableFunction {
-            name,
-            params,
-            alias,
+        |(lateral, name, _, args, _, alias)| {
+            let mut params = vec![];
+            let mut named_params = vec![];
+            for arg in args {
+                match arg {
+                    TableFunctionParam::Positional(param) => params.push(param),
+                    TableFunctionParam::Named(param_name, param) => {
+                        named_params.push((param_name, param))
+                    }
+                }
+            }
+            TableReferenceElement::TableFunction {
+                lateral: lateral.is_some(),
+                name,
+                params,
+                named_params,
+                alias,
+            }
         },
     );
     let subquery = map(
@@ -299,6 +315,24 @@ pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceEleme
     Ok((rest, WithSpan { span, elem }))
 }
 
+enum TableFunctionParam<'a> {
+    Positional(Expr<'a>),
+    Named(Identifier<'a>, Expr<'a>),
+}
+
+fn table_function_param(i: Input) -> IResult<TableFunctionParam> {
+    let named = map(
+        rule! { #function_name ~ "=>" ~ #expr },
+        |(name, _, param)| TableFunctionParam::Named(name, param),
+    );
+    let positional = map(expr, TableFunctionParam::Positional);
+
+    rule!(
+        #named
+        | #positional
+    )(i)
+}
+
 struct TableReferenceParser;
 
 impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement<'a>>>> PrattParser<I>
@@ -335,13 +369,17 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement<'a>>>> PrattParse
                 travel_point,
             },
             TableReferenceElement::TableFunction {
+                lateral,
                 name,
                 params,
+                named_params,
                 alias,
             } => TableReference::TableFunction {
                 span: input.span.0,
+                lateral,
                 name,
                 params,
+                named_params,
                 alias,
             },
             TableReferenceElement::Subquery { subquery, alias } => TableReference::Subquery {
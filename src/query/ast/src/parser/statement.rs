@@ -750,56 +750,37 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             stage_name: stage_name.to_string(),
         },
     );
-    let copy_into = map(
+    let copy_into = map(copy_stmt, Statement::Copy);
+
+    let create_pipe = map(
         rule! {
-            COPY
-            ~ INTO ~ #copy_unit
-            ~ FROM ~ #copy_unit
-            ~ ( FILES ~ "=" ~ "(" ~ #comma_separated_list0(literal_string) ~ ")")?
-            ~ ( PATTERN ~ "=" ~ #literal_string)?
-            ~ ( FILE_FORMAT ~ "=" ~ #options)?
-            ~ ( VALIDATION_MODE ~ "=" ~ #literal_string)?
-            ~ ( SIZE_LIMIT ~ "=" ~ #literal_u64)?
-            ~ ( MAX_FILE_SIZE ~ "=" ~ #literal_u64)?
-            ~ ( SPLIT_SIZE ~ "=" ~ #literal_u64)?
-            ~ ( SINGLE ~ "=" ~ #literal_bool)?
-            ~ ( PURGE ~ "=" ~ #literal_bool)?
-            ~ ( FORCE ~ "=" ~ #literal_bool)?
+            CREATE ~ PIPE ~ ( IF ~ NOT ~ EXISTS )?
+            ~ #ident
+            ~ ( AUTO_INGEST ~ "=" ~ #literal_bool )?
+            ~ ( (COMMENT | COMMENTS) ~ "=" ~ #literal_string )?
+            ~ AS ~ #copy_stmt
         },
-        |(
-            _,
-            _,
-            dst,
-            _,
-            src,
-            files,
-            pattern,
-            file_format,
-            validation_mode,
-            size_limit,
-            max_file_size,
-            split_size,
-            single,
-            purge,
-            force,
-        )| {
-            Statement::Copy(CopyStmt {
-                src,
-                dst,
-                files: files.map(|v| v.3).unwrap_or_default(),
-                pattern: pattern.map(|v| v.2).unwrap_or_default(),
-                file_format: file_format.map(|v| v.2).unwrap_or_default(),
-                validation_mode: validation_mode.map(|v| v.2).unwrap_or_default(),
-                size_limit: size_limit.map(|v| v.2).unwrap_or_default() as usize,
-                max_file_size: max_file_size.map(|v| v.2).unwrap_or_default() as usize,
-                split_size: split_size.map(|v| v.2).unwrap_or_default() as usize,
-                single: single.map(|v| v.2).unwrap_or_default(),
-                purge: purge.map(|v| v.2).unwrap_or_default(),
-                force: force.map(|v| v.2).unwrap_or_default(),
+        |(_, _, opt_if_not_exists, pipe, opt_auto_ingest, opt_comment, _, copy)| {
+            Statement::CreatePipe(CreatePipeStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                pipe_name: pipe.to_string(),
+                auto_ingest: opt_auto_ingest.map(|v| v.2).unwrap_or(true),
+                comments: opt_comment.map(|v| v.2).unwrap_or_default(),
+                copy,
             })
         },
     );
 
+    let drop_pipe = map(
+        rule! {
+            DROP ~ PIPE ~ ( IF ~ EXISTS )? ~ #ident
+        },
+        |(_, _, opt_if_exists, pipe_name)| Statement::DropPipe {
+            if_exists: opt_if_exists.is_some(),
+            pipe_name: pipe_name.to_string(),
+        },
+    );
+
     let call = map(
         rule! {
             CALL ~ #ident ~ "(" ~ #comma_separated_list0(parameter_to_string) ~ ")"
@@ -975,11 +956,14 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             #copy_into: "`COPY
                 INTO { internalStage | externalStage | externalLocation | [<database_name>.]<table_name> }
                 FROM { internalStage | externalStage | externalLocation | [<database_name>.]<table_name> | ( <query> ) }
+                [ PARTITION BY <expr> [ , <expr> ] [ , ... ] ]
                 [ FILE_FORMAT = ( { TYPE = { CSV | JSON | PARQUET } [ formatTypeOptions ] } ) ]
                 [ FILES = ( '<file_name>' [ , '<file_name>' ] [ , ... ] ) ]
                 [ PATTERN = '<regex_pattern>' ]
                 [ VALIDATION_MODE = RETURN_ROWS ]
                 [ copyOptions ]`"
+            | #create_pipe: "`CREATE PIPE [ IF NOT EXISTS ] <pipe_name> [ AUTO_INGEST = { TRUE | FALSE } ] [ COMMENT = '<string_literal>' ] AS <copy_statement>`"
+            | #drop_pipe: "`DROP PIPE [ IF EXISTS ] <pipe_name>`"
         ),
         rule! (
             #call: "`CALL <procedure_name>(<parameter>, ...)`"
@@ -1375,6 +1359,70 @@ pub fn kill_target(i: Input) -> IResult<KillTarget> {
     ))(i)
 }
 
+pub fn copy_stmt(i: Input) -> IResult<CopyStmt> {
+    map(
+        rule! {
+            COPY
+            ~ INTO ~ #copy_unit
+            ~ FROM ~ #copy_unit
+            ~ ( PARTITION ~ ^BY ~ ^#comma_separated_list1(expr) )?
+            ~ ( FILES ~ "=" ~ "(" ~ #comma_separated_list0(literal_string) ~ ")")?
+            ~ ( PATTERN ~ "=" ~ #literal_string)?
+            ~ ( FILE_FORMAT ~ "=" ~ #options)?
+            ~ ( VALIDATION_MODE ~ "=" ~ ( #literal_string | #ident_to_string ))?
+            ~ ( SIZE_LIMIT ~ "=" ~ #literal_u64)?
+            ~ ( MAX_FILE_SIZE ~ "=" ~ #literal_u64)?
+            ~ ( SPLIT_SIZE ~ "=" ~ #literal_u64)?
+            ~ ( SINGLE ~ "=" ~ #literal_bool)?
+            ~ ( PURGE ~ "=" ~ #literal_bool)?
+            ~ ( FORCE ~ "=" ~ #literal_bool)?
+            ~ ( INCLUDE_QUERY_ID ~ "=" ~ #literal_bool)?
+            ~ ( OVERWRITE ~ "=" ~ #literal_bool)?
+            ~ ( HEADER ~ "=" ~ #literal_bool)?
+        },
+        |(
+            _,
+            _,
+            dst,
+            _,
+            src,
+            partition_by,
+            files,
+            pattern,
+            file_format,
+            validation_mode,
+            size_limit,
+            max_file_size,
+            split_size,
+            single,
+            purge,
+            force,
+            include_query_id,
+            overwrite,
+            header,
+        )| {
+            CopyStmt {
+                src,
+                dst,
+                partition_by: partition_by.map(|v| v.2).unwrap_or_default(),
+                files: files.map(|v| v.3).unwrap_or_default(),
+                pattern: pattern.map(|v| v.2).unwrap_or_default(),
+                file_format: file_format.map(|v| v.2).unwrap_or_default(),
+                validation_mode: validation_mode.map(|v| v.2).unwrap_or_default(),
+                size_limit: size_limit.map(|v| v.2).unwrap_or_default() as usize,
+                max_file_size: max_file_size.map(|v| v.2).unwrap_or_default() as usize,
+                split_size: split_size.map(|v| v.2).unwrap_or_default() as usize,
+                single: single.map(|v| v.2).unwrap_or_default(),
+                purge: purge.map(|v| v.2).unwrap_or_default(),
+                force: force.map(|v| v.2).unwrap_or_default(),
+                include_query_id: include_query_id.map(|v| v.2).unwrap_or_default(),
+                overwrite: overwrite.map(|v| v.2).unwrap_or_default(),
+                header: header.map(|v| v.2).unwrap_or_default(),
+            }
+        },
+    )(i)
+}
+
 /// Parse input into `CopyUnit`
 ///
 /// # Notes
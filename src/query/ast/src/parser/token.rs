@@ -263,6 +263,8 @@ pub enum TokenKind {
     AT,
     #[token("ASC", ignore(ascii_case))]
     ASC,
+    #[token("AUTO_INGEST", ignore(ascii_case))]
+    AUTO_INGEST,
     #[token("AWS_KEY_ID", ignore(ascii_case))]
     AWS_KEY_ID,
     #[token("AWS_SECRET_KEY", ignore(ascii_case))]
@@ -337,6 +339,8 @@ pub enum TokenKind {
     DAY,
     #[token("DECADE", ignore(ascii_case))]
     DECADE,
+    #[token("DECIMAL", ignore(ascii_case))]
+    DECIMAL,
     #[token("DEFAULT", ignore(ascii_case))]
     DEFAULT,
     #[token("DELETE", ignore(ascii_case))]
@@ -429,6 +433,8 @@ pub enum TokenKind {
     GROUP,
     #[token("HAVING", ignore(ascii_case))]
     HAVING,
+    #[token("HEADER", ignore(ascii_case))]
+    HEADER,
     #[token("HISTORY", ignore(ascii_case))]
     HISTORY,
     #[token("HOUR", ignore(ascii_case))]
@@ -441,6 +447,8 @@ pub enum TokenKind {
     IF,
     #[token("IN", ignore(ascii_case))]
     IN,
+    #[token("INCLUDE_QUERY_ID", ignore(ascii_case))]
+    INCLUDE_QUERY_ID,
     #[token("INNER", ignore(ascii_case))]
     INNER,
     #[token("INSERT", ignore(ascii_case))]
@@ -481,6 +489,8 @@ pub enum TokenKind {
     KILL,
     #[token("ROLES", ignore(ascii_case))]
     ROLES,
+    #[token("LATERAL", ignore(ascii_case))]
+    LATERAL,
     #[token("LEADING", ignore(ascii_case))]
     LEADING,
     #[token("LEFT", ignore(ascii_case))]
@@ -523,6 +533,8 @@ pub enum TokenKind {
     NOTENANTSETTING,
     #[token("NULL", ignore(ascii_case))]
     NULL,
+    #[token("NUMERIC", ignore(ascii_case))]
+    NUMERIC,
     #[token("OBJECT", ignore(ascii_case))]
     OBJECT,
     #[token("OF", ignore(ascii_case))]
@@ -545,8 +557,12 @@ pub enum TokenKind {
     OVERWRITE,
     #[token("PARQUET", ignore(ascii_case))]
     PARQUET,
+    #[token("PARTITION", ignore(ascii_case))]
+    PARTITION,
     #[token("PATTERN", ignore(ascii_case))]
     PATTERN,
+    #[token("PIPE", ignore(ascii_case))]
+    PIPE,
     #[token("PIPELINE", ignore(ascii_case))]
     PIPELINE,
     #[token("PLAINTEXT_PASSWORD", ignore(ascii_case))]
@@ -663,6 +679,8 @@ pub enum TokenKind {
     THEN,
     #[token("TIMESTAMP", ignore(ascii_case))]
     TIMESTAMP,
+    #[token("TIMESTAMP_TZ", ignore(ascii_case))]
+    TIMESTAMP_TZ,
     #[token("TIMEZONE_HOUR", ignore(ascii_case))]
     TIMEZONE_HOUR,
     #[token("TIMEZONE_MINUTE", ignore(ascii_case))]
@@ -999,7 +1017,7 @@ impl TokenKind {
             | TokenKind::INNER
             | TokenKind::IS
             | TokenKind::JOIN
-            // | TokenKind::LATERAL
+            | TokenKind::LATERAL
             | TokenKind::LEADING
             | TokenKind::LEFT
             | TokenKind::LIKE
@@ -20,7 +20,9 @@ use common_functions::is_builtin_function;
 
 use crate::ast::Expr;
 use crate::ast::Identifier;
+use crate::ast::Lambda;
 use crate::ast::Literal;
+use crate::ast::OrderByExpr;
 use crate::parser::token::Token;
 use crate::walk_expr;
 use crate::Visitor;
@@ -87,6 +89,8 @@ impl<'ast> Visitor<'ast> for UDFValidator {
         name: &'ast Identifier<'ast>,
         args: &'ast [Expr<'ast>],
         _params: &'ast [Literal],
+        order_by: &'ast [OrderByExpr<'ast>],
+        lambda: &'ast Option<Lambda<'ast>>,
     ) {
         let name = name.to_string();
         if !is_builtin_function(&name) && self.name.eq_ignore_ascii_case(&name) {
@@ -97,5 +101,17 @@ impl<'ast> Visitor<'ast> for UDFValidator {
         for arg in args {
             walk_expr(self, arg);
         }
+        for order_by in order_by {
+            walk_expr(self, &order_by.expr);
+        }
+        if let Some(lambda) = lambda {
+            // The parameters of the lambda are not parameters of the UDF
+            let outer_params = std::mem::take(&mut self.expr_params);
+            walk_expr(self, &lambda.expr);
+            for param in lambda.params.iter() {
+                self.expr_params.remove(&param.to_string());
+            }
+            self.expr_params.extend(outer_params);
+        }
     }
 }
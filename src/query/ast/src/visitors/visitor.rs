@@ -232,10 +232,18 @@ pub trait Visitor<'ast>: Sized {
         _name: &'ast Identifier<'ast>,
         args: &'ast [Expr<'ast>],
         _params: &'ast [Literal],
+        order_by: &'ast [OrderByExpr<'ast>],
+        lambda: &'ast Option<Lambda<'ast>>,
     ) {
         for arg in args {
             walk_expr(self, arg);
         }
+        for order_by in order_by {
+            self.visit_order_by(order_by);
+        }
+        if let Some(lambda) = lambda {
+            walk_expr(self, &lambda.expr);
+        }
     }
 
     fn visit_case_when(
@@ -485,6 +493,10 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_list_stage(&mut self, _location: &'ast str, _pattern: &'ast str) {}
 
+    fn visit_create_pipe(&mut self, _stmt: &'ast CreatePipeStmt<'ast>) {}
+
+    fn visit_drop_pipe(&mut self, _if_exists: bool, _pipe_name: &'ast str) {}
+
     fn visit_presign(&mut self, _presign: &'ast PresignStmt) {}
 
     fn visit_create_share(&mut self, _stmt: &'ast CreateShareStmt<'ast>) {}
@@ -237,10 +237,18 @@ pub trait VisitorMut: Sized {
         _name: &mut Identifier<'_>,
         args: &mut [Expr<'_>],
         _params: &mut [Literal],
+        order_by: &mut [OrderByExpr<'_>],
+        lambda: &mut Option<Lambda<'_>>,
     ) {
         for arg in args.iter_mut() {
             walk_expr_mut(self, arg);
         }
+        for order_by in order_by.iter_mut() {
+            self.visit_order_by(order_by);
+        }
+        if let Some(lambda) = lambda {
+            walk_expr_mut(self, &mut lambda.expr);
+        }
     }
 
     fn visit_case_when(
@@ -488,6 +496,10 @@ pub trait VisitorMut: Sized {
 
     fn visit_list_stage(&mut self, _location: &mut String, _pattern: &mut String) {}
 
+    fn visit_create_pipe(&mut self, _stmt: &mut CreatePipeStmt<'_>) {}
+
+    fn visit_drop_pipe(&mut self, _if_exists: bool, _pipe_name: &mut String) {}
+
     fn visit_presign(&mut self, _presign: &mut PresignStmt) {}
 
     fn visit_create_share(&mut self, _stmt: &mut CreateShareStmt<'_>) {}
@@ -93,7 +93,9 @@ pub fn walk_expr_mut<'a, V: VisitorMut>(visitor: &mut V, expr: &mut Expr<'a>) {
             name,
             args,
             params,
-        } => visitor.visit_function_call(span, *distinct, name, args, params),
+            order_by,
+            lambda,
+        } => visitor.visit_function_call(span, *distinct, name, args, params, order_by, lambda),
         Expr::Case {
             span,
             operand,
@@ -240,6 +242,7 @@ pub fn walk_table_reference_mut<'a, V: VisitorMut>(
         TableReference::TableFunction {
             name,
             params,
+            named_params,
             alias,
             ..
         } => {
@@ -247,6 +250,10 @@ pub fn walk_table_reference_mut<'a, V: VisitorMut>(
             for param in params {
                 visitor.visit_expr(param);
             }
+            for (param_name, param) in named_params {
+                visitor.visit_identifier(param_name);
+                visitor.visit_expr(param);
+            }
             if let Some(alias) = alias {
                 visitor.visit_identifier(&mut alias.name);
             }
@@ -388,6 +395,11 @@ pub fn walk_statement_mut<'a, V: VisitorMut>(visitor: &mut V, statement: &mut St
             visitor.visit_remove_stage(location, pattern)
         }
         Statement::DescribeStage { stage_name } => visitor.visit_describe_stage(stage_name),
+        Statement::CreatePipe(stmt) => visitor.visit_create_pipe(stmt),
+        Statement::DropPipe {
+            if_exists,
+            pipe_name,
+        } => visitor.visit_drop_pipe(*if_exists, pipe_name),
         Statement::Call(stmt) => visitor.visit_call(stmt),
         Statement::Presign(stmt) => visitor.visit_presign(stmt),
         Statement::CreateShare(stmt) => visitor.visit_create_share(stmt),
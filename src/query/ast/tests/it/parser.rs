@@ -91,6 +91,9 @@ fn test_statement() {
         r#"drop database catalog.t;"#,
         r#"drop database if exists t;"#,
         r#"create table c(a DateTime null, b DateTime(3));"#,
+        r#"create table c(a decimal(10, 2) null, b numeric);"#,
+        r#"create table c(a map(string, int32 null), b map);"#,
+        r#"create table c(a timestamp_tz null, b timestamp_tz);"#,
         r#"create view v as select number % 3 as a from numbers(1000);"#,
         r#"alter view v as select number % 3 as a from numbers(1000);"#,
         r#"drop view v;"#,
@@ -111,6 +114,7 @@ fn test_statement() {
         r#"select * from t4;"#,
         r#"select * from aa.bb;"#,
         r#"select * from a, b, c;"#,
+        r#"select * from t, lateral flatten(input => t.v, outer => true) as f;"#,
         r#"select * from a, b, c order by "db"."a"."c1";"#,
         r#"select * from a join b on a.a = b.a;"#,
         r#"select * from a left outer join b on a.a = b.a;"#,
@@ -271,6 +275,15 @@ fn test_statement() {
                     skip_header = 1
                 )
                 force=true;"#,
+        r#"COPY INTO mytable
+                FROM @my_stage
+                VALIDATION_MODE = RETURN_10_ROWS;"#,
+        r#"COPY INTO mytable
+                FROM @my_stage
+                VALIDATION_MODE = 'RETURN_ERRORS';"#,
+        r#"COPY INTO @my_stage FROM mytable PARTITION BY a FILE_FORMAT = (type = 'PARQUET') INCLUDE_QUERY_ID = true OVERWRITE = true HEADER = true;"#,
+        r#"CREATE PIPE IF NOT EXISTS mypipe AUTO_INGEST = false COMMENT = 'load my_stage' AS COPY INTO mytable FROM @my_stage FILE_FORMAT = (type = 'CSV');"#,
+        r#"DROP PIPE IF EXISTS mypipe;"#,
         // We used to support COPY FROM a quoted at string
         // r#"COPY INTO mytable
         //         FROM '@external_stage/path/to/file.csv'
@@ -507,6 +520,7 @@ fn test_expr() {
         r#"1 is distinct from 2"#,
         r#"a is distinct from b"#,
         r#"1 is not distinct from null"#,
+        r#"array_filter(arr, x -> x > 1)"#,
     ];
 
     for case in cases {
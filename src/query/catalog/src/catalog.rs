@@ -16,24 +16,31 @@ use std::any::Any;
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use common_base::base::tokio::sync::mpsc;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_app::schema::CountTablesReply;
 use common_meta_app::schema::CountTablesReq;
 use common_meta_app::schema::CreateDatabaseReply;
 use common_meta_app::schema::CreateDatabaseReq;
+use common_meta_app::schema::CreateTableLockRevReply;
+use common_meta_app::schema::CreateTableLockRevReq;
 use common_meta_app::schema::CreateTableReq;
+use common_meta_app::schema::DeleteTableLockRevReq;
 use common_meta_app::schema::DropDatabaseReq;
 use common_meta_app::schema::DropTableReply;
 use common_meta_app::schema::DropTableReq;
+use common_meta_app::schema::ExtendTableLockRevReq;
 use common_meta_app::schema::GetTableCopiedFileReply;
 use common_meta_app::schema::GetTableCopiedFileReq;
+use common_meta_app::schema::ListTableLocksReq;
 use common_meta_app::schema::RenameDatabaseReply;
 use common_meta_app::schema::RenameDatabaseReq;
 use common_meta_app::schema::RenameTableReply;
 use common_meta_app::schema::RenameTableReq;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableLockInfo;
 use common_meta_app::schema::TableMeta;
 use common_meta_app::schema::TruncateTableReply;
 use common_meta_app::schema::TruncateTableReq;
@@ -186,6 +193,40 @@ pub trait Catalog: DynClone + Send + Sync {
         req: TruncateTableReq,
     ) -> Result<TruncateTableReply>;
 
+    /// Table lock
+
+    // Create a lock revision of a table, the smallest alive revision holds the lock.
+    async fn create_table_lock_rev(
+        &self,
+        _req: CreateTableLockRevReq,
+    ) -> Result<CreateTableLockRevReply> {
+        Err(ErrorCode::UnImplement(
+            "'create_table_lock_rev' not implemented",
+        ))
+    }
+
+    async fn extend_table_lock_rev(&self, _req: ExtendTableLockRevReq) -> Result<()> {
+        Err(ErrorCode::UnImplement(
+            "'extend_table_lock_rev' not implemented",
+        ))
+    }
+
+    async fn delete_table_lock_rev(&self, _req: DeleteTableLockRevReq) -> Result<()> {
+        Err(ErrorCode::UnImplement(
+            "'delete_table_lock_rev' not implemented",
+        ))
+    }
+
+    async fn list_table_locks(&self, _req: ListTableLocksReq) -> Result<Vec<TableLockInfo>> {
+        Err(ErrorCode::UnImplement("'list_table_locks' not implemented"))
+    }
+
+    // Watch the lock revisions of a table, a message is received when any of them is released.
+    // None is returned if the lock revisions can not be watched, the caller polls them instead.
+    async fn watch_table_locks(&self, _table_id: u64) -> Result<Option<mpsc::Receiver<()>>> {
+        Ok(None)
+    }
+
     /// Table function
 
     // Get function by name.
@@ -79,6 +79,23 @@ impl Config {
         !self.query.rpc_tls_server_key.is_empty() && !self.query.rpc_tls_server_cert.is_empty()
     }
 
+    /// Build the meta client config, with which writes are attributed to the tenant of this query node.
+    ///
+    /// A node in management mode manages all tenants, e.g., it sets tenant quotas,
+    /// thus its writes are not attributed to any tenant.
+    pub fn to_meta_grpc_client_conf(&self) -> RpcClientConf {
+        let tenant = if self.query.management_mode {
+            None
+        } else {
+            Some(self.query.tenant_id.clone())
+        };
+
+        RpcClientConf {
+            tenant,
+            ..self.meta.to_meta_grpc_client_conf()
+        }
+    }
+
     /// Transform config into the outer style.
     ///
     /// This function should only be used for end-users.
@@ -150,6 +167,8 @@ pub struct QueryConfig {
     pub async_insert_stale_timeout: u64,
     pub idm: IDMConfig,
     pub share_endpoint_address: String,
+    /// Seconds between two runs of a pipe, 0 disables running the pipes on this node.
+    pub pipe_poll_interval_secs: u64,
 }
 
 impl Default for QueryConfig {
@@ -196,6 +215,7 @@ impl Default for QueryConfig {
             async_insert_stale_timeout: 0,
             idm: IDMConfig::default(),
             share_endpoint_address: "".to_string(),
+            pipe_poll_interval_secs: 60,
         }
     }
 }
@@ -266,6 +286,9 @@ pub struct MetaConfig {
     /// AutoSyncInterval is the interval to update endpoints with its latest members.
     /// 0 disables auto-sync. By default auto-sync is disabled.
     pub auto_sync_interval: u64,
+    /// The max staleness in milliseconds a read from meta service is allowed to have,
+    /// so that it can be served by any meta node. 0 requires linearizable reads.
+    pub max_read_staleness_ms: u64,
     /// Certificate for client to identify meta rpc serve
     pub rpc_tls_meta_server_root_ca_cert: String,
     pub rpc_tls_meta_service_domain_name: String,
@@ -281,6 +304,7 @@ impl Default for MetaConfig {
             password: "".to_string(),
             client_timeout_in_second: 10,
             auto_sync_interval: 10,
+            max_read_staleness_ms: 0,
             rpc_tls_meta_server_root_ca_cert: "".to_string(),
             rpc_tls_meta_service_domain_name: "localhost".to_string(),
         }
@@ -318,6 +342,12 @@ impl MetaConfig {
             } else {
                 None
             },
+            max_read_staleness: if self.max_read_staleness_ms > 0 {
+                Some(Duration::from_millis(self.max_read_staleness_ms))
+            } else {
+                None
+            },
+            tenant: None,
         }
     }
 }
@@ -332,6 +362,7 @@ impl Debug for MetaConfig {
             .field("embedded_dir", &self.embedded_dir)
             .field("client_timeout_in_second", &self.client_timeout_in_second)
             .field("auto_sync_interval", &self.auto_sync_interval)
+            .field("max_read_staleness_ms", &self.max_read_staleness_ms)
             .field(
                 "rpc_tls_meta_server_root_ca_cert",
                 &self.rpc_tls_meta_server_root_ca_cert,
@@ -934,6 +934,10 @@ pub struct QueryConfig {
 
     #[clap(long, default_value = "")]
     pub share_endpoint_address: String,
+
+    /// Seconds between two runs of a pipe, 0 disables running the pipes on this node.
+    #[clap(long, default_value = "60")]
+    pub pipe_poll_interval_secs: u64,
 }
 
 impl Default for QueryConfig {
@@ -990,6 +994,7 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
                 users: users_to_inner(self.users)?,
             },
             share_endpoint_address: self.share_endpoint_address,
+            pipe_poll_interval_secs: self.pipe_poll_interval_secs,
         })
     }
 }
@@ -1045,6 +1050,7 @@ impl From<InnerQueryConfig> for QueryConfig {
             async_insert_stale_timeout: inner.async_insert_stale_timeout,
             users: users_from_inner(inner.idm.users),
             share_endpoint_address: inner.share_endpoint_address,
+            pipe_poll_interval_secs: inner.pipe_poll_interval_secs,
         }
     }
 }
@@ -1273,6 +1279,12 @@ pub struct MetaConfig {
     #[serde(alias = "auto_sync_interval")]
     pub auto_sync_interval: u64,
 
+    /// The max staleness in milliseconds a read from meta service is allowed to have,
+    /// so that it can be served by any meta node. 0 requires linearizable reads.
+    #[clap(long = "meta-max-read-staleness-ms", default_value = "0")]
+    #[serde(alias = "meta_max_read_staleness_ms")]
+    pub max_read_staleness_ms: u64,
+
     /// Certificate for client to identify meta rpc serve
     #[clap(long = "meta-rpc-tls-meta-server-root-ca-cert", default_value_t)]
     pub rpc_tls_meta_server_root_ca_cert: String,
@@ -1299,6 +1311,7 @@ impl TryInto<InnerMetaConfig> for MetaConfig {
             password: self.password,
             client_timeout_in_second: self.client_timeout_in_second,
             auto_sync_interval: self.auto_sync_interval,
+            max_read_staleness_ms: self.max_read_staleness_ms,
             rpc_tls_meta_server_root_ca_cert: self.rpc_tls_meta_server_root_ca_cert,
             rpc_tls_meta_service_domain_name: self.rpc_tls_meta_service_domain_name,
         })
@@ -1315,6 +1328,7 @@ impl From<InnerMetaConfig> for MetaConfig {
             password: inner.password,
             client_timeout_in_second: inner.client_timeout_in_second,
             auto_sync_interval: inner.auto_sync_interval,
+            max_read_staleness_ms: inner.max_read_staleness_ms,
             rpc_tls_meta_server_root_ca_cert: inner.rpc_tls_meta_server_root_ca_cert,
             rpc_tls_meta_service_domain_name: inner.rpc_tls_meta_service_domain_name,
         }
@@ -1331,6 +1345,7 @@ impl Debug for MetaConfig {
             .field("embedded_dir", &self.embedded_dir)
             .field("client_timeout_in_second", &self.client_timeout_in_second)
             .field("auto_sync_interval", &self.auto_sync_interval)
+            .field("max_read_staleness_ms", &self.max_read_staleness_ms)
             .field(
                 "rpc_tls_meta_server_root_ca_cert",
                 &self.rpc_tls_meta_server_root_ca_cert,
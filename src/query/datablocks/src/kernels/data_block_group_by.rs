@@ -12,9 +12,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_datavalues::make_timestamp_tz;
 use common_datavalues::remove_nullable;
+use common_datavalues::timestamp_tz_micros;
+use common_datavalues::Column;
+use common_datavalues::ColumnRef;
 use common_datavalues::DataType;
 use common_datavalues::DataTypeImpl;
+use common_datavalues::Int128Column;
+use common_datavalues::NullableColumn;
+use common_datavalues::Series;
+use common_datavalues::TypeID;
 use common_exception::Result;
 
 use crate::kernels::HashMethodKeysU16;
@@ -76,6 +84,40 @@ impl DataBlock {
         }
     }
 
+    /// The column to build hash keys from for a column of `data_type`.
+    ///
+    /// `TIMESTAMP_TZ` values are equal when their instants are, so their offsets are
+    /// dropped and only the UTC microseconds go into the keys. Groups built from such
+    /// keys come back with a `+00:00` offset.
+    pub fn hash_key_column(column: &ColumnRef, data_type: &DataTypeImpl) -> Result<ColumnRef> {
+        if remove_nullable(data_type).data_type_id() != TypeID::TimestampTz {
+            return Ok(column.clone());
+        }
+
+        let column = column.convert_full_column();
+        let (values, validity) = match column.is_nullable() {
+            true => {
+                let column: &NullableColumn = Series::check_get(&column)?;
+                (
+                    column.inner().clone(),
+                    Some(column.ensure_validity().clone()),
+                )
+            }
+            false => (column, None),
+        };
+
+        let values: &Int128Column = Series::check_get(&values)?;
+        let values = values
+            .iter()
+            .map(|v| make_timestamp_tz(timestamp_tz_micros(*v), 0))
+            .collect::<Vec<_>>();
+        let values = Int128Column::new_from_vec(values).arc();
+        Ok(match validity {
+            Some(validity) => NullableColumn::wrap_inner(values, Some(validity)),
+            None => values,
+        })
+    }
+
     pub fn group_by_blocks(block: &DataBlock, indices: &[usize]) -> Result<Vec<DataBlock>> {
         let method = Self::choose_hash_method(block, indices)?;
         Ok(match method {
@@ -75,6 +75,9 @@ impl ArrayColumn {
                     .expect("list to large list cast should be ok");
                 Self::from_arrow_array(array.as_ref())
             }
+            ArrowType::Map(_, _) => {
+                Self::from_map_array(array.as_any().downcast_ref::<MapArray>().unwrap())
+            }
             _ => Self::new(
                 array
                     .as_any()
@@ -85,6 +88,40 @@ impl ArrayColumn {
         }
     }
 
+    fn from_map_array(array: &MapArray) -> Self {
+        let data_type = from_arrow_type(array.data_type());
+        let map_type: MapType = data_type.clone().try_into().unwrap();
+        let entries = array
+            .field()
+            .as_any()
+            .downcast_ref::<StructArray>()
+            .unwrap();
+        let entries_values = entries
+            .values()
+            .iter()
+            .zip([map_type.key_type(), map_type.value_type()])
+            .map(|(array, ty)| {
+                if ty.is_nullable() {
+                    array.clone().into_nullable_column()
+                } else {
+                    array.clone().into_column()
+                }
+            })
+            .collect();
+        let values = StructColumn::from_data(entries_values, map_type.entries_type());
+        let offsets = array
+            .offsets()
+            .iter()
+            .map(|offset| *offset as i64)
+            .collect::<Vec<_>>();
+
+        Self {
+            data_type,
+            offsets: offsets.into(),
+            values: Arc::new(values),
+        }
+    }
+
     pub fn from_data(data_type: DataTypeImpl, offsets: Buffer<i64>, values: ColumnRef) -> Self {
         Self {
             data_type,
@@ -119,13 +156,24 @@ impl Column for ArrayColumn {
     }
 
     fn column_type_name(&self) -> String {
-        "Array".to_string()
+        match self.data_type {
+            DataTypeImpl::Map(_) => "Map".to_string(),
+            _ => "Array".to_string(),
+        }
     }
 
     fn column_meta(&self) -> ColumnMeta {
-        let data_type: ArrayType = self.data_type.clone().try_into().unwrap();
-        ColumnMeta::Array {
-            inner_type: data_type.inner_type().clone(),
+        match &self.data_type {
+            DataTypeImpl::Map(map_type) => ColumnMeta::Map {
+                key_type: map_type.key_type().clone(),
+                value_type: map_type.value_type().clone(),
+            },
+            data_type => {
+                let data_type: ArrayType = data_type.clone().try_into().unwrap();
+                ColumnMeta::Array {
+                    inner_type: data_type.inner_type().clone(),
+                }
+            }
         }
     }
 
@@ -139,17 +187,29 @@ impl Column for ArrayColumn {
 
     fn as_arrow_array(&self, data_type: DataTypeImpl) -> ArrayRef {
         let arrow_type = data_type.arrow_type();
-        if let ArrowType::LargeList(ref f) = arrow_type {
-            let inner_f = from_arrow_field(f.as_ref());
-            let array = self.values.as_arrow_array(inner_f);
-            Box::new(LargeListArray::from_data(
-                arrow_type,
-                self.offsets.clone(),
-                array,
-                None,
-            ))
-        } else {
-            unreachable!()
+        match arrow_type {
+            ArrowType::LargeList(ref f) => {
+                let inner_f = from_arrow_field(f.as_ref());
+                let array = self.values.as_arrow_array(inner_f);
+                Box::new(LargeListArray::from_data(
+                    arrow_type,
+                    self.offsets.clone(),
+                    array,
+                    None,
+                ))
+            }
+            // arrow map uses 32-bit offsets
+            ArrowType::Map(ref f, _) => {
+                let entries_f = from_arrow_field(f.as_ref());
+                let array = self.values.as_arrow_array(entries_f);
+                let offsets = self
+                    .offsets
+                    .iter()
+                    .map(|offset| *offset as i32)
+                    .collect::<Vec<_>>();
+                Box::new(MapArray::new(arrow_type, offsets.into(), array, None))
+            }
+            _ => unreachable!(),
         }
     }
 
@@ -197,7 +197,8 @@ where A: AsRef<dyn Array>
             Int64 | Interval | Timestamp => Arc::new(Int64Column::from_arrow_array(self.as_ref())),
             Float32 => Arc::new(Float32Column::from_arrow_array(self.as_ref())),
             Float64 => Arc::new(Float64Column::from_arrow_array(self.as_ref())),
-            Array => Arc::new(ArrayColumn::from_arrow_array(self.as_ref())),
+            Decimal | TimestampTz => Arc::new(Int128Column::from_arrow_array(self.as_ref())),
+            Array | Map => Arc::new(ArrayColumn::from_arrow_array(self.as_ref())),
             Struct => Arc::new(StructColumn::from_arrow_array(self.as_ref())),
             String => Arc::new(StringColumn::from_arrow_array(self.as_ref())),
             Variant => Arc::new(VariantColumn::from_arrow_array(self.as_ref())),
@@ -264,7 +265,7 @@ impl std::fmt::Debug for dyn Column + '_ {
                 String => {
                     fmt_dyn!(col, StringColumn, f)
                 },
-                Array => {
+                Array | Map => {
                     fmt_dyn!(col, ArrayColumn, f)
                 },
                 Struct => {
@@ -37,6 +37,7 @@ pub enum DataValue {
     Int64(i64),
     UInt64(u64),
     Float64(f64),
+    Int128(i128),
     String(Vec<u8>),
 
     // Container struct.
@@ -56,6 +57,7 @@ pub enum ValueType {
     UInt64,
     Int64,
     Float64,
+    Int128,
     String,
     Array,
     Struct,
@@ -86,6 +88,7 @@ impl DataValue {
             DataValue::Int64(_) => ValueType::Int64,
             DataValue::UInt64(_) => ValueType::UInt64,
             DataValue::Float64(_) => ValueType::Float64,
+            DataValue::Int128(_) => ValueType::Int128,
             DataValue::String(_) => ValueType::String,
             DataValue::Array(_) => ValueType::Array,
             DataValue::Struct(_) => ValueType::Struct,
@@ -123,6 +126,7 @@ impl DataValue {
                 UInt64Type::new_impl()
             }
             DataValue::Float64(_) => Float64Type::new_impl(),
+            DataValue::Int128(_) => DecimalType::new_impl(MAX_DECIMAL_PRECISION, 0),
             DataValue::String(_) => StringType::new_impl(),
             DataValue::Array(vals) => {
                 let inner_type = if vals.is_empty() {
@@ -152,6 +156,7 @@ impl DataValue {
             DataValue::Int64(_) => Int64Type::new_impl(),
             DataValue::UInt64(_) => UInt64Type::new_impl(),
             DataValue::Float64(_) => Float64Type::new_impl(),
+            DataValue::Int128(_) => DecimalType::new_impl(MAX_DECIMAL_PRECISION, 0),
             DataValue::String(_) => StringType::new_impl(),
             DataValue::Array(vals) => {
                 let inner_type = if vals.is_empty() {
@@ -192,7 +197,10 @@ impl DataValue {
     pub fn is_numeric(&self) -> bool {
         matches!(
             self,
-            DataValue::Int64(_) | DataValue::UInt64(_) | DataValue::Float64(_)
+            DataValue::Int64(_)
+                | DataValue::UInt64(_)
+                | DataValue::Float64(_)
+                | DataValue::Int128(_)
         )
     }
 
@@ -205,6 +213,7 @@ impl DataValue {
         match self {
             DataValue::Int64(v) if *v >= 0 => Ok(*v as u64),
             DataValue::UInt64(v) => Ok(*v),
+            DataValue::Int128(v) if *v >= 0 && *v <= u64::MAX as i128 => Ok(*v as u64),
             other => Result::Err(ErrorCode::BadDataValueType(format!(
                 "Unexpected type:{:?} to get u64 number",
                 other.value_type()
@@ -216,6 +225,9 @@ impl DataValue {
         match self {
             DataValue::Int64(v) => Ok(*v),
             DataValue::UInt64(v) => Ok(*v as i64),
+            DataValue::Int128(v) if *v >= i64::MIN as i128 && *v <= i64::MAX as i128 => {
+                Ok(*v as i64)
+            }
             other => Result::Err(ErrorCode::BadDataValueType(format!(
                 "Unexpected type:{:?} to get i64 number",
                 other.value_type()
@@ -223,6 +235,18 @@ impl DataValue {
         }
     }
 
+    pub fn as_i128(&self) -> Result<i128> {
+        match self {
+            DataValue::Int64(v) => Ok(*v as i128),
+            DataValue::UInt64(v) => Ok(*v as i128),
+            DataValue::Int128(v) => Ok(*v),
+            other => Result::Err(ErrorCode::BadDataValueType(format!(
+                "Unexpected type:{:?} to get i128 number",
+                other.value_type()
+            ))),
+        }
+    }
+
     pub fn as_bool(&self) -> Result<bool> {
         match self {
             DataValue::Boolean(v) => Ok(*v),
@@ -238,6 +262,7 @@ impl DataValue {
             DataValue::Int64(v) => Ok(*v as f64),
             DataValue::UInt64(v) => Ok(*v as f64),
             DataValue::Float64(v) => Ok(*v),
+            DataValue::Int128(v) => Ok(*v as f64),
             other => Result::Err(ErrorCode::BadDataValueType(format!(
                 "Unexpected type:{:?} to get f64 number",
                 other.value_type()
@@ -250,6 +275,7 @@ impl DataValue {
             DataValue::Int64(v) => Ok(Vec::<u8>::from((*v).to_string())),
             DataValue::UInt64(v) => Ok(Vec::<u8>::from((*v).to_string())),
             DataValue::Float64(v) => Ok(Vec::<u8>::from((*v).to_string())),
+            DataValue::Int128(v) => Ok(Vec::<u8>::from((*v).to_string())),
             DataValue::String(v) => Ok(v.to_owned()),
             DataValue::Variant(v) => Ok(v.to_string().into_bytes()),
             other => Result::Err(ErrorCode::BadDataValueType(format!(
@@ -316,6 +342,7 @@ impl Ord for DataValue {
                 (DataValue::Boolean(v1), DataValue::Boolean(v2)) => v1.cmp(v2),
                 (DataValue::UInt64(v1), DataValue::UInt64(v2)) => v1.cmp(v2),
                 (DataValue::Int64(v1), DataValue::Int64(v2)) => v1.cmp(v2),
+                (DataValue::Int128(v1), DataValue::Int128(v2)) => v1.cmp(v2),
                 (DataValue::Float64(v1), DataValue::Float64(v2)) => {
                     OrderedFloat::from(*v1).cmp(&OrderedFloat::from(*v2))
                 }
@@ -364,6 +391,10 @@ impl Ord for DataValue {
                 .cmp(&OrderedFloat::from(other.as_f64().unwrap()));
         }
 
+        if matches!(self, DataValue::Int128(_)) || matches!(other, DataValue::Int128(_)) {
+            return self.as_i128().unwrap().cmp(&other.as_i128().unwrap());
+        }
+
         self.as_i64().unwrap().cmp(&other.as_i64().unwrap())
     }
 }
@@ -378,6 +409,7 @@ impl Hash for DataValue {
             DataValue::UInt64(v) => v.hash(state),
             DataValue::Int64(v) => v.hash(state),
             DataValue::Float64(v) => v.to_bits().hash(state),
+            DataValue::Int128(v) => v.hash(state),
             DataValue::String(v) => v.hash(state),
             DataValue::Array(v) => v.hash(state),
             DataValue::Struct(v) => v.hash(state),
@@ -413,6 +445,7 @@ impl DFTryFrom<DataValue> for VariantValue {
             DataValue::Int64(v) => Ok(VariantValue::from(json!(v))),
             DataValue::UInt64(v) => Ok(VariantValue::from(json!(v))),
             DataValue::Float64(v) => Ok(VariantValue::from(json!(v))),
+            DataValue::Int128(v) => Ok(VariantValue::from(json!(v.to_string()))),
             DataValue::String(v) => Ok(VariantValue::from(json!(v))),
             DataValue::Array(v) => Ok(VariantValue::from(json!(v))),
             DataValue::Struct(v) => Ok(VariantValue::from(json!(v))),
@@ -429,6 +462,7 @@ impl DFTryFrom<&DataValue> for VariantValue {
             DataValue::Int64(v) => Ok(VariantValue::from(json!(*v as i64))),
             DataValue::UInt64(v) => Ok(VariantValue::from(json!(*v as u64))),
             DataValue::Float64(v) => Ok(VariantValue::from(json!(*v as f64))),
+            DataValue::Int128(v) => Ok(VariantValue::from(json!(v.to_string()))),
             DataValue::String(v) => Ok(VariantValue::from(json!(
                 String::from_utf8(v.to_vec()).unwrap()
             ))),
@@ -448,6 +482,7 @@ try_cast_data_value_to_std!(i8, as_i64);
 try_cast_data_value_to_std!(i16, as_i64);
 try_cast_data_value_to_std!(i32, as_i64);
 try_cast_data_value_to_std!(i64, as_i64);
+try_cast_data_value_to_std!(i128, as_i128);
 
 try_cast_data_value_to_std!(f32, as_f64);
 try_cast_data_value_to_std!(f64, as_f64);
@@ -457,6 +492,7 @@ std_to_data_value!(Int64, i8, i64);
 std_to_data_value!(Int64, i16, i64);
 std_to_data_value!(Int64, i32, i64);
 std_to_data_value!(Int64, i64, i64);
+std_to_data_value!(Int128, i128, i128);
 std_to_data_value!(UInt64, u8, u64);
 std_to_data_value!(UInt64, u16, u64);
 std_to_data_value!(UInt64, u32, u64);
@@ -507,6 +543,7 @@ impl fmt::Display for DataValue {
             DataValue::Float64(v) => write!(f, "{}", v),
             DataValue::Int64(v) => write!(f, "{}", v),
             DataValue::UInt64(v) => write!(f, "{}", v),
+            DataValue::Int128(v) => write!(f, "{}", v),
             DataValue::String(v) => match std::str::from_utf8(v) {
                 Ok(v) => write!(f, "{}", v),
                 Err(_e) => {
@@ -549,6 +586,7 @@ impl fmt::Debug for DataValue {
             DataValue::Int64(v) => write!(f, "{}", v),
             DataValue::UInt64(v) => write!(f, "{}", v),
             DataValue::Float64(v) => write!(f, "{}", v),
+            DataValue::Int128(v) => write!(f, "{}", v),
             DataValue::String(_) => write!(f, "{}", self),
             DataValue::Array(_) => write!(f, "{}", self),
             DataValue::Struct(_) => write!(f, "{}", self),
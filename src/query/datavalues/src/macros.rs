@@ -21,6 +21,7 @@ macro_rules! for_all_scalar_types {
             { i16 },
             { i32 },
             { i64 },
+            { i128 },
             { u8 },
             { u16 },
             { u32 },
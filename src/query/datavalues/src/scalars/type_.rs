@@ -111,6 +111,7 @@ impl_primitive_scalar_type!(i8, i8);
 impl_primitive_scalar_type!(i16, i16);
 impl_primitive_scalar_type!(i32, i32);
 impl_primitive_scalar_type!(i64, i64);
+impl_primitive_scalar_type!(i128, i128);
 
 type F32 = OrderedFloat<f32>;
 type F64 = OrderedFloat<f64>;
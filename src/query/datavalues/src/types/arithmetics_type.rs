@@ -13,8 +13,14 @@
 // limitations under the License.
 
 // This code is generated by common/codegen. DO NOT EDIT.
+use common_exception::Result;
+
+use crate::DataValueBinaryOperator;
+use crate::DecimalType;
 use crate::IntegerType;
 use crate::PrimitiveType;
+use crate::TypeID;
+use crate::MAX_DECIMAL_PRECISION;
 
 pub trait ResultTypeOfBinary {
     type AddMul: PrimitiveType;
@@ -867,3 +873,46 @@ impl ResultTypeOfUnary for f32 {
 impl ResultTypeOfUnary for f64 {
     type Negate = f64;
 }
+
+/// The decimal type that can hold every value of an integer type.
+pub fn decimal_type_of_integer(type_id: TypeID) -> Option<DecimalType> {
+    let precision = match type_id {
+        TypeID::UInt8 | TypeID::Int8 => 3,
+        TypeID::UInt16 | TypeID::Int16 => 5,
+        TypeID::UInt32 | TypeID::Int32 => 10,
+        TypeID::Int64 => 19,
+        TypeID::UInt64 => 20,
+        _ => return None,
+    };
+    Some(DecimalType::new(precision, 0))
+}
+
+/// Precision and scale of the result of a decimal arithmetic, the precision is capped at
+/// `MAX_DECIMAL_PRECISION` and overflowing values are reported when evaluating.
+pub fn decimal_arithmetic_result_type(
+    op: &DataValueBinaryOperator,
+    lhs: &DecimalType,
+    rhs: &DecimalType,
+) -> Result<DecimalType> {
+    let (p1, s1) = (lhs.precision() as u64, lhs.scale() as u64);
+    let (p2, s2) = (rhs.precision() as u64, rhs.scale() as u64);
+    let max = MAX_DECIMAL_PRECISION as u64;
+
+    let (precision, scale) = match op {
+        DataValueBinaryOperator::Plus | DataValueBinaryOperator::Minus => {
+            let scale = s1.max(s2);
+            ((p1 - s1).max(p2 - s2) + scale + 1, scale)
+        }
+        DataValueBinaryOperator::Mul => (p1 + p2, (s1 + s2).min(max)),
+        DataValueBinaryOperator::Div => {
+            let scale = s1.max((s1 + 6).min(12));
+            (p1 - s1 + s2 + scale, scale)
+        }
+        DataValueBinaryOperator::IntDiv => (p1 - s1 + s2, 0),
+        DataValueBinaryOperator::Modulo => {
+            let scale = s1.max(s2);
+            ((p1 - s1).min(p2 - s2) + scale, scale)
+        }
+    };
+    DecimalType::try_create(precision.clamp(1, max).max(scale), scale)
+}
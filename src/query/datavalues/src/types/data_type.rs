@@ -19,6 +19,7 @@ use std::fmt::Formatter;
 
 use common_arrow::arrow::datatypes::DataType as ArrowType;
 use common_arrow::arrow::datatypes::Field as ArrowField;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use dyn_clone::DynClone;
 use enum_dispatch::enum_dispatch;
@@ -26,7 +27,9 @@ use enum_dispatch::enum_dispatch;
 use super::type_array::ArrayType;
 use super::type_boolean::BooleanType;
 use super::type_date::DateType;
+use super::type_decimal::DecimalType;
 use super::type_id::TypeID;
+use super::type_map::MapType;
 use super::type_nullable::NullableType;
 use super::type_primitive::Float32Type;
 use super::type_primitive::Float64Type;
@@ -41,6 +44,7 @@ use super::type_primitive::UInt8Type;
 use super::type_string::StringType;
 use super::type_struct::StructType;
 use super::type_timestamp::TimestampType;
+use super::type_timestamp_tz::TimestampTzType;
 use crate::prelude::*;
 use crate::serializations::ConstSerializer;
 
@@ -65,11 +69,14 @@ pub enum DataTypeImpl {
     UInt64(UInt64Type),
     Float32(Float32Type),
     Float64(Float64Type),
+    Decimal(DecimalType),
     Date(DateType),
     Timestamp(TimestampType),
+    TimestampTz(TimestampTzType),
     String(StringType),
     Struct(StructType),
     Array(ArrayType),
+    Map(MapType),
     Variant(VariantType),
     VariantArray(VariantArrayType),
     VariantObject(VariantObjectType),
@@ -163,8 +170,15 @@ where Self: Sized
     fn create_deserializer(&self, capacity: usize) -> TypeDeserializerImpl;
 }
 
+/// Converts an arrow type defined by databend, panics on the arrow types not supported.
+///
+/// Use [`try_from_arrow_type`] for the arrow types from outside, e.g., the schema of a parquet file.
 pub fn from_arrow_type(dt: &ArrowType) -> DataTypeImpl {
-    match dt {
+    try_from_arrow_type(dt).unwrap()
+}
+
+pub fn try_from_arrow_type(dt: &ArrowType) -> Result<DataTypeImpl> {
+    let ty = match dt {
         ArrowType::Null => DataTypeImpl::Null(NullType {}),
         ArrowType::UInt8 => DataTypeImpl::UInt8(UInt8Type::default()),
         ArrowType::UInt16 => DataTypeImpl::UInt16(UInt16Type::default()),
@@ -177,13 +191,30 @@ pub fn from_arrow_type(dt: &ArrowType) -> DataTypeImpl {
         ArrowType::Boolean => DataTypeImpl::Boolean(BooleanType::default()),
         ArrowType::Float32 => DataTypeImpl::Float32(Float32Type::default()),
         ArrowType::Float64 => DataTypeImpl::Float64(Float64Type::default()),
+        ArrowType::Decimal(precision, scale) => {
+            DecimalType::new_impl(*precision as u8, *scale as u8)
+        }
 
         // TODO support other list
         ArrowType::List(f) | ArrowType::LargeList(f) | ArrowType::FixedSizeList(f, _) => {
-            let inner = from_arrow_field(f);
+            let inner = try_from_arrow_field(f)?;
             DataTypeImpl::Array(ArrayType::create(inner))
         }
 
+        ArrowType::Map(f, _) => match f.data_type() {
+            ArrowType::Struct(fields) if fields.len() == 2 => {
+                let key = try_from_arrow_field(&fields[0])?;
+                let value = try_from_arrow_field(&fields[1])?;
+                DataTypeImpl::Map(MapType::create(key, value))
+            }
+            _ => {
+                return Err(ErrorCode::IllegalDataType(format!(
+                    "Map entries must be a struct of key and value, but got: {:?}",
+                    dt
+                )));
+            }
+        },
+
         ArrowType::Binary | ArrowType::LargeBinary | ArrowType::Utf8 | ArrowType::LargeUtf8 => {
             DataTypeImpl::String(StringType::default())
         }
@@ -194,7 +225,10 @@ pub fn from_arrow_type(dt: &ArrowType) -> DataTypeImpl {
 
         ArrowType::Struct(fields) => {
             let names = fields.iter().map(|f| f.name.clone()).collect();
-            let types = fields.iter().map(from_arrow_field).collect();
+            let types = fields
+                .iter()
+                .map(try_from_arrow_field)
+                .collect::<Result<Vec<_>>>()?;
 
             DataTypeImpl::Struct(StructType::create(Some(names), types))
         }
@@ -202,50 +236,74 @@ pub fn from_arrow_type(dt: &ArrowType) -> DataTypeImpl {
             "Variant" => DataTypeImpl::Variant(VariantType::default()),
             "VariantArray" => DataTypeImpl::VariantArray(VariantArrayType::default()),
             "VariantObject" => DataTypeImpl::VariantObject(VariantObjectType::default()),
-            _ => unimplemented!("data_type: {:?}", dt),
+            _ => return Err(unsupported_arrow_type(dt)),
         },
 
-        // this is safe, because we define the datatype firstly
-        _ => {
-            unimplemented!("data_type: {:?}", dt)
-        }
-    }
+        _ => return Err(unsupported_arrow_type(dt)),
+    };
+    Ok(ty)
+}
+
+fn unsupported_arrow_type(dt: &ArrowType) -> ErrorCode {
+    ErrorCode::IllegalDataType(format!("Unsupported arrow data type: {:?}", dt))
 }
 
+/// Converts an arrow field defined by databend, panics on the arrow types not supported.
+///
+/// Use [`try_from_arrow_field`] for the arrow fields from outside, e.g., the schema of a parquet file.
 pub fn from_arrow_field(f: &ArrowField) -> DataTypeImpl {
+    try_from_arrow_field(f).unwrap()
+}
+
+pub fn try_from_arrow_field(f: &ArrowField) -> Result<DataTypeImpl> {
     if let Some(custom_name) = f.metadata.get(ARROW_EXTENSION_NAME) {
         let metadata = f.metadata.get(ARROW_EXTENSION_META).cloned();
         match custom_name.as_str() {
-            "Date" => return DateType::new_impl(),
+            "Date" => return Ok(DateType::new_impl()),
 
             // OLD COMPATIBLE Behavior
-            "Timestamp" => return TimestampType::new_impl(),
-            "Interval" => return IntervalType::new_impl(metadata.unwrap().into()),
-            "Variant" => return VariantType::new_impl(),
-            "VariantArray" => return VariantArrayType::new_impl(),
-            "VariantObject" => return VariantObjectType::new_impl(),
+            "Timestamp" => return Ok(TimestampType::new_impl()),
+            "Interval" => {
+                let kind = metadata.ok_or_else(|| {
+                    ErrorCode::IllegalDataType(format!(
+                        "Interval field {} has no interval kind",
+                        f.name
+                    ))
+                })?;
+                return Ok(IntervalType::new_impl(kind.into()));
+            }
+            "Variant" => return Ok(VariantType::new_impl()),
+            "VariantArray" => return Ok(VariantArrayType::new_impl()),
+            "VariantObject" => return Ok(VariantObjectType::new_impl()),
             "Tuple" => {
                 let dt = f.data_type();
                 match dt {
                     ArrowType::Struct(fields) => {
-                        let types = fields.iter().map(from_arrow_field).collect();
-                        return DataTypeImpl::Struct(StructType::create(None, types));
+                        let types = fields
+                            .iter()
+                            .map(try_from_arrow_field)
+                            .collect::<Result<Vec<_>>>()?;
+                        return Ok(DataTypeImpl::Struct(StructType::create(None, types)));
                     }
-                    _ => unreachable!(),
+                    _ => return Err(unsupported_arrow_type(dt)),
                 }
             }
             _ => {}
         }
     }
 
+    // TIMESTAMP_TZ is stored as an arrow decimal, only the metadata tells them apart
     let dt = f.data_type();
-    let ty = from_arrow_type(dt);
+    let ty = match f.metadata.get(ARROW_EXTENSION_NAME).map(|v| v.as_str()) {
+        Some("TimestampTz") => TimestampTzType::new_impl(),
+        _ => try_from_arrow_type(dt)?,
+    };
 
     let is_nullable = f.is_nullable;
     if is_nullable && ty.can_inside_nullable() {
-        NullableType::new_impl(ty)
+        Ok(NullableType::new_impl(ty))
     } else {
-        ty
+        Ok(ty)
     }
 }
 
@@ -283,11 +341,14 @@ macro_rules! for_all_data_type_impl_enum {
             { UInt64 },
             { Float32 },
             { Float64 },
+            { Decimal },
             { Date },
             { Timestamp },
+            { TimestampTz },
             { String },
             { Struct },
             { Array },
+            { Map },
             { Variant },
             { VariantArray },
             { VariantObject },
@@ -355,11 +416,14 @@ impl Display for DataTypeImpl {
             DataTypeImpl::UInt64(_) => write!(f, "uint64"),
             DataTypeImpl::Float32(_) => write!(f, "float32"),
             DataTypeImpl::Float64(_) => write!(f, "float64"),
+            DataTypeImpl::Decimal(t) => write!(f, "decimal({}, {})", t.precision(), t.scale()),
             DataTypeImpl::Date(_) => write!(f, "date"),
             DataTypeImpl::Timestamp(_) => write!(f, "timestamp"),
+            DataTypeImpl::TimestampTz(_) => write!(f, "timestamp_tz"),
             DataTypeImpl::String(_) => write!(f, "string"),
             DataTypeImpl::Struct(_) => write!(f, "struct"),
             DataTypeImpl::Array(_) => write!(f, "array"),
+            DataTypeImpl::Map(_) => write!(f, "map"),
             DataTypeImpl::Variant(_) => write!(f, "variant"),
             DataTypeImpl::VariantArray(_) => write!(f, "variant_array"),
             DataTypeImpl::VariantObject(_) => write!(f, "variant_object"),
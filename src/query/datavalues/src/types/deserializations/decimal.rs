@@ -0,0 +1,173 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Read;
+
+use common_exception::*;
+use common_io::prelude::*;
+
+use crate::columns::MutableColumn;
+use crate::prelude::*;
+
+pub struct DecimalDeserializer {
+    pub builder: MutablePrimitiveColumn<i128>,
+    pub decimal: DecimalType,
+}
+
+impl DecimalDeserializer {
+    fn parse(&mut self, text: &[u8]) -> Result<()> {
+        let value = parse_decimal(text, self.decimal.precision(), self.decimal.scale())?;
+        self.builder.append_value(value);
+        Ok(())
+    }
+
+    fn read_binary(reader: &mut &[u8]) -> Result<i128> {
+        let mut bytes = [0u8; 16];
+        reader.read_exact(&mut bytes)?;
+        Ok(i128::from_le_bytes(bytes))
+    }
+
+    fn read_text<R: BufferRead>(reader: &mut NestedCheckpointReader<R>) -> Result<Vec<u8>> {
+        let mut buf = Vec::with_capacity(16);
+        reader.keep_read(&mut buf, |c| {
+            c.is_ascii_digit() || matches!(c, b'+' | b'-' | b'.' | b'e' | b'E')
+        })?;
+        Ok(buf)
+    }
+}
+
+impl TypeDeserializer for DecimalDeserializer {
+    fn memory_size(&self) -> usize {
+        self.builder.memory_size()
+    }
+
+    fn de_binary(&mut self, reader: &mut &[u8], _format: &FormatSettings) -> Result<()> {
+        let value = Self::read_binary(reader)?;
+        self.decimal.check_value(value)?;
+        self.builder.append_value(value);
+        Ok(())
+    }
+
+    fn de_default(&mut self, _format: &FormatSettings) {
+        self.builder.append_value(i128::default());
+    }
+
+    fn de_fixed_binary_batch(
+        &mut self,
+        reader: &[u8],
+        step: usize,
+        rows: usize,
+        _format: &FormatSettings,
+    ) -> Result<()> {
+        for row in 0..rows {
+            let mut reader = &reader[step * row..];
+            let value = Self::read_binary(&mut reader)?;
+            self.decimal.check_value(value)?;
+            self.builder.append_value(value);
+        }
+        Ok(())
+    }
+
+    fn de_json(&mut self, value: &serde_json::Value, _format: &FormatSettings) -> Result<()> {
+        match value {
+            serde_json::Value::Number(v) => self.parse(v.to_string().as_bytes()),
+            serde_json::Value::String(v) => self.parse(v.as_bytes()),
+            _ => Err(ErrorCode::BadBytes(
+                "Incorrect json value, must be number or string",
+            )),
+        }
+    }
+
+    fn de_whole_text(&mut self, reader: &[u8], _format: &FormatSettings) -> Result<()> {
+        self.parse(reader)
+    }
+
+    fn de_text<R: BufferRead>(
+        &mut self,
+        reader: &mut NestedCheckpointReader<R>,
+        _format: &FormatSettings,
+    ) -> Result<()> {
+        let buf = Self::read_text(reader)?;
+        self.parse(&buf)
+    }
+
+    fn de_text_csv<R: BufferRead>(
+        &mut self,
+        reader: &mut NestedCheckpointReader<R>,
+        _format: &FormatSettings,
+    ) -> Result<()> {
+        let maybe_quote = reader.ignore(|f| f == b'\'' || f == b'"')?;
+        let buf = Self::read_text(reader)?;
+        if maybe_quote {
+            reader.must_ignore(|f| f == b'\'' || f == b'"')?;
+        }
+        self.parse(&buf)
+    }
+
+    fn de_text_quoted<R: BufferRead>(
+        &mut self,
+        reader: &mut NestedCheckpointReader<R>,
+        _format: &FormatSettings,
+    ) -> Result<()> {
+        let maybe_quote = reader.ignore_byte(b'\'')?;
+        let buf = Self::read_text(reader)?;
+        if maybe_quote {
+            reader.must_ignore_byte(b'\'')?;
+        }
+        self.parse(&buf)
+    }
+
+    // Values come from evaluated expressions, which are not scaled yet.
+    fn append_data_value(&mut self, value: DataValue, _format: &FormatSettings) -> Result<()> {
+        let multiplier = decimal_scale_multiplier(self.decimal.scale());
+        let scaled = match &value {
+            DataValue::Int64(v) => (*v as i128).checked_mul(multiplier),
+            DataValue::UInt64(v) => (*v as i128).checked_mul(multiplier),
+            DataValue::Int128(v) => v.checked_mul(multiplier),
+            DataValue::Float64(v) => {
+                let v = (v * multiplier as f64).round();
+                if v.is_finite() && v.abs() < i128::MAX as f64 {
+                    Some(v as i128)
+                } else {
+                    None
+                }
+            }
+            DataValue::String(v) => return self.parse(v),
+            other => {
+                return Err(ErrorCode::BadDataValueType(format!(
+                    "Unexpected type:{:?} to get decimal number",
+                    other.value_type()
+                )));
+            }
+        };
+        let scaled = scaled.ok_or_else(|| {
+            ErrorCode::Overflow(format!(
+                "Value {} is out of range of {}",
+                value,
+                self.decimal.sql_name()
+            ))
+        })?;
+        self.decimal.check_value(scaled)?;
+        self.builder.append_value(scaled);
+        Ok(())
+    }
+
+    fn pop_data_value(&mut self) -> Result<DataValue> {
+        self.builder.pop_data_value()
+    }
+
+    fn finish_to_column(&mut self) -> ColumnRef {
+        self.builder.to_column()
+    }
+}
@@ -0,0 +1,159 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::*;
+
+use crate::prelude::*;
+
+pub struct MapDeserializer {
+    pub builder: MutableArrayColumn,
+    pub key: Box<TypeDeserializerImpl>,
+    pub value: Box<TypeDeserializerImpl>,
+}
+
+impl MapDeserializer {
+    fn pop_entries(&mut self, size: usize) -> Result<ArrayValue> {
+        let mut entries = Vec::with_capacity(size);
+        for _ in 0..size {
+            let value = self.value.pop_data_value()?;
+            let key = self.key.pop_data_value()?;
+            entries.push(DataValue::Struct(vec![key, value]));
+        }
+        entries.reverse();
+        Ok(ArrayValue::new(entries))
+    }
+
+    fn de_binary_entries(&mut self, reader: &mut &[u8], format: &FormatSettings) -> Result<()> {
+        let size = reader.read_uvarint()? as usize;
+        for _ in 0..size {
+            self.key.de_binary(reader, format)?;
+            self.value.de_binary(reader, format)?;
+        }
+        let entries = self.pop_entries(size)?;
+        self.builder.append_value(entries);
+        Ok(())
+    }
+}
+
+impl TypeDeserializer for MapDeserializer {
+    fn memory_size(&self) -> usize {
+        self.builder.memory_size()
+    }
+
+    fn de_binary(&mut self, reader: &mut &[u8], format: &FormatSettings) -> Result<()> {
+        self.de_binary_entries(reader, format)
+    }
+
+    fn de_default(&mut self, _format: &FormatSettings) {
+        self.builder.append_default();
+    }
+
+    fn de_fixed_binary_batch(
+        &mut self,
+        reader: &[u8],
+        step: usize,
+        rows: usize,
+        format: &FormatSettings,
+    ) -> Result<()> {
+        for row in 0..rows {
+            let mut reader = &reader[step * row..];
+            self.de_binary_entries(&mut reader, format)?;
+        }
+        Ok(())
+    }
+
+    fn de_json(&mut self, value: &serde_json::Value, format: &FormatSettings) -> Result<()> {
+        match value {
+            serde_json::Value::Object(obj) => {
+                for (key, val) in obj.iter() {
+                    self.key
+                        .de_json(&serde_json::Value::String(key.clone()), format)?;
+                    self.value.de_json(val, format)?;
+                }
+                let entries = self.pop_entries(obj.len())?;
+                self.builder.append_value(entries);
+                Ok(())
+            }
+            _ => Err(ErrorCode::BadBytes("Incorrect json value, must be object")),
+        }
+    }
+
+    fn de_text<R: BufferRead>(
+        &mut self,
+        reader: &mut NestedCheckpointReader<R>,
+        format: &FormatSettings,
+    ) -> Result<()> {
+        reader.must_ignore_byte(b'{')?;
+        let mut idx = 0;
+        loop {
+            let _ = reader.ignore_white_spaces()?;
+            if reader.ignore_byte(b'}')? {
+                break;
+            }
+            if idx != 0 {
+                reader.must_ignore_byte(b',')?;
+            }
+            let _ = reader.ignore_white_spaces()?;
+            self.key.de_text_quoted(reader, format)?;
+            let _ = reader.ignore_white_spaces()?;
+            reader.must_ignore_byte(b':')?;
+            let _ = reader.ignore_white_spaces()?;
+            self.value.de_text_quoted(reader, format)?;
+            idx += 1;
+        }
+        let entries = self.pop_entries(idx)?;
+        self.builder.append_value(entries);
+        Ok(())
+    }
+
+    fn de_text_csv<R: BufferRead>(
+        &mut self,
+        reader: &mut NestedCheckpointReader<R>,
+        format: &FormatSettings,
+    ) -> Result<()> {
+        let maybe_single_quote = reader.ignore_byte(b'\'')?;
+        let maybe_double_quote = if !maybe_single_quote {
+            reader.ignore_byte(b'"')?
+        } else {
+            false
+        };
+        self.de_text(reader, format)?;
+        if maybe_single_quote {
+            reader.must_ignore_byte(b'\'')?;
+        } else if maybe_double_quote {
+            reader.must_ignore_byte(b'"')?;
+        }
+        Ok(())
+    }
+
+    fn de_whole_text(&mut self, reader: &[u8], format: &FormatSettings) -> Result<()> {
+        let reader = BufferReader::new(reader);
+        let mut reader = NestedCheckpointReader::new(Box::new(reader));
+        self.de_text(&mut reader, format)
+    }
+
+    fn append_data_value(&mut self, value: DataValue, _format: &FormatSettings) -> Result<()> {
+        self.builder.append_data_value(value)
+    }
+
+    fn pop_data_value(&mut self) -> Result<DataValue> {
+        self.builder.pop_data_value()
+    }
+
+    fn finish_to_column(&mut self) -> ColumnRef {
+        self.builder.to_column()
+    }
+}
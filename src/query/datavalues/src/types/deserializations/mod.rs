@@ -22,23 +22,29 @@ use crate::prelude::*;
 mod array;
 mod boolean;
 mod date;
+mod decimal;
+mod map;
 mod null;
 mod nullable;
 mod number;
 mod string;
 mod struct_;
 mod timestamp;
+mod timestamp_tz;
 mod variant;
 
 pub use array::*;
 pub use boolean::*;
 pub use date::*;
+pub use decimal::*;
+pub use map::*;
 pub use null::*;
 pub use nullable::*;
 pub use number::*;
 pub use string::*;
 pub use struct_::*;
 pub use timestamp::*;
+pub use timestamp_tz::*;
 pub use variant::*;
 
 #[enum_dispatch]
@@ -108,6 +114,7 @@ pub enum TypeDeserializerImpl {
     Null(NullDeserializer),
     Nullable(NullableDeserializer),
     Array(ArrayDeserializer),
+    Map(MapDeserializer),
     Boolean(BooleanDeserializer),
     Int8(NumberDeserializer<i8>),
     Int16(NumberDeserializer<i16>),
@@ -119,10 +126,12 @@ pub enum TypeDeserializerImpl {
     UInt64(NumberDeserializer<u64>),
     Float32(NumberDeserializer<f32>),
     Float64(NumberDeserializer<f64>),
+    Decimal(DecimalDeserializer),
 
     Date(DateDeserializer<i32>),
     Interval(DateDeserializer<i64>),
     Timestamp(TimestampDeserializer),
+    TimestampTz(TimestampTzDeserializer),
     String(StringDeserializer),
     Struct(StructDeserializer),
     Variant(VariantDeserializer),
@@ -0,0 +1,155 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Read;
+
+use common_exception::*;
+use common_io::prelude::*;
+
+use crate::columns::MutableColumn;
+use crate::prelude::*;
+
+pub struct TimestampTzDeserializer {
+    pub builder: MutablePrimitiveColumn<i128>,
+}
+
+impl TimestampTzDeserializer {
+    fn parse(&mut self, text: &[u8], format: &FormatSettings) -> Result<()> {
+        let value = parse_timestamp_tz(text, &format.timezone)?;
+        self.builder.append_value(value);
+        Ok(())
+    }
+
+    fn read_binary(reader: &mut &[u8]) -> Result<i128> {
+        let mut bytes = [0u8; 16];
+        reader.read_exact(&mut bytes)?;
+        let value = i128::from_le_bytes(bytes);
+        check_timestamp_tz(value)?;
+        Ok(value)
+    }
+
+    fn read_text<R: BufferRead>(reader: &mut NestedCheckpointReader<R>) -> Result<Vec<u8>> {
+        let mut buf = Vec::with_capacity(32);
+        reader.keep_read(&mut buf, |c| {
+            c.is_ascii_digit() || matches!(c, b'-' | b'+' | b':' | b'.' | b' ' | b'T' | b'Z' | b'z')
+        })?;
+        Ok(buf)
+    }
+}
+
+impl TypeDeserializer for TimestampTzDeserializer {
+    fn memory_size(&self) -> usize {
+        self.builder.memory_size()
+    }
+
+    fn de_binary(&mut self, reader: &mut &[u8], _format: &FormatSettings) -> Result<()> {
+        let value = Self::read_binary(reader)?;
+        self.builder.append_value(value);
+        Ok(())
+    }
+
+    fn de_default(&mut self, _format: &FormatSettings) {
+        self.builder.append_value(i128::default());
+    }
+
+    fn de_fixed_binary_batch(
+        &mut self,
+        reader: &[u8],
+        step: usize,
+        rows: usize,
+        _format: &FormatSettings,
+    ) -> Result<()> {
+        for row in 0..rows {
+            let mut reader = &reader[step * row..];
+            let value = Self::read_binary(&mut reader)?;
+            self.builder.append_value(value);
+        }
+        Ok(())
+    }
+
+    fn de_json(&mut self, value: &serde_json::Value, format: &FormatSettings) -> Result<()> {
+        match value {
+            serde_json::Value::String(v) => self.parse(v.as_bytes(), format),
+            _ => Err(ErrorCode::BadBytes("Incorrect json value, must be string")),
+        }
+    }
+
+    fn de_whole_text(&mut self, reader: &[u8], format: &FormatSettings) -> Result<()> {
+        self.parse(reader, format)
+    }
+
+    fn de_text<R: BufferRead>(
+        &mut self,
+        reader: &mut NestedCheckpointReader<R>,
+        format: &FormatSettings,
+    ) -> Result<()> {
+        let buf = Self::read_text(reader)?;
+        self.parse(&buf, format)
+    }
+
+    fn de_text_csv<R: BufferRead>(
+        &mut self,
+        reader: &mut NestedCheckpointReader<R>,
+        format: &FormatSettings,
+    ) -> Result<()> {
+        let maybe_quote = reader.ignore(|f| f == b'\'' || f == b'"')?;
+        let buf = Self::read_text(reader)?;
+        if maybe_quote {
+            reader.must_ignore(|f| f == b'\'' || f == b'"')?;
+        }
+        self.parse(&buf, format)
+    }
+
+    fn de_text_json<R: BufferRead>(
+        &mut self,
+        reader: &mut NestedCheckpointReader<R>,
+        format: &FormatSettings,
+    ) -> Result<()> {
+        reader.must_ignore_byte(b'"')?;
+        let buf = Self::read_text(reader)?;
+        reader.must_ignore_byte(b'"')?;
+        self.parse(&buf, format)
+    }
+
+    fn de_text_quoted<R: BufferRead>(
+        &mut self,
+        reader: &mut NestedCheckpointReader<R>,
+        format: &FormatSettings,
+    ) -> Result<()> {
+        reader.must_ignore_byte(b'\'')?;
+        let buf = Self::read_text(reader)?;
+        reader.must_ignore_byte(b'\'')?;
+        self.parse(&buf, format)
+    }
+
+    fn append_data_value(&mut self, value: DataValue, format: &FormatSettings) -> Result<()> {
+        match value {
+            DataValue::String(v) => self.parse(&v, format),
+            other => {
+                let value = other.as_i128()?;
+                check_timestamp_tz(value)?;
+                self.builder.append_value(value);
+                Ok(())
+            }
+        }
+    }
+
+    fn pop_data_value(&mut self) -> Result<DataValue> {
+        self.builder.pop_data_value()
+    }
+
+    fn finish_to_column(&mut self) -> ColumnRef {
+        self.builder.to_column()
+    }
+}
@@ -15,6 +15,8 @@
 use std::sync::Arc;
 
 use super::type_array::ArrayType;
+use super::type_decimal::DecimalType;
+use super::type_map::MapType;
 use super::type_nullable::NullableType;
 use super::type_struct::StructType;
 use super::DataType;
@@ -48,8 +50,15 @@ pub fn equal(lhs: &DataTypeImpl, rhs: &DataTypeImpl) -> bool {
     use crate::prelude::TypeID::*;
     match lhs.data_type_id() {
         Boolean | UInt8 | UInt16 | UInt32 | UInt64 | Int8 | Int16 | Int32 | Int64 | Float32
-        | Float64 | String | Date | Timestamp | Interval | Null | Variant | VariantArray
-        | VariantObject => true,
+        | Float64 | String | Date | Timestamp | TimestampTz | Interval | Null | Variant
+        | VariantArray | VariantObject => true,
+
+        Decimal => {
+            let lhs: DecimalType = lhs.to_owned().try_into().unwrap();
+            let rhs: DecimalType = rhs.to_owned().try_into().unwrap();
+
+            lhs.precision() == rhs.precision() && lhs.scale() == rhs.scale()
+        }
 
         Nullable => {
             let lhs: NullableType = lhs.to_owned().try_into().unwrap();
@@ -65,6 +74,13 @@ pub fn equal(lhs: &DataTypeImpl, rhs: &DataTypeImpl) -> bool {
             lhs.inner_type() == rhs.inner_type()
         }
 
+        Map => {
+            let lhs: MapType = lhs.to_owned().try_into().unwrap();
+            let rhs: MapType = rhs.to_owned().try_into().unwrap();
+
+            lhs.key_type() == rhs.key_type() && lhs.value_type() == rhs.value_type()
+        }
+
         Struct => {
             let lhs: StructType = lhs.to_owned().try_into().unwrap();
             let rhs: StructType = rhs.to_owned().try_into().unwrap();
@@ -18,13 +18,16 @@ pub mod type_array;
 pub mod type_boolean;
 pub mod type_coercion;
 pub mod type_date;
+pub mod type_decimal;
 pub mod type_interval;
+pub mod type_map;
 pub mod type_null;
 pub mod type_nullable;
 pub mod type_primitive;
 pub mod type_string;
 pub mod type_struct;
 pub mod type_timestamp;
+pub mod type_timestamp_tz;
 pub mod type_traits;
 pub mod type_variant;
 pub mod type_variant_array;
@@ -48,15 +51,18 @@ pub use serializations::TypeSerializerImpl;
 pub use type_array::*;
 pub use type_boolean::*;
 pub use type_date::*;
+pub use type_decimal::*;
 pub use type_factory::*;
 pub use type_id::*;
 pub use type_interval::*;
+pub use type_map::*;
 pub use type_null::*;
 pub use type_nullable::*;
 pub use type_primitive::*;
 pub use type_string::*;
 pub use type_struct::*;
 pub use type_timestamp::*;
+pub use type_timestamp_tz::*;
 pub use type_traits::*;
 pub use type_variant::*;
 pub use type_variant_array::*;
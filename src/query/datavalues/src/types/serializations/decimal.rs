@@ -0,0 +1,91 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_exception::Result;
+use common_io::prelude::FormatSettings;
+use serde_json::Value;
+
+use crate::decimal_scale_multiplier;
+use crate::format_decimal;
+use crate::ColumnRef;
+use crate::PrimitiveColumn;
+use crate::Series;
+use crate::TypeSerializer;
+
+#[derive(Debug, Clone)]
+pub struct DecimalSerializer<'a> {
+    pub(crate) values: &'a [i128],
+    pub(crate) scale: u8,
+}
+
+impl<'a> DecimalSerializer<'a> {
+    pub fn try_create(col: &'a ColumnRef, scale: u8) -> Result<Self> {
+        let col: &PrimitiveColumn<i128> = Series::check_get(col)?;
+        Ok(Self {
+            values: col.values(),
+            scale,
+        })
+    }
+
+    fn to_f64(&self, value: i128) -> f64 {
+        value as f64 / decimal_scale_multiplier(self.scale) as f64
+    }
+}
+
+impl<'a> TypeSerializer<'a> for DecimalSerializer<'a> {
+    fn need_quote(&self) -> bool {
+        false
+    }
+
+    fn write_field(&self, row_index: usize, buf: &mut Vec<u8>, _format: &FormatSettings) {
+        let s = format_decimal(self.values[row_index], self.scale);
+        buf.extend_from_slice(s.as_bytes());
+    }
+
+    // Keep the exact digits, a JSON number may be read back as a float by clients.
+    fn serialize_json_values(&self, _format: &FormatSettings) -> Result<Vec<Value>> {
+        let result: Vec<Value> = self
+            .values
+            .iter()
+            .map(|v| Value::String(format_decimal(*v, self.scale)))
+            .collect();
+        Ok(result)
+    }
+
+    fn serialize_json_object(
+        &self,
+        _valids: Option<&Bitmap>,
+        _format: &FormatSettings,
+    ) -> Result<Vec<Value>> {
+        let result: Vec<Value> = self
+            .values
+            .iter()
+            .map(|v| serde_json::to_value(self.to_f64(*v)).unwrap())
+            .collect();
+        Ok(result)
+    }
+
+    fn serialize_json_object_suppress_error(
+        &self,
+        _format: &FormatSettings,
+    ) -> Result<Vec<Option<Value>>> {
+        let result: Vec<Option<Value>> = self
+            .values
+            .iter()
+            .map(|v| serde_json::to_value(self.to_f64(*v)).ok())
+            .collect();
+        Ok(result)
+    }
+}
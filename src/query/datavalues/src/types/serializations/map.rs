@@ -0,0 +1,98 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_io::prelude::FormatSettings;
+use serde_json::Map;
+use serde_json::Value;
+
+use crate::prelude::*;
+
+#[derive(Clone)]
+pub struct MapSerializer<'a> {
+    offsets: &'a [i64],
+    key: Box<TypeSerializerImpl<'a>>,
+    value: Box<TypeSerializerImpl<'a>>,
+}
+
+impl<'a> MapSerializer<'a> {
+    pub fn try_create(
+        column: &'a ColumnRef,
+        key_type: &DataTypeImpl,
+        value_type: &DataTypeImpl,
+    ) -> Result<Self> {
+        let column: &ArrayColumn = Series::check_get(column)?;
+        let entries: &StructColumn = Series::check_get(column.values())?;
+        let key = Box::new(key_type.create_serializer(&entries.values()[0])?);
+        let value = Box::new(value_type.create_serializer(&entries.values()[1])?);
+        Ok(Self {
+            offsets: column.offsets(),
+            key,
+            value,
+        })
+    }
+}
+
+impl<'a> TypeSerializer<'a> for MapSerializer<'a> {
+    fn write_field(&self, row_index: usize, buf: &mut Vec<u8>, format: &FormatSettings) {
+        let start = self.offsets[row_index] as usize;
+        let end = self.offsets[row_index + 1] as usize;
+        buf.push(b'{');
+        for i in start..end {
+            if i != start {
+                buf.extend_from_slice(b", ");
+            }
+            self.key.write_field_quoted(i, buf, format, b'\'');
+            buf.push(b':');
+            self.value.write_field_quoted(i, buf, format, b'\'');
+        }
+        buf.push(b'}');
+    }
+
+    fn write_field_json(&self, row_index: usize, buf: &mut Vec<u8>, format: &FormatSettings) {
+        let start = self.offsets[row_index] as usize;
+        let end = self.offsets[row_index + 1] as usize;
+        buf.push(b'{');
+        for i in start..end {
+            if i != start {
+                buf.push(b',');
+            }
+            self.key.write_field_json(i, buf, format);
+            buf.push(b':');
+            self.value.write_field_json(i, buf, format);
+        }
+        buf.push(b'}');
+    }
+
+    fn serialize_json_values(&self, format: &FormatSettings) -> Result<Vec<Value>> {
+        let size = self.offsets.len() - 1;
+        let mut result = Vec::with_capacity(size);
+        let keys = self.key.serialize_json_values(format)?;
+        let values = self.value.serialize_json_values(format)?;
+        let mut iter = keys.into_iter().zip(values.into_iter());
+        for i in 0..size {
+            let len = (self.offsets[i + 1] - self.offsets[i]) as usize;
+            let mut object = Map::with_capacity(len);
+            for (key, value) in iter.by_ref().take(len) {
+                let key = match key {
+                    Value::String(key) => key,
+                    other => other.to_string(),
+                };
+                object.insert(key, value);
+            }
+            result.push(Value::Object(object))
+        }
+        Ok(result)
+    }
+}
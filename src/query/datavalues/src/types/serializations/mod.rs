@@ -16,13 +16,16 @@ mod array;
 mod boolean;
 mod const_;
 mod date;
+mod decimal;
 pub mod helper;
+mod map;
 mod null;
 mod nullable;
 mod number;
 mod string;
 mod struct_;
 mod timestamp;
+mod timestamp_tz;
 mod variant;
 
 pub use array::ArraySerializer;
@@ -34,9 +37,11 @@ use common_exception::ToErrorCode;
 use common_io::prelude::FormatSettings;
 pub use const_::ConstSerializer;
 pub use date::DateSerializer;
+pub use decimal::DecimalSerializer;
 use enum_dispatch::enum_dispatch;
 pub use helper::escape::write_escaped_string;
 pub use helper::json::write_json_string;
+pub use map::MapSerializer;
 pub use null::NullSerializer;
 pub use nullable::NullableSerializer;
 pub use number::NumberSerializer;
@@ -44,6 +49,7 @@ use serde_json::Value;
 pub use string::StringSerializer;
 pub use struct_::StructSerializer;
 pub use timestamp::TimestampSerializer;
+pub use timestamp_tz::TimestampTzSerializer;
 pub use variant::VariantSerializer;
 
 #[enum_dispatch]
@@ -132,12 +138,15 @@ pub enum TypeSerializerImpl<'a> {
     UInt64(NumberSerializer<'a, u64>),
     Float32(NumberSerializer<'a, f32>),
     Float64(NumberSerializer<'a, f64>),
+    Decimal(DecimalSerializer<'a>),
 
     Date(DateSerializer<'a, i32>),
     Interval(DateSerializer<'a, i64>),
     Timestamp(TimestampSerializer<'a>),
+    TimestampTz(TimestampTzSerializer<'a>),
     String(StringSerializer<'a>),
     Array(ArraySerializer<'a>),
+    Map(MapSerializer<'a>),
     Struct(StructSerializer<'a>),
     Variant(VariantSerializer<'a>),
 }
@@ -0,0 +1,66 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_io::prelude::FormatSettings;
+use serde_json::Value;
+
+use crate::timestamp_tz_to_datetime;
+use crate::ColumnRef;
+use crate::PrimitiveColumn;
+use crate::Series;
+use crate::TypeSerializer;
+
+const TIME_TZ_FMT: &str = "%Y-%m-%d %H:%M:%S%.6f %:z";
+
+/// Values are rendered with their own offset, the session timezone is not used.
+#[derive(Debug, Clone)]
+pub struct TimestampTzSerializer<'a> {
+    pub(crate) values: &'a [i128],
+}
+
+impl<'a> TimestampTzSerializer<'a> {
+    pub fn try_create(col: &'a ColumnRef) -> Result<Self> {
+        let col: &PrimitiveColumn<i128> = Series::check_get(col)?;
+        Ok(Self {
+            values: col.values(),
+        })
+    }
+
+    fn to_string(&self, value: i128) -> String {
+        timestamp_tz_to_datetime(value)
+            .format(TIME_TZ_FMT)
+            .to_string()
+    }
+}
+
+impl<'a> TypeSerializer<'a> for TimestampTzSerializer<'a> {
+    fn need_quote(&self) -> bool {
+        true
+    }
+
+    fn write_field(&self, row_index: usize, buf: &mut Vec<u8>, _format: &FormatSettings) {
+        let s = self.to_string(self.values[row_index]);
+        buf.extend_from_slice(s.as_bytes())
+    }
+
+    fn serialize_json_values(&self, _format: &FormatSettings) -> Result<Vec<Value>> {
+        let result: Vec<Value> = self
+            .values
+            .iter()
+            .map(|v| Value::String(self.to_string(*v)))
+            .collect();
+        Ok(result)
+    }
+}
@@ -63,6 +63,36 @@ pub fn construct_numeric_type(
     }
 }
 
+/// Coercion rule for decimal types: both sides are casted to a decimal that keeps all the
+/// integer and fractional digits of them. If one of them is floating, they are compared as Float64.
+pub fn decimal_coercion(lhs_type: &DataTypeImpl, rhs_type: &DataTypeImpl) -> Result<DataTypeImpl> {
+    let to_decimal = |data_type: &DataTypeImpl| -> Option<DecimalType> {
+        match data_type {
+            DataTypeImpl::Decimal(decimal) => Some(decimal.clone()),
+            other => decimal_type_of_integer(other.data_type_id()),
+        }
+    };
+
+    let lhs_id = lhs_type.data_type_id();
+    let rhs_id = rhs_type.data_type_id();
+    if lhs_id.is_floating() || rhs_id.is_floating() {
+        return Ok(Float64Type::new_impl());
+    }
+
+    match (to_decimal(lhs_type), to_decimal(rhs_type)) {
+        (Some(lhs), Some(rhs)) => {
+            let scale = cmp::max(lhs.scale(), rhs.scale());
+            let integral = cmp::max(lhs.precision() - lhs.scale(), rhs.precision() - rhs.scale());
+            let precision = cmp::min(integral + scale, MAX_DECIMAL_PRECISION);
+            Ok(DecimalType::new_impl(precision, scale))
+        }
+        _ => Result::Err(ErrorCode::BadDataValueType(format!(
+            "Can't coerce {:?} and {:?} to decimal",
+            lhs_type, rhs_type
+        ))),
+    }
+}
+
 /// Coercion rule for numerical types: The type that both lhs and rhs
 /// can be casted to for numerical calculation, while maintaining
 /// maximum precision
@@ -238,6 +268,15 @@ pub fn compare_coercion(lhs_type: &DataTypeImpl, rhs_type: &DataTypeImpl) -> Res
     let lhs_id = lhs_type.data_type_id();
     let rhs_id = rhs_type.data_type_id();
 
+    // TIMESTAMP_TZ values are compared by their instants, the offsets are ignored
+    if lhs_id.is_timestamp_tz() || rhs_id.is_timestamp_tz() {
+        let comparable =
+            |id: TypeID| id.is_timestamp_tz() || id.is_date_or_date_time() || id.is_string();
+        if comparable(lhs_id) && comparable(rhs_id) {
+            return Ok(TimestampType::new_impl());
+        }
+    }
+
     if lhs_type.eq(rhs_type) {
         // same type => equality is possible
         return Ok(lhs_type.clone());
@@ -247,6 +286,12 @@ pub fn compare_coercion(lhs_type: &DataTypeImpl, rhs_type: &DataTypeImpl) -> Res
         return numerical_coercion(lhs_type, rhs_type, true);
     }
 
+    if (lhs_id.is_decimal() && (rhs_id.is_numeric() || rhs_id.is_decimal()))
+        || (rhs_id.is_decimal() && lhs_id.is_numeric())
+    {
+        return decimal_coercion(lhs_type, rhs_type);
+    }
+
     //  one of is nothing
     {
         if lhs_id == TypeID::Null {
@@ -345,6 +390,14 @@ pub fn merge_types(lhs_type: &DataTypeImpl, rhs_type: &DataTypeImpl) -> Result<D
             let typ = merge_types(a.inner_type(), b.inner_type())?;
             Ok(DataTypeImpl::Array(ArrayType::create(typ)))
         }
+        (Map, Map) => {
+            let a: MapType = lhs_type.to_owned().try_into()?;
+            let b: MapType = rhs_type.to_owned().try_into()?;
+
+            let key = merge_types(a.key_type(), b.key_type())?;
+            let value = merge_types(a.value_type(), b.value_type())?;
+            Ok(DataTypeImpl::Map(MapType::create(key, value)))
+        }
         (Struct, Struct) => {
             let a: StructType = lhs_type.to_owned().try_into()?;
             let b: StructType = rhs_type.to_owned().try_into()?;
@@ -367,11 +420,15 @@ pub fn merge_types(lhs_type: &DataTypeImpl, rhs_type: &DataTypeImpl) -> Result<D
             )))
         }
         _ => {
-            if lhs_id == rhs_id {
+            if lhs_id == rhs_id && !lhs_id.is_decimal() {
                 return Ok(lhs_type.clone());
             }
             if lhs_id.is_numeric() && rhs_id.is_numeric() {
                 numerical_coercion(lhs_type, rhs_type, false)
+            } else if (lhs_id.is_decimal() || lhs_id.is_numeric())
+                && (rhs_id.is_decimal() || rhs_id.is_numeric())
+            {
+                decimal_coercion(lhs_type, rhs_type)
             } else {
                 Result::Err(ErrorCode::BadDataValueType(format!(
                     "Can't merge types from {:?} and {:?}",
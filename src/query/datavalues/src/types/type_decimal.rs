@@ -0,0 +1,291 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_arrow::arrow::datatypes::DataType as ArrowType;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use rand::prelude::*;
+
+use super::data_type::DataType;
+use super::type_id::TypeID;
+use crate::prelude::*;
+use crate::serializations::DecimalSerializer;
+use crate::serializations::TypeSerializerImpl;
+
+/// The max number of digits a decimal stored in 128 bits can hold.
+///
+/// This is also the max precision of `DECIMAL` in SQL: a wider decimal is only supported by
+/// `common_expression`, thus it is rejected when the type is resolved.
+pub const MAX_DECIMAL_PRECISION: u8 = 38;
+
+/// `DECIMAL` without arguments means `DECIMAL(10, 0)`, the same as MySQL.
+pub const DEFAULT_DECIMAL_PRECISION: u8 = 10;
+pub const DEFAULT_DECIMAL_SCALE: u8 = 0;
+
+/// Fixed-point number with `precision` digits in total and `scale` digits after the point.
+/// The value is stored as an i128 scaled by `10^scale`, e.g. `123.45` in `DECIMAL(5, 2)` is `12345`.
+#[derive(Clone, Hash, serde::Deserialize, serde::Serialize)]
+pub struct DecimalType {
+    precision: u8,
+    scale: u8,
+}
+
+impl DecimalType {
+    pub fn new(precision: u8, scale: u8) -> Self {
+        debug_assert!(precision > 0 && precision <= MAX_DECIMAL_PRECISION);
+        debug_assert!(scale <= precision);
+        Self { precision, scale }
+    }
+
+    pub fn new_impl(precision: u8, scale: u8) -> DataTypeImpl {
+        DataTypeImpl::Decimal(Self::new(precision, scale))
+    }
+
+    pub fn try_create(precision: u64, scale: u64) -> Result<Self> {
+        if precision == 0 || precision > MAX_DECIMAL_PRECISION as u64 {
+            return Err(ErrorCode::BadArguments(format!(
+                "Decimal precision must be between 1 and {}, but got {}, a precision over {} is not supported yet",
+                MAX_DECIMAL_PRECISION, precision, MAX_DECIMAL_PRECISION
+            )));
+        }
+        if scale > precision {
+            return Err(ErrorCode::BadArguments(format!(
+                "Decimal scale must be between 0 and the precision {}, but got {}",
+                precision, scale
+            )));
+        }
+        Ok(Self::new(precision as u8, scale as u8))
+    }
+
+    pub fn precision(&self) -> u8 {
+        self.precision
+    }
+
+    pub fn scale(&self) -> u8 {
+        self.scale
+    }
+
+    /// The largest absolute value this type can hold, i.e. `10^precision - 1`.
+    pub fn max_value(&self) -> i128 {
+        decimal_scale_multiplier(self.precision) - 1
+    }
+
+    pub fn check_value(&self, value: i128) -> Result<()> {
+        if value.unsigned_abs() > self.max_value() as u128 {
+            return Err(ErrorCode::Overflow(format!(
+                "Decimal value {} is out of range of {}",
+                format_decimal(value, self.scale),
+                self.sql_name()
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl DataType for DecimalType {
+    fn data_type_id(&self) -> TypeID {
+        TypeID::Decimal
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> String {
+        format!("Decimal({}, {})", self.precision, self.scale)
+    }
+
+    fn aliases(&self) -> &[&str] {
+        &["Numeric"]
+    }
+
+    fn default_value(&self) -> DataValue {
+        DataValue::Int128(0)
+    }
+
+    fn random_value(&self) -> DataValue {
+        let mut rng = rand::rngs::SmallRng::from_entropy();
+        let max = self.max_value();
+        DataValue::Int128(rng.gen_range(-max..=max))
+    }
+
+    fn create_constant_column(&self, data: &DataValue, size: usize) -> Result<ColumnRef> {
+        let value = data.as_i128()?;
+        let column = Series::from_data(&[value]);
+        Ok(Arc::new(ConstColumn::new(column, size)))
+    }
+
+    fn create_column(&self, data: &[DataValue]) -> Result<ColumnRef> {
+        let value = data
+            .iter()
+            .map(|v| v.as_i128())
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Series::from_data(&value))
+    }
+
+    fn arrow_type(&self) -> ArrowType {
+        ArrowType::Decimal(self.precision as usize, self.scale as usize)
+    }
+
+    fn create_serializer_inner<'a>(&self, col: &'a ColumnRef) -> Result<TypeSerializerImpl<'a>> {
+        Ok(DecimalSerializer::<'a>::try_create(col, self.scale)?.into())
+    }
+
+    fn create_deserializer(&self, capacity: usize) -> TypeDeserializerImpl {
+        DecimalDeserializer {
+            builder: MutablePrimitiveColumn::<i128>::with_capacity(capacity),
+            decimal: self.clone(),
+        }
+        .into()
+    }
+
+    fn create_mutable(&self, capacity: usize) -> Box<dyn MutableColumn> {
+        Box::new(MutablePrimitiveColumn::<i128>::with_capacity(capacity))
+    }
+}
+
+impl std::fmt::Debug for DecimalType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Returns `10^scale`, the factor between a decimal value and its stored integer.
+#[inline]
+pub fn decimal_scale_multiplier(scale: u8) -> i128 {
+    10_i128.pow(scale as u32)
+}
+
+/// Format the stored integer of a decimal with `scale` digits after the point.
+pub fn format_decimal(value: i128, scale: u8) -> String {
+    if scale == 0 {
+        return value.to_string();
+    }
+    let multiplier = decimal_scale_multiplier(scale) as u128;
+    let abs = value.unsigned_abs();
+    let sign = if value < 0 { "-" } else { "" };
+    format!(
+        "{}{}.{:0width$}",
+        sign,
+        abs / multiplier,
+        abs % multiplier,
+        width = scale as usize
+    )
+}
+
+/// Change the scale of a stored decimal integer, rounding half away from zero when
+/// digits are dropped. Returns `None` on overflow.
+pub fn rescale_decimal(value: i128, from_scale: u8, to_scale: u8) -> Option<i128> {
+    if to_scale >= from_scale {
+        value.checked_mul(decimal_scale_multiplier(to_scale - from_scale))
+    } else {
+        let divisor = decimal_scale_multiplier(from_scale - to_scale);
+        let (quotient, remainder) = (value / divisor, value % divisor);
+        if remainder.unsigned_abs() * 2 >= divisor as u128 {
+            Some(quotient + value.signum())
+        } else {
+            Some(quotient)
+        }
+    }
+}
+
+/// Parse decimal text such as `-12.345`, `.5` or `1.2e3` into the stored integer of
+/// `DECIMAL(precision, scale)`. Extra fractional digits are rounded half away from zero.
+pub fn parse_decimal(text: &[u8], precision: u8, scale: u8) -> Result<i128> {
+    let bad_bytes = || {
+        ErrorCode::BadBytes(format!(
+            "Cannot parse value '{}' to Decimal({}, {})",
+            String::from_utf8_lossy(text),
+            precision,
+            scale
+        ))
+    };
+
+    let (negative, rest) = match text.first() {
+        Some(b'-') => (true, &text[1..]),
+        Some(b'+') => (false, &text[1..]),
+        _ => (false, text),
+    };
+
+    let (mantissa, exponent) = match rest.iter().position(|c| *c == b'e' || *c == b'E') {
+        Some(pos) => {
+            let exp = std::str::from_utf8(&rest[pos + 1..])
+                .ok()
+                .and_then(|s| s.parse::<i32>().ok())
+                .ok_or_else(bad_bytes)?;
+            (&rest[..pos], exp)
+        }
+        None => (rest, 0),
+    };
+
+    let (int_part, frac_part) = match mantissa.iter().position(|c| *c == b'.') {
+        Some(pos) => (&mantissa[..pos], &mantissa[pos + 1..]),
+        None => (mantissa, &mantissa[mantissa.len()..]),
+    };
+    if int_part.is_empty() && frac_part.is_empty() {
+        return Err(bad_bytes());
+    }
+
+    // All digits of the value, with the point moved `digits_scale` places from the right.
+    let mut digits = Vec::with_capacity(int_part.len() + frac_part.len());
+    for c in int_part.iter().chain(frac_part.iter()) {
+        if !c.is_ascii_digit() {
+            return Err(bad_bytes());
+        }
+        digits.push(c - b'0');
+    }
+    let digits_scale = frac_part.len() as i32 - exponent;
+
+    let max_value = decimal_scale_multiplier(precision) - 1;
+    let overflow = || {
+        ErrorCode::Overflow(format!(
+            "Decimal value '{}' is out of range of Decimal({}, {})",
+            String::from_utf8_lossy(text),
+            precision,
+            scale
+        ))
+    };
+
+    // Number of digits to keep so that the value is scaled by exactly 10^scale.
+    let keep = digits.len() as i32 - digits_scale + scale as i32;
+    let mut value: i128 = 0;
+    for (i, digit) in digits.iter().enumerate() {
+        if i as i32 >= keep {
+            if *digit >= 5 && i as i32 == keep {
+                value = value.checked_add(1).ok_or_else(overflow)?;
+            }
+            break;
+        }
+        value = value
+            .checked_mul(10)
+            .and_then(|v| v.checked_add(*digit as i128))
+            .filter(|v| *v <= max_value)
+            .ok_or_else(overflow)?;
+    }
+    for _ in digits.len() as i32..keep {
+        value = value
+            .checked_mul(10)
+            .filter(|v| *v <= max_value)
+            .ok_or_else(overflow)?;
+    }
+    if value > max_value {
+        return Err(overflow());
+    }
+
+    Ok(if negative { -value } else { value })
+}
@@ -55,6 +55,7 @@ static TYPE_FACTORY: Lazy<Arc<TypeFactory>> = Lazy::new(|| {
 
     // Timestamp is a special case
     type_factory.register(TimestampType::new_impl());
+    type_factory.register(TimestampTzType::new_impl());
 
     Arc::new(type_factory)
 });
@@ -93,6 +94,17 @@ impl TypeFactory {
             return self.get(name).map(ArrayType::new_impl);
         }
 
+        if let Some(decimal) = Self::parse_decimal(&lowercase_name)? {
+            return Ok(decimal);
+        }
+
+        if lowercase_name.starts_with("map(") && lowercase_name.ends_with(')') {
+            let args = &origin_name[4..origin_name.len() - 1];
+            let (key, value) = Self::split_map_args(args)?;
+            let map = MapType::try_create(self.get(key.trim())?, self.get(value.trim())?)?;
+            return Ok(DataTypeImpl::Map(map));
+        }
+
         if lowercase_name.starts_with("tuple(") {
             let names = origin_name[6..origin_name.len() - 1].to_string();
             let bytes = names.as_bytes();
@@ -135,6 +147,19 @@ impl TypeFactory {
                             inner_names.push(names[start1..start2 - 1].to_string());
                         }
                         inner_data_types.push(self.get(&names[index - 5..sub_index + 1])?);
+                    } else if index >= 3 && names[index - 3..index].to_lowercase() == "map" {
+                        if start1 != start2 {
+                            inner_names.push(names[start1..start2 - 1].to_string());
+                        }
+                        inner_data_types.push(self.get(&names[index - 3..sub_index + 1])?);
+                    } else if index >= 7
+                        && (names[index - 7..index].to_lowercase() == "decimal"
+                            || names[index - 7..index].to_lowercase() == "numeric")
+                    {
+                        if start1 != start2 {
+                            inner_names.push(names[start1..start2 - 1].to_string());
+                        }
+                        inner_data_types.push(self.get(&names[index - 7..sub_index + 1])?);
                     } else {
                         return Result::Err(ErrorCode::IllegalDataType(
                             "The names of tuple elements format error",
@@ -188,6 +213,61 @@ impl TypeFactory {
             })
     }
 
+    // Split the arguments of `map(k, v)` at the top level comma.
+    fn split_map_args(args: &str) -> Result<(&str, &str)> {
+        let mut depth = 0;
+        for (index, byte) in args.bytes().enumerate() {
+            match byte {
+                b'(' => depth += 1,
+                b')' => depth -= 1,
+                b',' if depth == 0 => return Ok((&args[..index], &args[index + 1..])),
+                _ => {}
+            }
+        }
+        Err(ErrorCode::IllegalDataType(format!(
+            "Map type must have key and value types, but got: map({})",
+            args
+        )))
+    }
+
+    // Parse `decimal`, `decimal(p)` and `decimal(p, s)`, `numeric` is an alias.
+    fn parse_decimal(lowercase_name: &str) -> Result<Option<DataTypeImpl>> {
+        let args = match lowercase_name
+            .strip_prefix("decimal")
+            .or_else(|| lowercase_name.strip_prefix("numeric"))
+        {
+            Some(rest) => rest.trim(),
+            None => return Ok(None),
+        };
+
+        if args.is_empty() {
+            return Ok(Some(DecimalType::new_impl(
+                DEFAULT_DECIMAL_PRECISION,
+                DEFAULT_DECIMAL_SCALE,
+            )));
+        }
+
+        let args = match args.strip_prefix('(').and_then(|v| v.strip_suffix(')')) {
+            Some(args) => args,
+            None => return Ok(None),
+        };
+        let parse_arg = |arg: &str| {
+            arg.trim().parse::<u64>().map_err(|_| {
+                ErrorCode::IllegalDataType(format!(
+                    "Decimal arguments must be integers, but got: {}",
+                    lowercase_name
+                ))
+            })
+        };
+        let (precision, scale) = match args.split_once(',') {
+            Some((precision, scale)) => (parse_arg(precision)?, parse_arg(scale)?),
+            None => (parse_arg(args)?, DEFAULT_DECIMAL_SCALE as u64),
+        };
+        let decimal = DecimalType::try_create(precision, scale)
+            .map_err(|e| ErrorCode::IllegalDataType(e.message()))?;
+        Ok(Some(DataTypeImpl::Decimal(decimal)))
+    }
+
     pub fn register_names(&self) -> Vec<&str> {
         self.case_insensitive_types
             .keys()
@@ -33,6 +33,9 @@ pub enum TypeID {
     Float32,
     Float64,
 
+    /// A fixed-point number with precision and scale, it's physical type is Int128
+    Decimal,
+
     String,
 
     /// A 32-bit date representing the elapsed time since UNIX epoch (1970-01-01)
@@ -44,6 +47,11 @@ pub enum TypeID {
     /// store UTC timestamp
     Timestamp,
 
+    /// A timestamp with the UTC offset it was written with. The elapsed microseconds
+    /// since UNIX epoch (in UTC) are stored in the high 64 bits and the offset in seconds
+    /// in the low 32 bits, it's physical type is Int128
+    TimestampTz,
+
     /// Interval represents the time interval, e.g. the elapsed time between two date or timestamp.
     /// Underneath Interval is stored as int64, so it supports negative values.
     Interval,
@@ -51,6 +59,9 @@ pub enum TypeID {
     Array,
     Struct,
 
+    /// A map with String keys, it's physical type is Array of `(key, value)` structs
+    Map,
+
     /// Variant is a tagged universal type, which can store values of any other type,
     /// including Object and Array, up to a maximum size of 16 MB.
     Variant,
@@ -110,6 +121,11 @@ impl TypeID {
         matches!(self, TypeID::Float32 | TypeID::Float64)
     }
 
+    #[inline]
+    pub fn is_decimal(&self) -> bool {
+        matches!(self, TypeID::Decimal)
+    }
+
     #[inline]
     pub fn is_date_or_date_time(&self) -> bool {
         matches!(self, TypeID::Date | TypeID::Timestamp)
@@ -125,6 +141,11 @@ impl TypeID {
         matches!(self, TypeID::Timestamp)
     }
 
+    #[inline]
+    pub fn is_timestamp_tz(&self) -> bool {
+        matches!(self, TypeID::TimestampTz)
+    }
+
     /// Determine if a TypeID is signed numeric or not
     #[inline]
     pub fn is_signed_numeric(&self) -> bool {
@@ -160,9 +181,17 @@ impl TypeID {
         matches!(self, TypeID::Struct)
     }
 
+    #[inline]
+    pub fn is_map(&self) -> bool {
+        matches!(self, TypeID::Map)
+    }
+
     #[inline]
     pub fn is_quoted(&self) -> bool {
-        matches!(self, TypeID::String | TypeID::Date | TypeID::Timestamp)
+        matches!(
+            self,
+            TypeID::String | TypeID::Date | TypeID::Timestamp | TypeID::TimestampTz
+        )
     }
 
     #[inline]
@@ -190,6 +219,7 @@ impl TypeID {
             TypeID::Int16 | TypeID::UInt16 => Ok(2),
             TypeID::Int32 | TypeID::UInt32 | TypeID::Float32 | TypeID::Date => Ok(4),
             TypeID::Int64 | TypeID::UInt64 | TypeID::Float64 | TypeID::Timestamp => Ok(8),
+            TypeID::Decimal | TypeID::TimestampTz => Ok(16),
             _ => Result::Err(ErrorCode::BadArguments(format!(
                 "Function number_byte_size argument must be numeric types, but got {:?}",
                 self
@@ -215,9 +245,10 @@ impl TypeID {
             UInt64 => PhysicalTypeID::UInt64,
             Float32 => PhysicalTypeID::Float32,
             Float64 => PhysicalTypeID::Float64,
+            Decimal | TimestampTz => PhysicalTypeID::Int128,
 
             String => PhysicalTypeID::String,
-            Array => PhysicalTypeID::Array,
+            Array | Map => PhysicalTypeID::Array,
             Struct => PhysicalTypeID::Struct,
             Variant | VariantArray | VariantObject => PhysicalTypeID::Variant,
         }
@@ -253,6 +284,8 @@ pub enum PhysicalTypeID {
     Int32,
     /// A signed 64-bit integer.
     Int64,
+    /// A signed 128-bit integer.
+    Int128,
     /// An unsigned 8-bit integer.
     UInt8,
     /// An unsigned 16-bit integer.
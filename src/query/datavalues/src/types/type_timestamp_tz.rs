@@ -0,0 +1,251 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use chrono::DateTime;
+use chrono::FixedOffset;
+use chrono::Offset;
+use chrono::TimeZone;
+use chrono_tz::Tz;
+use common_arrow::arrow::datatypes::DataType as ArrowType;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::*;
+use rand::prelude::*;
+
+use super::data_type::DataType;
+use super::data_type::ARROW_EXTENSION_NAME;
+use super::type_id::TypeID;
+use crate::prelude::*;
+use crate::serializations::TimestampTzSerializer;
+use crate::serializations::TypeSerializerImpl;
+
+/// The largest UTC offset allowed by ISO 8601, in seconds.
+pub const TIMESTAMP_TZ_MAX_OFFSET: i32 = 14 * 3600;
+
+/// Timestamp with the UTC offset it was written with, e.g. `2022-03-27 01:30:00 +01:00`.
+///
+/// The value is stored as an i128, the UTC microseconds are kept in the high 64 bits
+/// and the offset in seconds in the low 32 bits. Comparing two values compares their
+/// instants, the offset only affects rendering and calendar arithmetic.
+#[derive(Default, Clone, Hash, serde::Deserialize, serde::Serialize)]
+pub struct TimestampTzType {}
+
+impl TimestampTzType {
+    pub fn new_impl() -> DataTypeImpl {
+        DataTypeImpl::TimestampTz(TimestampTzType {})
+    }
+
+    pub fn format_string(&self) -> &str {
+        "%Y-%m-%d %H:%M:%S%.6f %:z"
+    }
+}
+
+/// Pack the UTC microseconds and the offset in seconds into a `TIMESTAMP_TZ` value.
+#[inline]
+pub fn make_timestamp_tz(micros: i64, offset: i32) -> i128 {
+    ((micros as i128) << 64) | (offset as u32 as i128)
+}
+
+/// The UTC microseconds of a `TIMESTAMP_TZ` value.
+#[inline]
+pub fn timestamp_tz_micros(value: i128) -> i64 {
+    (value >> 64) as i64
+}
+
+/// The offset in seconds east of UTC of a `TIMESTAMP_TZ` value.
+#[inline]
+pub fn timestamp_tz_offset(value: i128) -> i32 {
+    value as u32 as i32
+}
+
+#[inline]
+pub fn timestamp_tz_to_datetime(value: i128) -> DateTime<FixedOffset> {
+    let micros = timestamp_tz_micros(value);
+    let offset = FixedOffset::east(timestamp_tz_offset(value));
+    let (secs, nanos) = (
+        micros.div_euclid(MICROSECONDS),
+        micros.rem_euclid(MICROSECONDS) * 1_000,
+    );
+    offset.timestamp(secs, nanos as u32)
+}
+
+/// Keeps the offset the time zone has at that instant, so a value written in
+/// `Europe/London` gets `+00:00` in winter and `+01:00` in summer.
+#[inline]
+pub fn timestamp_tz_from_datetime<T: TimeZone>(dt: &DateTime<T>) -> i128 {
+    let offset = dt.offset().fix().local_minus_utc();
+    make_timestamp_tz(dt.timestamp_micros(), offset)
+}
+
+pub fn check_timestamp_tz(value: i128) -> Result<()> {
+    check_timestamp(timestamp_tz_micros(value))?;
+    if timestamp_tz_offset(value).abs() > TIMESTAMP_TZ_MAX_OFFSET {
+        return Err(ErrorCode::InvalidTimestamp(
+            "Timestamp offset only ranges from -14:00 to +14:00",
+        ));
+    }
+    Ok(())
+}
+
+/// Parse `2022-03-27 01:30:00[.ffffff][ ][Z|+hh:mm|+hhmm|+hh]`, a value without an
+/// offset gets the offset of `tz` at that local time.
+pub fn parse_timestamp_tz(text: &[u8], tz: &Tz) -> Result<i128> {
+    let text = std::str::from_utf8(text)
+        .map_err(|_| ErrorCode::BadBytes(format!("Cannot convert value:{:?} to utf8", text)))?
+        .trim();
+    let (datetime, offset) = split_timestamp_offset(text)?;
+
+    let mut reader = BufferReader::new(datetime.as_bytes());
+    let value = match offset {
+        Some(offset) => {
+            let local = reader.read_timestamp_text(&Tz::UTC)?;
+            let micros = local.timestamp_micros() - offset as i64 * MICROSECONDS;
+            make_timestamp_tz(micros, offset)
+        }
+        None => timestamp_tz_from_datetime(&reader.read_timestamp_text(tz)?),
+    };
+    reader.must_eof()?;
+    check_timestamp_tz(value)?;
+    Ok(value)
+}
+
+// Split the trailing offset from the date and time, the date part `YYYY-MM-DD`
+// itself contains `-` so the offset is only searched after it.
+fn split_timestamp_offset(text: &str) -> Result<(&str, Option<i32>)> {
+    const DATE_LEN: usize = 10;
+    if text.len() <= DATE_LEN {
+        return Ok((text, None));
+    }
+    if let Some(datetime) = text.strip_suffix(|c| c == 'Z' || c == 'z') {
+        return Ok((datetime.trim_end(), Some(0)));
+    }
+    let pos = match text[DATE_LEN..].rfind(|c| c == '+' || c == '-') {
+        Some(pos) => pos + DATE_LEN,
+        None => return Ok((text, None)),
+    };
+
+    let digits: String = text[pos + 1..].chars().filter(|c| *c != ':').collect();
+    let parse = |s: &str| {
+        s.parse::<i32>().map_err(|_| {
+            ErrorCode::BadBytes(format!(
+                "err with parse timezone offset of {}, format like this:[+08:00]",
+                text
+            ))
+        })
+    };
+    let (hours, minutes) = match digits.len() {
+        2 => (parse(&digits)?, 0),
+        4 => (parse(&digits[..2])?, parse(&digits[2..])?),
+        _ => {
+            return Err(ErrorCode::BadBytes(format!(
+                "err with parse timezone offset of {}, format like this:[+08:00]",
+                text
+            )));
+        }
+    };
+    let mut offset = hours * 3600 + minutes * 60;
+    if minutes >= 60 || offset > TIMESTAMP_TZ_MAX_OFFSET {
+        return Err(ErrorCode::BadBytes(format!(
+            "err with parse timezone offset of {}, timezone gap: [-14:00,+14:00]",
+            text
+        )));
+    }
+    if text.as_bytes()[pos] == b'-' {
+        offset = -offset;
+    }
+    Ok((text[..pos].trim_end(), Some(offset)))
+}
+
+impl DataType for TimestampTzType {
+    fn data_type_id(&self) -> TypeID {
+        TypeID::TimestampTz
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> String {
+        "TimestampTz".to_string()
+    }
+
+    fn sql_name(&self) -> String {
+        "TIMESTAMP_TZ".to_string()
+    }
+
+    fn aliases(&self) -> &[&str] {
+        &["Timestamp_Tz"]
+    }
+
+    fn default_value(&self) -> DataValue {
+        DataValue::Int128(0)
+    }
+
+    fn random_value(&self) -> DataValue {
+        let mut rng = rand::rngs::SmallRng::from_entropy();
+        let micros = rng.gen_range(TIMESTAMP_MIN..=TIMESTAMP_MAX);
+        let offset = rng.gen_range(-TIMESTAMP_TZ_MAX_OFFSET / 900..=TIMESTAMP_TZ_MAX_OFFSET / 900);
+        DataValue::Int128(make_timestamp_tz(micros, offset * 900))
+    }
+
+    fn create_constant_column(&self, data: &DataValue, size: usize) -> Result<ColumnRef> {
+        let value = data.as_i128()?;
+        let column = Series::from_data(&[value]);
+        Ok(Arc::new(ConstColumn::new(column, size)))
+    }
+
+    fn create_column(&self, data: &[DataValue]) -> Result<ColumnRef> {
+        let value = data
+            .iter()
+            .map(|v| v.as_i128())
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Series::from_data(&value))
+    }
+
+    fn arrow_type(&self) -> ArrowType {
+        ArrowType::Decimal(38, 0)
+    }
+
+    fn custom_arrow_meta(&self) -> Option<BTreeMap<String, String>> {
+        let mut mp = BTreeMap::new();
+        mp.insert(ARROW_EXTENSION_NAME.to_string(), "TimestampTz".to_string());
+        Some(mp)
+    }
+
+    fn create_serializer_inner<'a>(&self, col: &'a ColumnRef) -> Result<TypeSerializerImpl<'a>> {
+        Ok(TimestampTzSerializer::<'a>::try_create(col)?.into())
+    }
+
+    fn create_deserializer(&self, capacity: usize) -> TypeDeserializerImpl {
+        TimestampTzDeserializer {
+            builder: MutablePrimitiveColumn::<i128>::with_capacity(capacity),
+        }
+        .into()
+    }
+
+    fn create_mutable(&self, capacity: usize) -> Box<dyn MutableColumn> {
+        Box::new(MutablePrimitiveColumn::<i128>::with_capacity(capacity))
+    }
+}
+
+impl std::fmt::Debug for TimestampTzType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "TimestampTz")
+    }
+}
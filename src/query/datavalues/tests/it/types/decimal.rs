@@ -0,0 +1,106 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_io::prelude::FormatSettings;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn test_parse_decimal() -> Result<()> {
+    let cases: Vec<(&str, u8, u8, i128)> = vec![
+        ("0", 10, 0, 0),
+        ("123", 5, 0, 123),
+        ("-123.45", 5, 2, -12345),
+        ("+1.5", 5, 2, 150),
+        (".5", 3, 1, 5),
+        ("1.005", 5, 2, 101),
+        ("-1.005", 5, 2, -101),
+        ("1.004", 5, 2, 100),
+        ("1.2e3", 6, 2, 120000),
+        ("125e-2", 5, 1, 13),
+        (
+            "99999999999999999999999999999999999999",
+            38,
+            0,
+            i128::pow(10, 38) - 1,
+        ),
+    ];
+    for (text, precision, scale, expect) in cases {
+        let value = parse_decimal(text.as_bytes(), precision, scale)?;
+        assert_eq!(
+            value, expect,
+            "parse {} as Decimal({}, {})",
+            text, precision, scale
+        );
+    }
+
+    assert!(parse_decimal(b"1000", 3, 0).is_err());
+    assert!(parse_decimal(b"9.995", 3, 2).is_err());
+    assert!(parse_decimal(b"1.2.3", 5, 2).is_err());
+    assert!(parse_decimal(b"abc", 5, 2).is_err());
+    assert!(parse_decimal(b"", 5, 2).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_format_decimal() {
+    assert_eq!(format_decimal(12345, 2), "123.45");
+    assert_eq!(format_decimal(-12345, 2), "-123.45");
+    assert_eq!(format_decimal(5, 3), "0.005");
+    assert_eq!(format_decimal(-5, 3), "-0.005");
+    assert_eq!(format_decimal(42, 0), "42");
+}
+
+#[test]
+fn test_rescale_decimal() {
+    assert_eq!(rescale_decimal(12345, 2, 4), Some(1234500));
+    assert_eq!(rescale_decimal(12345, 2, 1), Some(1235));
+    assert_eq!(rescale_decimal(-12345, 2, 1), Some(-1235));
+    assert_eq!(rescale_decimal(12344, 2, 0), Some(123));
+    assert_eq!(rescale_decimal(i128::MAX, 0, 1), None);
+}
+
+#[test]
+fn test_decimal_type() -> Result<()> {
+    let factory = TypeFactory::instance();
+    assert_eq!(factory.get("Decimal(10, 2)")?, DecimalType::new_impl(10, 2));
+    assert_eq!(factory.get("numeric(5)")?, DecimalType::new_impl(5, 0));
+    assert_eq!(factory.get("decimal")?, DecimalType::new_impl(10, 0));
+    assert_eq!(
+        factory.get("Nullable(Decimal(38, 10))")?,
+        NullableType::new_impl(DecimalType::new_impl(38, 10))
+    );
+    assert!(factory.get("Decimal(39, 2)").is_err());
+    assert!(factory.get("Decimal(5, 6)").is_err());
+
+    let data_type = DecimalType::new_impl(5, 2);
+    let column = data_type.create_column(&[DataValue::Int128(12345), DataValue::Int128(-1)])?;
+    let serializer = data_type.create_serializer(&column)?;
+    let format = FormatSettings::default();
+    let mut buf = vec![];
+    serializer.write_field(0, &mut buf, &format);
+    buf.push(b',');
+    serializer.write_field(1, &mut buf, &format);
+    assert_eq!(buf, b"123.45,-0.01");
+
+    let mut deserializer = data_type.create_deserializer(2);
+    deserializer.de_whole_text(b"1.5", &format)?;
+    deserializer.append_data_value(DataValue::Int64(3), &format)?;
+    assert!(deserializer.de_whole_text(b"1000", &format).is_err());
+    let column = deserializer.finish_to_column();
+    assert_eq!(column.get(0), DataValue::Int128(150));
+    assert_eq!(column.get(1), DataValue::Int128(300));
+    Ok(())
+}
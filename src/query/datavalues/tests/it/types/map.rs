@@ -0,0 +1,82 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_arrow::arrow::datatypes::DataType as ArrowType;
+use common_arrow::arrow::datatypes::Field as ArrowField;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::FormatSettings;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn test_map_type() -> Result<()> {
+    let factory = TypeFactory::instance();
+    let data_type = MapType::new_impl(StringType::new_impl(), Int32Type::new_impl());
+    assert_eq!(factory.get("Map(String, Int32)")?, data_type);
+    assert_eq!(
+        factory.get("map(string, nullable(array(int32)))")?,
+        MapType::new_impl(
+            StringType::new_impl(),
+            NullableType::new_impl(ArrayType::new_impl(Int32Type::new_impl()))
+        )
+    );
+    assert!(factory.get("Map(Int32, String)").is_err());
+    assert_eq!(data_type.name(), "Map(String, Int32)");
+
+    let format = FormatSettings::default();
+    let mut deserializer = data_type.create_deserializer(2);
+    deserializer.de_whole_text(b"{'a': 1, 'b':2}", &format)?;
+    deserializer.de_json(&serde_json::json!({"c": 3}), &format)?;
+    let column = deserializer.finish_to_column();
+    assert_eq!(column.data_type(), data_type);
+    assert_eq!(
+        column.get(1),
+        DataValue::Array(vec![DataValue::Struct(vec![
+            DataValue::String(b"c".to_vec()),
+            DataValue::Int64(3)
+        ])])
+    );
+
+    let serializer = data_type.create_serializer(&column)?;
+    let mut buf = vec![];
+    serializer.write_field(0, &mut buf, &format);
+    assert_eq!(buf, b"{'a':1, 'b':2}");
+    let mut buf = vec![];
+    serializer.write_field_json(0, &mut buf, &format);
+    assert_eq!(buf, br#"{"a":1,"b":2}"#);
+
+    let arrow_array = column.as_arrow_array(data_type.clone());
+    let column2 = arrow_array.into_column();
+    assert_eq!(column2.data_type(), data_type);
+    assert_eq!(column2.get(0), column.get(0));
+    assert_eq!(column2.get(1), column.get(1));
+    Ok(())
+}
+
+#[test]
+fn test_map_from_arrow_type() -> Result<()> {
+    let data_type = MapType::new_impl(StringType::new_impl(), Int32Type::new_impl());
+    assert_eq!(try_from_arrow_type(&data_type.arrow_type())?, data_type);
+
+    // The entries of a map must be a struct of key and value.
+    let entries = ArrowField::new("entries", ArrowType::Int32, false);
+    let arrow_type = ArrowType::Map(Box::new(entries), false);
+    let err = try_from_arrow_type(&arrow_type).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::illegal_data_type_code());
+
+    let field = ArrowField::new("m", arrow_type, true);
+    assert!(try_from_arrow_field(&field).is_err());
+    Ok(())
+}
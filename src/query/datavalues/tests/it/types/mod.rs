@@ -14,6 +14,8 @@
 
 mod create_column;
 mod date_ts_bound;
+mod decimal;
 mod deserializations;
+mod map;
 mod serializations;
 mod viewer;
@@ -153,6 +153,11 @@ pub fn from_timestamp_data_with_validity(d: Vec<i64>, valids: Vec<bool>) -> Colu
     }))
 }
 
+// Specialize for `TimestampTzType`, to keep `Vec<i128>` free for the decimal columns.
+pub fn from_timestamp_tz_data(d: Vec<i128>) -> Column {
+    TimestampTzType::upcast_column(TimestampTzType::column_from_vec(d, &[]))
+}
+
 // Specialize for `DateType`, because from `Vec<i32>` will be conflict with `Int32Type`.
 pub fn from_date_data(d: Vec<i32>) -> Column {
     DateType::upcast_column(DateType::column_from_vec(d, &[]))
@@ -17,7 +17,14 @@ use common_datavalues::remove_nullable;
 use common_datavalues::ColumnRef;
 use common_datavalues::DataTypeImpl;
 use common_datavalues::DataValue;
+use common_exception::ErrorCode;
+use common_exception::Result;
 
+use crate::types::decimal::DecimalColumn;
+use crate::types::decimal::DecimalDataType;
+use crate::types::decimal::DecimalScalar;
+use crate::types::decimal::DecimalSize;
+use crate::types::nullable::NullableColumn;
 use crate::types::number::NumberScalar;
 use crate::types::AnyType;
 use crate::types::DataType;
@@ -36,35 +43,46 @@ pub fn can_convert(datatype: &DataTypeImpl) -> bool {
     )
 }
 
-pub fn from_type(datatype: &DataTypeImpl) -> DataType {
-    with_number_type!(|TYPE| match datatype {
+pub fn from_type(datatype: &DataTypeImpl) -> Result<DataType> {
+    Ok(with_number_type!(|TYPE| match datatype {
         DataTypeImpl::TYPE(_) => DataType::Number(NumberDataType::TYPE),
 
         DataTypeImpl::Null(_) => DataType::Null,
-        DataTypeImpl::Nullable(v) => DataType::Nullable(Box::new(from_type(v.inner_type()))),
+        DataTypeImpl::Nullable(v) => DataType::Nullable(Box::new(from_type(v.inner_type())?)),
         DataTypeImpl::Boolean(_) => DataType::Boolean,
+        DataTypeImpl::Decimal(ty) => DataType::Decimal(DecimalDataType::Decimal128(DecimalSize {
+            precision: ty.precision(),
+            scale: ty.scale(),
+        })),
         DataTypeImpl::Timestamp(_) => DataType::Timestamp,
+        DataTypeImpl::TimestampTz(_) => DataType::TimestampTz,
         DataTypeImpl::Date(_) => DataType::Date,
         DataTypeImpl::String(_) => DataType::String,
         DataTypeImpl::Struct(ty) => {
-            let inners = ty.types().iter().map(from_type).collect();
+            let inners = ty.types().iter().map(from_type).collect::<Result<_>>()?;
             DataType::Tuple(inners)
         }
-        DataTypeImpl::Array(ty) => DataType::Array(Box::new(from_type(ty.inner_type()))),
+        DataTypeImpl::Array(ty) => DataType::Array(Box::new(from_type(ty.inner_type())?)),
+        DataTypeImpl::Map(ty) => DataType::Map(Box::new(from_type(ty.value_type())?)),
         DataTypeImpl::Variant(_)
         | DataTypeImpl::VariantArray(_)
         | DataTypeImpl::VariantObject(_) => DataType::Variant,
-        DataTypeImpl::Interval(_) => unimplemented!(),
-    })
+        DataTypeImpl::Interval(_) => {
+            return Err(ErrorCode::UnImplement(format!(
+                "Unsupported data type {} in the new expression framework",
+                datatype
+            )));
+        }
+    }))
 }
 
-pub fn from_scalar(datavalue: &DataValue, datatype: &DataTypeImpl) -> Scalar {
+pub fn from_scalar(datavalue: &DataValue, datatype: &DataTypeImpl) -> Result<Scalar> {
     if datavalue.is_null() {
-        return Scalar::Null;
+        return Ok(Scalar::Null);
     }
 
     let datatype = remove_nullable(datatype);
-    match datatype {
+    Ok(match datatype {
         DataTypeImpl::Null(_) => Scalar::Null,
         DataTypeImpl::Boolean(_) => Scalar::Boolean(datavalue.as_bool().unwrap()),
         DataTypeImpl::Int8(_) => {
@@ -97,7 +115,15 @@ pub fn from_scalar(datavalue: &DataValue, datatype: &DataTypeImpl) -> Scalar {
         DataTypeImpl::Float64(_) => {
             Scalar::Number(NumberScalar::Float64(datavalue.as_f64().unwrap().into()))
         }
+        DataTypeImpl::Decimal(ty) => Scalar::Decimal(DecimalScalar::Decimal128(
+            datavalue.as_i128().unwrap(),
+            DecimalSize {
+                precision: ty.precision(),
+                scale: ty.scale(),
+            },
+        )),
         DataTypeImpl::Timestamp(_) => Scalar::Timestamp(datavalue.as_i64().unwrap() as i64),
+        DataTypeImpl::TimestampTz(_) => Scalar::TimestampTz(datavalue.as_i128().unwrap()),
         DataTypeImpl::Date(_) => Scalar::Date(datavalue.as_i64().unwrap() as i32),
         DataTypeImpl::String(_) => Scalar::String(datavalue.as_string().unwrap()),
         DataTypeImpl::Struct(types) => {
@@ -110,7 +136,7 @@ pub fn from_scalar(datavalue: &DataValue, datatype: &DataTypeImpl) -> Scalar {
                 .iter()
                 .zip(values.iter())
                 .map(|(ty, v)| from_scalar(v, ty))
-                .collect();
+                .collect::<Result<_>>()?;
 
             Scalar::Tuple(inners)
         }
@@ -120,11 +146,28 @@ pub fn from_scalar(datavalue: &DataValue, datatype: &DataTypeImpl) -> Scalar {
                 _ => unreachable!(),
             };
 
-            let new_type = from_type(ty.inner_type());
+            let new_type = from_type(ty.inner_type())?;
             let mut builder = ColumnBuilder::with_capacity(&new_type, values.len());
 
             for value in values.iter() {
-                let scalar = from_scalar(value, ty.inner_type());
+                let scalar = from_scalar(value, ty.inner_type())?;
+                builder.push(scalar.as_ref());
+            }
+            let col = builder.build();
+            Scalar::Array(col)
+        }
+        DataTypeImpl::Map(ty) => {
+            let entries = match datavalue {
+                DataValue::Array(x) => x,
+                _ => unreachable!(),
+            };
+
+            let entries_type = ty.entries_type();
+            let mut builder =
+                ColumnBuilder::with_capacity(&from_type(&entries_type)?, entries.len());
+
+            for entry in entries.iter() {
+                let scalar = from_scalar(entry, &entries_type)?;
                 builder.push(scalar.as_ref());
             }
             let col = builder.build();
@@ -141,29 +184,54 @@ pub fn from_scalar(datavalue: &DataValue, datatype: &DataTypeImpl) -> Scalar {
             let v: Vec<u8> = serde_json::to_vec(value).unwrap();
             Scalar::Variant(v)
         }
-        _ => unreachable!(),
-    }
+        _ => {
+            return Err(ErrorCode::UnImplement(format!(
+                "Unsupported data type {} in the new expression framework",
+                datatype
+            )));
+        }
+    })
 }
 
-pub fn convert_column(column: &ColumnRef, logical_type: &DataTypeImpl) -> Value<AnyType> {
+pub fn convert_column(column: &ColumnRef, logical_type: &DataTypeImpl) -> Result<Value<AnyType>> {
     if column.is_const() {
         let value = column.get(0);
-        let scalar = from_scalar(&value, logical_type);
-        return Value::Scalar(scalar);
+        let scalar = from_scalar(&value, logical_type)?;
+        return Ok(Value::Scalar(scalar));
     }
 
+    // Fail before reading an arrow column the new framework has no type for.
+    from_type(logical_type)?;
     let arrow_column = column.as_arrow_array(logical_type.clone());
     let new_column = Column::from_arrow(arrow_column.as_ref());
-    Value::Column(new_column)
+    if matches!(remove_nullable(logical_type), DataTypeImpl::TimestampTz(_)) {
+        return Ok(Value::Column(decimal_to_timestamp_tz(new_column)));
+    }
+    Ok(Value::Column(new_column))
+}
+
+// TIMESTAMP_TZ is an arrow decimal in `common_datavalues`, only the logical type
+// tells it apart from a DECIMAL(38, 0).
+fn decimal_to_timestamp_tz(column: Column) -> Column {
+    match column {
+        Column::Decimal(DecimalColumn::Decimal128(values, _)) => Column::TimestampTz(values),
+        Column::Nullable(box NullableColumn { column, validity }) => {
+            Column::Nullable(Box::new(NullableColumn {
+                column: decimal_to_timestamp_tz(column),
+                validity,
+            }))
+        }
+        column => column,
+    }
 }
 
-pub fn from_block(datablock: &DataBlock) -> Chunk {
-    let columns: Vec<(Value<AnyType>, DataType)> = datablock
+pub fn from_block(datablock: &DataBlock) -> Result<Chunk> {
+    let columns = datablock
         .columns()
         .iter()
         .zip(datablock.schema().fields().iter())
-        .map(|(c, f)| (convert_column(c, f.data_type()), from_type(f.data_type())))
-        .collect();
+        .map(|(c, f)| Ok((convert_column(c, f.data_type())?, from_type(f.data_type())?)))
+        .collect::<Result<Vec<(Value<AnyType>, DataType)>>>()?;
 
-    Chunk::new(columns, datablock.num_rows())
+    Ok(Chunk::new(columns, datablock.num_rows()))
 }
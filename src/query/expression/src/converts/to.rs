@@ -19,7 +19,14 @@ use common_datavalues::ConstColumn;
 use common_datavalues::DataSchemaRef;
 use common_datavalues::DataTypeImpl;
 use common_datavalues::IntoColumn;
+use common_exception::ErrorCode;
+use common_exception::Result;
 
+use crate::types::decimal::DecimalColumn;
+use crate::types::decimal::DecimalDataType;
+use crate::types::decimal::DecimalSize;
+use crate::types::decimal::MAX_DECIMAL128_PRECISION;
+use crate::types::nullable::NullableColumn;
 use crate::types::AnyType;
 use crate::types::DataType;
 use crate::types::NumberDataType;
@@ -29,26 +36,37 @@ use crate::Column;
 use crate::ColumnBuilder;
 use crate::Value;
 
-pub fn to_type(datatype: &DataTypeImpl) -> DataType {
-    with_number_type!(|TYPE| match datatype {
+pub fn to_type(datatype: &DataTypeImpl) -> Result<DataType> {
+    Ok(with_number_type!(|TYPE| match datatype {
         DataTypeImpl::TYPE(_) => DataType::Number(NumberDataType::TYPE),
 
         DataTypeImpl::Null(_) => DataType::Null,
-        DataTypeImpl::Nullable(v) => DataType::Nullable(Box::new(to_type(v.inner_type()))),
+        DataTypeImpl::Nullable(v) => DataType::Nullable(Box::new(to_type(v.inner_type())?)),
         DataTypeImpl::Boolean(_) => DataType::Boolean,
+        DataTypeImpl::Decimal(ty) => DataType::Decimal(DecimalDataType::Decimal128(DecimalSize {
+            precision: ty.precision(),
+            scale: ty.scale(),
+        })),
         DataTypeImpl::Timestamp(_) => DataType::Timestamp,
+        DataTypeImpl::TimestampTz(_) => DataType::TimestampTz,
         DataTypeImpl::Date(_) => DataType::Date,
         DataTypeImpl::String(_) => DataType::String,
         DataTypeImpl::Struct(ty) => {
-            let inners = ty.types().iter().map(to_type).collect();
+            let inners = ty.types().iter().map(to_type).collect::<Result<_>>()?;
             DataType::Tuple(inners)
         }
-        DataTypeImpl::Array(ty) => DataType::Array(Box::new(to_type(ty.inner_type()))),
+        DataTypeImpl::Array(ty) => DataType::Array(Box::new(to_type(ty.inner_type())?)),
+        DataTypeImpl::Map(ty) => DataType::Map(Box::new(to_type(ty.value_type())?)),
         DataTypeImpl::Variant(_)
         | DataTypeImpl::VariantArray(_)
         | DataTypeImpl::VariantObject(_) => DataType::Variant,
-        DataTypeImpl::Interval(_) => unimplemented!(),
-    })
+        DataTypeImpl::Interval(_) => {
+            return Err(ErrorCode::UnImplement(format!(
+                "Unsupported data type {} in the new expression framework",
+                datatype
+            )));
+        }
+    }))
 }
 
 // we do not need conver scalar to datavalue
@@ -62,7 +80,7 @@ pub fn to_column(column: &Value<AnyType>, size: usize, data_type: &DataType) ->
         }
         Value::Column(c) => {
             let is_nullable = matches!(c, Column::Nullable(_));
-            let arrow_column = c.as_arrow();
+            let arrow_column = timestamp_tz_to_decimal(c.clone()).as_arrow();
             if is_nullable {
                 arrow_column.into_nullable_column()
             } else {
@@ -72,6 +90,26 @@ pub fn to_column(column: &Value<AnyType>, size: usize, data_type: &DataType) ->
     }
 }
 
+// `common_datavalues` keeps TIMESTAMP_TZ in an arrow decimal, the logical type comes
+// from the schema.
+fn timestamp_tz_to_decimal(column: Column) -> Column {
+    match column {
+        Column::TimestampTz(values) => {
+            Column::Decimal(DecimalColumn::Decimal128(values, DecimalSize {
+                precision: MAX_DECIMAL128_PRECISION,
+                scale: 0,
+            }))
+        }
+        Column::Nullable(box NullableColumn { column, validity }) => {
+            Column::Nullable(Box::new(NullableColumn {
+                column: timestamp_tz_to_decimal(column),
+                validity,
+            }))
+        }
+        column => column,
+    }
+}
+
 pub fn to_datablock(chunk: &Chunk, schema: DataSchemaRef) -> DataBlock {
     let columns = chunk
         .columns()
@@ -12,12 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use chrono::format::Item;
+use chrono::format::StrftimeItems;
 use chrono::Date;
 use chrono::DateTime;
 use chrono::Datelike;
 use chrono::Duration;
 use chrono::NaiveDate;
 use chrono::NaiveDateTime;
+use chrono::Offset;
 use chrono::TimeZone;
 use chrono::Timelike;
 use chrono::Utc;
@@ -272,3 +275,85 @@ impl ToNumber<u8> for ToSecond {
         dt.second() as u8
     }
 }
+
+/// Resolve a local wall clock in `tz`. An ambiguous time takes the earliest instant,
+/// a time skipped by a DST transition is moved forward past the gap.
+pub fn resolve_local_datetime<T: TimeZone>(tz: &T, local: &NaiveDateTime) -> DateTime<T> {
+    match tz.from_local_datetime(local).earliest() {
+        Some(dt) => dt,
+        None => {
+            let before = tz
+                .offset_from_utc_datetime(&(*local - Duration::days(1)))
+                .fix();
+            tz.from_utc_datetime(&(*local - Duration::seconds(before.local_minus_utc() as i64)))
+        }
+    }
+}
+
+pub fn check_strftime_format(format: &str) -> Result<(), String> {
+    if StrftimeItems::new(format).any(|item| matches!(item, Item::Error)) {
+        return Err(format!("Invalid strftime format pattern {}", format));
+    }
+    Ok(())
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DateUnit {
+    Year,
+    Quarter,
+    Month,
+    Week,
+    Day,
+    Hour,
+    Minute,
+    Second,
+}
+
+impl DateUnit {
+    pub fn parse(unit: &[u8]) -> Result<DateUnit, String> {
+        let unit = String::from_utf8_lossy(unit).trim().to_lowercase();
+        match unit.strip_suffix('s').unwrap_or(unit.as_str()) {
+            "year" => Ok(DateUnit::Year),
+            "quarter" => Ok(DateUnit::Quarter),
+            "month" => Ok(DateUnit::Month),
+            "week" => Ok(DateUnit::Week),
+            "day" => Ok(DateUnit::Day),
+            "hour" => Ok(DateUnit::Hour),
+            "minute" => Ok(DateUnit::Minute),
+            "second" => Ok(DateUnit::Second),
+            _ => Err(format!(
+                "Unsupported date part {}, expected one of year, quarter, month, week, day, hour, minute, second",
+                unit
+            )),
+        }
+    }
+
+    /// The number of unit boundaries crossed from `a` to `b`.
+    ///
+    /// Calendar units count on the local fields, so a day stays a day across a DST
+    /// transition. Time units truncate in local time and count the elapsed instants.
+    pub fn diff<T: TimeZone>(&self, a: &DateTime<T>, b: &DateTime<T>) -> i64 {
+        let (la, lb) = (a.naive_local(), b.naive_local());
+        let months = |d: &NaiveDateTime| d.year() as i64 * 12 + d.month0() as i64;
+        let days = |d: &NaiveDateTime| d.num_days_from_ce() as i64;
+        let truncate = |d: &DateTime<T>, secs: i64| {
+            let local = d.naive_local().timestamp();
+            local - local.rem_euclid(secs) - d.offset().fix().local_minus_utc() as i64
+        };
+
+        match self {
+            DateUnit::Year => (lb.year() - la.year()) as i64,
+            DateUnit::Quarter => months(&lb).div_euclid(3) - months(&la).div_euclid(3),
+            DateUnit::Month => months(&lb) - months(&la),
+            DateUnit::Week => {
+                let monday =
+                    |d: &NaiveDateTime| days(d) - d.weekday().num_days_from_monday() as i64;
+                (monday(&lb) - monday(&la)).div_euclid(7)
+            }
+            DateUnit::Day => days(&lb) - days(&la),
+            DateUnit::Hour => (truncate(b, 3600) - truncate(a, 3600)).div_euclid(3600),
+            DateUnit::Minute => (truncate(b, 60) - truncate(a, 60)).div_euclid(60),
+            DateUnit::Second => b.timestamp() - a.timestamp(),
+        }
+    }
+}
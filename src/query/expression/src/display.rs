@@ -33,6 +33,11 @@ use crate::function::FunctionSignature;
 use crate::property::Domain;
 use crate::property::FunctionProperty;
 use crate::types::boolean::BooleanDomain;
+use crate::types::decimal::display_decimal;
+use crate::types::decimal::DecimalColumn;
+use crate::types::decimal::DecimalDataType;
+use crate::types::decimal::DecimalDomain;
+use crate::types::decimal::DecimalScalar;
 use crate::types::nullable::NullableDomain;
 use crate::types::number::NumberColumn;
 use crate::types::number::NumberDataType;
@@ -41,12 +46,14 @@ use crate::types::number::NumberScalar;
 use crate::types::number::SimpleDomain;
 use crate::types::string::StringColumn;
 use crate::types::string::StringDomain;
+use crate::types::timestamp_tz::timestamp_tz_to_string;
 use crate::types::AnyType;
 use crate::types::DataType;
 use crate::types::ValueType;
 use crate::values::ScalarRef;
 use crate::values::Value;
 use crate::values::ValueRef;
+use crate::with_decimal_type;
 use crate::with_number_type;
 use crate::Column;
 
@@ -93,9 +100,11 @@ impl<'a> Debug for ScalarRef<'a> {
             ScalarRef::Null => write!(f, "NULL"),
             ScalarRef::EmptyArray => write!(f, "[] :: Array(Nothing)"),
             ScalarRef::Number(val) => write!(f, "{val:?}"),
+            ScalarRef::Decimal(val) => write!(f, "{val:?}"),
             ScalarRef::Boolean(val) => write!(f, "{val}"),
             ScalarRef::String(s) => write!(f, "{:?}", String::from_utf8_lossy(s)),
             ScalarRef::Timestamp(t) => write!(f, "{t:?}"),
+            ScalarRef::TimestampTz(t) => write!(f, "{t:?}"),
             ScalarRef::Date(d) => write!(f, "{d:?}"),
             ScalarRef::Array(col) => write!(f, "[{}]", col.iter().join(", ")),
             ScalarRef::Tuple(fields) => {
@@ -116,9 +125,11 @@ impl Debug for Column {
             Column::Null { len } => f.debug_struct("Null").field("len", len).finish(),
             Column::EmptyArray { len } => f.debug_struct("EmptyArray").field("len", len).finish(),
             Column::Number(col) => write!(f, "{col:?}"),
+            Column::Decimal(col) => write!(f, "{col:?}"),
             Column::Boolean(col) => f.debug_tuple("Boolean").field(col).finish(),
             Column::String(col) => write!(f, "{col:?}"),
             Column::Timestamp(col) => write!(f, "{col:?}"),
+            Column::TimestampTz(col) => write!(f, "{col:?}"),
             Column::Date(col) => write!(f, "{col:?}"),
             Column::Array(col) => write!(f, "{col:?}"),
             Column::Nullable(col) => write!(f, "{col:?}"),
@@ -138,9 +149,11 @@ impl<'a> Display for ScalarRef<'a> {
             ScalarRef::Null => write!(f, "NULL"),
             ScalarRef::EmptyArray => write!(f, "[]"),
             ScalarRef::Number(val) => write!(f, "{val}"),
+            ScalarRef::Decimal(val) => write!(f, "{val}"),
             ScalarRef::Boolean(val) => write!(f, "{val}"),
             ScalarRef::String(s) => write!(f, "{:?}", String::from_utf8_lossy(s)),
             ScalarRef::Timestamp(t) => write!(f, "{}", display_timestamp(*t)),
+            ScalarRef::TimestampTz(t) => write!(f, "{}", timestamp_tz_to_string(*t)),
             ScalarRef::Date(d) => write!(f, "{}", display_date(*d as i64)),
             ScalarRef::Array(col) => write!(f, "[{}]", col.iter().join(", ")),
             ScalarRef::Tuple(fields) => {
@@ -275,6 +288,32 @@ impl Debug for NumberColumn {
     }
 }
 
+impl Display for DecimalScalar {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        with_decimal_type!(|DECIMAL_TYPE| match self {
+            DecimalScalar::DECIMAL_TYPE(val, size) => {
+                write!(f, "{}", display_decimal(*val, size.scale))
+            }
+        })
+    }
+}
+
+impl Debug for DecimalColumn {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        with_decimal_type!(|DECIMAL_TYPE| match self {
+            DecimalColumn::DECIMAL_TYPE(val, size) => f
+                .debug_tuple(stringify!(DECIMAL_TYPE))
+                .field(&format_args!(
+                    "[{}]",
+                    &val.iter()
+                        .map(|x| display_decimal(*x, size.scale))
+                        .join(", ")
+                ))
+                .finish(),
+        })
+    }
+}
+
 impl Debug for StringColumn {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("StringColumn")
@@ -322,10 +361,32 @@ impl Display for RawExpr {
                 }
                 write!(f, ")")
             }
+            RawExpr::LambdaFunctionCall {
+                name,
+                args,
+                lambda_params,
+                lambda_expr,
+                ..
+            } => {
+                write!(f, "{name}(")?;
+                for arg in args {
+                    write!(f, "{arg}, ")?;
+                }
+                write_lambda_params(f, lambda_params)?;
+                write!(f, " -> {lambda_expr})")
+            }
         }
     }
 }
 
+fn write_lambda_params(f: &mut std::fmt::Formatter<'_>, params: &[String]) -> std::fmt::Result {
+    if params.len() == 1 {
+        write!(f, "{}", params[0])
+    } else {
+        write!(f, "({})", params.join(", "))
+    }
+}
+
 impl Display for Literal {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -352,7 +413,9 @@ impl Display for DataType {
             DataType::Boolean => write!(f, "Boolean"),
             DataType::String => write!(f, "String"),
             DataType::Number(num) => write!(f, "{num}"),
+            DataType::Decimal(decimal) => write!(f, "{decimal}"),
             DataType::Timestamp => write!(f, "Timestamp"),
+            DataType::TimestampTz => write!(f, "TimestampTz"),
             DataType::Date => write!(f, "Date"),
             DataType::Null => write!(f, "NULL"),
             DataType::Nullable(inner) => write!(f, "{inner} NULL"),
@@ -396,6 +459,13 @@ impl Display for NumberDataType {
     }
 }
 
+impl Display for DecimalDataType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        let size = self.size();
+        write!(f, "Decimal({}, {})", size.precision, size.scale)
+    }
+}
+
 impl Display for Expr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -445,6 +515,24 @@ impl Display for Expr {
                 }
                 write!(f, ")")
             }
+            Expr::LambdaFunctionCall {
+                name,
+                args,
+                lambda_params,
+                lambda_expr,
+                ..
+            } => {
+                write!(f, "{name}(")?;
+                for arg in args {
+                    write!(f, "{arg}, ")?;
+                }
+                let params = lambda_params
+                    .iter()
+                    .map(|(param, _)| param.clone())
+                    .collect::<Vec<_>>();
+                write_lambda_params(f, &params)?;
+                write!(f, " -> {lambda_expr})")
+            }
         }
     }
 }
@@ -548,6 +636,19 @@ impl Display for NumberDomain {
     }
 }
 
+impl Display for DecimalDomain {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        with_decimal_type!(|DECIMAL_TYPE| match self {
+            DecimalDomain::DECIMAL_TYPE(SimpleDomain { min, max }, size) => write!(
+                f,
+                "{{{}..={}}}",
+                display_decimal(*min, size.scale),
+                display_decimal(*max, size.scale)
+            ),
+        })
+    }
+}
+
 impl<T: Display> Display for SimpleDomain<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         write!(f, "{{{}..={}}}", self.min, self.max)
@@ -557,9 +658,11 @@ impl Display for Domain {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
             Domain::Number(domain) => write!(f, "{domain}"),
+            Domain::Decimal(domain) => write!(f, "{domain}"),
             Domain::Boolean(domain) => write!(f, "{domain}"),
             Domain::String(domain) => write!(f, "{domain}"),
             Domain::Timestamp(domain) => write!(f, "{domain}"),
+            Domain::TimestampTz(domain) => write!(f, "{domain}"),
             Domain::Date(domain) => write!(f, "{domain}"),
             Domain::Nullable(domain) => write!(f, "{domain}"),
             Domain::Array(None) => write!(f, "[]"),
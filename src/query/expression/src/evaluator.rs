@@ -30,6 +30,8 @@ use crate::property::Domain;
 use crate::types::any::AnyType;
 use crate::types::array::ArrayColumn;
 use crate::types::date::date_to_string;
+use crate::types::decimal::DecimalDomain;
+use crate::types::decimal::DecimalScalar;
 use crate::types::nullable::NullableColumn;
 use crate::types::nullable::NullableDomain;
 use crate::types::number::NumberColumn;
@@ -39,6 +41,7 @@ use crate::types::number::NumberScalar;
 use crate::types::number::SimpleDomain;
 use crate::types::string::StringColumnBuilder;
 use crate::types::timestamp::timestamp_to_string;
+use crate::types::timestamp_tz::timestamp_tz_to_string;
 use crate::types::variant::cast_scalar_to_variant;
 use crate::types::variant::cast_scalars_to_variants;
 use crate::types::DataType;
@@ -47,6 +50,7 @@ use crate::values::Column;
 use crate::values::ColumnBuilder;
 use crate::values::Scalar;
 use crate::values::Value;
+use crate::with_decimal_type;
 use crate::with_number_type;
 use crate::ScalarRef;
 
@@ -91,6 +95,24 @@ impl<'a> Evaluator<'a> {
                 };
                 (function.eval)(cols_ref.as_slice(), ctx).map_err(|msg| (span.clone(), msg))
             }
+            Expr::LambdaFunctionCall {
+                span,
+                name,
+                args,
+                lambda_params,
+                lambda_expr,
+                return_type,
+            } => {
+                let array = self.run(&args[0])?;
+                let lambda = Lambda {
+                    span: span.clone(),
+                    name,
+                    params: lambda_params,
+                    expr: lambda_expr,
+                    return_type,
+                };
+                self.run_lambda(&lambda, array)
+            }
             Expr::Cast {
                 span,
                 expr,
@@ -253,6 +275,19 @@ impl<'a> Evaluator<'a> {
                 timestamp_to_string(ts, &self.tz).as_bytes().to_vec(),
             )),
 
+            (Scalar::TimestampTz(ts), DataType::String) => Ok(Scalar::String(
+                timestamp_tz_to_string(ts).as_bytes().to_vec(),
+            )),
+
+            (scalar @ Scalar::Decimal(_), _) | (scalar, DataType::Decimal(_)) => {
+                cast_decimal_scalar(scalar.clone(), dest_type).ok_or_else(|| {
+                    (
+                        span,
+                        format!("unable to cast {} to {dest_type}", scalar.as_ref()),
+                    )
+                })
+            }
+
             (Scalar::Date(d), DataType::String) => Ok(Scalar::String(
                 date_to_string(d, &self.tz).as_bytes().to_vec(),
             )),
@@ -263,6 +298,7 @@ impl<'a> Evaluator<'a> {
             | (scalar @ Scalar::Boolean(_), DataType::Boolean)
             | (scalar @ Scalar::String(_), DataType::String)
             | (scalar @ Scalar::Timestamp(_), DataType::Timestamp)
+            | (scalar @ Scalar::TimestampTz(_), DataType::TimestampTz)
             | (scalar @ Scalar::Date(_), DataType::Date) => Ok(scalar),
 
             (scalar, dest_ty) => Err((
@@ -421,6 +457,17 @@ impl<'a> Evaluator<'a> {
                 Ok(Column::String(builder.build()))
             }
 
+            (Column::TimestampTz(col), DataType::String) => {
+                // "YYYY-mm-DD HH:MM:SS.ssssss +hh:mm"
+                let mut builder = StringColumnBuilder::with_capacity(col.len(), col.len() * 33);
+                for val in col.iter() {
+                    let s = timestamp_tz_to_string(*val);
+                    builder.put_str(s.as_str());
+                    builder.commit_row();
+                }
+                Ok(Column::String(builder.build()))
+            }
+
             (Column::Date(col), DataType::String) => {
                 // We can get the data_capacity, so no need to use `from_iter`.
                 // "YYYY-mm-DD"
@@ -433,12 +480,28 @@ impl<'a> Evaluator<'a> {
                 Ok(Column::String(builder.build()))
             }
 
+            (col @ Column::Decimal(_), _) | (col, DataType::Decimal(_)) => {
+                let mut builder = ColumnBuilder::with_capacity(dest_type, col.len());
+                for scalar in col.iter() {
+                    let new_scalar =
+                        cast_decimal_scalar(scalar.to_owned(), dest_type).ok_or_else(|| {
+                            (
+                                span.clone(),
+                                format!("unable to cast {scalar} to {dest_type}"),
+                            )
+                        })?;
+                    builder.push(new_scalar.as_ref());
+                }
+                Ok(builder.build())
+            }
+
             // identical types
             (col @ Column::Null { .. }, DataType::Null)
             | (col @ Column::EmptyArray { .. }, DataType::EmptyArray)
             | (col @ Column::Boolean(_), DataType::Boolean)
             | (col @ Column::String { .. }, DataType::String)
             | (col @ Column::Timestamp { .. }, DataType::Timestamp)
+            | (col @ Column::TimestampTz(_), DataType::TimestampTz)
             | (col @ Column::Date(_), DataType::Date) => Ok(col),
 
             (col, dest_ty) => Err((span, (format!("unable to cast {col:?} to {dest_ty}")))),
@@ -632,6 +695,21 @@ impl<'a> Evaluator<'a> {
                 }))
             }
 
+            (Column::TimestampTz(col), DataType::String) => {
+                // "YYYY-mm-DD HH:MM:SS.ssssss +hh:mm"
+                let mut builder = StringColumnBuilder::with_capacity(col.len(), col.len() * 33);
+                for val in col.iter() {
+                    let s = timestamp_tz_to_string(*val);
+                    builder.put_str(s.as_str());
+                    builder.commit_row();
+                }
+                let new_col = builder.build();
+                Column::Nullable(Box::new(NullableColumn {
+                    validity: constant_bitmap(true, col.len()).into(),
+                    column: Column::String(new_col),
+                }))
+            }
+
             (Column::Date(col), DataType::String) => {
                 // We can get the data_capacity, so no need to use `from_iter`.
                 // "YYYY-mm-DD"
@@ -648,11 +726,33 @@ impl<'a> Evaluator<'a> {
                 }))
             }
 
+            (col @ Column::Decimal(_), _) | (col, DataType::Decimal(_)) => {
+                let mut builder = ColumnBuilder::with_capacity(inner_type, col.len());
+                let mut validity = MutableBitmap::with_capacity(col.len());
+                for scalar in col.iter() {
+                    match cast_decimal_scalar(scalar.to_owned(), inner_type) {
+                        Some(new_scalar) => {
+                            builder.push(new_scalar.as_ref());
+                            validity.push(true);
+                        }
+                        None => {
+                            builder.push_default();
+                            validity.push(false);
+                        }
+                    }
+                }
+                Column::Nullable(Box::new(NullableColumn {
+                    validity: validity.into(),
+                    column: builder.build(),
+                }))
+            }
+
             // identical types
             (column @ Column::Boolean(_), DataType::Boolean)
             | (column @ Column::String { .. }, DataType::String)
             | (column @ Column::EmptyArray { .. }, DataType::EmptyArray)
             | (column @ Column::Timestamp { .. }, DataType::Timestamp)
+            | (column @ Column::TimestampTz(_), DataType::TimestampTz)
             | (column @ Column::Date(_), DataType::Date) => {
                 Column::Nullable(Box::new(NullableColumn {
                     validity: constant_bitmap(true, column.len()).into(),
@@ -673,6 +773,195 @@ impl<'a> Evaluator<'a> {
     }
 }
 
+/// The lambda of a higher-order function like `array_transform(arr, x -> x + 1)`.
+struct Lambda<'a> {
+    span: Span,
+    name: &'a str,
+    params: &'a [(String, DataType)],
+    expr: &'a Expr,
+    return_type: &'a DataType,
+}
+
+impl<'a> Evaluator<'a> {
+    fn run_lambda(&self, lambda: &Lambda, array: Value<AnyType>) -> Result<Value<AnyType>> {
+        match array {
+            Value::Scalar(Scalar::Null) => Ok(Value::Scalar(Scalar::Null)),
+            Value::Scalar(Scalar::EmptyArray) => {
+                let column = self.run_lambda_column(lambda, Column::EmptyArray { len: 1 })?;
+                Ok(Value::Scalar(column.index(0).unwrap().to_owned()))
+            }
+            Value::Scalar(Scalar::Array(values)) => {
+                let array = ArrayColumn {
+                    offsets: vec![0, values.len() as u64].into(),
+                    values,
+                };
+                let column = self.run_lambda_array(lambda, array)?;
+                Ok(Value::Scalar(column.index(0).unwrap().to_owned()))
+            }
+            Value::Scalar(scalar) => unreachable!("expect array, but got {scalar:?}"),
+            Value::Column(column) => Ok(Value::Column(self.run_lambda_column(lambda, column)?)),
+        }
+    }
+
+    fn run_lambda_column(&self, lambda: &Lambda, column: Column) -> Result<Column> {
+        match column {
+            Column::Null { len } => Ok(Column::Null { len }),
+            Column::EmptyArray { len } => match lambda.name {
+                "array_reduce" => {
+                    Ok(ColumnBuilder::repeat(&ScalarRef::Null, len, lambda.return_type).build())
+                }
+                _ => Ok(Column::EmptyArray { len }),
+            },
+            Column::Nullable(box NullableColumn { column, validity }) => {
+                match self.run_lambda_column(lambda, column)? {
+                    Column::Nullable(box nullable) => {
+                        Ok(Column::Nullable(Box::new(NullableColumn {
+                            validity: bitmap::and(&nullable.validity, &validity),
+                            column: nullable.column,
+                        })))
+                    }
+                    Column::Null { len } => Ok(Column::Null { len }),
+                    column => Ok(Column::Nullable(Box::new(NullableColumn {
+                        column,
+                        validity,
+                    }))),
+                }
+            }
+            Column::Array(box array) => self.run_lambda_array(lambda, array),
+            column => unreachable!("expect array column, but got {column:?}"),
+        }
+    }
+
+    /// Evaluates the lambda on the flattened elements of all arrays at once.
+    fn run_lambda_array(&self, lambda: &Lambda, array: ArrayColumn<AnyType>) -> Result<Column> {
+        // Rebase the offsets in case the array column is sliced.
+        let start = array.offsets[0];
+        let end = *array.offsets.last().unwrap();
+        let values = array.values.slice(start as usize..end as usize);
+        let offsets: Vec<u64> = array.offsets.iter().map(|offset| offset - start).collect();
+
+        match lambda.name {
+            "array_transform" => {
+                let elem_type = match lambda.return_type {
+                    DataType::Array(box ty) | DataType::Nullable(box DataType::Array(box ty)) => ty,
+                    ty => unreachable!("unexpected return type {ty}"),
+                };
+                let num_rows = values.len();
+                let values = match self.run_lambda_expr(lambda, vec![values], num_rows)? {
+                    Value::Scalar(scalar) => {
+                        ColumnBuilder::repeat(&scalar.as_ref(), num_rows, elem_type).build()
+                    }
+                    Value::Column(column) => column,
+                };
+                Ok(Column::Array(Box::new(ArrayColumn {
+                    values,
+                    offsets: offsets.into(),
+                })))
+            }
+            "array_filter" => {
+                let num_rows = values.len();
+                let predicate: bitmap::Bitmap =
+                    match self.run_lambda_expr(lambda, vec![values.clone()], num_rows)? {
+                        Value::Scalar(Scalar::Boolean(true)) => {
+                            constant_bitmap(true, num_rows).into()
+                        }
+                        Value::Column(Column::Boolean(predicate)) => predicate,
+                        Value::Column(Column::Nullable(box NullableColumn {
+                            column: Column::Boolean(predicate),
+                            validity,
+                        })) => bitmap::and(&predicate, &validity),
+                        // NULL is treated as false.
+                        _ => constant_bitmap(false, num_rows).into(),
+                    };
+
+                let mut new_offsets = Vec::with_capacity(offsets.len());
+                new_offsets.push(0);
+                let mut len = 0;
+                for window in offsets.windows(2) {
+                    len += (window[0]..window[1])
+                        .filter(|idx| predicate.get_bit(*idx as usize))
+                        .count() as u64;
+                    new_offsets.push(len);
+                }
+                Ok(Column::Array(Box::new(ArrayColumn {
+                    values: values.filter(&predicate),
+                    offsets: new_offsets.into(),
+                })))
+            }
+            "array_reduce" => {
+                let acc_type = &lambda.params[0].1;
+                let elem_type = &lambda.params[1].1;
+                let num_rows = offsets.len() - 1;
+                let array_len = |row: usize| offsets[row + 1] - offsets[row];
+
+                // Reduce the elements of all arrays step by step, `rows` are the arrays
+                // that still have elements left, and `acc` holds their accumulators.
+                let mut results: Vec<Option<Scalar>> = vec![None; num_rows];
+                let mut rows: Vec<usize> =
+                    (0..num_rows).filter(|row| array_len(*row) > 0).collect();
+                let first_elems: Vec<u64> = rows.iter().map(|row| offsets[*row]).collect();
+                let mut acc = values.take(&first_elems);
+                if acc_type != elem_type {
+                    acc = self.run_cast_column(lambda.span.clone(), acc, acc_type)?;
+                }
+
+                let mut step = 1;
+                while !rows.is_empty() {
+                    let (finished, remaining): (Vec<usize>, Vec<usize>) =
+                        (0..rows.len()).partition(|idx| array_len(rows[*idx]) <= step);
+                    for idx in finished {
+                        results[rows[idx]] = Some(acc.index(idx).unwrap().to_owned());
+                    }
+                    if remaining.is_empty() {
+                        break;
+                    }
+
+                    let acc_remaining = acc.take(&remaining);
+                    rows = remaining.iter().map(|idx| rows[*idx]).collect();
+                    let elems: Vec<u64> = rows.iter().map(|row| offsets[*row] + step).collect();
+                    let num_rows = rows.len();
+                    acc = match self.run_lambda_expr(
+                        lambda,
+                        vec![acc_remaining, values.take(&elems)],
+                        num_rows,
+                    )? {
+                        Value::Scalar(scalar) => {
+                            ColumnBuilder::repeat(&scalar.as_ref(), num_rows, acc_type).build()
+                        }
+                        Value::Column(column) => column,
+                    };
+                    step += 1;
+                }
+
+                let mut builder = ColumnBuilder::with_capacity(lambda.return_type, num_rows);
+                for result in &results {
+                    match result {
+                        Some(scalar) => builder.push(scalar.as_ref()),
+                        None => builder.push_default(),
+                    }
+                }
+                Ok(builder.build())
+            }
+            name => unreachable!("unknown lambda function `{name}`"),
+        }
+    }
+
+    fn run_lambda_expr(
+        &self,
+        lambda: &Lambda,
+        args: Vec<Column>,
+        num_rows: usize,
+    ) -> Result<Value<AnyType>> {
+        let columns = args
+            .into_iter()
+            .zip(lambda.params.iter())
+            .map(|(column, (_, ty))| (Value::Column(column), ty.clone()))
+            .collect();
+        let chunk = Chunk::new(columns, num_rows);
+        Evaluator::new(&chunk, self.tz).run(lambda.expr)
+    }
+}
+
 pub struct ConstantFolder<'a> {
     input_domains: &'a [Domain],
     tz: Tz,
@@ -835,6 +1124,43 @@ impl<'a> ConstantFolder<'a> {
 
                 (func_expr, func_domain)
             }
+            Expr::LambdaFunctionCall {
+                span,
+                name,
+                args,
+                lambda_params,
+                lambda_expr,
+                return_type,
+            } => {
+                let args_expr = args.iter().map(|arg| self.fold(arg).0).collect::<Vec<_>>();
+                let all_args_is_scalar = args_expr.iter().all(|arg| arg.as_constant().is_some());
+
+                let lambda_func_expr = Expr::LambdaFunctionCall {
+                    span: span.clone(),
+                    name: name.clone(),
+                    args: args_expr,
+                    lambda_params: lambda_params.clone(),
+                    lambda_expr: lambda_expr.clone(),
+                    return_type: return_type.clone(),
+                };
+
+                if all_args_is_scalar {
+                    let chunk = Chunk::empty();
+                    let evaluator = Evaluator::new(&chunk, self.tz);
+                    if let Ok(Value::Scalar(scalar)) = evaluator.run(&lambda_func_expr) {
+                        let domain = scalar.as_ref().domain();
+                        return (
+                            Expr::Constant {
+                                span: span.clone(),
+                                scalar,
+                            },
+                            Some(domain),
+                        );
+                    }
+                }
+
+                (lambda_func_expr, None)
+            }
         }
     }
 
@@ -926,10 +1252,18 @@ impl<'a> ConstantFolder<'a> {
                 })
             }
 
+            (Domain::Decimal(domain), DataType::Decimal(dest_ty)) => {
+                let (min, max) = domain_bounds(domain);
+                let min = min.cast_to(dest_ty)?;
+                let max = max.cast_to(dest_ty)?;
+                Some(Domain::Decimal(min.domain().merge(&max.domain())))
+            }
+
             // identical types
             (Domain::Boolean(_), DataType::Boolean)
             | (Domain::String(_), DataType::String)
             | (Domain::Timestamp(_), DataType::Timestamp)
+            | (Domain::TimestampTz(_), DataType::TimestampTz)
             | (Domain::Date(_), DataType::Date) => Some(domain.clone()),
 
             // failure cases
@@ -1051,10 +1385,24 @@ impl<'a> ConstantFolder<'a> {
                 }))),
             }),
 
+            (Domain::Decimal(_), _) | (_, DataType::Decimal(_)) => {
+                match self.calculate_cast(span, domain, inner_type) {
+                    Some(domain) => Domain::Nullable(NullableDomain {
+                        has_null: false,
+                        value: Some(Box::new(domain)),
+                    }),
+                    None => Domain::Nullable(NullableDomain {
+                        has_null: true,
+                        value: Some(Box::new(Domain::Undefined)),
+                    }),
+                }
+            }
+
             // identical types
             (Domain::Boolean(_), DataType::Boolean)
             | (Domain::String(_), DataType::String)
             | (Domain::Timestamp(_), DataType::Timestamp)
+            | (Domain::TimestampTz(_), DataType::TimestampTz)
             | (Domain::Date(_), DataType::Date) => Domain::Nullable(NullableDomain {
                 has_null: false,
                 value: Some(Box::new(domain.clone())),
@@ -1068,3 +1416,58 @@ impl<'a> ConstantFolder<'a> {
         }
     }
 }
+
+/// Casts between decimals, and between decimals and numbers, strings or booleans.
+/// Returns `None` if the value cannot be represented by the destination type.
+fn cast_decimal_scalar(scalar: Scalar, dest_type: &DataType) -> Option<Scalar> {
+    match (scalar, dest_type) {
+        (Scalar::Decimal(decimal), DataType::Decimal(dest_ty)) => {
+            decimal.cast_to(dest_ty).map(Scalar::Decimal)
+        }
+        (Scalar::Number(num), DataType::Decimal(dest_ty)) => {
+            let decimal = match num {
+                NumberScalar::Float32(value) => DecimalScalar::from_f64(value.0 as f64, dest_ty),
+                NumberScalar::Float64(value) => DecimalScalar::from_f64(value.0, dest_ty),
+                NumberScalar::UInt64(value) => DecimalScalar::from_u64(value, dest_ty),
+                _ => with_number_type!(|SRC_TYPE| match num {
+                    NumberScalar::SRC_TYPE(value) => {
+                        DecimalScalar::from_i64(num_traits::cast::cast(value)?, dest_ty)
+                    }
+                }),
+            };
+            decimal.map(Scalar::Decimal)
+        }
+        (Scalar::Boolean(value), DataType::Decimal(dest_ty)) => {
+            DecimalScalar::from_i64(value as i64, dest_ty).map(Scalar::Decimal)
+        }
+        (Scalar::String(value), DataType::Decimal(dest_ty)) => {
+            dest_ty.parse_scalar(&value).ok().map(Scalar::Decimal)
+        }
+        (Scalar::Decimal(decimal), DataType::Number(dest_ty)) => {
+            let num = match dest_ty {
+                NumberDataType::Float32 => NumberScalar::Float32((decimal.to_f64() as f32).into()),
+                NumberDataType::Float64 => NumberScalar::Float64(decimal.to_f64().into()),
+                _ => with_number_type!(|DEST_TYPE| match dest_ty {
+                    NumberDataType::DEST_TYPE => {
+                        NumberScalar::DEST_TYPE(num_traits::cast::cast(decimal.to_i128()?)?)
+                    }
+                }),
+            };
+            Some(Scalar::Number(num))
+        }
+        (Scalar::Decimal(decimal), DataType::Boolean) => Some(Scalar::Boolean(!decimal.is_zero())),
+        (Scalar::Decimal(decimal), DataType::String) => {
+            Some(Scalar::String(decimal.to_string().into_bytes()))
+        }
+        _ => None,
+    }
+}
+
+fn domain_bounds(domain: &DecimalDomain) -> (DecimalScalar, DecimalScalar) {
+    with_decimal_type!(|DECIMAL_TYPE| match domain {
+        DecimalDomain::DECIMAL_TYPE(SimpleDomain { min, max }, size) => (
+            DecimalScalar::DECIMAL_TYPE(*min, *size),
+            DecimalScalar::DECIMAL_TYPE(*max, *size),
+        ),
+    })
+}
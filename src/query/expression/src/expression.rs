@@ -55,6 +55,17 @@ pub enum RawExpr {
         params: Vec<usize>,
         args: Vec<RawExpr>,
     },
+    /// A higher-order function with a lambda, like `array_transform(arr, x -> x + 1)`.
+    ///
+    /// The lambda parameters are referred by `ColumnRef` with the index of the parameter
+    /// inside `lambda_expr`, their data types are resolved during type checking.
+    LambdaFunctionCall {
+        span: Span,
+        name: String,
+        args: Vec<RawExpr>,
+        lambda_params: Vec<String>,
+        lambda_expr: Box<RawExpr>,
+    },
 }
 
 #[derive(Debug, Clone, Educe, EnumAsInner)]
@@ -86,6 +97,14 @@ pub enum Expr {
         generics: Vec<DataType>,
         args: Vec<Expr>,
     },
+    LambdaFunctionCall {
+        span: Span,
+        name: String,
+        args: Vec<Expr>,
+        lambda_params: Vec<(String, DataType)>,
+        lambda_expr: Box<Expr>,
+        return_type: DataType,
+    },
 }
 
 /// Serializable expression used to share executable expression between nodes.
@@ -118,6 +137,14 @@ pub enum RemoteExpr {
         generics: Vec<DataType>,
         args: Vec<RemoteExpr>,
     },
+    LambdaFunctionCall {
+        span: Span,
+        name: String,
+        args: Vec<RemoteExpr>,
+        lambda_params: Vec<(String, DataType)>,
+        lambda_expr: Box<RemoteExpr>,
+        return_type: DataType,
+    },
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -147,6 +174,10 @@ impl RawExpr {
                 RawExpr::Cast { expr, .. } => walk(expr, buf),
                 RawExpr::TryCast { expr, .. } => walk(expr, buf),
                 RawExpr::FunctionCall { args, .. } => args.iter().for_each(|expr| walk(expr, buf)),
+                // The column references inside the lambda are the lambda parameters.
+                RawExpr::LambdaFunctionCall { args, .. } => {
+                    args.iter().for_each(|expr| walk(expr, buf))
+                }
                 RawExpr::Literal { .. } => (),
             }
         }
@@ -192,6 +223,21 @@ impl RemoteExpr {
                 generics,
                 args: args.into_iter().map(RemoteExpr::from_expr).collect(),
             },
+            Expr::LambdaFunctionCall {
+                span,
+                name,
+                args,
+                lambda_params,
+                lambda_expr,
+                return_type,
+            } => RemoteExpr::LambdaFunctionCall {
+                span,
+                name,
+                args: args.into_iter().map(RemoteExpr::from_expr).collect(),
+                lambda_params,
+                lambda_expr: Box::new(RemoteExpr::from_expr(*lambda_expr)),
+                return_type,
+            },
         }
     }
 
@@ -235,6 +281,24 @@ impl RemoteExpr {
                         .collect::<Option<_>>()?,
                 }
             }
+            RemoteExpr::LambdaFunctionCall {
+                span,
+                name,
+                args,
+                lambda_params,
+                lambda_expr,
+                return_type,
+            } => Expr::LambdaFunctionCall {
+                span,
+                name,
+                args: args
+                    .into_iter()
+                    .map(|arg| arg.into_expr(fn_registry))
+                    .collect::<Option<_>>()?,
+                lambda_params,
+                lambda_expr: Box::new(lambda_expr.into_expr(fn_registry)?),
+                return_type,
+            },
         })
     }
 }
@@ -16,6 +16,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 
 use crate::types::array::ArrayColumnBuilder;
+use crate::types::decimal::DecimalColumnBuilder;
 use crate::types::nullable::NullableColumn;
 use crate::types::number::NumberColumn;
 use crate::types::string::StringColumnBuilder;
@@ -30,6 +31,7 @@ use crate::types::NullableType;
 use crate::types::NumberType;
 use crate::types::StringType;
 use crate::types::TimestampType;
+use crate::types::TimestampTzType;
 use crate::types::ValueType;
 use crate::types::VariantType;
 use crate::with_number_mapped_type;
@@ -86,6 +88,13 @@ impl Column {
                     Self::concat_arg_types::<NumberType<NUM_TYPE>>(columns)
                 }
             }),
+            Column::Decimal(col) => {
+                let builder = ColumnBuilder::Decimal(DecimalColumnBuilder::with_capacity(
+                    &col.data_type(),
+                    capacity,
+                ));
+                Self::concat_value_types::<AnyType>(builder, columns)
+            }
             Column::Boolean(_) => Self::concat_arg_types::<BooleanType>(columns),
             Column::String(_) => {
                 let data_capacity = columns.iter().map(|c| c.memory_size() - c.len() * 8).sum();
@@ -96,6 +105,10 @@ impl Column {
                 let builder = Vec::with_capacity(capacity);
                 Self::concat_value_types::<TimestampType>(builder, columns)
             }
+            Column::TimestampTz(_) => {
+                let builder = Vec::with_capacity(capacity);
+                Self::concat_value_types::<TimestampTzType>(builder, columns)
+            }
             Column::Date(_) => {
                 let builder = Vec::with_capacity(capacity);
                 Self::concat_value_types::<DateType>(builder, columns)
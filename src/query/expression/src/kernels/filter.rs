@@ -21,6 +21,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 
 use crate::types::array::ArrayColumnBuilder;
+use crate::types::decimal::DecimalColumn;
 use crate::types::nullable::NullableColumn;
 use crate::types::number::NumberColumn;
 use crate::types::number::NumberScalar;
@@ -31,6 +32,7 @@ use crate::types::ArrayType;
 use crate::types::BooleanType;
 use crate::types::StringType;
 use crate::types::ValueType;
+use crate::with_decimal_type;
 use crate::with_number_mapped_type;
 use crate::with_number_type;
 use crate::Chunk;
@@ -153,6 +155,13 @@ impl Column {
                     )))
                 }
             }),
+            Column::Decimal(column) => with_decimal_type!(|DECIMAL_TYPE| match column {
+                DecimalColumn::DECIMAL_TYPE(values, size) =>
+                    Column::Decimal(DecimalColumn::DECIMAL_TYPE(
+                        Self::filter_primitive_types(values, filter),
+                        *size
+                    )),
+            }),
             Column::Boolean(bm) => Self::filter_scalar_types::<BooleanType>(
                 bm,
                 MutableBitmap::with_capacity(length),
@@ -167,6 +176,10 @@ impl Column {
                 let ts = Self::filter_primitive_types(column, filter);
                 Column::Timestamp(ts)
             }
+            Column::TimestampTz(column) => {
+                let ts = Self::filter_primitive_types(column, filter);
+                Column::TimestampTz(ts)
+            }
             Column::Date(column) => {
                 let d = Self::filter_primitive_types(column, filter);
                 Column::Date(d)
@@ -28,6 +28,7 @@ use crate::types::DateType;
 use crate::types::NumberType;
 use crate::types::StringType;
 use crate::types::TimestampType;
+use crate::types::TimestampTzType;
 use crate::types::ValueType;
 use crate::types::VariantType;
 use crate::with_number_mapped_type;
@@ -112,6 +113,12 @@ impl Column {
                 indices,
                 scatter_size,
             ),
+            Column::Decimal(_) => Self::scatter_scalars::<AnyType, _>(
+                self,
+                ColumnBuilder::with_capacity(data_type, length),
+                indices,
+                scatter_size,
+            ),
             Column::Boolean(bm) => Self::scatter_scalars::<BooleanType, _>(
                 bm,
                 MutableBitmap::with_capacity(length),
@@ -130,6 +137,12 @@ impl Column {
                 indices,
                 scatter_size,
             ),
+            Column::TimestampTz(column) => Self::scatter_scalars::<TimestampTzType, _>(
+                column,
+                Vec::with_capacity(length),
+                indices,
+                scatter_size,
+            ),
             Column::Date(column) => Self::scatter_scalars::<DateType, _>(
                 column,
                 Vec::with_capacity(length),
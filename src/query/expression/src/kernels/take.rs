@@ -16,6 +16,7 @@ use common_arrow::arrow::types::Index;
 use common_exception::Result;
 
 use crate::types::array::ArrayColumnBuilder;
+use crate::types::decimal::DecimalColumn;
 use crate::types::nullable::NullableColumn;
 use crate::types::number::NumberColumn;
 use crate::types::AnyType;
@@ -24,8 +25,10 @@ use crate::types::ArrayType;
 use crate::types::BooleanType;
 use crate::types::NumberType;
 use crate::types::StringType;
+use crate::types::TimestampTzType;
 use crate::types::ValueType;
 use crate::types::VariantType;
+use crate::with_decimal_type;
 use crate::with_number_mapped_type;
 use crate::Chunk;
 use crate::Column;
@@ -59,6 +62,16 @@ impl Column {
                 NumberColumn::NUM_TYPE(values) =>
                     Self::take_arg_types::<NumberType<NUM_TYPE>, _>(values, indices),
             }),
+            Column::Decimal(column) => with_decimal_type!(|DECIMAL_TYPE| match column {
+                DecimalColumn::DECIMAL_TYPE(values, size) =>
+                    Column::Decimal(DecimalColumn::DECIMAL_TYPE(
+                        indices
+                            .iter()
+                            .map(|index| values[index.to_usize()])
+                            .collect(),
+                        *size,
+                    )),
+            }),
             Column::Boolean(bm) => Self::take_arg_types::<BooleanType, _>(bm, indices),
             Column::String(column) => Self::take_arg_types::<StringType, _>(column, indices),
             Column::Timestamp(column) => {
@@ -69,6 +82,9 @@ impl Column {
                     .unwrap();
                 Column::Timestamp(ts)
             }
+            Column::TimestampTz(column) => {
+                Self::take_arg_types::<TimestampTzType, _>(column, indices)
+            }
             Column::Date(column) => {
                 let d = Self::take_arg_types::<NumberType<i32>, _>(column, indices)
                     .into_number()
@@ -24,6 +24,7 @@ use crate::types::DateType;
 use crate::types::NumberType;
 use crate::types::StringType;
 use crate::types::TimestampType;
+use crate::types::TimestampTzType;
 use crate::types::ValueType;
 use crate::types::VariantType;
 use crate::with_number_mapped_type;
@@ -115,6 +116,10 @@ impl Column {
                     Self::take_chunk_value_types::<NumberType<NUM_TYPE>>(columns, builder, indices)
                 }
             }),
+            Column::Decimal(_) => {
+                let builder = ColumnBuilder::with_capacity(&datatype, result_size);
+                Self::take_chunk_value_types::<AnyType>(columns, builder, indices)
+            }
             Column::Boolean(_) => {
                 let builder = BooleanType::create_builder(result_size, &[]);
                 Self::take_chunk_value_types::<BooleanType>(columns, builder, indices)
@@ -127,6 +132,10 @@ impl Column {
                 let builder = TimestampType::create_builder(result_size, &[]);
                 Self::take_chunk_value_types::<TimestampType>(columns, builder, indices)
             }
+            Column::TimestampTz(_) => {
+                let builder = TimestampTzType::create_builder(result_size, &[]);
+                Self::take_chunk_value_types::<TimestampTzType>(columns, builder, indices)
+            }
             Column::Date(_) => {
                 let builder = DateType::create_builder(result_size, &[]);
                 Self::take_chunk_value_types::<DateType>(columns, builder, indices)
@@ -15,6 +15,7 @@
 use enum_as_inner::EnumAsInner;
 
 use crate::types::boolean::BooleanDomain;
+use crate::types::decimal::DecimalDomain;
 use crate::types::nullable::NullableDomain;
 use crate::types::number::NumberDomain;
 use crate::types::number::NumberScalar;
@@ -39,9 +40,11 @@ impl FunctionProperty {
 #[derive(Debug, Clone, PartialEq, EnumAsInner)]
 pub enum Domain {
     Number(NumberDomain),
+    Decimal(DecimalDomain),
     Boolean(BooleanDomain),
     String(StringDomain),
     Timestamp(SimpleDomain<i64>),
+    TimestampTz(SimpleDomain<i128>),
     Date(SimpleDomain<i32>),
     Nullable(NullableDomain<AnyType>),
     Array(Option<Box<Domain>>),
@@ -62,6 +65,7 @@ impl Domain {
                     _ => unreachable!("unable to merge {this:?} with {other:?}"),
                 })
             }
+            (Domain::Decimal(this), Domain::Decimal(other)) => Domain::Decimal(this.merge(other)),
             (Domain::Boolean(this), Domain::Boolean(other)) => Domain::Boolean(BooleanDomain {
                 has_false: this.has_false || other.has_false,
                 has_true: this.has_true || other.has_true,
@@ -80,6 +84,12 @@ impl Domain {
                     max: this.max.max(other.max),
                 })
             }
+            (Domain::TimestampTz(this), Domain::TimestampTz(other)) => {
+                Domain::TimestampTz(SimpleDomain {
+                    min: this.min.min(other.min),
+                    max: this.max.max(other.max),
+                })
+            }
             (Domain::Date(this), Domain::Date(other)) => Domain::Date(SimpleDomain {
                 min: this.min.min(other.min),
                 max: this.max.max(other.max),
@@ -197,9 +207,13 @@ impl Domain {
             Domain::String(StringDomain { min, max }) if Some(min) == max.as_ref() => {
                 Some(Scalar::String(min.clone()))
             }
+            Domain::Decimal(domain) => domain.as_singleton().map(Scalar::Decimal),
             Domain::Timestamp(SimpleDomain { min, max }) if min == max => {
                 Some(Scalar::Timestamp(*min))
             }
+            Domain::TimestampTz(SimpleDomain { min, max }) if min == max => {
+                Some(Scalar::TimestampTz(*min))
+            }
             Domain::Date(SimpleDomain { min, max }) if min == max => Some(Scalar::Date(*min)),
             Domain::Nullable(NullableDomain {
                 has_null: true,
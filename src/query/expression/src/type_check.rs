@@ -111,7 +111,253 @@ pub fn check(ast: &RawExpr, fn_registry: &FunctionRegistry) -> Result<(Expr, Dat
                 fn_registry,
             )
         }
+        RawExpr::LambdaFunctionCall {
+            span,
+            name,
+            args,
+            lambda_params,
+            lambda_expr,
+        } => check_lambda_function(
+            span.clone(),
+            name,
+            args,
+            lambda_params,
+            lambda_expr,
+            fn_registry,
+        ),
+    }
+}
+
+/// Type checks the higher-order functions `array_transform`, `array_filter` and `array_reduce`.
+///
+/// The lambda parameters are bound to the element type of the array argument, and
+/// for `array_reduce` the accumulator is bound to the common super type of the element
+/// and the lambda result.
+fn check_lambda_function(
+    span: Span,
+    name: &str,
+    args: &[RawExpr],
+    lambda_params: &[String],
+    lambda_expr: &RawExpr,
+    fn_registry: &FunctionRegistry,
+) -> Result<(Expr, DataType)> {
+    let name = name.to_lowercase();
+    let num_params = match name.as_str() {
+        "array_transform" | "array_filter" => 1,
+        "array_reduce" => 2,
+        _ => {
+            return Err((
+                span,
+                format!("function `{name}` does not accept a lambda argument"),
+            ));
+        }
+    };
+    if lambda_params.len() != num_params {
+        return Err((
+            span,
+            format!(
+                "the lambda of `{name}` expects {num_params} parameter(s), but got {}",
+                lambda_params.len()
+            ),
+        ));
     }
+    if args.len() != 1 {
+        return Err((
+            span,
+            format!("`{name}` expects 1 argument, but got {}", args.len()),
+        ));
+    }
+
+    let (arg, arg_type) = check(&args[0], fn_registry)?;
+    let (elem_type, is_nullable, is_empty) = match &arg_type {
+        DataType::Null => (DataType::Null, true, true),
+        DataType::EmptyArray => (DataType::Null, false, true),
+        DataType::Nullable(box DataType::EmptyArray) => (DataType::Null, true, true),
+        DataType::Array(box ty) => (ty.clone(), false, false),
+        DataType::Nullable(box DataType::Array(box ty)) => (ty.clone(), true, false),
+        ty => {
+            return Err((
+                span,
+                format!("`{name}` expects an array as the first argument, but got {ty}"),
+            ));
+        }
+    };
+
+    let (params_type, lambda_expr, return_type) = match name.as_str() {
+        "array_transform" => {
+            let params_type = vec![elem_type];
+            let (lambda_expr, lambda_type) = check(
+                &bind_lambda_params(&name, lambda_expr, &params_type)?,
+                fn_registry,
+            )?;
+            let return_type = if is_empty {
+                DataType::EmptyArray
+            } else {
+                DataType::Array(Box::new(lambda_type))
+            };
+            (params_type, lambda_expr, return_type)
+        }
+        "array_filter" => {
+            let params_type = vec![elem_type.clone()];
+            let (lambda_expr, lambda_type) = check(
+                &bind_lambda_params(&name, lambda_expr, &params_type)?,
+                fn_registry,
+            )?;
+            if !matches!(
+                lambda_type,
+                DataType::Boolean | DataType::Null | DataType::Nullable(box DataType::Boolean)
+            ) {
+                return Err((
+                    span,
+                    format!("the lambda of `{name}` must return a boolean, but got {lambda_type}"),
+                ));
+            }
+            let return_type = if is_empty {
+                DataType::EmptyArray
+            } else {
+                DataType::Array(Box::new(elem_type))
+            };
+            (params_type, lambda_expr, return_type)
+        }
+        "array_reduce" => {
+            // The accumulator must be able to hold both the first element and the result
+            // of the lambda, so widen it until the lambda returns the accumulator type,
+            // e.g. `(acc, x) -> acc + x` on `Array(UInt8)` reduces into `UInt64`.
+            let mut acc_type = elem_type.clone();
+            let (params_type, lambda_expr, lambda_type) = loop {
+                let params_type = vec![acc_type.clone(), elem_type.clone()];
+                let (lambda_expr, lambda_type) = check(
+                    &bind_lambda_params(&name, lambda_expr, &params_type)?,
+                    fn_registry,
+                )?;
+                if lambda_type == acc_type {
+                    break (params_type, lambda_expr, lambda_type);
+                }
+
+                let super_type = common_super_type(acc_type.clone(), lambda_type.clone())
+                    .ok_or_else(|| {
+                        (
+                            span.clone(),
+                            format!(
+                                "the lambda of `{name}` returns {lambda_type}, which is not compatible with the accumulator type {acc_type}"
+                            ),
+                        )
+                    })?;
+                if super_type == acc_type {
+                    let lambda_expr = Expr::Cast {
+                        span: span.clone(),
+                        expr: Box::new(lambda_expr),
+                        dest_type: acc_type.clone(),
+                    };
+                    break (params_type, lambda_expr, acc_type);
+                }
+                acc_type = super_type;
+            };
+            // Reducing an empty array returns NULL.
+            let return_type = match lambda_type {
+                ty @ (DataType::Null | DataType::Nullable(_)) => ty,
+                ty => DataType::Nullable(Box::new(ty)),
+            };
+            (params_type, lambda_expr, return_type)
+        }
+        _ => unreachable!(),
+    };
+
+    let return_type = match return_type {
+        ty @ (DataType::Null | DataType::Nullable(_)) => ty,
+        ty if is_nullable => DataType::Nullable(Box::new(ty)),
+        ty => ty,
+    };
+
+    Ok((
+        Expr::LambdaFunctionCall {
+            span,
+            name,
+            args: vec![arg],
+            lambda_params: lambda_params
+                .iter()
+                .cloned()
+                .zip(params_type.into_iter())
+                .collect(),
+            lambda_expr: Box::new(lambda_expr),
+            return_type: return_type.clone(),
+        },
+        return_type,
+    ))
+}
+
+/// Resolves the data types of the lambda parameters referred by `ColumnRef`.
+///
+/// The lambda is evaluated on its parameters only, so a `ColumnRef` out of the parameters,
+/// which captures a column of the outer chunk, is rejected.
+fn bind_lambda_params(name: &str, expr: &RawExpr, params_type: &[DataType]) -> Result<RawExpr> {
+    Ok(match expr {
+        RawExpr::Literal { .. } => expr.clone(),
+        RawExpr::ColumnRef { span, id, .. } => RawExpr::ColumnRef {
+            span: span.clone(),
+            id: *id,
+            data_type: params_type
+                .get(*id)
+                .ok_or_else(|| {
+                    (
+                        span.clone(),
+                        format!(
+                            "the lambda of `{name}` can only refer to its parameters, captured columns are not supported"
+                        ),
+                    )
+                })?
+                .clone(),
+        },
+        RawExpr::Cast {
+            span,
+            expr,
+            dest_type,
+        } => RawExpr::Cast {
+            span: span.clone(),
+            expr: Box::new(bind_lambda_params(name, expr, params_type)?),
+            dest_type: dest_type.clone(),
+        },
+        RawExpr::TryCast {
+            span,
+            expr,
+            dest_type,
+        } => RawExpr::TryCast {
+            span: span.clone(),
+            expr: Box::new(bind_lambda_params(name, expr, params_type)?),
+            dest_type: dest_type.clone(),
+        },
+        RawExpr::FunctionCall {
+            span,
+            name: func_name,
+            params,
+            args,
+        } => RawExpr::FunctionCall {
+            span: span.clone(),
+            name: func_name.clone(),
+            params: params.clone(),
+            args: args
+                .iter()
+                .map(|arg| bind_lambda_params(name, arg, params_type))
+                .collect::<Result<_>>()?,
+        },
+        // The nested lambda has its own parameters.
+        RawExpr::LambdaFunctionCall {
+            span,
+            name: func_name,
+            args,
+            lambda_params,
+            lambda_expr,
+        } => RawExpr::LambdaFunctionCall {
+            span: span.clone(),
+            name: func_name.clone(),
+            args: args
+                .iter()
+                .map(|arg| bind_lambda_params(name, arg, params_type))
+                .collect::<Result<_>>()?,
+            lambda_params: lambda_params.clone(),
+            lambda_expr: lambda_expr.clone(),
+        },
+    })
 }
 
 fn wrap_nullable_for_try_cast(span: Span, ty: &DataType) -> Result<DataType> {
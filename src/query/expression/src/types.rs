@@ -17,6 +17,7 @@ pub mod arithmetics_type;
 pub mod array;
 pub mod boolean;
 pub mod date;
+pub mod decimal;
 pub mod empty_array;
 pub mod generic;
 pub mod map;
@@ -25,6 +26,7 @@ pub mod nullable;
 pub mod number;
 pub mod string;
 pub mod timestamp;
+pub mod timestamp_tz;
 pub mod variant;
 
 use std::fmt::Debug;
@@ -39,6 +41,7 @@ pub use self::any::AnyType;
 pub use self::array::ArrayType;
 pub use self::boolean::BooleanType;
 pub use self::date::DateType;
+pub use self::decimal::DecimalDataType;
 pub use self::empty_array::EmptyArrayType;
 pub use self::generic::GenericType;
 pub use self::map::MapType;
@@ -48,6 +51,7 @@ pub use self::number::NumberDataType;
 pub use self::number::NumberType;
 pub use self::string::StringType;
 pub use self::timestamp::TimestampType;
+pub use self::timestamp_tz::TimestampTzType;
 pub use self::variant::VariantType;
 use crate::property::Domain;
 use crate::util::concat_array;
@@ -63,7 +67,9 @@ pub enum DataType {
     Boolean,
     String,
     Number(NumberDataType),
+    Decimal(DecimalDataType),
     Timestamp,
+    TimestampTz,
     Date,
     Null,
     Nullable(Box<DataType>),
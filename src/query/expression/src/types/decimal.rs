@@ -0,0 +1,673 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Debug;
+use std::fmt::Display;
+use std::ops::Range;
+
+use common_arrow::arrow::buffer::Buffer;
+use enum_as_inner::EnumAsInner;
+pub use ethnum::i256;
+use itertools::Itertools;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::types::number::SimpleDomain;
+use crate::util::buffer_into_mut;
+
+pub const MAX_DECIMAL128_PRECISION: u8 = 38;
+pub const MAX_DECIMAL256_PRECISION: u8 = 76;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DecimalSize {
+    pub precision: u8,
+    pub scale: u8,
+}
+
+/// Fixed-point decimal with `precision` significant digits, `scale` of them
+/// after the decimal point. Values are stored as integers scaled by `10^scale`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, EnumAsInner)]
+pub enum DecimalDataType {
+    Decimal128(DecimalSize),
+    Decimal256(DecimalSize),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, EnumAsInner)]
+pub enum DecimalScalar {
+    Decimal128(i128, DecimalSize),
+    Decimal256(i256, DecimalSize),
+}
+
+#[derive(Clone, PartialEq, EnumAsInner)]
+pub enum DecimalColumn {
+    Decimal128(Buffer<i128>, DecimalSize),
+    Decimal256(Buffer<i256>, DecimalSize),
+}
+
+#[derive(Debug, Clone, PartialEq, EnumAsInner)]
+pub enum DecimalColumnBuilder {
+    Decimal128(Vec<i128>, DecimalSize),
+    Decimal256(Vec<i256>, DecimalSize),
+}
+
+#[derive(Debug, Clone, PartialEq, EnumAsInner)]
+pub enum DecimalDomain {
+    Decimal128(SimpleDomain<i128>, DecimalSize),
+    Decimal256(SimpleDomain<i256>, DecimalSize),
+}
+
+#[macro_export]
+macro_rules! with_decimal_type {
+    ( | $t:tt | $($tail:tt)* ) => {
+        match_template::match_template! {
+            $t = [Decimal128, Decimal256],
+            $($tail)*
+        }
+    }
+}
+
+/// Integer representation shared by `Decimal128` and `Decimal256`.
+pub trait Decimal:
+    Debug + Display + Copy + Default + PartialEq + Eq + PartialOrd + Ord + Send + Sync + 'static
+{
+    fn zero() -> Self;
+    fn from_i64(value: i64) -> Self;
+    fn from_u64(value: u64) -> Self;
+    fn from_i256(value: i256) -> Option<Self>;
+    fn to_i128(self) -> Option<i128>;
+    fn to_i256(self) -> i256;
+    fn to_f64(self) -> f64;
+
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    fn checked_div(self, rhs: Self) -> Option<Self>;
+    fn checked_rem(self, rhs: Self) -> Option<Self>;
+    fn is_negative(self) -> bool;
+
+    /// Returns `10^n`.
+    fn e(n: u8) -> Self;
+
+    /// The largest absolute value representable with `precision` digits.
+    fn max_of_precision(precision: u8) -> Self {
+        Self::e(precision).checked_sub(Self::from_i64(1)).unwrap()
+    }
+
+    /// Rescales the value from scale `from` to scale `to`, rounding half away
+    /// from zero when digits are dropped. Returns `None` on overflow.
+    fn rescale(self, from: u8, to: u8) -> Option<Self> {
+        if to >= from {
+            self.checked_mul(Self::e(to - from))
+        } else {
+            let divisor = Self::e(from - to);
+            let quotient = self.checked_div(divisor)?;
+            let remainder = self.checked_rem(divisor)?;
+            let doubled = remainder.checked_add(remainder)?;
+            let half_reached = if self.is_negative() {
+                doubled <= Self::zero().checked_sub(divisor)?
+            } else {
+                doubled >= divisor
+            };
+            match (half_reached, self.is_negative()) {
+                (false, _) => Some(quotient),
+                (true, false) => quotient.checked_add(Self::from_i64(1)),
+                (true, true) => quotient.checked_sub(Self::from_i64(1)),
+            }
+        }
+    }
+}
+
+impl Decimal for i128 {
+    fn zero() -> Self {
+        0
+    }
+
+    fn from_i64(value: i64) -> Self {
+        value as i128
+    }
+
+    fn from_u64(value: u64) -> Self {
+        value as i128
+    }
+
+    fn from_i256(value: i256) -> Option<Self> {
+        value.to_i128()
+    }
+
+    fn to_i128(self) -> Option<i128> {
+        Some(self)
+    }
+
+    fn to_i256(self) -> i256 {
+        i256::from(self)
+    }
+
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+
+    fn checked_add(self, rhs: Self) -> Option<Self> {
+        i128::checked_add(self, rhs)
+    }
+
+    fn checked_sub(self, rhs: Self) -> Option<Self> {
+        i128::checked_sub(self, rhs)
+    }
+
+    fn checked_mul(self, rhs: Self) -> Option<Self> {
+        i128::checked_mul(self, rhs)
+    }
+
+    fn checked_div(self, rhs: Self) -> Option<Self> {
+        i128::checked_div(self, rhs)
+    }
+
+    fn checked_rem(self, rhs: Self) -> Option<Self> {
+        i128::checked_rem(self, rhs)
+    }
+
+    fn is_negative(self) -> bool {
+        self < 0
+    }
+
+    fn e(n: u8) -> Self {
+        10_i128.pow(n as u32)
+    }
+}
+
+impl Decimal for i256 {
+    fn zero() -> Self {
+        i256::ZERO
+    }
+
+    fn from_i64(value: i64) -> Self {
+        i256::from(value)
+    }
+
+    fn from_u64(value: u64) -> Self {
+        i256::from(value)
+    }
+
+    fn from_i256(value: i256) -> Option<Self> {
+        Some(value)
+    }
+
+    fn to_i128(self) -> Option<i128> {
+        if self >= i256::from(i128::MIN) && self <= i256::from(i128::MAX) {
+            Some(self.as_i128())
+        } else {
+            None
+        }
+    }
+
+    fn to_i256(self) -> i256 {
+        self
+    }
+
+    fn to_f64(self) -> f64 {
+        self.as_f64()
+    }
+
+    fn checked_add(self, rhs: Self) -> Option<Self> {
+        i256::checked_add(self, rhs)
+    }
+
+    fn checked_sub(self, rhs: Self) -> Option<Self> {
+        i256::checked_sub(self, rhs)
+    }
+
+    fn checked_mul(self, rhs: Self) -> Option<Self> {
+        i256::checked_mul(self, rhs)
+    }
+
+    fn checked_div(self, rhs: Self) -> Option<Self> {
+        i256::checked_div(self, rhs)
+    }
+
+    fn checked_rem(self, rhs: Self) -> Option<Self> {
+        i256::checked_rem(self, rhs)
+    }
+
+    fn is_negative(self) -> bool {
+        self < i256::ZERO
+    }
+
+    fn e(n: u8) -> Self {
+        i256::new(10).pow(n as u32)
+    }
+}
+
+impl DecimalDataType {
+    /// Chooses the narrowest representation able to hold `size`.
+    pub fn from_size(size: DecimalSize) -> Result<DecimalDataType, String> {
+        if size.precision == 0 || size.precision > MAX_DECIMAL256_PRECISION {
+            return Err(format!(
+                "Decimal precision must be between 1 and {}, but got {}",
+                MAX_DECIMAL256_PRECISION, size.precision
+            ));
+        }
+        if size.scale > size.precision {
+            return Err(format!(
+                "Decimal scale must be between 0 and precision {}, but got {}",
+                size.precision, size.scale
+            ));
+        }
+        if size.precision <= MAX_DECIMAL128_PRECISION {
+            Ok(DecimalDataType::Decimal128(size))
+        } else {
+            Ok(DecimalDataType::Decimal256(size))
+        }
+    }
+
+    pub fn size(&self) -> DecimalSize {
+        crate::with_decimal_type!(|DECIMAL_TYPE| match self {
+            DecimalDataType::DECIMAL_TYPE(size) => *size,
+        })
+    }
+
+    pub fn precision(&self) -> u8 {
+        self.size().precision
+    }
+
+    pub fn scale(&self) -> u8 {
+        self.size().scale
+    }
+
+    /// Parses a decimal literal such as `-123.456` or `1.5e3`, rounding the
+    /// fractional part to the scale of this type.
+    pub fn parse_scalar(&self, text: &[u8]) -> Result<DecimalScalar, String> {
+        match self {
+            DecimalDataType::Decimal128(size) => {
+                let value = parse_decimal::<i128>(text, *size)?;
+                Ok(DecimalScalar::Decimal128(value, *size))
+            }
+            DecimalDataType::Decimal256(size) => {
+                let value = parse_decimal::<i256>(text, *size)?;
+                Ok(DecimalScalar::Decimal256(value, *size))
+            }
+        }
+    }
+}
+
+impl DecimalScalar {
+    pub fn size(&self) -> DecimalSize {
+        crate::with_decimal_type!(|DECIMAL_TYPE| match self {
+            DecimalScalar::DECIMAL_TYPE(_, size) => *size,
+        })
+    }
+
+    pub fn data_type(&self) -> DecimalDataType {
+        crate::with_decimal_type!(|DECIMAL_TYPE| match self {
+            DecimalScalar::DECIMAL_TYPE(_, size) => DecimalDataType::DECIMAL_TYPE(*size),
+        })
+    }
+
+    pub fn domain(&self) -> DecimalDomain {
+        crate::with_decimal_type!(|DECIMAL_TYPE| match self {
+            DecimalScalar::DECIMAL_TYPE(value, size) => DecimalDomain::DECIMAL_TYPE(
+                SimpleDomain {
+                    min: *value,
+                    max: *value,
+                },
+                *size
+            ),
+        })
+    }
+
+    pub fn is_zero(&self) -> bool {
+        crate::with_decimal_type!(|DECIMAL_TYPE| match self {
+            DecimalScalar::DECIMAL_TYPE(value, _) => *value == Decimal::zero(),
+        })
+    }
+
+    pub fn to_f64(&self) -> f64 {
+        crate::with_decimal_type!(|DECIMAL_TYPE| match self {
+            DecimalScalar::DECIMAL_TYPE(value, size) =>
+                value.to_f64() / 10_f64.powi(size.scale as i32),
+        })
+    }
+
+    /// Converts the value to another decimal type, returning `None` if it does
+    /// not fit into the target precision.
+    pub fn cast_to(&self, dest_type: &DecimalDataType) -> Option<DecimalScalar> {
+        let from_scale = self.size().scale;
+        let value = crate::with_decimal_type!(|DECIMAL_TYPE| match self {
+            DecimalScalar::DECIMAL_TYPE(value, _) => value.to_i256(),
+        });
+        let value = value.rescale(from_scale, dest_type.scale())?;
+        if value.abs() > i256::max_of_precision(dest_type.precision()) {
+            return None;
+        }
+        match dest_type {
+            DecimalDataType::Decimal128(size) => {
+                Some(DecimalScalar::Decimal128(value.to_i128()?, *size))
+            }
+            DecimalDataType::Decimal256(size) => Some(DecimalScalar::Decimal256(value, *size)),
+        }
+    }
+
+    pub fn from_i64(value: i64, dest_type: &DecimalDataType) -> Option<DecimalScalar> {
+        DecimalScalar::Decimal256(i256::from(value), DecimalSize {
+            precision: MAX_DECIMAL256_PRECISION,
+            scale: 0,
+        })
+        .cast_to(dest_type)
+    }
+
+    pub fn from_u64(value: u64, dest_type: &DecimalDataType) -> Option<DecimalScalar> {
+        DecimalScalar::Decimal256(i256::from(value), DecimalSize {
+            precision: MAX_DECIMAL256_PRECISION,
+            scale: 0,
+        })
+        .cast_to(dest_type)
+    }
+
+    pub fn from_f64(value: f64, dest_type: &DecimalDataType) -> Option<DecimalScalar> {
+        let scaled = (value * 10_f64.powi(dest_type.scale() as i32)).round();
+        if !scaled.is_finite() || scaled.abs() >= 10_f64.powi(dest_type.precision() as i32) {
+            return None;
+        }
+        match dest_type {
+            DecimalDataType::Decimal128(size) => {
+                Some(DecimalScalar::Decimal128(scaled as i128, *size))
+            }
+            DecimalDataType::Decimal256(size) => {
+                Some(DecimalScalar::Decimal256(i256::from(scaled as i128), *size))
+            }
+        }
+    }
+
+    /// Converts the value to an integer, rounding half away from zero.
+    pub fn to_i128(&self) -> Option<i128> {
+        crate::with_decimal_type!(|DECIMAL_TYPE| match self {
+            DecimalScalar::DECIMAL_TYPE(value, size) => value.rescale(size.scale, 0)?.to_i128(),
+        })
+    }
+}
+
+impl PartialOrd for DecimalScalar {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        match (self, other) {
+            (DecimalScalar::Decimal128(v1, s1), DecimalScalar::Decimal128(v2, s2)) if s1 == s2 => {
+                v1.partial_cmp(v2)
+            }
+            (DecimalScalar::Decimal256(v1, s1), DecimalScalar::Decimal256(v2, s2)) if s1 == s2 => {
+                v1.partial_cmp(v2)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl DecimalColumn {
+    pub fn len(&self) -> usize {
+        crate::with_decimal_type!(|DECIMAL_TYPE| match self {
+            DecimalColumn::DECIMAL_TYPE(col, _) => col.len(),
+        })
+    }
+
+    pub fn size(&self) -> DecimalSize {
+        crate::with_decimal_type!(|DECIMAL_TYPE| match self {
+            DecimalColumn::DECIMAL_TYPE(_, size) => *size,
+        })
+    }
+
+    pub fn data_type(&self) -> DecimalDataType {
+        crate::with_decimal_type!(|DECIMAL_TYPE| match self {
+            DecimalColumn::DECIMAL_TYPE(_, size) => DecimalDataType::DECIMAL_TYPE(*size),
+        })
+    }
+
+    pub fn index(&self, index: usize) -> Option<DecimalScalar> {
+        crate::with_decimal_type!(|DECIMAL_TYPE| match self {
+            DecimalColumn::DECIMAL_TYPE(col, size) =>
+                Some(DecimalScalar::DECIMAL_TYPE(col.get(index).cloned()?, *size)),
+        })
+    }
+
+    /// # Safety
+    ///
+    /// Calling this method with an out-of-bounds index is *[undefined behavior]*
+    pub unsafe fn index_unchecked(&self, index: usize) -> DecimalScalar {
+        crate::with_decimal_type!(|DECIMAL_TYPE| match self {
+            DecimalColumn::DECIMAL_TYPE(col, size) =>
+                DecimalScalar::DECIMAL_TYPE(*col.get_unchecked(index), *size),
+        })
+    }
+
+    pub fn slice(&self, range: Range<usize>) -> Self {
+        crate::with_decimal_type!(|DECIMAL_TYPE| match self {
+            DecimalColumn::DECIMAL_TYPE(col, size) => DecimalColumn::DECIMAL_TYPE(
+                col.clone().slice(range.start, range.end - range.start),
+                *size,
+            ),
+        })
+    }
+
+    pub fn domain(&self) -> DecimalDomain {
+        assert!(self.len() > 0);
+        crate::with_decimal_type!(|DECIMAL_TYPE| match self {
+            DecimalColumn::DECIMAL_TYPE(col, size) => {
+                let (min, max) = col.iter().minmax().into_option().unwrap();
+                DecimalDomain::DECIMAL_TYPE(
+                    SimpleDomain {
+                        min: *min,
+                        max: *max,
+                    },
+                    *size,
+                )
+            }
+        })
+    }
+
+    pub fn memory_size(&self) -> usize {
+        match self {
+            DecimalColumn::Decimal128(col, _) => col.len() * 16,
+            DecimalColumn::Decimal256(col, _) => col.len() * 32,
+        }
+    }
+}
+
+impl DecimalColumnBuilder {
+    pub fn from_column(col: DecimalColumn) -> Self {
+        crate::with_decimal_type!(|DECIMAL_TYPE| match col {
+            DecimalColumn::DECIMAL_TYPE(col, size) =>
+                DecimalColumnBuilder::DECIMAL_TYPE(buffer_into_mut(col), size),
+        })
+    }
+
+    pub fn repeat(scalar: DecimalScalar, n: usize) -> DecimalColumnBuilder {
+        crate::with_decimal_type!(|DECIMAL_TYPE| match scalar {
+            DecimalScalar::DECIMAL_TYPE(value, size) =>
+                DecimalColumnBuilder::DECIMAL_TYPE(vec![value; n], size),
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        crate::with_decimal_type!(|DECIMAL_TYPE| match self {
+            DecimalColumnBuilder::DECIMAL_TYPE(col, _) => col.len(),
+        })
+    }
+
+    pub fn with_capacity(ty: &DecimalDataType, capacity: usize) -> Self {
+        crate::with_decimal_type!(|DECIMAL_TYPE| match ty {
+            DecimalDataType::DECIMAL_TYPE(size) =>
+                DecimalColumnBuilder::DECIMAL_TYPE(Vec::with_capacity(capacity), *size),
+        })
+    }
+
+    pub fn push(&mut self, item: DecimalScalar) {
+        crate::with_decimal_type!(|DECIMAL_TYPE| match (self, item) {
+            (
+                DecimalColumnBuilder::DECIMAL_TYPE(builder, builder_size),
+                DecimalScalar::DECIMAL_TYPE(value, size),
+            ) => {
+                debug_assert_eq!(*builder_size, size);
+                builder.push(value)
+            }
+            (builder, scalar) => unreachable!("unable to push {scalar:?} to {builder:?}"),
+        })
+    }
+
+    pub fn push_default(&mut self) {
+        crate::with_decimal_type!(|DECIMAL_TYPE| match self {
+            DecimalColumnBuilder::DECIMAL_TYPE(builder, _) => builder.push(Decimal::zero()),
+        })
+    }
+
+    pub fn append(&mut self, other: &DecimalColumnBuilder) {
+        crate::with_decimal_type!(|DECIMAL_TYPE| match (self, other) {
+            (
+                DecimalColumnBuilder::DECIMAL_TYPE(builder, _),
+                DecimalColumnBuilder::DECIMAL_TYPE(other_builder, _),
+            ) => {
+                builder.extend_from_slice(other_builder);
+            }
+            (this, other) => unreachable!("unable append {other:?} onto {this:?}"),
+        })
+    }
+
+    pub fn build(self) -> DecimalColumn {
+        crate::with_decimal_type!(|DECIMAL_TYPE| match self {
+            DecimalColumnBuilder::DECIMAL_TYPE(builder, size) =>
+                DecimalColumn::DECIMAL_TYPE(builder.into(), size),
+        })
+    }
+
+    pub fn build_scalar(self) -> DecimalScalar {
+        assert_eq!(self.len(), 1);
+
+        crate::with_decimal_type!(|DECIMAL_TYPE| match self {
+            DecimalColumnBuilder::DECIMAL_TYPE(builder, size) =>
+                DecimalScalar::DECIMAL_TYPE(builder[0], size),
+        })
+    }
+}
+
+impl DecimalDomain {
+    pub fn size(&self) -> DecimalSize {
+        crate::with_decimal_type!(|DECIMAL_TYPE| match self {
+            DecimalDomain::DECIMAL_TYPE(_, size) => *size,
+        })
+    }
+
+    pub fn merge(&self, other: &DecimalDomain) -> DecimalDomain {
+        crate::with_decimal_type!(|DECIMAL_TYPE| match (self, other) {
+            (DecimalDomain::DECIMAL_TYPE(this, size), DecimalDomain::DECIMAL_TYPE(other, _)) => {
+                DecimalDomain::DECIMAL_TYPE(
+                    SimpleDomain {
+                        min: this.min.min(other.min),
+                        max: this.max.max(other.max),
+                    },
+                    *size,
+                )
+            }
+            (this, other) => unreachable!("unable to merge {this:?} with {other:?}"),
+        })
+    }
+
+    pub fn as_singleton(&self) -> Option<DecimalScalar> {
+        crate::with_decimal_type!(|DECIMAL_TYPE| match self {
+            DecimalDomain::DECIMAL_TYPE(SimpleDomain { min, max }, size) if min == max => {
+                Some(DecimalScalar::DECIMAL_TYPE(*min, *size))
+            }
+            _ => None,
+        })
+    }
+}
+
+/// Formats a scaled integer, e.g. `12345` with scale 2 as `123.45`.
+pub fn display_decimal<T: Decimal>(value: T, scale: u8) -> String {
+    let digits = value.to_string();
+    let (sign, digits) = match digits.strip_prefix('-') {
+        Some(digits) => ("-", digits),
+        None => ("", digits.as_str()),
+    };
+    if scale == 0 {
+        return format!("{sign}{digits}");
+    }
+    let scale = scale as usize;
+    let digits = format!("{digits:0>width$}", width = scale + 1);
+    let (integral, fractional) = digits.split_at(digits.len() - scale);
+    format!("{sign}{integral}.{fractional}")
+}
+
+fn parse_decimal<T: Decimal>(text: &[u8], size: DecimalSize) -> Result<T, String> {
+    let invalid = || {
+        format!(
+            "unable to cast '{}' to Decimal({}, {})",
+            String::from_utf8_lossy(text),
+            size.precision,
+            size.scale
+        )
+    };
+    let text = std::str::from_utf8(text).map_err(|_| invalid())?.trim();
+    let (mantissa, exponent) = match text.find(|c| c == 'e' || c == 'E') {
+        Some(pos) => (
+            &text[..pos],
+            text[pos + 1..].parse::<i32>().map_err(|_| invalid())?,
+        ),
+        None => (text, 0),
+    };
+    let (negative, mantissa) = match mantissa.as_bytes().first() {
+        Some(b'-') => (true, &mantissa[1..]),
+        Some(b'+') => (false, &mantissa[1..]),
+        _ => (false, mantissa),
+    };
+    let (integral, fractional) = match mantissa.find('.') {
+        Some(pos) => (&mantissa[..pos], &mantissa[pos + 1..]),
+        None => (mantissa, ""),
+    };
+    if integral.is_empty() && fractional.is_empty()
+        || !integral
+            .bytes()
+            .chain(fractional.bytes())
+            .all(|c| c.is_ascii_digit())
+    {
+        return Err(invalid());
+    }
+
+    // Accumulate every digit with 256-bit headroom, then move the decimal
+    // point to the target scale.
+    let mut value = i256::ZERO;
+    for c in integral.bytes().chain(fractional.bytes()) {
+        value = value
+            .checked_mul(i256::new(10))
+            .and_then(|v| v.checked_add(i256::from(c - b'0')))
+            .ok_or_else(invalid)?;
+    }
+    let current_scale = fractional.len() as i32 - exponent;
+    let value = if current_scale > size.scale as i32 {
+        let dropped = current_scale - size.scale as i32;
+        if dropped > MAX_DECIMAL256_PRECISION as i32 {
+            i256::ZERO
+        } else {
+            value.rescale(dropped as u8, 0).ok_or_else(invalid)?
+        }
+    } else {
+        let added = size.scale as i32 - current_scale;
+        if added > MAX_DECIMAL256_PRECISION as i32 {
+            return Err(invalid());
+        }
+        value.rescale(0, added as u8).ok_or_else(invalid)?
+    };
+    if value > i256::max_of_precision(size.precision) {
+        return Err(format!(
+            "Decimal overflow: '{}' does not fit into Decimal({}, {})",
+            text, size.precision, size.scale
+        ));
+    }
+    let value = if negative { -value } else { value };
+    T::from_i256(value).ok_or_else(invalid)
+}
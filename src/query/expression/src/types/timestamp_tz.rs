@@ -0,0 +1,290 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::ops::Range;
+
+use chrono::DateTime;
+use chrono::FixedOffset;
+use chrono::Offset;
+use chrono::TimeZone;
+use chrono_tz::Tz;
+use common_arrow::arrow::buffer::Buffer;
+
+use super::number::SimpleDomain;
+use crate::property::Domain;
+use crate::types::timestamp::check_timestamp;
+use crate::types::timestamp::string_to_timestamp;
+use crate::types::timestamp::MICROS_IN_A_SEC;
+use crate::types::ArgType;
+use crate::types::DataType;
+use crate::types::GenericMap;
+use crate::types::ValueType;
+use crate::util::buffer_into_mut;
+use crate::values::Column;
+use crate::values::Scalar;
+use crate::ColumnBuilder;
+use crate::ScalarRef;
+
+/// The largest UTC offset allowed by ISO 8601, in seconds.
+pub const TIMESTAMP_TZ_MAX_OFFSET: i32 = 14 * 3600;
+
+/// Timestamp with the UTC offset it was written with, laid out the same as the
+/// `TIMESTAMP_TZ` of `common_datavalues`: the UTC microseconds in the high 64 bits
+/// and the offset in seconds in the low 32 bits.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimestampTzType;
+
+impl ValueType for TimestampTzType {
+    type Scalar = i128;
+    type ScalarRef<'a> = i128;
+    type Column = Buffer<i128>;
+    type Domain = SimpleDomain<i128>;
+    type ColumnIterator<'a> = std::iter::Cloned<std::slice::Iter<'a, i128>>;
+    type ColumnBuilder = Vec<i128>;
+
+    #[inline]
+    fn upcast_gat<'short, 'long: 'short>(long: i128) -> i128 {
+        long
+    }
+
+    fn to_owned_scalar<'a>(scalar: Self::ScalarRef<'a>) -> Self::Scalar {
+        scalar
+    }
+
+    fn to_scalar_ref<'a>(scalar: &'a Self::Scalar) -> Self::ScalarRef<'a> {
+        *scalar
+    }
+
+    fn try_downcast_scalar<'a>(scalar: &'a ScalarRef) -> Option<Self::ScalarRef<'a>> {
+        match scalar {
+            ScalarRef::TimestampTz(scalar) => Some(*scalar),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_column<'a>(col: &'a Column) -> Option<Self::Column> {
+        match col {
+            Column::TimestampTz(column) => Some(column.clone()),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_domain(domain: &Domain) -> Option<SimpleDomain<i128>> {
+        domain.as_timestamp_tz().map(SimpleDomain::clone)
+    }
+
+    fn try_downcast_builder<'a>(
+        builder: &'a mut ColumnBuilder,
+    ) -> Option<&'a mut Self::ColumnBuilder> {
+        match builder {
+            ColumnBuilder::TimestampTz(builder) => Some(builder),
+            _ => None,
+        }
+    }
+
+    fn upcast_scalar(scalar: Self::Scalar) -> Scalar {
+        Scalar::TimestampTz(scalar)
+    }
+
+    fn upcast_column(col: Self::Column) -> Column {
+        Column::TimestampTz(col)
+    }
+
+    fn upcast_domain(domain: SimpleDomain<i128>) -> Domain {
+        Domain::TimestampTz(domain)
+    }
+
+    fn column_len<'a>(col: &'a Self::Column) -> usize {
+        col.len()
+    }
+
+    fn index_column<'a>(col: &'a Self::Column, index: usize) -> Option<Self::ScalarRef<'a>> {
+        col.get(index).cloned()
+    }
+
+    unsafe fn index_column_unchecked<'a>(
+        col: &'a Self::Column,
+        index: usize,
+    ) -> Self::ScalarRef<'a> {
+        *col.get_unchecked(index)
+    }
+
+    fn slice_column<'a>(col: &'a Self::Column, range: Range<usize>) -> Self::Column {
+        col.clone().slice(range.start, range.end - range.start)
+    }
+
+    fn iter_column<'a>(col: &'a Self::Column) -> Self::ColumnIterator<'a> {
+        col.iter().cloned()
+    }
+
+    fn column_to_builder(col: Self::Column) -> Self::ColumnBuilder {
+        buffer_into_mut(col)
+    }
+
+    fn builder_len(builder: &Self::ColumnBuilder) -> usize {
+        builder.len()
+    }
+
+    fn push_item(builder: &mut Self::ColumnBuilder, item: Self::Scalar) {
+        builder.push(item);
+    }
+
+    fn push_default(builder: &mut Self::ColumnBuilder) {
+        builder.push(Self::Scalar::default());
+    }
+
+    fn append_builder(builder: &mut Self::ColumnBuilder, other: &Self::ColumnBuilder) {
+        builder.extend_from_slice(other);
+    }
+
+    fn build_column(builder: Self::ColumnBuilder) -> Self::Column {
+        builder.into()
+    }
+
+    fn build_scalar(builder: Self::ColumnBuilder) -> Self::Scalar {
+        assert_eq!(builder.len(), 1);
+        builder[0]
+    }
+}
+
+impl ArgType for TimestampTzType {
+    fn data_type() -> DataType {
+        DataType::TimestampTz
+    }
+
+    fn create_builder(capacity: usize, _generics: &GenericMap) -> Self::ColumnBuilder {
+        Vec::with_capacity(capacity)
+    }
+
+    fn column_from_vec(vec: Vec<Self::Scalar>, _generics: &GenericMap) -> Self::Column {
+        vec.into()
+    }
+
+    fn column_from_iter(iter: impl Iterator<Item = Self::Scalar>, _: &GenericMap) -> Self::Column {
+        iter.collect()
+    }
+
+    fn column_from_ref_iter<'a>(
+        iter: impl Iterator<Item = Self::ScalarRef<'a>>,
+        _: &GenericMap,
+    ) -> Self::Column {
+        iter.collect()
+    }
+}
+
+/// Pack the UTC microseconds and the offset in seconds into a `TIMESTAMP_TZ` value.
+#[inline]
+pub fn make_timestamp_tz(micros: i64, offset: i32) -> i128 {
+    ((micros as i128) << 64) | (offset as u32 as i128)
+}
+
+/// The UTC microseconds of a `TIMESTAMP_TZ` value.
+#[inline]
+pub fn timestamp_tz_micros(value: i128) -> i64 {
+    (value >> 64) as i64
+}
+
+/// The offset in seconds east of UTC of a `TIMESTAMP_TZ` value.
+#[inline]
+pub fn timestamp_tz_offset(value: i128) -> i32 {
+    value as u32 as i32
+}
+
+#[inline]
+pub fn timestamp_tz_to_datetime(value: i128) -> DateTime<FixedOffset> {
+    let micros = timestamp_tz_micros(value);
+    let offset = FixedOffset::east(timestamp_tz_offset(value));
+    offset.timestamp(
+        micros.div_euclid(MICROS_IN_A_SEC),
+        (micros.rem_euclid(MICROS_IN_A_SEC) * 1_000) as u32,
+    )
+}
+
+/// Keeps the offset the time zone has at that instant.
+#[inline]
+pub fn timestamp_tz_from_datetime<T: TimeZone>(dt: &DateTime<T>) -> Result<i128, String> {
+    let offset = dt.offset().fix().local_minus_utc();
+    check_timestamp_tz(make_timestamp_tz(dt.timestamp_micros(), offset))
+}
+
+/// check the micros and the offset in timestamp_tz value.
+#[inline]
+pub fn check_timestamp_tz(value: i128) -> Result<i128, String> {
+    check_timestamp(timestamp_tz_micros(value))?;
+    if timestamp_tz_offset(value).abs() > TIMESTAMP_TZ_MAX_OFFSET {
+        return Err(format!(
+            "timestamp_tz offset `{}` is out of range",
+            timestamp_tz_offset(value)
+        ));
+    }
+    Ok(value)
+}
+
+#[inline]
+pub fn timestamp_tz_to_string(value: i128) -> String {
+    timestamp_tz_to_datetime(value)
+        .format("%Y-%m-%d %H:%M:%S%.6f %:z")
+        .to_string()
+}
+
+/// Parse `2022-03-27 01:30:00[.ffffff][ ][Z|+hh:mm|+hhmm|+hh]`, a value without an
+/// offset gets the offset of `tz` at that local time.
+pub fn string_to_timestamp_tz(ts_str: impl AsRef<[u8]>, tz: &Tz) -> Option<i128> {
+    let text = std::str::from_utf8(ts_str.as_ref()).ok()?.trim();
+    let value = match split_timestamp_offset(text)? {
+        (datetime, Some(offset)) => {
+            let local = string_to_timestamp(datetime, &Tz::UTC)?;
+            let micros = local.timestamp_micros() - offset as i64 * MICROS_IN_A_SEC;
+            make_timestamp_tz(micros, offset)
+        }
+        (datetime, None) => timestamp_tz_from_datetime(&string_to_timestamp(datetime, tz)?).ok()?,
+    };
+    check_timestamp_tz(value).ok()
+}
+
+// Split the trailing offset from the date and time, the date part `YYYY-MM-DD`
+// itself contains `-` so the offset is only searched after it.
+fn split_timestamp_offset(text: &str) -> Option<(&str, Option<i32>)> {
+    const DATE_LEN: usize = 10;
+    if text.len() <= DATE_LEN {
+        return Some((text, None));
+    }
+    if let Some(datetime) = text.strip_suffix(|c| c == 'Z' || c == 'z') {
+        return Some((datetime.trim_end(), Some(0)));
+    }
+    let pos = match text[DATE_LEN..].rfind(|c| c == '+' || c == '-') {
+        Some(pos) => pos + DATE_LEN,
+        None => return Some((text, None)),
+    };
+
+    let digits: String = text[pos + 1..].chars().filter(|c| *c != ':').collect();
+    let (hours, minutes) = match digits.len() {
+        2 => (digits.parse::<i32>().ok()?, 0),
+        4 => (
+            digits[..2].parse::<i32>().ok()?,
+            digits[2..].parse::<i32>().ok()?,
+        ),
+        _ => return None,
+    };
+    let offset = hours * 3600 + minutes * 60;
+    if minutes >= 60 || offset > TIMESTAMP_TZ_MAX_OFFSET {
+        return None;
+    }
+    let offset = if text.as_bytes()[pos] == b'-' {
+        -offset
+    } else {
+        offset
+    };
+    Some((text[..pos].trim_end(), Some(offset)))
+}
@@ -19,6 +19,7 @@ use crate::property::Domain;
 use crate::types::string::StringColumn;
 use crate::types::string::StringColumnBuilder;
 use crate::types::string::StringIterator;
+use crate::types::timestamp_tz::timestamp_tz_to_string;
 use crate::types::ArgType;
 use crate::types::DataType;
 use crate::types::GenericMap;
@@ -172,9 +173,13 @@ pub fn cast_scalar_to_variant(scalar: ScalarRef, buf: &mut Vec<u8>) {
             NumberScalar::Float32(n) => n.0.into(),
             NumberScalar::Float64(n) => n.0.into(),
         },
+        ScalarRef::Decimal(d) => d.to_f64().into(),
         ScalarRef::Boolean(b) => common_jsonb::Value::Bool(b),
         ScalarRef::String(s) => common_jsonb::Value::String(String::from_utf8_lossy(s)),
         ScalarRef::Timestamp(ts) => ts.into(),
+        ScalarRef::TimestampTz(ts) => {
+            common_jsonb::Value::String(timestamp_tz_to_string(ts).into())
+        }
         ScalarRef::Date(d) => d.into(),
         ScalarRef::Array(col) => {
             let items = cast_scalars_to_variants(col.iter());
@@ -33,6 +33,10 @@ use crate::property::Domain;
 use crate::types::array::ArrayColumn;
 use crate::types::array::ArrayColumnBuilder;
 use crate::types::boolean::BooleanDomain;
+use crate::types::decimal::DecimalColumn;
+use crate::types::decimal::DecimalColumnBuilder;
+use crate::types::decimal::DecimalScalar;
+use crate::types::decimal::DecimalSize;
 use crate::types::nullable::NullableColumn;
 use crate::types::nullable::NullableColumnBuilder;
 use crate::types::nullable::NullableDomain;
@@ -53,6 +57,7 @@ use crate::util::buffer_into_mut;
 use crate::util::constant_bitmap;
 use crate::util::deserialize_arrow_array;
 use crate::util::serialize_arrow_array;
+use crate::with_decimal_type;
 use crate::with_number_type;
 
 #[derive(Debug, Clone, EnumAsInner)]
@@ -73,7 +78,9 @@ pub enum Scalar {
     Null,
     EmptyArray,
     Number(NumberScalar),
+    Decimal(DecimalScalar),
     Timestamp(i64),
+    TimestampTz(i128),
     Date(i32),
     Boolean(bool),
     String(Vec<u8>),
@@ -88,9 +95,11 @@ pub enum ScalarRef<'a> {
     Null,
     EmptyArray,
     Number(NumberScalar),
+    Decimal(DecimalScalar),
     Boolean(bool),
     String(&'a [u8]),
     Timestamp(i64),
+    TimestampTz(i128),
     Date(i32),
     Array(Column),
     Tuple(Vec<ScalarRef<'a>>),
@@ -102,9 +111,11 @@ pub enum Column {
     Null { len: usize },
     EmptyArray { len: usize },
     Number(NumberColumn),
+    Decimal(DecimalColumn),
     Boolean(Bitmap),
     String(StringColumn),
     Timestamp(Buffer<i64>),
+    TimestampTz(Buffer<i128>),
     Date(Buffer<i32>),
     Array(Box<ArrayColumn<AnyType>>),
     Nullable(Box<NullableColumn<AnyType>>),
@@ -121,9 +132,11 @@ pub enum ColumnBuilder {
         len: usize,
     },
     Number(NumberColumnBuilder),
+    Decimal(DecimalColumnBuilder),
     Boolean(MutableBitmap),
     String(StringColumnBuilder),
     Timestamp(Vec<i64>),
+    TimestampTz(Vec<i128>),
     Date(Vec<i32>),
     Array(Box<ArrayColumnBuilder<AnyType>>),
     Nullable(Box<NullableColumnBuilder<AnyType>>),
@@ -209,9 +222,11 @@ impl Scalar {
             Scalar::Null => ScalarRef::Null,
             Scalar::EmptyArray => ScalarRef::EmptyArray,
             Scalar::Number(n) => ScalarRef::Number(*n),
+            Scalar::Decimal(d) => ScalarRef::Decimal(*d),
             Scalar::Boolean(b) => ScalarRef::Boolean(*b),
             Scalar::String(s) => ScalarRef::String(s.as_slice()),
             Scalar::Timestamp(t) => ScalarRef::Timestamp(*t),
+            Scalar::TimestampTz(t) => ScalarRef::TimestampTz(*t),
             Scalar::Date(d) => ScalarRef::Date(*d),
             Scalar::Array(col) => ScalarRef::Array(col.clone()),
             Scalar::Tuple(fields) => ScalarRef::Tuple(fields.iter().map(Scalar::as_ref).collect()),
@@ -226,9 +241,11 @@ impl<'a> ScalarRef<'a> {
             ScalarRef::Null => Scalar::Null,
             ScalarRef::EmptyArray => Scalar::EmptyArray,
             ScalarRef::Number(n) => Scalar::Number(*n),
+            ScalarRef::Decimal(d) => Scalar::Decimal(*d),
             ScalarRef::Boolean(b) => Scalar::Boolean(*b),
             ScalarRef::String(s) => Scalar::String(s.to_vec()),
             ScalarRef::Timestamp(t) => Scalar::Timestamp(*t),
+            ScalarRef::TimestampTz(t) => Scalar::TimestampTz(*t),
             ScalarRef::Date(d) => Scalar::Date(*d),
             ScalarRef::Array(col) => Scalar::Array(col.clone()),
             ScalarRef::Tuple(fields) => {
@@ -246,6 +263,7 @@ impl<'a> ScalarRef<'a> {
             }),
             ScalarRef::EmptyArray => Domain::Array(None),
             ScalarRef::Number(num) => Domain::Number(num.domain()),
+            ScalarRef::Decimal(dec) => Domain::Decimal(dec.domain()),
             ScalarRef::Boolean(true) => Domain::Boolean(BooleanDomain {
                 has_false: false,
                 has_true: true,
@@ -259,6 +277,7 @@ impl<'a> ScalarRef<'a> {
                 max: Some(s.to_vec()),
             }),
             ScalarRef::Timestamp(t) => Domain::Timestamp(SimpleDomain { min: *t, max: *t }),
+            ScalarRef::TimestampTz(t) => Domain::TimestampTz(SimpleDomain { min: *t, max: *t }),
             ScalarRef::Date(d) => Domain::Date(SimpleDomain { min: *d, max: *d }),
             ScalarRef::Array(array) => Domain::Array(Some(Box::new(array.domain()))),
             ScalarRef::Tuple(fields) => {
@@ -275,9 +294,11 @@ impl PartialOrd for Scalar {
             (Scalar::Null, Scalar::Null) => Some(Ordering::Equal),
             (Scalar::EmptyArray, Scalar::EmptyArray) => Some(Ordering::Equal),
             (Scalar::Number(n1), Scalar::Number(n2)) => n1.partial_cmp(n2),
+            (Scalar::Decimal(d1), Scalar::Decimal(d2)) => d1.partial_cmp(d2),
             (Scalar::Boolean(b1), Scalar::Boolean(b2)) => b1.partial_cmp(b2),
             (Scalar::String(s1), Scalar::String(s2)) => s1.partial_cmp(s2),
             (Scalar::Timestamp(t1), Scalar::Timestamp(t2)) => t1.partial_cmp(t2),
+            (Scalar::TimestampTz(t1), Scalar::TimestampTz(t2)) => t1.partial_cmp(t2),
             (Scalar::Date(d1), Scalar::Date(d2)) => d1.partial_cmp(d2),
             (Scalar::Array(a1), Scalar::Array(a2)) => a1.partial_cmp(a2),
             (Scalar::Tuple(t1), Scalar::Tuple(t2)) => t1.partial_cmp(t2),
@@ -298,9 +319,11 @@ impl PartialOrd for ScalarRef<'_> {
             (ScalarRef::Null, ScalarRef::Null) => Some(Ordering::Equal),
             (ScalarRef::EmptyArray, ScalarRef::EmptyArray) => Some(Ordering::Equal),
             (ScalarRef::Number(n1), ScalarRef::Number(n2)) => n1.partial_cmp(n2),
+            (ScalarRef::Decimal(d1), ScalarRef::Decimal(d2)) => d1.partial_cmp(d2),
             (ScalarRef::Boolean(b1), ScalarRef::Boolean(b2)) => b1.partial_cmp(b2),
             (ScalarRef::String(s1), ScalarRef::String(s2)) => s1.partial_cmp(s2),
             (ScalarRef::Timestamp(t1), ScalarRef::Timestamp(t2)) => t1.partial_cmp(t2),
+            (ScalarRef::TimestampTz(t1), ScalarRef::TimestampTz(t2)) => t1.partial_cmp(t2),
             (ScalarRef::Date(d1), ScalarRef::Date(d2)) => d1.partial_cmp(d2),
             (ScalarRef::Array(a1), ScalarRef::Array(a2)) => a1.partial_cmp(a2),
             (ScalarRef::Tuple(t1), ScalarRef::Tuple(t2)) => t1.partial_cmp(t2),
@@ -328,11 +351,24 @@ impl PartialOrd for Column {
                     _ => None,
                 })
             }
+            (Column::Decimal(col1), Column::Decimal(col2)) => with_decimal_type!(|DECIMAL_TYPE| {
+                match (col1, col2) {
+                    (DecimalColumn::DECIMAL_TYPE(c1, s1), DecimalColumn::DECIMAL_TYPE(c2, s2))
+                        if s1 == s2 =>
+                    {
+                        c1.iter().partial_cmp(c2.iter())
+                    }
+                    _ => None,
+                }
+            }),
             (Column::Boolean(col1), Column::Boolean(col2)) => col1.iter().partial_cmp(col2.iter()),
             (Column::String(col1), Column::String(col2)) => col1.iter().partial_cmp(col2.iter()),
             (Column::Timestamp(col1), Column::Timestamp(col2)) => {
                 col1.iter().partial_cmp(col2.iter())
             }
+            (Column::TimestampTz(col1), Column::TimestampTz(col2)) => {
+                col1.iter().partial_cmp(col2.iter())
+            }
             (Column::Date(col1), Column::Date(col2)) => col1.iter().partial_cmp(col2.iter()),
             (Column::Array(col1), Column::Array(col2)) => col1.iter().partial_cmp(col2.iter()),
             (Column::Nullable(col1), Column::Nullable(col2)) => {
@@ -360,9 +396,11 @@ impl Column {
             Column::Null { len } => *len,
             Column::EmptyArray { len } => *len,
             Column::Number(col) => col.len(),
+            Column::Decimal(col) => col.len(),
             Column::Boolean(col) => col.len(),
             Column::String(col) => col.len(),
             Column::Timestamp(col) => col.len(),
+            Column::TimestampTz(col) => col.len(),
             Column::Date(col) => col.len(),
             Column::Array(col) => col.len(),
             Column::Nullable(col) => col.len(),
@@ -376,9 +414,11 @@ impl Column {
             Column::Null { .. } => Some(ScalarRef::Null),
             Column::EmptyArray { .. } => Some(ScalarRef::EmptyArray),
             Column::Number(col) => Some(ScalarRef::Number(col.index(index)?)),
+            Column::Decimal(col) => Some(ScalarRef::Decimal(col.index(index)?)),
             Column::Boolean(col) => Some(ScalarRef::Boolean(col.get(index)?)),
             Column::String(col) => Some(ScalarRef::String(col.index(index)?)),
             Column::Timestamp(col) => Some(ScalarRef::Timestamp(col.get(index).cloned()?)),
+            Column::TimestampTz(col) => Some(ScalarRef::TimestampTz(col.get(index).cloned()?)),
             Column::Date(col) => Some(ScalarRef::Date(col.get(index).cloned()?)),
             Column::Array(col) => Some(ScalarRef::Array(col.index(index)?)),
             Column::Nullable(col) => Some(col.index(index)?.unwrap_or(ScalarRef::Null)),
@@ -399,9 +439,11 @@ impl Column {
             Column::Null { .. } => ScalarRef::Null,
             Column::EmptyArray { .. } => ScalarRef::EmptyArray,
             Column::Number(col) => ScalarRef::Number(col.index_unchecked(index)),
+            Column::Decimal(col) => ScalarRef::Decimal(col.index_unchecked(index)),
             Column::Boolean(col) => ScalarRef::Boolean(col.get_bit_unchecked(index)),
             Column::String(col) => ScalarRef::String(col.index_unchecked(index)),
             Column::Timestamp(col) => ScalarRef::Timestamp(*col.get_unchecked(index)),
+            Column::TimestampTz(col) => ScalarRef::TimestampTz(*col.get_unchecked(index)),
             Column::Date(col) => ScalarRef::Date(*col.get_unchecked(index)),
             Column::Array(col) => ScalarRef::Array(col.index_unchecked(index)),
             Column::Nullable(col) => col.index_unchecked(index).unwrap_or(ScalarRef::Null),
@@ -430,6 +472,7 @@ impl Column {
                 len: range.end - range.start,
             },
             Column::Number(col) => Column::Number(col.slice(range)),
+            Column::Decimal(col) => Column::Decimal(col.slice(range)),
             Column::Boolean(col) => {
                 Column::Boolean(col.clone().slice(range.start, range.end - range.start))
             }
@@ -437,6 +480,9 @@ impl Column {
             Column::Timestamp(col) => {
                 Column::Timestamp(col.clone().slice(range.start, range.end - range.start))
             }
+            Column::TimestampTz(col) => {
+                Column::TimestampTz(col.clone().slice(range.start, range.end - range.start))
+            }
             Column::Date(col) => {
                 Column::Date(col.clone().slice(range.start, range.end - range.start))
             }
@@ -470,6 +516,7 @@ impl Column {
             }),
             Column::EmptyArray { .. } => Domain::Array(None),
             Column::Number(col) => Domain::Number(col.domain()),
+            Column::Decimal(col) => Domain::Decimal(col.domain()),
             Column::Boolean(col) => Domain::Boolean(BooleanDomain {
                 has_false: col.unset_bits() > 0,
                 has_true: col.len() - col.unset_bits() > 0,
@@ -488,6 +535,13 @@ impl Column {
                     max: *max,
                 })
             }
+            Column::TimestampTz(col) => {
+                let (min, max) = col.iter().minmax().into_option().unwrap();
+                Domain::TimestampTz(SimpleDomain {
+                    min: *min,
+                    max: *max,
+                })
+            }
             Column::Date(col) => {
                 let (min, max) = col.iter().minmax().into_option().unwrap();
                 Domain::Date(SimpleDomain {
@@ -535,9 +589,20 @@ impl Column {
             Column::Number(NumberColumn::UInt64(_)) => ArrowDataType::UInt64,
             Column::Number(NumberColumn::Float32(_)) => ArrowDataType::Float32,
             Column::Number(NumberColumn::Float64(_)) => ArrowDataType::Float64,
+            Column::Decimal(DecimalColumn::Decimal128(_, size)) => {
+                ArrowDataType::Decimal(size.precision as usize, size.scale as usize)
+            }
+            Column::Decimal(DecimalColumn::Decimal256(_, size)) => {
+                ArrowDataType::Decimal256(size.precision as usize, size.scale as usize)
+            }
             Column::Boolean(_) => ArrowDataType::Boolean,
             Column::String { .. } => ArrowDataType::LargeBinary,
             Column::Timestamp(_) => ArrowDataType::Timestamp(TimeUnit::Microsecond, None),
+            Column::TimestampTz(_) => ArrowDataType::Extension(
+                "TimestampTz".to_owned(),
+                Box::new(ArrowDataType::Decimal(38, 0)),
+                None,
+            ),
             Column::Date(_) => ArrowDataType::Date32,
             Column::Array(box ArrayColumn {
                 values: Column::Nullable(box NullableColumn { column, .. }),
@@ -657,6 +722,24 @@ impl Column {
                     ),
                 )
             }
+            Column::Decimal(DecimalColumn::Decimal128(col, _)) => Box::new(
+                common_arrow::arrow::array::PrimitiveArray::<i128>::from_data(
+                    self.arrow_type(),
+                    col.clone(),
+                    None,
+                ),
+            ),
+            Column::Decimal(DecimalColumn::Decimal256(col, _)) => {
+                let values = col
+                    .iter()
+                    .map(|v| common_arrow::arrow::types::i256(*v))
+                    .collect::<Vec<_>>();
+                Box::new(common_arrow::arrow::array::PrimitiveArray::<
+                    common_arrow::arrow::types::i256,
+                >::from_data(
+                    self.arrow_type(), values.into(), None
+                ))
+            }
             Column::Boolean(col) => Box::new(common_arrow::arrow::array::BooleanArray::from_data(
                 self.arrow_type(),
                 col.clone(),
@@ -677,6 +760,13 @@ impl Column {
                     None,
                 ),
             ),
+            Column::TimestampTz(col) => Box::new(
+                common_arrow::arrow::array::PrimitiveArray::<i128>::from_data(
+                    self.arrow_type(),
+                    col.clone(),
+                    None,
+                ),
+            ),
             Column::Date(col) => Box::new(
                 common_arrow::arrow::array::PrimitiveArray::<i32>::from_data(
                     self.arrow_type(),
@@ -813,6 +903,34 @@ impl Column {
                 let col = unsafe { std::mem::transmute::<Buffer<f64>, Buffer<F64>>(col) };
                 Column::Number(NumberColumn::Float64(col))
             }
+            ArrowDataType::Decimal(precision, scale) => {
+                let col = arrow_col
+                    .as_any()
+                    .downcast_ref::<common_arrow::arrow::array::PrimitiveArray<i128>>()
+                    .expect("fail to read from arrow: array should be `PrimitiveArray<i128>`")
+                    .values()
+                    .clone();
+                Column::Decimal(DecimalColumn::Decimal128(col, DecimalSize {
+                    precision: *precision as u8,
+                    scale: *scale as u8,
+                }))
+            }
+            ArrowDataType::Decimal256(precision, scale) => {
+                let col = arrow_col
+                    .as_any()
+                    .downcast_ref::<common_arrow::arrow::array::PrimitiveArray<
+                        common_arrow::arrow::types::i256,
+                    >>()
+                    .expect("fail to read from arrow: array should be `PrimitiveArray<i256>`")
+                    .values()
+                    .iter()
+                    .map(|v| v.0)
+                    .collect::<Vec<_>>();
+                Column::Decimal(DecimalColumn::Decimal256(col.into(), DecimalSize {
+                    precision: *precision as u8,
+                    scale: *scale as u8,
+                }))
+            }
             ArrowDataType::Boolean => Column::Boolean(
                 arrow_col
                     .as_any()
@@ -901,6 +1019,16 @@ impl Column {
                     .values()
                     .clone(),
             ),
+            ArrowDataType::Extension(name, _, None) if name == "TimestampTz" => {
+                Column::TimestampTz(
+                    arrow_col
+                        .as_any()
+                        .downcast_ref::<common_arrow::arrow::array::PrimitiveArray<i128>>()
+                        .expect("fail to read from arrow: array should be `PrimitiveArray<i128>`")
+                        .values()
+                        .clone(),
+                )
+            }
             ArrowDataType::Extension(name, _, None) if name == "Variant" => {
                 let arrow_col = arrow_col
                     .as_any()
@@ -932,6 +1060,22 @@ impl Column {
                     offsets: offsets.into(),
                 }))
             }
+            ArrowDataType::Map(_, _) => {
+                let map_col = arrow_col
+                    .as_any()
+                    .downcast_ref::<common_arrow::arrow::array::MapArray>()
+                    .expect("fail to read from arrow: array should be `MapArray`");
+                let values = Column::from_arrow(&**map_col.field());
+                let offsets = map_col
+                    .offsets()
+                    .iter()
+                    .map(|x| *x as u64)
+                    .collect::<Vec<_>>();
+                Column::Array(Box::new(ArrayColumn {
+                    values,
+                    offsets: offsets.into(),
+                }))
+            }
             ArrowDataType::Struct(_) => {
                 let arrow_col = arrow_col
                     .as_any()
@@ -981,9 +1125,11 @@ impl Column {
             Column::Number(NumberColumn::Int16(_)) => self.len() * 2,
             Column::Number(NumberColumn::Int32(_)) => self.len() * 4,
             Column::Number(NumberColumn::Int64(_)) => self.len() * 8,
+            Column::Decimal(col) => col.memory_size(),
             Column::Boolean(c) => c.as_slice().0.len(),
             Column::String(col) => col.data.len() + col.offsets.len() * 8,
             Column::Timestamp(col) => col.len() * 8,
+            Column::TimestampTz(col) => col.len() * 16,
             Column::Date(col) => col.len() * 4,
             Column::Array(col) => col.values.memory_size() + col.offsets.len() * 8,
             Column::Nullable(c) => c.column.memory_size() + c.validity.as_slice().0.len(),
@@ -1030,9 +1176,11 @@ impl ColumnBuilder {
             Column::Null { len } => ColumnBuilder::Null { len },
             Column::EmptyArray { len } => ColumnBuilder::EmptyArray { len },
             Column::Number(col) => ColumnBuilder::Number(NumberColumnBuilder::from_column(col)),
+            Column::Decimal(col) => ColumnBuilder::Decimal(DecimalColumnBuilder::from_column(col)),
             Column::Boolean(col) => ColumnBuilder::Boolean(bitmap_into_mut(col)),
             Column::String(col) => ColumnBuilder::String(StringColumnBuilder::from_column(col)),
             Column::Timestamp(col) => ColumnBuilder::Timestamp(buffer_into_mut(col)),
+            Column::TimestampTz(col) => ColumnBuilder::TimestampTz(buffer_into_mut(col)),
             Column::Date(col) => ColumnBuilder::Date(buffer_into_mut(col)),
             Column::Array(box col) => {
                 ColumnBuilder::Array(Box::new(ArrayColumnBuilder::from_column(col)))
@@ -1069,9 +1217,13 @@ impl ColumnBuilder {
             },
             ScalarRef::EmptyArray => ColumnBuilder::EmptyArray { len: n },
             ScalarRef::Number(num) => ColumnBuilder::Number(NumberColumnBuilder::repeat(*num, n)),
+            ScalarRef::Decimal(dec) => {
+                ColumnBuilder::Decimal(DecimalColumnBuilder::repeat(*dec, n))
+            }
             ScalarRef::Boolean(b) => ColumnBuilder::Boolean(constant_bitmap(*b, n)),
             ScalarRef::String(s) => ColumnBuilder::String(StringColumnBuilder::repeat(s, n)),
             ScalarRef::Timestamp(d) => ColumnBuilder::Timestamp(vec![*d; n]),
+            ScalarRef::TimestampTz(d) => ColumnBuilder::TimestampTz(vec![*d; n]),
             ScalarRef::Date(d) => ColumnBuilder::Date(vec![*d; n]),
             ScalarRef::Array(col) => {
                 ColumnBuilder::Array(Box::new(ArrayColumnBuilder::repeat(col, n)))
@@ -1099,9 +1251,11 @@ impl ColumnBuilder {
             ColumnBuilder::Null { len } => *len,
             ColumnBuilder::EmptyArray { len } => *len,
             ColumnBuilder::Number(col) => col.len(),
+            ColumnBuilder::Decimal(col) => col.len(),
             ColumnBuilder::Boolean(builder) => builder.len(),
             ColumnBuilder::String(builder) => builder.len(),
             ColumnBuilder::Timestamp(builder) => builder.len(),
+            ColumnBuilder::TimestampTz(builder) => builder.len(),
             ColumnBuilder::Date(builder) => builder.len(),
             ColumnBuilder::Array(builder) => builder.len(),
             ColumnBuilder::Nullable(builder) => builder.len(),
@@ -1117,6 +1271,9 @@ impl ColumnBuilder {
             DataType::Number(num_ty) => {
                 ColumnBuilder::Number(NumberColumnBuilder::with_capacity(num_ty, capacity))
             }
+            DataType::Decimal(ty) => {
+                ColumnBuilder::Decimal(DecimalColumnBuilder::with_capacity(ty, capacity))
+            }
             DataType::Boolean => ColumnBuilder::Boolean(MutableBitmap::with_capacity(capacity)),
             DataType::String => {
                 ColumnBuilder::String(StringColumnBuilder::with_capacity(capacity, 0))
@@ -1124,6 +1281,7 @@ impl ColumnBuilder {
             DataType::Timestamp => {
                 ColumnBuilder::Number(NumberColumnBuilder::Int64(Vec::with_capacity(capacity)))
             }
+            DataType::TimestampTz => ColumnBuilder::TimestampTz(Vec::with_capacity(capacity)),
             DataType::Date => {
                 ColumnBuilder::Number(NumberColumnBuilder::Int32(Vec::with_capacity(capacity)))
             }
@@ -1167,6 +1325,7 @@ impl ColumnBuilder {
             (ColumnBuilder::Null { len }, ScalarRef::Null) => *len += 1,
             (ColumnBuilder::EmptyArray { len }, ScalarRef::EmptyArray) => *len += 1,
             (ColumnBuilder::Number(builder), ScalarRef::Number(value)) => builder.push(value),
+            (ColumnBuilder::Decimal(builder), ScalarRef::Decimal(value)) => builder.push(value),
             (ColumnBuilder::Boolean(builder), ScalarRef::Boolean(value)) => builder.push(value),
             (ColumnBuilder::String(builder), ScalarRef::String(value)) => {
                 builder.put_slice(value);
@@ -1175,6 +1334,9 @@ impl ColumnBuilder {
             (ColumnBuilder::Timestamp(builder), ScalarRef::Timestamp(value)) => {
                 builder.push(value);
             }
+            (ColumnBuilder::TimestampTz(builder), ScalarRef::TimestampTz(value)) => {
+                builder.push(value);
+            }
             (ColumnBuilder::Date(builder), ScalarRef::Date(value)) => builder.push(value),
             (ColumnBuilder::Array(builder), ScalarRef::Array(value)) => {
                 builder.push(value);
@@ -1205,9 +1367,11 @@ impl ColumnBuilder {
             ColumnBuilder::Null { len } => *len += 1,
             ColumnBuilder::EmptyArray { len } => *len += 1,
             ColumnBuilder::Number(builder) => builder.push_default(),
+            ColumnBuilder::Decimal(builder) => builder.push_default(),
             ColumnBuilder::Boolean(builder) => builder.push(false),
             ColumnBuilder::String(builder) => builder.commit_row(),
             ColumnBuilder::Timestamp(builder) => builder.push(0),
+            ColumnBuilder::TimestampTz(builder) => builder.push(0),
             ColumnBuilder::Date(builder) => builder.push(0),
             ColumnBuilder::Array(builder) => builder.push_default(),
             ColumnBuilder::Nullable(builder) => builder.push_null(),
@@ -1235,6 +1399,9 @@ impl ColumnBuilder {
             (ColumnBuilder::Number(builder), ColumnBuilder::Number(other_builder)) => {
                 builder.append(other_builder);
             }
+            (ColumnBuilder::Decimal(builder), ColumnBuilder::Decimal(other_builder)) => {
+                builder.append(other_builder);
+            }
             (ColumnBuilder::Boolean(builder), ColumnBuilder::Boolean(other_builder)) => {
                 append_bitmap(builder, other_builder);
             }
@@ -1244,6 +1411,9 @@ impl ColumnBuilder {
             (ColumnBuilder::Timestamp(builder), ColumnBuilder::Timestamp(other_builder)) => {
                 builder.extend_from_slice(other_builder);
             }
+            (ColumnBuilder::TimestampTz(builder), ColumnBuilder::TimestampTz(other_builder)) => {
+                builder.extend_from_slice(other_builder);
+            }
             (ColumnBuilder::Date(builder), ColumnBuilder::Date(other_builder)) => {
                 builder.extend_from_slice(other_builder);
             }
@@ -1275,9 +1445,11 @@ impl ColumnBuilder {
             ColumnBuilder::Null { len } => Column::Null { len },
             ColumnBuilder::EmptyArray { len } => Column::EmptyArray { len },
             ColumnBuilder::Number(builder) => Column::Number(builder.build()),
+            ColumnBuilder::Decimal(builder) => Column::Decimal(builder.build()),
             ColumnBuilder::Boolean(builder) => Column::Boolean(builder.into()),
             ColumnBuilder::String(builder) => Column::String(builder.build()),
             ColumnBuilder::Timestamp(builder) => Column::Timestamp(builder.into()),
+            ColumnBuilder::TimestampTz(builder) => Column::TimestampTz(builder.into()),
             ColumnBuilder::Date(builder) => Column::Date(builder.into()),
             ColumnBuilder::Array(builder) => Column::Array(Box::new(builder.build())),
             ColumnBuilder::Nullable(builder) => Column::Nullable(Box::new(builder.build())),
@@ -1295,9 +1467,11 @@ impl ColumnBuilder {
             ColumnBuilder::Null { .. } => Scalar::Null,
             ColumnBuilder::EmptyArray { .. } => Scalar::EmptyArray,
             ColumnBuilder::Number(builder) => Scalar::Number(builder.build_scalar()),
+            ColumnBuilder::Decimal(builder) => Scalar::Decimal(builder.build_scalar()),
             ColumnBuilder::Boolean(builder) => Scalar::Boolean(builder.get(0)),
             ColumnBuilder::String(builder) => Scalar::String(builder.build_scalar()),
             ColumnBuilder::Timestamp(builder) => Scalar::Timestamp(builder[0]),
+            ColumnBuilder::TimestampTz(builder) => Scalar::TimestampTz(builder[0]),
             ColumnBuilder::Date(builder) => Scalar::Date(builder[0]),
             ColumnBuilder::Array(builder) => Scalar::Array(builder.build_scalar()),
             ColumnBuilder::Nullable(builder) => builder.build_scalar().unwrap_or(Scalar::Null),
@@ -18,14 +18,16 @@ use common_datablocks::DataBlock;
 use common_datavalues::prelude::*;
 use common_expression::converts::can_convert;
 use common_expression::converts::from_block;
+use common_expression::converts::from_type;
 use common_expression::converts::to_datablock;
+use common_expression::converts::to_type;
 use rand::Rng;
 
 fn random_type() -> DataTypeImpl {
     let mut rng = rand::thread_rng();
 
     loop {
-        let datatype = match rng.gen_range(0..21) {
+        let datatype = match rng.gen_range(0..22) {
             0 => DataTypeImpl::Null(NullType {}),
             1 => NullableType::new_impl(u64::to_data_type()),
             2 => DataTypeImpl::Boolean(BooleanType {}),
@@ -50,6 +52,7 @@ fn random_type() -> DataTypeImpl {
             17 => ArrayType::new_impl(f32::to_data_type()),
             18 => DataTypeImpl::VariantArray(VariantArrayType {}),
             19 => DataTypeImpl::VariantObject(VariantObjectType {}),
+            20 => TimestampTzType::new_impl(),
             _ => DataTypeImpl::Variant(VariantType {}),
         };
         if can_convert(&datatype) {
@@ -96,7 +99,7 @@ fn test_convert() {
 
     let block = DataBlock::create(schema.clone(), columns);
 
-    let chunk = from_block(&block);
+    let chunk = from_block(&block).unwrap();
     {
         assert_eq!(chunk.num_rows(), num_rows);
         assert_eq!(chunk.num_columns(), num_cols);
@@ -112,3 +115,30 @@ fn test_converts() {
         test_convert();
     }
 }
+
+#[test]
+fn test_convert_timestamp_tz() {
+    let datatype = NullableType::new_impl(TimestampTzType::new_impl());
+    assert_eq!(
+        from_type(&datatype).unwrap(),
+        common_expression::types::DataType::TimestampTz.wrap_nullable()
+    );
+
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", datatype.clone())]);
+    let column = datatype.create_random_column(4);
+    let block = DataBlock::create(schema.clone(), vec![column]);
+    let chunk = from_block(&block).unwrap();
+    assert_eq!(to_datablock(&chunk, schema), block);
+}
+
+#[test]
+fn test_convert_unsupported_type() {
+    let datatype = NullableType::new_impl(IntervalType::new_impl(IntervalKind::Day));
+    assert!(from_type(&datatype).is_err());
+    assert!(to_type(&datatype).is_err());
+
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", datatype.clone())]);
+    let column = datatype.create_random_column(4);
+    let block = DataBlock::create(schema, vec![column]);
+    assert!(from_block(&block).is_err());
+}
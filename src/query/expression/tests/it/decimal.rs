@@ -0,0 +1,142 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_expression::types::decimal::i256;
+use common_expression::types::decimal::DecimalColumn;
+use common_expression::types::decimal::DecimalScalar;
+use common_expression::types::decimal::DecimalSize;
+use common_expression::types::DataType;
+use common_expression::types::DecimalDataType;
+use common_expression::Column;
+use common_expression::ColumnBuilder;
+use common_expression::Scalar;
+
+fn decimal_type(precision: u8, scale: u8) -> DecimalDataType {
+    DecimalDataType::from_size(DecimalSize { precision, scale }).unwrap()
+}
+
+#[test]
+fn test_decimal_data_type() {
+    assert!(matches!(
+        decimal_type(38, 10),
+        DecimalDataType::Decimal128(_)
+    ));
+    assert!(matches!(
+        decimal_type(39, 10),
+        DecimalDataType::Decimal256(_)
+    ));
+    assert!(
+        DecimalDataType::from_size(DecimalSize {
+            precision: 0,
+            scale: 0
+        })
+        .is_err()
+    );
+    assert!(
+        DecimalDataType::from_size(DecimalSize {
+            precision: 77,
+            scale: 0
+        })
+        .is_err()
+    );
+    assert!(
+        DecimalDataType::from_size(DecimalSize {
+            precision: 5,
+            scale: 6
+        })
+        .is_err()
+    );
+    assert_eq!(
+        DataType::Decimal(decimal_type(10, 2)).to_string(),
+        "Decimal(10, 2)"
+    );
+}
+
+#[test]
+fn test_decimal_parse_and_display() {
+    let ty = decimal_type(10, 2);
+    let cases = [
+        ("123.45", "123.45"),
+        ("-0.01", "-0.01"),
+        ("1.005", "1.01"),
+        ("-1.005", "-1.01"),
+        ("  7 ", "7.00"),
+        ("1.5e2", "150.00"),
+        ("12345678.9", "12345678.90"),
+    ];
+    for (text, expected) in cases {
+        let scalar = ty.parse_scalar(text.as_bytes()).unwrap();
+        assert_eq!(scalar.to_string(), expected, "parsing {text}");
+    }
+
+    for text in ["", "abc", "1.2.3", "123456789.0", "--1"] {
+        assert!(ty.parse_scalar(text.as_bytes()).is_err(), "parsing {text}");
+    }
+
+    let wide = decimal_type(76, 0);
+    let scalar = wide.parse_scalar(&[b'9'; 76]).unwrap();
+    assert_eq!(scalar.to_string(), "9".repeat(76));
+}
+
+#[test]
+fn test_decimal_cast() {
+    let scalar = decimal_type(10, 3).parse_scalar(b"-12.345").unwrap();
+
+    let narrowed = scalar.cast_to(&decimal_type(5, 1)).unwrap();
+    assert_eq!(narrowed.to_string(), "-12.3");
+    assert!(scalar.cast_to(&decimal_type(3, 2)).is_none());
+
+    let widened = scalar.cast_to(&decimal_type(50, 10)).unwrap();
+    assert_eq!(widened.to_string(), "-12.3450000000");
+    assert_eq!(widened.to_i128(), Some(-12));
+
+    let from_int = DecimalScalar::from_i64(42, &decimal_type(4, 2)).unwrap();
+    assert_eq!(from_int.to_string(), "42.00");
+    assert!(DecimalScalar::from_i64(420, &decimal_type(4, 2)).is_none());
+
+    let from_float = DecimalScalar::from_f64(0.125, &decimal_type(4, 2)).unwrap();
+    assert_eq!(from_float.to_string(), "0.13");
+}
+
+#[test]
+fn test_decimal_column() {
+    let size = DecimalSize {
+        precision: 40,
+        scale: 2,
+    };
+    let mut builder = ColumnBuilder::with_capacity(&DataType::Decimal(decimal_type(40, 2)), 3);
+    for v in [100, -250, 0] {
+        builder.push(Scalar::Decimal(DecimalScalar::Decimal256(i256::from(v), size)).as_ref());
+    }
+    let column = builder.build();
+    assert_eq!(column.len(), 3);
+    assert_eq!(
+        column.index(1).unwrap().to_string(),
+        Scalar::Decimal(DecimalScalar::Decimal256(i256::from(-250), size))
+            .as_ref()
+            .to_string()
+    );
+
+    let column_128 = Column::Decimal(DecimalColumn::Decimal128(
+        vec![1_i128, -2, 3].into(),
+        DecimalSize {
+            precision: 10,
+            scale: 1,
+        },
+    ));
+    for column in [column, column_128] {
+        let arrow_col = column.as_arrow();
+        assert_eq!(Column::from_arrow(arrow_col.as_ref()), column);
+    }
+}
@@ -16,6 +16,7 @@
 #![feature(try_blocks)]
 
 mod convert;
+mod decimal;
 // TODO(andylokandy): migrate to crate funciton-v2
 mod expression;
 mod kernel;
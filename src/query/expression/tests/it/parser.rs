@@ -18,7 +18,9 @@ use common_ast::parser::token::Token;
 use common_ast::parser::tokenize_sql;
 use common_ast::Backtrace;
 use common_ast::Dialect;
+use common_expression::types::decimal::DecimalSize;
 use common_expression::types::DataType;
+use common_expression::types::DecimalDataType;
 use common_expression::types::NumberDataType;
 use common_expression::Literal;
 use common_expression::RawExpr;
@@ -73,6 +75,35 @@ pub fn transform_expr(ast: common_ast::ast::Expr, columns: &[(&str, DataType)])
             expr: Box::new(transform_expr(*expr, columns)),
             dest_type: transform_data_type(target_type),
         },
+        common_ast::ast::Expr::FunctionCall {
+            span,
+            name,
+            args,
+            lambda: Some(lambda),
+            ..
+        } => {
+            let lambda_params = lambda
+                .params
+                .iter()
+                .map(|param| param.name.clone())
+                .collect();
+            let lambda_columns = lambda
+                .params
+                .iter()
+                .enumerate()
+                .map(|(idx, param)| (param.name.as_str(), DataType::Generic(idx)))
+                .collect::<Vec<_>>();
+            RawExpr::LambdaFunctionCall {
+                span: transform_span(span),
+                name: name.name,
+                args: args
+                    .into_iter()
+                    .map(|arg| transform_expr(arg, columns))
+                    .collect(),
+                lambda_params,
+                lambda_expr: Box::new(transform_expr(*lambda.expr, &lambda_columns)),
+            }
+        }
         common_ast::ast::Expr::FunctionCall {
             span,
             name,
@@ -148,10 +179,20 @@ fn transform_data_type(target_type: common_ast::ast::TypeName) -> DataType {
         common_ast::ast::TypeName::Int64 => DataType::Number(NumberDataType::Int64),
         common_ast::ast::TypeName::Float32 => DataType::Number(NumberDataType::Float32),
         common_ast::ast::TypeName::Float64 => DataType::Number(NumberDataType::Float64),
+        common_ast::ast::TypeName::Decimal { precision, scale } => DataType::Decimal(
+            DecimalDataType::from_size(DecimalSize {
+                precision: precision as u8,
+                scale: scale as u8,
+            })
+            .unwrap(),
+        ),
         common_ast::ast::TypeName::String => DataType::String,
         common_ast::ast::TypeName::Array {
             item_type: Some(item_type),
         } => DataType::Array(Box::new(transform_data_type(*item_type))),
+        common_ast::ast::TypeName::Map { value_type, .. } => {
+            DataType::Map(Box::new(transform_data_type(*value_type)))
+        }
         common_ast::ast::TypeName::Tuple { fields_type, .. } => {
             DataType::Tuple(fields_type.into_iter().map(transform_data_type).collect())
         }
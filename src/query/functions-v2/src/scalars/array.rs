@@ -12,12 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cmp::Ordering;
 use std::sync::Arc;
 
 use common_expression::types::array::ArrayColumnBuilder;
+use common_expression::types::boolean::BooleanDomain;
 use common_expression::types::number::SimpleDomain;
 use common_expression::types::number::UInt64Type;
 use common_expression::types::ArrayType;
+use common_expression::types::BooleanType;
 use common_expression::types::DataType;
 use common_expression::types::EmptyArrayType;
 use common_expression::types::GenericType;
@@ -32,6 +35,7 @@ use common_expression::FunctionProperty;
 use common_expression::FunctionRegistry;
 use common_expression::FunctionSignature;
 use common_expression::Scalar;
+use common_expression::ScalarRef;
 use common_expression::Value;
 use common_expression::ValueRef;
 
@@ -124,4 +128,105 @@ pub fn register(registry: &mut FunctionRegistry) {
             }
         ),
     );
+
+    registry.register_2_arg::<EmptyArrayType, GenericType<0>, BooleanType, _, _>(
+        "array_contains",
+        FunctionProperty::default(),
+        |_, _| {
+            Some(BooleanDomain {
+                has_false: true,
+                has_true: false,
+            })
+        },
+        |_, _, _| false,
+    );
+
+    registry.register_2_arg::<ArrayType<GenericType<0>>, GenericType<0>, BooleanType, _, _>(
+        "array_contains",
+        FunctionProperty::default(),
+        |_, _| None,
+        |arr, item, _| arr.iter().any(|value| value == item),
+    );
+
+    registry.register_2_arg::<EmptyArrayType, EmptyArrayType, EmptyArrayType, _, _>(
+        "array_concat",
+        FunctionProperty::default(),
+        |_, _| Some(()),
+        |_, _, _| (),
+    );
+
+    registry.register_2_arg::<ArrayType<GenericType<0>>, ArrayType<GenericType<0>>, ArrayType<GenericType<0>>, _, _>(
+        "array_concat",
+        FunctionProperty::default(),
+        |lhs, rhs| Some(lhs.merge(rhs)),
+        |lhs, rhs, _| Column::concat(&[lhs, rhs]),
+    );
+
+    registry.register_3_arg::<EmptyArrayType, UInt64Type, UInt64Type, EmptyArrayType, _, _>(
+        "array_slice",
+        FunctionProperty::default(),
+        |_, _, _| Some(()),
+        |_, _, _, _| (),
+    );
+
+    // The positions are 1-based and inclusive, like `array_slice([1, 2, 3, 4], 2, 3)` returns `[2, 3]`.
+    registry.register_3_arg::<ArrayType<GenericType<0>>, UInt64Type, UInt64Type, ArrayType<GenericType<0>>, _, _>(
+        "array_slice",
+        FunctionProperty::default(),
+        |domain, _, _| Some(domain.clone()),
+        |arr, start, end, _| {
+            let start = (start.max(1) as usize - 1).min(arr.len());
+            let end = (end as usize).clamp(start, arr.len());
+            arr.slice(start..end)
+        },
+    );
+
+    registry.register_1_arg::<EmptyArrayType, EmptyArrayType, _, _>(
+        "array_sort",
+        FunctionProperty::default(),
+        |_| Some(()),
+        |_, _| (),
+    );
+
+    // Sorts the elements in ascending order, NULLs are placed last.
+    registry.register_1_arg::<ArrayType<GenericType<0>>, ArrayType<GenericType<0>>, _, _>(
+        "array_sort",
+        FunctionProperty::default(),
+        |domain| Some(domain.clone()),
+        |arr, _| {
+            let mut indices: Vec<usize> = (0..arr.len()).collect();
+            indices.sort_by(|lhs, rhs| {
+                match (arr.index(*lhs).unwrap(), arr.index(*rhs).unwrap()) {
+                    (ScalarRef::Null, ScalarRef::Null) => Ordering::Equal,
+                    (ScalarRef::Null, _) => Ordering::Greater,
+                    (_, ScalarRef::Null) => Ordering::Less,
+                    (lhs, rhs) => lhs.partial_cmp(&rhs).unwrap_or(Ordering::Equal),
+                }
+            });
+            arr.take(&indices)
+        },
+    );
+
+    registry.register_1_arg::<EmptyArrayType, EmptyArrayType, _, _>(
+        "array_distinct",
+        FunctionProperty::default(),
+        |_| Some(()),
+        |_, _| (),
+    );
+
+    // Removes the duplicated elements, the first occurrence of every element is kept.
+    registry.register_1_arg::<ArrayType<GenericType<0>>, ArrayType<GenericType<0>>, _, _>(
+        "array_distinct",
+        FunctionProperty::default(),
+        |domain| Some(domain.clone()),
+        |arr, _| {
+            let mut indices: Vec<usize> = Vec::with_capacity(arr.len());
+            for (idx, value) in arr.iter().enumerate() {
+                if !indices.iter().any(|i| arr.index(*i).unwrap() == value) {
+                    indices.push(idx);
+                }
+            }
+            arr.take(&indices)
+        },
+    );
 }
@@ -14,6 +14,7 @@
 
 use chrono::Datelike;
 use chrono::Utc;
+use chrono_tz::Tz;
 use common_arrow::arrow::temporal_conversions::EPOCH_DAYS_FROM_CE;
 use common_expression::date_helper::*;
 use common_expression::types::date::check_date;
@@ -28,16 +29,23 @@ use common_expression::types::number::UInt32Type;
 use common_expression::types::number::UInt64Type;
 use common_expression::types::number::UInt8Type;
 use common_expression::types::timestamp::check_number_to_timestamp;
+use common_expression::types::timestamp::check_timestamp;
 use common_expression::types::timestamp::microseconds_to_days;
 use common_expression::types::timestamp::string_to_timestamp;
 use common_expression::types::timestamp::MICROS_IN_A_SEC;
+use common_expression::types::timestamp_tz::string_to_timestamp_tz;
+use common_expression::types::timestamp_tz::timestamp_tz_from_datetime;
+use common_expression::types::timestamp_tz::timestamp_tz_micros;
+use common_expression::types::timestamp_tz::timestamp_tz_to_datetime;
 use common_expression::types::DateType;
 use common_expression::types::NullableType;
 use common_expression::types::StringType;
 use common_expression::types::TimestampType;
+use common_expression::types::TimestampTzType;
 use common_expression::vectorize_1_arg;
 use common_expression::vectorize_with_builder_1_arg;
 use common_expression::vectorize_with_builder_2_arg;
+use common_expression::vectorize_with_builder_3_arg;
 use common_expression::FunctionProperty;
 use common_expression::FunctionRegistry;
 use common_expression::Value;
@@ -45,7 +53,7 @@ use num_traits::AsPrimitive;
 
 pub fn register(registry: &mut FunctionRegistry) {
     // [cast | try_cast](xx AS [date | timestamp])
-    // to_[date | timestamp](xx)
+    // to_[date | timestamp | timestamp_tz](xx)
     register_cast_functions(registry);
     register_try_cast_functions(registry);
 
@@ -59,6 +67,12 @@ pub fn register(registry: &mut FunctionRegistry) {
 
     // to_*([date | timestamp]) -> number
     register_to_number_functions(registry);
+
+    // date_diff(unit, [date | timestamp | timestamp_tz], [date | timestamp | timestamp_tz])
+    // convert_timezone(tz_to, [timestamp | timestamp_tz])
+    // convert_timezone(tz_from, tz_to, timestamp)
+    // [to_char | date_format]([date | timestamp | timestamp_tz], format)
+    register_calendar_functions(registry);
 }
 
 fn number_domain_to_timestamp_domain<T: AsPrimitive<i64>>(
@@ -122,6 +136,44 @@ fn register_cast_functions(registry: &mut FunctionRegistry) {
         }),
     );
 
+    registry.register_passthrough_nullable_1_arg::<StringType, TimestampTzType, _, _>(
+        "to_timestamp_tz",
+        FunctionProperty::default(),
+        |_| None,
+        vectorize_with_builder_1_arg::<StringType, TimestampTzType>(|val, output, ctx| {
+            let ts = string_to_timestamp_tz(val, &ctx.tz).ok_or_else(|| {
+                format!(
+                    "unable to cast {} to TimestampTzType",
+                    String::from_utf8_lossy(val)
+                )
+            })?;
+            output.push(ts);
+            Ok(())
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<TimestampType, TimestampTzType, _, _>(
+        "to_timestamp_tz",
+        FunctionProperty::default(),
+        |_| None,
+        vectorize_with_builder_1_arg::<TimestampType, TimestampTzType>(|val, output, ctx| {
+            output.push(timestamp_tz_from_datetime(&val.to_timestamp(&ctx.tz))?);
+            Ok(())
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<TimestampTzType, TimestampType, _, _>(
+        "to_timestamp",
+        FunctionProperty::default(),
+        |domain| {
+            Some(SimpleDomain {
+                min: timestamp_tz_micros(domain.min),
+                max: timestamp_tz_micros(domain.max),
+            })
+        },
+        vectorize_1_arg::<TimestampTzType, TimestampType>(|val, _| timestamp_tz_micros(val)),
+    );
+
     registry.register_passthrough_nullable_1_arg::<TimestampType, DateType, _, _>(
         "to_date",
         FunctionProperty::default(),
@@ -599,3 +651,149 @@ fn register_to_number_functions(registry: &mut FunctionRegistry) {
         }),
     );
 }
+
+fn register_calendar_functions(registry: &mut FunctionRegistry) {
+    registry
+        .register_passthrough_nullable_3_arg::<StringType, DateType, DateType, Int64Type, _, _>(
+            "date_diff",
+            FunctionProperty::default(),
+            |_, _, _| None,
+            vectorize_with_builder_3_arg::<StringType, DateType, DateType, Int64Type>(
+                |unit, a, b, output, _| {
+                    let unit = DateUnit::parse(unit)?;
+                    output.push(unit.diff(
+                        &a.to_date(&Tz::UTC).and_hms(0, 0, 0),
+                        &b.to_date(&Tz::UTC).and_hms(0, 0, 0),
+                    ));
+                    Ok(())
+                },
+            ),
+        );
+    registry.register_passthrough_nullable_3_arg::<StringType, TimestampType, TimestampType, Int64Type, _, _>(
+        "date_diff",
+        FunctionProperty::default(),
+        |_, _, _| None,
+        vectorize_with_builder_3_arg::<StringType, TimestampType, TimestampType, Int64Type>(
+            |unit, a, b, output, ctx| {
+                let unit = DateUnit::parse(unit)?;
+                output.push(unit.diff(&a.to_timestamp(&ctx.tz), &b.to_timestamp(&ctx.tz)));
+                Ok(())
+            },
+        ),
+    );
+    registry.register_passthrough_nullable_3_arg::<StringType, TimestampTzType, TimestampTzType, Int64Type, _, _>(
+        "date_diff",
+        FunctionProperty::default(),
+        |_, _, _| None,
+        vectorize_with_builder_3_arg::<StringType, TimestampTzType, TimestampTzType, Int64Type>(
+            |unit, a, b, output, _| {
+                let unit = DateUnit::parse(unit)?;
+                output.push(unit.diff(&timestamp_tz_to_datetime(a), &timestamp_tz_to_datetime(b)));
+                Ok(())
+            },
+        ),
+    );
+
+    // The two arguments form keeps the instant and takes the offset of `tz_to`.
+    registry
+        .register_passthrough_nullable_2_arg::<StringType, TimestampType, TimestampTzType, _, _>(
+            "convert_timezone",
+            FunctionProperty::default(),
+            |_, _| None,
+            vectorize_with_builder_2_arg::<StringType, TimestampType, TimestampTzType>(
+                |to, ts, output, _| {
+                    let to = parse_timezone(to)?;
+                    output.push(timestamp_tz_from_datetime(&ts.to_timestamp(&to))?);
+                    Ok(())
+                },
+            ),
+        );
+    registry
+        .register_passthrough_nullable_2_arg::<StringType, TimestampTzType, TimestampTzType, _, _>(
+            "convert_timezone",
+            FunctionProperty::default(),
+            |_, _| None,
+            vectorize_with_builder_2_arg::<StringType, TimestampTzType, TimestampTzType>(
+                |to, ts, output, _| {
+                    let to = parse_timezone(to)?;
+                    output.push(timestamp_tz_from_datetime(
+                        &timestamp_tz_to_datetime(ts).with_timezone(&to),
+                    )?);
+                    Ok(())
+                },
+            ),
+        );
+
+    // The three arguments form converts the wall clock of a naive timestamp.
+    registry.register_passthrough_nullable_3_arg::<StringType, StringType, TimestampType, TimestampType, _, _>(
+        "convert_timezone",
+        FunctionProperty::default(),
+        |_, _, _| None,
+        vectorize_with_builder_3_arg::<StringType, StringType, TimestampType, TimestampType>(
+            |from, to, ts, output, _| {
+                let from = parse_timezone(from)?;
+                let to = parse_timezone(to)?;
+                let local = ts.to_timestamp(&Tz::UTC).naive_utc();
+                let local = resolve_local_datetime(&from, &local)
+                    .with_timezone(&to)
+                    .naive_local();
+                let micros = local.timestamp() * MICROS_IN_A_SEC + local.timestamp_subsec_micros() as i64;
+                output.push(check_timestamp(micros)?);
+                Ok(())
+            },
+        ),
+    );
+
+    registry.register_aliases("to_char", &["date_format"]);
+    registry.register_passthrough_nullable_2_arg::<DateType, StringType, StringType, _, _>(
+        "to_char",
+        FunctionProperty::default(),
+        |_, _| None,
+        vectorize_with_builder_2_arg::<DateType, StringType, StringType>(
+            |date, format, output, ctx| {
+                let format = std::str::from_utf8(format).map_err(|e| e.to_string())?;
+                check_strftime_format(format)?;
+                let local = date.to_date(&Tz::UTC).and_hms(0, 0, 0).naive_utc();
+                let dt = resolve_local_datetime(&ctx.tz, &local);
+                output.put_str(&dt.format(format).to_string());
+                output.commit_row();
+                Ok(())
+            },
+        ),
+    );
+    registry.register_passthrough_nullable_2_arg::<TimestampType, StringType, StringType, _, _>(
+        "to_char",
+        FunctionProperty::default(),
+        |_, _| None,
+        vectorize_with_builder_2_arg::<TimestampType, StringType, StringType>(
+            |ts, format, output, ctx| {
+                let format = std::str::from_utf8(format).map_err(|e| e.to_string())?;
+                check_strftime_format(format)?;
+                output.put_str(&ts.to_timestamp(&ctx.tz).format(format).to_string());
+                output.commit_row();
+                Ok(())
+            },
+        ),
+    );
+    registry.register_passthrough_nullable_2_arg::<TimestampTzType, StringType, StringType, _, _>(
+        "to_char",
+        FunctionProperty::default(),
+        |_, _| None,
+        vectorize_with_builder_2_arg::<TimestampTzType, StringType, StringType>(
+            |ts, format, output, _| {
+                let format = std::str::from_utf8(format).map_err(|e| e.to_string())?;
+                check_strftime_format(format)?;
+                output.put_str(&timestamp_tz_to_datetime(ts).format(format).to_string());
+                output.commit_row();
+                Ok(())
+            },
+        ),
+    );
+}
+
+fn parse_timezone(tz: &[u8]) -> Result<Tz, String> {
+    let tz = String::from_utf8_lossy(tz);
+    tz.trim()
+        .parse::<Tz>()
+        .map_err(|_| format!("Invalid timezone {}, like Asia/Shanghai", tz))
+}
@@ -14,6 +14,10 @@
 
 use std::io::Write;
 
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::Column;
+use common_expression::ColumnFrom;
 use goldenfile::Mint;
 
 use super::run_ast;
@@ -26,6 +30,12 @@ fn test_array() {
     test_create(file);
     test_length(file);
     test_get(file);
+    test_contains(file);
+    test_concat(file);
+    test_slice(file);
+    test_sort(file);
+    test_distinct(file);
+    test_lambda(file);
 }
 
 fn test_create(file: &mut impl Write) {
@@ -57,3 +67,36 @@ fn test_get(file: &mut impl Write) {
     run_ast(file, "get([1, null, 3], 0)", &[]);
     run_ast(file, "get([1, null, 3], 1)", &[]);
 }
+
+fn test_contains(file: &mut impl Write) {
+    run_ast(file, "array_contains([1, 2, 3], 2)", &[]);
+    run_ast(file, "array_contains(['a', 'b'], 'c')", &[]);
+}
+
+fn test_concat(file: &mut impl Write) {
+    run_ast(file, "array_concat([1, 2], [3])", &[]);
+}
+
+fn test_slice(file: &mut impl Write) {
+    run_ast(file, "array_slice([1, 2, 3, 4], 2, 3)", &[]);
+    run_ast(file, "array_slice([1, 2, 3, 4], 3, 10)", &[]);
+}
+
+fn test_sort(file: &mut impl Write) {
+    run_ast(file, "array_sort([3, 1, 2])", &[]);
+}
+
+fn test_distinct(file: &mut impl Write) {
+    run_ast(file, "array_distinct([1, 2, 1, 3, 2])", &[]);
+}
+
+fn test_lambda(file: &mut impl Write) {
+    run_ast(file, "array_transform([1, 2, 3], x -> x + 1)", &[]);
+    run_ast(file, "array_filter([1, 2, 3, 4], x -> x > 2)", &[]);
+    run_ast(file, "array_reduce([1, 2, 3], (acc, x) -> acc + x)", &[]);
+    run_ast(file, "array_transform([1, 2, 3], x -> x + a)", &[(
+        "a",
+        DataType::Number(NumberDataType::UInt8),
+        Column::from_data(vec![1u8, 2, 3]),
+    )]);
+}
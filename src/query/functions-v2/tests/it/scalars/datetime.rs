@@ -16,6 +16,8 @@ use std::io::Write;
 
 use common_expression::from_date_data;
 use common_expression::from_timestamp_data;
+use common_expression::from_timestamp_tz_data;
+use common_expression::types::timestamp_tz::make_timestamp_tz;
 use common_expression::types::DataType;
 use common_expression::types::NumberDataType;
 use common_expression::Column;
@@ -39,6 +41,7 @@ fn test_datetime() {
     test_date_arith(file);
     test_timestamp_arith(file);
     test_to_number(file);
+    test_calendar(file);
 }
 
 fn test_to_timestamp(file: &mut impl Write) {
@@ -1013,3 +1016,73 @@ fn test_to_number(file: &mut impl Write) {
         from_timestamp_data(vec![-100, 0, 100]),
     )]);
 }
+
+fn test_calendar(file: &mut impl Write) {
+    run_ast(
+        file,
+        "date_diff('month', to_timestamp('2022-01-31 23:00:00'), to_timestamp('2022-03-01 01:00:00'))",
+        &[],
+    );
+    run_ast(
+        file,
+        "date_diff('hour', to_timestamp('2022-01-31 23:00:00'), to_timestamp('2022-03-01 01:00:00'))",
+        &[],
+    );
+    run_ast(
+        file,
+        "date_diff('week', to_date(19000), to_date(19010))",
+        &[],
+    );
+    run_ast(file, "date_diff('fortnight', to_date(0), to_date(1))", &[]);
+    run_ast(
+        file,
+        "convert_timezone('America/New_York', 'Europe/London', to_timestamp('2022-07-01 12:00:00'))",
+        &[],
+    );
+    run_ast(
+        file,
+        "to_char(to_timestamp('2022-07-01 12:00:00'), '%Y/%m/%d %H:%M')",
+        &[],
+    );
+    run_ast(file, "date_format(to_date(19000), '%a %d %b %Y')", &[]);
+
+    run_ast(file, "to_timestamp_tz('2022-03-27 01:30:00 +01:00')", &[]);
+    run_ast(
+        file,
+        "to_timestamp(to_timestamp_tz('2022-07-01 12:00:00 +08:00'))",
+        &[],
+    );
+    run_ast(
+        file,
+        "date_diff('day', to_timestamp_tz('2022-03-26 23:30:00 -05:00'), to_timestamp_tz('2022-03-27 01:30:00 +01:00'))",
+        &[],
+    );
+    run_ast(
+        file,
+        "date_diff('hour', to_timestamp_tz('2022-03-26 23:30:00 -05:00'), to_timestamp_tz('2022-03-27 01:30:00 +01:00'))",
+        &[],
+    );
+    run_ast(
+        file,
+        "convert_timezone('Asia/Shanghai', to_timestamp('2022-07-01 12:00:00'))",
+        &[],
+    );
+    run_ast(
+        file,
+        "convert_timezone('America/New_York', to_timestamp_tz('2022-07-01 12:00:00 +08:00'))",
+        &[],
+    );
+    run_ast(
+        file,
+        "to_char(to_timestamp_tz('2022-07-01 12:00:00 +08:00'), '%Y/%m/%d %H:%M %z')",
+        &[],
+    );
+    run_ast(file, "to_char(a, '%Y-%m-%d %H:%M %:z')", &[(
+        "a",
+        DataType::TimestampTz,
+        from_timestamp_tz_data(vec![
+            make_timestamp_tz(1648341000000000, 3600),
+            make_timestamp_tz(1656648000000000, -4 * 3600),
+        ]),
+    )]);
+}
@@ -0,0 +1,276 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::fmt;
+use std::sync::Arc;
+
+use bytes::BytesMut;
+use common_arrow::arrow::bitmap::Bitmap;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::*;
+
+use super::aggregate_function::AggregateFunction;
+use super::aggregate_function::AggregateFunctionRef;
+use super::aggregate_function_factory::AggregateFunctionDescription;
+use super::aggregate_function_factory::AggregateFunctionFeatures;
+use super::aggregate_hll_state::HyperLogLog;
+use super::aggregator_common::assert_unary_arguments;
+use super::aggregator_common::assert_variadic_arguments;
+use super::StateAddr;
+
+/// What the rows fed to the function are.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum HllInput {
+    /// Plain values, every row is hashed and added to the sketch.
+    Values,
+    /// Sketches produced by `hll_sketch`, which are merged together.
+    Sketches,
+}
+
+/// What the function returns once the sketch is built.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum HllOutput {
+    /// The estimated number of distinct values.
+    Estimate,
+    /// The encoded sketch, which can be stored and merged later.
+    Sketch,
+}
+
+/// The HyperLogLog based aggregate functions:
+///
+/// - `approx_count_distinct(x, ...)` estimates the number of distinct rows.
+/// - `hll_sketch(x, ...)` returns the sketch of the rows.
+/// - `hll_merge(sketch)` merges sketches into a single one.
+#[derive(Clone)]
+pub struct AggregateApproxCountDistinctFunction {
+    display_name: String,
+    input: HllInput,
+    output: HllOutput,
+}
+
+impl AggregateApproxCountDistinctFunction {
+    fn add_rows(
+        &self,
+        state: &mut HyperLogLog,
+        columns: &[ColumnRef],
+        rows: impl Iterator<Item = usize>,
+    ) -> Result<()> {
+        match self.input {
+            HllInput::Values => {
+                let mut buffer = Vec::new();
+                for row in rows {
+                    state.add_row(columns, row, &mut buffer);
+                }
+            }
+            HllInput::Sketches => {
+                let column: &StringColumn = Series::check_get(&columns[0])?;
+                for row in rows {
+                    state.merge(&HyperLogLog::from_bytes(column.get_data(row))?);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl AggregateFunction for AggregateApproxCountDistinctFunction {
+    fn name(&self) -> &str {
+        "AggregateApproxCountDistinctFunction"
+    }
+
+    fn return_type(&self) -> Result<DataTypeImpl> {
+        match self.output {
+            HllOutput::Estimate => Ok(u64::to_data_type()),
+            HllOutput::Sketch => Ok(Vu8::to_data_type()),
+        }
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(HyperLogLog::new);
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<HyperLogLog>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[ColumnRef],
+        validity: Option<&Bitmap>,
+        input_rows: usize,
+    ) -> Result<()> {
+        let state = place.get::<HyperLogLog>();
+        match validity {
+            Some(bitmap) => self.add_rows(
+                state,
+                columns,
+                (0..input_rows).filter(|row| bitmap.get_bit(*row)),
+            ),
+            None => self.add_rows(state, columns, 0..input_rows),
+        }
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        columns: &[ColumnRef],
+        _input_rows: usize,
+    ) -> Result<()> {
+        for (row, place) in places.iter().enumerate() {
+            let state = place.next(offset).get::<HyperLogLog>();
+            self.add_rows(state, columns, row..row + 1)?;
+        }
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[ColumnRef], row: usize) -> Result<()> {
+        let state = place.get::<HyperLogLog>();
+        self.add_rows(state, columns, row..row + 1)
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
+        let state = place.get::<HyperLogLog>();
+        serialize_into_buf(writer, state)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<HyperLogLog>();
+        *state = deserialize_from_slice(reader)?;
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<HyperLogLog>();
+        let rhs = rhs.get::<HyperLogLog>();
+        state.merge(rhs);
+        Ok(())
+    }
+
+    #[allow(unused_mut)]
+    fn merge_result(&self, place: StateAddr, column: &mut dyn MutableColumn) -> Result<()> {
+        let state = place.get::<HyperLogLog>();
+        match self.output {
+            HllOutput::Estimate => {
+                let column: &mut MutablePrimitiveColumn<u64> =
+                    Series::check_get_mutable_column(column)?;
+                column.push(state.estimate());
+            }
+            HllOutput::Sketch => {
+                let column: &mut MutableStringColumn = Series::check_get_mutable_column(column)?;
+                column.append_value(state.to_bytes()?);
+            }
+        }
+        Ok(())
+    }
+
+    fn need_manual_drop_state(&self) -> bool {
+        true
+    }
+
+    unsafe fn drop_state(&self, place: StateAddr) {
+        let state = place.get::<HyperLogLog>();
+        std::ptr::drop_in_place(state);
+    }
+}
+
+impl fmt::Display for AggregateApproxCountDistinctFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+fn try_create(
+    display_name: &str,
+    input: HllInput,
+    output: HllOutput,
+    arguments: Vec<DataField>,
+) -> Result<AggregateFunctionRef> {
+    match input {
+        HllInput::Values => assert_variadic_arguments(display_name, arguments.len(), (1, 32))?,
+        HllInput::Sketches => {
+            assert_unary_arguments(display_name, arguments.len())?;
+            if arguments[0].data_type().data_type_id() != TypeID::String {
+                return Err(ErrorCode::BadDataValueType(format!(
+                    "{} expect a sketch produced by hll_sketch, but got {:?}",
+                    display_name,
+                    arguments[0].data_type()
+                )));
+            }
+        }
+    }
+
+    Ok(Arc::new(AggregateApproxCountDistinctFunction {
+        display_name: display_name.to_string(),
+        input,
+        output,
+    }))
+}
+
+pub fn try_create_aggregate_approx_count_distinct_function(
+    display_name: &str,
+    _params: Vec<DataValue>,
+    arguments: Vec<DataField>,
+) -> Result<AggregateFunctionRef> {
+    try_create(
+        display_name,
+        HllInput::Values,
+        HllOutput::Estimate,
+        arguments,
+    )
+}
+
+pub fn try_create_aggregate_hll_sketch_function(
+    display_name: &str,
+    _params: Vec<DataValue>,
+    arguments: Vec<DataField>,
+) -> Result<AggregateFunctionRef> {
+    try_create(display_name, HllInput::Values, HllOutput::Sketch, arguments)
+}
+
+pub fn try_create_aggregate_hll_merge_function(
+    display_name: &str,
+    _params: Vec<DataValue>,
+    arguments: Vec<DataField>,
+) -> Result<AggregateFunctionRef> {
+    try_create(
+        display_name,
+        HllInput::Sketches,
+        HllOutput::Sketch,
+        arguments,
+    )
+}
+
+pub fn aggregate_approx_count_distinct_function_desc() -> AggregateFunctionDescription {
+    let features = AggregateFunctionFeatures {
+        returns_default_when_only_null: true,
+        ..Default::default()
+    };
+    AggregateFunctionDescription::creator_with_features(
+        Box::new(try_create_aggregate_approx_count_distinct_function),
+        features,
+    )
+}
+
+pub fn aggregate_hll_sketch_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_hll_sketch_function))
+}
+
+pub fn aggregate_hll_merge_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_hll_merge_function))
+}
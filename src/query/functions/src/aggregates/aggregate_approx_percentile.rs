@@ -0,0 +1,359 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::f64::consts::PI;
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use bytes::BytesMut;
+use common_arrow::arrow::bitmap::Bitmap;
+use common_datavalues::prelude::*;
+use common_datavalues::with_match_primitive_type_id;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::*;
+use num::cast::AsPrimitive;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::StateAddr;
+use crate::aggregates::aggregate_function_factory::AggregateFunctionDescription;
+use crate::aggregates::aggregate_quantile::get_quantile_levels;
+use crate::aggregates::aggregator_common::assert_unary_arguments;
+use crate::aggregates::AggregateFunction;
+use crate::aggregates::AggregateFunctionRef;
+
+const TDIGEST_COMPRESSION: f64 = 100.0;
+const TDIGEST_BUFFER_SIZE: usize = 500;
+
+#[derive(Serialize, Deserialize, Clone, Copy)]
+struct Centroid {
+    mean: f64,
+    weight: f64,
+}
+
+/// A merging t-digest, see https://github.com/tdunning/t-digest.
+///
+/// Incoming values are buffered and folded into the centroids in batches, the
+/// size of every centroid is bounded by the arcsine scale function so that the
+/// tails keep a much finer resolution than the median.
+#[derive(Serialize, Deserialize)]
+struct AggregateApproxPercentileState {
+    pub centroids: Vec<Centroid>,
+    pub buffer: Vec<Centroid>,
+    pub min: f64,
+    pub max: f64,
+}
+
+impl AggregateApproxPercentileState {
+    fn new() -> Self {
+        Self {
+            centroids: Vec::new(),
+            buffer: Vec::new(),
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+
+    #[inline(always)]
+    fn add(&mut self, value: f64) {
+        if value.is_nan() {
+            return;
+        }
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+        self.buffer.push(Centroid {
+            mean: value,
+            weight: 1.0,
+        });
+        if self.buffer.len() >= TDIGEST_BUFFER_SIZE {
+            self.compress();
+        }
+    }
+
+    fn merge(&mut self, other: &Self) {
+        if other.centroids.is_empty() && other.buffer.is_empty() {
+            return;
+        }
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+        self.buffer.extend_from_slice(&other.centroids);
+        self.buffer.extend_from_slice(&other.buffer);
+        if self.buffer.len() >= TDIGEST_BUFFER_SIZE {
+            self.compress();
+        }
+    }
+
+    /// Maps a quantile to the scale of the digest, one unit of `k` is the
+    /// maximum size of a centroid.
+    fn k(q: f64) -> f64 {
+        TDIGEST_COMPRESSION * ((2.0 * q - 1.0).asin() + PI / 2.0) / PI
+    }
+
+    fn k_inverse(k: f64) -> f64 {
+        ((k.min(TDIGEST_COMPRESSION) * PI / TDIGEST_COMPRESSION - PI / 2.0).sin() + 1.0) / 2.0
+    }
+
+    fn compress(&mut self) {
+        if self.buffer.is_empty() {
+            return;
+        }
+
+        let mut centroids = std::mem::take(&mut self.buffer);
+        centroids.append(&mut self.centroids);
+        centroids.sort_unstable_by(|a, b| a.mean.total_cmp(&b.mean));
+
+        let total: f64 = centroids.iter().map(|c| c.weight).sum();
+        let mut merged = Vec::with_capacity(TDIGEST_COMPRESSION as usize);
+        let mut current = centroids[0];
+        let mut weight_so_far = 0.0;
+        let mut weight_limit = total * Self::k_inverse(Self::k(0.0) + 1.0);
+
+        for next in centroids.into_iter().skip(1) {
+            if weight_so_far + current.weight + next.weight <= weight_limit {
+                let weight = current.weight + next.weight;
+                current.mean += (next.mean - current.mean) * next.weight / weight;
+                current.weight = weight;
+            } else {
+                weight_so_far += current.weight;
+                weight_limit = total * Self::k_inverse(Self::k(weight_so_far / total) + 1.0);
+                merged.push(current);
+                current = next;
+            }
+        }
+        merged.push(current);
+
+        self.centroids = merged;
+    }
+
+    /// Estimates the quantile at `level` by interpolating between the centers
+    /// of the neighbouring centroids. `compress` must be called before.
+    fn quantile(&self, level: f64) -> f64 {
+        let centroids = &self.centroids;
+        if centroids.is_empty() {
+            return 0.0;
+        }
+        if centroids.len() == 1 {
+            return centroids[0].mean;
+        }
+
+        let total: f64 = centroids.iter().map(|c| c.weight).sum();
+        let rank = level * total;
+
+        let first = centroids[0];
+        if rank < first.weight / 2.0 {
+            return self.min + (first.mean - self.min) * rank / (first.weight / 2.0);
+        }
+
+        let last = centroids[centroids.len() - 1];
+        if rank > total - last.weight / 2.0 {
+            let remaining = total - rank;
+            return self.max - (self.max - last.mean) * remaining / (last.weight / 2.0);
+        }
+
+        let mut weight_so_far = first.weight / 2.0;
+        for pair in centroids.windows(2) {
+            let step = (pair[0].weight + pair[1].weight) / 2.0;
+            if rank <= weight_so_far + step {
+                let fraction = (rank - weight_so_far) / step;
+                return pair[0].mean + (pair[1].mean - pair[0].mean) * fraction;
+            }
+            weight_so_far += step;
+        }
+
+        last.mean
+    }
+}
+
+#[derive(Clone)]
+pub struct AggregateApproxPercentileFunction<T> {
+    display_name: String,
+    _arguments: Vec<DataField>,
+    level: f64,
+    t: PhantomData<T>,
+}
+
+impl<T> AggregateFunction for AggregateApproxPercentileFunction<T>
+where T: PrimitiveType + AsPrimitive<f64>
+{
+    fn name(&self) -> &str {
+        "AggregateApproxPercentileFunction"
+    }
+
+    fn return_type(&self) -> Result<DataTypeImpl> {
+        Ok(f64::to_data_type())
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(AggregateApproxPercentileState::new);
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateApproxPercentileState>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[ColumnRef],
+        validity: Option<&Bitmap>,
+        _input_rows: usize,
+    ) -> Result<()> {
+        let state = place.get::<AggregateApproxPercentileState>();
+        let column: &PrimitiveColumn<T> = unsafe { Series::static_cast(&columns[0]) };
+
+        match validity {
+            Some(bitmap) => {
+                for (value, is_valid) in column.iter().zip(bitmap.iter()) {
+                    if is_valid {
+                        state.add(value.as_());
+                    }
+                }
+            }
+            None => {
+                for value in column.iter() {
+                    state.add(value.as_());
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        columns: &[ColumnRef],
+        _input_rows: usize,
+    ) -> Result<()> {
+        let column: &PrimitiveColumn<T> = unsafe { Series::static_cast(&columns[0]) };
+
+        column.iter().zip(places.iter()).for_each(|(value, place)| {
+            let place = place.next(offset);
+            let state = place.get::<AggregateApproxPercentileState>();
+            state.add(value.as_());
+        });
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[ColumnRef], row: usize) -> Result<()> {
+        let column: &PrimitiveColumn<T> = unsafe { Series::static_cast(&columns[0]) };
+
+        let state = place.get::<AggregateApproxPercentileState>();
+        let v: f64 = unsafe { column.value_unchecked(row).as_() };
+        state.add(v);
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
+        let state = place.get::<AggregateApproxPercentileState>();
+        state.compress();
+        serialize_into_buf(writer, state)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateApproxPercentileState>();
+        *state = deserialize_from_slice(reader)?;
+
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<AggregateApproxPercentileState>();
+        let rhs = rhs.get::<AggregateApproxPercentileState>();
+        state.merge(rhs);
+        Ok(())
+    }
+
+    #[allow(unused_mut)]
+    fn merge_result(&self, place: StateAddr, column: &mut dyn MutableColumn) -> Result<()> {
+        let state = place.get::<AggregateApproxPercentileState>();
+        state.compress();
+
+        let column: &mut MutablePrimitiveColumn<f64> = Series::check_get_mutable_column(column)?;
+        column.push(state.quantile(self.level));
+        Ok(())
+    }
+
+    fn need_manual_drop_state(&self) -> bool {
+        true
+    }
+
+    unsafe fn drop_state(&self, place: StateAddr) {
+        let state = place.get::<AggregateApproxPercentileState>();
+        std::ptr::drop_in_place(state);
+    }
+}
+
+impl<T> fmt::Display for AggregateApproxPercentileFunction<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+impl<T> AggregateApproxPercentileFunction<T>
+where T: PrimitiveType + AsPrimitive<f64>
+{
+    pub fn try_create(
+        display_name: &str,
+        level: f64,
+        arguments: Vec<DataField>,
+    ) -> Result<AggregateFunctionRef> {
+        Ok(Arc::new(Self {
+            display_name: display_name.to_string(),
+            _arguments: arguments,
+            level,
+            t: PhantomData,
+        }))
+    }
+}
+
+pub fn try_create_aggregate_approx_percentile_function(
+    display_name: &str,
+    params: Vec<DataValue>,
+    arguments: Vec<DataField>,
+) -> Result<AggregateFunctionRef> {
+    assert_unary_arguments(display_name, arguments.len())?;
+
+    let level = match params.len() {
+        0 => 0.5,
+        1 => get_quantile_levels(display_name, &params)?[0],
+        n => {
+            return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+                "{} expect to have at most one parameter, but got {}",
+                display_name, n
+            )));
+        }
+    };
+
+    let data_type = arguments[0].data_type();
+
+    with_match_primitive_type_id!(data_type.data_type_id(), |$T| {
+        AggregateApproxPercentileFunction::<$T>::try_create(display_name, level, arguments)
+    },
+
+    {
+        Err(ErrorCode::BadDataValueType(format!(
+            "AggregateApproxPercentileFunction does not support type '{:?}'",
+            data_type
+        )))
+    })
+}
+
+pub fn aggregate_approx_percentile_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_approx_percentile_function))
+}
@@ -0,0 +1,359 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::cmp::Ordering;
+use std::fmt;
+use std::sync::Arc;
+
+use bytes::BytesMut;
+use common_arrow::arrow::bitmap::Bitmap;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::*;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::aggregate_function::AggregateFunction;
+use super::aggregate_function::AggregateFunctionRef;
+use super::aggregate_function_factory::AggregateFunctionDescription;
+use super::aggregate_function_factory::AggregateFunctionFeatures;
+use super::StateAddr;
+
+#[derive(Serialize, Deserialize, Default)]
+struct AggregateArrayAggState {
+    pub values: Vec<DataValue>,
+    /// The `ORDER BY` keys of every value, flattened row by row.
+    pub keys: Vec<DataValue>,
+}
+
+impl AggregateArrayAggState {
+    #[inline(always)]
+    fn add(&mut self, columns: &[ColumnRef], row: usize) {
+        let value = columns[0].get(row);
+        if value.is_null() {
+            return;
+        }
+        self.values.push(value);
+        for column in columns[1..].iter() {
+            self.keys.push(column.get(row));
+        }
+    }
+
+    fn merge(&mut self, other: &Self) {
+        self.values.extend_from_slice(&other.values);
+        self.keys.extend_from_slice(&other.keys);
+    }
+
+    /// Returns the positions of the values sorted by their keys, values with
+    /// equal keys keep the order in which they were aggregated.
+    fn sorted_indices(&self, order_by: &[OrderByKey]) -> Vec<usize> {
+        let mut indices: Vec<usize> = (0..self.values.len()).collect();
+        if order_by.is_empty() {
+            return indices;
+        }
+
+        let size = order_by.len();
+        indices.sort_by(|a, b| {
+            let lhs = &self.keys[a * size..(a + 1) * size];
+            let rhs = &self.keys[b * size..(b + 1) * size];
+            for ((l, r), key) in lhs.iter().zip(rhs.iter()).zip(order_by.iter()) {
+                let ordering = key.compare(l, r);
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+            Ordering::Equal
+        });
+        indices
+    }
+}
+
+#[derive(Clone, Copy)]
+struct OrderByKey {
+    asc: bool,
+    nulls_first: bool,
+}
+
+impl OrderByKey {
+    fn compare(&self, lhs: &DataValue, rhs: &DataValue) -> Ordering {
+        match (lhs.is_null(), rhs.is_null()) {
+            (true, true) => Ordering::Equal,
+            (true, false) if self.nulls_first => Ordering::Less,
+            (true, false) => Ordering::Greater,
+            (false, true) if self.nulls_first => Ordering::Greater,
+            (false, true) => Ordering::Less,
+            (false, false) if self.asc => lhs.cmp(rhs),
+            (false, false) => rhs.cmp(lhs),
+        }
+    }
+}
+
+/// Collects the values of a group, NULL values are skipped.
+///
+/// `array_agg` returns them as an array, `string_agg` concatenates them with a separator.
+#[derive(Clone)]
+pub struct AggregateArrayAggFunction {
+    display_name: String,
+    return_type: DataTypeImpl,
+    separator: Option<Vec<u8>>,
+    order_by: Vec<OrderByKey>,
+}
+
+impl AggregateFunction for AggregateArrayAggFunction {
+    fn name(&self) -> &str {
+        "AggregateArrayAggFunction"
+    }
+
+    fn return_type(&self) -> Result<DataTypeImpl> {
+        Ok(self.return_type.clone())
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(AggregateArrayAggState::default);
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateArrayAggState>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[ColumnRef],
+        validity: Option<&Bitmap>,
+        input_rows: usize,
+    ) -> Result<()> {
+        let state = place.get::<AggregateArrayAggState>();
+        match validity {
+            Some(bitmap) => {
+                for (row, is_valid) in bitmap.iter().enumerate() {
+                    if is_valid {
+                        state.add(columns, row);
+                    }
+                }
+            }
+            None => {
+                for row in 0..input_rows {
+                    state.add(columns, row);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        columns: &[ColumnRef],
+        _input_rows: usize,
+    ) -> Result<()> {
+        for (row, place) in places.iter().enumerate() {
+            let state = place.next(offset).get::<AggregateArrayAggState>();
+            state.add(columns, row);
+        }
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[ColumnRef], row: usize) -> Result<()> {
+        let state = place.get::<AggregateArrayAggState>();
+        state.add(columns, row);
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
+        let state = place.get::<AggregateArrayAggState>();
+        serialize_into_buf(writer, state)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateArrayAggState>();
+        *state = deserialize_from_slice(reader)?;
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<AggregateArrayAggState>();
+        let rhs = rhs.get::<AggregateArrayAggState>();
+        state.merge(rhs);
+        Ok(())
+    }
+
+    #[allow(unused_mut)]
+    fn merge_result(&self, place: StateAddr, column: &mut dyn MutableColumn) -> Result<()> {
+        let state = place.get::<AggregateArrayAggState>();
+        let indices = state.sorted_indices(&self.order_by);
+
+        match &self.separator {
+            Some(separator) => {
+                let column: &mut MutableStringColumn = Series::check_get_mutable_column(column)?;
+                let mut result = Vec::new();
+                for (i, index) in indices.into_iter().enumerate() {
+                    if i > 0 {
+                        result.extend_from_slice(separator);
+                    }
+                    result.extend_from_slice(&state.values[index].as_string()?);
+                }
+                column.append_value(result);
+            }
+            None => {
+                let builder: &mut MutableArrayColumn = Series::check_get_mutable_column(column)?;
+                for index in indices.iter() {
+                    builder
+                        .inner_column
+                        .append_data_value(state.values[*index].clone())?;
+                }
+                builder.add_offset(indices.len());
+            }
+        }
+        Ok(())
+    }
+
+    fn need_manual_drop_state(&self) -> bool {
+        true
+    }
+
+    unsafe fn drop_state(&self, place: StateAddr) {
+        let state = place.get::<AggregateArrayAggState>();
+        std::ptr::drop_in_place(state);
+    }
+
+    fn get_own_null_adaptor(
+        &self,
+        nested_function: AggregateFunctionRef,
+        _params: Vec<DataValue>,
+        _arguments: Vec<DataField>,
+    ) -> Result<Option<AggregateFunctionRef>> {
+        // NULL values are skipped by the function itself, so that a NULL `ORDER BY`
+        // key does not drop its value.
+        Ok(Some(nested_function))
+    }
+}
+
+impl fmt::Display for AggregateArrayAggFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+/// Parses the trailing `(asc, nulls_first)` parameters of the `ORDER BY` keys.
+fn get_order_by_keys(
+    display_name: &str,
+    params: &[DataValue],
+    arguments: &[DataField],
+) -> Result<Vec<OrderByKey>> {
+    let order_by = params
+        .iter()
+        .map(|param| match param {
+            DataValue::Struct(fields) if fields.len() == 2 => Ok(OrderByKey {
+                asc: fields[0].as_bool()?,
+                nulls_first: fields[1].as_bool()?,
+            }),
+            _ => Err(ErrorCode::BadArguments(format!(
+                "{} expect ORDER BY keys as parameters, but got {:?}",
+                display_name, param
+            ))),
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    if arguments.len() != order_by.len() + 1 {
+        return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+            "{} expect to have single arguments, but got {}",
+            display_name,
+            arguments.len() - order_by.len()
+        )));
+    }
+    Ok(order_by)
+}
+
+pub fn try_create_aggregate_array_agg_function(
+    display_name: &str,
+    params: Vec<DataValue>,
+    arguments: Vec<DataField>,
+) -> Result<AggregateFunctionRef> {
+    if arguments.is_empty() {
+        return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+            "{} expect to have single arguments, but got 0",
+            display_name
+        )));
+    }
+
+    let order_by = get_order_by_keys(display_name, &params, &arguments)?;
+    let return_type = ArrayType::new_impl(arguments[0].data_type().clone());
+
+    Ok(Arc::new(AggregateArrayAggFunction {
+        display_name: display_name.to_string(),
+        return_type,
+        separator: None,
+        order_by,
+    }))
+}
+
+pub fn try_create_aggregate_string_agg_function(
+    display_name: &str,
+    params: Vec<DataValue>,
+    arguments: Vec<DataField>,
+) -> Result<AggregateFunctionRef> {
+    let (separator, params) = match params.split_first() {
+        Some((DataValue::String(separator), params)) => (separator.clone(), params),
+        _ => (vec![], params.as_slice()),
+    };
+
+    if arguments.is_empty() {
+        return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+            "{} expect to have single arguments, but got 0",
+            display_name
+        )));
+    }
+    if arguments[0].data_type().data_type_id() != TypeID::String {
+        return Err(ErrorCode::BadDataValueType(format!(
+            "{} does not support type '{}', the values must be strings",
+            display_name,
+            arguments[0].data_type().name()
+        )));
+    }
+
+    let order_by = get_order_by_keys(display_name, params, &arguments)?;
+
+    Ok(Arc::new(AggregateArrayAggFunction {
+        display_name: display_name.to_string(),
+        return_type: Vu8::to_data_type(),
+        separator: Some(separator),
+        order_by,
+    }))
+}
+
+pub fn aggregate_array_agg_function_desc() -> AggregateFunctionDescription {
+    let features = AggregateFunctionFeatures {
+        allows_order_by: true,
+        ..Default::default()
+    };
+    AggregateFunctionDescription::creator_with_features(
+        Box::new(try_create_aggregate_array_agg_function),
+        features,
+    )
+}
+
+pub fn aggregate_string_agg_function_desc() -> AggregateFunctionDescription {
+    let features = AggregateFunctionFeatures {
+        allows_order_by: true,
+        ..Default::default()
+    };
+    AggregateFunctionDescription::creator_with_features(
+        Box::new(try_create_aggregate_string_agg_function),
+        features,
+    )
+}
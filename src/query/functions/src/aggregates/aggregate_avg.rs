@@ -29,6 +29,7 @@ use serde::de::DeserializeOwned;
 use serde::Deserialize;
 use serde::Serialize;
 
+use super::aggregate_decimal::AggregateDecimalAvgFunction;
 use super::aggregate_sum::sum_primitive;
 use super::StateAddr;
 use crate::aggregates::aggregate_function_factory::AggregateFunctionDescription;
@@ -220,6 +221,11 @@ pub fn try_create_aggregate_avg_function(
         return AggregateAvgFunction::<u8, u64>::try_create(display_name, arguments);
     }
 
+    if let DataTypeImpl::Decimal(decimal) = data_type {
+        let scale = decimal.scale();
+        return AggregateDecimalAvgFunction::try_create(display_name, arguments, scale);
+    }
+
     let mut phid = data_type.data_type_id();
     // null use dummy func, it's already covered in `AggregateNullResultFunction`
     if data_type.is_null() {
@@ -24,6 +24,7 @@ use common_hashtable::KeysRef;
 use common_io::prelude::*;
 use ordered_float::OrderedFloat;
 
+use super::aggregate_array_agg::try_create_aggregate_array_agg_function;
 use super::aggregate_distinct_state::AggregateDistinctPrimitiveState;
 use super::aggregate_distinct_state::AggregateDistinctState;
 use super::aggregate_distinct_state::AggregateDistinctStringState;
@@ -156,6 +157,7 @@ impl<S, State> fmt::Display for AggregateDistinctCombinator<S, State> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self.nested_name.as_str() {
             "uniq" => write!(f, "uniq"),
+            "group_array_distinct" => write!(f, "group_array_distinct"),
             _ => write!(f, "{}_distinct", self.nested_name),
         }
     }
@@ -182,6 +184,26 @@ pub fn try_create_uniq(
     try_create(nested_name, params, arguments, &creator)
 }
 
+pub fn aggregate_combinator_group_array_distinct_desc() -> AggregateFunctionDescription {
+    let features = super::aggregate_function_factory::AggregateFunctionFeatures {
+        allows_order_by: true,
+        ..Default::default()
+    };
+    AggregateFunctionDescription::creator_with_features(
+        Box::new(try_create_group_array_distinct),
+        features,
+    )
+}
+
+pub fn try_create_group_array_distinct(
+    nested_name: &str,
+    params: Vec<DataValue>,
+    arguments: Vec<DataField>,
+) -> Result<Arc<dyn AggregateFunction>> {
+    let creator: AggregateFunctionCreator = Box::new(try_create_aggregate_array_agg_function);
+    try_create(nested_name, params, arguments, &creator)
+}
+
 #[macro_export]
 macro_rules! dispatch_primitive_type_id {
     ($key_type:expr, | $_:tt $T:ident| $_a:tt $E:ident | $body:tt,  $nbody:tt) => {{
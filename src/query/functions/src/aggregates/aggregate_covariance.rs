@@ -352,8 +352,3 @@ pub fn aggregate_covariance_population_desc() -> AggregateFunctionDescription {
 }
 
 ///////////////////////////////////////////////////////////////////////////////
-
-///////////////////////////////////////////////////////////////////////////////
-// TODO: correlation function
-// struct AggregateCorrelationImpl;
-///////////////////////////////////////////////////////////////////////////////
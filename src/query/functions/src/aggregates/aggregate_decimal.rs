@@ -0,0 +1,216 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::fmt;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::*;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::StateAddr;
+use crate::aggregates::AggregateFunction;
+use crate::aggregates::AggregateFunctionRef;
+
+pub type AggregateDecimalSumFunction = AggregateDecimalFunction<false>;
+
+pub type AggregateDecimalAvgFunction = AggregateDecimalFunction<true>;
+
+#[derive(Serialize, Deserialize)]
+struct AggregateDecimalState {
+    pub value: i128,
+    pub count: u64,
+}
+
+impl AggregateDecimalState {
+    #[inline(always)]
+    fn add(&mut self, value: i128, count: u64, display_name: &str) -> Result<()> {
+        self.value = self
+            .value
+            .checked_add(value)
+            .ok_or_else(|| decimal_overflow(display_name))?;
+        self.count += count;
+        Ok(())
+    }
+}
+
+fn decimal_overflow(display_name: &str) -> ErrorCode {
+    ErrorCode::Overflow(format!("Decimal overflow in {}", display_name))
+}
+
+/// The sum (or the average when `AVG` is true) of decimals, which fails on overflow
+/// instead of wrapping around.
+#[derive(Clone)]
+pub struct AggregateDecimalFunction<const AVG: bool> {
+    display_name: String,
+    _arguments: Vec<DataField>,
+    // The scale of the decimal argument.
+    scale: u8,
+    result_type: DecimalType,
+}
+
+impl<const AVG: bool> AggregateFunction for AggregateDecimalFunction<AVG> {
+    fn name(&self) -> &str {
+        if AVG {
+            "AggregateDecimalAvgFunction"
+        } else {
+            "AggregateDecimalSumFunction"
+        }
+    }
+
+    fn return_type(&self) -> Result<DataTypeImpl> {
+        let return_type = DataTypeImpl::Decimal(self.result_type.clone());
+        // The average of no values is NULL.
+        if AVG {
+            Ok(wrap_nullable(&return_type))
+        } else {
+            Ok(return_type)
+        }
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(|| AggregateDecimalState { value: 0, count: 0 });
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateDecimalState>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[ColumnRef],
+        validity: Option<&Bitmap>,
+        _input_rows: usize,
+    ) -> Result<()> {
+        let column: &PrimitiveColumn<i128> = Series::check_get(&columns[0])?;
+        let mut sum = 0i128;
+        let mut count = 0u64;
+        for (row, value) in column.iter().enumerate() {
+            if validity.map_or(true, |v| v.get_bit(row)) {
+                sum = sum
+                    .checked_add(*value)
+                    .ok_or_else(|| decimal_overflow(&self.display_name))?;
+                count += 1;
+            }
+        }
+
+        let state = place.get::<AggregateDecimalState>();
+        state.add(sum, count, &self.display_name)
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        columns: &[ColumnRef],
+        _input_rows: usize,
+    ) -> Result<()> {
+        let column: &PrimitiveColumn<i128> = Series::check_get(&columns[0])?;
+        for (value, place) in column.iter().zip(places.iter()) {
+            let place = place.next(offset);
+            let state = place.get::<AggregateDecimalState>();
+            state.add(*value, 1, &self.display_name)?;
+        }
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[ColumnRef], row: usize) -> Result<()> {
+        let column: &PrimitiveColumn<i128> = Series::check_get(&columns[0])?;
+        let state = place.get::<AggregateDecimalState>();
+        state.add(column.get_data(row), 1, &self.display_name)
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
+        let state = place.get::<AggregateDecimalState>();
+        serialize_into_buf(writer, state)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateDecimalState>();
+        *state = deserialize_from_slice(reader)?;
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<AggregateDecimalState>();
+        let rhs = rhs.get::<AggregateDecimalState>();
+        state.add(rhs.value, rhs.count, &self.display_name)
+    }
+
+    fn merge_result(&self, place: StateAddr, array: &mut dyn MutableColumn) -> Result<()> {
+        let state = place.get::<AggregateDecimalState>();
+
+        if !AVG {
+            self.result_type.check_value(state.value)?;
+            let builder: &mut MutablePrimitiveColumn<i128> =
+                Series::check_get_mutable_column(array)?;
+            builder.append_value(state.value);
+            return Ok(());
+        }
+
+        let builder: &mut MutableNullableColumn = Series::check_get_mutable_column(array)?;
+        if state.count == 0 {
+            builder.append_default();
+            return Ok(());
+        }
+
+        let sum = rescale_decimal(state.value, self.scale, self.result_type.scale())
+            .ok_or_else(|| decimal_overflow(&self.display_name))?;
+        let count = state.count as i128;
+        let (quotient, remainder) = (sum / count, sum % count);
+        // Round half away from zero.
+        let value = if remainder.unsigned_abs() * 2 >= count as u128 {
+            quotient + sum.signum()
+        } else {
+            quotient
+        };
+        self.result_type.check_value(value)?;
+
+        let inner: &mut MutablePrimitiveColumn<i128> =
+            Series::check_get_mutable_column(builder.inner_mut().as_mut())?;
+        inner.append_value(value);
+        builder.append_value(true);
+        Ok(())
+    }
+}
+
+impl<const AVG: bool> fmt::Display for AggregateDecimalFunction<AVG> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+impl<const AVG: bool> AggregateDecimalFunction<AVG> {
+    /// The sum of decimals keeps the scale and widens to the max precision, the average
+    /// also keeps at least 6 digits after the point.
+    pub fn try_create(
+        display_name: &str,
+        arguments: Vec<DataField>,
+        scale: u8,
+    ) -> Result<AggregateFunctionRef> {
+        let result_scale = if AVG { scale.max(6) } else { scale };
+        Ok(Arc::new(Self {
+            display_name: display_name.to_string(),
+            _arguments: arguments,
+            scale,
+            result_type: DecimalType::new(MAX_DECIMAL_PRECISION, result_scale),
+        }))
+    }
+}
@@ -57,6 +57,9 @@ pub struct AggregateFunctionFeatures {
     /// should we return Nullable type with NULL when no values were aggregated
     /// or we should return non-Nullable type with default value (example: count, count_distinct).
     pub(crate) returns_default_when_only_null: bool,
+    /// Whether the function accepts `ORDER BY` keys, like `array_agg(a ORDER BY b)`.
+    /// The keys are passed after the arguments, each one with a `(asc, nulls_first)` parameter.
+    pub(crate) allows_order_by: bool,
 
     // Function Category
     pub category: &'static str,
@@ -252,6 +255,14 @@ impl AggregateFunctionFactory {
         false
     }
 
+    pub fn allows_order_by(&self, name: impl AsRef<str>) -> bool {
+        let lowercase_name = name.as_ref().to_lowercase();
+        self.case_insensitive_desc
+            .get(&lowercase_name)
+            .map(|desc| desc.features.allows_order_by)
+            .unwrap_or(false)
+    }
+
     pub fn registered_names(&self) -> Vec<String> {
         self.case_insensitive_desc.keys().cloned().collect()
     }
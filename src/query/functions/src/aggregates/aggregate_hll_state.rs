@@ -0,0 +1,190 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::hash::Hasher;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::*;
+use serde::Deserialize;
+use serde::Serialize;
+use twox_hash::XxHash64;
+
+/// Number of bits of the hash used to pick a register.
+const HLL_PRECISION: u32 = 14;
+const HLL_REGISTERS: usize = 1 << HLL_PRECISION;
+/// The sparse representation is converted to registers once it would no
+/// longer be smaller than them.
+const HLL_SPARSE_LIMIT: usize = HLL_REGISTERS / 4;
+/// Below this estimate linear counting is more accurate than the raw
+/// HyperLogLog estimate, the value is the one of HLL++ for precision 14.
+const HLL_LINEAR_COUNTING_THRESHOLD: f64 = 11500.0;
+
+/// Leading byte of a stored sketch, bumped whenever the layout changes.
+const HLL_SKETCH_VERSION: u8 = 1;
+
+/// A HyperLogLog sketch in the spirit of HLL++.
+///
+/// Small sets are kept as a sparse register map, which is switched to the
+/// dense registers once it grows. Two sketches are merged by taking the
+/// maximum of every register, so partial states can be combined in any order.
+#[derive(Serialize, Deserialize, Default, Clone)]
+pub struct HyperLogLog {
+    sparse: BTreeMap<u16, u8>,
+    registers: Vec<u8>,
+}
+
+impl HyperLogLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Hashes the value of every column at `row` and adds it to the sketch,
+    /// `buffer` is reused between rows to avoid allocations.
+    #[inline]
+    pub fn add_row(&mut self, columns: &[ColumnRef], row: usize, buffer: &mut Vec<u8>) {
+        buffer.clear();
+        for column in columns {
+            column.serialize(buffer, row);
+        }
+
+        let mut hasher = XxHash64::with_seed(0);
+        hasher.write(buffer);
+        self.add_hash(hasher.finish());
+    }
+
+    #[inline]
+    pub fn add_hash(&mut self, hash: u64) {
+        let index = (hash >> (64 - HLL_PRECISION)) as u16;
+        // Set a guard bit so the rank never exceeds the remaining bits.
+        let rest = (hash << HLL_PRECISION) | (1 << (HLL_PRECISION - 1));
+        let rank = rest.leading_zeros() as u8 + 1;
+        self.update_register(index, rank);
+    }
+
+    #[inline]
+    fn update_register(&mut self, index: u16, rank: u8) {
+        if self.is_sparse() {
+            let register = self.sparse.entry(index).or_insert(0);
+            if *register < rank {
+                *register = rank;
+            }
+            if self.sparse.len() > HLL_SPARSE_LIMIT {
+                self.convert_to_dense();
+            }
+        } else {
+            let register = &mut self.registers[index as usize];
+            if *register < rank {
+                *register = rank;
+            }
+        }
+    }
+
+    #[inline]
+    fn is_sparse(&self) -> bool {
+        self.registers.is_empty()
+    }
+
+    fn convert_to_dense(&mut self) {
+        let mut registers = vec![0u8; HLL_REGISTERS];
+        for (index, rank) in std::mem::take(&mut self.sparse) {
+            registers[index as usize] = rank;
+        }
+        self.registers = registers;
+    }
+
+    pub fn merge(&mut self, other: &Self) {
+        if other.is_sparse() {
+            for (index, rank) in other.sparse.iter() {
+                self.update_register(*index, *rank);
+            }
+            return;
+        }
+
+        if self.is_sparse() {
+            self.convert_to_dense();
+        }
+        for (register, rank) in self.registers.iter_mut().zip(other.registers.iter()) {
+            if *register < *rank {
+                *register = *rank;
+            }
+        }
+    }
+
+    /// Estimates the number of distinct values added to the sketch.
+    pub fn estimate(&self) -> u64 {
+        let m = HLL_REGISTERS as f64;
+
+        if self.is_sparse() {
+            let zeros = m - self.sparse.len() as f64;
+            return (m * (m / zeros).ln()).round() as u64;
+        }
+
+        let mut sum = 0.0;
+        let mut zeros = 0usize;
+        for rank in self.registers.iter() {
+            sum += 2f64.powi(-(*rank as i32));
+            if *rank == 0 {
+                zeros += 1;
+            }
+        }
+
+        if zeros > 0 {
+            let linear_counting = m * (m / zeros as f64).ln();
+            if linear_counting <= HLL_LINEAR_COUNTING_THRESHOLD {
+                return linear_counting.round() as u64;
+            }
+        }
+
+        let alpha = 0.7213 / (1.0 + 1.079 / m);
+        (alpha * m * m / sum).round() as u64
+    }
+
+    /// Encodes the sketch so that it can be stored in a String column.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let mut bytes = vec![HLL_SKETCH_VERSION];
+        serialize_into_buf(&mut bytes, self)?;
+        Ok(bytes)
+    }
+
+    /// Decodes a sketch produced by `to_bytes`, an empty input is an empty sketch.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        match bytes.split_first() {
+            None => Ok(Self::new()),
+            Some((&HLL_SKETCH_VERSION, mut body)) => {
+                let sketch: Self = deserialize_from_slice(&mut body)?;
+                let valid = if sketch.is_sparse() {
+                    sketch
+                        .sparse
+                        .keys()
+                        .all(|index| (*index as usize) < HLL_REGISTERS)
+                } else {
+                    sketch.registers.len() == HLL_REGISTERS
+                };
+                if !valid {
+                    return Err(ErrorCode::BadBytes(
+                        "Invalid HyperLogLog sketch, the registers are corrupted",
+                    ));
+                }
+                Ok(sketch)
+            }
+            Some((version, _)) => Err(ErrorCode::BadBytes(format!(
+                "Unknown HyperLogLog sketch version {}",
+                version
+            ))),
+        }
+    }
+}
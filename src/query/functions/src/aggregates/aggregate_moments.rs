@@ -0,0 +1,411 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_datavalues::prelude::*;
+use common_datavalues::with_match_primitive_type_id;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::*;
+use num::cast::AsPrimitive;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::StateAddr;
+use crate::aggregates::aggregate_function_factory::AggregateFunctionDescription;
+use crate::aggregates::aggregator_common::assert_unary_arguments;
+use crate::aggregates::AggregateFunction;
+use crate::aggregates::AggregateFunctionRef;
+
+/// The central moments of a data set, `m{k}` is the sum of `(x - mean)^k`.
+#[derive(Serialize, Deserialize, Default)]
+pub struct AggregateMomentsState {
+    pub count: u64,
+    pub mean: f64,
+    pub m2: f64,
+    pub m3: f64,
+    pub m4: f64,
+}
+
+// Source: "Formulas for Robust, One-Pass Parallel Computation of Covariances and
+// Arbitrary-Order Statistical Moments"
+// (P. Pébay, Sandia National Laboratories, SAND2008-6212)
+// Paper link: https://www.osti.gov/biblio/1028931
+impl AggregateMomentsState {
+    // The online update of the formula II.3 in the paper.
+    #[inline(always)]
+    fn add(&mut self, value: f64) {
+        let n1 = self.count as f64;
+        self.count += 1;
+        let n = self.count as f64;
+
+        let delta = value - self.mean;
+        let delta_n = delta / n;
+        let delta_n2 = delta_n * delta_n;
+        let term1 = delta * delta_n * n1;
+
+        self.mean += delta_n;
+        self.m4 += term1 * delta_n2 * (n * n - 3.0 * n + 3.0) + 6.0 * delta_n2 * self.m2
+            - 4.0 * delta_n * self.m3;
+        self.m3 += term1 * delta_n * (n - 2.0) - 3.0 * delta_n * self.m2;
+        self.m2 += term1;
+    }
+
+    // The pairwise update of the formula II.1 in the paper, so the partial states of
+    // different nodes can be merged without losing precision.
+    #[inline(always)]
+    fn merge(&mut self, other: &Self) {
+        if other.count == 0 {
+            return;
+        }
+        if self.count == 0 {
+            self.count = other.count;
+            self.mean = other.mean;
+            self.m2 = other.m2;
+            self.m3 = other.m3;
+            self.m4 = other.m4;
+            return;
+        }
+
+        let (na, nb) = (self.count as f64, other.count as f64);
+        let n = na + nb;
+        let delta = other.mean - self.mean;
+        let delta2 = delta * delta;
+        let delta3 = delta * delta2;
+        let delta4 = delta2 * delta2;
+
+        let m4 = self.m4
+            + other.m4
+            + delta4 * na * nb * (na * na - na * nb + nb * nb) / (n * n * n)
+            + 6.0 * delta2 * (na * na * other.m2 + nb * nb * self.m2) / (n * n)
+            + 4.0 * delta * (na * other.m3 - nb * self.m3) / n;
+        let m3 = self.m3
+            + other.m3
+            + delta3 * na * nb * (na - nb) / (n * n)
+            + 3.0 * delta * (na * other.m2 - nb * self.m2) / n;
+        let m2 = self.m2 + other.m2 + delta2 * na * nb / n;
+
+        self.count += other.count;
+        self.mean += delta * nb / n;
+        self.m2 = m2;
+        self.m3 = m3;
+        self.m4 = m4;
+    }
+}
+
+#[derive(Clone)]
+pub struct AggregateMomentsFunction<T, R> {
+    display_name: String,
+    _arguments: Vec<DataField>,
+    t: PhantomData<T>,
+    r: PhantomData<R>,
+}
+
+impl<T, R> AggregateFunction for AggregateMomentsFunction<T, R>
+where
+    T: PrimitiveType + AsPrimitive<f64>,
+    R: AggregateMoments,
+{
+    fn name(&self) -> &str {
+        R::name()
+    }
+
+    fn return_type(&self) -> Result<DataTypeImpl> {
+        Ok(f64::to_data_type())
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(AggregateMomentsState::default);
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateMomentsState>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[ColumnRef],
+        validity: Option<&Bitmap>,
+        _input_rows: usize,
+    ) -> Result<()> {
+        let state = place.get::<AggregateMomentsState>();
+        let column: &PrimitiveColumn<T> = unsafe { Series::static_cast(&columns[0]) };
+
+        match validity {
+            Some(bitmap) => {
+                for (value, is_valid) in column.iter().zip(bitmap.iter()) {
+                    if is_valid {
+                        state.add(value.as_());
+                    }
+                }
+            }
+            None => {
+                for value in column.iter() {
+                    state.add(value.as_());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        columns: &[ColumnRef],
+        _input_rows: usize,
+    ) -> Result<()> {
+        let column: &PrimitiveColumn<T> = unsafe { Series::static_cast(&columns[0]) };
+
+        column.iter().zip(places.iter()).for_each(|(value, place)| {
+            let place = place.next(offset);
+            let state = place.get::<AggregateMomentsState>();
+            state.add(value.as_());
+        });
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[ColumnRef], row: usize) -> Result<()> {
+        let column: &PrimitiveColumn<T> = unsafe { Series::static_cast(&columns[0]) };
+
+        let state = place.get::<AggregateMomentsState>();
+        let v: f64 = unsafe { column.value_unchecked(row).as_() };
+        state.add(v);
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
+        let state = place.get::<AggregateMomentsState>();
+        serialize_into_buf(writer, state)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateMomentsState>();
+        *state = deserialize_from_slice(reader)?;
+
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<AggregateMomentsState>();
+        let rhs = rhs.get::<AggregateMomentsState>();
+        state.merge(rhs);
+        Ok(())
+    }
+
+    #[allow(unused_mut)]
+    fn merge_result(&self, place: StateAddr, column: &mut dyn MutableColumn) -> Result<()> {
+        let state = place.get::<AggregateMomentsState>();
+        let column: &mut MutablePrimitiveColumn<f64> = Series::check_get_mutable_column(column)?;
+        column.append_value(R::apply(state));
+        Ok(())
+    }
+}
+
+impl<T, R> fmt::Display for AggregateMomentsFunction<T, R> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+impl<T, R> AggregateMomentsFunction<T, R>
+where
+    T: PrimitiveType + AsPrimitive<f64>,
+    R: AggregateMoments,
+{
+    pub fn try_create(
+        display_name: &str,
+        arguments: Vec<DataField>,
+    ) -> Result<AggregateFunctionRef> {
+        Ok(Arc::new(Self {
+            display_name: display_name.to_string(),
+            _arguments: arguments,
+            t: PhantomData,
+            r: PhantomData,
+        }))
+    }
+}
+
+pub fn try_create_aggregate_moments<R: AggregateMoments>(
+    display_name: &str,
+    _params: Vec<DataValue>,
+    arguments: Vec<DataField>,
+) -> Result<Arc<dyn AggregateFunction>> {
+    assert_unary_arguments(display_name, arguments.len())?;
+
+    let data_type = arguments[0].data_type();
+
+    with_match_primitive_type_id!(data_type.data_type_id(), |$T| {
+        AggregateMomentsFunction::<$T, R>::try_create(display_name, arguments)
+    },
+    {
+        Err(ErrorCode::BadDataValueType(format!(
+            "AggregateMomentsFunction does not support type '{:?}'",
+            data_type
+        )))
+    })
+}
+
+pub trait AggregateMoments: Send + Sync + 'static {
+    fn name() -> &'static str;
+
+    fn apply(state: &AggregateMomentsState) -> f64;
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Population variance function implementation
+struct AggregateVariancePopulationImpl;
+
+impl AggregateMoments for AggregateVariancePopulationImpl {
+    fn name() -> &'static str {
+        "AggregateVariancePopulationFunction"
+    }
+
+    fn apply(state: &AggregateMomentsState) -> f64 {
+        if state.count == 0 {
+            f64::INFINITY
+        } else {
+            state.m2 / state.count as f64
+        }
+    }
+}
+
+pub fn aggregate_variance_population_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        try_create_aggregate_moments::<AggregateVariancePopulationImpl>,
+    ))
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+///////////////////////////////////////////////////////////////////////////////
+// Sample variance function implementation
+struct AggregateVarianceSampleImpl;
+
+impl AggregateMoments for AggregateVarianceSampleImpl {
+    fn name() -> &'static str {
+        "AggregateVarianceSampleFunction"
+    }
+
+    fn apply(state: &AggregateMomentsState) -> f64 {
+        if state.count < 2 {
+            f64::INFINITY
+        } else {
+            state.m2 / (state.count - 1) as f64
+        }
+    }
+}
+
+pub fn aggregate_variance_sample_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        try_create_aggregate_moments::<AggregateVarianceSampleImpl>,
+    ))
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+///////////////////////////////////////////////////////////////////////////////
+// Sample standard deviation function implementation
+struct AggregateStddevSampleImpl;
+
+impl AggregateMoments for AggregateStddevSampleImpl {
+    fn name() -> &'static str {
+        "AggregateStddevSampleFunction"
+    }
+
+    fn apply(state: &AggregateMomentsState) -> f64 {
+        AggregateVarianceSampleImpl::apply(state).sqrt()
+    }
+}
+
+pub fn aggregate_stddev_sample_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        try_create_aggregate_moments::<AggregateStddevSampleImpl>,
+    ))
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+///////////////////////////////////////////////////////////////////////////////
+// Skewness function implementation
+//
+// The adjusted Fisher-Pearson standardized moment coefficient G1, which is the
+// sample skewness computed by pandas and Excel.
+struct AggregateSkewnessImpl;
+
+impl AggregateMoments for AggregateSkewnessImpl {
+    fn name() -> &'static str {
+        "AggregateSkewnessFunction"
+    }
+
+    fn apply(state: &AggregateMomentsState) -> f64 {
+        if state.count < 3 {
+            return f64::INFINITY;
+        }
+        if state.m2 == 0.0 {
+            return 0.0;
+        }
+
+        let n = state.count as f64;
+        let g1 = n.sqrt() * state.m3 / state.m2.powf(1.5);
+        g1 * (n * (n - 1.0)).sqrt() / (n - 2.0)
+    }
+}
+
+pub fn aggregate_skewness_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        try_create_aggregate_moments::<AggregateSkewnessImpl>,
+    ))
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+///////////////////////////////////////////////////////////////////////////////
+// Kurtosis function implementation
+//
+// The sample excess kurtosis G2, which is the kurtosis computed by pandas and Excel.
+struct AggregateKurtosisImpl;
+
+impl AggregateMoments for AggregateKurtosisImpl {
+    fn name() -> &'static str {
+        "AggregateKurtosisFunction"
+    }
+
+    fn apply(state: &AggregateMomentsState) -> f64 {
+        if state.count < 4 {
+            return f64::INFINITY;
+        }
+        if state.m2 == 0.0 {
+            return 0.0;
+        }
+
+        let n = state.count as f64;
+        let g2 = n * state.m4 / (state.m2 * state.m2) - 3.0;
+        ((n + 1.0) * g2 + 6.0) * (n - 1.0) / ((n - 2.0) * (n - 3.0))
+    }
+}
+
+pub fn aggregate_kurtosis_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        try_create_aggregate_moments::<AggregateKurtosisImpl>,
+    ))
+}
+
+///////////////////////////////////////////////////////////////////////////////
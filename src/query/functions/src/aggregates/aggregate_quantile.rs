@@ -0,0 +1,346 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use bytes::BytesMut;
+use common_arrow::arrow::bitmap::Bitmap;
+use common_datavalues::prelude::*;
+use common_datavalues::with_match_primitive_type_id;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::*;
+use num::cast::AsPrimitive;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::StateAddr;
+use crate::aggregates::aggregate_function_factory::AggregateFunctionDescription;
+use crate::aggregates::aggregator_common::assert_unary_arguments;
+use crate::aggregates::AggregateFunction;
+use crate::aggregates::AggregateFunctionRef;
+
+#[derive(Serialize, Deserialize)]
+struct AggregateQuantileState {
+    pub values: Vec<f64>,
+    pub sorted: bool,
+}
+
+impl AggregateQuantileState {
+    #[inline(always)]
+    fn add(&mut self, value: f64) {
+        self.values.push(value);
+        self.sorted = false;
+    }
+
+    #[inline(always)]
+    fn merge(&mut self, other: &Self) {
+        if other.values.is_empty() {
+            return;
+        }
+        self.values.extend_from_slice(&other.values);
+        self.sorted = false;
+    }
+
+    fn sort(&mut self) {
+        if !self.sorted {
+            self.values.sort_unstable_by(|a, b| a.total_cmp(b));
+            self.sorted = true;
+        }
+    }
+
+    /// Returns the exact quantile at `level`, linearly interpolating between the
+    /// two closest ranks. The values must be sorted.
+    fn quantile(&self, level: f64) -> f64 {
+        if self.values.is_empty() {
+            return 0.0;
+        }
+
+        let pos = level * (self.values.len() - 1) as f64;
+        let lower = pos.floor() as usize;
+        let upper = pos.ceil() as usize;
+        let (a, b) = (self.values[lower], self.values[upper]);
+        a + (b - a) * (pos - lower as f64)
+    }
+}
+
+#[derive(Clone)]
+pub struct AggregateQuantileFunction<T> {
+    display_name: String,
+    _arguments: Vec<DataField>,
+    levels: Vec<f64>,
+    return_array: bool,
+    t: PhantomData<T>,
+}
+
+impl<T> AggregateFunction for AggregateQuantileFunction<T>
+where T: PrimitiveType + AsPrimitive<f64>
+{
+    fn name(&self) -> &str {
+        "AggregateQuantileFunction"
+    }
+
+    fn return_type(&self) -> Result<DataTypeImpl> {
+        if self.return_array {
+            Ok(ArrayType::new_impl(f64::to_data_type()))
+        } else {
+            Ok(f64::to_data_type())
+        }
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(|| AggregateQuantileState {
+            values: Vec::new(),
+            sorted: true,
+        });
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateQuantileState>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[ColumnRef],
+        validity: Option<&Bitmap>,
+        _input_rows: usize,
+    ) -> Result<()> {
+        let state = place.get::<AggregateQuantileState>();
+        let column: &PrimitiveColumn<T> = unsafe { Series::static_cast(&columns[0]) };
+
+        match validity {
+            Some(bitmap) => {
+                for (value, is_valid) in column.iter().zip(bitmap.iter()) {
+                    if is_valid {
+                        state.add(value.as_());
+                    }
+                }
+            }
+            None => {
+                state.values.reserve(column.len());
+                for value in column.iter() {
+                    state.add(value.as_());
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        columns: &[ColumnRef],
+        _input_rows: usize,
+    ) -> Result<()> {
+        let column: &PrimitiveColumn<T> = unsafe { Series::static_cast(&columns[0]) };
+
+        column.iter().zip(places.iter()).for_each(|(value, place)| {
+            let place = place.next(offset);
+            let state = place.get::<AggregateQuantileState>();
+            state.add(value.as_());
+        });
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[ColumnRef], row: usize) -> Result<()> {
+        let column: &PrimitiveColumn<T> = unsafe { Series::static_cast(&columns[0]) };
+
+        let state = place.get::<AggregateQuantileState>();
+        let v: f64 = unsafe { column.value_unchecked(row).as_() };
+        state.add(v);
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
+        let state = place.get::<AggregateQuantileState>();
+        serialize_into_buf(writer, state)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateQuantileState>();
+        *state = deserialize_from_slice(reader)?;
+
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<AggregateQuantileState>();
+        let rhs = rhs.get::<AggregateQuantileState>();
+        state.merge(rhs);
+        Ok(())
+    }
+
+    #[allow(unused_mut)]
+    fn merge_result(&self, place: StateAddr, column: &mut dyn MutableColumn) -> Result<()> {
+        let state = place.get::<AggregateQuantileState>();
+        state.sort();
+
+        if self.return_array {
+            let builder: &mut MutableArrayColumn = Series::check_get_mutable_column(column)?;
+            let inner_column: &mut MutablePrimitiveColumn<f64> =
+                Series::check_get_mutable_column(builder.inner_column.as_mut())?;
+            for level in self.levels.iter() {
+                inner_column.append_value(state.quantile(*level));
+            }
+            builder.add_offset(self.levels.len());
+        } else {
+            let column: &mut MutablePrimitiveColumn<f64> =
+                Series::check_get_mutable_column(column)?;
+            column.push(state.quantile(self.levels[0]));
+        }
+        Ok(())
+    }
+
+    fn need_manual_drop_state(&self) -> bool {
+        true
+    }
+
+    unsafe fn drop_state(&self, place: StateAddr) {
+        let state = place.get::<AggregateQuantileState>();
+        std::ptr::drop_in_place(state);
+    }
+}
+
+impl<T> fmt::Display for AggregateQuantileFunction<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+impl<T> AggregateQuantileFunction<T>
+where T: PrimitiveType + AsPrimitive<f64>
+{
+    pub fn try_create(
+        display_name: &str,
+        levels: Vec<f64>,
+        return_array: bool,
+        arguments: Vec<DataField>,
+    ) -> Result<AggregateFunctionRef> {
+        Ok(Arc::new(Self {
+            display_name: display_name.to_string(),
+            _arguments: arguments,
+            levels,
+            return_array,
+            t: PhantomData,
+        }))
+    }
+}
+
+/// Parses the quantile levels from the parameters, every level must be in [0, 1].
+pub(crate) fn get_quantile_levels(display_name: &str, params: &[DataValue]) -> Result<Vec<f64>> {
+    params
+        .iter()
+        .map(|param| {
+            let level = param.as_f64()?;
+            if !(0.0..=1.0).contains(&level) {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Quantile level of {} must be in [0, 1], but got {}",
+                    display_name, level
+                )));
+            }
+            Ok(level)
+        })
+        .collect()
+}
+
+fn try_create_aggregate_quantile(
+    display_name: &str,
+    levels: Vec<f64>,
+    return_array: bool,
+    arguments: Vec<DataField>,
+) -> Result<AggregateFunctionRef> {
+    assert_unary_arguments(display_name, arguments.len())?;
+
+    let data_type = arguments[0].data_type();
+
+    with_match_primitive_type_id!(data_type.data_type_id(), |$T| {
+        AggregateQuantileFunction::<$T>::try_create(display_name, levels, return_array, arguments)
+    },
+
+    {
+        Err(ErrorCode::BadDataValueType(format!(
+            "AggregateQuantileFunction does not support type '{:?}'",
+            data_type
+        )))
+    })
+}
+
+pub fn try_create_aggregate_median_function(
+    display_name: &str,
+    params: Vec<DataValue>,
+    arguments: Vec<DataField>,
+) -> Result<AggregateFunctionRef> {
+    if !params.is_empty() {
+        return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+            "{} expect to have no parameters, but got {}",
+            display_name,
+            params.len()
+        )));
+    }
+
+    try_create_aggregate_quantile(display_name, vec![0.5], false, arguments)
+}
+
+pub fn try_create_aggregate_quantile_function(
+    display_name: &str,
+    params: Vec<DataValue>,
+    arguments: Vec<DataField>,
+) -> Result<AggregateFunctionRef> {
+    let levels = match params.len() {
+        0 => vec![0.5],
+        1 => get_quantile_levels(display_name, &params)?,
+        n => {
+            return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+                "{} expect to have at most one parameter, but got {}",
+                display_name, n
+            )));
+        }
+    };
+
+    try_create_aggregate_quantile(display_name, levels, false, arguments)
+}
+
+pub fn try_create_aggregate_quantiles_function(
+    display_name: &str,
+    params: Vec<DataValue>,
+    arguments: Vec<DataField>,
+) -> Result<AggregateFunctionRef> {
+    if params.is_empty() {
+        return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+            "{} expect to have at least one parameter",
+            display_name
+        )));
+    }
+
+    let levels = get_quantile_levels(display_name, &params)?;
+    try_create_aggregate_quantile(display_name, levels, true, arguments)
+}
+
+pub fn aggregate_median_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_median_function))
+}
+
+pub fn aggregate_quantile_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_quantile_function))
+}
+
+pub fn aggregate_quantiles_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_quantiles_function))
+}
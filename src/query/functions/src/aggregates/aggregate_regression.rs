@@ -0,0 +1,507 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::*;
+use num::cast::AsPrimitive;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::StateAddr;
+use crate::aggregates::aggregate_function_factory::AggregateFunctionDescription;
+use crate::aggregates::aggregate_function_factory::AggregateFunctionFeatures;
+use crate::aggregates::aggregator_common::assert_binary_arguments;
+use crate::aggregates::AggregateFunction;
+use crate::aggregates::AggregateFunctionRef;
+use crate::with_match_primitive_type_ids;
+
+/// The state of the linear regression of `y` on `x`, the functions are called like
+/// `regr_slope(y, x)`, with the dependent variable first.
+#[derive(Serialize, Deserialize, Default)]
+pub struct AggregateRegressionState {
+    pub count: u64,
+    pub x_mean: f64,
+    pub y_mean: f64,
+    /// Sum of `(x - x_mean)^2`.
+    pub x_m2: f64,
+    /// Sum of `(y - y_mean)^2`.
+    pub y_m2: f64,
+    /// Sum of `(x - x_mean) * (y - y_mean)`.
+    pub co_moments: f64,
+}
+
+// Welford's online algorithm, and the pairwise merge from "Updating Formulae and a
+// Pairwise Algorithm for Computing Sample Variances" (T. Chan et al., 1979).
+impl AggregateRegressionState {
+    #[inline(always)]
+    fn add(&mut self, y: f64, x: f64) {
+        self.count += 1;
+        let n = self.count as f64;
+
+        let x_delta = x - self.x_mean;
+        let y_delta = y - self.y_mean;
+        self.x_mean += x_delta / n;
+        self.y_mean += y_delta / n;
+
+        self.x_m2 += x_delta * (x - self.x_mean);
+        self.y_m2 += y_delta * (y - self.y_mean);
+        self.co_moments += x_delta * (y - self.y_mean);
+    }
+
+    #[inline(always)]
+    fn merge(&mut self, other: &Self) {
+        let total = self.count + other.count;
+        if total == 0 {
+            return;
+        }
+
+        let n = total as f64;
+        let factor = self.count as f64 * other.count as f64 / n;
+        let x_delta = other.x_mean - self.x_mean;
+        let y_delta = other.y_mean - self.y_mean;
+
+        self.x_m2 += other.x_m2 + x_delta * x_delta * factor;
+        self.y_m2 += other.y_m2 + y_delta * y_delta * factor;
+        self.co_moments += other.co_moments + x_delta * y_delta * factor;
+        self.x_mean += x_delta * other.count as f64 / n;
+        self.y_mean += y_delta * other.count as f64 / n;
+        self.count = total;
+    }
+
+    fn slope(&self) -> f64 {
+        self.co_moments / self.x_m2
+    }
+}
+
+#[derive(Clone)]
+pub struct AggregateRegressionFunction<T0, T1, R> {
+    display_name: String,
+    _arguments: Vec<DataField>,
+    t0: PhantomData<T0>,
+    t1: PhantomData<T1>,
+    r: PhantomData<R>,
+}
+
+impl<T0, T1, R> AggregateFunction for AggregateRegressionFunction<T0, T1, R>
+where
+    T0: PrimitiveType + AsPrimitive<f64>,
+    T1: PrimitiveType + AsPrimitive<f64>,
+    R: AggregateRegression,
+{
+    fn name(&self) -> &str {
+        R::name()
+    }
+
+    fn return_type(&self) -> Result<DataTypeImpl> {
+        Ok(R::return_type())
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(AggregateRegressionState::default);
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateRegressionState>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[ColumnRef],
+        validity: Option<&Bitmap>,
+        _input_rows: usize,
+    ) -> Result<()> {
+        let state = place.get::<AggregateRegressionState>();
+        let left: &PrimitiveColumn<T0> = unsafe { Series::static_cast(&columns[0]) };
+        let right: &PrimitiveColumn<T1> = unsafe { Series::static_cast(&columns[1]) };
+
+        match validity {
+            Some(bitmap) => {
+                left.iter().zip(right.iter()).zip(bitmap.iter()).for_each(
+                    |((left_val, right_val), valid)| {
+                        if valid {
+                            state.add(left_val.as_(), right_val.as_());
+                        }
+                    },
+                );
+            }
+            None => {
+                left.iter()
+                    .zip(right.iter())
+                    .for_each(|(left_val, right_val)| {
+                        state.add(left_val.as_(), right_val.as_());
+                    });
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        columns: &[ColumnRef],
+        _input_rows: usize,
+    ) -> Result<()> {
+        let left: &PrimitiveColumn<T0> = unsafe { Series::static_cast(&columns[0]) };
+        let right: &PrimitiveColumn<T1> = unsafe { Series::static_cast(&columns[1]) };
+
+        left.iter().zip(right.iter()).zip(places.iter()).for_each(
+            |((left_val, right_val), place)| {
+                let place = place.next(offset);
+                let state = place.get::<AggregateRegressionState>();
+                state.add(left_val.as_(), right_val.as_());
+            },
+        );
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[ColumnRef], row: usize) -> Result<()> {
+        let left: &PrimitiveColumn<T0> = unsafe { Series::static_cast(&columns[0]) };
+        let right: &PrimitiveColumn<T1> = unsafe { Series::static_cast(&columns[1]) };
+
+        let left_val = unsafe { left.value_unchecked(row) };
+        let right_val = unsafe { right.value_unchecked(row) };
+
+        let state = place.get::<AggregateRegressionState>();
+        state.add(left_val.as_(), right_val.as_());
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
+        let state = place.get::<AggregateRegressionState>();
+        serialize_into_buf(writer, state)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateRegressionState>();
+        *state = deserialize_from_slice(reader)?;
+
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<AggregateRegressionState>();
+        let rhs = rhs.get::<AggregateRegressionState>();
+        state.merge(rhs);
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr, column: &mut dyn MutableColumn) -> Result<()> {
+        let state = place.get::<AggregateRegressionState>();
+        R::merge_result(state, column)
+    }
+}
+
+impl<T0, T1, R> fmt::Display for AggregateRegressionFunction<T0, T1, R> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+impl<T0, T1, R> AggregateRegressionFunction<T0, T1, R>
+where
+    T0: PrimitiveType + AsPrimitive<f64>,
+    T1: PrimitiveType + AsPrimitive<f64>,
+    R: AggregateRegression,
+{
+    pub fn try_create(
+        display_name: &str,
+        arguments: Vec<DataField>,
+    ) -> Result<AggregateFunctionRef> {
+        Ok(Arc::new(Self {
+            display_name: display_name.to_string(),
+            _arguments: arguments,
+            t0: PhantomData,
+            t1: PhantomData,
+            r: PhantomData,
+        }))
+    }
+}
+
+pub fn try_create_aggregate_regression<R: AggregateRegression>(
+    display_name: &str,
+    _params: Vec<DataValue>,
+    arguments: Vec<DataField>,
+) -> Result<Arc<dyn AggregateFunction>> {
+    assert_binary_arguments(display_name, arguments.len())?;
+
+    let data_type0 = arguments[0].data_type();
+    let data_type1 = arguments[1].data_type();
+
+    with_match_primitive_type_ids!(data_type0.data_type_id(), data_type1.data_type_id(), |$T0, $T1| {
+        AggregateRegressionFunction::<$T0, $T1, R>::try_create(display_name, arguments)
+    },
+    {
+        Err(ErrorCode::BadDataValueType(format!(
+            "AggregateRegressionFunction does not support type '{:?}' or '{:?}'",
+            data_type0, data_type1
+        )))
+    })
+}
+
+fn aggregate_regression_desc<R: AggregateRegression>() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_regression::<R>))
+}
+
+pub trait AggregateRegression: Send + Sync + 'static {
+    fn name() -> &'static str;
+
+    fn apply(state: &AggregateRegressionState) -> f64;
+
+    fn return_type() -> DataTypeImpl {
+        f64::to_data_type()
+    }
+
+    fn merge_result(
+        state: &AggregateRegressionState,
+        column: &mut dyn MutableColumn,
+    ) -> Result<()> {
+        let column: &mut MutablePrimitiveColumn<f64> = Series::check_get_mutable_column(column)?;
+        column.append_value(Self::apply(state));
+        Ok(())
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Correlation function implementation
+struct AggregateCorrelationImpl;
+
+impl AggregateRegression for AggregateCorrelationImpl {
+    fn name() -> &'static str {
+        "AggregateCorrelationFunction"
+    }
+
+    fn apply(state: &AggregateRegressionState) -> f64 {
+        if state.count < 2 {
+            f64::INFINITY
+        } else {
+            state.co_moments / (state.x_m2 * state.y_m2).sqrt()
+        }
+    }
+}
+
+pub fn aggregate_correlation_desc() -> AggregateFunctionDescription {
+    aggregate_regression_desc::<AggregateCorrelationImpl>()
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+///////////////////////////////////////////////////////////////////////////////
+// Slope of the least-squares-fit linear equation
+struct AggregateRegrSlopeImpl;
+
+impl AggregateRegression for AggregateRegrSlopeImpl {
+    fn name() -> &'static str {
+        "AggregateRegrSlopeFunction"
+    }
+
+    fn apply(state: &AggregateRegressionState) -> f64 {
+        if state.count < 2 {
+            f64::INFINITY
+        } else {
+            state.slope()
+        }
+    }
+}
+
+pub fn aggregate_regr_slope_desc() -> AggregateFunctionDescription {
+    aggregate_regression_desc::<AggregateRegrSlopeImpl>()
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+///////////////////////////////////////////////////////////////////////////////
+// Y-intercept of the least-squares-fit linear equation
+struct AggregateRegrInterceptImpl;
+
+impl AggregateRegression for AggregateRegrInterceptImpl {
+    fn name() -> &'static str {
+        "AggregateRegrInterceptFunction"
+    }
+
+    fn apply(state: &AggregateRegressionState) -> f64 {
+        if state.count < 2 {
+            f64::INFINITY
+        } else {
+            state.y_mean - state.slope() * state.x_mean
+        }
+    }
+}
+
+pub fn aggregate_regr_intercept_desc() -> AggregateFunctionDescription {
+    aggregate_regression_desc::<AggregateRegrInterceptImpl>()
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+///////////////////////////////////////////////////////////////////////////////
+// Square of the correlation coefficient
+struct AggregateRegrR2Impl;
+
+impl AggregateRegression for AggregateRegrR2Impl {
+    fn name() -> &'static str {
+        "AggregateRegrR2Function"
+    }
+
+    fn apply(state: &AggregateRegressionState) -> f64 {
+        if state.count < 2 || state.x_m2 == 0.0 {
+            f64::INFINITY
+        } else if state.y_m2 == 0.0 {
+            // A horizontal line fits perfectly.
+            1.0
+        } else {
+            state.co_moments * state.co_moments / (state.x_m2 * state.y_m2)
+        }
+    }
+}
+
+pub fn aggregate_regr_r2_desc() -> AggregateFunctionDescription {
+    aggregate_regression_desc::<AggregateRegrR2Impl>()
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+///////////////////////////////////////////////////////////////////////////////
+// Number of rows in which both expressions are not NULL
+struct AggregateRegrCountImpl;
+
+impl AggregateRegression for AggregateRegrCountImpl {
+    fn name() -> &'static str {
+        "AggregateRegrCountFunction"
+    }
+
+    fn apply(state: &AggregateRegressionState) -> f64 {
+        state.count as f64
+    }
+
+    fn return_type() -> DataTypeImpl {
+        u64::to_data_type()
+    }
+
+    fn merge_result(
+        state: &AggregateRegressionState,
+        column: &mut dyn MutableColumn,
+    ) -> Result<()> {
+        let column: &mut MutablePrimitiveColumn<u64> = Series::check_get_mutable_column(column)?;
+        column.append_value(state.count);
+        Ok(())
+    }
+}
+
+pub fn aggregate_regr_count_desc() -> AggregateFunctionDescription {
+    // Like `count`, returns 0 instead of NULL for an empty set.
+    let features = AggregateFunctionFeatures {
+        returns_default_when_only_null: true,
+        ..Default::default()
+    };
+    AggregateFunctionDescription::creator_with_features(
+        Box::new(try_create_aggregate_regression::<AggregateRegrCountImpl>),
+        features,
+    )
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+///////////////////////////////////////////////////////////////////////////////
+// Averages and sums of squares of the independent and dependent variables
+struct AggregateRegrAvgxImpl;
+
+impl AggregateRegression for AggregateRegrAvgxImpl {
+    fn name() -> &'static str {
+        "AggregateRegrAvgxFunction"
+    }
+
+    fn apply(state: &AggregateRegressionState) -> f64 {
+        state.x_mean
+    }
+}
+
+pub fn aggregate_regr_avgx_desc() -> AggregateFunctionDescription {
+    aggregate_regression_desc::<AggregateRegrAvgxImpl>()
+}
+
+struct AggregateRegrAvgyImpl;
+
+impl AggregateRegression for AggregateRegrAvgyImpl {
+    fn name() -> &'static str {
+        "AggregateRegrAvgyFunction"
+    }
+
+    fn apply(state: &AggregateRegressionState) -> f64 {
+        state.y_mean
+    }
+}
+
+pub fn aggregate_regr_avgy_desc() -> AggregateFunctionDescription {
+    aggregate_regression_desc::<AggregateRegrAvgyImpl>()
+}
+
+struct AggregateRegrSxxImpl;
+
+impl AggregateRegression for AggregateRegrSxxImpl {
+    fn name() -> &'static str {
+        "AggregateRegrSxxFunction"
+    }
+
+    fn apply(state: &AggregateRegressionState) -> f64 {
+        state.x_m2
+    }
+}
+
+pub fn aggregate_regr_sxx_desc() -> AggregateFunctionDescription {
+    aggregate_regression_desc::<AggregateRegrSxxImpl>()
+}
+
+struct AggregateRegrSyyImpl;
+
+impl AggregateRegression for AggregateRegrSyyImpl {
+    fn name() -> &'static str {
+        "AggregateRegrSyyFunction"
+    }
+
+    fn apply(state: &AggregateRegressionState) -> f64 {
+        state.y_m2
+    }
+}
+
+pub fn aggregate_regr_syy_desc() -> AggregateFunctionDescription {
+    aggregate_regression_desc::<AggregateRegrSyyImpl>()
+}
+
+struct AggregateRegrSxyImpl;
+
+impl AggregateRegression for AggregateRegrSxyImpl {
+    fn name() -> &'static str {
+        "AggregateRegrSxyFunction"
+    }
+
+    fn apply(state: &AggregateRegressionState) -> f64 {
+        state.co_moments
+    }
+}
+
+pub fn aggregate_regr_sxy_desc() -> AggregateFunctionDescription {
+    aggregate_regression_desc::<AggregateRegrSxyImpl>()
+}
+
+///////////////////////////////////////////////////////////////////////////////
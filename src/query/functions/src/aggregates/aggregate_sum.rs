@@ -28,6 +28,7 @@ use num::traits::AsPrimitive;
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 
+use super::aggregate_decimal::AggregateDecimalSumFunction;
 use super::aggregate_function::AggregateFunction;
 use super::aggregate_function::AggregateFunctionRef;
 use super::aggregate_function_factory::AggregateFunctionDescription;
@@ -216,6 +217,11 @@ pub fn try_create_aggregate_sum_function(
         return AggregateSumFunction::<u8, u64>::try_create(display_name, arguments);
     }
 
+    if let DataTypeImpl::Decimal(decimal) = data_type {
+        let scale = decimal.scale();
+        return AggregateDecimalSumFunction::try_create(display_name, arguments, scale);
+    }
+
     let mut phid = data_type.data_type_id();
     // null use dummy func, it's already covered in `AggregateNullResultFunction`
     if data_type.is_null() {
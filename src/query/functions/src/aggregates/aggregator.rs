@@ -12,16 +12,41 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use super::aggregate_approx_count_distinct::aggregate_approx_count_distinct_function_desc;
+use super::aggregate_approx_count_distinct::aggregate_hll_merge_function_desc;
+use super::aggregate_approx_count_distinct::aggregate_hll_sketch_function_desc;
+use super::aggregate_approx_percentile::aggregate_approx_percentile_function_desc;
 use super::aggregate_arg_min_max::aggregate_arg_max_function_desc;
 use super::aggregate_arg_min_max::aggregate_arg_min_function_desc;
+use super::aggregate_array_agg::aggregate_array_agg_function_desc;
+use super::aggregate_array_agg::aggregate_string_agg_function_desc;
 use super::aggregate_avg::aggregate_avg_function_desc;
 use super::aggregate_combinator_distinct::aggregate_combinator_distinct_desc;
+use super::aggregate_combinator_distinct::aggregate_combinator_group_array_distinct_desc;
 use super::aggregate_combinator_distinct::aggregate_combinator_uniq_desc;
 use super::aggregate_covariance::aggregate_covariance_population_desc;
 use super::aggregate_covariance::aggregate_covariance_sample_desc;
 use super::aggregate_min_max_any::aggregate_any_function_desc;
 use super::aggregate_min_max_any::aggregate_max_function_desc;
 use super::aggregate_min_max_any::aggregate_min_function_desc;
+use super::aggregate_moments::aggregate_kurtosis_desc;
+use super::aggregate_moments::aggregate_skewness_desc;
+use super::aggregate_moments::aggregate_stddev_sample_desc;
+use super::aggregate_moments::aggregate_variance_population_desc;
+use super::aggregate_moments::aggregate_variance_sample_desc;
+use super::aggregate_quantile::aggregate_median_function_desc;
+use super::aggregate_quantile::aggregate_quantile_function_desc;
+use super::aggregate_quantile::aggregate_quantiles_function_desc;
+use super::aggregate_regression::aggregate_correlation_desc;
+use super::aggregate_regression::aggregate_regr_avgx_desc;
+use super::aggregate_regression::aggregate_regr_avgy_desc;
+use super::aggregate_regression::aggregate_regr_count_desc;
+use super::aggregate_regression::aggregate_regr_intercept_desc;
+use super::aggregate_regression::aggregate_regr_r2_desc;
+use super::aggregate_regression::aggregate_regr_slope_desc;
+use super::aggregate_regression::aggregate_regr_sxx_desc;
+use super::aggregate_regression::aggregate_regr_sxy_desc;
+use super::aggregate_regression::aggregate_regr_syy_desc;
 use super::aggregate_stddev_pop::aggregate_stddev_pop_function_desc;
 use super::aggregate_window_funnel::aggregate_window_funnel_function_desc;
 use super::AggregateCountFunction;
@@ -48,14 +73,56 @@ impl Aggregators {
         factory.register("stddev", aggregate_stddev_pop_function_desc());
         factory.register("stddev_pop", aggregate_stddev_pop_function_desc());
         factory.register("std", aggregate_stddev_pop_function_desc());
+        factory.register("stddev_samp", aggregate_stddev_sample_desc());
+
+        factory.register("var_pop", aggregate_variance_population_desc());
+        factory.register("var_samp", aggregate_variance_sample_desc());
+        factory.register("variance", aggregate_variance_sample_desc());
+        factory.register("skewness", aggregate_skewness_desc());
+        factory.register("kurtosis", aggregate_kurtosis_desc());
 
         factory.register("covar_samp", aggregate_covariance_sample_desc());
         factory.register("covar_pop", aggregate_covariance_population_desc());
+        factory.register("corr", aggregate_correlation_desc());
+
+        factory.register("regr_slope", aggregate_regr_slope_desc());
+        factory.register("regr_intercept", aggregate_regr_intercept_desc());
+        factory.register("regr_r2", aggregate_regr_r2_desc());
+        factory.register("regr_count", aggregate_regr_count_desc());
+        factory.register("regr_avgx", aggregate_regr_avgx_desc());
+        factory.register("regr_avgy", aggregate_regr_avgy_desc());
+        factory.register("regr_sxx", aggregate_regr_sxx_desc());
+        factory.register("regr_syy", aggregate_regr_syy_desc());
+        factory.register("regr_sxy", aggregate_regr_sxy_desc());
 
         factory.register("window_funnel", aggregate_window_funnel_function_desc());
         factory.register("uniq", aggregate_combinator_uniq_desc());
 
         factory.register("retention", aggregate_retention_function_desc());
+
+        factory.register(
+            "approx_count_distinct",
+            aggregate_approx_count_distinct_function_desc(),
+        );
+        factory.register("hll_sketch", aggregate_hll_sketch_function_desc());
+        factory.register("hll_merge", aggregate_hll_merge_function_desc());
+
+        factory.register("median", aggregate_median_function_desc());
+        factory.register("quantile", aggregate_quantile_function_desc());
+        factory.register("quantiles", aggregate_quantiles_function_desc());
+        factory.register(
+            "approx_percentile",
+            aggregate_approx_percentile_function_desc(),
+        );
+
+        factory.register("array_agg", aggregate_array_agg_function_desc());
+        factory.register("group_array", aggregate_array_agg_function_desc());
+        factory.register(
+            "group_array_distinct",
+            aggregate_combinator_group_array_distinct_desc(),
+        );
+        factory.register("string_agg", aggregate_string_agg_function_desc());
+        factory.register("listagg", aggregate_string_agg_function_desc());
     }
 
     pub fn register_combinator(factory: &mut AggregateFunctionFactory) {
@@ -17,6 +17,7 @@ mod aggregate_function;
 
 mod aggregate_function_factory;
 mod aggregate_function_state;
+mod aggregate_hll_state;
 
 mod aggregate_sum;
 mod aggregator;
@@ -26,34 +27,49 @@ mod adaptors;
 
 #[macro_use]
 mod macros;
+mod aggregate_approx_count_distinct;
+mod aggregate_approx_percentile;
 mod aggregate_arg_min_max;
+mod aggregate_array_agg;
 mod aggregate_avg;
 mod aggregate_combinator_distinct;
 mod aggregate_combinator_if;
 mod aggregate_covariance;
+mod aggregate_decimal;
 mod aggregate_distinct_state;
 mod aggregate_min_max_any;
+mod aggregate_moments;
 mod aggregate_null_result;
+mod aggregate_quantile;
+mod aggregate_regression;
 mod aggregate_retention;
 mod aggregate_scalar_state;
 mod aggregate_stddev_pop;
 mod aggregate_window_funnel;
 
 pub use adaptors::*;
+pub use aggregate_approx_count_distinct::AggregateApproxCountDistinctFunction;
+pub use aggregate_approx_percentile::AggregateApproxPercentileFunction;
 pub use aggregate_arg_min_max::AggregateArgMinMaxFunction;
+pub use aggregate_array_agg::AggregateArrayAggFunction;
 pub use aggregate_avg::AggregateAvgFunction;
 pub use aggregate_combinator_distinct::AggregateDistinctCombinator;
 pub use aggregate_combinator_if::AggregateIfCombinator;
 pub use aggregate_count::AggregateCountFunction;
 pub use aggregate_covariance::AggregateCovarianceFunction;
+pub use aggregate_decimal::AggregateDecimalFunction;
 pub use aggregate_function::AggregateFunction;
 pub use aggregate_function::AggregateFunctionRef;
 pub use aggregate_function_factory::AggregateFunctionFactory;
 pub use aggregate_function_state::get_layout_offsets;
 pub use aggregate_function_state::StateAddr;
 pub use aggregate_function_state::StateAddrs;
+pub use aggregate_hll_state::HyperLogLog;
 pub use aggregate_min_max_any::AggregateMinMaxAnyFunction;
+pub use aggregate_moments::AggregateMomentsFunction;
 pub use aggregate_null_result::AggregateNullResultFunction;
+pub use aggregate_quantile::AggregateQuantileFunction;
+pub use aggregate_regression::AggregateRegressionFunction;
 pub use aggregate_retention::AggregateRetentionFunction;
 pub use aggregate_stddev_pop::AggregateStddevPopFunction;
 pub use aggregate_sum::AggregateSumFunction;
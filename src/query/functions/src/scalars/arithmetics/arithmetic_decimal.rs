@@ -0,0 +1,257 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::default_column_cast;
+use crate::scalars::scalar_binary_op;
+use crate::scalars::EvalContext;
+use crate::scalars::Function;
+use crate::scalars::FunctionContext;
+
+/// Arithmetic between a decimal and a decimal or integer. The result type is inferred by
+/// `decimal_arithmetic_result_type`, and an overflowing result is reported as an error.
+/// If the other side is a floating number, both sides are computed as Float64.
+#[derive(Clone)]
+pub struct DecimalArithmeticFunction {
+    op: DataValueBinaryOperator,
+    lhs: DataTypeImpl,
+    rhs: DataTypeImpl,
+    result_type: DataTypeImpl,
+}
+
+impl DecimalArithmeticFunction {
+    pub fn is_decimal_arithmetic(args: &[&DataTypeImpl]) -> bool {
+        args.iter().any(|arg| arg.data_type_id().is_decimal())
+    }
+
+    pub fn try_create_func(
+        op: DataValueBinaryOperator,
+        args: &[&DataTypeImpl],
+    ) -> Result<Box<dyn Function>> {
+        let coerced_type = |arg: &DataTypeImpl| -> Result<DataTypeImpl> {
+            let type_id = arg.data_type_id();
+            if type_id.is_floating() {
+                return Ok(Float64Type::new_impl());
+            }
+            match arg {
+                DataTypeImpl::Decimal(_) => Ok(arg.clone()),
+                _ => match decimal_type_of_integer(type_id) {
+                    Some(decimal) => Ok(DataTypeImpl::Decimal(decimal)),
+                    None => Err(ErrorCode::BadDataValueType(format!(
+                        "DataValue Error: Unsupported arithmetic ({:?}) {} ({:?})",
+                        args[0].data_type_id(),
+                        op,
+                        args[1].data_type_id()
+                    ))),
+                },
+            }
+        };
+
+        let lhs = coerced_type(args[0])?;
+        let rhs = coerced_type(args[1])?;
+        let result_type = match (&lhs, &rhs) {
+            (DataTypeImpl::Decimal(l), DataTypeImpl::Decimal(r)) => {
+                DataTypeImpl::Decimal(decimal_arithmetic_result_type(&op, l, r)?)
+            }
+            _ if matches!(op, DataValueBinaryOperator::IntDiv) => Int64Type::new_impl(),
+            _ => Float64Type::new_impl(),
+        };
+        let (lhs, rhs) = match result_type {
+            DataTypeImpl::Decimal(_) => (lhs, rhs),
+            _ => (Float64Type::new_impl(), Float64Type::new_impl()),
+        };
+
+        Ok(Box::new(Self {
+            op,
+            lhs,
+            rhs,
+            result_type,
+        }))
+    }
+
+    fn eval_float(&self, lhs: &ColumnRef, rhs: &ColumnRef) -> Result<ColumnRef> {
+        let mut ctx = EvalContext::default();
+        let col = match self.op {
+            DataValueBinaryOperator::Plus => scalar_binary_op(
+                lhs,
+                rhs,
+                |l: f64, r: f64, _: &mut EvalContext| l + r,
+                &mut ctx,
+            ),
+            DataValueBinaryOperator::Minus => scalar_binary_op(
+                lhs,
+                rhs,
+                |l: f64, r: f64, _: &mut EvalContext| l - r,
+                &mut ctx,
+            ),
+            DataValueBinaryOperator::Mul => scalar_binary_op(
+                lhs,
+                rhs,
+                |l: f64, r: f64, _: &mut EvalContext| l * r,
+                &mut ctx,
+            ),
+            DataValueBinaryOperator::Div => scalar_binary_op(
+                lhs,
+                rhs,
+                |l: f64, r: f64, _: &mut EvalContext| l / r,
+                &mut ctx,
+            ),
+            DataValueBinaryOperator::Modulo => scalar_binary_op(
+                lhs,
+                rhs,
+                |l: f64, r: f64, _: &mut EvalContext| l % r,
+                &mut ctx,
+            ),
+            DataValueBinaryOperator::IntDiv => {
+                let col = scalar_binary_op(
+                    lhs,
+                    rhs,
+                    |l: f64, r: f64, ctx: &mut EvalContext| {
+                        if r == 0.0 {
+                            ctx.set_error(ErrorCode::BadArguments("Division by zero"));
+                            return 0;
+                        }
+                        (l / r) as i64
+                    },
+                    &mut ctx,
+                )?;
+                return Ok(Arc::new(col));
+            }
+        }?;
+        Ok(Arc::new(col))
+    }
+
+    fn eval_decimal(&self, lhs: &ColumnRef, rhs: &ColumnRef) -> Result<ColumnRef> {
+        let l_scale = decimal_of(&self.lhs).scale();
+        let r_scale = decimal_of(&self.rhs).scale();
+        let result = decimal_of(&self.result_type);
+        let scale = result.scale();
+        let max_value = result.max_value();
+
+        let overflow = |ctx: &mut EvalContext| {
+            ctx.set_error(ErrorCode::Overflow(format!(
+                "Decimal overflow in {} of {:?} and {:?}",
+                self.op, self.lhs, self.rhs
+            )));
+            0
+        };
+        let division_by_zero = |ctx: &mut EvalContext| {
+            ctx.set_error(ErrorCode::BadArguments("Division by zero"));
+            0
+        };
+        // Both sides rescaled to the larger scale of them.
+        let common_scale = l_scale.max(r_scale);
+        let align = move |l: i128, r: i128| -> Option<(i128, i128)> {
+            Some((
+                rescale_decimal(l, l_scale, common_scale)?,
+                rescale_decimal(r, r_scale, common_scale)?,
+            ))
+        };
+
+        let func = move |l: i128, r: i128, ctx: &mut EvalContext| -> i128 {
+            let value = match self.op {
+                DataValueBinaryOperator::Plus => align(l, r)
+                    .and_then(|(l, r)| l.checked_add(r))
+                    .and_then(|v| rescale_decimal(v, common_scale, scale)),
+                DataValueBinaryOperator::Minus => align(l, r)
+                    .and_then(|(l, r)| l.checked_sub(r))
+                    .and_then(|v| rescale_decimal(v, common_scale, scale)),
+                DataValueBinaryOperator::Mul => l
+                    .checked_mul(r)
+                    .and_then(|v| rescale_decimal(v, l_scale + r_scale, scale)),
+                DataValueBinaryOperator::Div => {
+                    if r == 0 {
+                        return division_by_zero(ctx);
+                    }
+                    // l / 10^l_scale / (r / 10^r_scale) * 10^scale, rounded half away from zero.
+                    (scale + r_scale)
+                        .checked_sub(l_scale)
+                        .and_then(|shift| rescale_decimal(l, 0, shift))
+                        .and_then(|l| {
+                            let (quotient, remainder) = (l / r, l % r);
+                            if remainder.unsigned_abs() * 2 >= r.unsigned_abs() {
+                                quotient.checked_add(l.signum() * r.signum())
+                            } else {
+                                Some(quotient)
+                            }
+                        })
+                }
+                DataValueBinaryOperator::IntDiv => {
+                    if r == 0 {
+                        return division_by_zero(ctx);
+                    }
+                    align(l, r).map(|(l, r)| l / r)
+                }
+                DataValueBinaryOperator::Modulo => {
+                    if r == 0 {
+                        return division_by_zero(ctx);
+                    }
+                    align(l, r)
+                        .map(|(l, r)| l % r)
+                        .and_then(|v| rescale_decimal(v, common_scale, scale))
+                }
+            };
+            match value {
+                Some(v) if v.unsigned_abs() <= max_value as u128 => v,
+                _ => overflow(ctx),
+            }
+        };
+
+        let col = scalar_binary_op(lhs, rhs, func, &mut EvalContext::default())?;
+        Ok(Arc::new(col))
+    }
+}
+
+fn decimal_of(data_type: &DataTypeImpl) -> DecimalType {
+    match data_type {
+        DataTypeImpl::Decimal(decimal) => decimal.clone(),
+        _ => unreachable!(),
+    }
+}
+
+impl Function for DecimalArithmeticFunction {
+    fn name(&self) -> &str {
+        "DecimalArithmeticFunction"
+    }
+
+    fn return_type(&self) -> DataTypeImpl {
+        self.result_type.clone()
+    }
+
+    fn eval(
+        &self,
+        _func_ctx: FunctionContext,
+        columns: &ColumnsWithField,
+        _input_rows: usize,
+    ) -> Result<ColumnRef> {
+        let lhs = default_column_cast(columns[0].column(), &self.lhs)?;
+        let rhs = default_column_cast(columns[1].column(), &self.rhs)?;
+        match self.result_type {
+            DataTypeImpl::Decimal(_) => self.eval_decimal(&lhs, &rhs),
+            _ => self.eval_float(&lhs, &rhs),
+        }
+    }
+}
+
+impl fmt::Display for DecimalArithmeticFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.op)
+    }
+}
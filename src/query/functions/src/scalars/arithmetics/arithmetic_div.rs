@@ -20,6 +20,7 @@ use num::traits::AsPrimitive;
 
 use super::arithmetic_mul::arithmetic_mul_div_monotonicity;
 use crate::scalars::BinaryArithmeticFunction;
+use crate::scalars::DecimalArithmeticFunction;
 use crate::scalars::EvalContext;
 use crate::scalars::Function;
 use crate::scalars::FunctionDescription;
@@ -38,6 +39,10 @@ impl ArithmeticDivFunction {
         _display_name: &str,
         args: &[&DataTypeImpl],
     ) -> Result<Box<dyn Function>> {
+        if DecimalArithmeticFunction::is_decimal_arithmetic(args) {
+            return DecimalArithmeticFunction::try_create_func(DataValueBinaryOperator::Div, args);
+        }
+
         with_match_primitive_types_error!(args[0].data_type_id(), |$T| {
             with_match_primitive_types_error!(args[1].data_type_id(), |$D| {
                 BinaryArithmeticFunction::<$T, $D, f64, _>::try_create_func(
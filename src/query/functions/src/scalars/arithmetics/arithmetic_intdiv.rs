@@ -20,6 +20,7 @@ use num::Zero;
 use num_traits::AsPrimitive;
 
 use crate::scalars::BinaryArithmeticFunction;
+use crate::scalars::DecimalArithmeticFunction;
 use crate::scalars::EvalContext;
 use crate::scalars::Function;
 use crate::scalars::FunctionDescription;
@@ -47,6 +48,13 @@ impl ArithmeticIntDivFunction {
         _display_name: &str,
         args: &[&DataTypeImpl],
     ) -> Result<Box<dyn Function>> {
+        if DecimalArithmeticFunction::is_decimal_arithmetic(args) {
+            return DecimalArithmeticFunction::try_create_func(
+                DataValueBinaryOperator::IntDiv,
+                args,
+            );
+        }
+
         with_match_primitive_types_error!(args[0].data_type_id(), |$T| {
             with_match_primitive_types_error!(args[1].data_type_id(), |$D| {
                 BinaryArithmeticFunction::<$T, $D, <($T, $D) as ResultTypeOfBinary>::IntDiv, _>::try_create_func(
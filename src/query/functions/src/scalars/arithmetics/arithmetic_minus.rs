@@ -24,6 +24,7 @@ use num::traits::AsPrimitive;
 use num_traits::WrappingSub;
 
 use crate::scalars::BinaryArithmeticFunction;
+use crate::scalars::DecimalArithmeticFunction;
 use crate::scalars::EvalContext;
 use crate::scalars::Function;
 use crate::scalars::FunctionDescription;
@@ -56,6 +57,13 @@ impl ArithmeticMinusFunction {
         _display_name: &str,
         args: &[&DataTypeImpl],
     ) -> Result<Box<dyn Function>> {
+        if DecimalArithmeticFunction::is_decimal_arithmetic(args) {
+            return DecimalArithmeticFunction::try_create_func(
+                DataValueBinaryOperator::Minus,
+                args,
+            );
+        }
+
         let op = DataValueBinaryOperator::Minus;
         let left_type = args[0].data_type_id();
         let right_type = args[1].data_type_id();
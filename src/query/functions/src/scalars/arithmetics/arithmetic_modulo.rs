@@ -24,6 +24,7 @@ use common_exception::Result;
 use num_traits::AsPrimitive;
 
 use super::utils::rem_scalar;
+use crate::scalars::DecimalArithmeticFunction;
 use crate::scalars::Function;
 use crate::scalars::FunctionContext;
 use crate::scalars::FunctionDescription;
@@ -36,6 +37,13 @@ impl ArithmeticModuloFunction {
         _display_name: &str,
         args: &[&DataTypeImpl],
     ) -> Result<Box<dyn Function>> {
+        if DecimalArithmeticFunction::is_decimal_arithmetic(args) {
+            return DecimalArithmeticFunction::try_create_func(
+                DataValueBinaryOperator::Modulo,
+                args,
+            );
+        }
+
         with_match_primitive_types_error!(args[0].data_type_id(), |$T| {
             with_match_primitive_types_error!(args[1].data_type_id(), |$D| {
                 Ok(Box::new(
@@ -22,6 +22,7 @@ use num::traits::AsPrimitive;
 use num_traits::WrappingMul;
 
 use crate::scalars::BinaryArithmeticFunction;
+use crate::scalars::DecimalArithmeticFunction;
 use crate::scalars::EvalContext;
 use crate::scalars::Function;
 use crate::scalars::FunctionDescription;
@@ -53,6 +54,10 @@ impl ArithmeticMulFunction {
         _display_name: &str,
         args: &[&DataTypeImpl],
     ) -> Result<Box<dyn Function>> {
+        if DecimalArithmeticFunction::is_decimal_arithmetic(args) {
+            return DecimalArithmeticFunction::try_create_func(DataValueBinaryOperator::Mul, args);
+        }
+
         let op = DataValueBinaryOperator::Mul;
 
         with_match_primitive_types_error!(args[0].data_type_id(), |$T| {
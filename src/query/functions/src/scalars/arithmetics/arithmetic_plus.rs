@@ -24,6 +24,7 @@ use num::traits::AsPrimitive;
 use num_traits::WrappingAdd;
 
 use crate::scalars::BinaryArithmeticFunction;
+use crate::scalars::DecimalArithmeticFunction;
 use crate::scalars::EvalContext;
 use crate::scalars::Function;
 use crate::scalars::FunctionDescription;
@@ -56,6 +57,10 @@ impl ArithmeticPlusFunction {
         _display_name: &str,
         args: &[&DataTypeImpl],
     ) -> Result<Box<dyn Function>> {
+        if DecimalArithmeticFunction::is_decimal_arithmetic(args) {
+            return DecimalArithmeticFunction::try_create_func(DataValueBinaryOperator::Plus, args);
+        }
+
         let op = DataValueBinaryOperator::Plus;
         let left_type = args[0].data_type_id();
         let right_type = args[1].data_type_id();
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod arithmetic;
+mod arithmetic_decimal;
 mod arithmetic_div;
 mod arithmetic_intdiv;
 mod arithmetic_minus;
@@ -25,6 +26,7 @@ mod unary_arithmetic;
 mod utils;
 
 pub use arithmetic::ArithmeticFunction;
+pub use arithmetic_decimal::DecimalArithmeticFunction;
 pub use arithmetic_div::ArithmeticDivFunction;
 pub use arithmetic_intdiv::ArithmeticIntDivFunction;
 pub use arithmetic_minus::ArithmeticMinusFunction;
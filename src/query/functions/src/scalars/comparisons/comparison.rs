@@ -117,14 +117,17 @@ impl<T: ComparisonImpl> ComparisonFunctionCreator<T> {
         display_name: &str,
         args: &[&DataTypeImpl],
     ) -> Result<Box<dyn Function>> {
-        // expect array & struct
-        let has_array_struct = args
-            .iter()
-            .any(|arg| matches!(arg.data_type_id(), TypeID::Struct | TypeID::Array));
+        // expect array & struct & map
+        let has_array_struct = args.iter().any(|arg| {
+            matches!(
+                arg.data_type_id(),
+                TypeID::Struct | TypeID::Array | TypeID::Map
+            )
+        });
 
         if has_array_struct {
             return Err(ErrorCode::BadArguments(format!(
-                "Illegal types {:?} of argument of function {}, can not be struct or array",
+                "Illegal types {:?} of argument of function {}, can not be struct, array or map",
                 args, display_name
             )));
         }
@@ -141,12 +144,17 @@ impl<T: ComparisonImpl> ComparisonFunctionCreator<T> {
             return ComparisonFunction::try_create_func(display_name, func);
         }
 
-        if args[0].eq(args[1]) {
+        // TimestampTz compares by instant, the offsets are dropped by the coercion.
+        if args[0].eq(args[1]) && !lhs_id.is_timestamp_tz() {
             return with_match_physical_primitive_type!(lhs_id.to_physical_type(), |$T| {
                 let func = Arc::new(ComparisonPrimitiveImpl::<$T, _>::new(args[0].clone(), false, T::eval_simd::<$T>));
                 ComparisonFunction::try_create_func(display_name, func)
             }, {
                 match lhs_id {
+                    TypeID::Decimal => {
+                        let func = Arc::new(ComparisonPrimitiveImpl::<i128, _>::new(args[0].clone(), false, T::eval_simd::<i128>));
+                        ComparisonFunction::try_create_func(display_name, func)
+                    },
                     TypeID::Boolean => {
                         let func = Arc::new(ComparisonBooleanImpl::<T::BooleanSimd>::new());
                         ComparisonFunction::try_create_func(display_name, func)
@@ -178,6 +186,10 @@ impl<T: ComparisonImpl> ComparisonFunctionCreator<T> {
             ComparisonFunction::try_create_func(display_name, func)
         }, {
             match least_supertype.data_type_id() {
+                TypeID::Decimal => {
+                    let func = Arc::new(ComparisonPrimitiveImpl::<i128, _>::new(least_supertype, true, T::eval_simd::<i128>));
+                    ComparisonFunction::try_create_func(display_name, func)
+                },
                 TypeID::Boolean => {
                     let func = Arc::new(ComparisonBooleanImpl::<T::BooleanSimd>::new());
                     ComparisonFunction::try_create_func(display_name, func)
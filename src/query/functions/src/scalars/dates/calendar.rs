@@ -0,0 +1,171 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono_tz::Tz;
+use common_datavalues::chrono::format::Item;
+use common_datavalues::chrono::format::StrftimeItems;
+use common_datavalues::chrono::DateTime;
+use common_datavalues::chrono::Datelike;
+use common_datavalues::chrono::Duration;
+use common_datavalues::chrono::FixedOffset;
+use common_datavalues::chrono::NaiveDateTime;
+use common_datavalues::chrono::Offset;
+use common_datavalues::chrono::TimeZone;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+pub fn assert_date_or_timestamp_tz(data_type: &DataTypeImpl) -> Result<()> {
+    let id = data_type.data_type_id();
+    if !id.is_date_or_date_time() && !id.is_timestamp_tz() {
+        return Err(ErrorCode::IllegalDataType(format!(
+            "Expected a date, timestamp or timestamp_tz type, but got {}",
+            data_type.name()
+        )));
+    }
+    Ok(())
+}
+
+pub fn parse_timezone(tz: &[u8]) -> Result<Tz> {
+    let tz = String::from_utf8_lossy(tz);
+    tz.trim().parse::<Tz>().map_err(|_| {
+        ErrorCode::InvalidTimezone(format!("Invalid timezone {}, like Asia/Shanghai", tz))
+    })
+}
+
+pub fn check_strftime_format(format: &[u8]) -> Result<&str> {
+    let format = std::str::from_utf8(format).map_err(|_| {
+        ErrorCode::BadArguments(format!("Cannot convert format:{:?} to utf8", format))
+    })?;
+    if StrftimeItems::new(format).any(|item| matches!(item, Item::Error)) {
+        return Err(ErrorCode::BadArguments(format!(
+            "Invalid strftime format pattern {}",
+            format
+        )));
+    }
+    Ok(format)
+}
+
+/// Resolve a local wall clock in `tz`. An ambiguous time takes the earliest instant,
+/// a time skipped by a DST transition is moved forward past the gap.
+pub fn resolve_local_datetime<T: TimeZone>(tz: &T, local: &NaiveDateTime) -> DateTime<T> {
+    match tz.from_local_datetime(local).earliest() {
+        Some(dt) => dt,
+        None => {
+            let before = tz
+                .offset_from_utc_datetime(&(*local - Duration::days(1)))
+                .fix();
+            tz.from_utc_datetime(&(*local - Duration::seconds(before.local_minus_utc() as i64)))
+        }
+    }
+}
+
+/// The values of a Date, Timestamp or TimestampTz column with the offset they are
+/// calendared in. Dates are the midnight and timestamps the instant in `tz`, while
+/// a TimestampTz keeps its own offset.
+pub fn column_to_datetimes(
+    column: &ColumnWithField,
+    input_rows: usize,
+    tz: &Tz,
+) -> Result<Vec<DateTime<FixedOffset>>> {
+    let fix = |dt: DateTime<Tz>| dt.with_timezone(&dt.offset().fix());
+    match column.data_type().data_type_id() {
+        TypeID::Date => {
+            let viewer = i32::try_create_viewer(column.column())?;
+            Ok((0..input_rows)
+                .map(|row| {
+                    let date = viewer.value_at(row).to_date(&Tz::UTC).naive_utc();
+                    fix(resolve_local_datetime(tz, &date.and_hms(0, 0, 0)))
+                })
+                .collect())
+        }
+        TypeID::Timestamp => {
+            let viewer = i64::try_create_viewer(column.column())?;
+            Ok((0..input_rows)
+                .map(|row| fix(viewer.value_at(row).to_timestamp(tz)))
+                .collect())
+        }
+        TypeID::TimestampTz => {
+            let viewer = i128::try_create_viewer(column.column())?;
+            Ok((0..input_rows)
+                .map(|row| timestamp_tz_to_datetime(viewer.value_at(row)))
+                .collect())
+        }
+        other => Err(ErrorCode::IllegalDataType(format!(
+            "Illegal type {:?}, expected a Date, Timestamp or TimestampTz",
+            other
+        ))),
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DateUnit {
+    Year,
+    Quarter,
+    Month,
+    Week,
+    Day,
+    Hour,
+    Minute,
+    Second,
+}
+
+impl DateUnit {
+    pub fn parse(unit: &[u8]) -> Result<DateUnit> {
+        let unit = String::from_utf8_lossy(unit).trim().to_lowercase();
+        match unit.strip_suffix('s').unwrap_or(unit.as_str()) {
+            "year" => Ok(DateUnit::Year),
+            "quarter" => Ok(DateUnit::Quarter),
+            "month" => Ok(DateUnit::Month),
+            "week" => Ok(DateUnit::Week),
+            "day" => Ok(DateUnit::Day),
+            "hour" => Ok(DateUnit::Hour),
+            "minute" => Ok(DateUnit::Minute),
+            "second" => Ok(DateUnit::Second),
+            _ => Err(ErrorCode::BadArguments(format!(
+                "Unsupported date part {}, expected one of year, quarter, month, week, day, hour, minute, second",
+                unit
+            ))),
+        }
+    }
+
+    /// The number of unit boundaries crossed from `a` to `b`.
+    ///
+    /// Calendar units count on the local fields, so a day stays a day across a DST
+    /// transition. Time units truncate in local time and count the elapsed instants.
+    pub fn diff(&self, a: &DateTime<FixedOffset>, b: &DateTime<FixedOffset>) -> i64 {
+        let (la, lb) = (a.naive_local(), b.naive_local());
+        let months = |d: &NaiveDateTime| d.year() as i64 * 12 + d.month0() as i64;
+        let days = |d: &NaiveDateTime| d.num_days_from_ce() as i64;
+        let truncate = |d: &DateTime<FixedOffset>, secs: i64| {
+            let local = d.naive_local().timestamp();
+            local - local.rem_euclid(secs) - d.offset().local_minus_utc() as i64
+        };
+
+        match self {
+            DateUnit::Year => (lb.year() - la.year()) as i64,
+            DateUnit::Quarter => months(&lb).div_euclid(3) - months(&la).div_euclid(3),
+            DateUnit::Month => months(&lb) - months(&la),
+            DateUnit::Week => {
+                let monday =
+                    |d: &NaiveDateTime| days(d) - d.weekday().num_days_from_monday() as i64;
+                (monday(&lb) - monday(&la)).div_euclid(7)
+            }
+            DateUnit::Day => days(&lb) - days(&la),
+            DateUnit::Hour => (truncate(b, 3600) - truncate(a, 3600)).div_euclid(3600),
+            DateUnit::Minute => (truncate(b, 60) - truncate(a, 60)).div_euclid(60),
+            DateUnit::Second => b.timestamp() - a.timestamp(),
+        }
+    }
+}
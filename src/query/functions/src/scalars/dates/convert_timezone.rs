@@ -0,0 +1,124 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use chrono_tz::Tz;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use super::calendar::assert_date_or_timestamp_tz;
+use super::calendar::column_to_datetimes;
+use super::calendar::parse_timezone;
+use super::calendar::resolve_local_datetime;
+use crate::scalars::assert_date_or_timestamp;
+use crate::scalars::assert_string;
+use crate::scalars::Function;
+use crate::scalars::FunctionContext;
+use crate::scalars::FunctionDescription;
+use crate::scalars::FunctionFeatures;
+
+/// `convert_timezone(tz_to, ts)` returns the instant of `ts` as a TimestampTz with the
+/// offset `tz_to` has at that instant.
+///
+/// `convert_timezone(tz_from, tz_to, ts)` reads the wall clock of `ts` as a local time
+/// in `tz_from` and returns the wall clock of the same instant in `tz_to`.
+#[derive(Clone)]
+pub struct ConvertTimezoneFunction {
+    display_name: String,
+    with_source: bool,
+}
+
+impl ConvertTimezoneFunction {
+    pub fn try_create(display_name: &str, args: &[&DataTypeImpl]) -> Result<Box<dyn Function>> {
+        let with_source = args.len() == 3;
+        for arg in &args[..args.len() - 1] {
+            assert_string(arg)?;
+        }
+        if with_source {
+            assert_date_or_timestamp(args[2])?;
+        } else {
+            assert_date_or_timestamp_tz(args[1])?;
+        }
+
+        Ok(Box::new(ConvertTimezoneFunction {
+            display_name: display_name.to_string(),
+            with_source,
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .variadic_arguments(2, 3),
+        )
+    }
+}
+
+impl Function for ConvertTimezoneFunction {
+    fn name(&self) -> &str {
+        self.display_name.as_str()
+    }
+
+    fn return_type(&self) -> DataTypeImpl {
+        if self.with_source {
+            TimestampType::new_impl()
+        } else {
+            TimestampTzType::new_impl()
+        }
+    }
+
+    fn eval(
+        &self,
+        func_ctx: FunctionContext,
+        columns: &ColumnsWithField,
+        input_rows: usize,
+    ) -> Result<ColumnRef> {
+        if !self.with_source {
+            let targets = Vu8::try_create_viewer(columns[0].column())?;
+            let values = column_to_datetimes(&columns[1], input_rows, &func_ctx.tz)?;
+
+            let mut result = Vec::with_capacity(input_rows);
+            for (row, value) in values.iter().enumerate() {
+                let tz = parse_timezone(targets.value_at(row))?;
+                result.push(timestamp_tz_from_datetime(&value.with_timezone(&tz)));
+            }
+            return Ok(Int128Column::from_vecs(result).arc());
+        }
+
+        let sources = Vu8::try_create_viewer(columns[0].column())?;
+        let targets = Vu8::try_create_viewer(columns[1].column())?;
+        // The wall clock is read without any session timezone.
+        let values = column_to_datetimes(&columns[2], input_rows, &Tz::UTC)?;
+
+        let mut result = Vec::with_capacity(input_rows);
+        for (row, value) in values.iter().enumerate() {
+            let from = parse_timezone(sources.value_at(row))?;
+            let to = parse_timezone(targets.value_at(row))?;
+            let instant = resolve_local_datetime(&from, &value.naive_local());
+            let local = instant.with_timezone(&to).naive_local();
+            let micros = local.timestamp() * 1_000_000 + local.timestamp_subsec_micros() as i64;
+            check_timestamp(micros)?;
+            result.push(micros);
+        }
+        Ok(Int64Column::from_vecs(result).arc())
+    }
+}
+
+impl fmt::Display for ConvertTimezoneFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -23,8 +23,11 @@ use super::AddDaysFunction;
 use super::AddMonthsFunction;
 use super::AddTimesFunction;
 use super::AddYearsFunction;
+use super::ConvertTimezoneFunction;
 use super::DateAddFunction;
+use super::DateDiffFunction;
 use super::RoundFunction;
+use super::ToCharFunction;
 use super::ToDayOfMonthFunction;
 use super::ToDayOfWeekFunction;
 use super::ToDayOfYearFunction;
@@ -176,5 +179,9 @@ impl DateFunction {
         );
 
         factory.register("date_add", DateAddFunction::desc());
+        factory.register("date_diff", DateDiffFunction::desc());
+        factory.register("convert_timezone", ConvertTimezoneFunction::desc());
+        factory.register("to_char", ToCharFunction::desc());
+        factory.register("date_format", ToCharFunction::desc());
     }
 }
@@ -0,0 +1,84 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use super::calendar::assert_date_or_timestamp_tz;
+use super::calendar::column_to_datetimes;
+use super::calendar::DateUnit;
+use crate::scalars::assert_string;
+use crate::scalars::Function;
+use crate::scalars::FunctionContext;
+use crate::scalars::FunctionDescription;
+use crate::scalars::FunctionFeatures;
+
+/// `date_diff(unit, a, b)` counts the unit boundaries crossed from `a` to `b`.
+#[derive(Clone)]
+pub struct DateDiffFunction {
+    display_name: String,
+}
+
+impl DateDiffFunction {
+    pub fn try_create(display_name: &str, args: &[&DataTypeImpl]) -> Result<Box<dyn Function>> {
+        assert_string(args[0])?;
+        assert_date_or_timestamp_tz(args[1])?;
+        assert_date_or_timestamp_tz(args[2])?;
+
+        Ok(Box::new(DateDiffFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic().num_arguments(3))
+    }
+}
+
+impl Function for DateDiffFunction {
+    fn name(&self) -> &str {
+        self.display_name.as_str()
+    }
+
+    fn return_type(&self) -> DataTypeImpl {
+        Int64Type::new_impl()
+    }
+
+    fn eval(
+        &self,
+        func_ctx: FunctionContext,
+        columns: &ColumnsWithField,
+        input_rows: usize,
+    ) -> Result<ColumnRef> {
+        let units = Vu8::try_create_viewer(columns[0].column())?;
+        let lhs = column_to_datetimes(&columns[1], input_rows, &func_ctx.tz)?;
+        let rhs = column_to_datetimes(&columns[2], input_rows, &func_ctx.tz)?;
+
+        let mut values = Vec::with_capacity(input_rows);
+        for row in 0..input_rows {
+            let unit = DateUnit::parse(units.value_at(row))?;
+            values.push(unit.diff(&lhs[row], &rhs[row]));
+        }
+        Ok(Int64Column::from_vecs(values).arc())
+    }
+}
+
+impl fmt::Display for DateDiffFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
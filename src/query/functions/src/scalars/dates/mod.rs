@@ -12,20 +12,27 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod calendar;
+mod convert_timezone;
 mod date;
 mod date_add;
+mod date_diff;
 mod interval_function;
 mod now;
 mod number_function;
 mod round_function;
 mod simple_date;
+mod to_char;
 mod to_interval_function;
 mod week_date;
 #[macro_use]
 mod macros;
 
+pub use calendar::resolve_local_datetime;
+pub use convert_timezone::ConvertTimezoneFunction;
 pub use date::DateFunction;
 pub use date_add::DateAddFunction;
+pub use date_diff::DateDiffFunction;
 pub use interval_function::AddDaysFunction;
 pub use interval_function::AddMonthsFunction;
 pub use interval_function::AddTimesFunction;
@@ -49,5 +56,6 @@ pub use round_function::RoundFunction;
 pub use simple_date::TodayFunction;
 pub use simple_date::TomorrowFunction;
 pub use simple_date::YesterdayFunction;
+pub use to_char::ToCharFunction;
 pub use to_interval_function::to_interval_function_creator;
 pub use week_date::ToStartOfWeekFunction;
@@ -0,0 +1,87 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::io::Write;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use super::calendar::assert_date_or_timestamp_tz;
+use super::calendar::check_strftime_format;
+use super::calendar::column_to_datetimes;
+use crate::scalars::assert_string;
+use crate::scalars::Function;
+use crate::scalars::FunctionContext;
+use crate::scalars::FunctionDescription;
+use crate::scalars::FunctionFeatures;
+
+/// `to_char(ts, format)` renders a date or timestamp with a strftime pattern, timestamps
+/// in the session timezone and a TimestampTz in its own offset.
+#[derive(Clone)]
+pub struct ToCharFunction {
+    display_name: String,
+}
+
+impl ToCharFunction {
+    pub fn try_create(display_name: &str, args: &[&DataTypeImpl]) -> Result<Box<dyn Function>> {
+        assert_date_or_timestamp_tz(args[0])?;
+        assert_string(args[1])?;
+
+        Ok(Box::new(ToCharFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic().num_arguments(2))
+    }
+}
+
+impl Function for ToCharFunction {
+    fn name(&self) -> &str {
+        self.display_name.as_str()
+    }
+
+    fn return_type(&self) -> DataTypeImpl {
+        Vu8::to_data_type()
+    }
+
+    fn eval(
+        &self,
+        func_ctx: FunctionContext,
+        columns: &ColumnsWithField,
+        input_rows: usize,
+    ) -> Result<ColumnRef> {
+        let values = column_to_datetimes(&columns[0], input_rows, &func_ctx.tz)?;
+        let formats = Vu8::try_create_viewer(columns[1].column())?;
+
+        let mut builder = MutableStringColumn::with_capacity(input_rows);
+        let mut buf = Vec::new();
+        for (row, value) in values.iter().enumerate() {
+            let format = check_strftime_format(formats.value_at(row))?;
+            buf.clear();
+            write!(buf, "{}", value.format(format))?;
+            builder.append_value(&buf);
+        }
+        Ok(builder.to_column())
+    }
+}
+
+impl fmt::Display for ToCharFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -19,10 +19,12 @@ use common_arrow::arrow::bitmap::Bitmap;
 use common_datavalues::chrono::DateTime;
 use common_datavalues::chrono::TimeZone;
 use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
 use common_exception::Result;
 
 use super::cast_with_type::arrow_cast_compute;
 use super::cast_with_type::CastOptions;
+use crate::scalars::resolve_local_datetime;
 use crate::scalars::FunctionContext;
 
 const DATE_FMT: &str = "%Y-%m-%d";
@@ -57,6 +59,17 @@ pub fn cast_from_date(
             Ok((result, None))
         }
 
+        // The date is the midnight in the session timezone.
+        TypeID::TimestampTz => {
+            let tz = func_ctx.tz;
+            let it = c.iter().map(|v| {
+                let date = v.to_date(&Tz::UTC).naive_utc().and_hms(0, 0, 0);
+                timestamp_tz_from_datetime(&resolve_local_datetime(&tz, &date))
+            });
+            let result = Arc::new(Int128Column::from_iterator(it));
+            Ok((result, None))
+        }
+
         _ => arrow_cast_compute(
             column,
             &i32::to_data_type(),
@@ -108,6 +121,16 @@ pub fn cast_from_timestamp(
             Ok((result, None))
         }
 
+        // Keep the offset the session timezone has at every instant.
+        TypeID::TimestampTz => {
+            let tz = func_ctx.tz;
+            let it = c
+                .iter()
+                .map(|v| timestamp_tz_from_datetime(&DateConverter::to_timestamp(v, &tz)));
+            let result = Arc::new(Int128Column::from_iterator(it));
+            Ok((result, None))
+        }
+
         _ => arrow_cast_compute(
             column,
             &i64::to_data_type(),
@@ -118,6 +141,54 @@ pub fn cast_from_timestamp(
     }
 }
 
+pub fn cast_from_timestamp_tz(
+    column: &ColumnRef,
+    from_type: &DataTypeImpl,
+    data_type: &DataTypeImpl,
+    _cast_options: &CastOptions,
+    _func_ctx: &FunctionContext,
+) -> Result<(ColumnRef, Option<Bitmap>)> {
+    let c = Series::remove_nullable(column);
+    let c: &Int128Column = Series::check_get(&c)?;
+    let size = c.len();
+
+    let timestamp_tz: TimestampTzType = from_type.to_owned().try_into()?;
+
+    match data_type.data_type_id() {
+        TypeID::String => {
+            let mut builder = MutableStringColumn::with_capacity(size);
+            for v in c.iter() {
+                let s = timestamp_tz_to_datetime(*v)
+                    .format(timestamp_tz.format_string())
+                    .to_string();
+                builder.append_value(s.as_bytes());
+            }
+            Ok((builder.to_column(), None))
+        }
+
+        // The date is the local date at the offset of the value.
+        TypeID::Date => {
+            let it = c.iter().map(|v| {
+                let local = timestamp_tz_to_datetime(*v).naive_local();
+                (local.timestamp().div_euclid(24 * 3600)) as i32
+            });
+            let result = Arc::new(Int32Column::from_iterator(it));
+            Ok((result, None))
+        }
+
+        TypeID::Timestamp => {
+            let it = c.iter().map(|v| timestamp_tz_micros(*v));
+            let result = Arc::new(Int64Column::from_iterator(it));
+            Ok((result, None))
+        }
+
+        _ => Err(ErrorCode::BadDataValueType(format!(
+            "Cast error happens in casting from TimestampTz to {}",
+            data_type.name()
+        ))),
+    }
+}
+
 #[inline]
 fn timestamp_to_string(date: DateTime<Tz>, fmt: &str) -> String {
     date.format(fmt).to_string()
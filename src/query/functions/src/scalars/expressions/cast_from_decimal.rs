@@ -0,0 +1,167 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_datavalues::prelude::*;
+use common_datavalues::with_match_primitive_type_id;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use super::cast_with_type::arrow_cast_compute;
+use super::cast_with_type::CastOptions;
+use crate::scalars::FunctionContext;
+
+pub fn cast_from_decimal(
+    column: &ColumnRef,
+    from_type: &DataTypeImpl,
+    data_type: &DataTypeImpl,
+    cast_options: &CastOptions,
+    func_ctx: &FunctionContext,
+) -> Result<(ColumnRef, Option<Bitmap>)> {
+    let from_decimal: DecimalType = from_type.clone().try_into()?;
+    let scale = from_decimal.scale();
+    let c = Series::remove_nullable(column);
+    let c: &Int128Column = Series::check_get(&c)?;
+    let size = c.len();
+
+    let return_res = |column| {
+        let nullable_column: &NullableColumn = Series::check_get(&column)?;
+        Ok((
+            nullable_column.inner().clone(),
+            Some(nullable_column.ensure_validity().clone()),
+        ))
+    };
+    match data_type {
+        DataTypeImpl::Decimal(to_decimal) => {
+            let mut builder = NullableColumnBuilder::<i128>::with_capacity(size);
+            for v in c.iter() {
+                match rescale_decimal(*v, scale, to_decimal.scale())
+                    .filter(|v| to_decimal.check_value(*v).is_ok())
+                {
+                    Some(v) => builder.append(v, true),
+                    None => builder.append_null(),
+                }
+            }
+            let column = builder.build(size);
+            return_res(column)
+        }
+        DataTypeImpl::String(_) => {
+            let mut builder = ColumnBuilder::<Vu8>::with_capacity(size);
+            for v in c.iter() {
+                builder.append(format_decimal(*v, scale).as_bytes());
+            }
+            Ok((builder.build(size), None))
+        }
+        DataTypeImpl::Boolean(_) => {
+            let column = BooleanColumn::from_iterator(c.iter().map(|v| *v != 0));
+            Ok((column.arc(), None))
+        }
+        _ if data_type.data_type_id().is_floating() => {
+            let multiplier = decimal_scale_multiplier(scale) as f64;
+            with_match_primitive_type_id!(data_type.data_type_id(), |$T| {
+                let mut builder = ColumnBuilder::<$T>::with_capacity(size);
+                for v in c.iter() {
+                    builder.append((*v as f64 / multiplier) as $T);
+                }
+                Ok((builder.build(size), None))
+            }, {
+                unreachable!()
+            })
+        }
+        _ if data_type.data_type_id().is_integer() => {
+            // Round half away from zero, the same as casting to DECIMAL(p, 0).
+            with_match_primitive_type_id!(data_type.data_type_id(), |$T| {
+                let mut builder = NullableColumnBuilder::<$T>::with_capacity(size);
+                for v in c.iter() {
+                    match rescale_decimal(*v, scale, 0).and_then(num::cast::<i128, $T>) {
+                        Some(v) => builder.append(v, true),
+                        None => builder.append_null(),
+                    }
+                }
+                let column = builder.build(size);
+                return_res(column)
+            }, {
+                unreachable!()
+            })
+        }
+        _ if data_type.data_type_id().is_variant() => {
+            arrow_cast_compute(column, from_type, data_type, cast_options, func_ctx)
+        }
+        _ => Err(ErrorCode::BadDataValueType(format!(
+            "Cast error happens in casting from {} to {}",
+            from_type.name(),
+            data_type.name()
+        ))),
+    }
+}
+
+pub fn cast_to_decimal(
+    column: &ColumnRef,
+    from_type: &DataTypeImpl,
+    data_type: &DataTypeImpl,
+) -> Result<(ColumnRef, Option<Bitmap>)> {
+    let decimal: DecimalType = data_type.clone().try_into()?;
+    let multiplier = decimal_scale_multiplier(decimal.scale());
+    let column = Series::remove_nullable(column);
+    let size = column.len();
+    let mut builder = NullableColumnBuilder::<i128>::with_capacity(size);
+
+    let mut append = |v: Option<i128>| match v.filter(|v| decimal.check_value(*v).is_ok()) {
+        Some(v) => builder.append(v, true),
+        None => builder.append_null(),
+    };
+    let from_id = from_type.data_type_id();
+    if from_id.is_floating() {
+        with_match_primitive_type_id!(from_id, |$T| {
+            let col: &PrimitiveColumn<$T> = Series::check_get(&column)?;
+            for v in col.iter() {
+                let v = (*v as f64 * multiplier as f64).round();
+                append(if v.is_finite() && v.abs() < i128::MAX as f64 {
+                    Some(v as i128)
+                } else {
+                    None
+                });
+            }
+        }, {
+            unreachable!()
+        });
+    } else if from_id.is_integer() {
+        with_match_primitive_type_id!(from_id, |$T| {
+            let col: &PrimitiveColumn<$T> = Series::check_get(&column)?;
+            for v in col.iter() {
+                append((*v as i128).checked_mul(multiplier));
+            }
+        }, {
+            unreachable!()
+        });
+    } else if from_id == TypeID::Boolean {
+        let col: &BooleanColumn = Series::check_get(&column)?;
+        for v in col.iter() {
+            append(Some(v as i128 * multiplier));
+        }
+    } else {
+        return Err(ErrorCode::BadDataValueType(format!(
+            "Cast error happens in casting from {} to {}",
+            from_type.name(),
+            data_type.name()
+        )));
+    }
+
+    let column = builder.build(size);
+    let nullable_column: &NullableColumn = Series::check_get(&column)?;
+    Ok((
+        nullable_column.inner().clone(),
+        Some(nullable_column.ensure_validity().clone()),
+    ))
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use bstr::ByteSlice;
 use chrono_tz::Tz;
 use common_arrow::arrow::bitmap::Bitmap;
 use common_arrow::arrow::temporal_conversions::EPOCH_DAYS_FROM_CE;
@@ -73,6 +74,18 @@ pub fn cast_from_string(
             let column = builder.build(size);
             return_res(column)
         }
+        TypeID::TimestampTz => {
+            let mut builder = NullableColumnBuilder::<i128>::with_capacity(size);
+            let tz = func_ctx.tz;
+            for v in str_column.iter() {
+                match parse_timestamp_tz(v, &tz) {
+                    Ok(d) => builder.append(d, true),
+                    Err(_) => builder.append_null(),
+                }
+            }
+            let column = builder.build(size);
+            return_res(column)
+        }
         TypeID::Boolean => {
             let mut builder = NullableColumnBuilder::<bool>::with_capacity(size);
             for v in str_column.iter() {
@@ -87,6 +100,18 @@ pub fn cast_from_string(
             let column = builder.build(size);
             return_res(column)
         }
+        TypeID::Decimal => {
+            let decimal: DecimalType = data_type.clone().try_into()?;
+            let mut builder = NullableColumnBuilder::<i128>::with_capacity(size);
+            for v in str_column.iter() {
+                match parse_decimal(v.trim(), decimal.precision(), decimal.scale()) {
+                    Ok(d) => builder.append(d, true),
+                    Err(_) => builder.append_null(),
+                }
+            }
+            let column = builder.build(size);
+            return_res(column)
+        }
         TypeID::Interval => todo!(),
         _ => arrow_cast_compute(column, from_type, data_type, cast_options, func_ctx),
     }
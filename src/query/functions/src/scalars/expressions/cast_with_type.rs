@@ -26,9 +26,12 @@ use common_exception::Result;
 use common_io::prelude::FormatSettings;
 
 use super::cast_from_datetimes::cast_from_date;
+use super::cast_from_decimal::cast_from_decimal;
+use super::cast_from_decimal::cast_to_decimal;
 use super::cast_from_string::cast_from_string;
 use super::cast_from_variant::cast_from_variant;
 use crate::scalars::expressions::cast_from_datetimes::cast_from_timestamp;
+use crate::scalars::expressions::cast_from_datetimes::cast_from_timestamp_tz;
 use crate::scalars::FunctionContext;
 
 #[derive(PartialEq, Eq, Debug, Clone, Copy)]
@@ -164,6 +167,20 @@ pub fn cast_with_type(
             cast_options,
             func_ctx,
         ),
+        TypeID::TimestampTz => cast_from_timestamp_tz(
+            column,
+            &nonull_from_type,
+            &nonull_data_type,
+            cast_options,
+            func_ctx,
+        ),
+        TypeID::Decimal => cast_from_decimal(
+            column,
+            &nonull_from_type,
+            &nonull_data_type,
+            cast_options,
+            func_ctx,
+        ),
         TypeID::Variant | TypeID::VariantArray | TypeID::VariantObject => {
             cast_from_variant(column, &nonull_data_type, func_ctx)
         }
@@ -189,6 +206,7 @@ pub fn cast_with_type(
         }
     } else if nonull_data_type.data_type_id() == TypeID::Array
         || nonull_data_type.data_type_id() == TypeID::Struct
+        || nonull_data_type.data_type_id() == TypeID::Map
     {
         return Err(ErrorCode::BadDataValueType(format!(
             "Cast error happens in casting from {} to {}",
@@ -248,7 +266,10 @@ pub fn cast_to_variant(
         )));
     }
     let mut builder = ColumnBuilder::<VariantValue>::with_capacity(size);
-    if from_type.data_type_id().is_numeric() || from_type.data_type_id() == TypeID::Boolean {
+    if from_type.data_type_id().is_numeric()
+        || from_type.data_type_id().is_decimal()
+        || from_type.data_type_id() == TypeID::Boolean
+    {
         let serializer = from_type.create_serializer(&column)?;
         let format = FormatSettings::default();
         match serializer.serialize_json_object(None, &format) {
@@ -326,6 +347,13 @@ pub fn arrow_cast_compute(
         return cast_to_variant(column, from_type, data_type, func_ctx);
     } else if data_type.data_type_id() == TypeID::Timestamp {
         return cast_to_timestamp(column, from_type);
+    } else if data_type.data_type_id() == TypeID::Decimal {
+        return cast_to_decimal(column, from_type, data_type);
+    } else if data_type.data_type_id() == TypeID::TimestampTz {
+        return Err(ErrorCode::BadDataValueType(format!(
+            "Cast error happens in casting from {} to TimestampTz.",
+            from_type.data_type_id()
+        )));
     } else if data_type.data_type_id() == TypeID::Date {
         return arrow_cast_compute(
             column,
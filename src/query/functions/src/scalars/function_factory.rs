@@ -30,6 +30,7 @@ use super::FunctionAdapter;
 use super::FunctionFeatures;
 use super::HashesFunction;
 use super::LogicFunction;
+use super::MapFunction;
 use super::MathsFunction;
 use super::OtherFunction;
 use super::SemiStructuredFunction;
@@ -74,6 +75,7 @@ static FUNCTION_FACTORY: Lazy<Arc<FunctionFactory>> = Lazy::new(|| {
     CommonFunction::register(&mut function_factory);
     ToCastFunction::register(&mut function_factory);
     TupleClassFunction::register(&mut function_factory);
+    MapFunction::register(&mut function_factory);
     ComparisonFunction::register(&mut function_factory);
     ContextFunction::register(&mut function_factory);
     SemiStructuredFunction::register(&mut function_factory);
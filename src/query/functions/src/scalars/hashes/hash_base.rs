@@ -220,6 +220,7 @@ impl DFHash for DataValue {
             DataValue::Int64(v) => DFHash::hash(v, state),
             DataValue::UInt64(v) => DFHash::hash(v, state),
             DataValue::Float64(v) => DFHash::hash(v, state),
+            DataValue::Int128(v) => Hash::hash(v, state),
             DataValue::String(vals) => {
                 for v in vals {
                     DFHash::hash(v, state);
@@ -0,0 +1,99 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::Function;
+use crate::scalars::FunctionContext;
+use crate::scalars::FunctionDescription;
+use crate::scalars::FunctionFeatures;
+
+pub type MapKeysFunction = MapEntriesFunctionImpl<false>;
+
+pub type MapValuesFunction = MapEntriesFunctionImpl<true>;
+
+/// Extracts the keys (or values when `VALUES` is true) of a map as an array.
+#[derive(Clone)]
+pub struct MapEntriesFunctionImpl<const VALUES: bool> {
+    display_name: String,
+    result_type: DataTypeImpl,
+}
+
+impl<const VALUES: bool> MapEntriesFunctionImpl<VALUES> {
+    pub fn try_create(display_name: &str, args: &[&DataTypeImpl]) -> Result<Box<dyn Function>> {
+        if !args[0].data_type_id().is_map() {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Invalid argument types for function '{}': ({:?})",
+                display_name.to_uppercase(),
+                args[0].data_type_id()
+            )));
+        }
+
+        let map_type: MapType = args[0].clone().try_into()?;
+        let inner_type = if VALUES {
+            map_type.value_type().clone()
+        } else {
+            map_type.key_type().clone()
+        };
+
+        Ok(Box::new(MapEntriesFunctionImpl::<VALUES> {
+            display_name: display_name.to_string(),
+            result_type: ArrayType::new_impl(inner_type),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic().num_arguments(1))
+    }
+}
+
+impl<const VALUES: bool> Function for MapEntriesFunctionImpl<VALUES> {
+    fn name(&self) -> &str {
+        &self.display_name
+    }
+
+    fn return_type(&self) -> DataTypeImpl {
+        self.result_type.clone()
+    }
+
+    fn eval(
+        &self,
+        _func_ctx: FunctionContext,
+        columns: &ColumnsWithField,
+        _input_rows: usize,
+    ) -> Result<ColumnRef> {
+        let column = columns[0].column().convert_full_column();
+        let map_column: &ArrayColumn = Series::check_get(&column)?;
+        let entries: &StructColumn = Series::check_get(map_column.values())?;
+        let index = if VALUES { 1 } else { 0 };
+
+        Ok(ArrayColumn::from_data(
+            self.result_type.clone(),
+            map_column.offsets().to_vec().into(),
+            entries.values()[index].clone(),
+        )
+        .arc())
+    }
+}
+
+impl<const VALUES: bool> fmt::Display for MapEntriesFunctionImpl<VALUES> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.display_name.to_uppercase())
+    }
+}
@@ -0,0 +1,120 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::Function;
+use crate::scalars::FunctionContext;
+
+/// Looks up a key in a map, `m['k']`. Returns NULL if the key is missing.
+#[derive(Clone)]
+pub struct MapGetFunction {
+    map_type: MapType,
+    display_name: String,
+}
+
+impl MapGetFunction {
+    pub fn try_create(display_name: &str, args: &[&DataTypeImpl]) -> Result<Box<dyn Function>> {
+        let data_type = args[0];
+        let key_type = args[1];
+
+        if !data_type.data_type_id().is_map() || !key_type.data_type_id().is_string() {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Invalid argument types for function '{}': ({:?}, {:?})",
+                display_name.to_uppercase(),
+                data_type.data_type_id(),
+                key_type.data_type_id()
+            )));
+        }
+
+        let map_type: MapType = data_type.clone().try_into()?;
+        Ok(Box::new(MapGetFunction {
+            map_type,
+            display_name: display_name.to_string(),
+        }))
+    }
+}
+
+impl Function for MapGetFunction {
+    fn name(&self) -> &str {
+        &self.display_name
+    }
+
+    fn return_type(&self) -> DataTypeImpl {
+        let value_type = self.map_type.value_type();
+        if value_type.is_null() || value_type.is_nullable() {
+            value_type.clone()
+        } else {
+            NullableType::new_impl(value_type.clone())
+        }
+    }
+
+    fn eval(
+        &self,
+        _func_ctx: FunctionContext,
+        columns: &ColumnsWithField,
+        input_rows: usize,
+    ) -> Result<ColumnRef> {
+        if self.map_type.value_type().is_null() {
+            return Ok(NullColumn::new(input_rows).arc());
+        }
+
+        let map_column = columns[0].column().convert_full_column();
+        let map_column: &ArrayColumn = Series::check_get(&map_column)?;
+        let offsets = map_column.offsets();
+        let entries: &StructColumn = Series::check_get(map_column.values())?;
+        let map_keys: &StringColumn = Series::check_get(&entries.values()[0])?;
+        let keys = Vu8::try_create_viewer(columns[1].column())?;
+
+        // The index of the entry of the key in each row, the row is NULL if the key is missing.
+        let mut indices = Vec::with_capacity(input_rows);
+        let mut validity = MutableBitmap::with_capacity(input_rows);
+        for row in 0..input_rows {
+            let key = keys.value_at(row);
+            let found = (offsets[row] as usize..offsets[row + 1] as usize)
+                .find(|i| map_keys.get_data(*i) == key);
+            indices.push(found.unwrap_or_default());
+            validity.push(found.is_some());
+        }
+
+        let values = &entries.values()[1];
+        if values.is_empty() {
+            return self
+                .return_type()
+                .create_column(&vec![DataValue::Null; input_rows]);
+        }
+
+        let values = Series::take(values, &indices)?;
+        let validity: Bitmap = validity.into();
+        let (values, validity) = if values.is_nullable() {
+            let values: &NullableColumn = Series::check_get(&values)?;
+            (values.inner().clone(), &validity & values.ensure_validity())
+        } else {
+            (values, validity)
+        };
+        Ok(NullableColumn::wrap_inner(values, Some(validity)))
+    }
+}
+
+impl fmt::Display for MapGetFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name.to_uppercase())
+    }
+}
@@ -0,0 +1,78 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use crate::aggregates::HyperLogLog;
+use crate::scalars::assert_string;
+use crate::scalars::Function;
+use crate::scalars::FunctionContext;
+use crate::scalars::FunctionDescription;
+use crate::scalars::FunctionFeatures;
+
+/// Estimates the number of distinct values of a sketch built by `hll_sketch` or `hll_merge`.
+#[derive(Clone)]
+pub struct HllEstimateFunction {
+    display_name: String,
+}
+
+impl HllEstimateFunction {
+    pub fn try_create(display_name: &str, args: &[&DataTypeImpl]) -> Result<Box<dyn Function>> {
+        assert_string(args[0])?;
+
+        Ok(Box::new(HllEstimateFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic().num_arguments(1))
+    }
+}
+
+impl Function for HllEstimateFunction {
+    fn name(&self) -> &str {
+        &self.display_name
+    }
+
+    fn return_type(&self) -> DataTypeImpl {
+        UInt64Type::new_impl()
+    }
+
+    fn eval(
+        &self,
+        _func_ctx: FunctionContext,
+        columns: &ColumnsWithField,
+        input_rows: usize,
+    ) -> Result<ColumnRef> {
+        let viewer = Vu8::try_create_viewer(columns[0].column())?;
+
+        // Null rows hold an empty value, which decodes to an empty sketch.
+        let mut builder = ColumnBuilder::<u64>::with_capacity(input_rows);
+        for input in viewer.iter() {
+            builder.append(HyperLogLog::from_bytes(input)?.estimate());
+        }
+        Ok(builder.build(input_rows))
+    }
+}
+
+impl fmt::Display for HllEstimateFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name.to_uppercase())
+    }
+}
@@ -14,6 +14,7 @@
 
 mod assume_not_null;
 mod exists;
+mod hll_estimate;
 mod humanize;
 mod ignore;
 mod inet_aton;
@@ -26,6 +27,7 @@ mod type_of;
 
 pub use assume_not_null::AssumeNotNullFunction;
 pub use exists::ExistsFunction;
+pub use hll_estimate::HllEstimateFunction;
 pub use humanize::HumanizeNumberFunction;
 pub use humanize::HumanizeSizeFunction;
 pub use ignore::IgnoreFunction;
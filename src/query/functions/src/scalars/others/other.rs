@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use super::assume_not_null::AssumeNotNullFunction;
+use super::hll_estimate::HllEstimateFunction;
 use super::humanize::HumanizeNumberFunction;
 use super::humanize::HumanizeSizeFunction;
 use super::inet_aton::InetAtonFunction;
@@ -55,5 +56,7 @@ impl OtherFunction {
 
         factory.register("assume_not_null", AssumeNotNullFunction::desc());
         factory.register("to_nullable", ToNullableFunction::desc());
+
+        factory.register("hll_estimate", HllEstimateFunction::desc());
     }
 }
@@ -26,6 +26,7 @@ use crate::scalars::Function;
 use crate::scalars::FunctionContext;
 use crate::scalars::FunctionDescription;
 use crate::scalars::FunctionFeatures;
+use crate::scalars::MapGetFunction;
 
 pub type GetFunction = GetFunctionImpl<false>;
 
@@ -45,6 +46,10 @@ impl<const IGNORE_CASE: bool> GetFunctionImpl<IGNORE_CASE> {
             return ArrayGetFunction::try_create(display_name, args);
         }
 
+        if data_type.data_type_id().is_map() {
+            return MapGetFunction::try_create(display_name, args);
+        }
+
         if !data_type.data_type_id().is_variant()
             || (!path_type.data_type_id().is_string() && !path_type.data_type_id().is_integer())
         {
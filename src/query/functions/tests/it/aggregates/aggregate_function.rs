@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::borrow::BorrowMut;
+use std::sync::Arc;
 
 use bumpalo::Bump;
 use common_datavalues::prelude::*;
@@ -252,6 +253,66 @@ fn test_aggregate_function() -> Result<()> {
             input_array: Box::new(MutablePrimitiveColumn::<f64>::default()),
             expect_array: Series::from_data([-1.25000f64]),
         },
+        Test {
+            name: "var-sample-passed",
+            eval_nums: 1,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "var_samp",
+            func_name: "var_samp",
+            arrays: vec![arrays[0].clone()],
+            error: "",
+            input_array: Box::new(MutablePrimitiveColumn::<f64>::default()),
+            expect_array: Series::from_data([1.6666666666666667f64]),
+        },
+        Test {
+            name: "corr-passed",
+            eval_nums: 1,
+            params: vec![],
+            args: vec![args[0].clone(), args[1].clone()],
+            display: "corr",
+            func_name: "corr",
+            arrays: vec![arrays[0].clone(), arrays[1].clone()],
+            error: "",
+            input_array: Box::new(MutablePrimitiveColumn::<f64>::default()),
+            expect_array: Series::from_data([-1.0f64]),
+        },
+        Test {
+            name: "regr-slope-passed",
+            eval_nums: 1,
+            params: vec![],
+            args: vec![args[0].clone(), args[1].clone()],
+            display: "regr_slope",
+            func_name: "regr_slope",
+            arrays: vec![arrays[0].clone(), arrays[1].clone()],
+            error: "",
+            input_array: Box::new(MutablePrimitiveColumn::<f64>::default()),
+            expect_array: Series::from_data([-1.0f64]),
+        },
+        Test {
+            name: "regr-intercept-passed",
+            eval_nums: 1,
+            params: vec![],
+            args: vec![args[0].clone(), args[1].clone()],
+            display: "regr_intercept",
+            func_name: "regr_intercept",
+            arrays: vec![arrays[0].clone(), arrays[1].clone()],
+            error: "",
+            input_array: Box::new(MutablePrimitiveColumn::<f64>::default()),
+            expect_array: Series::from_data([5.0f64]),
+        },
+        Test {
+            name: "regr-count-passed",
+            eval_nums: 1,
+            params: vec![],
+            args: vec![args[0].clone(), args[1].clone()],
+            display: "regr_count",
+            func_name: "regr_count",
+            arrays: vec![arrays[0].clone(), arrays[1].clone()],
+            error: "",
+            input_array: Box::new(MutablePrimitiveColumn::<u64>::default()),
+            expect_array: Series::from_data([4u64]),
+        },
         Test {
             name: "window-funnel-passed",
             eval_nums: 2,
@@ -274,6 +335,109 @@ fn test_aggregate_function() -> Result<()> {
             input_array: Box::new(MutablePrimitiveColumn::<u8>::default()),
             expect_array: Series::from_data([1u8]),
         },
+        Test {
+            name: "median-passed",
+            eval_nums: 1,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "median",
+            func_name: "median",
+            arrays: vec![arrays[0].clone()],
+            error: "",
+            input_array: Box::new(MutablePrimitiveColumn::<f64>::default()),
+            expect_array: Series::from_data([2.5f64]),
+        },
+        Test {
+            name: "quantile-passed",
+            eval_nums: 1,
+            params: vec![DataValue::Float64(0.25)],
+            args: vec![args[0].clone()],
+            display: "quantile",
+            func_name: "quantile",
+            arrays: vec![arrays[0].clone()],
+            error: "",
+            input_array: Box::new(MutablePrimitiveColumn::<f64>::default()),
+            expect_array: Series::from_data([1.75f64]),
+        },
+        Test {
+            name: "quantile-level-error",
+            eval_nums: 1,
+            params: vec![DataValue::Float64(1.5)],
+            args: vec![args[0].clone()],
+            display: "quantile",
+            func_name: "quantile",
+            arrays: vec![arrays[0].clone()],
+            error: "Code: 1006, displayText = Quantile level of quantile must be in [0, 1], but got 1.5.",
+            input_array: Box::new(MutablePrimitiveColumn::<f64>::default()),
+            expect_array: Series::from_data([0f64]),
+        },
+        Test {
+            name: "approx-percentile-passed",
+            eval_nums: 2,
+            params: vec![DataValue::Float64(0.5)],
+            args: vec![args[0].clone()],
+            display: "approx_percentile",
+            func_name: "approx_percentile",
+            arrays: vec![arrays[0].clone()],
+            error: "",
+            input_array: Box::new(MutablePrimitiveColumn::<f64>::default()),
+            expect_array: Series::from_data([2.5f64]),
+        },
+        Test {
+            name: "approx-count-distinct-passed",
+            eval_nums: 2,
+            params: vec![],
+            args: vec![args[0].clone(), args[1].clone()],
+            display: "approx_count_distinct",
+            func_name: "approx_count_distinct",
+            arrays: vec![arrays[0].clone(), arrays[1].clone()],
+            error: "",
+            input_array: Box::new(MutablePrimitiveColumn::<u64>::default()),
+            expect_array: Series::from_data([4u64]),
+        },
+        Test {
+            name: "array-agg-passed",
+            eval_nums: 1,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "array_agg",
+            func_name: "array_agg",
+            arrays: vec![arrays[0].clone()],
+            error: "",
+            input_array: ArrayType::new_impl(i64::to_data_type()).create_mutable(1),
+            expect_array: Arc::new(ArrayColumn::from_data(
+                ArrayType::new_impl(i64::to_data_type()),
+                vec![0i64, 4].into(),
+                Series::from_data(vec![4i64, 3, 2, 1]),
+            )),
+        },
+        Test {
+            name: "string-agg-order-by-passed",
+            eval_nums: 1,
+            params: vec![
+                DataValue::String(b",".to_vec()),
+                DataValue::Struct(vec![DataValue::Boolean(true), DataValue::Boolean(false)]),
+            ],
+            args: vec![args[6].clone(), args[0].clone()],
+            display: "string_agg",
+            func_name: "string_agg",
+            arrays: vec![arrays[6].clone(), arrays[0].clone()],
+            error: "",
+            input_array: Box::new(MutableStringColumn::default()),
+            expect_array: Series::from_data(["b,a,y,x"]),
+        },
+        Test {
+            name: "string-agg-type-error",
+            eval_nums: 1,
+            params: vec![DataValue::String(b",".to_vec())],
+            args: vec![args[0].clone()],
+            display: "string_agg",
+            func_name: "string_agg",
+            arrays: vec![arrays[0].clone()],
+            error: "Code: 1010, displayText = string_agg does not support type 'Int64', the values must be strings.",
+            input_array: Box::new(MutableStringColumn::default()),
+            expect_array: Series::from_data([""]),
+        },
     ];
 
     for mut t in tests {
@@ -615,6 +779,51 @@ fn test_aggregate_function_with_group_by() -> Result<()> {
                 Vec::from([1u8, 0u8]),
             )),
         },
+        Test {
+            name: "median-passed",
+            eval_nums: 1,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "median",
+            func_name: "median",
+            arrays: vec![arrays[0].clone()],
+            error: "",
+            input_array: Box::new(MutablePrimitiveColumn::<f64>::default()),
+            expect_array: Box::new(MutablePrimitiveColumn::<f64>::from_data(
+                f64::to_data_type(),
+                Vec::from([3.0f64, 2.0f64]),
+            )),
+        },
+        Test {
+            name: "approx-percentile-passed",
+            eval_nums: 1,
+            params: vec![DataValue::Float64(1.0)],
+            args: vec![args[0].clone()],
+            display: "approx_percentile",
+            func_name: "approx_percentile",
+            arrays: vec![arrays[0].clone()],
+            error: "",
+            input_array: Box::new(MutablePrimitiveColumn::<f64>::default()),
+            expect_array: Box::new(MutablePrimitiveColumn::<f64>::from_data(
+                f64::to_data_type(),
+                Vec::from([4.0f64, 3.0f64]),
+            )),
+        },
+        Test {
+            name: "approx-count-distinct-passed",
+            eval_nums: 2,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "approx_count_distinct",
+            func_name: "approx_count_distinct",
+            arrays: vec![arrays[0].clone()],
+            error: "",
+            input_array: Box::new(MutablePrimitiveColumn::<u64>::default()),
+            expect_array: Box::new(MutablePrimitiveColumn::<u64>::from_data(
+                u64::to_data_type(),
+                Vec::from([2u64, 2u64]),
+            )),
+        },
     ];
 
     for mut t in tests {
@@ -953,6 +1162,48 @@ fn test_covariance_with_comparable_data_sets() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_moments_with_comparable_data_sets() -> Result<()> {
+    let arena = Bump::new();
+
+    let arrays: Vec<ColumnRef> = vec![Series::from_data(vec![1i64, 2, 3, 4, 10])];
+    let args = vec![DataField::new("a", i64::to_data_type())];
+
+    let factory = AggregateFunctionFactory::instance();
+
+    let run_test = |func_name: &'static str| -> Result<f64> {
+        let func = factory.get_or_null(func_name, vec![], args.clone(), false)?;
+        let addr = arena.alloc_layout(func.state_layout());
+        func.init_state(addr.into());
+        // accumulate the rows into two states to exercise the merge of the moments
+        let addr2 = arena.alloc_layout(func.state_layout());
+        func.init_state(addr2.into());
+        func.accumulate_row(addr.into(), &arrays, 0)?;
+        func.accumulate_row(addr.into(), &arrays, 1)?;
+        for row in 2..5 {
+            func.accumulate_row(addr2.into(), &arrays, row)?;
+        }
+        func.merge(addr.into(), addr2.into())?;
+
+        let mut array = MutablePrimitiveColumn::<f64>::default();
+        func.merge_result(addr.into(), &mut array)?;
+        Ok(array.values()[0])
+    };
+
+    approx_eq!(f64, 12.5, run_test("var_samp")?, epsilon = 0.000001);
+    approx_eq!(f64, 10.0, run_test("var_pop")?, epsilon = 0.000001);
+    approx_eq!(
+        f64,
+        12.5_f64.sqrt(),
+        run_test("stddev_samp")?,
+        epsilon = 0.000001
+    );
+    approx_eq!(f64, 1.697056, run_test("skewness")?, epsilon = 0.000001);
+    approx_eq!(f64, 3.152, run_test("kurtosis")?, epsilon = 0.000001);
+
+    Ok(())
+}
+
 #[test]
 fn test_aggregate_function_on_boolean() -> Result<()> {
     struct Test {
@@ -64,3 +64,145 @@ fn test_to_start_of_function() -> Result<()> {
 
     test_scalar_functions_with_type("to_start_of_quarter", &test)
 }
+
+#[test]
+fn test_date_diff_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionWithFieldTest {
+            name: "test-date-diff-timestamp",
+            columns: vec![
+                ColumnWithField::new(
+                    Series::from_data(vec!["month", "day", "hour"]),
+                    DataField::new("unit", Vu8::to_data_type()),
+                ),
+                ColumnWithField::new(
+                    Series::from_data(vec![1643670000000000i64; 3]),
+                    DataField::new("a", TimestampType::new_impl()),
+                ),
+                ColumnWithField::new(
+                    Series::from_data(vec![1646096400000000i64; 3]),
+                    DataField::new("b", TimestampType::new_impl()),
+                ),
+            ],
+            expect: Series::from_data(vec![2i64, 29, 674]),
+            error: "",
+        },
+        // 2022-03-26 12:00 +00:00 to 2022-03-27 12:00 +01:00 in Europe/London
+        ScalarFunctionWithFieldTest {
+            name: "test-date-diff-dst",
+            columns: vec![
+                ColumnWithField::new(
+                    Series::from_data(vec!["day", "hour"]),
+                    DataField::new("unit", Vu8::to_data_type()),
+                ),
+                ColumnWithField::new(
+                    Series::from_data(vec![make_timestamp_tz(1648296000000000, 0); 2]),
+                    DataField::new("a", TimestampTzType::new_impl()),
+                ),
+                ColumnWithField::new(
+                    Series::from_data(vec![make_timestamp_tz(1648378800000000, 3600); 2]),
+                    DataField::new("b", TimestampTzType::new_impl()),
+                ),
+            ],
+            expect: Series::from_data(vec![1i64, 23]),
+            error: "",
+        },
+        ScalarFunctionWithFieldTest {
+            name: "test-date-diff-unknown-unit",
+            columns: vec![
+                ColumnWithField::new(
+                    Series::from_data(vec!["fortnight"]),
+                    DataField::new("unit", Vu8::to_data_type()),
+                ),
+                ColumnWithField::new(
+                    Series::from_data(vec![0i64]),
+                    DataField::new("a", TimestampType::new_impl()),
+                ),
+                ColumnWithField::new(
+                    Series::from_data(vec![0i64]),
+                    DataField::new("b", TimestampType::new_impl()),
+                ),
+            ],
+            expect: Series::from_data(Vec::<i64>::new()),
+            error: "Unsupported date part fortnight, expected one of year, quarter, month, week, day, hour, minute, second",
+        },
+    ];
+
+    test_scalar_functions_with_type("date_diff", &tests)
+}
+
+#[test]
+fn test_convert_timezone_function() -> Result<()> {
+    test_scalar_functions_with_type("convert_timezone", &[
+        ScalarFunctionWithFieldTest {
+            name: "test-convert-timezone-to-timestamp-tz",
+            columns: vec![
+                ColumnWithField::new(
+                    Series::from_data(vec!["Europe/London"]),
+                    DataField::new("tz_to", Vu8::to_data_type()),
+                ),
+                ColumnWithField::new(
+                    Series::from_data(vec![1648378800000000i64]),
+                    DataField::new("ts", TimestampType::new_impl()),
+                ),
+            ],
+            expect: Series::from_data(vec![make_timestamp_tz(1648378800000000, 3600)]),
+            error: "",
+        },
+        ScalarFunctionWithFieldTest {
+            name: "test-convert-timezone-wall-clock",
+            columns: vec![
+                ColumnWithField::new(
+                    Series::from_data(vec!["America/New_York"]),
+                    DataField::new("tz_from", Vu8::to_data_type()),
+                ),
+                ColumnWithField::new(
+                    Series::from_data(vec!["Europe/London"]),
+                    DataField::new("tz_to", Vu8::to_data_type()),
+                ),
+                ColumnWithField::new(
+                    Series::from_data(vec![1656676800000000i64]),
+                    DataField::new("ts", TimestampType::new_impl()),
+                ),
+            ],
+            expect: Series::from_data(vec![1656694800000000i64]),
+            error: "",
+        },
+    ])
+}
+
+#[test]
+fn test_to_char_function() -> Result<()> {
+    test_scalar_functions_with_type("to_char", &[
+        ScalarFunctionWithFieldTest {
+            name: "test-to-char-timestamp",
+            columns: vec![
+                ColumnWithField::new(
+                    Series::from_data(vec![1656676800000000i64]),
+                    DataField::new("ts", TimestampType::new_impl()),
+                ),
+                ColumnWithField::new(
+                    Series::from_data(vec!["%Y/%m/%d %H:%M"]),
+                    DataField::new("format", Vu8::to_data_type()),
+                ),
+            ],
+            expect: Series::from_data(vec!["2022/07/01 12:00"]),
+            error: "",
+        },
+        ScalarFunctionWithFieldTest {
+            name: "test-to-char-timestamp-tz",
+            columns: vec![
+                ColumnWithField::new(
+                    Series::from_data(vec![make_timestamp_tz(1648378800000000, 3600)]),
+                    DataField::new("ts", TimestampTzType::new_impl()),
+                ),
+                ColumnWithField::new(
+                    Series::from_data(vec!["%Y-%m-%d %H:%M %:z"]),
+                    DataField::new("format", Vu8::to_data_type()),
+                ),
+            ],
+            expect: Series::from_data(vec!["2022-03-27 12:00 +01:00"]),
+            error: "",
+        },
+    ])
+}
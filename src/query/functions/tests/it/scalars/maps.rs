@@ -0,0 +1,116 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use super::scalar_function_test::test_eval;
+
+fn map_column() -> Result<ColumnRef> {
+    let map_type = MapType::create(StringType::new_impl(), Int32Type::new_impl());
+    let entry = |k: &str, v: i32| {
+        DataValue::Struct(vec![
+            DataValue::String(k.as_bytes().to_vec()),
+            DataValue::Int64(v as i64),
+        ])
+    };
+    map_type.create_column(&[
+        DataValue::Array(vec![entry("a", 1), entry("b", 2)]),
+        DataValue::Array(vec![]),
+        DataValue::Array(vec![entry("b", 3)]),
+    ])
+}
+
+fn collect(column: ColumnRef) -> Vec<DataValue> {
+    let column = column.convert_full_column();
+    (0..column.len()).map(|i| column.get(i)).collect()
+}
+
+#[test]
+fn test_map_keys_values_function() -> Result<()> {
+    let column = map_column()?;
+
+    let keys = test_eval("map_keys", &[column.clone()])?;
+    assert_eq!(collect(keys), vec![
+        DataValue::Array(vec![
+            DataValue::String(b"a".to_vec()),
+            DataValue::String(b"b".to_vec()),
+        ]),
+        DataValue::Array(vec![]),
+        DataValue::Array(vec![DataValue::String(b"b".to_vec())]),
+    ]);
+
+    let values = test_eval("map_values", &[column])?;
+    assert_eq!(collect(values), vec![
+        DataValue::Array(vec![DataValue::Int64(1), DataValue::Int64(2)]),
+        DataValue::Array(vec![]),
+        DataValue::Array(vec![DataValue::Int64(3)]),
+    ]);
+
+    Ok(())
+}
+
+#[test]
+fn test_map_get_function() -> Result<()> {
+    let column = map_column()?;
+    let keys = Series::from_data(vec!["b", "b", "b"]);
+
+    let result = test_eval("get", &[column, keys])?;
+    assert_eq!(result.data_type().name(), "Nullable(Int32)");
+    assert_eq!(collect(result), vec![
+        DataValue::Int64(2),
+        DataValue::Null,
+        DataValue::Int64(3),
+    ]);
+
+    let keys = Series::from_data(vec!["a", "a", "c"]);
+    let result = test_eval("get", &[map_column()?, keys])?;
+    assert_eq!(collect(result), vec![
+        DataValue::Int64(1),
+        DataValue::Null,
+        DataValue::Null,
+    ]);
+
+    let keys = ConstColumn::new(Series::from_data(vec!["a"]), 3).arc();
+    let result = test_eval("get", &[map_column()?, keys])?;
+    assert_eq!(collect(result), vec![
+        DataValue::Int64(1),
+        DataValue::Null,
+        DataValue::Null,
+    ]);
+
+    // A NULL value is the same as a missing key.
+    let map_type = MapType::create(
+        StringType::new_impl(),
+        NullableType::new_impl(Int32Type::new_impl()),
+    );
+    let column = map_type.create_column(&[
+        DataValue::Array(vec![DataValue::Struct(vec![
+            DataValue::String(b"a".to_vec()),
+            DataValue::Null,
+        ])]),
+        DataValue::Array(vec![DataValue::Struct(vec![
+            DataValue::String(b"a".to_vec()),
+            DataValue::Int64(4),
+        ])]),
+    ])?;
+    let keys = Series::from_data(vec!["a", "a"]);
+    let result = test_eval("get", &[column, keys])?;
+    assert_eq!(result.data_type().name(), "Nullable(Int32)");
+    assert_eq!(collect(result), vec![DataValue::Null, DataValue::Int64(4)]);
+
+    assert!(test_eval("map_keys", &[Series::from_data(vec![1_i32])]).is_err());
+
+    Ok(())
+}
@@ -21,6 +21,7 @@ mod expressions;
 mod geos;
 mod hashes;
 mod logics;
+mod maps;
 mod maths;
 mod nullables;
 mod others;
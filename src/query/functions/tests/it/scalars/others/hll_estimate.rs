@@ -0,0 +1,58 @@
+// Copyright 2022 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::aggregates::HyperLogLog;
+
+use crate::scalars::scalar_function_test::test_scalar_functions;
+use crate::scalars::scalar_function_test::ScalarFunctionTest;
+
+#[test]
+fn test_hll_estimate_function() -> Result<()> {
+    let mut single = HyperLogLog::new();
+    single.add_hash(42);
+
+    // Every hash lands in its own register.
+    let mut hundred = HyperLogLog::new();
+    for i in 0..100u64 {
+        hundred.add_hash(i << 50);
+    }
+
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "empty-sketch",
+            columns: vec![Series::from_data(vec![""])],
+            expect: Series::from_data(vec![0u64]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "sparse-sketch",
+            columns: vec![Series::from_data(vec![
+                single.to_bytes()?,
+                hundred.to_bytes()?,
+            ])],
+            expect: Series::from_data(vec![1u64, 100]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "unknown-version",
+            columns: vec![Series::from_data(vec![vec![9u8, 0, 0]])],
+            expect: Series::from_data(vec![0u64]),
+            error: "Unknown HyperLogLog sketch version 9",
+        },
+    ];
+
+    test_scalar_functions("hll_estimate", &tests)
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod hll_estimate;
 mod humanize;
 mod inet_aton;
 mod inet_ntoa;
@@ -26,6 +26,7 @@ pub use plan_delete::DeletePlan;
 pub use plan_node_extras::Extras;
 pub use plan_node_extras::PrewhereInfo;
 pub use plan_node_extras::Projection;
+pub use plan_node_extras::VirtualColumn;
 pub use plan_node_stage::StageKind;
 pub use plan_node_stage_table::StageTableInfo;
 pub use plan_node_statistics::Statistics;
@@ -16,7 +16,9 @@ use std::collections::BTreeMap;
 use std::fmt::Debug;
 use std::fmt::Formatter;
 
+use common_datavalues::DataField;
 use common_datavalues::DataSchema;
+use common_datavalues::DataTypeImpl;
 use common_legacy_expression::LegacyExpression;
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, Eq)]
@@ -77,6 +79,28 @@ pub struct PrewhereInfo {
     pub filter: LegacyExpression,
 }
 
+/// A path of a variant column read as a column of its own, e.g. `v:a.b`.
+///
+/// Storages that shred variant columns may read it from the shredded data,
+/// otherwise it is extracted from the source column.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct VirtualColumn {
+    /// name of the virtual column in the output schema
+    pub name: String,
+    /// index of the source variant column in the table schema
+    pub source_index: usize,
+    /// keys of the object path inside the source column
+    pub path: Vec<String>,
+    /// `Nullable(Variant)` for a plain path, or the nullable target type of a cast
+    pub data_type: DataTypeImpl,
+}
+
+impl VirtualColumn {
+    pub fn to_data_field(&self) -> DataField {
+        DataField::new(&self.name, self.data_type.clone())
+    }
+}
+
 /// Extras is a wrapper for push down items.
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Default)]
 pub struct Extras {
@@ -92,6 +116,9 @@ pub struct Extras {
     pub limit: Option<usize>,
     /// Optional order_by expression plan
     pub order_by: Vec<LegacyExpression>,
+    /// Virtual columns to be read along with the projection
+    #[serde(default)]
+    pub virtual_columns: Vec<VirtualColumn>,
 }
 
 impl Extras {
@@ -102,6 +129,7 @@ impl Extras {
             prewhere: None,
             limit: None,
             order_by: vec![],
+            virtual_columns: vec![],
         }
     }
 }
@@ -18,6 +18,7 @@ use std::sync::Arc;
 use common_datavalues::DataField;
 use common_datavalues::DataSchema;
 use common_datavalues::DataSchemaRef;
+use common_datavalues::DataSchemaRefExt;
 use common_legacy_expression::LegacyExpression;
 use common_meta_app::schema::TableInfo;
 
@@ -78,13 +79,25 @@ pub struct ReadDataSourcePlan {
 impl ReadDataSourcePlan {
     /// Return schema after the projection
     pub fn schema(&self) -> DataSchemaRef {
-        self.scan_fields
+        let schema = self
+            .scan_fields
             .clone()
             .map(|x| {
                 let fields: Vec<_> = x.iter().map(|(_, f)| f.clone()).collect();
                 Arc::new(self.source_info.schema().project_by_fields(fields))
             })
-            .unwrap_or_else(|| self.source_info.schema())
+            .unwrap_or_else(|| self.source_info.schema());
+
+        match &self.push_downs {
+            Some(Extras {
+                virtual_columns, ..
+            }) if !virtual_columns.is_empty() => {
+                let mut fields = schema.fields().clone();
+                fields.extend(virtual_columns.iter().map(|c| c.to_data_field()));
+                DataSchemaRefExt::create(fields)
+            }
+            _ => schema,
+        }
     }
 
     /// Return designated required fields or all fields in a hash map.
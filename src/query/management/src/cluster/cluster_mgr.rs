@@ -74,6 +74,8 @@ impl ClusterMgr {
 
         KVMeta {
             expire_at: Some(expire_at.as_secs()),
+            lease: None,
+            tenant: None,
         }
     }
 }
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod cluster;
+mod pipe;
 mod quota;
 mod role;
 mod serde;
@@ -23,6 +24,8 @@ mod user;
 
 pub use cluster::ClusterApi;
 pub use cluster::ClusterMgr;
+pub use pipe::PipeApi;
+pub use pipe::PipeMgr;
 pub use quota::QuotaApi;
 pub use quota::QuotaMgr;
 pub use role::RoleApi;
@@ -0,0 +1,36 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_meta_types::PipeInfo;
+use common_meta_types::SeqV;
+
+#[async_trait::async_trait]
+pub trait PipeApi: Sync + Send {
+    // Add a pipe to /tenant/pipe-name.
+    async fn add_pipe(&self, pipe: PipeInfo) -> Result<u64>;
+
+    // Update a pipe, only if its seq matches `seq`.
+    // Used by the query nodes to claim a run of the pipe.
+    async fn update_pipe(&self, pipe: PipeInfo, seq: Option<u64>) -> Result<u64>;
+
+    // Get pipe by name.
+    async fn get_pipe(&self, pipe_name: &str, seq: Option<u64>) -> Result<SeqV<PipeInfo>>;
+
+    // Get all the pipes for a tenant.
+    async fn get_pipes(&self) -> Result<Vec<SeqV<PipeInfo>>>;
+
+    // Drop the tenant's pipe by name.
+    async fn drop_pipe(&self, pipe_name: &str, seq: Option<u64>) -> Result<()>;
+}
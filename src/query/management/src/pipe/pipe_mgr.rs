@@ -0,0 +1,129 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::escape_for_key;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_api::KVApi;
+use common_meta_types::IntoSeqV;
+use common_meta_types::MatchSeq;
+use common_meta_types::MatchSeqExt;
+use common_meta_types::Operation;
+use common_meta_types::PipeInfo;
+use common_meta_types::SeqV;
+use common_meta_types::UpsertKVReq;
+
+use crate::pipe::PipeApi;
+
+static PIPE_API_KEY_PREFIX: &str = "__fd_pipes";
+
+pub struct PipeMgr {
+    kv_api: Arc<dyn KVApi>,
+    pipe_prefix: String,
+}
+
+impl PipeMgr {
+    pub fn create(kv_api: Arc<dyn KVApi>, tenant: &str) -> Result<Self> {
+        if tenant.is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty(while pipe mgr create)",
+            ));
+        }
+
+        Ok(PipeMgr {
+            kv_api,
+            pipe_prefix: format!("{}/{}", PIPE_API_KEY_PREFIX, escape_for_key(tenant)?),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl PipeApi for PipeMgr {
+    async fn add_pipe(&self, info: PipeInfo) -> Result<u64> {
+        let seq = MatchSeq::Exact(0);
+        let val = Operation::Update(serde_json::to_vec(&info)?);
+        let key = format!("{}/{}", self.pipe_prefix, escape_for_key(&info.name)?);
+        let upsert_info = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq, val, None));
+
+        let res = upsert_info.await?.added_or_else(|v| {
+            ErrorCode::PipeAlreadyExists(format!("Pipe already exists, seq [{}]", v.seq))
+        })?;
+
+        Ok(res.seq)
+    }
+
+    async fn update_pipe(&self, info: PipeInfo, seq: Option<u64>) -> Result<u64> {
+        let val = Operation::Update(serde_json::to_vec(&info)?);
+        let key = format!("{}/{}", self.pipe_prefix, escape_for_key(&info.name)?);
+        let upsert_info =
+            self.kv_api
+                .upsert_kv(UpsertKVReq::new(&key, MatchSeq::from(seq), val, None));
+
+        let res = upsert_info.await?;
+        // The value is unchanged if the seq does not match,
+        // e.g. another query node claimed the pipe first.
+        match (res.changed(), res.result) {
+            (true, Some(SeqV { seq: s, .. })) => Ok(s),
+            _ => Err(ErrorCode::UnknownPipe(format!(
+                "Unknown pipe, or seq not match {}",
+                info.name
+            ))),
+        }
+    }
+
+    async fn get_pipe(&self, pipe_name: &str, seq: Option<u64>) -> Result<SeqV<PipeInfo>> {
+        let key = format!("{}/{}", self.pipe_prefix, escape_for_key(pipe_name)?);
+        let res = self.kv_api.get_kv(&key).await?;
+        let seq_value =
+            res.ok_or_else(|| ErrorCode::UnknownPipe(format!("Unknown pipe {}", pipe_name)))?;
+
+        match MatchSeq::from(seq).match_seq(&seq_value) {
+            Ok(_) => Ok(seq_value.into_seqv()?),
+            Err(_) => Err(ErrorCode::UnknownPipe(format!(
+                "Unknown pipe {}",
+                pipe_name
+            ))),
+        }
+    }
+
+    async fn get_pipes(&self) -> Result<Vec<SeqV<PipeInfo>>> {
+        let values = self.kv_api.prefix_list_kv(&self.pipe_prefix).await?;
+
+        let mut pipes = Vec::with_capacity(values.len());
+        for (_, value) in values {
+            pipes.push(value.into_seqv()?);
+        }
+        Ok(pipes)
+    }
+
+    async fn drop_pipe(&self, pipe_name: &str, seq: Option<u64>) -> Result<()> {
+        let key = format!("{}/{}", self.pipe_prefix, escape_for_key(pipe_name)?);
+        let res = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq.into(), Operation::Delete, None))
+            .await?;
+        if res.prev.is_some() && res.result.is_none() {
+            Ok(())
+        } else {
+            Err(ErrorCode::UnknownPipe(format!(
+                "Unknown pipe {}",
+                pipe_name
+            )))
+        }
+    }
+}
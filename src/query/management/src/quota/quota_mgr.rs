@@ -25,11 +25,10 @@ use common_meta_types::Operation;
 use common_meta_types::SeqV;
 use common_meta_types::TenantQuota;
 use common_meta_types::UpsertKVReq;
+use common_meta_types::TENANT_QUOTA_KEY_PREFIX;
 
 use super::quota_api::QuotaApi;
 
-static QUOTA_API_KEY_PREFIX: &str = "__fd_quotas";
-
 pub struct QuotaMgr {
     kv_api: Arc<dyn KVApi>,
     key: String,
@@ -44,7 +43,7 @@ impl QuotaMgr {
         }
         Ok(QuotaMgr {
             kv_api,
-            key: format!("{}/{}", QUOTA_API_KEY_PREFIX, escape_for_key(tenant)?),
+            key: format!("{}/{}", TENANT_QUOTA_KEY_PREFIX, escape_for_key(tenant)?),
         })
     }
 }
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod cluster;
+mod pipe;
 mod setting;
 mod stage;
 mod udf;
@@ -0,0 +1,96 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::tokio;
+use common_exception::Result;
+use common_management::*;
+use common_meta_embedded::MetaEmbedded;
+use common_meta_types::PipeInfo;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_add_pipe() -> Result<()> {
+    let (_, pipe_api) = new_pipe_api().await?;
+
+    let pipe = create_test_pipe();
+    pipe_api.add_pipe(pipe.clone()).await?;
+
+    let got = pipe_api.get_pipe("p1", None).await?;
+    assert_eq!(got.data, pipe);
+
+    match pipe_api.add_pipe(pipe).await {
+        Ok(_) => panic!("Already exists add pipe must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2953),
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_claim_pipe() -> Result<()> {
+    let (_, pipe_api) = new_pipe_api().await?;
+
+    pipe_api.add_pipe(create_test_pipe()).await?;
+    let seqv = pipe_api.get_pipe("p1", None).await?;
+
+    let mut claimed = seqv.data.clone();
+    claimed.status.last_run_node = "node1".to_string();
+    pipe_api.update_pipe(claimed, Some(seqv.seq)).await?;
+
+    // The seq changed, the second claim must fail.
+    let mut claimed = seqv.data.clone();
+    claimed.status.last_run_node = "node2".to_string();
+    match pipe_api.update_pipe(claimed, Some(seqv.seq)).await {
+        Ok(_) => panic!("Claim pipe with a stale seq must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2952),
+    }
+
+    let pipes = pipe_api.get_pipes().await?;
+    assert_eq!(pipes.len(), 1);
+    assert_eq!(pipes[0].data.status.last_run_node, "node1");
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_drop_pipe() -> Result<()> {
+    let (_, pipe_api) = new_pipe_api().await?;
+
+    pipe_api.add_pipe(create_test_pipe()).await?;
+    pipe_api.drop_pipe("p1", None).await?;
+    assert!(pipe_api.get_pipes().await?.is_empty());
+
+    match pipe_api.drop_pipe("p1", None).await {
+        Ok(_) => panic!("Unknown pipe drop must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2952),
+    }
+
+    Ok(())
+}
+
+fn create_test_pipe() -> PipeInfo {
+    PipeInfo {
+        name: "p1".to_string(),
+        copy_stmt: "COPY INTO t1 FROM @s1".to_string(),
+        auto_ingest: true,
+        ..Default::default()
+    }
+}
+
+async fn new_pipe_api() -> Result<(Arc<MetaEmbedded>, PipeMgr)> {
+    let test_api = Arc::new(MetaEmbedded::new_temp().await?);
+    let mgr = PipeMgr::create(test_api.clone(), "admin")?;
+    Ok((test_api, mgr))
+}
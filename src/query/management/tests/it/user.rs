@@ -28,6 +28,8 @@ use common_meta_types::MGetKVReply;
 use common_meta_types::MatchSeq;
 use common_meta_types::Operation;
 use common_meta_types::PasswordHashMethod;
+use common_meta_types::RangeKVReply;
+use common_meta_types::RangeKVReq;
 use common_meta_types::SeqV;
 use common_meta_types::TxnReply;
 use common_meta_types::TxnRequest;
@@ -56,6 +58,8 @@ mock! {
 
         async fn prefix_list_kv(&self, prefix: &str) -> Result<ListKVReply, KVAppError>;
 
+        async fn range_kv(&self, req: RangeKVReq) -> Result<RangeKVReply, KVAppError>;
+
         async fn transaction(&self, txn: TxnRequest) -> Result<TxnReply, KVAppError>;
 
         }
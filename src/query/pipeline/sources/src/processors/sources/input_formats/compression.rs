@@ -0,0 +1,95 @@
+//  Copyright 2022 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use opendal::io_util::CompressAlgorithm;
+use opendal::io_util::DecompressDecoder;
+use opendal::io_util::DecompressState;
+
+/// Number of leading bytes needed by `detect_compression_alg`.
+pub const COMPRESSION_MAGIC_LEN: usize = 6;
+
+/// Detect the compression algorithm from the magic bytes at the head of the data.
+///
+/// Brotli and raw deflate streams have no magic number, so they can only be
+/// recognized by file extension or an explicit `COMPRESSION` option.
+pub fn detect_compression_alg(prefix: &[u8]) -> Option<CompressAlgorithm> {
+    match prefix {
+        [0x1f, 0x8b, ..] => Some(CompressAlgorithm::Gzip),
+        [0x28, 0xb5, 0x2f, 0xfd, ..] => Some(CompressAlgorithm::Zstd),
+        [b'B', b'Z', b'h', b'1'..=b'9', ..] => Some(CompressAlgorithm::Bz2),
+        [0xfd, b'7', b'z', b'X', b'Z', 0x00, ..] => Some(CompressAlgorithm::Xz),
+        [0x78, flg, rest @ ..] if is_zlib_header(*flg, rest) => Some(CompressAlgorithm::Zlib),
+        _ => None,
+    }
+}
+
+/// Check a zlib header `0x78 FLG` followed by the head of the deflate stream `rest`.
+///
+/// The header must have CM = 8 with a 32K window, no preset dictionary and a valid checksum,
+/// i.e., `(CMF * 256 + FLG) % 31 == 0`. `0x78 0x5e` is also plain text `x^`, thus in this case
+/// the first deflate block type must be valid and the following bytes must not be all text.
+fn is_zlib_header(flg: u8, rest: &[u8]) -> bool {
+    if flg & 0x20 != 0 || (0x7800u16 | flg as u16) % 31 != 0 {
+        return false;
+    }
+
+    if !flg.is_ascii_graphic() {
+        return true;
+    }
+
+    match rest.first() {
+        // BTYPE = 3 is reserved.
+        Some(b) if (b >> 1) & 0b11 == 0b11 => false,
+        Some(_) => !rest
+            .iter()
+            .all(|b| b.is_ascii_graphic() || b.is_ascii_whitespace()),
+        None => false,
+    }
+}
+
+pub fn decompress(decoder: &mut DecompressDecoder, compressed: &[u8]) -> Result<Vec<u8>> {
+    let mut decompress_bufs = vec![];
+    let mut amt = 0;
+    loop {
+        match decoder.state() {
+            DecompressState::Reading => {
+                if amt == compressed.len() {
+                    break;
+                }
+                let read = decoder.fill(&compressed[amt..]);
+                amt += read;
+            }
+            DecompressState::Decoding => {
+                let mut decompress_buf = vec![0u8; 4096];
+                let written = decoder.decode(&mut decompress_buf[..]).map_err(|e| {
+                    ErrorCode::InvalidCompressionData(format!("compression data invalid: {e}"))
+                })?;
+                decompress_buf.truncate(written);
+                decompress_bufs.push(decompress_buf);
+            }
+            DecompressState::Flushing => {
+                let mut decompress_buf = vec![0u8; 4096];
+                let written = decoder.finish(&mut decompress_buf).map_err(|e| {
+                    ErrorCode::InvalidCompressionData(format!("compression data invalid: {e}"))
+                })?;
+                decompress_buf.truncate(written);
+                decompress_bufs.push(decompress_buf);
+            }
+            DecompressState::Done => break,
+        }
+    }
+    Ok(decompress_bufs.concat())
+}
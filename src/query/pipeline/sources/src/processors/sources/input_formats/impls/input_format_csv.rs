@@ -33,7 +33,9 @@ use crate::processors::sources::input_formats::input_format_text::AligningState;
 use crate::processors::sources::input_formats::input_format_text::BlockBuilder;
 use crate::processors::sources::input_formats::input_format_text::InputFormatTextBase;
 use crate::processors::sources::input_formats::input_format_text::RowBatch;
+use crate::processors::sources::input_formats::input_format_text::RowError;
 use crate::processors::sources::input_formats::InputContext;
+use crate::processors::sources::input_formats::InputError;
 
 pub struct InputFormatCSV {}
 
@@ -46,7 +48,7 @@ impl InputFormatCSV {
         format_settings: &FormatSettings,
         path: &str,
         row_index: usize,
-    ) -> Result<()> {
+    ) -> std::result::Result<(), RowError> {
         let mut field_start = 0;
         for (c, deserializer) in deserializers.iter_mut().enumerate() {
             let field_end = field_ends[c];
@@ -59,12 +61,20 @@ impl InputFormatCSV {
                 // todo(youngsofun): do not need escape, already done in csv-core
                 if let Err(e) = deserializer.de_text(&mut reader, format_settings) {
                     let err_msg = format_column_error(schema, c, col_data, &e.message());
-                    return Err(csv_error(&err_msg, path, row_index));
+                    return Err(RowError::new(
+                        Some(c),
+                        c,
+                        csv_error(&err_msg, path, row_index),
+                    ));
                 };
                 reader.ignore_white_spaces().expect("must success");
                 if reader.must_eof().is_err() {
                     let err_msg = format_column_error(schema, c, col_data, "bad field end");
-                    return Err(csv_error(&err_msg, path, row_index));
+                    return Err(RowError::new(
+                        Some(c),
+                        c + 1,
+                        csv_error(&err_msg, path, row_index),
+                    ));
                 }
             }
             field_start = field_end;
@@ -102,22 +112,23 @@ impl InputFormatTextBase for InputFormatCSV {
     }
 
     fn deserialize(builder: &mut BlockBuilder<Self>, batch: RowBatch) -> Result<()> {
-        let columns = &mut builder.mutable_columns;
-        let n_column = columns.len();
+        let n_column = builder.mutable_columns.len();
         let mut start = 0usize;
         let start_row = batch.start_row.expect("must success");
         let mut field_end_idx = 0;
         for (i, end) in batch.row_ends.iter().enumerate() {
             let buf = &batch.data[start..*end];
-            Self::read_row(
+            if let Err(e) = Self::read_row(
                 buf,
-                columns,
+                &mut builder.mutable_columns,
                 &builder.ctx.schema,
                 &batch.field_ends[field_end_idx..field_end_idx + n_column],
                 &builder.ctx.format_settings,
                 &batch.path,
                 start_row + i,
-            )?;
+            ) {
+                builder.on_row_error(&batch.path, start_row + i, e)?;
+            }
             start = *end;
             field_end_idx += n_column;
         }
@@ -168,26 +179,8 @@ impl InputFormatTextBase for InputFormatCSV {
                     ));
                 }
                 ReadRecordResult::Record => {
-                    if endlen < num_fields {
-                        return Err(csv_error(
-                            &format!("expect {} fields, only found {} ", num_fields, n_end),
-                            &state.path,
-                            state.rows,
-                        ));
-                    } else if endlen > num_fields + 1 {
-                        return Err(csv_error(
-                            &format!("too many fields, expect {}, got {}", num_fields, n_end),
-                            &state.path,
-                            state.rows,
-                        ));
-                    } else if endlen == num_fields + 1
-                        && field_ends[num_fields] != field_ends[num_fields - 1]
-                    {
-                        return Err(csv_error(
-                            "CSV allow ending with ',', but should not have data after it",
-                            &state.path,
-                            state.rows,
-                        ));
+                    if let Some(msg) = check_num_fields(field_ends, endlen, n_end, num_fields) {
+                        return Err(csv_error(&msg, &state.path, state.rows));
                     }
 
                     state.rows_to_skip -= 1;
@@ -248,26 +241,22 @@ impl InputFormatTextBase for InputFormatCSV {
                     ));
                 }
                 ReadRecordResult::Record => {
-                    if endlen < num_fields {
-                        return Err(csv_error(
-                            &format!("expect {} fields, only found {} ", num_fields, n_end),
-                            &state.path,
-                            start_row + row_batch.row_ends.len(),
-                        ));
-                    } else if endlen > num_fields + 1 {
-                        return Err(csv_error(
-                            &format!("too many fields, expect {}, got {}", num_fields, n_end),
-                            &state.path,
-                            start_row + row_batch.row_ends.len(),
-                        ));
-                    } else if endlen == num_fields + 1
-                        && field_ends[num_fields] != field_ends[num_fields - 1]
-                    {
-                        return Err(csv_error(
-                            "CSV allow ending with ',', but should not have data after it",
-                            &state.path,
-                            start_row + row_batch.row_ends.len(),
-                        ));
+                    if let Some(msg) = check_num_fields(field_ends, endlen, n_end, num_fields) {
+                        let row = start_row + row_batch.row_ends.len();
+                        match &state.ctx.error_collector {
+                            None => return Err(csv_error(&msg, &state.path, row)),
+                            Some(collector) => {
+                                collector.push(InputError {
+                                    file: state.path.clone(),
+                                    line: row + 1,
+                                    column: None,
+                                    message: msg,
+                                });
+                                // keep the record as a row of empty fields,
+                                // so the rows after it keep their line numbers.
+                                field_ends[..num_fields].fill(0);
+                            }
+                        }
                     }
                     row_batch
                         .field_ends
@@ -350,6 +339,29 @@ impl CsvReaderState {
     }
 }
 
+fn check_num_fields(
+    field_ends: &[usize],
+    endlen: usize,
+    n_end: usize,
+    num_fields: usize,
+) -> Option<String> {
+    if endlen < num_fields {
+        Some(format!(
+            "expect {} fields, only found {} ",
+            num_fields, n_end
+        ))
+    } else if endlen > num_fields + 1 {
+        Some(format!(
+            "too many fields, expect {}, got {}",
+            num_fields, n_end
+        ))
+    } else if endlen == num_fields + 1 && field_ends[num_fields] != field_ends[num_fields - 1] {
+        Some("CSV allow ending with ',', but should not have data after it".to_string())
+    } else {
+        None
+    }
+}
+
 fn csv_error(msg: &str, path: &str, row: usize) -> ErrorCode {
     let row = row + 1;
     let msg = format!("fail to parse CSV {}:{} {} ", path, row, msg);
@@ -0,0 +1,155 @@
+//  Copyright 2022 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::sync::Arc;
+
+use bstr::ByteSlice;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::TypeDeserializer;
+use common_datavalues::TypeDeserializerImpl;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_formats::verbose_string;
+use common_io::prelude::FormatSettings;
+use common_meta_types::StageFileFormatType;
+use common_settings::Settings;
+
+use crate::processors::sources::input_formats::impls::input_format_tsv::format_column_error;
+use crate::processors::sources::input_formats::input_format_text::get_time_zone;
+use crate::processors::sources::input_formats::input_format_text::AligningState;
+use crate::processors::sources::input_formats::input_format_text::BlockBuilder;
+use crate::processors::sources::input_formats::input_format_text::InputFormatTextBase;
+use crate::processors::sources::input_formats::input_format_text::RowBatch;
+use crate::processors::sources::input_formats::input_format_text::RowError;
+
+pub struct InputFormatFixedWidth {}
+
+impl InputFormatFixedWidth {
+    #[allow(clippy::too_many_arguments)]
+    fn read_row(
+        buf: &[u8],
+        deserializers: &mut [TypeDeserializerImpl],
+        schema: &DataSchemaRef,
+        column_offsets: &[usize],
+        format_settings: &FormatSettings,
+        path: &str,
+        offset: usize,
+        row_index: Option<usize>,
+    ) -> std::result::Result<(), RowError> {
+        let buf = buf.trim_end_with(|c| c == '\n' || c == '\r');
+        for (column_index, field) in split_fixed_width(buf, column_offsets)
+            .into_iter()
+            .enumerate()
+        {
+            let deserializer = &mut deserializers[column_index];
+            if field.is_empty() {
+                deserializer.de_default(format_settings);
+            } else if let Err(e) = deserializer.de_whole_text(field, format_settings) {
+                let row_info = if let Some(r) = row_index {
+                    format!("at row {},", r)
+                } else {
+                    String::new()
+                };
+                let mut msg = format!(
+                    "fail to parse fixed-width {} at offset {}, {} reason={}, row data: ",
+                    path,
+                    offset + column_offsets[column_index].min(buf.len()),
+                    row_info,
+                    format_column_error(schema, column_index, field, &e.message()),
+                );
+                verbose_string(buf, &mut msg);
+                return Err(RowError::new(
+                    Some(column_index),
+                    column_index,
+                    ErrorCode::BadBytes(msg),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Cut a record into fields by the start offsets of the columns and trim the padding
+/// spaces. The last column takes the rest of the record, and columns beyond the end of
+/// a short record are empty.
+pub fn split_fixed_width<'a>(record: &'a [u8], column_offsets: &[usize]) -> Vec<&'a [u8]> {
+    column_offsets
+        .iter()
+        .enumerate()
+        .map(|(i, start)| {
+            let end = column_offsets.get(i + 1).copied().unwrap_or(record.len());
+            let start = (*start).min(record.len());
+            let end = end.min(record.len());
+            record[start..end].trim_with(|c| c == ' ' || c == '\t')
+        })
+        .collect()
+}
+
+impl InputFormatTextBase for InputFormatFixedWidth {
+    fn format_type() -> StageFileFormatType {
+        StageFileFormatType::FixedWidth
+    }
+
+    fn is_splittable() -> bool {
+        true
+    }
+
+    fn get_format_settings(settings: &Arc<Settings>) -> Result<FormatSettings> {
+        let timezone = get_time_zone(settings)?;
+        Ok(FormatSettings {
+            record_delimiter: settings.get_format_record_delimiter()?.into_bytes(),
+            empty_as_default: settings.get_format_empty_as_default()? > 0,
+            timezone,
+            ..Default::default()
+        })
+    }
+
+    fn default_field_delimiter() -> u8 {
+        b' '
+    }
+
+    fn deserialize(builder: &mut BlockBuilder<Self>, batch: RowBatch) -> Result<()> {
+        tracing::debug!(
+            "fixed-width deserializing row batch {}, id={}, start_row={:?}, offset={}",
+            batch.path,
+            batch.batch_id,
+            batch.start_row,
+            batch.offset
+        );
+        let mut start = 0usize;
+        let start_row = batch.start_row;
+        for (i, end) in batch.row_ends.iter().enumerate() {
+            let buf = &batch.data[start..*end];
+            if let Err(e) = Self::read_row(
+                buf,
+                &mut builder.mutable_columns,
+                &builder.ctx.schema,
+                &builder.ctx.column_offsets,
+                &builder.ctx.format_settings,
+                &batch.path,
+                batch.offset + start,
+                start_row.map(|n| n + i),
+            ) {
+                let row = start_row.map(|n| n + i).unwrap_or_default();
+                builder.on_row_error(&batch.path, row, e)?;
+            }
+            start = *end;
+        }
+        Ok(())
+    }
+
+    fn align(state: &mut AligningState<Self>, buf: &[u8]) -> Result<Vec<RowBatch>> {
+        Ok(state.align_by_record_delimiter(buf))
+    }
+}
@@ -30,6 +30,7 @@ use crate::processors::sources::input_formats::input_format_text::AligningState;
 use crate::processors::sources::input_formats::input_format_text::BlockBuilder;
 use crate::processors::sources::input_formats::input_format_text::InputFormatTextBase;
 use crate::processors::sources::input_formats::input_format_text::RowBatch;
+use crate::processors::sources::input_formats::input_format_text::RowError;
 
 pub struct InputFormatNDJson {}
 
@@ -39,8 +40,9 @@ impl InputFormatNDJson {
         deserializers: &mut [TypeDeserializerImpl],
         format_settings: &FormatSettings,
         schema: &DataSchemaRef,
-    ) -> Result<()> {
-        let mut json: serde_json::Value = serde_json::from_reader(buf)?;
+    ) -> std::result::Result<(), RowError> {
+        let mut json: serde_json::Value =
+            serde_json::from_reader(buf).map_err(|e| RowError::new(None, 0, e.into()))?;
         // if it's not case_sensitive, we convert to lowercase
         if !format_settings.ident_case_sensitive {
             if let serde_json::Value::Object(x) = json {
@@ -49,7 +51,12 @@ impl InputFormatNDJson {
             }
         }
 
-        for (f, deser) in schema.fields().iter().zip(deserializers.iter_mut()) {
+        for (c, (f, deser)) in schema
+            .fields()
+            .iter()
+            .zip(deserializers.iter_mut())
+            .enumerate()
+        {
             let value = if format_settings.ident_case_sensitive {
                 &json[f.name().to_owned()]
             } else {
@@ -58,12 +65,16 @@ impl InputFormatNDJson {
 
             deser.de_json(value, format_settings).map_err(|e| {
                 let value_str = format!("{:?}", value);
-                ErrorCode::BadBytes(format!(
-                    "{}. column={} value={}",
-                    e,
-                    f.name(),
-                    maybe_truncated(&value_str, 1024),
-                ))
+                RowError::new(
+                    Some(c),
+                    c,
+                    ErrorCode::BadBytes(format!(
+                        "{}. column={} value={}",
+                        e,
+                        f.name(),
+                        maybe_truncated(&value_str, 1024),
+                    )),
+                )
             })?;
         }
         Ok(())
@@ -93,16 +104,15 @@ impl InputFormatTextBase for InputFormatNDJson {
     }
 
     fn deserialize(builder: &mut BlockBuilder<Self>, batch: RowBatch) -> Result<()> {
-        let columns = &mut builder.mutable_columns;
         let mut start = 0usize;
         let start_row = batch.start_row;
         for (i, end) in batch.row_ends.iter().enumerate() {
             let buf = &batch.data[start..*end];
             let buf = buf.trim();
             if !buf.is_empty() {
-                if let Err(e) = Self::read_row(
+                if let Err(mut e) = Self::read_row(
                     buf,
-                    columns,
+                    &mut builder.mutable_columns,
                     &builder.ctx.format_settings,
                     &builder.ctx.schema,
                 ) {
@@ -114,11 +124,13 @@ impl InputFormatTextBase for InputFormatNDJson {
                     let msg = format!(
                         "fail to parse NDJSON: {},  path={}, offset={}, {}",
                         &batch.path,
-                        e,
+                        e.error,
                         batch.offset + start,
                         row_info,
                     );
-                    return Err(ErrorCode::BadBytes(msg));
+                    e.error = ErrorCode::BadBytes(msg);
+                    let row = start_row.map(|n| n + i).unwrap_or_default();
+                    builder.on_row_error(&batch.path, row, e)?;
                 }
             }
             start = *end;
@@ -37,6 +37,7 @@ use common_arrow::parquet::read::read_metadata;
 use common_arrow::read_columns_async;
 use common_datablocks::DataBlock;
 use common_datavalues::remove_nullable;
+use common_datavalues::try_from_arrow_field;
 use common_datavalues::DataField;
 use common_datavalues::DataSchemaRef;
 use common_exception::ErrorCode;
@@ -368,7 +369,7 @@ fn get_fields(file_meta: &FileMetaData, schema: &DataSchemaRef) -> Result<Vec<Fi
             .filter(|c| c.name.eq_ignore_ascii_case(f.name()))
             .last()
         {
-            let tf = DataField::from(m);
+            let tf = DataField::new(&m.name, try_from_arrow_field(m)?);
             if remove_nullable(tf.data_type()) != remove_nullable(f.data_type()) {
                 let pair = (f, m);
                 let diff = pair.make_diff("expected_field", "infer_field");
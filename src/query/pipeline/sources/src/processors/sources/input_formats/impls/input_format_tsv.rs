@@ -30,6 +30,7 @@ use crate::processors::sources::input_formats::input_format_text::AligningState;
 use crate::processors::sources::input_formats::input_format_text::BlockBuilder;
 use crate::processors::sources::input_formats::input_format_text::InputFormatTextBase;
 use crate::processors::sources::input_formats::input_format_text::RowBatch;
+use crate::processors::sources::input_formats::input_format_text::RowError;
 
 pub struct InputFormatTSV {}
 
@@ -44,15 +45,21 @@ impl InputFormatTSV {
         batch_id: usize,
         offset: usize,
         row_index: Option<usize>,
-    ) -> Result<()> {
+    ) -> std::result::Result<(), RowError> {
         let num_columns = deserializers.len();
         let mut column_index = 0;
         let mut field_start = 0;
         let mut pos = 0;
         let mut err_msg = None;
+        let mut err_column = None;
+        let mut num_deserialized = 0;
         let buf_len = buf.len();
         while pos <= buf_len {
             if pos == buf_len || buf[pos] == b'\t' {
+                if column_index >= num_columns {
+                    err_msg = Some("too many columns".to_string());
+                    break;
+                }
                 let col_data = &buf[field_start..pos];
                 if col_data.is_empty() {
                     deserializers[column_index].de_default(format_settings);
@@ -68,6 +75,7 @@ impl InputFormatTSV {
                             col_data,
                             &e.message(),
                         ));
+                        err_column = Some(column_index);
                         break;
                     };
                     reader.ignore_white_spaces().expect("must success");
@@ -78,15 +86,14 @@ impl InputFormatTSV {
                             col_data,
                             "bad field end",
                         ));
+                        err_column = Some(column_index);
+                        num_deserialized += 1;
                         break;
                     }
                 }
+                num_deserialized += 1;
                 column_index += 1;
                 field_start = pos + 1;
-                if column_index > num_columns {
-                    err_msg = Some("too many columns".to_string());
-                    break;
-                }
             }
             pos += 1;
         }
@@ -113,7 +120,11 @@ impl InputFormatTSV {
                 m
             );
             verbose_string(buf, &mut msg);
-            Err(ErrorCode::BadBytes(msg))
+            Err(RowError::new(
+                err_column,
+                num_deserialized,
+                ErrorCode::BadBytes(msg),
+            ))
         } else {
             Ok(())
         }
@@ -153,22 +164,23 @@ impl InputFormatTextBase for InputFormatTSV {
             batch.start_row,
             batch.offset
         );
-        let schema = &builder.ctx.schema;
-        let columns = &mut builder.mutable_columns;
         let mut start = 0usize;
         let start_row = batch.start_row;
         for (i, end) in batch.row_ends.iter().enumerate() {
             let buf = &batch.data[start..*end]; // include \n
-            Self::read_row(
+            if let Err(e) = Self::read_row(
                 buf,
-                columns,
-                schema,
+                &mut builder.mutable_columns,
+                &builder.ctx.schema,
                 &builder.ctx.format_settings,
                 &batch.path,
                 batch.batch_id,
                 batch.offset + start,
                 start_row.map(|n| n + i),
-            )?;
+            ) {
+                let row = start_row.map(|n| n + i).unwrap_or_default();
+                builder.on_row_error(&batch.path, row, e)?;
+            }
             start = *end;
         }
         Ok(())
@@ -0,0 +1,598 @@
+//  Copyright 2022 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use bstr::ByteSlice;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::TypeDeserializer;
+use common_datavalues::TypeDeserializerImpl;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::FormatSettings;
+use common_meta_types::StageFileFormatType;
+use common_settings::Settings;
+use quick_xml::events::BytesStart;
+use quick_xml::events::Event;
+use quick_xml::Reader;
+
+use crate::processors::sources::input_formats::impls::input_format_tsv::format_column_error;
+use crate::processors::sources::input_formats::input_format_text::get_time_zone;
+use crate::processors::sources::input_formats::input_format_text::AligningState;
+use crate::processors::sources::input_formats::input_format_text::BlockBuilder;
+use crate::processors::sources::input_formats::input_format_text::InputFormatTextBase;
+use crate::processors::sources::input_formats::input_format_text::RowBatch;
+use crate::processors::sources::input_formats::input_format_text::RowError;
+
+/// Where the value of a column is found, relative to the row element.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct XmlColumnPath {
+    /// Names of the nested child elements, empty for the row element itself.
+    pub elements: Vec<Vec<u8>>,
+    /// Read the attribute of the element instead of its text.
+    pub attribute: Option<Vec<u8>>,
+    /// Fall back to the attribute of the row element if the child element is missing.
+    pub fallback_attribute: Option<Vec<u8>>,
+}
+
+impl XmlColumnPath {
+    /// Parse `child/grandchild`, `child/@attr` or `@attr`.
+    pub fn parse(path: &str) -> Result<Self> {
+        let mut elements = vec![];
+        let mut attribute = None;
+        let parts = path.trim().trim_matches('/').split('/').collect::<Vec<_>>();
+        for (i, part) in parts.iter().enumerate() {
+            let part = part.trim();
+            if part.is_empty() {
+                return Err(ErrorCode::BadArguments(format!(
+                    "invalid xml column path '{path}'"
+                )));
+            }
+            if let Some(attr) = part.strip_prefix('@') {
+                if i != parts.len() - 1 || attr.is_empty() {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "invalid xml column path '{path}', attribute must be the last part"
+                    )));
+                }
+                attribute = Some(attr.as_bytes().to_vec());
+            } else {
+                elements.push(part.as_bytes().to_vec());
+            }
+        }
+        Ok(Self {
+            elements,
+            attribute,
+            fallback_attribute: None,
+        })
+    }
+
+    /// Without a mapping, a column reads the child element of the same name, or else
+    /// the attribute of the same name of the row element.
+    fn by_name(name: &str) -> Self {
+        Self {
+            elements: vec![name.as_bytes().to_vec()],
+            attribute: None,
+            fallback_attribute: Some(name.as_bytes().to_vec()),
+        }
+    }
+}
+
+/// How rows and their columns are found in an XML document.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct XmlLayout {
+    /// Element names from the document root to a row element, `*` matches any name.
+    pub row_path: Vec<Vec<u8>>,
+    pub columns: Vec<XmlColumnPath>,
+}
+
+impl XmlLayout {
+    /// `row_path` defaults to the children of the root element, and columns not in
+    /// `column_mapping` are read by their names.
+    pub fn try_create(
+        row_path: &str,
+        column_mapping: &BTreeMap<String, String>,
+        schema: &DataSchemaRef,
+    ) -> Result<Self> {
+        let row_path = if row_path.trim().is_empty() {
+            vec![b"*".to_vec(), b"*".to_vec()]
+        } else {
+            row_path
+                .trim()
+                .trim_start_matches('/')
+                .split('/')
+                .map(|p| p.trim().as_bytes().to_vec())
+                .collect::<Vec<_>>()
+        };
+        if row_path.iter().any(|p| p.is_empty()) {
+            return Err(ErrorCode::BadArguments("invalid xml ROW_PATH"));
+        }
+
+        for name in column_mapping.keys() {
+            if !schema
+                .fields()
+                .iter()
+                .any(|f| f.name().eq_ignore_ascii_case(name))
+            {
+                return Err(ErrorCode::BadArguments(format!(
+                    "COLUMN_MAPPING refers to unknown column '{name}'"
+                )));
+            }
+        }
+        let mut columns = Vec::with_capacity(schema.num_fields());
+        for field in schema.fields() {
+            let mapped = column_mapping.get(field.name()).or_else(|| {
+                column_mapping
+                    .iter()
+                    .find(|(k, _)| k.eq_ignore_ascii_case(field.name()))
+                    .map(|(_, v)| v)
+            });
+            let column = match mapped {
+                Some(path) => XmlColumnPath::parse(path)?,
+                None => XmlColumnPath::by_name(field.name()),
+            };
+            columns.push(column);
+        }
+        Ok(Self { row_path, columns })
+    }
+
+    /// Extract the values of the columns from the text of a row element,
+    /// `None` for missing ones.
+    pub fn read_row_values(&self, row: &[u8]) -> Result<Vec<Option<Vec<u8>>>> {
+        let mut values: Vec<Option<Vec<u8>>> = vec![None; self.columns.len()];
+        // columns that are collecting text, with the depth of their elements
+        let mut capturing: Vec<(usize, usize)> = vec![];
+        let mut done = vec![false; self.columns.len()];
+        let mut stack: Vec<Vec<u8>> = vec![];
+        let mut seen_row = false;
+
+        let mut reader = Reader::from_reader(row);
+        reader.trim_text(true);
+        loop {
+            let event = reader
+                .read_event()
+                .map_err(|e| ErrorCode::BadBytes(format!("invalid xml: {e}")))?;
+            match event {
+                Event::Start(e) if !seen_row => {
+                    seen_row = true;
+                    self.read_attributes(&e, &stack, &mut values, &mut done)?;
+                    self.fill_fallback_attributes(&e, &mut values)?;
+                }
+                Event::Empty(e) if !seen_row => {
+                    self.read_attributes(&e, &stack, &mut values, &mut done)?;
+                    self.fill_fallback_attributes(&e, &mut values)?;
+                    break;
+                }
+                Event::Start(e) => {
+                    stack.push(e.name().as_ref().to_vec());
+                    self.read_attributes(&e, &stack, &mut values, &mut done)?;
+                    for (c, column) in self.columns.iter().enumerate() {
+                        if !done[c] && column.attribute.is_none() && column.elements == stack {
+                            values[c] = Some(vec![]);
+                            capturing.push((c, stack.len()));
+                        }
+                    }
+                }
+                Event::Empty(e) => {
+                    stack.push(e.name().as_ref().to_vec());
+                    self.read_attributes(&e, &stack, &mut values, &mut done)?;
+                    for (c, column) in self.columns.iter().enumerate() {
+                        if !done[c] && column.attribute.is_none() && column.elements == stack {
+                            values[c] = Some(vec![]);
+                            done[c] = true;
+                        }
+                    }
+                    stack.pop();
+                }
+                Event::Text(e) => {
+                    let text = e
+                        .unescape()
+                        .map_err(|e| ErrorCode::BadBytes(format!("invalid xml: {e}")))?;
+                    for (c, _) in capturing.iter() {
+                        if let Some(v) = values[*c].as_mut() {
+                            v.extend_from_slice(text.as_bytes());
+                        }
+                    }
+                }
+                Event::CData(e) => {
+                    let text = e.into_inner();
+                    for (c, _) in capturing.iter() {
+                        if let Some(v) = values[*c].as_mut() {
+                            v.extend_from_slice(&text);
+                        }
+                    }
+                }
+                Event::End(_) => {
+                    if stack.is_empty() {
+                        break;
+                    }
+                    let depth = stack.len();
+                    capturing.retain(|(c, d)| {
+                        if *d == depth {
+                            done[*c] = true;
+                            false
+                        } else {
+                            true
+                        }
+                    });
+                    stack.pop();
+                }
+                Event::Eof => break,
+                _ => {}
+            }
+        }
+        for v in values.iter_mut().flatten() {
+            let trimmed = v.trim();
+            if trimmed.len() != v.len() {
+                *v = trimmed.to_vec();
+            }
+        }
+        Ok(values)
+    }
+
+    fn read_attributes(
+        &self,
+        e: &BytesStart,
+        stack: &[Vec<u8>],
+        values: &mut [Option<Vec<u8>>],
+        done: &mut [bool],
+    ) -> Result<()> {
+        for (c, column) in self.columns.iter().enumerate() {
+            if done[c] || column.elements != stack {
+                continue;
+            }
+            if let Some(name) = &column.attribute {
+                values[c] = find_attribute(e, name)?;
+                done[c] = true;
+            }
+        }
+        Ok(())
+    }
+
+    fn fill_fallback_attributes(
+        &self,
+        e: &BytesStart,
+        values: &mut [Option<Vec<u8>>],
+    ) -> Result<()> {
+        for (c, column) in self.columns.iter().enumerate() {
+            if let Some(name) = &column.fallback_attribute {
+                if let Some(v) = find_attribute(e, name)? {
+                    values[c] = Some(v);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn find_attribute(e: &BytesStart, name: &[u8]) -> Result<Option<Vec<u8>>> {
+    for attr in e.attributes() {
+        let attr = attr.map_err(|e| ErrorCode::BadBytes(format!("invalid xml attribute: {e}")))?;
+        if attr.key.as_ref() == name {
+            let value = attr
+                .unescape_value()
+                .map_err(|e| ErrorCode::BadBytes(format!("invalid xml attribute: {e}")))?;
+            return Ok(Some(value.as_bytes().to_vec()));
+        }
+    }
+    Ok(None)
+}
+
+/// Cut the row elements out of an XML stream, which may arrive in pieces of any size.
+pub struct XmlReaderState {
+    row_path: Vec<Vec<u8>>,
+    // the unconsumed input, from the start of the current row or incomplete markup
+    pending: Vec<u8>,
+    // position in `pending` to continue scanning from
+    pos: usize,
+    // open elements from the document root
+    stack: Vec<Vec<u8>>,
+    // depth and start position in `pending` of the current row element
+    row: Option<(usize, usize)>,
+}
+
+impl XmlReaderState {
+    pub fn create(layout: &XmlLayout) -> Self {
+        Self {
+            row_path: layout.row_path.clone(),
+            pending: vec![],
+            pos: 0,
+            stack: vec![],
+            row: None,
+        }
+    }
+
+    /// Feed the next piece of input, return the complete row elements in it,
+    /// concatenated, with their end positions.
+    pub fn read(&mut self, buf: &[u8]) -> Result<(Vec<u8>, Vec<usize>)> {
+        self.pending.extend_from_slice(buf);
+        let mut data = vec![];
+        let mut row_ends = vec![];
+        loop {
+            let lt = match memchr(b'<', &self.pending[self.pos..]) {
+                Some(i) => self.pos + i,
+                None => {
+                    self.pos = self.pending.len();
+                    break;
+                }
+            };
+            let end = match markup_end(&self.pending[lt..])? {
+                Some(n) => lt + n,
+                None => {
+                    // wait for the rest of the markup
+                    self.pos = lt;
+                    break;
+                }
+            };
+            let markup = &self.pending[lt..end];
+            if markup.starts_with(b"</") {
+                let name = markup[2..markup.len() - 1].trim().to_vec();
+                match self.stack.pop() {
+                    Some(open) if open == name => {}
+                    open => {
+                        return Err(ErrorCode::BadBytes(format!(
+                            "invalid xml: end tag </{}> does not match <{}>",
+                            String::from_utf8_lossy(&name),
+                            String::from_utf8_lossy(&open.unwrap_or_default()),
+                        )));
+                    }
+                }
+                if let Some((depth, start)) = self.row {
+                    if depth == self.stack.len() + 1 {
+                        data.extend_from_slice(&self.pending[start..end]);
+                        row_ends.push(data.len());
+                        self.row = None;
+                    }
+                }
+            } else if is_name_start(markup[1]) {
+                let name = markup[1..]
+                    .iter()
+                    .position(|c| c.is_ascii_whitespace() || *c == b'/' || *c == b'>')
+                    .map(|n| markup[1..1 + n].to_vec())
+                    .unwrap_or_default();
+                let self_closing = markup.ends_with(b"/>");
+                self.stack.push(name);
+                if self.row.is_none() && is_row_path(&self.row_path, &self.stack) {
+                    self.row = Some((self.stack.len(), lt));
+                }
+                if self_closing {
+                    self.stack.pop();
+                    if let Some((depth, start)) = self.row {
+                        if depth == self.stack.len() + 1 {
+                            data.extend_from_slice(&self.pending[start..end]);
+                            row_ends.push(data.len());
+                            self.row = None;
+                        }
+                    }
+                }
+            }
+            // comments, CDATA, processing instructions and declarations are skipped,
+            // CDATA in a row is kept as part of the row
+            self.pos = end;
+        }
+
+        // drop the consumed input
+        let keep_from = match self.row {
+            Some((_, start)) => start,
+            None => self.pos,
+        };
+        self.pending.drain(..keep_from);
+        self.pos -= keep_from;
+        if let Some((_, start)) = self.row.as_mut() {
+            *start -= keep_from;
+        }
+        Ok((data, row_ends))
+    }
+
+    /// Check the end of the input is not in the middle of a row.
+    pub fn finish(&self) -> Result<()> {
+        if self.row.is_some() {
+            return Err(ErrorCode::BadBytes(
+                "invalid xml: unexpected end of file in a row element",
+            ));
+        }
+        Ok(())
+    }
+}
+
+fn is_row_path(row_path: &[Vec<u8>], path: &[Vec<u8>]) -> bool {
+    path.len() == row_path.len()
+        && path
+            .iter()
+            .zip(row_path.iter())
+            .all(|(name, p)| p == b"*" || name == p)
+}
+
+fn is_name_start(c: u8) -> bool {
+    c.is_ascii_alphabetic() || c == b'_' || c == b':' || c >= 0x80
+}
+
+fn memchr(c: u8, buf: &[u8]) -> Option<usize> {
+    buf.iter().position(|b| *b == c)
+}
+
+fn find(pattern: &[u8], buf: &[u8]) -> Option<usize> {
+    buf.windows(pattern.len()).position(|w| w == pattern)
+}
+
+/// Length of the markup at the head of `buf`, which starts with `<`,
+/// `None` if the markup is not complete yet.
+fn markup_end(buf: &[u8]) -> Result<Option<usize>> {
+    const COMMENT: &[u8] = b"<!--";
+    const CDATA: &[u8] = b"<![CDATA[";
+    let terminated_by =
+        |start: usize, end: &[u8]| find(end, &buf[start..]).map(|n| start + n + end.len());
+    if buf.len() < 2 {
+        return Ok(None);
+    }
+    match buf[1] {
+        b'?' => Ok(terminated_by(2, b"?>")),
+        b'!' => {
+            if buf.len() < CDATA.len() && (COMMENT.starts_with(buf) || CDATA.starts_with(buf)) {
+                Ok(None)
+            } else if buf.starts_with(COMMENT) {
+                Ok(terminated_by(COMMENT.len(), b"-->"))
+            } else if buf.starts_with(CDATA) {
+                Ok(terminated_by(CDATA.len(), b"]]>"))
+            } else {
+                // <!DOCTYPE ...>, with an optional internal subset in []
+                let mut depth = 0;
+                for (i, c) in buf.iter().enumerate().skip(2) {
+                    match c {
+                        b'[' => depth += 1,
+                        b']' => depth -= 1,
+                        b'>' if depth == 0 => return Ok(Some(i + 1)),
+                        _ => {}
+                    }
+                }
+                Ok(None)
+            }
+        }
+        _ => {
+            // start or end tag, `>` may be quoted in attribute values
+            let mut quote = None;
+            for (i, c) in buf.iter().enumerate().skip(1) {
+                match (quote, c) {
+                    (None, b'"' | b'\'') => quote = Some(*c),
+                    (Some(q), c) if q == *c => quote = None,
+                    (None, b'>') => return Ok(Some(i + 1)),
+                    (None, b'<') => {
+                        return Err(ErrorCode::BadBytes("invalid xml: unexpected '<' in a tag"));
+                    }
+                    _ => {}
+                }
+            }
+            Ok(None)
+        }
+    }
+}
+
+pub struct InputFormatXML {}
+
+impl InputFormatXML {
+    fn read_row(
+        buf: &[u8],
+        deserializers: &mut [TypeDeserializerImpl],
+        schema: &DataSchemaRef,
+        layout: &XmlLayout,
+        format_settings: &FormatSettings,
+    ) -> std::result::Result<(), RowError> {
+        let values = layout
+            .read_row_values(buf)
+            .map_err(|e| RowError::new(None, 0, e))?;
+        for (column_index, value) in values.into_iter().enumerate() {
+            let deserializer = &mut deserializers[column_index];
+            match value {
+                Some(v) if !v.is_empty() => {
+                    if let Err(e) = deserializer.de_whole_text(&v, format_settings) {
+                        let msg = format_column_error(schema, column_index, &v, &e.message());
+                        return Err(RowError::new(
+                            Some(column_index),
+                            column_index,
+                            ErrorCode::BadBytes(msg),
+                        ));
+                    }
+                }
+                _ => deserializer.de_default(format_settings),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl InputFormatTextBase for InputFormatXML {
+    fn format_type() -> StageFileFormatType {
+        StageFileFormatType::Xml
+    }
+
+    fn get_format_settings(settings: &Arc<Settings>) -> Result<FormatSettings> {
+        let timezone = get_time_zone(settings)?;
+        Ok(FormatSettings {
+            timezone,
+            ..Default::default()
+        })
+    }
+
+    fn default_field_delimiter() -> u8 {
+        b','
+    }
+
+    fn deserialize(builder: &mut BlockBuilder<Self>, batch: RowBatch) -> Result<()> {
+        let mut start = 0usize;
+        let start_row = batch.start_row;
+        for (i, end) in batch.row_ends.iter().enumerate() {
+            let buf = &batch.data[start..*end];
+            if let Err(mut e) = Self::read_row(
+                buf,
+                &mut builder.mutable_columns,
+                &builder.ctx.schema,
+                &builder.ctx.xml_layout,
+                &builder.ctx.format_settings,
+            ) {
+                let row_info = if let Some(r) = start_row {
+                    format!("row={},", r + i)
+                } else {
+                    String::new()
+                };
+                let msg = format!(
+                    "fail to parse XML: {}, path={}, {}",
+                    e.error.message(),
+                    &batch.path,
+                    row_info,
+                );
+                e.error = ErrorCode::BadBytes(msg);
+                let row = start_row.map(|n| n + i).unwrap_or_default();
+                builder.on_row_error(&batch.path, row, e)?;
+            }
+            start = *end;
+        }
+        Ok(())
+    }
+
+    fn align(state: &mut AligningState<Self>, buf: &[u8]) -> Result<Vec<RowBatch>> {
+        let reader = state.xml_reader.as_mut().expect("must success");
+        let (mut data, mut row_ends) = reader
+            .read(buf)
+            .map_err(|e| ErrorCode::BadBytes(format!("{}, path={}", e.message(), state.path)))?;
+        if state.rows_to_skip > 0 && !row_ends.is_empty() {
+            let n = state.rows_to_skip.min(row_ends.len());
+            let skipped = row_ends[n - 1];
+            data.drain(..skipped);
+            row_ends = row_ends[n..].iter().map(|e| e - skipped).collect();
+            state.rows_to_skip -= n;
+            state.rows += n;
+        }
+        if row_ends.is_empty() {
+            return Ok(vec![]);
+        }
+        let output = RowBatch {
+            data,
+            row_ends,
+            field_ends: vec![],
+            path: state.path.clone(),
+            batch_id: state.batch_id,
+            offset: state.offset,
+            start_row: Some(state.rows),
+        };
+        state.offset += buf.len();
+        state.rows += output.row_ends.len();
+        state.batch_id += 1;
+        tracing::debug!(
+            "xml aligner: batch {}, {} rows",
+            output.batch_id,
+            output.row_ends.len()
+        );
+        Ok(vec![output])
+    }
+}
@@ -13,6 +13,8 @@
 //  limitations under the License.
 
 pub mod input_format_csv;
+pub mod input_format_fixed_width;
 pub mod input_format_ndjson;
 pub mod input_format_parquet;
 pub mod input_format_tsv;
+pub mod input_format_xml;
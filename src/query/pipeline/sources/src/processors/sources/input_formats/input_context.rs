@@ -34,9 +34,13 @@ use opendal::Operator;
 
 use crate::processors::sources::input_formats::delimiter::RecordDelimiter;
 use crate::processors::sources::input_formats::impls::input_format_csv::InputFormatCSV;
+use crate::processors::sources::input_formats::impls::input_format_fixed_width::InputFormatFixedWidth;
 use crate::processors::sources::input_formats::impls::input_format_ndjson::InputFormatNDJson;
 use crate::processors::sources::input_formats::impls::input_format_parquet::InputFormatParquet;
 use crate::processors::sources::input_formats::impls::input_format_tsv::InputFormatTSV;
+use crate::processors::sources::input_formats::impls::input_format_xml::InputFormatXML;
+use crate::processors::sources::input_formats::impls::input_format_xml::XmlLayout;
+use crate::processors::sources::input_formats::input_error::InputErrorCollector;
 use crate::processors::sources::input_formats::input_format_text::InputFormatText;
 use crate::processors::sources::input_formats::input_pipeline::StreamingReadBatch;
 use crate::processors::sources::input_formats::input_split::SplitInfo;
@@ -115,6 +119,11 @@ pub struct InputContext {
     pub field_delimiter: u8,
     pub record_delimiter: RecordDelimiter,
 
+    // fixed-width format only, start offset of each column in a record
+    pub column_offsets: Vec<usize>,
+    // xml format only
+    pub xml_layout: XmlLayout,
+
     // runtime config
     pub settings: Arc<Settings>,
     pub format_settings: FormatSettings,
@@ -124,6 +133,9 @@ pub struct InputContext {
     pub block_memory_size_threshold: usize,
 
     pub scan_progress: Arc<Progress>,
+
+    // skip and collect bad rows instead of failing, for validation only
+    pub error_collector: Option<Arc<InputErrorCollector>>,
 }
 
 impl Debug for InputContext {
@@ -137,6 +149,7 @@ impl Debug for InputContext {
             .field("rows_per_block", &self.rows_per_block)
             .field("read_batch_size", &self.read_batch_size)
             .field("num_splits", &self.splits.len())
+            .field("collect_errors", &self.error_collector.is_some())
             .finish()
     }
 }
@@ -150,6 +163,10 @@ impl InputContext {
                 Ok(Arc::new(InputFormatText::<InputFormatNDJson>::create()))
             }
             StageFileFormatType::Parquet => Ok(Arc::new(InputFormatParquet {})),
+            StageFileFormatType::Xml => Ok(Arc::new(InputFormatText::<InputFormatXML>::create())),
+            StageFileFormatType::FixedWidth => {
+                Ok(Arc::new(InputFormatText::<InputFormatFixedWidth>::create()))
+            }
             format => Err(ErrorCode::LogicalError(format!(
                 "Unsupported file format: {:?}",
                 format
@@ -193,6 +210,32 @@ impl InputContext {
                 file_format_options.field_delimiter.as_bytes()[0]
             }
         };
+
+        let column_offsets = match file_format_options.format {
+            StageFileFormatType::FixedWidth => {
+                if file_format_options.column_offsets.len() != schema.num_fields() {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "COLUMN_OFFSETS has {} offsets, but there are {} columns",
+                        file_format_options.column_offsets.len(),
+                        schema.num_fields()
+                    )));
+                }
+                file_format_options
+                    .column_offsets
+                    .iter()
+                    .map(|o| *o as usize)
+                    .collect()
+            }
+            _ => vec![],
+        };
+        let xml_layout = match file_format_options.format {
+            StageFileFormatType::Xml => XmlLayout::try_create(
+                &file_format_options.row_path,
+                &file_format_options.column_mapping,
+                &schema,
+            )?,
+            _ => XmlLayout::default(),
+        };
         Ok(InputContext {
             format,
             schema,
@@ -200,6 +243,8 @@ impl InputContext {
             settings,
             format_settings,
             record_delimiter,
+            column_offsets,
+            xml_layout,
             rows_per_block,
             read_batch_size,
             rows_to_skip,
@@ -208,6 +253,7 @@ impl InputContext {
             source: InputSource::Operator(operator),
             plan: InputPlan::CopyInto(plan),
             block_memory_size_threshold: DEFAULT_BLOCK_SIZE_IN_MEM_SIZE_THRESHOLD,
+            error_collector: None,
         })
     }
 
@@ -224,6 +270,11 @@ impl InputContext {
 
         let format_type =
             StageFileFormatType::from_str(format_name).map_err(ErrorCode::UnknownFormat)?;
+        if format_type == StageFileFormatType::FixedWidth {
+            return Err(ErrorCode::UnknownFormat(
+                "FixedWidth format needs COLUMN_OFFSETS, which is only supported by COPY",
+            ));
+        }
         let format = Self::get_input_format(&format_type)?;
         let format_settings = format.get_format_settings(&settings)?;
         let read_batch_size = settings.get_input_read_buffer_size()? as usize;
@@ -248,6 +299,10 @@ impl InputContext {
             is_multi_part,
             compression,
         };
+        let xml_layout = match format_type {
+            StageFileFormatType::Xml => XmlLayout::try_create("", &Default::default(), &schema)?,
+            _ => XmlLayout::default(),
+        };
 
         Ok(InputContext {
             format,
@@ -255,6 +310,8 @@ impl InputContext {
             settings,
             format_settings,
             record_delimiter,
+            column_offsets: vec![],
+            xml_layout,
             rows_per_block,
             read_batch_size,
             field_delimiter,
@@ -264,6 +321,7 @@ impl InputContext {
             plan: InputPlan::StreamingLoad(plan),
             splits: vec![],
             block_memory_size_threshold: DEFAULT_BLOCK_SIZE_IN_MEM_SIZE_THRESHOLD,
+            error_collector: None,
         })
     }
 
@@ -275,12 +333,15 @@ impl InputContext {
         1
     }
 
-    pub fn get_compression_alg(&self, path: &str) -> Result<Option<CompressAlgorithm>> {
-        let opt = match &self.plan {
+    pub fn get_compression_option(&self) -> StageFileCompression {
+        match &self.plan {
             InputPlan::CopyInto(p) => p.stage_info.file_format_options.compression,
             InputPlan::StreamingLoad(p) => p.compression,
-        };
-        Self::get_compression_alg_copy(opt, path)
+        }
+    }
+
+    pub fn get_compression_alg(&self, path: &str) -> Result<Option<CompressAlgorithm>> {
+        Self::get_compression_alg_copy(self.get_compression_option(), path)
     }
 
     pub fn get_compression_alg_copy(
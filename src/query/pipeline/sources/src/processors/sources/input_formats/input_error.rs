@@ -0,0 +1,59 @@
+//  Copyright 2022 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::mem;
+use std::sync::Mutex;
+
+/// A row that failed to be deserialized, located in its file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputError {
+    pub file: String,
+    /// 1-based line of the row in the file, header lines included.
+    pub line: usize,
+    /// Name of the column the error happened in, `None` if the row as a whole is malformed.
+    pub column: Option<String>,
+    pub message: String,
+}
+
+/// Collects row errors instead of aborting at the first one.
+///
+/// Used by `COPY ... VALIDATION_MODE = RETURN_ERRORS | RETURN_ALL_ERRORS`:
+/// bad rows are skipped and recorded here, and reported when the pipeline finishes.
+#[derive(Debug, Default)]
+pub struct InputErrorCollector {
+    errors: Mutex<Vec<InputError>>,
+}
+
+impl InputErrorCollector {
+    pub fn push(&self, error: InputError) {
+        let mut guard = self.errors.lock().expect("must success");
+        guard.push(error);
+    }
+
+    pub fn len(&self) -> usize {
+        self.errors.lock().expect("must success").len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Take all the collected errors, ordered by file and line.
+    pub fn take(&self) -> Vec<InputError> {
+        let mut guard = self.errors.lock().expect("must success");
+        let mut errors = mem::take(&mut *guard);
+        errors.sort_by(|a, b| a.file.cmp(&b.file).then(a.line.cmp(&b.line)));
+        errors
+    }
+}
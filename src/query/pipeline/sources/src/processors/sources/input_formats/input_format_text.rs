@@ -24,6 +24,7 @@ use common_datavalues::TypeDeserializerImpl;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_io::prelude::FormatSettings;
+use common_meta_types::StageFileCompression;
 use common_meta_types::StageFileFormatType;
 use common_pipeline_core::Pipeline;
 use common_settings::Settings;
@@ -33,10 +34,15 @@ use opendal::Operator;
 
 use super::InputFormat;
 use crate::processors::sources::input_formats::beyond_end_reader::BeyondEndReader;
+use crate::processors::sources::input_formats::compression::decompress;
+use crate::processors::sources::input_formats::compression::detect_compression_alg;
+use crate::processors::sources::input_formats::compression::COMPRESSION_MAGIC_LEN;
 use crate::processors::sources::input_formats::delimiter::RecordDelimiter;
 use crate::processors::sources::input_formats::impls::input_format_csv::CsvReaderState;
+use crate::processors::sources::input_formats::impls::input_format_xml::XmlReaderState;
 use crate::processors::sources::input_formats::input_context::CopyIntoPlan;
 use crate::processors::sources::input_formats::input_context::InputContext;
+use crate::processors::sources::input_formats::input_error::InputError;
 use crate::processors::sources::input_formats::input_pipeline::AligningStateTrait;
 use crate::processors::sources::input_formats::input_pipeline::BlockBuilderTrait;
 use crate::processors::sources::input_formats::input_pipeline::InputFormatPipe;
@@ -122,12 +128,23 @@ impl<T: InputFormatTextBase> InputFormat for InputFormatText<T> {
         for path in &plan.files {
             let obj = op.object(path);
             let size = obj.metadata().await?.content_length() as usize;
-            let compress_alg = InputContext::get_compression_alg_copy(
-                plan.stage_info.file_format_options.compression,
-                path,
-            )?;
+            let compress_option = plan.stage_info.file_format_options.compression;
+            let mut compress_alg = InputContext::get_compression_alg_copy(compress_option, path)?;
             let split_size = plan.stage_info.copy_options.split_size;
-            if compress_alg.is_none() && T::is_splittable() && split_size > 0 {
+            let splittable = T::is_splittable() && split_size > 0;
+            if compress_alg.is_none()
+                && splittable
+                && compress_option == StageFileCompression::Auto
+                && size > 0
+            {
+                // A compressed file can not be split, so look at its magic bytes
+                // before cutting it by offsets.
+                let prefix = obj
+                    .range_read(0..size.min(COMPRESSION_MAGIC_LEN) as u64)
+                    .await?;
+                compress_alg = detect_compression_alg(&prefix);
+            }
+            if compress_alg.is_none() && splittable {
                 let split_offsets = split_by_size(size, split_size as usize);
                 let num_file_splits = split_offsets.len();
                 tracing::debug!(
@@ -200,7 +217,11 @@ pub struct AligningState<T> {
     pub tail_of_last_batch: Vec<u8>,
     pub num_fields: usize,
     pub decoder: Option<DecompressDecoder>,
+    /// Head of the input buffered until the compression can be detected,
+    /// `None` when the compression is already known.
+    pub sniff_buf: Option<Vec<u8>>,
     pub csv_reader: Option<CsvReaderState>,
+    pub xml_reader: Option<XmlReaderState>,
     phantom: PhantomData<T>,
 }
 
@@ -214,6 +235,7 @@ impl<T: InputFormatTextBase> AligningState<T> {
             for b in buf.iter() {
                 if *b == record_delimiter_end {
                     self.rows_to_skip -= 1;
+                    self.rows += 1;
                     if self.rows_to_skip == 0 {
                         break;
                     }
@@ -266,6 +288,25 @@ impl<T: InputFormatTextBase> AligningState<T> {
         }
     }
 
+    /// Buffer the head of the input until enough bytes are seen to detect the
+    /// compression, then return the buffered data to be aligned.
+    fn sniff(&mut self, data: Vec<u8>, is_end: bool) -> Option<Vec<u8>> {
+        let buf = match self.sniff_buf.as_mut() {
+            None => return Some(data),
+            Some(buf) => buf,
+        };
+        buf.extend_from_slice(&data);
+        if buf.len() < COMPRESSION_MAGIC_LEN && !is_end {
+            return None;
+        }
+        let buf = self.sniff_buf.take().unwrap();
+        if let Some(alg) = detect_compression_alg(&buf) {
+            tracing::debug!("detected compression {:?} of file {}", alg, self.path);
+            self.decoder = Some(DecompressDecoder::new(alg));
+        }
+        Some(buf)
+    }
+
     fn flush(&mut self) -> Vec<RowBatch> {
         if self.tail_of_last_batch.is_empty() {
             vec![]
@@ -305,20 +346,38 @@ impl<T: InputFormatTextBase> AligningStateTrait for AligningState<T> {
         };
         let path = split_info.file.path.clone();
 
-        let decoder = ctx.get_compression_alg(&path)?.map(DecompressDecoder::new);
+        let compress_alg = split_info.file.compress_alg;
+        let decoder = compress_alg.map(DecompressDecoder::new);
+        // Without a known codec, a whole-file input in `Auto` mode may still be
+        // compressed, so detect it from the magic bytes of the first read batch.
+        let sniff_buf = if compress_alg.is_none()
+            && split_info.num_file_splits == 1
+            && ctx.get_compression_option() == StageFileCompression::Auto
+        {
+            Some(vec![])
+        } else {
+            None
+        };
         let csv_reader = if T::format_type() == StageFileFormatType::Csv {
             Some(CsvReaderState::create(ctx))
         } else {
             None
         };
+        let xml_reader = if T::format_type() == StageFileFormatType::Xml {
+            Some(XmlReaderState::create(&ctx.xml_layout))
+        } else {
+            None
+        };
 
         Ok(AligningState::<T> {
             ctx: ctx.clone(),
             split_info: split_info.clone(),
             path,
             decoder,
+            sniff_buf,
             rows_to_skip,
             csv_reader,
+            xml_reader,
             tail_of_last_batch: vec![],
             rows: 0,
             batch_id: 0,
@@ -332,6 +391,10 @@ impl<T: InputFormatTextBase> AligningStateTrait for AligningState<T> {
 
     fn align(&mut self, read_batch: Option<Vec<u8>>) -> Result<Vec<RowBatch>> {
         let row_batches = if let Some(data) = read_batch {
+            let data = match self.sniff(data, false) {
+                Some(data) => data,
+                None => return Ok(vec![]),
+            };
             let buf = if let Some(decoder) = self.decoder.as_mut() {
                 decompress(decoder, &data)?
             } else {
@@ -339,10 +402,20 @@ impl<T: InputFormatTextBase> AligningStateTrait for AligningState<T> {
             };
             T::align(self, &buf)?
         } else {
+            let mut row_batches = match self.sniff(vec![], true) {
+                Some(data) if !data.is_empty() => self.align(Some(data))?,
+                _ => vec![],
+            };
             if let Some(decoder) = &self.decoder {
                 assert_eq!(decoder.state(), DecompressState::Done)
             }
-            self.flush()
+            if let Some(xml_reader) = &self.xml_reader {
+                xml_reader.finish().map_err(|e| {
+                    ErrorCode::BadBytes(format!("{}, path={}", e.message(), self.path))
+                })?;
+            }
+            row_batches.extend(self.flush());
+            row_batches
         };
         Ok(row_batches)
     }
@@ -357,6 +430,25 @@ impl<T: InputFormatTextBase> AligningStateTrait for AligningState<T> {
     }
 }
 
+/// Error of deserializing a single row.
+pub struct RowError {
+    /// Index of the column that failed, `None` if the row as a whole is malformed.
+    pub column: Option<usize>,
+    /// Number of leading columns of the row already pushed into the deserializers.
+    pub num_deserialized: usize,
+    pub error: ErrorCode,
+}
+
+impl RowError {
+    pub fn new(column: Option<usize>, num_deserialized: usize, error: ErrorCode) -> Self {
+        RowError {
+            column,
+            num_deserialized,
+            error,
+        }
+    }
+}
+
 pub struct BlockBuilder<T> {
     pub ctx: Arc<InputContext>,
     pub mutable_columns: Vec<TypeDeserializerImpl>,
@@ -382,6 +474,28 @@ impl<T: InputFormatTextBase> BlockBuilder<T> {
     fn memory_size(&self) -> usize {
         self.mutable_columns.iter().map(|x| x.memory_size()).sum()
     }
+
+    /// Fail with the error of the row at `row` (0-based, in file), or, if the context
+    /// collects errors, drop the values already pushed for the row and record the error.
+    pub fn on_row_error(&mut self, path: &str, row: usize, e: RowError) -> Result<()> {
+        let collector = match &self.ctx.error_collector {
+            None => return Err(e.error),
+            Some(c) => c.clone(),
+        };
+        for deserializer in self.mutable_columns[..e.num_deserialized].iter_mut() {
+            deserializer.pop_data_value()?;
+        }
+        self.num_rows -= 1;
+        collector.push(InputError {
+            file: path.to_string(),
+            line: row + 1,
+            column: e
+                .column
+                .map(|c| self.ctx.schema.field(c).name().to_string()),
+            message: e.error.message(),
+        });
+        Ok(())
+    }
 }
 
 impl<T: InputFormatTextBase> BlockBuilderTrait for BlockBuilder<T> {
@@ -420,40 +534,6 @@ impl<T: InputFormatTextBase> BlockBuilderTrait for BlockBuilder<T> {
     }
 }
 
-fn decompress(decoder: &mut DecompressDecoder, compressed: &[u8]) -> Result<Vec<u8>> {
-    let mut decompress_bufs = vec![];
-    let mut amt = 0;
-    loop {
-        match decoder.state() {
-            DecompressState::Reading => {
-                if amt == compressed.len() {
-                    break;
-                }
-                let read = decoder.fill(&compressed[amt..]);
-                amt += read;
-            }
-            DecompressState::Decoding => {
-                let mut decompress_buf = vec![0u8; 4096];
-                let written = decoder.decode(&mut decompress_buf[..]).map_err(|e| {
-                    ErrorCode::InvalidCompressionData(format!("compression data invalid: {e}"))
-                })?;
-                decompress_buf.truncate(written);
-                decompress_bufs.push(decompress_buf);
-            }
-            DecompressState::Flushing => {
-                let mut decompress_buf = vec![0u8; 4096];
-                let written = decoder.finish(&mut decompress_buf).map_err(|e| {
-                    ErrorCode::InvalidCompressionData(format!("compression data invalid: {e}"))
-                })?;
-                decompress_buf.truncate(written);
-                decompress_bufs.push(decompress_buf);
-            }
-            DecompressState::Done => break,
-        }
-    }
-    Ok(decompress_bufs.concat())
-}
-
 pub fn get_time_zone(settings: &Settings) -> Result<Tz> {
     let tz = settings.get_timezone()?;
     tz.parse::<Tz>()
@@ -13,9 +13,11 @@
 //  limitations under the License.
 
 mod beyond_end_reader;
+mod compression;
 mod delimiter;
 mod impls;
 mod input_context;
+mod input_error;
 mod input_format;
 mod input_format_text;
 mod input_pipeline;
@@ -24,7 +26,14 @@ mod source_aligner;
 mod source_deserializer;
 mod transform_deserializer;
 
+pub use compression::decompress;
+pub use compression::detect_compression_alg;
+pub use impls::input_format_fixed_width::split_fixed_width;
+pub use impls::input_format_xml::XmlLayout;
+pub use impls::input_format_xml::XmlReaderState;
 pub use input_context::InputContext;
+pub use input_error::InputError;
+pub use input_error::InputErrorCollector;
 pub use input_format::InputFormat;
 pub use input_pipeline::StreamingReadBatch;
 pub use input_split::split_by_size;
@@ -0,0 +1,136 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fs;
+
+use common_exception::Result;
+use common_meta_types::StageFileCompression;
+use common_pipeline_sources::processors::sources::input_formats::decompress;
+use common_pipeline_sources::processors::sources::input_formats::detect_compression_alg;
+use common_pipeline_sources::processors::sources::input_formats::InputContext;
+use opendal::io_util::CompressAlgorithm;
+use opendal::io_util::DecompressDecoder;
+use opendal::io_util::DecompressState;
+
+const DATA_DIR: &str = "../../../../tests/data";
+
+// zlib and raw deflate streams of "a,b\n1,2\n".
+const ZLIB_DATA: &[u8] = &[
+    0x78, 0x9c, 0x4b, 0xd4, 0x49, 0xe2, 0x32, 0xd4, 0x31, 0xe2, 0x02, 0x00, 0x08, 0x78, 0x01, 0x93,
+];
+const DEFLATE_DATA: &[u8] = &[0x4b, 0xd4, 0x49, 0xe2, 0x32, 0xd4, 0x31, 0xe2, 0x02, 0x00];
+
+fn read_data(name: &str) -> Vec<u8> {
+    fs::read(format!("{DATA_DIR}/{name}")).unwrap()
+}
+
+/// Decompress in small chunks, the way read batches arrive in the pipeline.
+fn decompress_chunks(alg: CompressAlgorithm, data: &[u8], chunk_size: usize) -> Result<Vec<u8>> {
+    let mut decoder = DecompressDecoder::new(alg);
+    let mut output = vec![];
+    for chunk in data.chunks(chunk_size) {
+        output.extend(decompress(&mut decoder, chunk)?);
+    }
+    assert_eq!(decoder.state(), DecompressState::Done);
+    Ok(output)
+}
+
+#[test]
+fn test_detect_compression_by_magic() {
+    let cases = [
+        ("ontime_200.csv.gz", Some(CompressAlgorithm::Gzip)),
+        ("ontime_200.csv.bz2", Some(CompressAlgorithm::Bz2)),
+        ("ontime_200.csv.xz", Some(CompressAlgorithm::Xz)),
+        ("ontime_200.csv.zst", Some(CompressAlgorithm::Zstd)),
+        ("ontime_200.csv", None),
+        ("ontime_200.ndjson", None),
+        ("escape.tsv", None),
+    ];
+    for (name, expect) in cases {
+        assert_eq!(detect_compression_alg(&read_data(name)), expect, "{name}");
+    }
+
+    assert_eq!(
+        detect_compression_alg(ZLIB_DATA),
+        Some(CompressAlgorithm::Zlib)
+    );
+    // raw deflate and brotli have no magic number
+    assert_eq!(detect_compression_alg(DEFLATE_DATA), None);
+    // too short or plain text
+    assert_eq!(detect_compression_alg(&[]), None);
+    assert_eq!(detect_compression_alg(&[0x1f]), None);
+    assert_eq!(detect_compression_alg(b"BZh,1\n"), None);
+    assert_eq!(detect_compression_alg(b"x,y\n"), None);
+    // `x^` is a valid zlib header with a valid checksum
+    assert_eq!(detect_compression_alg(b"x^2,y\n"), None);
+    assert_eq!(
+        detect_compression_alg(&[0x78, 0x5e, 0x4b, 0xd4, 0x49, 0xe2]),
+        Some(CompressAlgorithm::Zlib)
+    );
+}
+
+#[test]
+fn test_compression_alg_by_extension() -> Result<()> {
+    let cases = [
+        ("a.csv.gz", Some(CompressAlgorithm::Gzip)),
+        ("a.csv.bz2", Some(CompressAlgorithm::Bz2)),
+        ("a.csv.br", Some(CompressAlgorithm::Brotli)),
+        ("a.csv.zst", Some(CompressAlgorithm::Zstd)),
+        ("a.csv.zz", Some(CompressAlgorithm::Zlib)),
+        ("a.csv.deflate", Some(CompressAlgorithm::Deflate)),
+        ("a.csv.xz", Some(CompressAlgorithm::Xz)),
+        ("a.csv", None),
+        ("", None),
+    ];
+    for (path, expect) in cases {
+        let alg = InputContext::get_compression_alg_copy(StageFileCompression::Auto, path)?;
+        assert_eq!(alg, expect, "{path}");
+    }
+
+    // an explicit option wins over the extension
+    let alg = InputContext::get_compression_alg_copy(StageFileCompression::Zstd, "a.csv.gz")?;
+    assert_eq!(alg, Some(CompressAlgorithm::Zstd));
+    let alg = InputContext::get_compression_alg_copy(StageFileCompression::None, "a.csv.gz")?;
+    assert_eq!(alg, None);
+    Ok(())
+}
+
+#[test]
+fn test_decompress_per_codec() -> Result<()> {
+    let expect = read_data("ontime_200.csv");
+    let cases = [
+        ("ontime_200.csv.gz", CompressAlgorithm::Gzip),
+        ("ontime_200.csv.bz2", CompressAlgorithm::Bz2),
+        ("ontime_200.csv.xz", CompressAlgorithm::Xz),
+        ("ontime_200.csv.zst", CompressAlgorithm::Zstd),
+    ];
+    for (name, alg) in cases {
+        let data = read_data(name);
+        for chunk_size in [1, 1000, data.len()] {
+            let output = decompress_chunks(alg, &data, chunk_size)?;
+            assert_eq!(output, expect, "{name} in chunks of {chunk_size}");
+        }
+    }
+
+    for (data, alg) in [
+        (ZLIB_DATA, CompressAlgorithm::Zlib),
+        (DEFLATE_DATA, CompressAlgorithm::Deflate),
+    ] {
+        for chunk_size in [1, data.len()] {
+            let output = decompress_chunks(alg, data, chunk_size)?;
+            assert_eq!(output, b"a,b\n1,2\n", "{alg:?} in chunks of {chunk_size}");
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,39 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_pipeline_sources::processors::sources::input_formats::split_fixed_width;
+
+#[test]
+fn test_split_fixed_width() {
+    let offsets = [0, 5, 15];
+    assert_eq!(split_fixed_width(b"00042Alice     NY", &offsets), vec![
+        &b"00042"[..],
+        b"Alice",
+        b"NY"
+    ]);
+    // padding on both sides is trimmed, the last column takes the rest
+    assert_eq!(
+        split_fixed_width(b"   42  Bob     New York ", &offsets),
+        vec![&b"42"[..], b"Bob", b"New York"]
+    );
+    // short records leave the trailing columns empty
+    assert_eq!(split_fixed_width(b"00042Al", &offsets), vec![
+        &b"00042"[..],
+        b"Al",
+        b""
+    ]);
+    assert_eq!(split_fixed_width(b"", &offsets), vec![&b""[..], b"", b""]);
+    // bytes before the first offset are skipped
+    assert_eq!(split_fixed_width(b"#1 ab", &[1, 3]), vec![&b"1"[..], b"ab"]);
+}
@@ -0,0 +1,148 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use common_datavalues::prelude::*;
+use common_pipeline_sources::processors::sources::input_formats::XmlLayout;
+use common_pipeline_sources::processors::sources::input_formats::XmlReaderState;
+
+const FEED: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE feed [<!ENTITY e "x">]>
+<!-- a <comment> -->
+<feed>
+  <header><record id="0">not a row</record></header>
+  <records>
+    <record id="1" note="a > b"><name>Alice</name><address><city>Paris</city></address></record>
+    <record id="2"><name><![CDATA[<Bob>]]></name><address city="Berlin"/></record>
+    <record id="3"/>
+  </records>
+</feed>
+"#;
+
+fn test_schema() -> DataSchemaRef {
+    DataSchemaRefExt::create(vec![
+        DataField::new("id", i32::to_data_type()),
+        DataField::new("name", Vu8::to_data_type()),
+        DataField::new("city", Vu8::to_data_type()),
+    ])
+}
+
+fn read_rows(layout: &XmlLayout, data: &[u8], chunk_size: usize) -> Vec<String> {
+    let mut reader = XmlReaderState::create(layout);
+    let mut rows = vec![];
+    for chunk in data.chunks(chunk_size) {
+        let (buf, row_ends) = reader.read(chunk).unwrap();
+        let mut start = 0;
+        for end in row_ends {
+            rows.push(String::from_utf8(buf[start..end].to_vec()).unwrap());
+            start = end;
+        }
+    }
+    reader.finish().unwrap();
+    rows
+}
+
+#[test]
+fn test_xml_reader_rows() {
+    let layout =
+        XmlLayout::try_create("/feed/records/record", &BTreeMap::new(), &test_schema()).unwrap();
+    let expect = vec![
+        r#"<record id="1" note="a > b"><name>Alice</name><address><city>Paris</city></address></record>"#,
+        r#"<record id="2"><name><![CDATA[<Bob>]]></name><address city="Berlin"/></record>"#,
+        r#"<record id="3"/>"#,
+    ];
+    // rows are the same however the input is cut
+    for chunk_size in [1, 2, 3, 7, 64, FEED.len()] {
+        assert_eq!(
+            read_rows(&layout, FEED.as_bytes(), chunk_size),
+            expect,
+            "chunk size {chunk_size}"
+        );
+    }
+
+    // by default, rows are the children of the root element
+    let layout = XmlLayout::try_create("", &BTreeMap::new(), &test_schema()).unwrap();
+    let rows = read_rows(&layout, FEED.as_bytes(), 5);
+    assert_eq!(rows.len(), 2);
+    assert!(rows[0].starts_with("<header>"));
+    assert!(rows[1].starts_with("<records>"));
+
+    // wildcard
+    let layout = XmlLayout::try_create("/feed/*/record", &BTreeMap::new(), &test_schema()).unwrap();
+    assert_eq!(read_rows(&layout, FEED.as_bytes(), 5).len(), 4);
+}
+
+#[test]
+fn test_xml_reader_errors() {
+    let layout = XmlLayout::try_create("/a/b", &BTreeMap::new(), &test_schema()).unwrap();
+
+    let mut reader = XmlReaderState::create(&layout);
+    assert!(reader.read(b"<a><b></c></a>").is_err());
+
+    // end of input in the middle of a row
+    let mut reader = XmlReaderState::create(&layout);
+    let (_, row_ends) = reader.read(b"<a><b>1</b><b>2").unwrap();
+    assert_eq!(row_ends.len(), 1);
+    assert!(reader.finish().is_err());
+}
+
+#[test]
+fn test_xml_layout() {
+    let schema = test_schema();
+    let mapping = BTreeMap::from([
+        ("id".to_string(), "@id".to_string()),
+        ("CITY".to_string(), "address/city".to_string()),
+    ]);
+    let layout = XmlLayout::try_create("/feed/records/record", &mapping, &schema).unwrap();
+
+    let values = layout
+        .read_row_values(br#"<record id="1"><name> Alice &amp; Co </name><address><city>Paris</city></address></record>"#)
+        .unwrap();
+    assert_eq!(values, vec![
+        Some(b"1".to_vec()),
+        Some(b"Alice & Co".to_vec()),
+        Some(b"Paris".to_vec()),
+    ]);
+
+    let values = layout
+        .read_row_values(br#"<record id="2"><name><![CDATA[<Bob>]]></name></record>"#)
+        .unwrap();
+    assert_eq!(values, vec![
+        Some(b"2".to_vec()),
+        Some(b"<Bob>".to_vec()),
+        None
+    ]);
+
+    let values = layout.read_row_values(br#"<record/>"#).unwrap();
+    assert_eq!(values, vec![None, None, None]);
+
+    // attribute of a child element
+    let mapping = BTreeMap::from([("city".to_string(), "address/@city".to_string())]);
+    let layout = XmlLayout::try_create("/feed/records/record", &mapping, &schema).unwrap();
+    let values = layout
+        .read_row_values(br#"<record id="2"><name>Bob</name><address city="Berlin"/></record>"#)
+        .unwrap();
+    assert_eq!(values, vec![
+        Some(b"2".to_vec()),
+        Some(b"Bob".to_vec()),
+        Some(b"Berlin".to_vec()),
+    ]);
+
+    // unknown column or bad path
+    let mapping = BTreeMap::from([("zip".to_string(), "@zip".to_string())]);
+    assert!(XmlLayout::try_create("", &mapping, &schema).is_err());
+    let mapping = BTreeMap::from([("id".to_string(), "@id/name".to_string())]);
+    assert!(XmlLayout::try_create("", &mapping, &schema).is_err());
+}
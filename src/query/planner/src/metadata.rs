@@ -80,11 +80,20 @@ impl Metadata {
     pub fn columns_by_table_index(&self, index: IndexType) -> Vec<ColumnEntry> {
         self.columns
             .iter()
-            .filter(|v| v.table_index == Some(index))
+            .filter(|v| v.table_index == Some(index) && !v.is_virtual_column())
             .cloned()
             .collect()
     }
 
+    /// Get the virtual columns of a table which have been referenced so far.
+    pub fn virtual_columns_by_table_index(&self, index: IndexType) -> Vec<IndexType> {
+        self.columns
+            .iter()
+            .filter(|v| v.table_index == Some(index) && v.is_virtual_column())
+            .map(|v| v.column_index)
+            .collect()
+    }
+
     pub fn add_column(
         &mut self,
         name: String,
@@ -99,6 +108,30 @@ impl Metadata {
         column_index
     }
 
+    /// Add a virtual column which reads `path` of the variant column `source_index`,
+    /// or return the existing one if it has been added with the same name.
+    pub fn add_virtual_column(
+        &mut self,
+        source_index: IndexType,
+        path: Vec<String>,
+        name: String,
+        data_type: DataTypeImpl,
+    ) -> IndexType {
+        let table_index = self.column(source_index).table_index;
+        if let Some(column) = self
+            .columns
+            .iter()
+            .find(|v| v.virtual_source() == Some(source_index) && v.name == name)
+        {
+            return column.column_index;
+        }
+        let column_index = self.columns.len();
+        let mut column_entry = ColumnEntry::new(name, data_type, column_index, table_index, None);
+        column_entry.virtual_path = Some((source_index, path));
+        self.columns.push(column_entry);
+        column_index
+    }
+
     pub fn add_table(
         &mut self,
         catalog: String,
@@ -184,7 +217,7 @@ impl Metadata {
         let indices: Vec<usize> = self
             .columns
             .iter()
-            .filter(|v| v.table_index == Some(table_index))
+            .filter(|v| v.table_index == Some(table_index) && !v.is_virtual_column())
             .map(|v| v.column_index)
             .collect();
 
@@ -266,6 +299,8 @@ pub struct ColumnEntry {
     table_index: Option<IndexType>,
     /// Path indices for inner column of struct data type.
     path_indices: Option<Vec<IndexType>>,
+    /// Source column and object path for virtual column of variant data type.
+    virtual_path: Option<(IndexType, Vec<String>)>,
 }
 
 impl ColumnEntry {
@@ -282,6 +317,7 @@ impl ColumnEntry {
             data_type,
             table_index,
             path_indices,
+            virtual_path: None,
         }
     }
 
@@ -314,4 +350,19 @@ impl ColumnEntry {
     pub fn has_path_indices(&self) -> bool {
         self.path_indices.is_some()
     }
+
+    /// Get the index of the source column if this column entry is a virtual column.
+    pub fn virtual_source(&self) -> Option<IndexType> {
+        self.virtual_path.as_ref().map(|(source, _)| *source)
+    }
+
+    /// Get the object path inside the source column if this column entry is a virtual column.
+    pub fn virtual_path(&self) -> Option<&[String]> {
+        self.virtual_path.as_ref().map(|(_, path)| path.as_slice())
+    }
+
+    /// Check if this column entry is a virtual column
+    pub fn is_virtual_column(&self) -> bool {
+        self.virtual_path.is_some()
+    }
 }
@@ -18,6 +18,7 @@ mod alter_user;
 mod alter_view;
 mod call;
 mod create_database;
+mod create_pipe;
 mod create_role;
 mod create_stage;
 mod create_udf;
@@ -25,6 +26,7 @@ mod create_user;
 mod create_view;
 mod describe_table;
 mod drop_database;
+mod drop_pipe;
 mod drop_role;
 mod drop_stage;
 mod drop_table;
@@ -57,6 +59,7 @@ pub use alter_user::AlterUserPlan;
 pub use alter_view::AlterViewPlan;
 pub use call::CallPlan;
 pub use create_database::CreateDatabasePlan;
+pub use create_pipe::CreatePipePlan;
 pub use create_role::CreateRolePlan;
 pub use create_stage::CreateStagePlan;
 pub use create_udf::CreateUDFPlan;
@@ -64,6 +67,7 @@ pub use create_user::CreateUserPlan;
 pub use create_view::CreateViewPlan;
 pub use describe_table::DescribeTablePlan;
 pub use drop_database::DropDatabasePlan;
+pub use drop_pipe::DropPipePlan;
 pub use drop_role::DropRolePlan;
 pub use drop_stage::DropStagePlan;
 pub use drop_table::DropTablePlan;
@@ -15,24 +15,31 @@
 use std::any::Any;
 use std::sync::Arc;
 
+use common_base::base::tokio::sync::mpsc;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_app::schema::CountTablesReply;
 use common_meta_app::schema::CountTablesReq;
 use common_meta_app::schema::CreateDatabaseReply;
 use common_meta_app::schema::CreateDatabaseReq;
+use common_meta_app::schema::CreateTableLockRevReply;
+use common_meta_app::schema::CreateTableLockRevReq;
 use common_meta_app::schema::CreateTableReq;
+use common_meta_app::schema::DeleteTableLockRevReq;
 use common_meta_app::schema::DropDatabaseReq;
 use common_meta_app::schema::DropTableReply;
 use common_meta_app::schema::DropTableReq;
+use common_meta_app::schema::ExtendTableLockRevReq;
 use common_meta_app::schema::GetTableCopiedFileReply;
 use common_meta_app::schema::GetTableCopiedFileReq;
+use common_meta_app::schema::ListTableLocksReq;
 use common_meta_app::schema::RenameDatabaseReply;
 use common_meta_app::schema::RenameDatabaseReq;
 use common_meta_app::schema::RenameTableReply;
 use common_meta_app::schema::RenameTableReq;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableLockInfo;
 use common_meta_app::schema::TableMeta;
 use common_meta_app::schema::TruncateTableReply;
 use common_meta_app::schema::TruncateTableReq;
@@ -476,6 +483,29 @@ impl Catalog for DatabaseCatalog {
             .await
     }
 
+    async fn create_table_lock_rev(
+        &self,
+        req: CreateTableLockRevReq,
+    ) -> Result<CreateTableLockRevReply> {
+        self.mutable_catalog.create_table_lock_rev(req).await
+    }
+
+    async fn extend_table_lock_rev(&self, req: ExtendTableLockRevReq) -> Result<()> {
+        self.mutable_catalog.extend_table_lock_rev(req).await
+    }
+
+    async fn delete_table_lock_rev(&self, req: DeleteTableLockRevReq) -> Result<()> {
+        self.mutable_catalog.delete_table_lock_rev(req).await
+    }
+
+    async fn list_table_locks(&self, req: ListTableLocksReq) -> Result<Vec<TableLockInfo>> {
+        self.mutable_catalog.list_table_locks(req).await
+    }
+
+    async fn watch_table_locks(&self, table_id: u64) -> Result<Option<mpsc::Receiver<()>>> {
+        self.mutable_catalog.watch_table_locks(table_id).await
+    }
+
     async fn upsert_table_option(
         &self,
         tenant: &str,
@@ -15,30 +15,41 @@
 use std::any::Any;
 use std::sync::Arc;
 
+use common_base::base::tokio;
+use common_base::base::tokio::sync::mpsc;
 use common_exception::Result;
+use common_meta_api::get_start_and_end_of_prefix;
+use common_meta_api::KVApiKey;
 use common_meta_api::SchemaApi;
 use common_meta_app::schema::CountTablesReply;
 use common_meta_app::schema::CountTablesReq;
 use common_meta_app::schema::CreateDatabaseReply;
 use common_meta_app::schema::CreateDatabaseReq;
+use common_meta_app::schema::CreateTableLockRevReply;
+use common_meta_app::schema::CreateTableLockRevReq;
 use common_meta_app::schema::CreateTableReq;
 use common_meta_app::schema::DatabaseIdent;
 use common_meta_app::schema::DatabaseInfo;
 use common_meta_app::schema::DatabaseMeta;
 use common_meta_app::schema::DatabaseNameIdent;
+use common_meta_app::schema::DeleteTableLockRevReq;
 use common_meta_app::schema::DropDatabaseReq;
 use common_meta_app::schema::DropTableReply;
 use common_meta_app::schema::DropTableReq;
+use common_meta_app::schema::ExtendTableLockRevReq;
 use common_meta_app::schema::GetDatabaseReq;
 use common_meta_app::schema::GetTableCopiedFileReply;
 use common_meta_app::schema::GetTableCopiedFileReq;
 use common_meta_app::schema::ListDatabaseReq;
+use common_meta_app::schema::ListTableLocksReq;
 use common_meta_app::schema::RenameDatabaseReply;
 use common_meta_app::schema::RenameDatabaseReq;
 use common_meta_app::schema::RenameTableReply;
 use common_meta_app::schema::RenameTableReq;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableLockInfo;
+use common_meta_app::schema::TableLockKey;
 use common_meta_app::schema::TableMeta;
 use common_meta_app::schema::TruncateTableReply;
 use common_meta_app::schema::TruncateTableReq;
@@ -52,7 +63,11 @@ use common_meta_app::schema::UpsertTableCopiedFileReply;
 use common_meta_app::schema::UpsertTableCopiedFileReq;
 use common_meta_app::schema::UpsertTableOptionReply;
 use common_meta_app::schema::UpsertTableOptionReq;
+use common_meta_store::MetaStore;
 use common_meta_store::MetaStoreProvider;
+use common_meta_types::protobuf::watch_request::FilterType;
+use common_meta_types::protobuf::WatchRequest;
+use common_meta_types::MetaError;
 use common_meta_types::MetaId;
 use tracing::info;
 
@@ -93,7 +108,7 @@ impl MutableCatalog {
     /// ```
     pub async fn try_create_with_config(conf: Config) -> Result<Self> {
         let meta = {
-            let provider = Arc::new(MetaStoreProvider::new(conf.meta.to_meta_grpc_client_conf()));
+            let provider = Arc::new(MetaStoreProvider::new(conf.to_meta_grpc_client_conf()));
 
             provider.create_meta_store().await?
         };
@@ -335,6 +350,67 @@ impl Catalog for MutableCatalog {
         Ok(res)
     }
 
+    async fn create_table_lock_rev(
+        &self,
+        req: CreateTableLockRevReq,
+    ) -> Result<CreateTableLockRevReply> {
+        let res = self.ctx.meta.create_table_lock_rev(req).await?;
+        Ok(res)
+    }
+
+    async fn extend_table_lock_rev(&self, req: ExtendTableLockRevReq) -> Result<()> {
+        self.ctx.meta.extend_table_lock_rev(req).await?;
+        Ok(())
+    }
+
+    async fn delete_table_lock_rev(&self, req: DeleteTableLockRevReq) -> Result<()> {
+        self.ctx.meta.delete_table_lock_rev(req).await?;
+        Ok(())
+    }
+
+    async fn list_table_locks(&self, req: ListTableLocksReq) -> Result<Vec<TableLockInfo>> {
+        let res = self.ctx.meta.list_table_locks(req).await?;
+        Ok(res)
+    }
+
+    async fn watch_table_locks(&self, table_id: u64) -> Result<Option<mpsc::Receiver<()>>> {
+        // An embedded meta store is not shared with other nodes, there is nothing to watch.
+        let client = match &self.ctx.meta {
+            MetaStore::L(_) => return Ok(None),
+            MetaStore::R(client) => client,
+        };
+
+        let prefix = format!("{}/{}/", TableLockKey::PREFIX, table_id);
+        let (key, key_end) = get_start_and_end_of_prefix(&prefix)?;
+        let watch = WatchRequest {
+            key,
+            key_end: Some(key_end),
+            // A waiter can only proceed after a smaller revision is released.
+            filter_type: FilterType::Delete.into(),
+            start_revision: None,
+        };
+        let mut stream = client.request::<_, _, MetaError>(watch).await?;
+
+        // Notifications are merged, the receiver lists the locks after each of them anyway.
+        let (tx, rx) = mpsc::channel(1);
+        tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    _ = tx.closed() => return,
+                    msg = stream.message() => match msg {
+                        Ok(Some(_)) => {
+                            let _ = tx.try_send(());
+                        }
+                        // Dropping the sender tells the receiver to poll instead.
+                        Ok(None) | Err(_) => return,
+                    },
+                }
+            }
+        });
+
+        Ok(Some(rx))
+    }
+
     fn get_table_engines(&self) -> Vec<StorageDescription> {
         self.ctx.storage_factory.get_storage_descriptors()
     }
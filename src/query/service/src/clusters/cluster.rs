@@ -142,7 +142,7 @@ impl ClusterDiscovery {
     const METRIC_LABEL_FUNCTION: &'static str = "function";
 
     pub async fn create_meta_client(cfg: &Config) -> Result<MetaStore> {
-        let meta_api_provider = MetaStoreProvider::new(cfg.meta.to_meta_grpc_client_conf());
+        let meta_api_provider = MetaStoreProvider::new(cfg.to_meta_grpc_client_conf());
         match meta_api_provider.create_meta_store().await {
             Ok(meta_store) => Ok(meta_store),
             Err(cause) => {
@@ -0,0 +1,295 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_functions::scalars::CastFunction;
+use common_functions::scalars::Function;
+use common_functions::scalars::FunctionAdapter;
+use common_functions::scalars::FunctionContext;
+
+use crate::evaluator::EvalNode;
+
+/// Functions taking a lambda, which are lowered as `func(array, body)`.
+pub const LAMBDA_FUNCTIONS: [&str; 3] = ["array_transform", "array_filter", "array_reduce"];
+
+/// Marker function of a lambda parameter in a lowered lambda body, `lambda_param(index)`.
+pub const LAMBDA_PARAM_FUNCTION: &str = "lambda_param";
+
+pub fn is_lambda_function(name: &str) -> bool {
+    LAMBDA_FUNCTIONS.contains(&name)
+}
+
+/// Types of the lambda parameters of `name` applied on `array_type`, the accumulator of
+/// `array_reduce` comes first.
+pub fn lambda_param_types(
+    name: &str,
+    array_type: &DataTypeImpl,
+    acc_type: Option<&DataTypeImpl>,
+) -> Result<Vec<DataTypeImpl>> {
+    let element_type = match remove_nullable(array_type) {
+        DataTypeImpl::Array(array_type) => array_type.inner_type().clone(),
+        other => {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Function '{}' expects an array argument, but got {:?}",
+                name.to_uppercase(),
+                other.data_type_id()
+            )));
+        }
+    };
+
+    match name {
+        "array_reduce" => Ok(vec![
+            acc_type.cloned().unwrap_or_else(|| element_type.clone()),
+            element_type,
+        ]),
+        _ => Ok(vec![element_type]),
+    }
+}
+
+/// Return type of `name` applied on a non-null array of `array_type`, `body_type` is the
+/// type of the lambda body.
+pub fn lambda_return_type(
+    name: &str,
+    array_type: &DataTypeImpl,
+    body_type: &DataTypeImpl,
+) -> Result<DataTypeImpl> {
+    match name {
+        "array_transform" => Ok(ArrayType::new_impl(body_type.clone())),
+        "array_filter" => Ok(remove_nullable(array_type)),
+        "array_reduce" => Ok(wrap_nullable(body_type)),
+        _ => Err(ErrorCode::UnknownFunction(format!(
+            "Unsupported lambda function: {}",
+            name
+        ))),
+    }
+}
+
+/// Builds the evaluation of `lambda_param(index)`, a column of the block a lambda body runs on.
+pub fn create_lambda_param_node(args: &[EvalNode]) -> Result<EvalNode> {
+    match args {
+        [EvalNode::Constant { value, .. }] => Ok(EvalNode::IndexedVariable {
+            index: value.as_u64()? as usize,
+        }),
+        _ => Err(ErrorCode::LogicalError(
+            "Lambda parameter must be referred by a constant index",
+        )),
+    }
+}
+
+/// Builds the evaluation of a lowered lambda function, `args` are the array and the body.
+pub fn create_lambda_node(
+    name: &str,
+    mut args: Vec<EvalNode>,
+    arg_types: &[DataTypeImpl],
+) -> Result<EvalNode> {
+    if args.len() != 2 || arg_types.len() != 2 {
+        return Err(ErrorCode::LogicalError(format!(
+            "Lowered lambda function '{}' must have an array and a body",
+            name
+        )));
+    }
+
+    let body_type = arg_types[1].clone();
+    let param_types = lambda_param_types(name, &arg_types[0], Some(&body_type))?;
+    let element_type = param_types.last().unwrap().clone();
+
+    let return_type = lambda_return_type(name, &arg_types[0], &body_type)?;
+    let acc_cast = if name == "array_reduce" && element_type != body_type {
+        Some(if body_type.is_nullable() {
+            CastFunction::create_try("", body_type.name().as_str(), element_type)?
+        } else {
+            CastFunction::create("", body_type.name().as_str(), element_type)?
+        })
+    } else {
+        None
+    };
+
+    let params = param_types
+        .into_iter()
+        .enumerate()
+        .map(|(index, data_type)| DataField::new(&index.to_string(), data_type))
+        .collect::<Vec<_>>();
+
+    let body = args.pop().unwrap();
+    let func = LambdaFunction {
+        display_name: name.to_string(),
+        body,
+        params: DataSchemaRefExt::create(params),
+        acc_cast,
+        return_type,
+    };
+
+    Ok(EvalNode::Function {
+        func: FunctionAdapter::create(Box::new(func), arg_types[0].is_nullable()),
+        args,
+    })
+}
+
+/// Applies a lambda body on the elements of an array column, evaluating the body once
+/// on all elements of the block (once per element position for `array_reduce`).
+#[derive(Clone)]
+pub struct LambdaFunction {
+    display_name: String,
+    body: EvalNode,
+    params: DataSchemaRef,
+    // Casts the first element into the accumulator type of `array_reduce`.
+    acc_cast: Option<Box<dyn Function>>,
+    return_type: DataTypeImpl,
+}
+
+impl LambdaFunction {
+    fn eval_body(&self, func_ctx: &FunctionContext, columns: Vec<ColumnRef>) -> Result<ColumnRef> {
+        let block = DataBlock::create(self.params.clone(), columns);
+        let result = self.body.eval(func_ctx, &block)?;
+        Ok(result.vector().convert_full_column())
+    }
+
+    fn transform(
+        &self,
+        func_ctx: &FunctionContext,
+        offsets: Vec<i64>,
+        values: ColumnRef,
+    ) -> Result<ColumnRef> {
+        let values = self.eval_body(func_ctx, vec![values])?;
+        Ok(ArrayColumn::from_data(self.return_type.clone(), offsets.into(), values).arc())
+    }
+
+    fn filter(
+        &self,
+        func_ctx: &FunctionContext,
+        offsets: Vec<i64>,
+        values: ColumnRef,
+    ) -> Result<ColumnRef> {
+        let predicate = self.eval_body(func_ctx, vec![values.clone()])?;
+        let predicate = DataBlock::cast_to_nonull_boolean(&predicate)?.convert_full_column();
+        let predicate: &BooleanColumn = Series::check_get(&predicate)?;
+
+        let mut new_offsets = Vec::with_capacity(offsets.len());
+        new_offsets.push(0i64);
+        for window in offsets.windows(2) {
+            let kept = (window[0]..window[1])
+                .filter(|i| predicate.get_data(*i as usize))
+                .count() as i64;
+            new_offsets.push(new_offsets.last().unwrap() + kept);
+        }
+
+        Ok(ArrayColumn::from_data(
+            self.return_type.clone(),
+            new_offsets.into(),
+            values.filter(predicate),
+        )
+        .arc())
+    }
+
+    fn reduce(
+        &self,
+        func_ctx: &FunctionContext,
+        offsets: Vec<i64>,
+        values: ColumnRef,
+    ) -> Result<ColumnRef> {
+        let rows = offsets.len() - 1;
+        let mut results = vec![DataValue::Null; rows];
+
+        // Rows still being reduced, the accumulator holds one value per row.
+        let mut active = (0..rows)
+            .filter(|row| offsets[*row + 1] > offsets[*row])
+            .collect::<Vec<_>>();
+        let first = active
+            .iter()
+            .map(|row| offsets[*row] as usize)
+            .collect::<Vec<_>>();
+        let mut acc = Series::take(&values, &first)?;
+        if let Some(acc_cast) = &self.acc_cast {
+            let field = DataField::new("", self.params.field(1).data_type().clone());
+            let column = ColumnWithField::new(acc, field);
+            acc = acc_cast.eval(func_ctx.clone(), &[column], active.len())?;
+        }
+
+        let mut step = 1;
+        while !active.is_empty() {
+            let mut next = Vec::with_capacity(active.len());
+            let mut acc_indices = Vec::with_capacity(active.len());
+            let mut element_indices = Vec::with_capacity(active.len());
+            for (index, row) in active.iter().enumerate() {
+                let element = offsets[*row] + step;
+                if element < offsets[*row + 1] {
+                    next.push(*row);
+                    acc_indices.push(index);
+                    element_indices.push(element as usize);
+                } else {
+                    results[*row] = acc.get(index);
+                }
+            }
+
+            if !next.is_empty() {
+                let acc_column = Series::take(&acc, &acc_indices)?;
+                let elements = Series::take(&values, &element_indices)?;
+                acc = self.eval_body(func_ctx, vec![acc_column, elements])?;
+            }
+            active = next;
+            step += 1;
+        }
+
+        self.return_type.create_column(&results)
+    }
+}
+
+impl Function for LambdaFunction {
+    fn name(&self) -> &str {
+        &self.display_name
+    }
+
+    fn return_type(&self) -> DataTypeImpl {
+        self.return_type.clone()
+    }
+
+    fn eval(
+        &self,
+        func_ctx: FunctionContext,
+        columns: &ColumnsWithField,
+        _input_rows: usize,
+    ) -> Result<ColumnRef> {
+        let column = columns[0].column().convert_full_column();
+        let array_column: &ArrayColumn = Series::check_get(&column)?;
+
+        // The array column may be a slice, rebase its offsets onto the referenced values.
+        let start = array_column.offsets()[0];
+        let end = array_column.offsets()[array_column.len()];
+        let offsets = array_column
+            .offsets()
+            .iter()
+            .map(|offset| offset - start)
+            .collect::<Vec<_>>();
+        let values = array_column
+            .values()
+            .slice(start as usize, (end - start) as usize);
+
+        match self.display_name.as_str() {
+            "array_transform" => self.transform(&func_ctx, offsets, values),
+            "array_filter" => self.filter(&func_ctx, offsets, values),
+            _ => self.reduce(&func_ctx, offsets, values),
+        }
+    }
+}
+
+impl fmt::Display for LambdaFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.display_name.to_uppercase())
+    }
+}
@@ -13,12 +13,17 @@
 // limitations under the License.
 
 mod eval_node;
+mod lambda;
 mod physical_scalar;
 mod scalar;
 
 use common_datavalues::ColumnRef;
 use common_datavalues::DataTypeImpl;
 pub use eval_node::EvalNode;
+pub use lambda::is_lambda_function;
+pub use lambda::lambda_param_types;
+pub use lambda::lambda_return_type;
+pub use lambda::LAMBDA_PARAM_FUNCTION;
 
 pub struct Evaluator;
 
@@ -22,6 +22,10 @@ use common_functions::scalars::CastFunction;
 use common_functions::scalars::FunctionFactory;
 
 use crate::evaluator::eval_node::EvalNode;
+use crate::evaluator::lambda::create_lambda_node;
+use crate::evaluator::lambda::create_lambda_param_node;
+use crate::evaluator::lambda::is_lambda_function;
+use crate::evaluator::lambda::LAMBDA_PARAM_FUNCTION;
 use crate::evaluator::Evaluator;
 use crate::sql::executor::PhysicalScalar;
 
@@ -45,6 +49,15 @@ impl Evaluator {
                     .map(|(v, _)| Self::eval_physical_scalar(v))
                     .collect::<Result<_>>()?;
 
+                // special case for lowered lambda functions
+                if name == LAMBDA_PARAM_FUNCTION {
+                    return create_lambda_param_node(&eval_args);
+                }
+                if is_lambda_function(name) {
+                    let arg_types = args.iter().map(|(_, v)| v.clone()).collect::<Vec<_>>();
+                    return create_lambda_node(name, eval_args, &arg_types);
+                }
+
                 // special case for in function
                 let name_lower = name.to_lowercase();
                 if name_lower.as_str() == "in" || name_lower.as_str() == "not_in" {
@@ -22,6 +22,10 @@ use common_functions::scalars::CastFunction;
 use common_functions::scalars::FunctionFactory;
 
 use crate::evaluator::eval_node::EvalNode;
+use crate::evaluator::lambda::create_lambda_node;
+use crate::evaluator::lambda::create_lambda_param_node;
+use crate::evaluator::lambda::is_lambda_function;
+use crate::evaluator::lambda::LAMBDA_PARAM_FUNCTION;
 use crate::evaluator::Evaluator;
 use crate::sql::plans::Scalar;
 use crate::sql::plans::ScalarExpr;
@@ -67,6 +71,14 @@ impl Evaluator {
                     .map(Self::eval_scalar)
                     .collect::<Result<_>>()?;
 
+                // special case for lowered lambda functions
+                if func.func_name == LAMBDA_PARAM_FUNCTION {
+                    return create_lambda_param_node(&eval_args);
+                }
+                if is_lambda_function(&func.func_name) {
+                    return create_lambda_node(&func.func_name, eval_args, &func.arg_types);
+                }
+
                 // special case for in function
                 let name_lower = func.func_name.to_lowercase();
                 if name_lower.as_str() == "in" || name_lower.as_str() == "not_in" {
@@ -92,7 +92,7 @@ impl GlobalServices {
         DataExchangeManager::init(config.clone(), global_services.clone())?;
         SessionManager::init(config.clone(), global_services.clone())?;
         UserApiProvider::init(
-            config.meta.to_meta_grpc_client_conf(),
+            config.to_meta_grpc_client_conf(),
             config.query.idm,
             global_services.clone(),
         )
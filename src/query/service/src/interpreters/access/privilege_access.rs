@@ -202,6 +202,8 @@ impl AccessChecker for PrivilegeAccess {
             Plan::CreateStage(_) => {}
             Plan::DropStage(_) => {}
             Plan::RemoveStage(_) => {}
+            Plan::CreatePipe(_) => {}
+            Plan::DropPipe(_) => {}
             Plan::Presign(_) => {}
             Plan::SetVariable(_) => {}
             Plan::Kill(_) => {
@@ -17,8 +17,10 @@ use std::sync::Arc;
 
 use chrono::TimeZone;
 use chrono::Utc;
+use common_base::base::tokio;
 use common_base::base::GlobalIORuntime;
 use common_datavalues::DataSchemaRef;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_types::GrantObject;
 use common_meta_types::StageFile;
@@ -107,6 +109,16 @@ pub fn execute_pipeline(ctx: Arc<QueryContext>, mut res: PipelineBuildResult) ->
     executor.execute()
 }
 
+/// Executes the pipeline on a blocking thread, since the executor blocks until the pipeline finishes.
+pub async fn execute_pipeline_blocking(
+    ctx: Arc<QueryContext>,
+    res: PipelineBuildResult,
+) -> Result<()> {
+    tokio::task::spawn_blocking(move || execute_pipeline(ctx, res))
+        .await
+        .map_err(|e| ErrorCode::TokioError(format!("Pipeline execution task failed: {}", e)))?
+}
+
 pub async fn validate_grant_object_exists(
     ctx: &Arc<QueryContext>,
     object: &GrantObject,
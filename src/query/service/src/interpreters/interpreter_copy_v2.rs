@@ -12,10 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 use std::collections::BTreeMap;
+use std::collections::HashSet;
 use std::path::Path;
 use std::sync::Arc;
 
 use common_base::base::GlobalIORuntime;
+use common_datablocks::DataBlock;
 use common_datavalues::prelude::*;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -26,19 +28,30 @@ use common_meta_app::schema::GetTableCopiedFileReq;
 use common_meta_app::schema::TableCopiedFileInfo;
 use common_meta_app::schema::UpsertTableCopiedFileReq;
 use common_meta_types::UserStageInfo;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::SinkPipeBuilder;
+use common_pipeline_sources::processors::sources::input_formats::InputContext;
+use common_pipeline_sources::processors::sources::input_formats::InputErrorCollector;
 use regex::Regex;
 
 use super::append2table;
+use crate::interpreters::interpreter_common::execute_pipeline_blocking;
 use crate::interpreters::interpreter_common::list_files;
 use crate::interpreters::interpreter_common::stat_file;
 use crate::interpreters::Interpreter;
 use crate::interpreters::SelectInterpreterV2;
+use crate::pipelines::processors::EmptySink;
+use crate::pipelines::processors::TransformLimit;
 use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryAffect;
 use crate::sessions::QueryContext;
 use crate::sessions::TableContext;
 use crate::sql::plans::CopyPlanV2;
 use crate::sql::plans::Plan;
+use crate::sql::plans::ValidationMode;
 use crate::storages::stage::StageTable;
+use crate::storages::stage::StageUnloadOptions;
+use crate::storages::stage::UnloadManifest;
 
 pub struct CopyInterpreterV2 {
     ctx: Arc<QueryContext>,
@@ -249,11 +262,106 @@ impl CopyInterpreterV2 {
         Ok(build_res)
     }
 
+    // Dry run the input format pipeline on the files against the schema of the table.
+    // Nothing is appended to the table, and the files are not recorded as copied.
+    // - RETURN_<n>_ROWS: return the first n rows, fail at the first error.
+    // - RETURN_ERRORS/RETURN_ALL_ERRORS: skip the bad rows and return every error found.
+    #[tracing::instrument(level = "debug", name = "validate_copy_files", skip(self, table_info), fields(ctx.id = self.ctx.get_id().as_str()))]
+    async fn validate_files(
+        &self,
+        table_info: &StageTableInfo,
+        files: Vec<String>,
+        validation_mode: &ValidationMode,
+    ) -> Result<PipelineBuildResult> {
+        if files.is_empty() {
+            return Ok(PipelineBuildResult::create());
+        }
+
+        let mut stage_info = table_info.stage_info.clone();
+        // Read every file as a single split, so that an error can be located by its line.
+        stage_info.copy_options.split_size = 0;
+
+        let table_ctx: Arc<dyn TableContext> = self.ctx.clone();
+        let operator = StageTable::get_op(&table_ctx, &stage_info)?;
+        let mut input_ctx = InputContext::try_create_from_copy(
+            operator,
+            self.ctx.get_settings().clone(),
+            self.ctx.get_format_settings()?,
+            table_info.schema.clone(),
+            stage_info,
+            files,
+            self.ctx.get_scan_progress(),
+        )
+        .await?;
+
+        let mut build_res = PipelineBuildResult::create();
+        match validation_mode {
+            ValidationMode::ReturnNRows(n) => {
+                let input_ctx = Arc::new(input_ctx);
+                input_ctx
+                    .format
+                    .exec_copy(input_ctx.clone(), &mut build_res.main_pipeline)?;
+
+                build_res.main_pipeline.resize(1)?;
+                build_res.main_pipeline.add_transform(
+                    |transform_input_port, transform_output_port| {
+                        TransformLimit::try_create(
+                            Some(*n as usize),
+                            0,
+                            transform_input_port,
+                            transform_output_port,
+                        )
+                    },
+                )?;
+                Ok(build_res)
+            }
+            _ => {
+                let collector = Arc::new(InputErrorCollector::default());
+                input_ctx.error_collector = Some(collector.clone());
+                let input_ctx = Arc::new(input_ctx);
+                input_ctx
+                    .format
+                    .exec_copy(input_ctx.clone(), &mut build_res.main_pipeline)?;
+
+                let mut sink_pipeline_builder = SinkPipeBuilder::create();
+                for _ in 0..build_res.main_pipeline.output_len() {
+                    let input_port = InputPort::create();
+                    sink_pipeline_builder
+                        .add_sink(input_port.clone(), EmptySink::create(input_port));
+                }
+                build_res
+                    .main_pipeline
+                    .add_pipe(sink_pipeline_builder.finalize());
+                execute_pipeline_blocking(self.ctx.clone(), build_res).await?;
+
+                let errors = collector.take();
+                tracing::info!("validate copy files found {} errors", errors.len());
+
+                let files: Vec<String> = errors.iter().map(|e| e.file.clone()).collect();
+                let lines: Vec<u64> = errors.iter().map(|e| e.line as u64).collect();
+                let column_names: Vec<Option<Vec<u8>>> = errors
+                    .iter()
+                    .map(|e| e.column.as_ref().map(|c| c.clone().into_bytes()))
+                    .collect();
+                let messages: Vec<String> = errors.iter().map(|e| e.message.clone()).collect();
+
+                PipelineBuildResult::from_blocks(vec![DataBlock::create(self.plan.schema(), vec![
+                    Series::from_data(files),
+                    Series::from_data(lines),
+                    Series::from_data(column_names),
+                    Series::from_data(messages),
+                ])])
+            }
+        }
+    }
+
     async fn execute_copy_into_stage(
         &self,
         stage: &UserStageInfo,
         path: &str,
         query: &Plan,
+        unload_options: StageUnloadOptions,
+        overwrite: bool,
     ) -> Result<PipelineBuildResult> {
         let (s_expr, metadata, bind_context) = match query {
             Plan::Query {
@@ -292,8 +400,18 @@ impl CopyInterpreterV2 {
             files: vec![],
         };
 
+        // The previous files are removed only after the new ones are written,
+        // so that a failed unload does not lose them.
+        let previous_files = if overwrite {
+            list_files(&self.ctx, stage, path).await?
+        } else {
+            vec![]
+        };
+
         let mut build_res = select_interpreter.execute2().await?;
-        let table = StageTable::try_create(stage_table_info)?;
+        let manifest = Arc::new(UnloadManifest::default());
+        let table =
+            StageTable::try_create_for_unload(stage_table_info, unload_options, manifest.clone())?;
 
         append2table(
             self.ctx.clone(),
@@ -303,7 +421,38 @@ impl CopyInterpreterV2 {
             false,
             true,
         )?;
-        Ok(build_res)
+        execute_pipeline_blocking(self.ctx.clone(), build_res).await?;
+
+        let files = manifest.take();
+
+        if overwrite {
+            let written: HashSet<&str> = files.iter().map(|f| f.path.as_str()).collect();
+            let stale_files: Vec<_> = previous_files
+                .iter()
+                .filter(|f| !written.contains(f.path.as_str()))
+                .collect();
+            tracing::info!(
+                "overwrite unload path {}, remove {} files",
+                path,
+                stale_files.len()
+            );
+
+            let table_ctx: Arc<dyn TableContext> = self.ctx.clone();
+            let op = StageTable::get_op(&table_ctx, stage)?;
+            for file in stale_files {
+                op.object(&file.path).delete().await?;
+            }
+        }
+
+        let file_names: Vec<String> = files.iter().map(|f| f.path.clone()).collect();
+        let file_sizes: Vec<u64> = files.iter().map(|f| f.bytes as u64).collect();
+        let row_counts: Vec<u64> = files.iter().map(|f| f.rows as u64).collect();
+
+        PipelineBuildResult::from_blocks(vec![DataBlock::create(self.plan.schema(), vec![
+            Series::from_data(file_names),
+            Series::from_data(file_sizes),
+            Series::from_data(row_counts),
+        ])])
     }
 }
 
@@ -313,6 +462,10 @@ impl Interpreter for CopyInterpreterV2 {
         "CopyInterpreterV2"
     }
 
+    fn schema(&self) -> DataSchemaRef {
+        self.plan.schema()
+    }
+
     #[tracing::instrument(level = "debug", name = "copy_interpreter_execute_v2", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         match &self.plan {
@@ -325,6 +478,7 @@ impl Interpreter for CopyInterpreterV2 {
                 pattern,
                 from,
                 force,
+                validation_mode,
                 ..
             } => match &from.source_info {
                 SourceInfo::StageSource(table_info) => {
@@ -363,6 +517,13 @@ impl Interpreter for CopyInterpreterV2 {
 
                     tracing::info!("matched files: {:?}, pattern: {}", &files, pattern);
 
+                    if *validation_mode == ValidationMode::ReturnAllErrors {
+                        // Validate the files copied before as well.
+                        return self
+                            .validate_files(table_info, files, validation_mode)
+                            .await;
+                    }
+
                     let (table_id, copy_stage_files) = self
                         .filter_duplicate_files(
                             *force,
@@ -383,6 +544,19 @@ impl Interpreter for CopyInterpreterV2 {
                         return Ok(PipelineBuildResult::create());
                     }
 
+                    if *validation_mode != ValidationMode::None {
+                        return self
+                            .validate_files(
+                                table_info,
+                                copy_stage_files.keys().cloned().collect(),
+                                validation_mode,
+                            )
+                            .await;
+                    }
+
+                    self.ctx.set_affect(QueryAffect::Copy {
+                        loaded_files: copy_stage_files.len() as u64,
+                    });
                     let result = self
                         .copy_files_to_table(
                             catalog_name,
@@ -412,8 +586,23 @@ impl Interpreter for CopyInterpreterV2 {
                 ))),
             },
             CopyPlanV2::IntoStage {
-                stage, from, path, ..
-            } => self.execute_copy_into_stage(stage, path, from).await,
+                stage,
+                from,
+                path,
+                partition_by,
+                include_query_id,
+                overwrite,
+                header,
+                ..
+            } => {
+                let unload_options = StageUnloadOptions {
+                    partition_by: partition_by.clone(),
+                    include_query_id: *include_query_id,
+                    header: *header,
+                };
+                self.execute_copy_into_stage(stage, path, from, unload_options, *overwrite)
+                    .await
+            }
         }
     }
 }
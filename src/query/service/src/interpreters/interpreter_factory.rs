@@ -212,6 +212,13 @@ impl InterpreterFactory {
                 *s.clone(),
             )?)),
 
+            // Pipes
+            Plan::CreatePipe(create_pipe) => Ok(Arc::new(CreatePipeInterpreter::try_create(
+                ctx,
+                *create_pipe.clone(),
+            )?)),
+            Plan::DropPipe(s) => Ok(Arc::new(DropPipeInterpreter::try_create(ctx, *s.clone())?)),
+
             // Grant
             Plan::GrantPriv(grant_priv) => Ok(Arc::new(GrantPrivilegeInterpreter::try_create(
                 ctx,
@@ -0,0 +1,58 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_planner::plans::CreatePipePlan;
+use common_users::UserApiProvider;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct CreatePipeInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreatePipePlan,
+}
+
+impl CreatePipeInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreatePipePlan) -> Result<Self> {
+        Ok(CreatePipeInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreatePipeInterpreter {
+    fn name(&self) -> &str {
+        "CreatePipeInterpreter"
+    }
+
+    #[tracing::instrument(level = "info", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = self.plan.clone();
+        let user_mgr = UserApiProvider::instance();
+
+        // The pipe runs the copy as its creator.
+        let mut pipe_info = plan.pipe_info;
+        pipe_info.creator = Some(self.ctx.get_current_user()?.identity());
+        let _create_pipe = user_mgr
+            .add_pipe(&plan.tenant, pipe_info, plan.if_not_exists)
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
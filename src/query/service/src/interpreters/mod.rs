@@ -33,6 +33,8 @@ mod interpreter_factory;
 mod interpreter_insert_v2;
 mod interpreter_kill;
 mod interpreter_list;
+mod interpreter_pipe_create;
+mod interpreter_pipe_drop;
 mod interpreter_presign;
 mod interpreter_privilege_grant;
 mod interpreter_privilege_revoke;
@@ -103,6 +105,8 @@ pub use interpreter_factory::InterpreterFactory;
 pub use interpreter_insert_v2::InsertInterpreterV2;
 pub use interpreter_kill::KillInterpreter;
 pub use interpreter_list::ListInterpreter;
+pub use interpreter_pipe_create::CreatePipeInterpreter;
+pub use interpreter_pipe_drop::DropPipeInterpreter;
 pub use interpreter_privilege_grant::GrantPrivilegeInterpreter;
 pub use interpreter_privilege_revoke::RevokePrivilegeInterpreter;
 pub use interpreter_query_log::InterpreterQueryLog;
@@ -37,6 +37,7 @@ pub mod evaluator;
 pub mod interpreters;
 pub mod metrics;
 pub mod pipelines;
+pub mod pipes;
 pub mod procedures;
 pub mod servers;
 pub mod sessions;
@@ -176,11 +176,13 @@ impl<const HAS_AGG: bool, Method: HashMethod + PolymorphicKeysHelper<Method> + S
     }
 
     #[inline(always)]
-    pub fn group_columns<'a>(indices: &[usize], block: &'a DataBlock) -> Vec<&'a ColumnRef> {
-        indices
+    pub fn group_columns(params: &AggregatorParams, block: &DataBlock) -> Result<Vec<ColumnRef>> {
+        params
+            .group_columns
             .iter()
-            .map(|&index| block.column(index))
-            .collect::<Vec<&ColumnRef>>()
+            .zip(params.group_data_types.iter())
+            .map(|(&index, data_type)| DataBlock::hash_key_column(block.column(index), data_type))
+            .collect::<Result<Vec<ColumnRef>>>()
     }
 
     #[inline(always)]
@@ -235,7 +237,8 @@ impl<Method: HashMethod + PolymorphicKeysHelper<Method> + Send> Aggregator
 
     fn consume(&mut self, block: DataBlock) -> Result<()> {
         // 1.1 and 1.2.
-        let group_columns = Self::group_columns(&self.params.group_columns, &block);
+        let group_columns = Self::group_columns(&self.params, &block)?;
+        let group_columns = group_columns.iter().collect::<Vec<&ColumnRef>>();
         let group_keys_state = self
             .method
             .build_keys_state(&group_columns, block.num_rows())?;
@@ -264,7 +267,8 @@ impl<Method: HashMethod + PolymorphicKeysHelper<Method> + Send> Aggregator
 
     fn consume(&mut self, block: DataBlock) -> Result<()> {
         // 1.1 and 1.2.
-        let group_columns = Self::group_columns(&self.params.group_columns, &block);
+        let group_columns = Self::group_columns(&self.params, &block)?;
+        let group_columns = group_columns.iter().collect::<Vec<&ColumnRef>>();
 
         let keys_state = self
             .method
@@ -42,7 +42,10 @@ impl HashJoinState for JoinHashTable {
             .hash_join_desc
             .build_keys
             .iter()
-            .map(|expr| Ok(expr.eval(&func_ctx, &input)?.vector().clone()))
+            .map(|expr| {
+                let typed_vector = expr.eval(&func_ctx, &input)?;
+                DataBlock::hash_key_column(typed_vector.vector(), &typed_vector.logical_type())
+            })
             .collect::<Result<Vec<ColumnRef>>>()?;
         self.row_space.push_cols(input, build_cols)
     }
@@ -249,7 +249,10 @@ impl JoinHashTable {
             .hash_join_desc
             .probe_keys
             .iter()
-            .map(|expr| Ok(expr.eval(&func_ctx, input)?.vector().clone()))
+            .map(|expr| {
+                let typed_vector = expr.eval(&func_ctx, input)?;
+                DataBlock::hash_key_column(typed_vector.vector(), &typed_vector.logical_type())
+            })
             .collect::<Result<Vec<ColumnRef>>>()?;
         let probe_keys = probe_keys.iter().collect::<Vec<&ColumnRef>>();
 
@@ -20,6 +20,7 @@ mod transform_aggregator;
 mod transform_cast_schema;
 mod transform_create_sets;
 mod transform_dummy;
+mod transform_flatten;
 mod transform_hash_join;
 mod transform_limit;
 mod transform_mark_join;
@@ -64,6 +65,7 @@ pub use transform_create_sets::TransformCreateSets;
 pub use transform_dummy::TransformDummy;
 pub use transform_expression::ExpressionTransform;
 pub use transform_expression::ProjectionTransform;
+pub use transform_flatten::TransformFlatten;
 pub use transform_hash_join::SinkBuildHashTable;
 pub use transform_hash_join::TransformHashJoinProbe;
 pub use transform_limit::TransformLimit;
@@ -0,0 +1,154 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::extract_value_by_path;
+use common_functions::scalars::parse_path_keys;
+use common_io::prelude::FormatSettings;
+use serde_json::Value as JsonValue;
+
+use crate::pipelines::processors::port::InputPort;
+use crate::pipelines::processors::port::OutputPort;
+use crate::pipelines::processors::processor::ProcessorPtr;
+use crate::pipelines::processors::transforms::transform::Transform;
+use crate::pipelines::processors::transforms::transform::Transformer;
+
+/// Expands the array or object in one column into rows, appending the
+/// `seq`, `key`, `path`, `index` and `value` columns.
+pub struct TransformFlatten {
+    input_index: usize,
+    path: Option<(String, Vec<DataValue>)>,
+    outer: bool,
+    // Shared by all the processors of the pipeline, so that `seq` identifies an input row.
+    seq: Arc<AtomicU64>,
+    output_schema: DataSchemaRef,
+}
+
+impl TransformFlatten
+where Self: Transform
+{
+    pub fn try_create(
+        input_port: Arc<InputPort>,
+        output_port: Arc<OutputPort>,
+        input_index: usize,
+        path: Option<String>,
+        outer: bool,
+        seq: Arc<AtomicU64>,
+        output_schema: DataSchemaRef,
+    ) -> Result<ProcessorPtr> {
+        let path = match path {
+            Some(path) => {
+                let column = Series::from_data(vec![path.as_str()]);
+                let keys = parse_path_keys(&column)?.remove(0);
+                Some((path, keys))
+            }
+            None => None,
+        };
+        Ok(Transformer::create(input_port, output_port, Self {
+            input_index,
+            path,
+            outer,
+            seq,
+            output_schema,
+        }))
+    }
+
+    fn element_path(&self, element: &str) -> String {
+        match &self.path {
+            Some((path, _)) if element.starts_with('[') => format!("{}{}", path, element),
+            Some((path, _)) => format!("{}.{}", path, element),
+            None => element.to_string(),
+        }
+    }
+}
+
+impl Transform for TransformFlatten {
+    const NAME: &'static str = "FlattenTransform";
+
+    fn transform(&mut self, data: DataBlock) -> Result<DataBlock> {
+        let column = data.column(self.input_index);
+        let data_type = data.schema().field(self.input_index).data_type();
+        let values = data_type
+            .create_serializer(column)?
+            .serialize_json_values(&FormatSettings::default())?;
+
+        let mut indices: Vec<u32> = Vec::with_capacity(values.len());
+        let mut seqs: Vec<u64> = Vec::with_capacity(values.len());
+        let mut keys: Vec<Option<String>> = Vec::with_capacity(values.len());
+        let mut paths: Vec<Option<String>> = Vec::with_capacity(values.len());
+        let mut positions: Vec<Option<u64>> = Vec::with_capacity(values.len());
+        let mut elements: Vec<Option<VariantValue>> = Vec::with_capacity(values.len());
+
+        for (row, value) in values.iter().enumerate() {
+            let seq = self.seq.fetch_add(1, Ordering::Relaxed) + 1;
+            let value = match &self.path {
+                Some((_, path_keys)) => extract_value_by_path(value, path_keys),
+                None => Some(value),
+            };
+
+            let rows = indices.len();
+            match value {
+                Some(JsonValue::Array(array)) => {
+                    for (position, element) in array.iter().enumerate() {
+                        indices.push(row as u32);
+                        seqs.push(seq);
+                        keys.push(None);
+                        paths.push(Some(self.element_path(&format!("[{}]", position))));
+                        positions.push(Some(position as u64));
+                        elements.push(Some(VariantValue::from(element)));
+                    }
+                }
+                Some(JsonValue::Object(object)) => {
+                    for (key, element) in object.iter() {
+                        indices.push(row as u32);
+                        seqs.push(seq);
+                        keys.push(Some(key.clone()));
+                        paths.push(Some(self.element_path(key)));
+                        positions.push(None);
+                        elements.push(Some(VariantValue::from(element)));
+                    }
+                }
+                _ => {}
+            }
+
+            if self.outer && indices.len() == rows {
+                indices.push(row as u32);
+                seqs.push(seq);
+                keys.push(None);
+                paths.push(None);
+                positions.push(None);
+                elements.push(None);
+            }
+        }
+
+        let block = DataBlock::block_take_by_indices(&data, &indices)?;
+        let mut columns = block.columns().to_vec();
+        columns.push(Series::from_data(seqs));
+        columns.push(Series::from_data(
+            keys.iter().map(|v| v.as_deref()).collect::<Vec<_>>(),
+        ));
+        columns.push(Series::from_data(
+            paths.iter().map(|v| v.as_deref()).collect::<Vec<_>>(),
+        ));
+        columns.push(Series::from_data(positions));
+        columns.push(Series::from_data(elements.iter().map(|v| v.as_ref())));
+        Ok(DataBlock::create(self.output_schema.clone(), columns))
+    }
+}
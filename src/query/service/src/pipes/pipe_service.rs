@@ -0,0 +1,194 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use common_base::base::tokio;
+use common_base::base::tokio::sync::Notify;
+use common_base::base::tokio::task::JoinHandle;
+use common_base::base::tokio::time::sleep as tokio_async_sleep;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::PipeInfo;
+use common_users::UserApiProvider;
+use futures::future::select;
+use futures::future::Either;
+use futures::Future;
+use futures::TryStreamExt;
+use tracing::info;
+use tracing::warn;
+
+use crate::clusters::ClusterDiscovery;
+use crate::clusters::ClusterHelper;
+use crate::interpreters::InterpreterFactory;
+use crate::sessions::QueryAffect;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+use crate::sessions::TableContext;
+use crate::sql::Planner;
+use crate::Config;
+
+/// Runs the due pipes of the tenant periodically.
+///
+/// Every query node of the tenant runs the service, a run of a pipe is claimed
+/// by updating its status in the meta with the seq read before,
+/// so that each run is done by only one of the nodes.
+pub struct PipeService {
+    conf: Config,
+    shutdown: Arc<AtomicBool>,
+    shutdown_notify: Arc<Notify>,
+    shutdown_handler: Option<JoinHandle<()>>,
+}
+
+impl PipeService {
+    pub fn create(conf: Config) -> PipeService {
+        PipeService {
+            conf,
+            shutdown: Arc::new(AtomicBool::new(false)),
+            shutdown_notify: Arc::new(Notify::new()),
+            shutdown_handler: None,
+        }
+    }
+
+    fn pipe_loop(&self) -> impl Future<Output = ()> + 'static {
+        let shutdown = self.shutdown.clone();
+        let shutdown_notify = self.shutdown_notify.clone();
+        let conf = self.conf.clone();
+        let interval_secs = conf.query.pipe_poll_interval_secs;
+
+        async move {
+            let mut shutdown_notified = Box::pin(shutdown_notify.notified());
+
+            while !shutdown.load(Ordering::Relaxed) {
+                // Poll more often than the interval, or a pipe may wait for two intervals.
+                let sleep = tokio_async_sleep(Duration::from_secs((interval_secs / 2).max(1)));
+
+                match select(shutdown_notified, Box::pin(sleep)).await {
+                    Either::Left((_, _)) => {
+                        break;
+                    }
+                    Either::Right((_, new_shutdown_notified)) => {
+                        shutdown_notified = new_shutdown_notified;
+                        if let Err(cause) = Self::run_due_pipes(&conf).await {
+                            warn!("Run pipes failure: {:?}", cause);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    async fn run_due_pipes(conf: &Config) -> Result<()> {
+        let tenant = &conf.query.tenant_id;
+        let interval_secs = conf.query.pipe_poll_interval_secs;
+        let user_mgr = UserApiProvider::instance();
+        let node_id = ClusterDiscovery::instance()
+            .discover(conf)
+            .await?
+            .local_id();
+
+        for seq_pipe in user_mgr.get_pipes(tenant).await? {
+            let now = Utc::now();
+            let mut pipe = seq_pipe.data;
+            if !pipe.is_due(now, interval_secs) {
+                continue;
+            }
+
+            pipe.status.last_run_on = Some(now);
+            pipe.status.last_run_node = node_id.clone();
+            // Fails if another node claimed the run, or the pipe is dropped.
+            let seq = match user_mgr
+                .update_pipe(tenant, pipe.clone(), seq_pipe.seq)
+                .await
+            {
+                Ok(seq) => seq,
+                Err(_) => continue,
+            };
+
+            match Self::run_pipe(tenant, &pipe).await {
+                Ok(loaded_files) => {
+                    info!("Pipe {} loaded {} files", pipe.name, loaded_files);
+                    pipe.status.last_loaded_files = loaded_files;
+                    pipe.status.total_loaded_files += loaded_files;
+                    pipe.status.last_error = String::new();
+                }
+                Err(cause) => {
+                    warn!("Pipe {} run failure: {:?}", pipe.name, cause);
+                    pipe.status.last_loaded_files = 0;
+                    pipe.status.last_error = cause.message();
+                }
+            }
+
+            if let Err(cause) = user_mgr.update_pipe(tenant, pipe, seq).await {
+                warn!("Cannot update the status of pipe: {:?}", cause);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Run the copy of the pipe as its creator, returns the number of the loaded files.
+    async fn run_pipe(tenant: &str, pipe: &PipeInfo) -> Result<u64> {
+        let creator = pipe.creator.clone().ok_or_else(|| {
+            ErrorCode::UnknownPipe(format!("Unknown creator of pipe {}", pipe.name))
+        })?;
+        let user = UserApiProvider::instance()
+            .get_user(tenant, creator)
+            .await?;
+
+        let session = SessionManager::instance()
+            .create_session(SessionType::Dummy)
+            .await?;
+        session.set_current_user(user);
+        let ctx = session.create_query_context().await?;
+
+        let mut planner = Planner::new(ctx.clone());
+        let (plan, _, _) = planner.plan_sql(&pipe.copy_stmt).await?;
+        ctx.attach_query_str(plan.to_string(), &pipe.copy_stmt);
+        let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+        let stream = interpreter.execute(ctx.clone()).await?;
+        let _ = stream.try_collect::<Vec<_>>().await?;
+
+        match ctx.get_affect() {
+            Some(QueryAffect::Copy { loaded_files }) => Ok(loaded_files),
+            _ => Ok(0),
+        }
+    }
+
+    /// Start running the pipes, nothing to do if the interval is 0.
+    pub fn start(&mut self) {
+        if self.conf.query.pipe_poll_interval_secs == 0 {
+            return;
+        }
+        self.shutdown_handler = Some(tokio::spawn(self.pipe_loop()));
+    }
+
+    pub async fn shutdown(&mut self) -> Result<()> {
+        if let Some(shutdown_handler) = self.shutdown_handler.take() {
+            self.shutdown.store(true, Ordering::Relaxed);
+            self.shutdown_notify.notify_waiters();
+            if let Err(shutdown_failure) = shutdown_handler.await {
+                return Err(ErrorCode::TokioError(format!(
+                    "Cannot shutdown pipe service, cause {:?}",
+                    shutdown_failure
+                )));
+            }
+        }
+        Ok(())
+    }
+}
@@ -47,7 +47,7 @@ impl OneBlockProcedure for TenantQuotaProcedure {
 
     fn features(&self) -> ProcedureFeatures {
         ProcedureFeatures::default()
-            .variadic_arguments(0, 5)
+            .variadic_arguments(0, 8)
             .management_mode_required(true)
     }
 
@@ -57,6 +57,9 @@ impl OneBlockProcedure for TenantQuotaProcedure {
     /// max_tables_per_database: u32
     /// max_stages: u32
     /// max_files_per_stage: u32
+    /// max_keys: u64
+    /// max_value_bytes: u64
+    /// max_write_ops_per_second: u64
     async fn all_data(&self, ctx: Arc<QueryContext>, args: Vec<String>) -> Result<DataBlock> {
         let mut tenant = ctx.get_tenant();
         if !args.is_empty() {
@@ -88,6 +91,15 @@ impl OneBlockProcedure for TenantQuotaProcedure {
         if let Some(max_files_per_stage) = args.get(4) {
             quota.max_files_per_stage = max_files_per_stage.parse::<u32>()?
         };
+        if let Some(max_keys) = args.get(5) {
+            quota.max_keys = max_keys.parse::<u64>()?
+        };
+        if let Some(max_value_bytes) = args.get(6) {
+            quota.max_value_bytes = max_value_bytes.parse::<u64>()?
+        };
+        if let Some(max_write_ops_per_second) = args.get(7) {
+            quota.max_write_ops_per_second = max_write_ops_per_second.parse::<u64>()?
+        };
 
         quota_api.set_quota(&quota, Some(res.seq)).await?;
 
@@ -100,6 +112,9 @@ impl OneBlockProcedure for TenantQuotaProcedure {
             DataField::new("max_tables_per_database", u32::to_data_type()),
             DataField::new("max_stages", u32::to_data_type()),
             DataField::new("max_files_per_stage", u32::to_data_type()),
+            DataField::new("max_keys", u64::to_data_type()),
+            DataField::new("max_value_bytes", u64::to_data_type()),
+            DataField::new("max_write_ops_per_second", u64::to_data_type()),
         ])
     }
 }
@@ -111,6 +126,9 @@ impl TenantQuotaProcedure {
             Series::from_data(vec![quota.max_tables_per_database]),
             Series::from_data(vec![quota.max_stages]),
             Series::from_data(vec![quota.max_files_per_stage]),
+            Series::from_data(vec![quota.max_keys]),
+            Series::from_data(vec![quota.max_value_bytes]),
+            Series::from_data(vec![quota.max_write_ops_per_second]),
         ]))
     }
 }
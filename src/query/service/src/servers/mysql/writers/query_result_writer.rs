@@ -122,13 +122,16 @@ impl<'a, W: AsyncWrite + Send + Unpin> DFQueryResultWriter<'a, W> {
                 TypeID::UInt64 => Ok(ColumnType::MYSQL_TYPE_LONG),
                 TypeID::Float32 => Ok(ColumnType::MYSQL_TYPE_FLOAT),
                 TypeID::Float64 => Ok(ColumnType::MYSQL_TYPE_FLOAT),
+                TypeID::Decimal => Ok(ColumnType::MYSQL_TYPE_NEWDECIMAL),
                 TypeID::String => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
                 TypeID::Boolean => Ok(ColumnType::MYSQL_TYPE_SHORT),
                 TypeID::Date => Ok(ColumnType::MYSQL_TYPE_DATE),
                 TypeID::Timestamp => Ok(ColumnType::MYSQL_TYPE_DATETIME),
+                TypeID::TimestampTz => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
                 TypeID::Null => Ok(ColumnType::MYSQL_TYPE_NULL),
                 TypeID::Interval => Ok(ColumnType::MYSQL_TYPE_LONG),
                 TypeID::Array => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+                TypeID::Map => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
                 TypeID::Struct => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
                 TypeID::Variant => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
                 TypeID::VariantArray => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
@@ -199,6 +202,14 @@ impl<'a, W: AsyncWrite + Send + Unpin> DFQueryResultWriter<'a, W> {
                                             .write_col(
                                                 serializer.serialize_field(row_index, format)?,
                                             )?,
+                                        (TypeID::Decimal, DataValue::Int128(_)) => row_writer
+                                            .write_col(
+                                                serializer.serialize_field(row_index, format)?,
+                                            )?,
+                                        (TypeID::TimestampTz, DataValue::Int128(_)) => row_writer
+                                            .write_col(
+                                            serializer.serialize_field(row_index, format)?,
+                                        )?,
                                         (TypeID::String, DataValue::String(v)) => {
                                             row_writer.write_col(v)?
                                         }
@@ -206,6 +217,10 @@ impl<'a, W: AsyncWrite + Send + Unpin> DFQueryResultWriter<'a, W> {
                                             .write_col(
                                                 serializer.serialize_field(row_index, format)?,
                                             )?,
+                                        (TypeID::Map, DataValue::Array(_)) => row_writer
+                                            .write_col(
+                                                serializer.serialize_field(row_index, format)?,
+                                            )?,
                                         (TypeID::Struct, DataValue::Struct(_)) => row_writer
                                             .write_col(
                                                 serializer.serialize_field(row_index, format)?,
@@ -32,4 +32,7 @@ pub enum QueryAffect {
         value: String,
         is_global: bool,
     },
+    Copy {
+        loaded_files: u64,
+    },
 }
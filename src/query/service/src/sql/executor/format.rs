@@ -26,6 +26,7 @@ use super::AggregatePartial;
 use super::EvalScalar;
 use super::Exchange;
 use super::Filter;
+use super::Flatten;
 use super::HashJoin;
 use super::Limit;
 use super::PhysicalPlan;
@@ -50,6 +51,7 @@ fn to_format_tree(plan: &PhysicalPlan, metadata: &MetadataRef) -> Result<FormatT
         PhysicalPlan::AggregateFinal(plan) => aggregate_final_to_format_tree(plan, metadata),
         PhysicalPlan::Sort(plan) => sort_to_format_tree(plan, metadata),
         PhysicalPlan::Limit(plan) => limit_to_format_tree(plan, metadata),
+        PhysicalPlan::Flatten(plan) => flatten_to_format_tree(plan, metadata),
         PhysicalPlan::HashJoin(plan) => hash_join_to_format_tree(plan, metadata),
         PhysicalPlan::Exchange(plan) => exchange_to_format_tree(plan, metadata),
         PhysicalPlan::UnionAll(plan) => union_all_to_format_tree(plan, metadata),
@@ -263,6 +265,20 @@ fn limit_to_format_tree(plan: &Limit, metadata: &MetadataRef) -> Result<FormatTr
     ]))
 }
 
+fn flatten_to_format_tree(
+    plan: &Flatten,
+    metadata: &MetadataRef,
+) -> Result<FormatTreeNode<String>> {
+    let index = plan.input_column.parse::<IndexType>()?;
+    let column = metadata.read().column(index).clone();
+    Ok(FormatTreeNode::with_children("Flatten".to_string(), vec![
+        FormatTreeNode::new(format!("input: {}", column.name())),
+        FormatTreeNode::new(format!("path: {}", plan.path.as_deref().unwrap_or("NONE"))),
+        FormatTreeNode::new(format!("outer: {}", plan.outer)),
+        to_format_tree(&plan.input, metadata)?,
+    ]))
+}
+
 fn hash_join_to_format_tree(
     plan: &HashJoin,
     metadata: &MetadataRef,
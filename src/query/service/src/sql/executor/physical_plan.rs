@@ -194,6 +194,32 @@ impl Limit {
     }
 }
 
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct Flatten {
+    pub input: Box<PhysicalPlan>,
+    pub input_column: ColumnID,
+    pub path: Option<String>,
+    pub outer: bool,
+
+    /// Column ids of `seq`, `key`, `path`, `index` and `value`
+    pub output_columns: Vec<ColumnID>,
+}
+
+impl Flatten {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        let input_schema = self.input.output_schema()?;
+        let mut fields = input_schema.fields().clone();
+        for (id, (_, data_type)) in self
+            .output_columns
+            .iter()
+            .zip(crate::sql::plans::Flatten::output_fields())
+        {
+            fields.push(DataField::new(id, data_type));
+        }
+        Ok(DataSchemaRefExt::create(fields))
+    }
+}
+
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct HashJoin {
     pub build: Box<PhysicalPlan>,
@@ -378,6 +404,7 @@ pub enum PhysicalPlan {
     AggregateFinal(AggregateFinal),
     Sort(Sort),
     Limit(Limit),
+    Flatten(Flatten),
     HashJoin(HashJoin),
     Exchange(Exchange),
     UnionAll(UnionAll),
@@ -409,6 +436,7 @@ impl PhysicalPlan {
             PhysicalPlan::AggregateFinal(plan) => plan.output_schema(),
             PhysicalPlan::Sort(plan) => plan.output_schema(),
             PhysicalPlan::Limit(plan) => plan.output_schema(),
+            PhysicalPlan::Flatten(plan) => plan.output_schema(),
             PhysicalPlan::HashJoin(plan) => plan.output_schema(),
             PhysicalPlan::Exchange(plan) => plan.output_schema(),
             PhysicalPlan::ExchangeSource(plan) => plan.output_schema(),
@@ -428,6 +456,7 @@ impl PhysicalPlan {
             PhysicalPlan::AggregateFinal(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::Sort(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::Limit(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::Flatten(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::HashJoin(plan) => Box::new(
                 std::iter::once(plan.probe.as_ref()).chain(std::iter::once(plan.build.as_ref())),
             ),
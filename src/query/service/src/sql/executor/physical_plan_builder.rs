@@ -27,6 +27,7 @@ use common_legacy_planners::Extras;
 use common_legacy_planners::PrewhereInfo;
 use common_legacy_planners::Projection;
 use common_legacy_planners::StageKind;
+use common_legacy_planners::VirtualColumn;
 use common_planner::IndexType;
 use common_planner::Metadata;
 use common_planner::MetadataRef;
@@ -38,6 +39,7 @@ use super::AggregateFinal;
 use super::AggregatePartial;
 use super::Exchange as PhysicalExchange;
 use super::Filter;
+use super::Flatten;
 use super::HashJoin;
 use super::Limit;
 use super::Sort;
@@ -80,9 +82,12 @@ impl PhysicalPlanBuilder {
         columns: &ColumnSet,
         has_inner_column: bool,
     ) -> Projection {
+        // virtual columns are not stored in the table schema, see `Extras::virtual_columns`
+        let columns = columns
+            .iter()
+            .filter(|index| !metadata.column(**index).is_virtual_column());
         if !has_inner_column {
             let col_indices = columns
-                .iter()
                 .map(|index| {
                     let name = metadata.column(*index).name();
                     schema.index_of(name).unwrap()
@@ -92,7 +97,6 @@ impl PhysicalPlanBuilder {
             Projection::Columns(col_indices)
         } else {
             let col_indices = columns
-                .iter()
                 .map(|index| {
                     let column = metadata.column(*index);
                     match &column.path_indices() {
@@ -426,6 +430,17 @@ impl PhysicalPlanBuilder {
                 limit: limit.limit,
                 offset: limit.offset,
             })),
+            RelOperator::Flatten(flatten) => Ok(PhysicalPlan::Flatten(Flatten {
+                input: Box::new(self.build(s_expr.child(0)?).await?),
+                input_column: flatten.input_column.to_string(),
+                path: flatten.path.clone(),
+                outer: flatten.outer,
+                output_columns: flatten
+                    .output_columns()
+                    .iter()
+                    .map(|index| index.to_string())
+                    .collect(),
+            })),
             RelOperator::Exchange(exchange) => {
                 let input = Box::new(self.build(s_expr.child(0)?).await?);
                 let input_schema = input.output_schema()?;
@@ -570,12 +585,26 @@ impl PhysicalPlanBuilder {
             })
             .transpose()?;
 
+        let mut virtual_columns = vec![];
+        for index in scan.columns.iter().sorted() {
+            let column = metadata.column(*index);
+            if let (Some(source), Some(path)) = (column.virtual_source(), column.virtual_path()) {
+                virtual_columns.push(VirtualColumn {
+                    name: column.name().to_string(),
+                    source_index: table_schema.index_of(metadata.column(source).name())?,
+                    path: path.to_vec(),
+                    data_type: column.data_type().clone(),
+                });
+            }
+        }
+
         Ok(Extras {
             projection: Some(projection),
             filters: push_down_filters.unwrap_or_default(),
             prewhere: prewhere_info,
             limit: scan.limit,
             order_by: order_by.unwrap_or_default(),
+            virtual_columns,
         })
     }
 }
@@ -26,6 +26,7 @@ use crate::sql::executor::Exchange;
 use crate::sql::executor::ExchangeSink;
 use crate::sql::executor::ExchangeSource;
 use crate::sql::executor::Filter;
+use crate::sql::executor::Flatten;
 use crate::sql::executor::HashJoin;
 use crate::sql::executor::Limit;
 use crate::sql::executor::PhysicalPlan;
@@ -60,6 +61,7 @@ impl<'a> Display for PhysicalPlanIndentFormatDisplay<'a> {
             PhysicalPlan::AggregateFinal(aggregate) => write!(f, "{}", aggregate)?,
             PhysicalPlan::Sort(sort) => write!(f, "{}", sort)?,
             PhysicalPlan::Limit(limit) => write!(f, "{}", limit)?,
+            PhysicalPlan::Flatten(flatten) => write!(f, "{}", flatten)?,
             PhysicalPlan::HashJoin(join) => write!(f, "{}", join)?,
             PhysicalPlan::Exchange(exchange) => write!(f, "{}", exchange)?,
             PhysicalPlan::ExchangeSource(source) => write!(f, "{}", source)?,
@@ -239,6 +241,18 @@ impl Display for Limit {
     }
 }
 
+impl Display for Flatten {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Flatten: [{}], Path: [{}], Outer: [{}]",
+            self.input_column,
+            self.path.as_deref().unwrap_or(""),
+            self.outer
+        )
+    }
+}
+
 impl Display for HashJoin {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self.join_type {
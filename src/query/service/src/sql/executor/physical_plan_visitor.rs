@@ -22,6 +22,7 @@ use super::Exchange;
 use super::ExchangeSink;
 use super::ExchangeSource;
 use super::Filter;
+use super::Flatten;
 use super::HashJoin;
 use super::Limit;
 use super::PhysicalPlan;
@@ -41,6 +42,7 @@ pub trait PhysicalPlanReplacer {
             PhysicalPlan::AggregateFinal(plan) => self.replace_aggregate_final(plan),
             PhysicalPlan::Sort(plan) => self.replace_sort(plan),
             PhysicalPlan::Limit(plan) => self.replace_limit(plan),
+            PhysicalPlan::Flatten(plan) => self.replace_flatten(plan),
             PhysicalPlan::HashJoin(plan) => self.replace_hash_join(plan),
             PhysicalPlan::Exchange(plan) => self.replace_exchange(plan),
             PhysicalPlan::ExchangeSource(plan) => self.replace_exchange_source(plan),
@@ -139,6 +141,18 @@ pub trait PhysicalPlanReplacer {
         }))
     }
 
+    fn replace_flatten(&mut self, plan: &Flatten) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+
+        Ok(PhysicalPlan::Flatten(Flatten {
+            input: Box::new(input),
+            input_column: plan.input_column.clone(),
+            path: plan.path.clone(),
+            outer: plan.outer,
+            output_columns: plan.output_columns.clone(),
+        }))
+    }
+
     fn replace_exchange(&mut self, plan: &Exchange) -> Result<PhysicalPlan> {
         let input = self.replace(&plan.input)?;
 
@@ -227,6 +241,9 @@ impl PhysicalPlan {
                 PhysicalPlan::Limit(plan) => {
                     Self::traverse(&plan.input, pre_visit, visit, post_visit);
                 }
+                PhysicalPlan::Flatten(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
                 PhysicalPlan::HashJoin(plan) => {
                     Self::traverse(&plan.build, pre_visit, visit, post_visit);
                     Self::traverse(&plan.probe, pre_visit, visit, post_visit);
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::atomic::AtomicU64;
 use std::sync::Arc;
 
 use async_channel::Receiver;
@@ -38,6 +39,7 @@ use super::EvalScalar;
 use super::ExchangeSink;
 use super::ExchangeSource;
 use super::Filter;
+use super::Flatten;
 use super::HashJoin;
 use super::Limit;
 use super::Project;
@@ -64,6 +66,7 @@ use crate::pipelines::processors::Sinker;
 use crate::pipelines::processors::SortMergeCompactor;
 use crate::pipelines::processors::TransformAggregator;
 use crate::pipelines::processors::TransformCastSchema;
+use crate::pipelines::processors::TransformFlatten;
 use crate::pipelines::processors::TransformHashJoinProbe;
 use crate::pipelines::processors::TransformLimit;
 use crate::pipelines::processors::TransformSortMerge;
@@ -123,6 +126,7 @@ impl PipelineBuilder {
             PhysicalPlan::AggregateFinal(aggregate) => self.build_aggregate_final(aggregate),
             PhysicalPlan::Sort(sort) => self.build_sort(sort),
             PhysicalPlan::Limit(limit) => self.build_limit(limit),
+            PhysicalPlan::Flatten(flatten) => self.build_flatten(flatten),
             PhysicalPlan::HashJoin(join) => self.build_join(join),
             PhysicalPlan::ExchangeSink(sink) => self.build_exchange_sink(sink),
             PhysicalPlan::ExchangeSource(source) => self.build_exchange_source(source),
@@ -480,6 +484,26 @@ impl PipelineBuilder {
         })
     }
 
+    fn build_flatten(&mut self, flatten: &Flatten) -> Result<()> {
+        self.build_pipeline(&flatten.input)?;
+
+        let input_schema = flatten.input.output_schema()?;
+        let input_index = input_schema.index_of(&flatten.input_column)?;
+        let output_schema = flatten.output_schema()?;
+        let seq = Arc::new(AtomicU64::new(0));
+        self.main_pipeline.add_transform(|input, output| {
+            TransformFlatten::try_create(
+                input,
+                output,
+                input_index,
+                flatten.path.clone(),
+                flatten.outer,
+                seq.clone(),
+                output_schema.clone(),
+            )
+        })
+    }
+
     fn build_join_probe(&mut self, join: &HashJoin, state: Arc<JoinHashTable>) -> Result<()> {
         self.build_pipeline(&join.probe)?;
 
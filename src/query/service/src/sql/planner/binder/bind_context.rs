@@ -96,6 +96,11 @@ pub struct BindContext {
     /// functions, otherwise a grouping error will be raised.
     pub in_grouping: bool,
 
+    /// True if object paths of variant columns cannot be bound as virtual columns in
+    /// current context. The grouping check only matches the variant column itself,
+    /// so the select list of a query with `GROUP BY` must keep the path expressions.
+    pub forbid_virtual_columns: bool,
+
     /// Format type of query output.
     pub format: Option<String>,
 
@@ -116,6 +121,7 @@ impl BindContext {
             columns: Vec::new(),
             aggregate_info: AggregateInfo::default(),
             in_grouping: false,
+            forbid_virtual_columns: false,
             format: None,
             ctes_map: Arc::new(RwLock::new(HashMap::new())),
         }
@@ -127,6 +133,7 @@ impl BindContext {
             columns: vec![],
             aggregate_info: Default::default(),
             in_grouping: false,
+            forbid_virtual_columns: false,
             format: None,
             ctes_map: parent.ctes_map.clone(),
         }
@@ -30,6 +30,7 @@ use common_planner::plans::AlterUDFPlan;
 use common_planner::plans::CallPlan;
 use common_planner::plans::CreateRolePlan;
 use common_planner::plans::CreateUDFPlan;
+use common_planner::plans::DropPipePlan;
 use common_planner::plans::DropRolePlan;
 use common_planner::plans::DropStagePlan;
 use common_planner::plans::DropUDFPlan;
@@ -204,6 +205,17 @@ impl<'a> Binder {
             Statement::RemoveStage { location, pattern } => {
                 self.bind_remove_stage(location, pattern).await?
             }
+
+            // Pipes
+            Statement::CreatePipe(stmt) => self.bind_create_pipe(bind_context, stmt).await?,
+            Statement::DropPipe {
+                if_exists,
+                pipe_name,
+            } => Plan::DropPipe(Box::new(DropPipePlan {
+                if_exists: *if_exists,
+                name: pipe_name.clone(),
+            })),
+
             Statement::Insert(stmt) => self.bind_insert(bind_context, stmt).await?,
             Statement::Delete {
                 table_reference,
@@ -18,8 +18,16 @@ use std::sync::Arc;
 
 use common_ast::ast::CopyStmt;
 use common_ast::ast::CopyUnit;
+use common_ast::ast::Expr;
+use common_ast::ast::Identifier;
+use common_ast::ast::Indirection;
 use common_ast::ast::Query;
+use common_ast::ast::SelectStmt;
+use common_ast::ast::SelectTarget;
+use common_ast::ast::SetExpr;
 use common_ast::ast::Statement;
+use common_ast::ast::TableAlias;
+use common_ast::ast::TableReference;
 use common_ast::parser::parse_sql;
 use common_ast::parser::tokenize_sql;
 use common_ast::Backtrace;
@@ -52,6 +60,12 @@ impl<'a> Binder {
         bind_context: &BindContext,
         stmt: &CopyStmt<'a>,
     ) -> Result<Plan> {
+        if !stmt.partition_by.is_empty() && matches!(stmt.dst, CopyUnit::Table { .. }) {
+            return Err(ErrorCode::SyntaxException(
+                "PARTITION BY is only supported by COPY INTO <location>",
+            ));
+        }
+
         match (&stmt.src, &stmt.dst) {
             (
                 CopyUnit::StageLocation { name, path },
@@ -335,11 +349,8 @@ impl<'a> Binder {
         let backtrace = Backtrace::new();
         let sub_stmt_msg = parse_sql(&tokens, Dialect::PostgreSQL, &backtrace)?;
         let sub_stmt = sub_stmt_msg.0;
-        let query = match &sub_stmt {
-            Statement::Query(query) => {
-                self.bind_statement(bind_context, &Statement::Query(query.clone()))
-                    .await?
-            }
+        let (query, partition_by) = match &sub_stmt {
+            Statement::Query(query) => self.bind_unload_query(bind_context, stmt, query).await?,
             _ => {
                 return Err(ErrorCode::SyntaxException(
                     "COPY INTO <location> FROM <non-query> is invalid",
@@ -360,6 +371,10 @@ impl<'a> Binder {
             path,
             validation_mode,
             from: Box::new(query),
+            partition_by,
+            include_query_id: stmt.include_query_id,
+            overwrite: stmt.overwrite,
+            header: stmt.header,
         })))
     }
 
@@ -380,11 +395,8 @@ impl<'a> Binder {
         let backtrace = Backtrace::new();
         let sub_stmt_msg = parse_sql(&tokens, Dialect::PostgreSQL, &backtrace)?;
         let sub_stmt = sub_stmt_msg.0;
-        let query = match &sub_stmt {
-            Statement::Query(query) => {
-                self.bind_statement(bind_context, &Statement::Query(query.clone()))
-                    .await?
-            }
+        let (query, partition_by) = match &sub_stmt {
+            Statement::Query(query) => self.bind_unload_query(bind_context, stmt, query).await?,
             _ => {
                 return Err(ErrorCode::SyntaxException(
                     "COPY INTO <location> FROM <non-query> is invalid",
@@ -411,6 +423,10 @@ impl<'a> Binder {
             path,
             validation_mode,
             from: Box::new(query),
+            partition_by,
+            include_query_id: stmt.include_query_id,
+            overwrite: stmt.overwrite,
+            header: stmt.header,
         })))
     }
 
@@ -423,8 +439,8 @@ impl<'a> Binder {
         dst_stage: &str,
         dst_path: &str,
     ) -> Result<Plan> {
-        let query = self
-            .bind_statement(bind_context, &Statement::Query(Box::new(src_query.clone())))
+        let (query, partition_by) = self
+            .bind_unload_query(bind_context, stmt, src_query)
             .await?;
 
         // Validation mode.
@@ -440,6 +456,10 @@ impl<'a> Binder {
             path,
             validation_mode,
             from: Box::new(query),
+            partition_by,
+            include_query_id: stmt.include_query_id,
+            overwrite: stmt.overwrite,
+            header: stmt.header,
         })))
     }
 
@@ -452,8 +472,8 @@ impl<'a> Binder {
         src_query: &Query<'_>,
         dst_uri_location: &UriLocation,
     ) -> Result<Plan> {
-        let query = self
-            .bind_statement(bind_context, &Statement::Query(Box::new(src_query.clone())))
+        let (query, partition_by) = self
+            .bind_unload_query(bind_context, stmt, src_query)
             .await?;
 
         // Validation mode.
@@ -475,9 +495,92 @@ impl<'a> Binder {
             path,
             validation_mode,
             from: Box::new(query),
+            partition_by,
+            include_query_id: stmt.include_query_id,
+            overwrite: stmt.overwrite,
+            header: stmt.header,
         })))
     }
 
+    /// Bind the query to unload into a location.
+    ///
+    /// With `PARTITION BY`, the partition expressions are projected after the
+    /// columns of the query, the names of the partitions are returned as well.
+    async fn bind_unload_query(
+        &mut self,
+        bind_context: &BindContext,
+        stmt: &CopyStmt<'a>,
+        query: &Query<'_>,
+    ) -> Result<(Plan, Vec<String>)> {
+        if stmt.partition_by.is_empty() {
+            let plan = self
+                .bind_statement(bind_context, &Statement::Query(Box::new(query.clone())))
+                .await?;
+            return Ok((plan, vec![]));
+        }
+
+        if stmt.single {
+            return Err(ErrorCode::SyntaxException(
+                "SINGLE = true is not supported with PARTITION BY",
+            ));
+        }
+
+        // `SELECT *, <expr> AS _partition_<i>, ... FROM (<query>) AS _unload`
+        let mut partition_by = Vec::with_capacity(stmt.partition_by.len());
+        let mut select_list = Vec::with_capacity(stmt.partition_by.len() + 1);
+        select_list.push(SelectTarget::QualifiedName(vec![Indirection::Star]));
+        for (i, expr) in stmt.partition_by.iter().enumerate() {
+            let name = match expr {
+                Expr::ColumnRef { column, .. } => {
+                    normalize_identifier(column, &self.name_resolution_ctx).name
+                }
+                _ => expr.to_string(),
+            };
+            partition_by.push(name);
+            select_list.push(SelectTarget::AliasedExpr {
+                expr: Box::new(expr.clone()),
+                alias: Some(Identifier {
+                    name: format!("_partition_{i}"),
+                    quote: None,
+                    span: expr.span()[0].clone(),
+                }),
+            });
+        }
+
+        let unload_query = Query {
+            span: query.span,
+            with: None,
+            body: SetExpr::Select(Box::new(SelectStmt {
+                span: query.span,
+                distinct: false,
+                select_list,
+                from: vec![TableReference::Subquery {
+                    span: query.span,
+                    subquery: Box::new(query.clone()),
+                    alias: Some(TableAlias {
+                        name: Identifier {
+                            name: "_unload".to_string(),
+                            quote: None,
+                            span: stmt.partition_by[0].span()[0].clone(),
+                        },
+                        columns: vec![],
+                    }),
+                }],
+                selection: None,
+                group_by: vec![],
+                having: None,
+            })),
+            order_by: vec![],
+            limit: vec![],
+            offset: None,
+            format: None,
+        };
+        let plan = self
+            .bind_statement(bind_context, &Statement::Query(Box::new(unload_query)))
+            .await?;
+        Ok((plan, partition_by))
+    }
+
     fn apply_stage_options(
         &mut self,
         stmt: &CopyStmt<'a>,
@@ -620,11 +723,74 @@ pub fn parse_copy_file_format_options(
     .parse()
     .map_err(ErrorCode::UnknownCompressionType)?;
 
+    // Column offsets of fixed-width records.
+    let column_offsets = match file_format_options.get("column_offsets") {
+        Some(offsets) => parse_column_offsets(offsets)?,
+        None => vec![],
+    };
+    if file_format == StageFileFormatType::FixedWidth && column_offsets.is_empty() {
+        return Err(ErrorCode::SyntaxException(
+            "COLUMN_OFFSETS must be specified for FixedWidth file format",
+        ));
+    }
+
+    // Path of xml row elements.
+    let row_path = file_format_options
+        .get("row_path")
+        .cloned()
+        .unwrap_or_default();
+
+    // Xml child elements or attributes of columns.
+    let column_mapping = match file_format_options.get("column_mapping") {
+        Some(mapping) => parse_column_mapping(mapping)?,
+        None => BTreeMap::new(),
+    };
+
     Ok(FileFormatOptions {
         format: file_format,
         skip_header,
         field_delimiter,
         record_delimiter,
         compression,
+        column_offsets,
+        row_path,
+        column_mapping,
     })
 }
+
+/// Parse `'0, 10, 25'` into the start offsets of columns, which must be increasing.
+fn parse_column_offsets(offsets: &str) -> Result<Vec<u64>> {
+    let mut result: Vec<u64> = vec![];
+    for offset in offsets.split(',') {
+        let offset = offset.trim().parse::<u64>().map_err(|_| {
+            ErrorCode::SyntaxException(format!(
+                "COLUMN_OFFSETS must be a list of numbers separated by ',', got '{offsets}'"
+            ))
+        })?;
+        if matches!(result.last(), Some(last) if *last >= offset) {
+            return Err(ErrorCode::SyntaxException(format!(
+                "COLUMN_OFFSETS must be increasing, got '{offsets}'"
+            )));
+        }
+        result.push(offset);
+    }
+    Ok(result)
+}
+
+/// Parse `'id = @id, city = address/city'` into a map from column name to path.
+fn parse_column_mapping(mapping: &str) -> Result<BTreeMap<String, String>> {
+    let mut result = BTreeMap::new();
+    for pair in mapping.split(',') {
+        match pair.split_once('=') {
+            Some((column, path)) if !column.trim().is_empty() && !path.trim().is_empty() => {
+                result.insert(column.trim().to_string(), path.trim().to_string());
+            }
+            _ => {
+                return Err(ErrorCode::SyntaxException(format!(
+                    "COLUMN_MAPPING must be a list of '<column> = <path>' separated by ',', got '{mapping}'"
+                )));
+            }
+        }
+    }
+    Ok(result)
+}
@@ -14,6 +14,7 @@
 
 mod account;
 mod database;
+mod pipe;
 mod share;
 mod stage;
 mod table;
@@ -0,0 +1,108 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::Utc;
+use common_ast::ast::CopyUnit;
+use common_ast::ast::CreatePipeStmt;
+use common_ast::ast::Identifier;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::PipeInfo;
+use common_planner::plans::CreatePipePlan;
+
+use crate::sql::binder::Binder;
+use crate::sql::plans::Plan;
+use crate::sql::BindContext;
+
+impl<'a> Binder {
+    pub(in crate::sql::planner::binder) async fn bind_create_pipe(
+        &mut self,
+        bind_context: &BindContext,
+        stmt: &CreatePipeStmt<'a>,
+    ) -> Result<Plan> {
+        let CreatePipeStmt {
+            if_not_exists,
+            pipe_name,
+            auto_ingest,
+            comments,
+            copy,
+        } = stmt;
+
+        if !matches!(
+            (&copy.src, &copy.dst),
+            (
+                CopyUnit::StageLocation { .. } | CopyUnit::UriLocation(_),
+                CopyUnit::Table { .. }
+            )
+        ) {
+            return Err(ErrorCode::SyntaxException(
+                "CREATE PIPE only supports COPY INTO <table> FROM <location>",
+            ));
+        }
+        // The statement is stored in the meta as it is, credentials are only kept by stages.
+        if matches!(&copy.src, CopyUnit::UriLocation(location) if !location.connection.is_empty()) {
+            return Err(ErrorCode::SyntaxException(
+                "CREATE PIPE does not support a location with CONNECTION, use a stage instead",
+            ));
+        }
+        if !copy.validation_mode.is_empty() {
+            return Err(ErrorCode::SyntaxException(
+                "CREATE PIPE does not support VALIDATION_MODE",
+            ));
+        }
+        // The pipe relies on the copied files of the table to skip the loaded files.
+        if copy.force {
+            return Err(ErrorCode::SyntaxException(
+                "CREATE PIPE does not support FORCE = true",
+            ));
+        }
+
+        // Make sure the copy is valid now, instead of failing in every run.
+        self.bind_copy(bind_context, copy).await?;
+
+        // The pipe runs in a new session, store the table with the current catalog and database.
+        let mut copy = copy.clone();
+        if let CopyUnit::Table {
+            catalog,
+            database,
+            table,
+        } = &mut copy.dst
+        {
+            let quoted = |name: String| Identifier {
+                name,
+                quote: Some('`'),
+                span: table.span.clone(),
+            };
+            if catalog.is_none() {
+                *catalog = Some(quoted(self.ctx.get_current_catalog()));
+            }
+            if database.is_none() {
+                *database = Some(quoted(self.ctx.get_current_database()));
+            }
+        }
+
+        Ok(Plan::CreatePipe(Box::new(CreatePipePlan {
+            if_not_exists: *if_not_exists,
+            tenant: self.ctx.get_tenant(),
+            pipe_info: PipeInfo {
+                name: pipe_name.clone(),
+                copy_stmt: copy.to_string(),
+                auto_ingest: *auto_ingest,
+                comment: comments.clone(),
+                created_on: Some(Utc::now()),
+                ..Default::default()
+            },
+        })))
+    }
+}
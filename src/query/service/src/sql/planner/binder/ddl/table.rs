@@ -653,6 +653,7 @@ impl<'a> Binder {
                     self.metadata.clone(),
                     &[],
                 );
+                scalar_binder.forbid_virtual_columns();
 
                 let push_downs = if let Some(expr) = selection {
                     let (scalar, _) = scalar_binder.bind(expr).await?;
@@ -947,6 +948,7 @@ impl<'a> Binder {
             self.metadata.clone(),
             &[],
         );
+        scalar_binder.forbid_virtual_columns();
 
         let mut cluster_keys = Vec::with_capacity(cluster_by.len());
         for cluster_by in cluster_by.iter() {
@@ -66,6 +66,7 @@ impl<'a> Binder {
             self.metadata.clone(),
             &[],
         );
+        scalar_binder.forbid_virtual_columns();
 
         let table = self
             .ctx
@@ -16,9 +16,12 @@ use std::sync::Arc;
 
 use async_recursion::async_recursion;
 use common_ast::ast::Expr;
+use common_ast::ast::Identifier;
 use common_ast::ast::Join;
 use common_ast::ast::JoinCondition;
 use common_ast::ast::JoinOperator;
+use common_ast::ast::TableAlias;
+use common_ast::ast::TableReference;
 use common_datavalues::type_coercion::compare_coercion;
 use common_datavalues::wrap_nullable;
 use common_exception::ErrorCode;
@@ -50,6 +53,20 @@ impl<'a> Binder {
         bind_context: &BindContext,
         join: &Join<'a>,
     ) -> Result<(SExpr, BindContext)> {
+        if let TableReference::TableFunction {
+            span: _,
+            lateral: true,
+            name,
+            params,
+            named_params,
+            alias,
+        } = join.right.as_ref()
+        {
+            return self
+                .bind_lateral_join(bind_context, join, name, params, named_params, alias)
+                .await;
+        }
+
         let (left_child, left_context) =
             self.bind_table_reference(bind_context, &join.left).await?;
         let (right_child, right_context) =
@@ -231,6 +248,48 @@ impl<'a> Binder {
         Ok((s_expr, bind_context))
     }
 
+    /// Bind `left, LATERAL FLATTEN(input => left.v)`. Instead of joining with an
+    /// independent right side, each row of the left side is expanded by flatten.
+    async fn bind_lateral_join(
+        &mut self,
+        bind_context: &BindContext,
+        join: &Join<'a>,
+        name: &Identifier<'a>,
+        params: &[Expr<'a>],
+        named_params: &[(Identifier<'a>, Expr<'a>)],
+        alias: &Option<TableAlias<'a>>,
+    ) -> Result<(SExpr, BindContext)> {
+        let func_name = normalize_identifier(name, &self.name_resolution_ctx).name;
+        if !func_name.eq_ignore_ascii_case("flatten") {
+            return Err(ErrorCode::SemanticError(format!(
+                "LATERAL is only supported for FLATTEN, but got: {}",
+                func_name
+            )));
+        }
+        if !matches!(join.op, JoinOperator::Inner | JoinOperator::CrossJoin)
+            || join.condition != JoinCondition::None
+        {
+            return Err(ErrorCode::SemanticError(
+                "LATERAL FLATTEN only supports cross join without join conditions".to_string(),
+            ));
+        }
+
+        let (left_child, left_context) =
+            self.bind_table_reference(bind_context, &join.left).await?;
+        let (s_expr, columns) = self
+            .bind_flatten(left_child, &left_context, params, named_params, alias)
+            .await?;
+
+        let mut bind_context = bind_context.replace();
+        for column in left_context.all_column_bindings() {
+            bind_context.add_column_binding(column.clone());
+        }
+        for column in columns {
+            bind_context.add_column_binding(column);
+        }
+        Ok((s_expr, bind_context))
+    }
+
     pub fn bind_join_with_type(
         &mut self,
         join_type: JoinType,
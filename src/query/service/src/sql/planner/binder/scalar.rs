@@ -32,6 +32,7 @@ pub struct ScalarBinder<'a> {
     name_resolution_ctx: &'a NameResolutionContext,
     metadata: MetadataRef,
     aliases: &'a [(String, Scalar)],
+    allow_virtual_columns: bool,
 }
 
 impl<'a> ScalarBinder<'a> {
@@ -48,9 +49,16 @@ impl<'a> ScalarBinder<'a> {
             name_resolution_ctx,
             metadata,
             aliases,
+            allow_virtual_columns: true,
         }
     }
 
+    /// Bind object paths of variant columns as regular function calls instead of
+    /// virtual columns, for the plans which evaluate the scalars on raw table blocks.
+    pub fn forbid_virtual_columns(&mut self) {
+        self.allow_virtual_columns = false;
+    }
+
     pub async fn bind(&mut self, expr: &Expr<'a>) -> Result<(Scalar, DataTypeImpl)> {
         let mut type_checker = TypeChecker::new(
             self.bind_context,
@@ -59,6 +67,7 @@ impl<'a> ScalarBinder<'a> {
             self.metadata.clone(),
             self.aliases,
         );
+        type_checker.set_allow_virtual_columns(self.allow_virtual_columns);
         Ok(*type_checker.resolve(expr, None).await?)
     }
 }
@@ -44,6 +44,7 @@ use crate::sql::plans::CastExpr;
 use crate::sql::plans::EvalScalar;
 use crate::sql::plans::Filter;
 use crate::sql::plans::JoinType;
+use crate::sql::plans::RelOperator;
 use crate::sql::plans::Scalar;
 use crate::sql::plans::ScalarItem;
 use crate::sql::plans::UnionAll;
@@ -93,6 +94,7 @@ impl<'a> Binder {
         if let Some(expr) = &stmt.selection {
             s_expr = self.bind_where(&from_context, expr, s_expr).await?;
         }
+        from_context.forbid_virtual_columns = !stmt.group_by.is_empty();
 
         // Generate a analyzed select list with from context
         let mut select_list = self
@@ -252,9 +254,35 @@ impl<'a> Binder {
             bind_context.resolve_format(format.clone())?
         }
 
+        let s_expr = self.bind_virtual_columns(s_expr)?;
+
         Ok((s_expr, bind_context))
     }
 
+    /// Virtual columns are created while binding the scalars after the tables have been
+    /// bound, so add them to the outputs of the scans of their tables.
+    fn bind_virtual_columns(&self, s_expr: SExpr) -> Result<SExpr> {
+        if let RelOperator::LogicalGet(get) = s_expr.plan() {
+            let virtual_columns = self
+                .metadata
+                .read()
+                .virtual_columns_by_table_index(get.table_index);
+            if virtual_columns.is_empty() {
+                return Ok(s_expr);
+            }
+            let mut get = get.clone();
+            get.columns.extend(virtual_columns);
+            return Ok(SExpr::create_leaf(get.into()));
+        }
+
+        let children = s_expr
+            .children()
+            .iter()
+            .map(|child| self.bind_virtual_columns(child.clone()))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(s_expr.replace_children(children))
+    }
+
     pub(super) async fn bind_where(
         &mut self,
         bind_context: &BindContext,
@@ -12,8 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
+use common_ast::ast::Expr;
+use common_ast::ast::Identifier;
 use common_ast::ast::Indirection;
 use common_ast::ast::SelectStmt;
 use common_ast::ast::SelectTarget;
@@ -41,9 +44,13 @@ use crate::sql::binder::Visibility;
 use crate::sql::optimizer::SExpr;
 use crate::sql::planner::semantic::normalize_identifier;
 use crate::sql::planner::semantic::TypeChecker;
+use crate::sql::plans::BoundColumnRef;
 use crate::sql::plans::ConstantExpr;
+use crate::sql::plans::EvalScalar;
+use crate::sql::plans::Flatten;
 use crate::sql::plans::LogicalGet;
 use crate::sql::plans::Scalar;
+use crate::sql::plans::ScalarItem;
 use crate::sql::BindContext;
 use crate::storages::view::view_table::QUERY;
 use crate::storages::NavigationPoint;
@@ -67,6 +74,14 @@ impl<'a> Binder {
                 }
             }
         }
+        self.bind_dummy_table(bind_context).await
+    }
+
+    /// Bind `system.one`, the single row source of queries without a FROM clause.
+    async fn bind_dummy_table(
+        &mut self,
+        bind_context: &BindContext,
+    ) -> Result<(SExpr, BindContext)> {
         let catalog = CATALOG_DEFAULT;
         let database = "system";
         let tenant = self.ctx.get_tenant();
@@ -167,10 +182,38 @@ impl<'a> Binder {
             }
             TableReference::TableFunction {
                 span: _,
+                lateral,
                 name,
                 params,
+                named_params,
                 alias,
             } => {
+                let func_name = normalize_identifier(name, &self.name_resolution_ctx).name;
+                if func_name.eq_ignore_ascii_case("flatten") {
+                    // FLATTEN without a preceding table expands a single value.
+                    let (child, child_context) = self.bind_dummy_table(bind_context).await?;
+                    let (s_expr, columns) = self
+                        .bind_flatten(child, &child_context, params, named_params, alias)
+                        .await?;
+                    let mut bind_context = child_context.replace();
+                    for column in columns {
+                        bind_context.add_column_binding(column);
+                    }
+                    return Ok((s_expr, bind_context));
+                }
+                if *lateral {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "LATERAL is only supported for FLATTEN, but got: {}",
+                        func_name
+                    )));
+                }
+                if !named_params.is_empty() {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "Table function {} doesn't support named arguments",
+                        func_name
+                    )));
+                }
+
                 let mut scalar_binder = ScalarBinder::new(
                     bind_context,
                     self.ctx.clone(),
@@ -206,10 +249,7 @@ impl<'a> Binder {
                 let table_meta: Arc<dyn TableFunction> = self
                     .catalogs
                     .get_catalog(CATALOG_DEFAULT)?
-                    .get_table_function(
-                        &normalize_identifier(name, &self.name_resolution_ctx).name,
-                        table_args,
-                    )?;
+                    .get_table_function(&func_name, table_args)?;
                 let table = table_meta.as_table();
 
                 let table_index = self.metadata.write().add_table(
@@ -324,6 +364,153 @@ impl<'a> Binder {
         ))
     }
 
+    /// Bind `FLATTEN(input => expr, path => 'a.b', outer => true)` on top of `child`. The
+    /// arguments can reference the columns of `child_context`, which is what makes
+    /// `t, LATERAL FLATTEN(input => t.v)` correlated with each row of `t`.
+    ///
+    /// Returns the new plan and the column bindings of the columns produced by flatten.
+    pub(super) async fn bind_flatten(
+        &mut self,
+        child: SExpr,
+        child_context: &BindContext,
+        params: &[Expr<'a>],
+        named_params: &[(Identifier<'a>, Expr<'a>)],
+        alias: &Option<TableAlias<'a>>,
+    ) -> Result<(SExpr, Vec<ColumnBinding>)> {
+        const ARGUMENTS: [&str; 3] = ["input", "path", "outer"];
+
+        if params.len() > ARGUMENTS.len() {
+            return Err(ErrorCode::SemanticError(format!(
+                "FLATTEN accepts at most {} arguments, but got: {}",
+                ARGUMENTS.len(),
+                params.len()
+            )));
+        }
+        let mut args: HashMap<&str, &Expr<'a>> = ARGUMENTS.into_iter().zip(params).collect();
+        for (name, expr) in named_params {
+            let name = normalize_identifier(name, &self.name_resolution_ctx)
+                .name
+                .to_lowercase();
+            let name = ARGUMENTS
+                .into_iter()
+                .find(|arg| *arg == name)
+                .ok_or_else(|| {
+                    ErrorCode::SemanticError(format!("Unknown argument of FLATTEN: {}", name))
+                })?;
+            if args.insert(name, expr).is_some() {
+                return Err(ErrorCode::SemanticError(format!(
+                    "Duplicate argument of FLATTEN: {}",
+                    name
+                )));
+            }
+        }
+        let input = *args.get("input").ok_or_else(|| {
+            ErrorCode::SemanticError("FLATTEN requires an input argument".to_string())
+        })?;
+
+        let (input_scalar, input_type, path, outer) = {
+            let mut scalar_binder = ScalarBinder::new(
+                child_context,
+                self.ctx.clone(),
+                &self.name_resolution_ctx,
+                self.metadata.clone(),
+                &[],
+            );
+            // arrays and objects are never shredded into virtual columns
+            scalar_binder.forbid_virtual_columns();
+            let (input_scalar, input_type) = scalar_binder.bind(input).await?;
+            let type_id = remove_nullable(&input_type).data_type_id();
+            if !(type_id.is_variant() || type_id.is_array() || type_id.is_map()) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "FLATTEN input must be Variant, Array or Map, but got: {}",
+                    input_type.name()
+                )));
+            }
+
+            let path = match args.get("path") {
+                Some(expr) => match scalar_binder.bind(expr).await?.0 {
+                    Scalar::ConstantExpr(ConstantExpr {
+                        value: DataValue::String(path),
+                        ..
+                    }) => Some(String::from_utf8_lossy(&path).to_string()),
+                    _ => {
+                        return Err(ErrorCode::SemanticError(
+                            "FLATTEN path must be a constant string".to_string(),
+                        ));
+                    }
+                },
+                None => None,
+            };
+
+            let outer = match args.get("outer") {
+                Some(expr) => match scalar_binder.bind(expr).await?.0 {
+                    Scalar::ConstantExpr(ConstantExpr {
+                        value: DataValue::Boolean(outer),
+                        ..
+                    }) => outer,
+                    _ => {
+                        return Err(ErrorCode::SemanticError(
+                            "FLATTEN outer must be a constant boolean".to_string(),
+                        ));
+                    }
+                },
+                None => false,
+            };
+
+            (input_scalar, input_type, path, outer)
+        };
+
+        // Flatten reads its input from a column of the child, evaluate the input
+        // expression first if it isn't one.
+        let (child, input_column) = match input_scalar {
+            Scalar::BoundColumnRef(BoundColumnRef { column })
+                if child_context
+                    .all_column_bindings()
+                    .iter()
+                    .any(|c| c.index == column.index) =>
+            {
+                (child, column.index)
+            }
+            scalar => {
+                let column = self.create_column_binding(None, None, input.to_string(), input_type);
+                let eval_scalar = EvalScalar {
+                    items: vec![ScalarItem {
+                        scalar,
+                        index: column.index,
+                    }],
+                };
+                (SExpr::create_unary(eval_scalar.into(), child), column.index)
+            }
+        };
+
+        let mut flatten_context = BindContext::new();
+        for (name, data_type) in Flatten::output_fields() {
+            let column = self.create_column_binding(
+                None,
+                Some("flatten".to_string()),
+                name.to_string(),
+                data_type,
+            );
+            flatten_context.add_column_binding(column);
+        }
+        if let Some(alias) = alias {
+            flatten_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
+        }
+
+        let columns = flatten_context.columns;
+        let flatten = Flatten {
+            input_column,
+            path,
+            outer,
+            seq_column: columns[0].index,
+            key_column: columns[1].index,
+            path_column: columns[2].index,
+            index_column: columns[3].index,
+            value_column: columns[4].index,
+        };
+        Ok((SExpr::create_unary(flatten.into(), child), columns))
+    }
+
     async fn resolve_data_source(
         &self,
         tenant: &str,
@@ -76,6 +76,7 @@ impl<'a> Binder {
             self.metadata.clone(),
             &[],
         );
+        scalar_binder.forbid_virtual_columns();
         let schema = table.schema();
         let mut update_columns = HashMap::with_capacity(update_list.len());
         for update_expr in update_list {
@@ -76,6 +76,10 @@ impl Plan {
             Plan::DropStage(s) => Ok(format!("{:?}", s)),
             Plan::RemoveStage(s) => Ok(format!("{:?}", s)),
 
+            // Pipes
+            Plan::CreatePipe(create_pipe) => Ok(format!("{:?}", create_pipe)),
+            Plan::DropPipe(s) => Ok(format!("{:?}", s)),
+
             // Account
             Plan::GrantRole(grant_role) => Ok(format!("{:?}", grant_role)),
             Plan::GrantPriv(grant_priv) => Ok(format!("{:?}", grant_priv)),
@@ -81,6 +81,7 @@ impl Display for FormatContext {
                 RelOperator::UnionAll(_) => write!(f, "Union"),
                 RelOperator::Pattern(_) => write!(f, "Pattern"),
                 RelOperator::DummyTableScan(_) => write!(f, "DummyTableScan"),
+                RelOperator::Flatten(_) => write!(f, "Flatten"),
             },
             Self::Text(text) => write!(f, "{}", text),
         }
@@ -46,7 +46,8 @@ fn compute_cost_impl(memo: &Memo, m_expr: &MExpr) -> Result<Cost> {
         | RelOperator::Filter(_)
         | RelOperator::Aggregate(_)
         | RelOperator::Sort(_)
-        | RelOperator::Limit(_) => compute_cost_unary_common_operator(memo, m_expr),
+        | RelOperator::Limit(_)
+        | RelOperator::Flatten(_) => compute_cost_unary_common_operator(memo, m_expr),
 
         _ => Err(ErrorCode::LogicalError(
             "Cannot compute cost from logical plan",
@@ -543,8 +543,8 @@ impl SubqueryRewriter {
                     flatten_plan,
                 ))
             }
-            RelOperator::Sort(_) | RelOperator::Limit(_) => {
-                // Currently, we don't support sort and limit contain subquery.
+            RelOperator::Sort(_) | RelOperator::Limit(_) | RelOperator::Flatten(_) => {
+                // Currently, we don't support sort, limit and flatten contain subquery.
                 let flatten_plan =
                     self.flatten(plan.child(0)?, correlated_columns, flatten_info)?;
                 Ok(SExpr::create_unary(plan.plan().clone(), flatten_plan))
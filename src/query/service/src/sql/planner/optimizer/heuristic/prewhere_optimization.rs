@@ -15,6 +15,7 @@
 use common_exception::Result;
 use common_planner::MetadataRef;
 
+use crate::sql::optimizer::util::contains_lambda;
 use crate::sql::optimizer::ColumnSet;
 use crate::sql::optimizer::SExpr;
 use crate::sql::plans::Filter;
@@ -100,12 +101,27 @@ impl PrewhereOptimizer {
                 // cannot optimize
                 return Ok(s_expr);
             }
+            if get
+                .columns
+                .iter()
+                .any(|index| metadata.column(*index).is_virtual_column())
+            {
+                // virtual columns are filled after the block is read, which prewhere
+                // filter can not see
+                return Ok(s_expr);
+            }
 
             let mut prewhere_columns = ColumnSet::new();
             let mut prewhere_pred = Vec::new();
+            let mut remaining_pred = Vec::new();
 
             // filter.predicates are already splited by AND
             for pred in filter.predicates.iter() {
+                // lambdas can not be evaluated by the legacy expressions of prewhere
+                if contains_lambda(pred) {
+                    remaining_pred.push(pred.clone());
+                    continue;
+                }
                 let columns = Self::collect_columns(pred);
                 prewhere_pred.push(pred.clone());
                 prewhere_columns.extend(&columns);
@@ -121,7 +137,18 @@ impl PrewhereOptimizer {
                 })
             };
 
-            Ok(SExpr::create_leaf(get.into()))
+            if remaining_pred.is_empty() {
+                Ok(SExpr::create_leaf(get.into()))
+            } else {
+                let filter = Filter {
+                    predicates: remaining_pred,
+                    is_having: filter.is_having,
+                };
+                Ok(SExpr::create_unary(
+                    filter.into(),
+                    SExpr::create_leaf(get.into()),
+                ))
+            }
         } else {
             let children = s_expr
                 .children()
@@ -220,6 +220,13 @@ impl UnusedColumnPruner {
                 RelOperator::Limit(p.clone()),
                 self.keep_required_columns(expr.child(0)?, required)?,
             )),
+            RelOperator::Flatten(p) => {
+                required.insert(p.input_column);
+                Ok(SExpr::create_unary(
+                    RelOperator::Flatten(p.clone()),
+                    self.keep_required_columns(expr.child(0)?, required)?,
+                ))
+            }
 
             RelOperator::DummyTableScan(_) | RelOperator::UnionAll(_) => Ok(expr.clone()),
 
@@ -126,10 +126,9 @@ impl SubqueryRewriter {
                 ))
             }
 
-            RelOperator::Limit(_) | RelOperator::Sort(_) => Ok(SExpr::create_unary(
-                s_expr.plan().clone(),
-                self.rewrite(s_expr.child(0)?)?,
-            )),
+            RelOperator::Limit(_) | RelOperator::Sort(_) | RelOperator::Flatten(_) => Ok(
+                SExpr::create_unary(s_expr.plan().clone(), self.rewrite(s_expr.child(0)?)?),
+            ),
 
             RelOperator::DummyTableScan(_) | RelOperator::LogicalGet(_) => Ok(s_expr.clone()),
 
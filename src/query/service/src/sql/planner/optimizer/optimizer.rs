@@ -116,6 +116,10 @@ pub fn optimize(
                     path,
                     validation_mode,
                     from,
+                    partition_by,
+                    include_query_id,
+                    overwrite,
+                    header,
                 } => {
                     CopyPlanV2::IntoStage {
                         stage,
@@ -123,6 +127,10 @@ pub fn optimize(
                         validation_mode,
                         // Make sure the subquery has been optimized.
                         from: Box::new(optimize(ctx, opt_ctx, *from)?),
+                        partition_by,
+                        include_query_id,
+                        overwrite,
+                        header,
                     }
                 }
                 into_table => into_table,
@@ -16,6 +16,7 @@ use common_exception::Result;
 
 use crate::sql::optimizer::rule::Rule;
 use crate::sql::optimizer::rule::TransformResult;
+use crate::sql::optimizer::util::contains_lambda;
 use crate::sql::optimizer::RuleID;
 use crate::sql::optimizer::SExpr;
 use crate::sql::plans::Filter;
@@ -64,7 +65,15 @@ impl Rule for RulePushDownFilterScan {
             return Ok(());
         }
 
-        get.push_down_predicates = Some(filter.predicates.clone());
+        // Predicates with lambdas stay in the filter, push down predicates are legacy expressions.
+        get.push_down_predicates = Some(
+            filter
+                .predicates
+                .iter()
+                .filter(|pred| !contains_lambda(pred))
+                .cloned()
+                .collect(),
+        );
 
         let result = SExpr::create_unary(filter.into(), SExpr::create_leaf(get.into()));
         state.add_result(result);
@@ -15,8 +15,10 @@
 use common_planner::MetadataRef;
 
 use super::SExpr;
+use crate::evaluator::is_lambda_function;
 use crate::sql::plans::JoinType;
 use crate::sql::plans::RelOperator;
+use crate::sql::plans::Scalar;
 
 /// Check if a query will read data from local tables(e.g. system tables).
 pub fn contains_local_table_scan(s_expr: &SExpr, metadata: &MetadataRef) -> bool {
@@ -50,6 +52,23 @@ pub fn validate_distributed_query(s_expr: &SExpr) -> bool {
                 | JoinType::RightMark
                 | JoinType::Single => false,
             },
+            // The `seq` column generated by flatten is only unique within a node.
+            RelOperator::Flatten(_) => false,
             _ => true,
         }
 }
+
+/// Check if a scalar calls a lambda function, which can only be evaluated by the
+/// `Evaluator`, not by the legacy expressions of prewhere and push down predicates.
+pub fn contains_lambda(scalar: &Scalar) -> bool {
+    match scalar {
+        Scalar::FunctionCall(func) => {
+            is_lambda_function(&func.func_name) || func.arguments.iter().any(contains_lambda)
+        }
+        Scalar::AndExpr(and) => contains_lambda(&and.left) || contains_lambda(&and.right),
+        Scalar::OrExpr(or) => contains_lambda(&or.left) || contains_lambda(&or.right),
+        Scalar::ComparisonExpr(cmp) => contains_lambda(&cmp.left) || contains_lambda(&cmp.right),
+        Scalar::CastExpr(cast) => contains_lambda(&cast.argument),
+        _ => false,
+    }
+}
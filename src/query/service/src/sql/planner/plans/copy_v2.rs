@@ -15,8 +15,9 @@
 use std::fmt::Debug;
 use std::fmt::Formatter;
 use std::str::FromStr;
+use std::sync::Arc;
 
-use common_datavalues::DataSchemaRef;
+use common_datavalues::prelude::*;
 use common_legacy_planners::ReadDataSourcePlan;
 use common_meta_types::MetaId;
 use common_meta_types::UserStageInfo;
@@ -73,9 +74,47 @@ pub enum CopyPlanV2 {
         path: String,
         validation_mode: ValidationMode,
         from: Box<Plan>,
+        /// Names of the `PARTITION BY` expressions, projected after the columns of `from`.
+        partition_by: Vec<String>,
+        include_query_id: bool,
+        overwrite: bool,
+        header: bool,
     },
 }
 
+impl CopyPlanV2 {
+    /// `COPY INTO <location>` returns the files it wrote.
+    /// The validation modes of `COPY INTO <table>` return the parsed rows
+    /// in the target schema, or the errors found in the files.
+    pub fn schema(&self) -> DataSchemaRef {
+        match self {
+            CopyPlanV2::IntoTable {
+                schema,
+                validation_mode,
+                ..
+            } => match validation_mode {
+                ValidationMode::None => Arc::new(DataSchema::empty()),
+                ValidationMode::ReturnNRows(_) => schema.clone(),
+                ValidationMode::ReturnErrors | ValidationMode::ReturnAllErrors => {
+                    let file = DataField::new("file", Vu8::to_data_type());
+                    let line = DataField::new("line", u64::to_data_type());
+                    let column_name = DataField::new_nullable("column_name", Vu8::to_data_type());
+                    let error = DataField::new("error", Vu8::to_data_type());
+
+                    Arc::new(DataSchema::new(vec![file, line, column_name, error]))
+                }
+            },
+            CopyPlanV2::IntoStage { .. } => {
+                let file_name = DataField::new("file_name", Vu8::to_data_type());
+                let file_size = DataField::new("file_size", u64::to_data_type());
+                let row_count = DataField::new("row_count", u64::to_data_type());
+
+                Arc::new(DataSchema::new(vec![file_name, file_size, row_count]))
+            }
+        }
+    }
+}
+
 impl Debug for CopyPlanV2 {
     // Ignore the schema.
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
@@ -106,11 +145,15 @@ impl Debug for CopyPlanV2 {
                 stage,
                 path,
                 validation_mode,
+                partition_by,
                 ..
             } => {
                 write!(f, "Copy into {:?}", stage)?;
                 write!(f, ", path: {:?}", path)?;
                 write!(f, ", validation_mode: {:?}", validation_mode)?;
+                if !partition_by.is_empty() {
+                    write!(f, ", partition_by: {:?}", partition_by)?;
+                }
             }
         }
         Ok(())
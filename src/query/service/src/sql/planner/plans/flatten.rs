@@ -0,0 +1,134 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_planner::IndexType;
+
+use crate::sql::optimizer::PhysicalProperty;
+use crate::sql::optimizer::RelExpr;
+use crate::sql::optimizer::RelationalProperty;
+use crate::sql::optimizer::RequiredProperty;
+use crate::sql::plans::LogicalOperator;
+use crate::sql::plans::Operator;
+use crate::sql::plans::PhysicalOperator;
+use crate::sql::plans::RelOp;
+
+/// Expand the array or object in `input_column` into rows, one row per element,
+/// used by `LATERAL FLATTEN(input => ...)`. The other columns of the input row
+/// are repeated for every element.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Flatten {
+    pub input_column: IndexType,
+    // Path of the element to flatten inside the input value
+    pub path: Option<String>,
+    // Produce a row with NULLs for an input that has no elements
+    pub outer: bool,
+
+    pub seq_column: IndexType,
+    pub key_column: IndexType,
+    pub path_column: IndexType,
+    pub index_column: IndexType,
+    pub value_column: IndexType,
+}
+
+impl Flatten {
+    /// Names and types of the columns produced by flatten, in output order.
+    pub fn output_fields() -> [(&'static str, DataTypeImpl); 5] {
+        [
+            ("seq", u64::to_data_type()),
+            ("key", NullableType::new_impl(StringType::new_impl())),
+            ("path", NullableType::new_impl(StringType::new_impl())),
+            ("index", NullableType::new_impl(u64::to_data_type())),
+            ("value", NullableType::new_impl(VariantType::new_impl())),
+        ]
+    }
+
+    pub fn output_columns(&self) -> [IndexType; 5] {
+        [
+            self.seq_column,
+            self.key_column,
+            self.path_column,
+            self.index_column,
+            self.value_column,
+        ]
+    }
+}
+
+impl Operator for Flatten {
+    fn rel_op(&self) -> RelOp {
+        RelOp::Flatten
+    }
+
+    fn is_physical(&self) -> bool {
+        true
+    }
+
+    fn is_logical(&self) -> bool {
+        true
+    }
+
+    fn as_physical(&self) -> Option<&dyn PhysicalOperator> {
+        Some(self)
+    }
+
+    fn as_logical(&self) -> Option<&dyn LogicalOperator> {
+        Some(self)
+    }
+}
+
+impl PhysicalOperator for Flatten {
+    fn derive_physical_prop<'a>(&self, rel_expr: &RelExpr<'a>) -> Result<PhysicalProperty> {
+        rel_expr.derive_physical_prop_child(0)
+    }
+
+    fn compute_required_prop_child<'a>(
+        &self,
+        _rel_expr: &RelExpr<'a>,
+        _child_index: usize,
+        required: &RequiredProperty,
+    ) -> Result<RequiredProperty> {
+        Ok(required.clone())
+    }
+}
+
+impl LogicalOperator for Flatten {
+    fn derive_relational_prop<'a>(&self, rel_expr: &RelExpr<'a>) -> Result<RelationalProperty> {
+        let input_prop = rel_expr.derive_relational_prop_child(0)?;
+
+        // Derive output columns
+        let mut output_columns = input_prop.output_columns;
+        output_columns.extend(self.output_columns());
+
+        // Derive outer columns
+        let outer_columns = input_prop
+            .outer_columns
+            .difference(&output_columns)
+            .cloned()
+            .collect();
+
+        // The number of elements is unknown, so we can only guess the cardinality
+        // is the same as input.
+        let cardinality = input_prop.cardinality;
+
+        Ok(RelationalProperty {
+            output_columns,
+            outer_columns,
+            cardinality,
+            precise_cardinality: None,
+
+            column_stats: Default::default(),
+        })
+    }
+}
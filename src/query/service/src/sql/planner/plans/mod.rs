@@ -19,6 +19,7 @@ mod dummy_table_scan;
 mod eval_scalar;
 mod exchange;
 mod filter;
+mod flatten;
 mod hash_join;
 pub mod insert;
 mod limit;
@@ -42,6 +43,7 @@ pub use dummy_table_scan::DummyTableScan;
 pub use eval_scalar::*;
 pub use exchange::*;
 pub use filter::*;
+pub use flatten::Flatten;
 pub use hash_join::PhysicalHashJoin;
 pub use insert::Insert;
 pub use insert::InsertInputSource;
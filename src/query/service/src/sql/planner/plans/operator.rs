@@ -19,6 +19,7 @@ use super::aggregate::Aggregate;
 use super::dummy_table_scan::DummyTableScan;
 use super::eval_scalar::EvalScalar;
 use super::filter::Filter;
+use super::flatten::Flatten;
 use super::hash_join::PhysicalHashJoin;
 use super::limit::Limit;
 use super::logical_get::LogicalGet;
@@ -84,6 +85,7 @@ pub enum RelOp {
     Exchange,
     UnionAll,
     DummyTableScan,
+    Flatten,
 
     // Pattern
     Pattern,
@@ -106,6 +108,7 @@ pub enum RelOperator {
     Exchange(Exchange),
     UnionAll(UnionAll),
     DummyTableScan(DummyTableScan),
+    Flatten(Flatten),
 
     Pattern(PatternPlan),
 }
@@ -126,6 +129,7 @@ impl Operator for RelOperator {
             RelOperator::Exchange(rel_op) => rel_op.rel_op(),
             RelOperator::UnionAll(rel_op) => rel_op.rel_op(),
             RelOperator::DummyTableScan(rel_op) => rel_op.rel_op(),
+            RelOperator::Flatten(rel_op) => rel_op.rel_op(),
         }
     }
 
@@ -144,6 +148,7 @@ impl Operator for RelOperator {
             RelOperator::Exchange(rel_op) => rel_op.is_physical(),
             RelOperator::UnionAll(rel_op) => rel_op.is_physical(),
             RelOperator::DummyTableScan(rel_op) => rel_op.is_physical(),
+            RelOperator::Flatten(rel_op) => rel_op.is_physical(),
         }
     }
 
@@ -162,6 +167,7 @@ impl Operator for RelOperator {
             RelOperator::Exchange(rel_op) => rel_op.is_logical(),
             RelOperator::UnionAll(rel_op) => rel_op.is_logical(),
             RelOperator::DummyTableScan(rel_op) => rel_op.is_logical(),
+            RelOperator::Flatten(rel_op) => rel_op.is_logical(),
         }
     }
 
@@ -180,6 +186,7 @@ impl Operator for RelOperator {
             RelOperator::Exchange(rel_op) => rel_op.as_logical(),
             RelOperator::UnionAll(rel_op) => rel_op.as_logical(),
             RelOperator::DummyTableScan(rel_op) => rel_op.as_logical(),
+            RelOperator::Flatten(rel_op) => rel_op.as_logical(),
         }
     }
 
@@ -198,6 +205,7 @@ impl Operator for RelOperator {
             RelOperator::Exchange(rel_op) => rel_op.as_physical(),
             RelOperator::UnionAll(rel_op) => rel_op.as_physical(),
             RelOperator::DummyTableScan(rel_op) => rel_op.as_physical(),
+            RelOperator::Flatten(rel_op) => rel_op.as_physical(),
         }
     }
 }
@@ -449,3 +457,22 @@ impl TryFrom<RelOperator> for DummyTableScan {
         }
     }
 }
+
+impl From<Flatten> for RelOperator {
+    fn from(v: Flatten) -> Self {
+        Self::Flatten(v)
+    }
+}
+
+impl TryFrom<RelOperator> for Flatten {
+    type Error = ErrorCode;
+    fn try_from(value: RelOperator) -> Result<Self> {
+        if let RelOperator::Flatten(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::LogicalError(
+                "Cannot downcast RelOperator to Flatten",
+            ))
+        }
+    }
+}
@@ -29,6 +29,7 @@ use common_planner::plans::AlterUserPlan;
 use common_planner::plans::AlterViewPlan;
 use common_planner::plans::CallPlan;
 use common_planner::plans::CreateDatabasePlan;
+use common_planner::plans::CreatePipePlan;
 use common_planner::plans::CreateRolePlan;
 use common_planner::plans::CreateStagePlan;
 use common_planner::plans::CreateUDFPlan;
@@ -36,6 +37,7 @@ use common_planner::plans::CreateUserPlan;
 use common_planner::plans::CreateViewPlan;
 use common_planner::plans::DescribeTablePlan;
 use common_planner::plans::DropDatabasePlan;
+use common_planner::plans::DropPipePlan;
 use common_planner::plans::DropRolePlan;
 use common_planner::plans::DropStagePlan;
 use common_planner::plans::DropTableClusterKeyPlan;
@@ -165,6 +167,10 @@ pub enum Plan {
     DropStage(Box<DropStagePlan>),
     RemoveStage(Box<RemoveStagePlan>),
 
+    // Pipes
+    CreatePipe(Box<CreatePipePlan>),
+    DropPipe(Box<DropPipePlan>),
+
     // Presign
     Presign(Box<PresignPlan>),
 
@@ -239,6 +245,8 @@ impl Display for Plan {
             Plan::CreateStage(_) => write!(f, "CreateStage"),
             Plan::DropStage(_) => write!(f, "DropStage"),
             Plan::RemoveStage(_) => write!(f, "RemoveStage"),
+            Plan::CreatePipe(_) => write!(f, "CreatePipe"),
+            Plan::DropPipe(_) => write!(f, "DropPipe"),
             Plan::GrantRole(_) => write!(f, "GrantRole"),
             Plan::GrantPriv(_) => write!(f, "GrantPriv"),
             Plan::ShowGrants(_) => write!(f, "ShowGrants"),
@@ -282,7 +290,7 @@ impl Plan {
             Plan::Explain { .. } | Plan::ExplainAst { .. } | Plan::ExplainSyntax { .. } => {
                 DataSchemaRefExt::create(vec![DataField::new("explain", StringType::new_impl())])
             }
-            Plan::Copy(_) => Arc::new(DataSchema::empty()),
+            Plan::Copy(plan) => plan.schema(),
             Plan::ShowCreateDatabase(plan) => plan.schema(),
             Plan::CreateDatabase(plan) => plan.schema(),
             Plan::UseDatabase(_) => Arc::new(DataSchema::empty()),
@@ -316,6 +324,8 @@ impl Plan {
             Plan::CreateStage(plan) => plan.schema(),
             Plan::DropStage(plan) => plan.schema(),
             Plan::RemoveStage(plan) => plan.schema(),
+            Plan::CreatePipe(plan) => plan.schema(),
+            Plan::DropPipe(plan) => plan.schema(),
             Plan::RevokePriv(_) => Arc::new(DataSchema::empty()),
             Plan::RevokeRole(_) => Arc::new(DataSchema::empty()),
             Plan::CreateUDF(_) => Arc::new(DataSchema::empty()),
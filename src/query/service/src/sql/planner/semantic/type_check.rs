@@ -20,8 +20,10 @@ use common_ast::ast::BinaryOperator;
 use common_ast::ast::Expr;
 use common_ast::ast::Identifier;
 use common_ast::ast::IntervalKind as ASTIntervalKind;
+use common_ast::ast::Lambda;
 use common_ast::ast::Literal;
 use common_ast::ast::MapAccessor;
+use common_ast::ast::OrderByExpr;
 use common_ast::ast::Query;
 use common_ast::ast::SubqueryModifier;
 use common_ast::ast::TrimWhere;
@@ -32,7 +34,9 @@ use common_ast::parser::tokenize_sql;
 use common_ast::Backtrace;
 use common_ast::DisplayError;
 use common_catalog::catalog::CatalogManager;
+use common_datavalues::remove_nullable;
 use common_datavalues::type_coercion::merge_types;
+use common_datavalues::wrap_nullable;
 use common_datavalues::ArrayType;
 use common_datavalues::DataField;
 use common_datavalues::DataType;
@@ -46,6 +50,8 @@ use common_datavalues::StringType;
 use common_datavalues::StructType;
 use common_datavalues::TimestampType;
 use common_datavalues::TypeID;
+use common_datavalues::UInt64Type;
+use common_datavalues::VariantType;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_functions::aggregates::AggregateFunctionFactory;
@@ -60,11 +66,17 @@ use common_users::UserApiProvider;
 use super::name_resolution::NameResolutionContext;
 use super::normalize_identifier;
 use crate::catalogs::CatalogManagerHelper;
+use crate::evaluator::is_lambda_function;
+use crate::evaluator::lambda_param_types;
+use crate::evaluator::lambda_return_type;
 use crate::evaluator::Evaluator;
+use crate::evaluator::LAMBDA_PARAM_FUNCTION;
 use crate::sessions::TableContext;
 use crate::sql::binder::wrap_cast_if_needed;
 use crate::sql::binder::Binder;
+use crate::sql::binder::ColumnBinding;
 use crate::sql::binder::NameResolutionResult;
+use crate::sql::binder::Visibility;
 use crate::sql::optimizer::RelExpr;
 use crate::sql::planner::metadata::optimize_remove_count_args;
 use crate::sql::plans::AggregateFunction;
@@ -102,6 +114,14 @@ pub struct TypeChecker<'a> {
     // true if current expr is inside an aggregate function.
     // This is used to check if there is nested aggregate function.
     in_aggregate_function: bool,
+
+    // true if object paths of variant columns in fuse tables can be resolved
+    // into virtual columns, which are read from the shredded sub-columns.
+    allow_virtual_columns: bool,
+
+    // Names and types of the parameters if current expr is inside a lambda body,
+    // which can only refer to these parameters.
+    lambda_params: Option<Vec<(String, DataTypeImpl)>>,
 }
 
 impl<'a> TypeChecker<'a> {
@@ -119,9 +139,15 @@ impl<'a> TypeChecker<'a> {
             metadata,
             aliases,
             in_aggregate_function: false,
+            allow_virtual_columns: true,
+            lambda_params: None,
         }
     }
 
+    pub fn set_allow_virtual_columns(&mut self, allow_virtual_columns: bool) {
+        self.allow_virtual_columns = allow_virtual_columns;
+    }
+
     fn post_resolve(
         &mut self,
         scalar: &Scalar,
@@ -157,6 +183,15 @@ impl<'a> TypeChecker<'a> {
         expr: &Expr<'_>,
         required_type: Option<DataTypeImpl>,
     ) -> Result<Box<(Scalar, DataTypeImpl)>> {
+        if self.allow_virtual_columns
+            && !self.bind_context.forbid_virtual_columns
+            && self.lambda_params.is_none()
+        {
+            if let Some(resolved) = self.try_resolve_virtual_column(expr).await? {
+                return Ok(resolved);
+            }
+        }
+
         let box (scalar, data_type): Box<(Scalar, DataTypeImpl)> = match expr {
             Expr::ColumnRef {
                 database,
@@ -171,6 +206,35 @@ impl<'a> TypeChecker<'a> {
                     .as_ref()
                     .map(|ident| normalize_identifier(ident, self.name_resolution_ctx).name);
                 let column = normalize_identifier(ident, self.name_resolution_ctx).name;
+                if let Some(params) = &self.lambda_params {
+                    let index = params
+                        .iter()
+                        .position(|(name, _)| {
+                            database.is_none() && table.is_none() && name == &column
+                        })
+                        .ok_or_else(|| {
+                            ErrorCode::SemanticError(expr.span().display_error(format!(
+                                "lambda functions can only refer to their parameters, but got column `{column}`, captured columns are not supported"
+                            )))
+                        })?;
+                    let data_type = params[index].1.clone();
+                    return Ok(Box::new((
+                        FunctionCall {
+                            arguments: vec![
+                                ConstantExpr {
+                                    value: DataValue::UInt64(index as u64),
+                                    data_type: Box::new(UInt64Type::new_impl()),
+                                }
+                                .into(),
+                            ],
+                            func_name: LAMBDA_PARAM_FUNCTION.to_string(),
+                            arg_types: vec![UInt64Type::new_impl()],
+                            return_type: Box::new(data_type.clone()),
+                        }
+                        .into(),
+                        data_type,
+                    )));
+                }
                 let result = self.bind_context.resolve_name(
                     database.as_deref(),
                     table.as_deref(),
@@ -212,6 +276,8 @@ impl<'a> TypeChecker<'a> {
                             },
                             args: vec![(args[0]).clone()],
                             params: vec![],
+                            order_by: vec![],
+                            lambda: None,
                         }],
                         None,
                     )
@@ -316,6 +382,8 @@ impl<'a> TypeChecker<'a> {
                                 },
                                 args: args.iter().copied().cloned().collect(),
                                 params: vec![],
+                                order_by: vec![],
+                                lambda: None,
                             }],
                             None,
                         )
@@ -516,6 +584,8 @@ impl<'a> TypeChecker<'a> {
                                 },
                                 args: vec![*operand.clone(), c.clone()],
                                 params: vec![],
+                                order_by: vec![],
+                                lambda: None,
                             };
                             arguments.push(equal_expr)
                         }
@@ -571,9 +641,21 @@ impl<'a> TypeChecker<'a> {
                 name,
                 args,
                 params,
-                ..
+                order_by,
+                lambda,
             } => {
                 let func_name = name.name.as_str();
+                if !order_by.is_empty() && !AggregateFunctionFactory::instance().check(func_name) {
+                    return Err(ErrorCode::SemanticError(span.display_error(format!(
+                        "ORDER BY is only supported in aggregate functions, but got {func_name}"
+                    ))));
+                }
+                if let Some(lambda) = lambda {
+                    return self
+                        .resolve_lambda_function(span, func_name, args, lambda)
+                        .await;
+                }
+
                 if !is_builtin_function(func_name)
                     && !Self::is_rewritable_scalar_function(func_name)
                 {
@@ -583,6 +665,7 @@ impl<'a> TypeChecker<'a> {
                 let args: Vec<&Expr> = args.iter().collect();
 
                 if AggregateFunctionFactory::instance().check(func_name) {
+                    self.check_not_in_lambda(span, "aggregate functions")?;
                     if self.in_aggregate_function {
                         // Reset the state
                         self.in_aggregate_function = false;
@@ -592,7 +675,7 @@ impl<'a> TypeChecker<'a> {
                     }
 
                     // Check aggregate function
-                    let params = params
+                    let mut params = params
                         .iter()
                         .map(|literal| {
                             self.resolve_literal(literal, None)
@@ -600,11 +683,59 @@ impl<'a> TypeChecker<'a> {
                         })
                         .collect::<Result<Vec<DataValue>>>()?;
 
+                    // The separator of `string_agg(expr, sep)` is passed as a parameter
+                    let mut args = args.clone();
+                    let lowercase_name = func_name.to_lowercase();
+                    if (lowercase_name.starts_with("string_agg")
+                        || lowercase_name.starts_with("listagg"))
+                        && params.is_empty()
+                    {
+                        let separator = match args.get(1) {
+                            Some(Expr::Literal {
+                                lit: Literal::String(separator),
+                                ..
+                            }) => {
+                                let separator = separator.clone();
+                                args.remove(1);
+                                separator
+                            }
+                            Some(_)
+                                if lowercase_name == "string_agg"
+                                    || lowercase_name == "listagg" =>
+                            {
+                                return Err(ErrorCode::SemanticError(span.display_error(format!(
+                                    "The separator of {func_name} must be a constant string"
+                                ))));
+                            }
+                            _ => String::new(),
+                        };
+                        params.push(DataValue::String(separator.into_bytes()));
+                    }
+
+                    // `ORDER BY` keys are passed as trailing arguments, each one with
+                    // a `(asc, nulls_first)` parameter
+                    if !order_by.is_empty() {
+                        if !AggregateFunctionFactory::instance().allows_order_by(func_name) {
+                            return Err(ErrorCode::SemanticError(span.display_error(format!(
+                                "ORDER BY is not supported in aggregate function {func_name}"
+                            ))));
+                        }
+                        for order in order_by.iter() {
+                            params.push(DataValue::Struct(vec![
+                                DataValue::Boolean(order.asc.unwrap_or(true)),
+                                DataValue::Boolean(order.nulls_first.unwrap_or(false)),
+                            ]));
+                        }
+                    }
+
                     self.in_aggregate_function = true;
                     let mut arguments = vec![];
                     for arg in args.iter() {
                         arguments.push(self.resolve(arg, None).await?);
                     }
+                    for order in order_by.iter() {
+                        arguments.push(self.resolve(&order.expr, None).await?);
+                    }
                     self.in_aggregate_function = false;
 
                     let data_fields = arguments
@@ -674,6 +805,7 @@ impl<'a> TypeChecker<'a> {
             }
 
             Expr::Exists { subquery, not, .. } => {
+                self.check_not_in_lambda(expr.span(), "subqueries")?;
                 self.resolve_subquery(
                     if !*not {
                         SubqueryType::Exists
@@ -690,6 +822,7 @@ impl<'a> TypeChecker<'a> {
             }
 
             Expr::Subquery { subquery, .. } => {
+                self.check_not_in_lambda(expr.span(), "subqueries")?;
                 self.resolve_subquery(SubqueryType::Scalar, subquery, false, None, None, None)
                     .await?
             }
@@ -700,6 +833,7 @@ impl<'a> TypeChecker<'a> {
                 expr,
                 span,
             } => {
+                self.check_not_in_lambda(span, "subqueries")?;
                 // Not in subquery will be transformed to not(Expr = Any(...))
                 if *not {
                     return self
@@ -887,6 +1021,101 @@ impl<'a> TypeChecker<'a> {
         Ok(Box::new(self.post_resolve(&scalar, &data_type)?))
     }
 
+    fn check_not_in_lambda(&self, span: &[Token<'_>], what: &str) -> Result<()> {
+        if self.lambda_params.is_some() {
+            return Err(ErrorCode::SemanticError(span.display_error(format!(
+                "{what} are not allowed in lambda functions"
+            ))));
+        }
+        Ok(())
+    }
+
+    /// Resolve a function taking a lambda, which is lowered into `func(array, body)`
+    /// with the parameters of the body bound to `lambda_param(index)`.
+    #[async_recursion::async_recursion]
+    async fn resolve_lambda_function(
+        &mut self,
+        span: &[Token<'_>],
+        func_name: &str,
+        args: &[Expr<'_>],
+        lambda: &Lambda<'_>,
+    ) -> Result<Box<(Scalar, DataTypeImpl)>> {
+        if !is_lambda_function(func_name) {
+            return Err(ErrorCode::SemanticError(span.display_error(format!(
+                "function {func_name} does not accept a lambda"
+            ))));
+        }
+        if args.len() != 1 {
+            return Err(ErrorCode::SemanticError(span.display_error(format!(
+                "{func_name} requires an array argument and a lambda, but got {} arguments",
+                args.len()
+            ))));
+        }
+        let num_params = if func_name == "array_reduce" { 2 } else { 1 };
+        if lambda.params.len() != num_params {
+            return Err(ErrorCode::SemanticError(span.display_error(format!(
+                "the lambda of {func_name} requires {num_params} parameter(s), but got {}",
+                lambda.params.len()
+            ))));
+        }
+
+        let box (array, array_type) = self.resolve(&args[0], None).await?;
+        let mut param_types = lambda_param_types(func_name, &array_type, None)
+            .map_err(|e| ErrorCode::SemanticError(span.display_error(e.message())))?;
+        let param_names = lambda
+            .params
+            .iter()
+            .map(|param| normalize_identifier(param, self.name_resolution_ctx).name)
+            .collect::<Vec<_>>();
+
+        let (body, body_type) = loop {
+            let params = param_names
+                .iter()
+                .cloned()
+                .zip(param_types.iter().cloned())
+                .collect();
+            let outer_params = self.lambda_params.replace(params);
+            let result = self.resolve(&lambda.expr, None).await;
+            self.lambda_params = outer_params;
+            let box (body, body_type) = result?;
+
+            if func_name != "array_reduce" || body_type == param_types[0] {
+                break (body, body_type);
+            }
+            // Widen the accumulator of array_reduce until the body returns its type.
+            let acc_type = merge_types(&param_types[0], &body_type)?;
+            if acc_type == param_types[0] {
+                break (wrap_cast_if_needed(body, &acc_type), acc_type);
+            }
+            param_types[0] = acc_type;
+        };
+
+        if func_name == "array_filter"
+            && remove_nullable(&body_type).data_type_id() != TypeID::Boolean
+        {
+            return Err(ErrorCode::SemanticError(span.display_error(format!(
+                "the lambda of {func_name} must return a boolean, but got {}",
+                body_type.name()
+            ))));
+        }
+
+        let mut return_type = lambda_return_type(func_name, &array_type, &body_type)?;
+        if array_type.is_nullable() {
+            return_type = wrap_nullable(&return_type);
+        }
+
+        Ok(Box::new((
+            FunctionCall {
+                arguments: vec![array, body],
+                func_name: func_name.to_string(),
+                arg_types: vec![array_type, body_type],
+                return_type: Box::new(return_type.clone()),
+            }
+            .into(),
+            return_type,
+        )))
+    }
+
     /// Resolve function call.
     #[async_recursion::async_recursion]
     pub async fn resolve_function(
@@ -1576,6 +1805,8 @@ impl<'a> TypeChecker<'a> {
                         },
                         args: vec![(*arg).clone()],
                         params: vec![],
+                        order_by: vec![],
+                        lambda: None,
                     };
 
                     new_args.push(is_not_null_expr);
@@ -1869,6 +2100,90 @@ impl<'a> TypeChecker<'a> {
         Ok(Box::new((scalar, data_type)))
     }
 
+    /// Resolve `v:a.b`, `v['a']['b']` and `get_path(v, 'a.b')` on a variant column of a
+    /// fuse table, optionally wrapped in a cast, into a virtual column. Returns `None`
+    /// if the expression can not be read from a virtual column.
+    #[async_recursion::async_recursion]
+    async fn try_resolve_virtual_column(
+        &mut self,
+        expr: &Expr<'async_recursion>,
+    ) -> Result<Option<Box<(Scalar, DataTypeImpl)>>> {
+        let (path_expr, target_type) = match expr {
+            Expr::Cast {
+                expr, target_type, ..
+            } => (expr.as_ref(), Some(target_type)),
+            _ => (expr, None),
+        };
+        let (source_expr, path) = match virtual_column_path(path_expr) {
+            Some(v) => v,
+            None => return Ok(None),
+        };
+
+        let source = match self.resolve(source_expr, None).await? {
+            box (Scalar::BoundColumnRef(BoundColumnRef { column }), _) => column,
+            _ => return Ok(None),
+        };
+        {
+            let metadata = self.metadata.read();
+            let entry = match metadata.columns().get(source.index) {
+                Some(entry) => entry,
+                None => return Ok(None),
+            };
+            let table_index = match entry.table_index() {
+                Some(table_index) => table_index,
+                None => return Ok(None),
+            };
+            if entry.has_path_indices()
+                || entry.is_virtual_column()
+                || !remove_nullable(entry.data_type())
+                    .data_type_id()
+                    .is_variant()
+                || !metadata
+                    .table(table_index)
+                    .table()
+                    .engine()
+                    .eq_ignore_ascii_case("FUSE")
+            {
+                return Ok(None);
+            }
+        }
+
+        // same as the return type of `get_path`
+        let path_type = NullableType::new_impl(VariantType::new_impl());
+        let (name, data_type) = match target_type {
+            Some(target_type) => {
+                let target_type = target_type.to_string();
+                let cast_func = CastFunction::create("", &target_type, path_type)?;
+                (
+                    format!("{}:{}::{}", source.column_name, path.join("."), target_type),
+                    cast_func.return_type(),
+                )
+            }
+            None => (
+                format!("{}:{}", source.column_name, path.join(".")),
+                path_type,
+            ),
+        };
+        let index = self.metadata.write().add_virtual_column(
+            source.index,
+            path,
+            name.clone(),
+            data_type.clone(),
+        );
+        let column = ColumnBinding {
+            database_name: source.database_name.clone(),
+            table_name: source.table_name.clone(),
+            column_name: name,
+            index,
+            data_type: Box::new(data_type.clone()),
+            visibility: Visibility::InVisible,
+        };
+        Ok(Some(Box::new((
+            BoundColumnRef { column }.into(),
+            data_type,
+        ))))
+    }
+
     #[allow(clippy::only_used_in_recursion)]
     fn clone_expr_with_replacement<F>(
         &self,
@@ -2046,6 +2361,8 @@ impl<'a> TypeChecker<'a> {
                     name,
                     args,
                     params,
+                    order_by,
+                    lambda,
                 } => Ok(Expr::FunctionCall {
                     span,
                     distinct: *distinct,
@@ -2055,6 +2372,28 @@ impl<'a> TypeChecker<'a> {
                         .map(|arg| self.clone_expr_with_replacement(arg, replacement_fn))
                         .collect::<Result<Vec<Expr>>>()?,
                     params: params.clone(),
+                    order_by: order_by
+                        .iter()
+                        .map(|order_by| {
+                            Ok(OrderByExpr {
+                                expr: self
+                                    .clone_expr_with_replacement(&order_by.expr, replacement_fn)?,
+                                asc: order_by.asc,
+                                nulls_first: order_by.nulls_first,
+                            })
+                        })
+                        .collect::<Result<Vec<OrderByExpr>>>()?,
+                    lambda: lambda
+                        .as_ref()
+                        .map(|lambda| {
+                            Ok(Lambda {
+                                params: lambda.params.clone(),
+                                expr: Box::new(
+                                    self.clone_expr_with_replacement(&lambda.expr, replacement_fn)?,
+                                ),
+                            })
+                        })
+                        .transpose()?,
                 }),
                 Expr::Case {
                     span,
@@ -2134,3 +2473,57 @@ impl<'a> TypeChecker<'a> {
         }
     }
 }
+
+/// Split an object path on a column, such as `v:a.b`, `v['a']['b']` or
+/// `get_path(v, 'a.b')`, into the column and the keys of the path.
+fn virtual_column_path<'e, 'x>(expr: &'e Expr<'x>) -> Option<(&'e Expr<'x>, Vec<String>)> {
+    let (source, path) = match expr {
+        Expr::MapAccess { .. } => {
+            let mut path = vec![];
+            let mut expr = expr;
+            while let Expr::MapAccess {
+                expr: inner,
+                accessor,
+                ..
+            } = expr
+            {
+                let key = match accessor {
+                    MapAccessor::Period { key } | MapAccessor::Colon { key } => key.name.clone(),
+                    MapAccessor::Bracket {
+                        key: Literal::String(key),
+                    } => key.clone(),
+                    _ => return None,
+                };
+                path.push(key);
+                expr = inner.as_ref();
+            }
+            path.reverse();
+            (expr, path)
+        }
+        Expr::FunctionCall {
+            distinct: false,
+            name,
+            args,
+            params,
+            ..
+        } if name.name.eq_ignore_ascii_case("get_path") && params.is_empty() && args.len() == 2 => {
+            match &args[1] {
+                Expr::Literal {
+                    lit: Literal::String(path),
+                    ..
+                } => (&args[0], path.split('.').map(|k| k.to_string()).collect()),
+                _ => return None,
+            }
+        }
+        _ => return None,
+    };
+
+    // keys which are not shredded by the fuse writer are never read from virtual columns
+    let valid = path
+        .iter()
+        .all(|key| !key.is_empty() && !key.contains(['.', ':', '[', ']', '"', '\'']));
+    match source {
+        Expr::ColumnRef { .. } if valid => Some((source, path)),
+        _ => None,
+    }
+}
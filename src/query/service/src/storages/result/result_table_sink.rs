@@ -190,6 +190,7 @@ impl Processor for ResultTableSink {
                     block_statistics,
                     bloom_index_location,
                     bloom_index_size,
+                    None,
                 )?;
 
                 self.state = State::Serialized {
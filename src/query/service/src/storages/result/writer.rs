@@ -119,7 +119,7 @@ impl ResultTableWriter {
             })
             .await?;
         self.accumulator
-            .add_block(size, meta_data, block_statistics, None, 0)?;
+            .add_block(size, meta_data, block_statistics, None, 0, None)?;
         Ok(self.get_last_part_info())
     }
 
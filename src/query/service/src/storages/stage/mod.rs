@@ -12,7 +12,12 @@
 //  See the License for the specific language governing permissions and
 //  limitations under the License.
 
+mod stage_partition_sink;
 mod stage_table;
 mod stage_table_sink;
+mod stage_unload;
 
 pub use stage_table::StageTable;
+pub use stage_unload::StageUnloadOptions;
+pub use stage_unload::UnloadManifest;
+pub use stage_unload::UnloadedFile;
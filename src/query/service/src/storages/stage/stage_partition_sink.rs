@@ -0,0 +1,306 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use async_trait::unboxed_simple;
+use common_datablocks::DataBlock;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::DataType;
+use common_datavalues::TypeSerializer;
+use common_exception::Result;
+use common_formats::output_format::OutputFormat;
+use common_formats::output_format::OutputFormatType;
+use common_io::prelude::FormatSettings;
+use common_legacy_planners::StageTableInfo;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use opendal::Operator;
+
+use super::stage_unload::unload_file_path;
+use super::stage_unload::unload_format;
+use super::stage_unload::write_unloaded_file;
+use super::stage_unload::UnloadManifest;
+use super::stage_unload::UnloadedFile;
+use crate::pipelines::processors::AsyncSink;
+use crate::pipelines::processors::AsyncSinker;
+use crate::sessions::TableContext;
+
+/// Directory name of the NULL partition values, the same as Hive.
+const DEFAULT_PARTITION: &str = "__HIVE_DEFAULT_PARTITION__";
+
+/// Max number of partitions buffering rows at the same time, the largest one
+/// is flushed into a file when exceeded.
+const MAX_OPEN_PARTITIONS: usize = 64;
+
+struct PartitionWriter {
+    /// Directory of the partition: `<path>/<name>=<value>/...`.
+    path: String,
+    output_format: Box<dyn OutputFormat>,
+    buffer: Vec<u8>,
+    rows: usize,
+    batch_id: usize,
+}
+
+/// Unloads blocks into a Hive style layout, one directory per partition.
+///
+/// The partition values are the last columns of the input blocks,
+/// they only decide the directories and are not written into the files.
+pub struct StagePartitionSink {
+    ctx: Arc<dyn TableContext>,
+    data_accessor: Operator,
+    table_info: StageTableInfo,
+    data_schema: DataSchemaRef,
+    partition_by: Vec<String>,
+
+    fmt: OutputFormatType,
+    format_settings: FormatSettings,
+    max_file_size: usize,
+
+    uuid: String,
+    group_id: usize,
+
+    writers: Vec<PartitionWriter>,
+    writer_index: HashMap<String, usize>,
+    // Number of the writers with buffered rows.
+    open_partitions: usize,
+    manifest: Arc<UnloadManifest>,
+}
+
+impl StagePartitionSink {
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_create(
+        input: Arc<InputPort>,
+        ctx: Arc<dyn TableContext>,
+        table_info: StageTableInfo,
+        data_schema: DataSchemaRef,
+        partition_by: Vec<String>,
+        data_accessor: Operator,
+        uuid: String,
+        group_id: usize,
+        header: bool,
+        manifest: Arc<UnloadManifest>,
+    ) -> Result<ProcessorPtr> {
+        let (fmt, format_settings) = unload_format(&ctx, &table_info, header)?;
+        let mut max_file_size = table_info.stage_info.copy_options.max_file_size;
+        if max_file_size == 0 {
+            // 64M per file by default
+            max_file_size = 64 * 1024 * 1024;
+        }
+
+        Ok(AsyncSinker::create(input, StagePartitionSink {
+            ctx,
+            data_accessor,
+            table_info,
+            data_schema,
+            partition_by,
+            fmt,
+            format_settings,
+            max_file_size,
+            uuid,
+            group_id,
+            writers: vec![],
+            writer_index: HashMap::new(),
+            open_partitions: 0,
+            manifest,
+        }))
+    }
+
+    /// `<name>=<value>/...` of each row of the block.
+    fn partition_keys(&self, block: &DataBlock) -> Result<Vec<String>> {
+        let num_data_columns = self.data_schema.num_fields();
+        let mut keys = vec![String::new(); block.num_rows()];
+
+        for (i, name) in self.partition_by.iter().enumerate() {
+            let column = block.column(num_data_columns + i).convert_full_column();
+            let data_type = block.schema().field(num_data_columns + i).data_type();
+            let serializer = data_type.create_serializer(&column)?;
+
+            for (row, key) in keys.iter_mut().enumerate() {
+                if i > 0 {
+                    key.push('/');
+                }
+                key.push_str(name);
+                key.push('=');
+                if column.null_at(row) {
+                    key.push_str(DEFAULT_PARTITION);
+                } else {
+                    let value = serializer.serialize_field(row, &self.format_settings)?;
+                    escape_partition_value(&value, key);
+                }
+            }
+        }
+        Ok(keys)
+    }
+
+    fn get_writer(&mut self, partition: String) -> usize {
+        if let Some(index) = self.writer_index.get(&partition) {
+            return *index;
+        }
+
+        let path = format!(
+            "{}/{}",
+            self.table_info.path.trim_end_matches('/'),
+            partition
+        );
+        let output_format = self
+            .fmt
+            .create_format(self.data_schema.clone(), self.format_settings.clone());
+        self.writers.push(PartitionWriter {
+            path,
+            output_format,
+            buffer: vec![],
+            rows: 0,
+            batch_id: 0,
+        });
+        self.writer_index.insert(partition, self.writers.len() - 1);
+        self.writers.len() - 1
+    }
+
+    async fn write_partition(&mut self, index: usize, block: DataBlock) -> Result<()> {
+        for i in (0..block.num_rows()).step_by(1024) {
+            let end = (i + 1024).min(block.num_rows());
+            let small_block = block.slice(i, end - i);
+
+            let writer = &mut self.writers[index];
+            if writer.rows == 0 {
+                self.open_partitions += 1;
+                let prefix = writer.output_format.serialize_prefix()?;
+                writer.buffer.extend_from_slice(&prefix);
+            }
+            let bs = writer.output_format.serialize_block(&small_block)?;
+            writer.buffer.extend_from_slice(&bs);
+            writer.rows += small_block.num_rows();
+
+            if writer.buffer.len() + writer.output_format.buffer_size() >= self.max_file_size {
+                self.flush_partition(index).await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn flush_partition(&mut self, index: usize) -> Result<()> {
+        let writer = &mut self.writers[index];
+        if writer.rows == 0 {
+            return Ok(());
+        }
+
+        let bs = writer.output_format.finalize()?;
+        writer.buffer.extend_from_slice(&bs);
+        let bytes = std::mem::take(&mut writer.buffer);
+        let rows = std::mem::take(&mut writer.rows);
+        self.open_partitions -= 1;
+        let path = unload_file_path(
+            &self.table_info,
+            &writer.path,
+            &self.uuid,
+            self.group_id,
+            writer.batch_id,
+        );
+        writer.batch_id += 1;
+
+        write_unloaded_file(&self.ctx, &self.data_accessor, &path, &bytes).await?;
+        self.manifest.push(UnloadedFile {
+            path,
+            rows,
+            bytes: bytes.len(),
+        });
+        Ok(())
+    }
+
+    /// Flushes the largest partitions until at most `MAX_OPEN_PARTITIONS` are buffering.
+    async fn flush_overflowed_partitions(&mut self) -> Result<()> {
+        while self.open_partitions > MAX_OPEN_PARTITIONS {
+            let index = self
+                .writers
+                .iter()
+                .enumerate()
+                .filter(|(_, writer)| writer.rows > 0)
+                .max_by_key(|(_, writer)| writer.buffer.len())
+                .map(|(index, _)| index)
+                .unwrap();
+            self.flush_partition(index).await?;
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl AsyncSink for StagePartitionSink {
+    const NAME: &'static str = "StagePartitionSink";
+
+    async fn on_finish(&mut self) -> Result<()> {
+        for index in 0..self.writers.len() {
+            self.flush_partition(index).await?;
+        }
+        Ok(())
+    }
+
+    #[unboxed_simple]
+    async fn consume(&mut self, data_block: DataBlock) -> Result<()> {
+        if data_block.is_empty() {
+            return Ok(());
+        }
+
+        let keys = self.partition_keys(&data_block)?;
+        let mut scatter_index = HashMap::new();
+        let mut partitions = vec![];
+        let mut indices = Vec::with_capacity(keys.len());
+        for key in keys {
+            let index = *scatter_index.entry(key.clone()).or_insert_with(|| {
+                partitions.push(key);
+                partitions.len() - 1
+            });
+            indices.push(index);
+        }
+
+        let columns = data_block.columns()[..self.data_schema.num_fields()].to_vec();
+        let data_block = DataBlock::create(self.data_schema.clone(), columns);
+        let blocks = DataBlock::scatter_block(&data_block, &indices, partitions.len())?;
+
+        for (partition, block) in partitions.into_iter().zip(blocks) {
+            let index = self.get_writer(partition);
+            self.write_partition(index, block).await?;
+            self.flush_overflowed_partitions().await?;
+        }
+        Ok(())
+    }
+}
+
+/// Escape the characters which are not allowed in a path segment, as `%XX`.
+fn escape_partition_value(value: &str, buf: &mut String) {
+    for c in value.chars() {
+        match c {
+            '\u{0}'..='\u{1f}'
+            | '"'
+            | '#'
+            | '%'
+            | '\''
+            | '*'
+            | '/'
+            | ':'
+            | '='
+            | '?'
+            | '\\'
+            | '\u{7f}'
+            | '{'
+            | '['
+            | ']'
+            | '^' => buf.push_str(&format!("%{:02X}", c as u32)),
+            c => buf.push(c),
+        }
+    }
+}
@@ -17,6 +17,7 @@ use std::sync::atomic::AtomicUsize;
 use std::sync::Arc;
 
 use common_datablocks::DataBlock;
+use common_datavalues::DataSchemaRefExt;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_legacy_planners::Extras;
@@ -36,7 +37,10 @@ use opendal::Operator;
 use parking_lot::Mutex;
 use tracing::info;
 
+use super::stage_partition_sink::StagePartitionSink;
 use super::stage_table_sink::StageTableSink;
+use super::stage_unload::StageUnloadOptions;
+use super::stage_unload::UnloadManifest;
 use crate::pipelines::processors::TransformLimit;
 use crate::pipelines::Pipeline;
 use crate::sessions::TableContext;
@@ -49,16 +53,29 @@ pub struct StageTable {
     // fn get_table_info(&self) -> &TableInfo).
     table_info_placeholder: TableInfo,
     input_context: Mutex<Option<Arc<InputContext>>>,
+    unload_options: StageUnloadOptions,
+    manifest: Arc<UnloadManifest>,
 }
 
 impl StageTable {
     pub fn try_create(table_info: StageTableInfo) -> Result<Arc<dyn Table>> {
+        Self::try_create_for_unload(table_info, StageUnloadOptions::default(), Arc::default())
+    }
+
+    /// Create a stage table to unload into, the written files are collected by `manifest`.
+    pub fn try_create_for_unload(
+        table_info: StageTableInfo,
+        unload_options: StageUnloadOptions,
+        manifest: Arc<UnloadManifest>,
+    ) -> Result<Arc<dyn Table>> {
         let table_info_placeholder = TableInfo::default().set_schema(table_info.schema());
 
         Ok(Arc::new(Self {
             table_info,
             table_info_placeholder,
             input_context: Default::default(),
+            unload_options,
+            manifest,
         }))
     }
 
@@ -148,8 +165,44 @@ impl Table for StageTable {
         let single = self.table_info.stage_info.copy_options.single;
         let op = StageTable::get_op(&ctx, &self.table_info.stage_info)?;
 
-        let uuid = uuid::Uuid::new_v4().to_string();
+        let uuid = if self.unload_options.include_query_id {
+            ctx.get_id()
+        } else {
+            uuid::Uuid::new_v4().to_string()
+        };
         let group_id = AtomicUsize::new(0);
+        let header = self.unload_options.header;
+
+        // partitioned unload, every sink writes its own files of each partition
+        let partition_by = &self.unload_options.partition_by;
+        if !partition_by.is_empty() {
+            let fields = self.table_info.schema.fields();
+            let data_schema =
+                DataSchemaRefExt::create(fields[..fields.len() - partition_by.len()].to_vec());
+
+            for _ in 0..pipeline.output_len() {
+                let input_port = InputPort::create();
+                let gid = group_id.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                sink_pipeline_builder.add_sink(
+                    input_port.clone(),
+                    StagePartitionSink::try_create(
+                        input_port,
+                        ctx.clone(),
+                        self.table_info.clone(),
+                        data_schema.clone(),
+                        partition_by.clone(),
+                        op.clone(),
+                        uuid.clone(),
+                        gid,
+                        header,
+                        self.manifest.clone(),
+                    )?,
+                );
+            }
+
+            pipeline.add_pipe(sink_pipeline_builder.finalize());
+            return Ok(());
+        }
 
         // parallel compact unload, the partial block will flush into next operator
         if !single && pipeline.output_len() > 1 {
@@ -163,6 +216,8 @@ impl Table for StageTable {
                     Some(output),
                     uuid.clone(),
                     gid,
+                    header,
+                    self.manifest.clone(),
                 )
             })?;
         }
@@ -182,6 +237,8 @@ impl Table for StageTable {
                 None,
                 uuid,
                 gid,
+                header,
+                self.manifest.clone(),
             )?,
         );
 
@@ -13,18 +13,13 @@
 // limitations under the License.
 
 use std::any::Any;
-use std::io::ErrorKind;
-use std::str::FromStr;
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use backon::ExponentialBackoff;
-use backon::Retryable;
 use common_datablocks::DataBlock;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_formats::output_format::OutputFormat;
-use common_formats::output_format::OutputFormatType;
 use common_legacy_planners::StageTableInfo;
 use common_pipeline_core::processors::port::InputPort;
 use common_pipeline_core::processors::port::OutputPort;
@@ -32,8 +27,12 @@ use common_pipeline_core::processors::processor::Event;
 use common_pipeline_core::processors::processor::ProcessorPtr;
 use common_pipeline_core::processors::Processor;
 use opendal::Operator;
-use tracing::warn;
 
+use super::stage_unload::unload_file_path;
+use super::stage_unload::unload_format;
+use super::stage_unload::write_unloaded_file;
+use super::stage_unload::UnloadManifest;
+use super::stage_unload::UnloadedFile;
 use crate::sessions::TableContext;
 
 #[derive(Debug)]
@@ -53,6 +52,8 @@ pub struct StageTableSink {
 
     table_info: StageTableInfo,
     working_buffer: Vec<u8>,
+    // Rows serialized into the working buffer.
+    working_rows: usize,
     working_datablocks: Vec<DataBlock>,
     output_format: Box<dyn OutputFormat>,
     write_header: bool,
@@ -63,6 +64,7 @@ pub struct StageTableSink {
 
     single: bool,
     max_file_size: usize,
+    manifest: Arc<UnloadManifest>,
 }
 
 impl StageTableSink {
@@ -76,24 +78,10 @@ impl StageTableSink {
 
         uuid: String,
         group_id: usize,
+        header: bool,
+        manifest: Arc<UnloadManifest>,
     ) -> Result<ProcessorPtr> {
-        let format_name = format!("{:?}", table_info.stage_info.file_format_options.format);
-
-        let fmt = OutputFormatType::from_str(format_name.as_str())?;
-        let mut format_settings = ctx.get_format_settings()?;
-
-        let format_options = &table_info.stage_info.file_format_options;
-        {
-            if !format_options.field_delimiter.is_empty() {
-                format_settings.field_delimiter =
-                    format_options.field_delimiter.as_bytes().to_vec();
-            }
-            if !format_options.record_delimiter.is_empty() {
-                format_settings.record_delimiter =
-                    format_options.record_delimiter.as_bytes().to_vec();
-            }
-        }
-
+        let (fmt, format_settings) = unload_format(&ctx, &table_info, header)?;
         let output_format = fmt.create_format(table_info.schema(), format_settings);
         let mut max_file_size = table_info.stage_info.copy_options.max_file_size;
         if max_file_size == 0 {
@@ -115,6 +103,7 @@ impl StageTableSink {
             working_buffer: Vec::with_capacity(
                 (max_file_size.min(256 * 1024) as f64 * 1.2) as usize,
             ),
+            working_rows: 0,
             working_datablocks: vec![],
             write_header: false,
 
@@ -122,33 +111,18 @@ impl StageTableSink {
             group_id,
             batch_id: 0,
             max_file_size,
+            manifest,
         })))
     }
 
     pub fn unload_path(&self) -> String {
-        let format_name = format!(
-            "{:?}",
-            self.table_info.stage_info.file_format_options.format
-        );
-        if self.table_info.path.ends_with("data_") {
-            format!(
-                "{}{}_{}_{}.{}",
-                self.table_info.path,
-                self.uuid,
-                self.group_id,
-                self.batch_id,
-                format_name.to_ascii_lowercase()
-            )
-        } else {
-            format!(
-                "{}/data_{}_{}_{}.{}",
-                self.table_info.path,
-                self.uuid,
-                self.group_id,
-                self.batch_id,
-                format_name.to_ascii_lowercase()
-            )
-        }
+        unload_file_path(
+            &self.table_info,
+            &self.table_info.path,
+            &self.uuid,
+            self.group_id,
+            self.batch_id,
+        )
     }
 }
 
@@ -226,6 +200,7 @@ impl Processor for StageTableSink {
 
                         let bs = self.output_format.serialize_block(&small_block)?;
                         self.working_buffer.extend_from_slice(bs.as_slice());
+                        self.working_rows += small_block.num_rows();
 
                         if self.working_buffer.len() + self.output_format.buffer_size()
                             >= self.max_file_size
@@ -247,6 +222,7 @@ impl Processor for StageTableSink {
                 } else {
                     let bs = self.output_format.serialize_block(&datablock)?;
                     self.working_buffer.extend_from_slice(bs.as_slice());
+                    self.working_rows += datablock.num_rows();
                 }
 
                 // hold this datablock
@@ -267,23 +243,14 @@ impl Processor for StageTableSink {
         match std::mem::replace(&mut self.state, State::None) {
             State::NeedWrite(bytes, remainng_block) => {
                 let path = self.unload_path();
-                self.ctx
-                    .get_dal_context()
-                    .get_metrics()
-                    .inc_write_bytes(bytes.len());
-
-                let object = self.data_accessor.object(&path);
-                { || object.write(bytes.as_slice()) }
-                    .retry(ExponentialBackoff::default())
-                    .when(|err| err.kind() == ErrorKind::Interrupted)
-                    .notify(|err, dur| {
-                        warn!(
-                            "stage table sink write retry after {}s for error {:?}",
-                            dur.as_secs(),
-                            err
-                        )
-                    })
-                    .await?;
+                write_unloaded_file(&self.ctx, &self.data_accessor, &path, &bytes).await?;
+                self.manifest.push(UnloadedFile {
+                    path,
+                    rows: std::mem::take(&mut self.working_rows),
+                    bytes: bytes.len(),
+                });
+                // Every file starts with its own header.
+                self.write_header = false;
 
                 match remainng_block {
                     Some(block) => self.state = State::NeedSerialize(block),
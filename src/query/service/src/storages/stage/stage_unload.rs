@@ -0,0 +1,157 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::ErrorKind;
+use std::mem;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use backon::ExponentialBackoff;
+use backon::Retryable;
+use common_exception::Result;
+use common_formats::output_format::OutputFormatType;
+use common_io::prelude::FormatSettings;
+use common_legacy_planners::StageTableInfo;
+use opendal::Operator;
+use parking_lot::Mutex;
+use tracing::warn;
+
+use crate::sessions::TableContext;
+
+/// Options of `COPY INTO <location>` which only apply while unloading.
+#[derive(Clone, Debug, Default)]
+pub struct StageUnloadOptions {
+    /// Names of the `PARTITION BY` expressions.
+    /// Their values are the last columns of the unloaded blocks.
+    pub partition_by: Vec<String>,
+    /// Name the files after the query id instead of a random uuid.
+    pub include_query_id: bool,
+    /// Write the column names first, for the formats supporting it.
+    pub header: bool,
+}
+
+/// A file written by `COPY INTO <location>`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnloadedFile {
+    pub path: String,
+    pub rows: usize,
+    pub bytes: usize,
+}
+
+/// Collects the files written by the stage sinks,
+/// returned as the result of `COPY INTO <location>`.
+#[derive(Debug, Default)]
+pub struct UnloadManifest {
+    files: Mutex<Vec<UnloadedFile>>,
+}
+
+impl UnloadManifest {
+    pub fn push(&self, file: UnloadedFile) {
+        self.files.lock().push(file);
+    }
+
+    /// Take all the written files, ordered by path.
+    pub fn take(&self) -> Vec<UnloadedFile> {
+        let mut files = mem::take(&mut *self.files.lock());
+        files.sort_by(|a, b| a.path.cmp(&b.path));
+        files
+    }
+}
+
+/// Output format and settings of the files unloaded into the stage.
+pub fn unload_format(
+    ctx: &Arc<dyn TableContext>,
+    table_info: &StageTableInfo,
+    header: bool,
+) -> Result<(OutputFormatType, FormatSettings)> {
+    let format_name = format!("{:?}", table_info.stage_info.file_format_options.format);
+
+    let mut fmt = OutputFormatType::from_str(format_name.as_str())?;
+    if header {
+        // Formats like parquet carry the column names already.
+        fmt = fmt.with_names().unwrap_or(fmt);
+    }
+    let mut format_settings = ctx.get_format_settings()?;
+
+    let format_options = &table_info.stage_info.file_format_options;
+    {
+        if !format_options.field_delimiter.is_empty() {
+            format_settings.field_delimiter = format_options.field_delimiter.as_bytes().to_vec();
+        }
+        if !format_options.record_delimiter.is_empty() {
+            format_settings.record_delimiter = format_options.record_delimiter.as_bytes().to_vec();
+        }
+    }
+
+    Ok((fmt, format_settings))
+}
+
+/// Path of the `batch_id`-th file written by the sink `group_id`.
+///
+/// If `path` ends with `data_` it is used as the prefix of the file names,
+/// otherwise the files are written under the `path` directory.
+pub fn unload_file_path(
+    table_info: &StageTableInfo,
+    path: &str,
+    id: &str,
+    group_id: usize,
+    batch_id: usize,
+) -> String {
+    let format_name = format!("{:?}", table_info.stage_info.file_format_options.format);
+    if path.ends_with("data_") {
+        format!(
+            "{}{}_{}_{}.{}",
+            path,
+            id,
+            group_id,
+            batch_id,
+            format_name.to_ascii_lowercase()
+        )
+    } else {
+        format!(
+            "{}/data_{}_{}_{}.{}",
+            path,
+            id,
+            group_id,
+            batch_id,
+            format_name.to_ascii_lowercase()
+        )
+    }
+}
+
+/// Write an unloaded file, retrying on interrupted writes.
+pub async fn write_unloaded_file(
+    ctx: &Arc<dyn TableContext>,
+    data_accessor: &Operator,
+    path: &str,
+    bytes: &[u8],
+) -> Result<()> {
+    ctx.get_dal_context()
+        .get_metrics()
+        .inc_write_bytes(bytes.len());
+
+    let object = data_accessor.object(path);
+    { || object.write(bytes) }
+        .retry(ExponentialBackoff::default())
+        .when(|err| err.kind() == ErrorKind::Interrupted)
+        .notify(|err, dur| {
+            warn!(
+                "stage table sink write retry after {}s for error {:?}",
+                dur.as_secs(),
+                err
+            )
+        })
+        .await?;
+    Ok(())
+}
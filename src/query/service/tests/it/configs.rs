@@ -73,6 +73,7 @@ async_insert_busy_timeout = 200
 async_insert_stale_timeout = 0
 users = []
 share_endpoint_address = ""
+pipe_poll_interval_secs = 60
 
 [log]
 level = "INFO"
@@ -96,6 +97,7 @@ username = "root"
 password = ""
 client_timeout_in_second = 10
 auto_sync_interval = 10
+max_read_staleness_ms = 0
 rpc_tls_meta_server_root_ca_cert = ""
 rpc_tls_meta_service_domain_name = "localhost"
 
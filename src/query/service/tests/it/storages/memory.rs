@@ -80,6 +80,7 @@ async fn test_memorytable() -> Result<()> {
                             limit: None,
                             order_by: vec![],
                             prewhere: None,
+                            virtual_columns: vec![],
                         }
                     })
                 })
@@ -118,7 +118,7 @@ impl TestGlobalServices {
         DataExchangeManager::init(config.clone(), global_services.clone())?;
         SessionManager::init(config.clone(), global_services.clone())?;
         UserApiProvider::init(
-            config.meta.to_meta_grpc_client_conf(),
+            config.to_meta_grpc_client_conf(),
             config.query.idm.clone(),
             global_services.clone(),
         )
@@ -309,6 +309,16 @@ impl Settings {
                 desc: "The timeout in seconds for waiting for processing of async insert, default value: 100.",
                 possible_values: None,
             },
+            SettingValue {
+                default_value: UserSettingValue::UInt64(120),
+                user_setting: UserSetting::create(
+                    "table_lock_timeout",
+                    UserSettingValue::UInt64(120),
+                ),
+                level: ScopeLevel::Session,
+                desc: "The timeout in seconds for waiting for a table lock, default value: 120.",
+                possible_values: None,
+            },
             SettingValue {
                 default_value: UserSettingValue::UInt64(0),
                 user_setting: UserSetting::create(
@@ -531,6 +541,16 @@ impl Settings {
         self.try_set_u64(key, val, false)
     }
 
+    pub fn get_table_lock_timeout(&self) -> Result<u64> {
+        let key = "table_lock_timeout";
+        self.try_get_u64(key)
+    }
+
+    pub fn set_table_lock_timeout(&self, val: u64) -> Result<()> {
+        let key = "table_lock_timeout";
+        self.try_set_u64(key, val, false)
+    }
+
     pub fn get_unquoted_ident_case_sensitive(&self) -> Result<bool> {
         static KEY: &str = "unquoted_ident_case_sensitive";
         let v = self.try_get_u64(KEY)?;
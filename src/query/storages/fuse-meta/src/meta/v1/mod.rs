@@ -19,5 +19,7 @@ mod snapshot;
 pub use index::BlockFilter;
 pub use segment::BlockMeta;
 pub use segment::SegmentInfo;
+pub use segment::VirtualColumnMeta;
+pub use segment::VirtualColumnsMeta;
 pub use snapshot::TableSnapshot;
 pub use snapshot::TableSnapshotLite;
@@ -15,6 +15,7 @@
 use std::collections::HashMap;
 
 use common_datablocks::DataBlock;
+use common_datavalues::DataTypeImpl;
 use serde::Deserialize;
 use serde::Serialize;
 
@@ -64,6 +65,35 @@ pub struct BlockMeta {
     /// used in the write path.
     #[serde(default = "Compression::legacy")]
     compression: Compression,
+
+    /// Frequently occurring paths of the variant columns, shredded into typed
+    /// columns and stored in a sidecar file.
+    ///
+    /// Blocks written before virtual columns were introduced do not have it.
+    #[serde(default)]
+    pub virtual_columns: Option<VirtualColumnsMeta>,
+}
+
+/// Meta information of the virtual columns of a block
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct VirtualColumnsMeta {
+    /// location of the sidecar file
+    pub location: Location,
+    /// size of the sidecar file
+    pub size: u64,
+    /// virtual columns in the order of the sidecar file schema
+    pub columns: Vec<VirtualColumnMeta>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct VirtualColumnMeta {
+    /// name of the source variant column
+    pub source_name: String,
+    /// keys of the path inside the source column, e.g. `["a", "b"]` for `v:a.b`
+    pub path: Vec<String>,
+    pub data_type: DataTypeImpl,
+    pub col_stats: Option<ColumnStatistics>,
+    pub col_meta: ColumnMeta,
 }
 
 impl BlockMeta {
@@ -90,6 +120,7 @@ impl BlockMeta {
             bloom_filter_index_location,
             bloom_filter_index_size,
             compression: Compression::Lz4Raw,
+            virtual_columns: None,
         }
     }
 
@@ -137,6 +168,7 @@ impl From<v0::BlockMeta> for BlockMeta {
             bloom_filter_index_location: None,
             bloom_filter_index_size: 0,
             compression: Compression::Lz4,
+            virtual_columns: None,
         }
     }
 }
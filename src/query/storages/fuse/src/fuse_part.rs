@@ -22,6 +22,7 @@ use common_arrow::arrow::datatypes::Schema as ArrowSchema;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_fuse_meta::meta::Compression;
+use common_fuse_meta::meta::VirtualColumnsMeta;
 use common_legacy_planners::PartInfo;
 use common_legacy_planners::PartInfoPtr;
 use common_legacy_planners::Projection;
@@ -52,6 +53,8 @@ pub struct FusePartInfo {
     pub nums_rows: usize,
     pub columns_meta: HashMap<usize, ColumnMeta>,
     pub compression: Compression,
+    /// The shredded virtual columns of the block, if any.
+    pub virtual_columns: Option<VirtualColumnsMeta>,
 }
 
 #[typetag::serde(name = "fuse")]
@@ -75,6 +78,7 @@ impl FusePartInfo {
         rows_count: u64,
         columns_meta: HashMap<usize, ColumnMeta>,
         compression: Compression,
+        virtual_columns: Option<VirtualColumnsMeta>,
     ) -> Arc<Box<dyn PartInfo>> {
         Arc::new(Box::new(FusePartInfo {
             location,
@@ -82,6 +86,7 @@ impl FusePartInfo {
             columns_meta,
             nums_rows: rows_count as usize,
             compression,
+            virtual_columns,
         }))
     }
 
@@ -125,6 +130,12 @@ impl ColumnLeaves {
                 }
                 ColumnLeaf::new(field.clone(), child_leaf_ids, Some(child_column_leaves))
             }
+            ArrowType::Map(entries_field, _) => {
+                // The keys and values of a map are stored as separate leaves,
+                // but they can only be read together as a whole map column.
+                let entries_leaf = Self::traverse_fields_dfs(entries_field, leaf_id);
+                ColumnLeaf::new(field.clone(), entries_leaf.leaf_ids, None)
+            }
             _ => {
                 let column_leaf = ColumnLeaf::new(field.clone(), vec![*leaf_id], None);
                 *leaf_id += 1;
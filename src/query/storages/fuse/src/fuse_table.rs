@@ -29,7 +29,6 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_fuse_meta::meta::ClusterKey;
 use common_fuse_meta::meta::ColumnStatistics as FuseColumnStatistics;
-use common_fuse_meta::meta::Statistics as FuseStatistics;
 use common_fuse_meta::meta::TableSnapshot;
 use common_fuse_meta::meta::Versioned;
 use common_legacy_expression::LegacyExpression;
@@ -47,7 +46,6 @@ use common_storage::StorageOperator;
 use common_storages_util::storage_context::StorageContext;
 use common_storages_util::table_storage_prefix::table_storage_prefix;
 use opendal::Operator;
-use uuid::Uuid;
 
 use crate::io::BlockCompactor;
 use crate::io::MetaReaders;
@@ -262,86 +260,11 @@ impl Table for FuseTable {
         ctx: Arc<dyn TableContext>,
         cluster_key_str: String,
     ) -> Result<()> {
-        let mut new_table_meta = self.get_table_info().meta.clone();
-        new_table_meta = new_table_meta.push_cluster_key(cluster_key_str);
-        let cluster_key_meta = new_table_meta.cluster_key();
-        let schema = self.schema().as_ref().clone();
-
-        let prev = self.read_table_snapshot(ctx.clone()).await?;
-        let prev_version = self.snapshot_format_version().await?;
-        let prev_timestamp = prev.as_ref().and_then(|v| v.timestamp);
-        let prev_snapshot_id = prev.as_ref().map(|v| (v.snapshot_id, prev_version));
-        let (summary, segments) = if let Some(v) = prev {
-            (v.summary.clone(), v.segments.clone())
-        } else {
-            (FuseStatistics::default(), vec![])
-        };
-
-        let new_snapshot = TableSnapshot::new(
-            Uuid::new_v4(),
-            &prev_timestamp,
-            prev_snapshot_id,
-            schema,
-            summary,
-            segments,
-            cluster_key_meta,
-        );
-
-        let mut table_info = self.table_info.clone();
-        table_info.meta = new_table_meta;
-
-        FuseTable::commit_to_meta_server(
-            ctx.as_ref(),
-            &table_info,
-            &self.meta_location_generator,
-            new_snapshot,
-            &self.operator,
-        )
-        .await
+        self.do_alter_cluster_keys(ctx, cluster_key_str).await
     }
 
     async fn drop_table_cluster_keys(&self, ctx: Arc<dyn TableContext>) -> Result<()> {
-        if self.cluster_key_meta.is_none() {
-            return Ok(());
-        }
-
-        let mut new_table_meta = self.get_table_info().meta.clone();
-        new_table_meta.default_cluster_key = None;
-        new_table_meta.default_cluster_key_id = None;
-
-        let schema = self.schema().as_ref().clone();
-
-        let prev = self.read_table_snapshot(ctx.clone()).await?;
-        let prev_version = self.snapshot_format_version().await?;
-        let prev_timestamp = prev.as_ref().and_then(|v| v.timestamp);
-        let prev_snapshot_id = prev.as_ref().map(|v| (v.snapshot_id, prev_version));
-        let (summary, segments) = if let Some(v) = prev {
-            (v.summary.clone(), v.segments.clone())
-        } else {
-            (FuseStatistics::default(), vec![])
-        };
-
-        let new_snapshot = TableSnapshot::new(
-            Uuid::new_v4(),
-            &prev_timestamp,
-            prev_snapshot_id,
-            schema,
-            summary,
-            segments,
-            None,
-        );
-
-        let mut table_info = self.table_info.clone();
-        table_info.meta = new_table_meta;
-
-        FuseTable::commit_to_meta_server(
-            ctx.as_ref(),
-            &table_info,
-            &self.meta_location_generator,
-            new_snapshot,
-            &self.operator,
-        )
-        .await
+        self.do_drop_cluster_keys(ctx).await
     }
 
     #[tracing::instrument(level = "debug", name = "fuse_table_read_partitions", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
@@ -27,6 +27,7 @@ use crate::constants::FUSE_TBL_BLOCK_PREFIX;
 use crate::constants::FUSE_TBL_SEGMENT_PREFIX;
 use crate::constants::FUSE_TBL_SNAPSHOT_PREFIX;
 use crate::FUSE_TBL_LAST_SNAPSHOT_HINT;
+use crate::FUSE_TBL_VIRTUAL_BLOCK_PREFIX;
 use crate::FUSE_TBL_XOR_BLOOM_INDEX_PREFIX;
 
 static SNAPSHOT_V0: SnapshotVersion = SnapshotVersion::V0(PhantomData);
@@ -76,6 +77,19 @@ impl TableMetaLocationGenerator {
         )
     }
 
+    pub fn block_virtual_columns_location(&self, block_id: &Uuid) -> Location {
+        (
+            format!(
+                "{}/{}/{}_v{}.parquet",
+                &self.prefix,
+                FUSE_TBL_VIRTUAL_BLOCK_PREFIX,
+                block_id.as_simple(),
+                DataBlock::VERSION,
+            ),
+            DataBlock::VERSION,
+        )
+    }
+
     pub fn gen_segment_info_location(&self) -> String {
         let segment_uuid = Uuid::new_v4().simple().to_string();
         format!(
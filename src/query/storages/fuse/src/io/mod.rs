@@ -28,6 +28,8 @@ pub use read::MetaReaders;
 pub use read::SegmentInfoReader;
 pub use read::SnapshotHistoryReader;
 pub use read::TableSnapshotReader;
+pub use read::VirtualColumnReader;
+pub use read::VirtualColumnsData;
 pub use segments::SegmentsIO;
 pub use snapshots::SnapshotsIO;
 pub use write::write_block;
@@ -36,3 +38,4 @@ pub use write::write_meta;
 pub use write::BlockCompactor;
 pub use write::BlockWriter;
 pub use write::SegmentWriter;
+pub use write::VirtualColumnBuilder;
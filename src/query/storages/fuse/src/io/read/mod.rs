@@ -17,6 +17,7 @@ mod bloom_index_reader;
 mod meta_readers;
 mod snapshot_history_reader;
 mod versioned_reader;
+mod virtual_column_reader;
 
 pub use block_reader::BlockReader;
 pub use bloom_index_reader::load_bloom_filter_by_columns;
@@ -25,3 +26,5 @@ pub use meta_readers::MetaReaders;
 pub use meta_readers::SegmentInfoReader;
 pub use meta_readers::TableSnapshotReader;
 pub use snapshot_history_reader::SnapshotHistoryReader;
+pub use virtual_column_reader::VirtualColumnReader;
+pub use virtual_column_reader::VirtualColumnsData;
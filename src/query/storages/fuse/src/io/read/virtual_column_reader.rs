@@ -0,0 +1,424 @@
+//  Copyright 2022 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_functions::scalars::cast_column_field;
+use common_functions::scalars::FunctionContext;
+use common_fuse_meta::meta::Compression;
+use common_fuse_meta::meta::StatisticsOfColumns;
+use common_fuse_meta::meta::VirtualColumnMeta;
+use common_fuse_meta::meta::VirtualColumnsMeta;
+use common_legacy_planners::PartInfoPtr;
+use common_legacy_planners::Projection;
+use common_legacy_planners::VirtualColumn;
+use opendal::Operator;
+use serde_json::Value as JsonValue;
+
+use crate::fuse_part::ColumnMeta;
+use crate::fuse_part::FusePartInfo;
+use crate::io::write::extract_path;
+use crate::io::BlockReader;
+
+type DataChunks = Vec<(usize, Vec<u8>)>;
+
+/// The raw data of a part read by [`VirtualColumnReader`].
+pub enum VirtualColumnsData {
+    /// All the virtual columns are read from the sidecar file of the block.
+    Shredded {
+        chunks: DataChunks,
+        virtual_part: PartInfoPtr,
+        virtual_reader: Arc<BlockReader>,
+        virtual_chunks: DataChunks,
+    },
+    /// The virtual columns are extracted from their source columns.
+    Extracted { chunks: DataChunks },
+}
+
+/// Reads the projected columns of a block together with the virtual columns.
+///
+/// The virtual columns are read from the sidecar file of the block if all of them
+/// have been shredded with a compatible type, otherwise they are extracted from
+/// the source variant columns, which yields the same values.
+pub struct VirtualColumnReader {
+    operator: Operator,
+    table_schema: DataSchemaRef,
+    projection: Projection,
+    virtual_columns: Vec<VirtualColumn>,
+    base_reader: Arc<BlockReader>,
+    fallback_reader: Arc<BlockReader>,
+    output_schema: DataSchemaRef,
+    func_ctx: FunctionContext,
+}
+
+impl VirtualColumnReader {
+    pub fn create(
+        operator: Operator,
+        table_schema: DataSchemaRef,
+        projection: Projection,
+        virtual_columns: Vec<VirtualColumn>,
+        func_ctx: FunctionContext,
+    ) -> Result<Arc<VirtualColumnReader>> {
+        let base_reader =
+            BlockReader::create(operator.clone(), table_schema.clone(), projection.clone())?;
+        let fallback_reader = BlockReader::create(
+            operator.clone(),
+            table_schema.clone(),
+            Self::projection_with_sources(&projection, &virtual_columns),
+        )?;
+
+        let mut fields = base_reader.schema().fields().clone();
+        fields.extend(virtual_columns.iter().map(|c| c.to_data_field()));
+        let output_schema = DataSchemaRefExt::create(fields);
+
+        Ok(Arc::new(VirtualColumnReader {
+            operator,
+            table_schema,
+            projection,
+            virtual_columns,
+            base_reader,
+            fallback_reader,
+            output_schema,
+            func_ctx,
+        }))
+    }
+
+    pub fn schema(&self) -> DataSchemaRef {
+        self.output_schema.clone()
+    }
+
+    /// Add the source columns of the virtual columns to the projection.
+    pub fn projection_with_sources(
+        projection: &Projection,
+        virtual_columns: &[VirtualColumn],
+    ) -> Projection {
+        let mut projection = projection.clone();
+        for virtual_column in virtual_columns {
+            let source_index = virtual_column.source_index;
+            match &mut projection {
+                Projection::Columns(indices) => {
+                    if !indices.contains(&source_index) {
+                        indices.push(source_index);
+                        indices.sort_unstable();
+                    }
+                }
+                Projection::InnerColumns(path_indices) => {
+                    if !path_indices.values().any(|path| path == &[source_index]) {
+                        let key = path_indices.keys().max().map_or(0, |k| k + 1);
+                        path_indices.insert(key, vec![source_index]);
+                    }
+                }
+            }
+        }
+        projection
+    }
+
+    /// Return the statistics of the columns together with the statistics of the virtual
+    /// columns, keyed by `base + i` for the i-th virtual column.
+    ///
+    /// Only the virtual columns shredded with a compatible scalar type have statistics,
+    /// the others are left out so that the range filter keeps the block.
+    pub fn merge_statistics(
+        col_stats: &StatisticsOfColumns,
+        virtual_columns_meta: Option<&VirtualColumnsMeta>,
+        virtual_columns: &[VirtualColumn],
+        table_schema: &DataSchemaRef,
+        base: u32,
+    ) -> StatisticsOfColumns {
+        let mut col_stats = col_stats.clone();
+        for (i, virtual_column) in virtual_columns.iter().enumerate() {
+            // statistics of a variant can not be compared with the scalars
+            let stats = virtual_columns_meta
+                .filter(|_| {
+                    !remove_nullable(&virtual_column.data_type)
+                        .data_type_id()
+                        .is_variant()
+                })
+                .and_then(|meta| Self::find_meta(meta, virtual_column, table_schema))
+                .filter(|meta| Self::is_compatible(meta, virtual_column))
+                .and_then(|meta| meta.col_stats.clone());
+            let key = base + i as u32;
+            match stats {
+                Some(stats) => col_stats.insert(key, stats),
+                // the key may be taken by a leaf of a nested column
+                None => col_stats.remove(&key),
+            };
+        }
+        col_stats
+    }
+
+    pub async fn read_columns_data(&self, part: PartInfoPtr) -> Result<VirtualColumnsData> {
+        match self.shredded_part(&part)? {
+            Some((virtual_part, virtual_reader)) => {
+                let chunks = if self.projection.is_empty() {
+                    vec![]
+                } else {
+                    self.base_reader.read_columns_data(part).await?
+                };
+                let virtual_chunks = virtual_reader
+                    .read_columns_data(virtual_part.clone())
+                    .await?;
+                Ok(VirtualColumnsData::Shredded {
+                    chunks,
+                    virtual_part,
+                    virtual_reader,
+                    virtual_chunks,
+                })
+            }
+            None => {
+                let chunks = self.fallback_reader.read_columns_data(part).await?;
+                Ok(VirtualColumnsData::Extracted { chunks })
+            }
+        }
+    }
+
+    pub fn deserialize(&self, part: PartInfoPtr, data: VirtualColumnsData) -> Result<DataBlock> {
+        let mut columns = Vec::with_capacity(self.output_schema.num_fields());
+        match data {
+            VirtualColumnsData::Shredded {
+                chunks,
+                virtual_part,
+                virtual_reader,
+                virtual_chunks,
+            } => {
+                if !self.projection.is_empty() {
+                    let block = self.base_reader.deserialize(part.clone(), chunks)?;
+                    columns.extend(block.columns().iter().cloned());
+                }
+                let part = FusePartInfo::from_part(&part)?;
+                let meta = part.virtual_columns.as_ref().unwrap();
+                let virtual_block = virtual_reader.deserialize(virtual_part, virtual_chunks)?;
+                for virtual_column in &self.virtual_columns {
+                    let column_meta = Self::find_meta(meta, virtual_column, &self.table_schema)
+                        .ok_or_else(|| {
+                            ErrorCode::LogicalError("It's a bug. Virtual column is not shredded")
+                        })?;
+                    let column =
+                        virtual_block.try_column_by_name(&Self::field_name(column_meta))?;
+                    columns.push(self.convert_column(column, column_meta, virtual_column)?);
+                }
+            }
+            VirtualColumnsData::Extracted { chunks } => {
+                let block = self.fallback_reader.deserialize(part, chunks)?;
+                for field in self.base_reader.schema().fields() {
+                    columns.push(block.try_column_by_name(field.name())?.clone());
+                }
+                for virtual_column in &self.virtual_columns {
+                    let source_name = self.table_schema.field(virtual_column.source_index).name();
+                    let source = block.try_column_by_name(source_name)?;
+                    columns.push(self.extract_column(source, virtual_column)?);
+                }
+            }
+        }
+        Ok(DataBlock::create(self.output_schema.clone(), columns))
+    }
+
+    /// Build the part of the sidecar file if all the virtual columns can be read from it.
+    fn shredded_part(&self, part: &PartInfoPtr) -> Result<Option<(PartInfoPtr, Arc<BlockReader>)>> {
+        let part = FusePartInfo::from_part(part)?;
+        let meta = match &part.virtual_columns {
+            Some(meta) => meta,
+            None => return Ok(None),
+        };
+
+        let mut indices = Vec::with_capacity(self.virtual_columns.len());
+        for virtual_column in &self.virtual_columns {
+            match meta.columns.iter().position(|m| {
+                Self::is_meta_of(m, virtual_column, &self.table_schema)
+                    && Self::is_compatible(m, virtual_column)
+            }) {
+                Some(idx) => indices.push(idx),
+                None => return Ok(None),
+            }
+        }
+        indices.sort_unstable();
+        indices.dedup();
+
+        let fields = meta
+            .columns
+            .iter()
+            .map(|m| DataField::new(&Self::field_name(m), m.data_type.clone()))
+            .collect();
+        let columns_meta = meta
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(idx, m)| {
+                let col_meta = &m.col_meta;
+                let column_meta =
+                    ColumnMeta::create(col_meta.offset, col_meta.len, col_meta.num_values);
+                (idx, column_meta)
+            })
+            .collect::<HashMap<_, _>>();
+
+        let virtual_reader = BlockReader::create(
+            self.operator.clone(),
+            DataSchemaRefExt::create(fields),
+            Projection::Columns(indices),
+        )?;
+        let virtual_part = FusePartInfo::create(
+            meta.location.0.clone(),
+            meta.location.1,
+            part.nums_rows as u64,
+            columns_meta,
+            Compression::Lz4Raw,
+            None,
+        );
+        Ok(Some((virtual_part, virtual_reader)))
+    }
+
+    fn field_name(meta: &VirtualColumnMeta) -> String {
+        format!("{}:{}", meta.source_name, meta.path.join("."))
+    }
+
+    fn is_meta_of(
+        meta: &VirtualColumnMeta,
+        virtual_column: &VirtualColumn,
+        table_schema: &DataSchemaRef,
+    ) -> bool {
+        meta.path == virtual_column.path
+            && &meta.source_name == table_schema.field(virtual_column.source_index).name()
+    }
+
+    fn find_meta<'a>(
+        meta: &'a VirtualColumnsMeta,
+        virtual_column: &VirtualColumn,
+        table_schema: &DataSchemaRef,
+    ) -> Option<&'a VirtualColumnMeta> {
+        meta.columns
+            .iter()
+            .find(|m| Self::is_meta_of(m, virtual_column, table_schema))
+    }
+
+    /// Check if the shredded values can be converted into the values of the virtual column
+    /// without any difference from extracting them from the source column.
+    fn is_compatible(meta: &VirtualColumnMeta, virtual_column: &VirtualColumn) -> bool {
+        let stored = remove_nullable(&meta.data_type).data_type_id();
+        let requested = remove_nullable(&virtual_column.data_type).data_type_id();
+        match requested {
+            // integers and floats are merged as floats, which can not be converted back
+            TypeID::Variant => matches!(stored, TypeID::Int64 | TypeID::Boolean | TypeID::String),
+            _ if requested == stored => true,
+            TypeID::Float64 => stored == TypeID::Int64,
+            // casting a variant to a narrower integer wraps around, so the values
+            // must be in range of the requested type
+            _ if requested.is_integer() && stored == TypeID::Int64 => {
+                match (&meta.col_stats, Self::integer_range(requested)) {
+                    (Some(stats), Some((min, max))) => {
+                        matches!((stats.min.as_i64(), stats.max.as_i64()), (Ok(lo), Ok(hi)) if lo >= min && hi <= max)
+                    }
+                    _ => false,
+                }
+            }
+            _ => false,
+        }
+    }
+
+    fn integer_range(type_id: TypeID) -> Option<(i64, i64)> {
+        match type_id {
+            TypeID::Int8 => Some((i8::MIN as i64, i8::MAX as i64)),
+            TypeID::Int16 => Some((i16::MIN as i64, i16::MAX as i64)),
+            TypeID::Int32 => Some((i32::MIN as i64, i32::MAX as i64)),
+            TypeID::Int64 => Some((i64::MIN, i64::MAX)),
+            TypeID::UInt8 => Some((0, u8::MAX as i64)),
+            TypeID::UInt16 => Some((0, u16::MAX as i64)),
+            TypeID::UInt32 => Some((0, u32::MAX as i64)),
+            TypeID::UInt64 => Some((0, i64::MAX)),
+            _ => None,
+        }
+    }
+
+    fn convert_column(
+        &self,
+        column: &ColumnRef,
+        meta: &VirtualColumnMeta,
+        virtual_column: &VirtualColumn,
+    ) -> Result<ColumnRef> {
+        if remove_nullable(&virtual_column.data_type)
+            .data_type_id()
+            .is_variant()
+        {
+            let mut builder = NullableColumnBuilder::<VariantValue>::with_capacity(column.len());
+            for row in 0..column.len() {
+                let value = match column.get(row) {
+                    DataValue::Null => None,
+                    DataValue::Int64(v) => Some(JsonValue::from(v)),
+                    DataValue::Boolean(v) => Some(JsonValue::Bool(v)),
+                    DataValue::String(v) => {
+                        Some(JsonValue::String(String::from_utf8_lossy(&v).into_owned()))
+                    }
+                    v => {
+                        return Err(ErrorCode::LogicalError(format!(
+                            "It's a bug. Unexpected shredded value {:?}",
+                            v
+                        )));
+                    }
+                };
+                match value {
+                    Some(v) => builder.append(&VariantValue::from(v), true),
+                    None => builder.append_null(),
+                }
+            }
+            return Ok(builder.build(column.len()));
+        }
+
+        let field = DataField::new(&virtual_column.name, meta.data_type.clone());
+        cast_column_field(
+            &ColumnWithField::new(column.clone(), field),
+            &meta.data_type,
+            &virtual_column.data_type,
+            &self.func_ctx,
+        )
+    }
+
+    fn extract_column(
+        &self,
+        source: &ColumnRef,
+        virtual_column: &VirtualColumn,
+    ) -> Result<ColumnRef> {
+        let num_rows = source.len();
+        let source = source.convert_full_column();
+        let (_, validity) = source.validity();
+        let validity = validity.cloned();
+        let inner = Series::remove_nullable(&source);
+        let variant_column: &VariantColumn = Series::check_get(&inner)?;
+
+        let mut builder = NullableColumnBuilder::<VariantValue>::with_capacity(num_rows);
+        for (row, value) in variant_column.scalar_iter().enumerate() {
+            let valid = validity.as_ref().map_or(true, |v| v.get_bit(row));
+            match extract_path(value.as_ref(), &virtual_column.path).filter(|_| valid) {
+                Some(v) => builder.append(&VariantValue::from(v), true),
+                None => builder.append_null(),
+            }
+        }
+        let column = builder.build(num_rows);
+
+        let path_type = NullableType::new_impl(VariantType::new_impl());
+        if virtual_column.data_type == path_type {
+            return Ok(column);
+        }
+        let field = DataField::new(&virtual_column.name, path_type.clone());
+        cast_column_field(
+            &ColumnWithField::new(column, field),
+            &path_type,
+            &virtual_column.data_type,
+            &self.func_ctx,
+        )
+    }
+}
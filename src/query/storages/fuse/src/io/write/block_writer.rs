@@ -25,12 +25,14 @@ use common_exception::Result;
 use common_fuse_meta::meta::BlockMeta;
 use common_fuse_meta::meta::ClusterStatistics;
 use common_fuse_meta::meta::Location;
+use common_fuse_meta::meta::VirtualColumnsMeta;
 use opendal::Operator;
 use tracing::warn;
 use uuid::Uuid;
 
 use crate::index::BlockFilter;
 use crate::io::TableMetaLocationGenerator;
+use crate::io::VirtualColumnBuilder;
 use crate::operations::util;
 use crate::statistics::gen_columns_statistics;
 
@@ -67,9 +69,12 @@ impl<'a> BlockWriter<'a> {
         let (bloom_filter_index_size, bloom_filter_index_location) = self
             .build_block_index(data_accessor, &block, block_id)
             .await?;
+        let virtual_columns = self
+            .build_virtual_columns(data_accessor, &block, block_id)
+            .await?;
         let (file_size, file_meta_data) = write_block(block, data_accessor, &location.0).await?;
         let col_metas = util::column_metas(&file_meta_data)?;
-        let block_meta = BlockMeta::new(
+        let mut block_meta = BlockMeta::new(
             row_count,
             block_size,
             file_size,
@@ -80,6 +85,7 @@ impl<'a> BlockWriter<'a> {
             Some(bloom_filter_index_location),
             bloom_filter_index_size,
         );
+        block_meta.virtual_columns = virtual_columns;
         Ok(block_meta)
     }
 
@@ -115,6 +121,24 @@ impl<'a> BlockWriter<'a> {
         write_data(&data, data_accessor, &location.0).await?;
         Ok((size, location))
     }
+
+    pub async fn build_virtual_columns(
+        &self,
+        data_accessor: &Operator,
+        block: &DataBlock,
+        block_id: Uuid,
+    ) -> Result<Option<VirtualColumnsMeta>> {
+        let location = self
+            .location_generator
+            .block_virtual_columns_location(&block_id);
+        match VirtualColumnBuilder::build(block, location)? {
+            Some((data, virtual_columns)) => {
+                write_data(&data, data_accessor, &virtual_columns.location.0).await?;
+                Ok(Some(virtual_columns))
+            }
+            None => Ok(None),
+        }
+    }
 }
 
 pub async fn write_block(
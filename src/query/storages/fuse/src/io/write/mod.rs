@@ -16,6 +16,7 @@ mod block_compactor;
 mod block_writer;
 mod meta_writer;
 mod segment_writer;
+mod virtual_column_builder;
 
 pub use block_compactor::BlockCompactor;
 pub use block_writer::write_block;
@@ -23,3 +24,5 @@ pub use block_writer::write_data;
 pub use block_writer::BlockWriter;
 pub use meta_writer::write_meta;
 pub use segment_writer::SegmentWriter;
+pub use virtual_column_builder::extract_path;
+pub use virtual_column_builder::VirtualColumnBuilder;
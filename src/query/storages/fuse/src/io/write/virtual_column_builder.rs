@@ -0,0 +1,281 @@
+//  Copyright 2022 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+use common_datablocks::serialize_data_blocks;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_fuse_meta::meta::Location;
+use common_fuse_meta::meta::VirtualColumnMeta;
+use common_fuse_meta::meta::VirtualColumnsMeta;
+use serde_json::Value as JsonValue;
+
+use crate::operations::util;
+use crate::statistics::gen_columns_statistics;
+
+/// Paths present in less than half of the rows are not worth shredding.
+const MIN_PATH_OCCURRENCE_RATIO: f64 = 0.5;
+/// At most this many virtual columns are shredded out of one variant column.
+const MAX_VIRTUAL_COLUMNS_PER_COLUMN: usize = 32;
+/// Stop tracking new paths of a variant column once this many have been seen,
+/// to bound the memory used by documents with unbounded key sets.
+const MAX_TRACKED_PATHS_PER_COLUMN: usize = 1024;
+/// Objects nested deeper than this are not descended into.
+const MAX_PATH_DEPTH: usize = 8;
+
+const DEFAULT_VIRTUAL_BLOCK_WRITE_BUFFER_SIZE: usize = 1024 * 1024;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ScalarKind {
+    Int64,
+    Float64,
+    Boolean,
+    String,
+    Mixed,
+}
+
+impl ScalarKind {
+    fn of(value: &JsonValue) -> ScalarKind {
+        match value {
+            JsonValue::Bool(_) => ScalarKind::Boolean,
+            JsonValue::Number(n) if n.is_i64() => ScalarKind::Int64,
+            JsonValue::Number(_) => ScalarKind::Float64,
+            JsonValue::String(_) => ScalarKind::String,
+            // explicit json nulls can not be told apart from missing values once
+            // shredded, arrays and objects are kept in the source column
+            _ => ScalarKind::Mixed,
+        }
+    }
+
+    fn merge(self, other: ScalarKind) -> ScalarKind {
+        match (self, other) {
+            (a, b) if a == b => a,
+            (ScalarKind::Int64, ScalarKind::Float64) | (ScalarKind::Float64, ScalarKind::Int64) => {
+                ScalarKind::Float64
+            }
+            _ => ScalarKind::Mixed,
+        }
+    }
+
+    fn data_type(self) -> Option<DataTypeImpl> {
+        let data_type = match self {
+            ScalarKind::Int64 => i64::to_data_type(),
+            ScalarKind::Float64 => f64::to_data_type(),
+            ScalarKind::Boolean => bool::to_data_type(),
+            ScalarKind::String => Vu8::to_data_type(),
+            ScalarKind::Mixed => return None,
+        };
+        Some(NullableType::new_impl(data_type))
+    }
+}
+
+struct PathStat {
+    occurrences: usize,
+    kind: ScalarKind,
+}
+
+/// Shreds the frequently occurring scalar paths of the variant columns of a block
+/// into typed columns.
+///
+/// A path is shredded only if it occurs in at least half of the rows and all its
+/// values are of the same scalar kind (integers and floats are merged as floats).
+pub struct VirtualColumnBuilder;
+
+impl VirtualColumnBuilder {
+    /// Returns the serialized sidecar block and its meta, or `None` if there is nothing to shred.
+    pub fn build(
+        block: &DataBlock,
+        location: Location,
+    ) -> Result<Option<(Vec<u8>, VirtualColumnsMeta)>> {
+        let num_rows = block.num_rows();
+        if num_rows == 0 {
+            return Ok(None);
+        }
+
+        let mut fields = vec![];
+        let mut columns = vec![];
+        let mut paths = vec![];
+        for (field, column) in block.schema().fields().iter().zip(block.columns()) {
+            if !remove_nullable(field.data_type())
+                .data_type_id()
+                .is_variant()
+            {
+                continue;
+            }
+            let column = Series::remove_nullable(&column.convert_full_column());
+            let variant_column: &VariantColumn = Series::check_get(&column)?;
+
+            for (path, kind) in Self::frequent_paths(variant_column, num_rows) {
+                let data_type = kind.data_type().unwrap();
+                let values = variant_column
+                    .scalar_iter()
+                    .map(|v| extract_path(v.as_ref(), &path));
+                columns.push(Self::build_column(kind, values, num_rows));
+                fields.push(DataField::new(
+                    &format!("{}:{}", field.name(), path.join(".")),
+                    data_type,
+                ));
+                paths.push((field.name().clone(), path));
+            }
+        }
+
+        if columns.is_empty() {
+            return Ok(None);
+        }
+
+        let schema = DataSchemaRefExt::create(fields);
+        let virtual_block = DataBlock::create(schema.clone(), columns);
+        let mut col_stats = gen_columns_statistics(&virtual_block)?;
+
+        let mut data = Vec::with_capacity(DEFAULT_VIRTUAL_BLOCK_WRITE_BUFFER_SIZE);
+        let (size, file_meta) = serialize_data_blocks(vec![virtual_block], &schema, &mut data)?;
+        let mut col_metas = util::column_metas(&file_meta)?;
+
+        let columns = paths
+            .into_iter()
+            .zip(schema.fields())
+            .enumerate()
+            .map(|(idx, ((source_name, path), field))| VirtualColumnMeta {
+                source_name,
+                path,
+                data_type: field.data_type().clone(),
+                col_stats: col_stats.remove(&(idx as u32)),
+                col_meta: col_metas.remove(&(idx as u32)).unwrap(),
+            })
+            .collect();
+
+        Ok(Some((data, VirtualColumnsMeta {
+            location,
+            size,
+            columns,
+        })))
+    }
+
+    fn frequent_paths(column: &VariantColumn, num_rows: usize) -> Vec<(Vec<String>, ScalarKind)> {
+        let mut stats: HashMap<Vec<String>, PathStat> = HashMap::new();
+        let mut path = vec![];
+        for value in column.scalar_iter() {
+            if let JsonValue::Object(object) = value.as_ref() {
+                Self::collect_paths(object, &mut path, &mut stats);
+            }
+        }
+
+        let min_occurrences = (num_rows as f64 * MIN_PATH_OCCURRENCE_RATIO).ceil() as usize;
+        // BTreeMap keeps the chosen paths in a deterministic order
+        let frequent = stats
+            .into_iter()
+            .filter(|(_, stat)| {
+                stat.kind != ScalarKind::Mixed && stat.occurrences >= min_occurrences
+            })
+            .map(|(path, stat)| (path, stat.kind))
+            .collect::<BTreeMap<_, _>>();
+        frequent
+            .into_iter()
+            .take(MAX_VIRTUAL_COLUMNS_PER_COLUMN)
+            .collect()
+    }
+
+    fn collect_paths(
+        object: &serde_json::Map<String, JsonValue>,
+        path: &mut Vec<String>,
+        stats: &mut HashMap<Vec<String>, PathStat>,
+    ) {
+        for (key, value) in object {
+            // keys that can not be written as a plain `a.b.c` path are skipped
+            if key.is_empty() || key.contains(['.', ':', '[', ']', '"', '\'']) {
+                continue;
+            }
+            path.push(key.clone());
+            // an object is never a scalar, so the path leading to it is not shredded,
+            // but the paths inside it may be
+            Self::record_path(path, ScalarKind::of(value), stats);
+            if let JsonValue::Object(inner) = value {
+                if path.len() < MAX_PATH_DEPTH {
+                    Self::collect_paths(inner, path, stats);
+                }
+            }
+            path.pop();
+        }
+    }
+
+    fn record_path(path: &[String], kind: ScalarKind, stats: &mut HashMap<Vec<String>, PathStat>) {
+        if let Some(stat) = stats.get_mut(path) {
+            stat.occurrences += 1;
+            stat.kind = stat.kind.merge(kind);
+        } else if stats.len() < MAX_TRACKED_PATHS_PER_COLUMN {
+            stats.insert(path.to_vec(), PathStat {
+                occurrences: 1,
+                kind,
+            });
+        }
+    }
+
+    fn build_column<'a>(
+        kind: ScalarKind,
+        values: impl Iterator<Item = Option<&'a JsonValue>>,
+        num_rows: usize,
+    ) -> ColumnRef {
+        match kind {
+            ScalarKind::Int64 => {
+                let mut builder = NullableColumnBuilder::<i64>::with_capacity(num_rows);
+                for value in values {
+                    match value.and_then(|v| v.as_i64()) {
+                        Some(v) => builder.append(v, true),
+                        None => builder.append_null(),
+                    }
+                }
+                builder.build(num_rows)
+            }
+            ScalarKind::Float64 => {
+                let mut builder = NullableColumnBuilder::<f64>::with_capacity(num_rows);
+                for value in values {
+                    match value.and_then(|v| v.as_f64()) {
+                        Some(v) => builder.append(v, true),
+                        None => builder.append_null(),
+                    }
+                }
+                builder.build(num_rows)
+            }
+            ScalarKind::Boolean => {
+                let mut builder = NullableColumnBuilder::<bool>::with_capacity(num_rows);
+                for value in values {
+                    match value.and_then(|v| v.as_bool()) {
+                        Some(v) => builder.append(v, true),
+                        None => builder.append_null(),
+                    }
+                }
+                builder.build(num_rows)
+            }
+            ScalarKind::String | ScalarKind::Mixed => {
+                let mut builder = NullableColumnBuilder::<Vu8>::with_capacity(num_rows);
+                for value in values {
+                    match value.and_then(|v| v.as_str()) {
+                        Some(v) => builder.append(v.as_bytes(), true),
+                        None => builder.append_null(),
+                    }
+                }
+                builder.build(num_rows)
+            }
+        }
+    }
+}
+
+/// Extracts the value at the object path, e.g. `["a", "b"]` for `v:a.b`.
+pub fn extract_path<'a>(value: &'a JsonValue, path: &[String]) -> Option<&'a JsonValue> {
+    path.iter()
+        .try_fold(value, |value, key| value.as_object()?.get(key))
+}
@@ -0,0 +1,130 @@
+//  Copyright 2022 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table::Table;
+use common_catalog::table::TableExt;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_fuse_meta::meta::Statistics as FuseStatistics;
+use common_fuse_meta::meta::TableSnapshot;
+use common_meta_app::schema::TableMeta;
+use uuid::Uuid;
+
+use crate::operations::TableLock;
+use crate::FuseTable;
+
+impl FuseTable {
+    /// Sets the cluster key of the table, while holding the table lock.
+    pub async fn do_alter_cluster_keys(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        cluster_key_str: String,
+    ) -> Result<()> {
+        let table_lock = TableLock::acquire(ctx.clone(), self.get_table_info()).await?;
+
+        // Appends commit without the lock, the change is retried on the version they commit.
+        loop {
+            let latest = self.refresh(ctx.as_ref()).await?;
+            let tbl = FuseTable::try_from_table(latest.as_ref())?;
+
+            let new_table_meta = tbl
+                .get_table_info()
+                .meta
+                .clone()
+                .push_cluster_key(cluster_key_str.clone());
+            match tbl
+                .commit_cluster_keys(ctx.clone(), new_table_meta, &table_lock)
+                .await
+            {
+                Err(e) if e.code() == ErrorCode::table_version_mismatched_code() => continue,
+                res => return res,
+            }
+        }
+    }
+
+    /// Removes the cluster key of the table, while holding the table lock.
+    pub async fn do_drop_cluster_keys(&self, ctx: Arc<dyn TableContext>) -> Result<()> {
+        if self.cluster_key_meta.is_none() {
+            return Ok(());
+        }
+
+        let table_lock = TableLock::acquire(ctx.clone(), self.get_table_info()).await?;
+
+        // Appends commit without the lock, the change is retried on the version they commit.
+        loop {
+            let latest = self.refresh(ctx.as_ref()).await?;
+            let tbl = FuseTable::try_from_table(latest.as_ref())?;
+            if tbl.cluster_key_meta.is_none() {
+                return Ok(());
+            }
+
+            let mut new_table_meta = tbl.get_table_info().meta.clone();
+            new_table_meta.default_cluster_key = None;
+            new_table_meta.default_cluster_key_id = None;
+            match tbl
+                .commit_cluster_keys(ctx.clone(), new_table_meta, &table_lock)
+                .await
+            {
+                Err(e) if e.code() == ErrorCode::table_version_mismatched_code() => continue,
+                res => return res,
+            }
+        }
+    }
+
+    async fn commit_cluster_keys(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        new_table_meta: TableMeta,
+        table_lock: &TableLock,
+    ) -> Result<()> {
+        let cluster_key_meta = new_table_meta.cluster_key();
+        let schema = self.schema().as_ref().clone();
+
+        let prev = self.read_table_snapshot(ctx.clone()).await?;
+        let prev_version = self.snapshot_format_version().await?;
+        let prev_timestamp = prev.as_ref().and_then(|v| v.timestamp);
+        let prev_snapshot_id = prev.as_ref().map(|v| (v.snapshot_id, prev_version));
+        let (summary, segments) = if let Some(v) = prev {
+            (v.summary.clone(), v.segments.clone())
+        } else {
+            (FuseStatistics::default(), vec![])
+        };
+
+        let new_snapshot = TableSnapshot::new(
+            Uuid::new_v4(),
+            &prev_timestamp,
+            prev_snapshot_id,
+            schema,
+            summary,
+            segments,
+            cluster_key_meta,
+        );
+
+        let mut table_info = self.table_info.clone();
+        table_info.meta = new_table_meta;
+
+        FuseTable::commit_to_meta_server(
+            ctx.as_ref(),
+            &table_info,
+            &self.meta_location_generator,
+            new_snapshot,
+            &self.operator,
+            Some(table_lock.revision()),
+        )
+        .await
+    }
+}
@@ -46,8 +46,10 @@ use tracing::warn;
 use uuid::Uuid;
 
 use crate::io::write_meta;
+use crate::io::SegmentsIO;
 use crate::io::TableMetaLocationGenerator;
 use crate::operations::AppendOperationLogEntry;
+use crate::operations::TableLock;
 use crate::operations::TableOperationLog;
 use crate::statistics;
 use crate::FuseTable;
@@ -65,6 +67,22 @@ impl FuseTable {
         operation_log: TableOperationLog,
         overwrite: bool,
     ) -> Result<()> {
+        // An overwrite replaces the data, other mutations of the table wait for the lock instead
+        // of retrying against it. An append only adds segments, which a mutation holding the lock
+        // takes in when it commits, see `commit_mutation()`.
+        let table_lock = if overwrite {
+            match TableLock::acquire(ctx.clone(), self.get_table_info()).await {
+                Ok(table_lock) => Some(table_lock),
+                Err(e) => {
+                    let _ = self::utils::abort_operations(self.get_operator(), operation_log).await;
+                    return Err(e);
+                }
+            }
+        } else {
+            None
+        };
+        let lock_revision = table_lock.as_ref().map(|l| l.revision());
+
         let mut tbl = self;
         let mut latest: Arc<dyn Table>;
 
@@ -94,7 +112,10 @@ impl FuseTable {
 
         let transient = self.transient();
         loop {
-            match tbl.try_commit(ctx.clone(), &operation_log, overwrite).await {
+            match tbl
+                .try_commit(ctx.clone(), &operation_log, overwrite, lock_revision)
+                .await
+            {
                 Ok(_) => {
                     break {
                         if transient {
@@ -164,6 +185,7 @@ impl FuseTable {
         ctx: Arc<dyn TableContext>,
         operation_log: &TableOperationLog,
         overwrite: bool,
+        lock_revision: Option<u64>,
     ) -> Result<()> {
         let prev = self.read_table_snapshot(ctx.clone()).await?;
         let prev_version = self.snapshot_format_version().await?;
@@ -218,10 +240,94 @@ impl FuseTable {
             &self.meta_location_generator,
             new_snapshot,
             &self.operator,
+            lock_revision,
         )
         .await
     }
 
+    /// Commits the snapshot of a mutation built on `base_snapshot`, while `table_lock` is held.
+    ///
+    /// The lock keeps other mutations out, but appends commit without it. If the table is
+    /// appended to after `base_snapshot`, the appended segments are added to the new snapshot and
+    /// the commit is retried.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn commit_mutation(
+        ctx: &Arc<dyn TableContext>,
+        table_info: &TableInfo,
+        location_generator: &TableMetaLocationGenerator,
+        base_snapshot: &TableSnapshot,
+        snapshot: TableSnapshot,
+        operator: &Operator,
+        table_lock: &TableLock,
+    ) -> Result<()> {
+        let mut table_info = table_info.clone();
+        let mut base_segments = base_snapshot.segments.clone();
+        let mut snapshot = snapshot;
+
+        loop {
+            let res = Self::commit_to_meta_server(
+                ctx.as_ref(),
+                &table_info,
+                location_generator,
+                snapshot.clone(),
+                operator,
+                Some(table_lock.revision()),
+            )
+            .await;
+
+            let e = match res {
+                Err(e) if e.code() == ErrorCode::table_version_mismatched_code() => e,
+                res => return res,
+            };
+
+            let catalog = ctx.get_catalog(table_info.catalog())?;
+            let (ident, meta) = catalog
+                .get_table_meta_by_id(table_info.ident.table_id)
+                .await?;
+            table_info.ident = ident;
+            table_info.meta = meta.as_ref().clone();
+
+            let latest = catalog.get_table_by_info(&table_info)?;
+            let latest = FuseTable::try_from_table(latest.as_ref())?;
+            let latest_snapshot = latest.read_table_snapshot(ctx.clone()).await?;
+            let latest_snapshot = match latest_snapshot {
+                Some(v) => v,
+                None => return Err(e),
+            };
+
+            // Appended segments are placed before the previous ones. Anything else means the table
+            // is changed without the lock.
+            let latest_segments = &latest_snapshot.segments;
+            let appended = match latest_segments.len().checked_sub(base_segments.len()) {
+                Some(n) if latest_segments[n..] == base_segments[..] => &latest_segments[..n],
+                _ => return Err(e),
+            };
+            debug!(
+                "table {} is appended {} segments during a mutation, commit again",
+                table_info.ident,
+                appended.len()
+            );
+
+            let segments_io = SegmentsIO::create(ctx.clone(), operator.clone());
+            let mut summary = snapshot.summary.clone();
+            for segment in segments_io.read_segments(appended).await? {
+                summary = statistics::merge_statistics(&summary, &segment?.summary)?;
+            }
+
+            let mut segments = appended.to_vec();
+            segments.append(&mut snapshot.segments);
+
+            let mut new_snapshot = TableSnapshot::from_previous(&latest_snapshot);
+            new_snapshot.schema = snapshot.schema;
+            new_snapshot.cluster_key_meta = snapshot.cluster_key_meta;
+            new_snapshot.segments = segments;
+            new_snapshot.summary = summary;
+
+            base_segments = latest_segments.clone();
+            snapshot = new_snapshot;
+        }
+    }
+
     fn merge_table_operations(
         schema: &DataSchema,
         previous: Option<Arc<TableSnapshot>>,
@@ -264,6 +370,7 @@ impl FuseTable {
         location_generator: &TableMetaLocationGenerator,
         snapshot: TableSnapshot,
         operator: &Operator,
+        lock_revision: Option<u64>,
     ) -> Result<()> {
         let snapshot_location = location_generator
             .snapshot_location_from_uuid(&snapshot.snapshot_id, snapshot.format_version())?;
@@ -300,6 +407,7 @@ impl FuseTable {
             table_id,
             seq: MatchSeq::Exact(table_version),
             new_table_meta,
+            lock_revision,
         };
 
         // 3. let's roll
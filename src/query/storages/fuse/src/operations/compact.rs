@@ -14,6 +14,7 @@
 
 use std::sync::Arc;
 
+use common_catalog::table::TableExt;
 use common_exception::Result;
 use common_legacy_planners::ReadDataSourcePlan;
 use common_legacy_planners::SourceInfo;
@@ -24,6 +25,7 @@ use common_pipeline_transforms::processors::transforms::TransformCompact;
 
 use super::FuseTableSink;
 use crate::operations::CompactMutator;
+use crate::operations::TableLock;
 use crate::statistics::ClusterStatsGenerator;
 use crate::FuseTable;
 use crate::Table;
@@ -38,7 +40,12 @@ impl FuseTable {
         ctx: Arc<dyn TableContext>,
         pipeline: &mut Pipeline,
     ) -> Result<Option<Arc<dyn TableMutator>>> {
-        let snapshot_opt = self.read_table_snapshot(ctx.clone()).await?;
+        let table_lock = Arc::new(TableLock::acquire(ctx.clone(), self.get_table_info()).await?);
+        // Builds the mutation on the latest version, which is committed against under the lock.
+        let latest = self.refresh(ctx.as_ref()).await?;
+        let tbl = FuseTable::try_from_table(latest.as_ref())?;
+
+        let snapshot_opt = tbl.read_table_snapshot(ctx.clone()).await?;
         let base_snapshot = if let Some(val) = snapshot_opt {
             val
         } else {
@@ -50,19 +57,20 @@ impl FuseTable {
             return Ok(None);
         }
 
-        let block_compactor = self.get_block_compactor();
+        let block_compactor = tbl.get_block_compactor();
         let block_per_seg =
-            self.get_option(FUSE_OPT_KEY_BLOCK_PER_SEGMENT, DEFAULT_BLOCK_PER_SEGMENT);
+            tbl.get_option(FUSE_OPT_KEY_BLOCK_PER_SEGMENT, DEFAULT_BLOCK_PER_SEGMENT);
 
         let mut mutator = CompactMutator::try_create(
             ctx.clone(),
             base_snapshot,
             block_compactor.clone(),
-            self.meta_location_generator().clone(),
+            tbl.meta_location_generator().clone(),
             block_per_seg,
-            self.cluster_key_meta.is_some(),
-            self.operator.clone(),
+            tbl.cluster_key_meta.is_some(),
+            tbl.operator.clone(),
         )?;
+        mutator.set_table_lock(table_lock, tbl.table_info.clone());
         let need_compact = mutator.blocks_select().await?;
         if !need_compact {
             return Ok(None);
@@ -71,12 +79,12 @@ impl FuseTable {
         let partitions_total = mutator.partitions_total();
         let (statistics, parts) = Self::read_partitions_with_metas(
             ctx.clone(),
-            self.table_info.schema(),
+            tbl.table_info.schema(),
             None,
             mutator.selected_blocks(),
             partitions_total,
         )?;
-        let table_info = self.get_table_info();
+        let table_info = tbl.get_table_info();
         let description = statistics.get_description(table_info);
         let plan = ReadDataSourcePlan {
             catalog: table_info.catalog().to_string(),
@@ -85,12 +93,12 @@ impl FuseTable {
             parts,
             statistics,
             description,
-            tbl_args: self.table_args(),
+            tbl_args: tbl.table_args(),
             push_downs: None,
         };
 
         ctx.try_set_partitions(plan.parts.clone())?;
-        self.do_read_data(ctx.clone(), &plan, pipeline)?;
+        tbl.do_read_data(ctx.clone(), &plan, pipeline)?;
 
         pipeline.add_transform(|transform_input_port, transform_output_port| {
             TransformCompact::try_create(
@@ -110,7 +118,7 @@ impl FuseTable {
                     ctx.clone(),
                     block_per_seg,
                     mutator.get_storage_operator(),
-                    self.meta_location_generator().clone(),
+                    tbl.meta_location_generator().clone(),
                     ClusterStatsGenerator::default(),
                     None,
                 )?,
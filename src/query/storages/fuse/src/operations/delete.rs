@@ -15,6 +15,7 @@
 use std::sync::Arc;
 
 use common_catalog::table::Table;
+use common_catalog::table::TableExt;
 use common_catalog::table_context::TableContext;
 use common_datavalues::DataSchemaRefExt;
 use common_exception::ErrorCode;
@@ -30,13 +31,19 @@ use tracing::debug;
 use crate::operations::mutation::delete_from_block;
 use crate::operations::mutation::deletion_mutator::Deletion;
 use crate::operations::mutation::DeletionMutator;
+use crate::operations::TableLock;
 use crate::pruning::BlockPruner;
 use crate::statistics::ClusterStatsGenerator;
 use crate::FuseTable;
 
 impl FuseTable {
     pub async fn do_delete(&self, ctx: Arc<dyn TableContext>, plan: &DeletePlan) -> Result<()> {
-        let snapshot_opt = self.read_table_snapshot(ctx.clone()).await?;
+        let table_lock = TableLock::acquire(ctx.clone(), self.get_table_info()).await?;
+        // Deletes from the latest version, no other mutation can commit until the lock is released.
+        let latest = self.refresh(ctx.as_ref()).await?;
+        let tbl = FuseTable::try_from_table(latest.as_ref())?;
+
+        let snapshot_opt = tbl.read_table_snapshot(ctx.clone()).await?;
 
         // check if table is empty
         let snapshot = if let Some(val) = snapshot_opt {
@@ -59,7 +66,8 @@ impl FuseTable {
                     "expression should be valid, but not",
                 ));
             }
-            self.delete_rows(ctx, &snapshot, &expr[0], plan).await
+            tbl.delete_rows(ctx, &snapshot, &expr[0], plan, &table_lock)
+                .await
         } else {
             // deleting the whole table... just a truncate
             let purge = false;
@@ -67,7 +75,7 @@ impl FuseTable {
                 "unconditionally delete from table, {}.{}.{}",
                 plan.catalog_name, plan.database_name, plan.table_name
             );
-            self.do_truncate(ctx.clone(), purge).await
+            tbl.truncate_locked(ctx.clone(), purge, &table_lock).await
         }
     }
 
@@ -77,6 +85,7 @@ impl FuseTable {
         snapshot: &Arc<TableSnapshot>,
         filter: &LegacyExpression,
         plan: &DeletePlan,
+        table_lock: &TableLock,
     ) -> Result<()> {
         let cluster_stats_gen = self.cluster_stats_gen(ctx.clone())?;
         let mut deletion_collector = DeletionMutator::try_create(
@@ -94,6 +103,7 @@ impl FuseTable {
             prewhere: None, // TBD: if delete rows need prewhere optimization
             limit: None,
             order_by: vec![],
+            virtual_columns: vec![],
         };
         let push_downs = Some(extras);
         let segments_location = snapshot.segments.clone();
@@ -129,21 +139,26 @@ impl FuseTable {
                 }
             }
         }
-        self.commit_deletion(ctx.as_ref(), deletion_collector).await
+        self.commit_deletion(&ctx, snapshot, deletion_collector, table_lock)
+            .await
     }
 
     async fn commit_deletion(
         &self,
-        ctx: &dyn TableContext,
+        ctx: &Arc<dyn TableContext>,
+        base_snapshot: &TableSnapshot,
         del_holder: DeletionMutator,
+        table_lock: &TableLock,
     ) -> Result<()> {
         let new_snapshot = del_holder.into_new_snapshot().await?;
-        Self::commit_to_meta_server(
+        Self::commit_mutation(
             ctx,
             self.get_table_info(),
             &self.meta_location_generator,
+            base_snapshot,
             new_snapshot,
             &self.operator,
+            table_lock,
         )
         .await?;
         // TODO check if error is recoverable, and try to resolve the conflict
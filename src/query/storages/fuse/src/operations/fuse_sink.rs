@@ -29,6 +29,7 @@ use common_fuse_meta::caches::CacheManager;
 use common_fuse_meta::meta::Location;
 use common_fuse_meta::meta::SegmentInfo;
 use common_fuse_meta::meta::Statistics;
+use common_fuse_meta::meta::VirtualColumnsMeta;
 use common_pipeline_core::processors::port::OutputPort;
 use common_storages_index::*;
 use opendal::Operator;
@@ -36,6 +37,7 @@ use opendal::Operator;
 use super::AppendOperationLogEntry;
 use crate::io;
 use crate::io::TableMetaLocationGenerator;
+use crate::io::VirtualColumnBuilder;
 use crate::pipelines::processors::port::InputPort;
 use crate::pipelines::processors::processor::Event;
 use crate::pipelines::processors::processor::ProcessorPtr;
@@ -59,6 +61,7 @@ enum State {
         meta_data: Box<ThriftFileMetaData>,
         block_statistics: BlockStatistics,
         bloom_index_state: BloomIndexState,
+        virtual_columns_state: Option<(Vec<u8>, VirtualColumnsMeta)>,
     },
     GenerateSegment,
     SerializedSegment {
@@ -185,6 +188,12 @@ impl Processor for FuseTableSink {
                     }
                 };
 
+                let virtual_columns_state = VirtualColumnBuilder::build(
+                    &block,
+                    self.meta_locations
+                        .block_virtual_columns_location(&block_id),
+                )?;
+
                 let block_statistics =
                     BlockStatistics::from(&block, block_location.0, cluster_stats)?;
                 // we need a configuration of block size threshold here
@@ -198,6 +207,7 @@ impl Processor for FuseTableSink {
                     block_statistics,
                     meta_data: Box::new(meta_data),
                     bloom_index_state,
+                    virtual_columns_state,
                 };
             }
             State::GenerateSegment => {
@@ -246,6 +256,7 @@ impl Processor for FuseTableSink {
                 meta_data,
                 block_statistics,
                 bloom_index_state,
+                virtual_columns_state,
             } => {
                 // write data block
                 io::write_data(
@@ -263,6 +274,16 @@ impl Processor for FuseTableSink {
                 )
                 .await?;
 
+                // write virtual columns
+                let virtual_columns = match virtual_columns_state {
+                    Some((data, virtual_columns)) => {
+                        io::write_data(&data, &self.data_accessor, &virtual_columns.location.0)
+                            .await?;
+                        Some(virtual_columns)
+                    }
+                    None => None,
+                };
+
                 let bloom_filter_index_size = bloom_index_state.size;
                 self.accumulator.add_block(
                     size,
@@ -270,6 +291,7 @@ impl Processor for FuseTableSink {
                     block_statistics,
                     Some(bloom_index_state.location),
                     bloom_filter_index_size,
+                    virtual_columns,
                 )?;
 
                 if self.accumulator.summary_block_count >= self.num_block_threshold {
@@ -171,6 +171,7 @@ impl FuseTable {
 
         let mut blocks_need_to_delete = HashSet::new();
         let mut blooms_need_to_delete = HashSet::new();
+        let mut virtual_blocks_need_to_delete = HashSet::new();
         for segment in segments {
             let segment = segment?;
 
@@ -186,6 +187,11 @@ impl FuseTable {
                 if let Some(bloom_index_location) = &block_meta.bloom_filter_index_location {
                     blooms_need_to_delete.insert(bloom_index_location.0.to_string());
                 }
+
+                // Virtual columns file.
+                if let Some(virtual_columns) = &block_meta.virtual_columns {
+                    virtual_blocks_need_to_delete.insert(virtual_columns.location.0.to_string());
+                }
             }
         }
 
@@ -211,6 +217,17 @@ impl FuseTable {
             info!("Finish to purge bloom index files");
         }
 
+        // Try to remove virtual column files in parallel.
+        {
+            let locations = Vec::from_iter(virtual_blocks_need_to_delete);
+            info!(
+                "Prepare to purge virtual column files, numbers:{}",
+                locations.len()
+            );
+            fuse_file.remove_file_in_batch(&locations).await?;
+            info!("Finish to purge virtual column files");
+        }
+
         // Try to remove segment files in parallel.
         {
             let locations = Vec::from_iter(
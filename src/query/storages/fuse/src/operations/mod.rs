@@ -13,6 +13,7 @@
 //  limitations under the License.
 
 mod append;
+mod cluster_keys;
 mod commit;
 mod compact;
 mod delete;
@@ -24,6 +25,7 @@ mod operation_log;
 mod read;
 mod read_partitions;
 mod recluster;
+mod table_lock;
 mod truncate;
 
 pub mod util;
@@ -35,4 +37,5 @@ pub use mutation::DeletionMutator;
 pub use mutation::ReclusterMutator;
 pub use operation_log::AppendOperationLogEntry;
 pub use operation_log::TableOperationLog;
+pub use table_lock::TableLock;
 pub use util::column_metas;
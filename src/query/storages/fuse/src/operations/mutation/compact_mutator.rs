@@ -30,6 +30,7 @@ use crate::io::SegmentWriter;
 use crate::io::SegmentsIO;
 use crate::io::TableMetaLocationGenerator;
 use crate::operations::AppendOperationLogEntry;
+use crate::operations::TableLock;
 use crate::statistics::merge_statistics;
 use crate::statistics::reducers::reduce_block_metas;
 use crate::statistics::reducers::reduce_statistics;
@@ -50,6 +51,7 @@ pub struct CompactMutator {
     block_per_seg: usize,
     // is_cluster indicates whether the table contains cluster key.
     is_cluster: bool,
+    table_lock: Option<(Arc<TableLock>, TableInfo)>,
 }
 
 impl CompactMutator {
@@ -73,9 +75,16 @@ impl CompactMutator {
             summary: Statistics::default(),
             block_per_seg,
             is_cluster,
+            table_lock: None,
         })
     }
 
+    /// Commits against `table_info` with the fencing token of `table_lock`, instead of the table
+    /// info given to `try_commit`. The lock is released once the mutator is dropped.
+    pub fn set_table_lock(&mut self, table_lock: Arc<TableLock>, table_info: TableInfo) {
+        self.table_lock = Some((table_lock, table_info));
+    }
+
     pub fn partitions_total(&self) -> usize {
         self.base_snapshot.summary.block_count as usize
     }
@@ -180,13 +189,30 @@ impl TableMutator for CompactMutator {
         new_snapshot.segments.append(&mut merged_segments);
         new_snapshot.summary = merge_statistics(&self.summary, &merged_summary)?;
 
-        FuseTable::commit_to_meta_server(
-            ctx.as_ref(),
-            table_info,
-            &self.location_generator,
-            new_snapshot,
-            &self.data_accessor,
-        )
-        .await
+        match &self.table_lock {
+            Some((table_lock, locked_table_info)) => {
+                FuseTable::commit_mutation(
+                    &ctx,
+                    locked_table_info,
+                    &self.location_generator,
+                    &snapshot,
+                    new_snapshot,
+                    &self.data_accessor,
+                    table_lock,
+                )
+                .await
+            }
+            None => {
+                FuseTable::commit_to_meta_server(
+                    ctx.as_ref(),
+                    table_info,
+                    &self.location_generator,
+                    new_snapshot,
+                    &self.data_accessor,
+                    None,
+                )
+                .await
+            }
+        }
     }
 }
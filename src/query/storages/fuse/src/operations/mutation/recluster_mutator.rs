@@ -31,6 +31,7 @@ use crate::io::BlockCompactor;
 use crate::io::TableMetaLocationGenerator;
 use crate::operations::mutation::BaseMutator;
 use crate::operations::AppendOperationLogEntry;
+use crate::operations::TableLock;
 use crate::sessions::TableContext;
 use crate::statistics::merge_statistics;
 use crate::FuseTable;
@@ -47,6 +48,7 @@ pub struct ReclusterMutator {
     block_compactor: BlockCompactor,
     threshold: f64,
     data_accessor: Operator,
+    table_lock: Option<(Arc<TableLock>, TableInfo)>,
 }
 
 impl ReclusterMutator {
@@ -73,9 +75,16 @@ impl ReclusterMutator {
             block_compactor,
             threshold,
             data_accessor,
+            table_lock: None,
         })
     }
 
+    /// Commits against `table_info` with the fencing token of `table_lock`, instead of the table
+    /// info given to `try_commit`. The lock is released once the mutator is dropped.
+    pub fn set_table_lock(&mut self, table_lock: Arc<TableLock>, table_info: TableInfo) {
+        self.table_lock = Some((table_lock, table_info));
+    }
+
     pub fn partitions_total(&self) -> usize {
         self.base_mutator.base_snapshot.summary.block_count as usize
     }
@@ -232,14 +241,31 @@ impl TableMutator for ReclusterMutator {
 
         let new_snapshot = base_mutator.into_new_snapshot(segments, summary).await?;
 
-        FuseTable::commit_to_meta_server(
-            ctx.as_ref(),
-            table_info,
-            &self.base_mutator.location_generator,
-            new_snapshot,
-            &self.data_accessor,
-        )
-        .await?;
+        match &self.table_lock {
+            Some((table_lock, locked_table_info)) => {
+                FuseTable::commit_mutation(
+                    &ctx,
+                    locked_table_info,
+                    &self.base_mutator.location_generator,
+                    &self.base_mutator.base_snapshot,
+                    new_snapshot,
+                    &self.data_accessor,
+                    table_lock,
+                )
+                .await?;
+            }
+            None => {
+                FuseTable::commit_to_meta_server(
+                    ctx.as_ref(),
+                    table_info,
+                    &self.base_mutator.location_generator,
+                    new_snapshot,
+                    &self.data_accessor,
+                    None,
+                )
+                .await?;
+            }
+        }
         Ok(())
     }
 }
@@ -21,6 +21,7 @@ use common_base::base::Runtime;
 use common_catalog::table_context::TableContext;
 use common_datablocks::DataBlock;
 use common_datavalues::ColumnRef;
+use common_datavalues::DataSchemaRef;
 use common_datavalues::DataSchemaRefExt;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -39,6 +40,8 @@ use common_pipeline_transforms::processors::ExpressionExecutor;
 
 use crate::fuse_lazy_part::FuseLazyPartInfo;
 use crate::io::BlockReader;
+use crate::io::VirtualColumnReader;
+use crate::io::VirtualColumnsData;
 use crate::operations::read::State::Generated;
 use crate::FuseTable;
 
@@ -161,6 +164,22 @@ impl FuseTable {
         let prewhere_filter = Arc::new(prewhere_filter);
         let remain_reader = Arc::new(remain_reader);
 
+        // virtual columns are never pushed down together with prewhere
+        let virtual_reader = match &plan.push_downs {
+            Some(Extras {
+                virtual_columns,
+                prewhere: None,
+                ..
+            }) if !virtual_columns.is_empty() => Some(VirtualColumnReader::create(
+                self.operator.clone(),
+                table_schema,
+                self.projection_of_push_downs(&plan.push_downs),
+                virtual_columns.clone(),
+                ctx.try_get_function_context()?,
+            )?),
+            _ => None,
+        };
+
         let max_threads = ctx.get_settings().get_max_threads()? as usize;
 
         let mut source_builder = SourcePipeBuilder::create();
@@ -176,6 +195,7 @@ impl FuseTable {
                     prewhere_reader.clone(),
                     prewhere_filter.clone(),
                     remain_reader.clone(),
+                    virtual_reader.clone(),
                 )?,
             );
         }
@@ -197,6 +217,7 @@ enum State {
     ReadDataRemain(PartInfoPtr, PrewhereData),
     PrewhereFilter(PartInfoPtr, DataChunks),
     Deserialize(PartInfoPtr, DataChunks, Option<PrewhereData>),
+    DeserializeVirtual(PartInfoPtr, VirtualColumnsData),
     Generated(Option<PartInfoPtr>, DataBlock),
     Finish,
 }
@@ -211,6 +232,8 @@ struct FuseTableSource {
     prewhere_reader: Arc<BlockReader>,
     prewhere_filter: Arc<Option<ExpressionExecutor>>,
     remain_reader: Arc<Option<BlockReader>>,
+    virtual_reader: Option<Arc<VirtualColumnReader>>,
+    output_schema: DataSchemaRef,
 }
 
 impl FuseTableSource {
@@ -221,8 +244,13 @@ impl FuseTableSource {
         prewhere_reader: Arc<BlockReader>,
         prewhere_filter: Arc<Option<ExpressionExecutor>>,
         remain_reader: Arc<Option<BlockReader>>,
+        virtual_reader: Option<Arc<VirtualColumnReader>>,
     ) -> Result<ProcessorPtr> {
         let scan_progress = ctx.get_scan_progress();
+        let output_schema = match &virtual_reader {
+            Some(virtual_reader) => virtual_reader.schema(),
+            None => output_reader.schema(),
+        };
         Ok(ProcessorPtr::create(Box::new(FuseTableSource {
             ctx,
             output,
@@ -232,19 +260,21 @@ impl FuseTableSource {
             prewhere_reader,
             prewhere_filter,
             remain_reader,
+            virtual_reader,
+            output_schema,
         })))
     }
 
     fn generate_one_block(&mut self, block: DataBlock) -> Result<()> {
         let new_part = self.ctx.try_get_part();
         // resort and prune columns
-        let block = block.resort(self.output_reader.schema())?;
+        let block = block.resort(self.output_schema.clone())?;
         self.state = State::Generated(new_part, block);
         Ok(())
     }
 
     fn generate_one_empty_block(&mut self) -> Result<()> {
-        let schema = self.output_reader.schema();
+        let schema = self.output_schema.clone();
         let new_part = self.ctx.try_get_part();
         self.state = Generated(new_part, DataBlock::empty_with_schema(schema));
         Ok(())
@@ -300,6 +330,7 @@ impl Processor for FuseTableSource {
             State::ReadDataRemain(_, _) => Ok(Event::Async),
             State::PrewhereFilter(_, _) => Ok(Event::Sync),
             State::Deserialize(_, _, _) => Ok(Event::Sync),
+            State::DeserializeVirtual(_, _) => Ok(Event::Sync),
             State::Generated(_, _) => Err(ErrorCode::LogicalError("It's a bug.")),
         }
     }
@@ -349,6 +380,21 @@ impl Processor for FuseTableSource {
                 self.generate_one_block(data_block)?;
                 Ok(())
             }
+            State::DeserializeVirtual(part, data) => match &self.virtual_reader {
+                Some(virtual_reader) => {
+                    let block = virtual_reader.deserialize(part, data)?;
+                    let progress_values = ProgressValues {
+                        rows: block.num_rows(),
+                        bytes: block.memory_size(),
+                    };
+                    self.scan_progress.incr(&progress_values);
+                    self.generate_one_block(block)?;
+                    Ok(())
+                }
+                None => Err(ErrorCode::LogicalError(
+                    "It's a bug. No virtual column reader",
+                )),
+            },
             State::PrewhereFilter(part, chunks) => {
                 // deserialize prewhere data block first
                 let data_block = self.prewhere_reader.deserialize(part.clone(), chunks)?;
@@ -395,6 +441,12 @@ impl Processor for FuseTableSource {
     async fn async_process(&mut self) -> Result<()> {
         match std::mem::replace(&mut self.state, State::Finish) {
             State::ReadDataPrewhere(Some(part)) => {
+                if let Some(virtual_reader) = &self.virtual_reader {
+                    let data = virtual_reader.read_columns_data(part.clone()).await?;
+                    self.state = State::DeserializeVirtual(part, data);
+                    return Ok(());
+                }
+
                 let chunks = self.prewhere_reader.read_columns_data(part.clone()).await?;
 
                 if self.prewhere_filter.is_some() {
@@ -35,6 +35,7 @@ use crate::fuse_lazy_part::FuseLazyPartInfo;
 use crate::fuse_part::ColumnLeaves;
 use crate::fuse_part::ColumnMeta;
 use crate::fuse_part::FusePartInfo;
+use crate::io::VirtualColumnReader;
 use crate::pruning::BlockPruner;
 use crate::FuseTable;
 
@@ -171,7 +172,12 @@ impl FuseTable {
             Some(extras) => match &extras.projection {
                 None => Self::all_columns_partitions(blocks_metas, limit),
                 Some(projection) => {
-                    Self::projection_partitions(blocks_metas, column_leaves, projection, limit)
+                    // the source columns are read if the virtual columns are not shredded
+                    let projection = VirtualColumnReader::projection_with_sources(
+                        projection,
+                        &extras.virtual_columns,
+                    );
+                    Self::projection_partitions(blocks_metas, column_leaves, &projection, limit)
                 }
             },
         };
@@ -278,6 +284,7 @@ impl FuseTable {
             rows_count,
             columns_meta,
             meta.compression(),
+            meta.virtual_columns.clone(),
         )
     }
 
@@ -313,6 +320,7 @@ impl FuseTable {
             rows_count,
             columns_meta,
             meta.compression(),
+            meta.virtual_columns.clone(),
         )
     }
 
@@ -325,8 +333,9 @@ impl FuseTable {
             Extras {
                 projection: Some(projs),
                 filters,
+                virtual_columns,
                 ..
-            } if projs.is_empty() && filters.is_empty() => {
+            } if projs.is_empty() && filters.is_empty() && virtual_columns.is_empty() => {
                 let summary = &snapshot.summary;
                 let stats = Statistics {
                     read_rows: summary.row_count as usize,
@@ -16,6 +16,7 @@ use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use common_catalog::table::Table;
+use common_catalog::table::TableExt;
 use common_catalog::table_context::TableContext;
 use common_datablocks::SortColumnDescription;
 use common_exception::Result;
@@ -33,6 +34,7 @@ use common_pipeline_transforms::processors::transforms::TransformSortPartial;
 
 use crate::operations::FuseTableSink;
 use crate::operations::ReclusterMutator;
+use crate::operations::TableLock;
 use crate::pruning::BlockPruner;
 use crate::FuseTable;
 use crate::TableMutator;
@@ -52,7 +54,12 @@ impl FuseTable {
             return Ok(None);
         }
 
-        let snapshot_opt = self.read_table_snapshot(ctx.clone()).await?;
+        let table_lock = Arc::new(TableLock::acquire(ctx.clone(), self.get_table_info()).await?);
+        // Builds the mutation on the latest version, which is committed against under the lock.
+        let latest = self.refresh(ctx.as_ref()).await?;
+        let tbl = FuseTable::try_from_table(latest.as_ref())?;
+
+        let snapshot_opt = tbl.read_table_snapshot(ctx.clone()).await?;
         let snapshot = if let Some(val) = snapshot_opt {
             val
         } else {
@@ -60,18 +67,18 @@ impl FuseTable {
             return Ok(None);
         };
 
-        let schema = self.table_info.schema();
+        let schema = tbl.table_info.schema();
         let segments_locations = snapshot.segments.clone();
         let block_metas = BlockPruner::prune(
             &ctx,
-            self.operator.clone(),
+            tbl.operator.clone(),
             schema,
             &push_downs,
             segments_locations,
         )
         .await?;
 
-        let default_cluster_key_id = self.cluster_key_meta.clone().unwrap().0;
+        let default_cluster_key_id = tbl.cluster_key_meta.clone().unwrap().0;
         let mut blocks_map: BTreeMap<i32, Vec<(usize, BlockMeta)>> = BTreeMap::new();
         block_metas.iter().for_each(|(idx, b)| {
             if let Some(stats) = &b.cluster_stats {
@@ -84,8 +91,8 @@ impl FuseTable {
             }
         });
 
-        let block_compactor = self.get_block_compactor();
-        let avg_depth_threshold = self.get_option(
+        let block_compactor = tbl.get_block_compactor();
+        let avg_depth_threshold = tbl.get_option(
             FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD,
             DEFAULT_AVG_DEPTH_THRESHOLD,
         );
@@ -96,16 +103,17 @@ impl FuseTable {
             1.0
         };
         let block_per_seg =
-            self.get_option(FUSE_OPT_KEY_BLOCK_PER_SEGMENT, DEFAULT_BLOCK_PER_SEGMENT);
+            tbl.get_option(FUSE_OPT_KEY_BLOCK_PER_SEGMENT, DEFAULT_BLOCK_PER_SEGMENT);
         let mut mutator = ReclusterMutator::try_create(
             ctx.clone(),
-            self.meta_location_generator.clone(),
+            tbl.meta_location_generator.clone(),
             snapshot,
             threshold,
             block_compactor.clone(),
             blocks_map,
-            self.operator.clone(),
+            tbl.operator.clone(),
         )?;
+        mutator.set_table_lock(table_lock, tbl.table_info.clone());
 
         let need_recluster = mutator.blocks_select().await?;
         if !need_recluster {
@@ -115,12 +123,12 @@ impl FuseTable {
         let partitions_total = mutator.partitions_total();
         let (statistics, parts) = Self::read_partitions_with_metas(
             ctx.clone(),
-            self.table_info.schema(),
+            tbl.table_info.schema(),
             None,
             mutator.selected_blocks(),
             partitions_total,
         )?;
-        let table_info = self.get_table_info();
+        let table_info = tbl.get_table_info();
         let description = statistics.get_description(table_info);
         let plan = ReadDataSourcePlan {
             catalog: table_info.catalog().to_string(),
@@ -129,14 +137,14 @@ impl FuseTable {
             parts,
             statistics,
             description,
-            tbl_args: self.table_args(),
+            tbl_args: tbl.table_args(),
             push_downs: None,
         };
 
         ctx.try_set_partitions(plan.parts.clone())?;
-        self.do_read_data(ctx.clone(), &plan, pipeline)?;
+        tbl.do_read_data(ctx.clone(), &plan, pipeline)?;
 
-        let cluster_stats_gen = self.get_cluster_stats_gen(
+        let cluster_stats_gen = tbl.get_cluster_stats_gen(
             ctx.clone(),
             pipeline,
             mutator.level() + 1,
@@ -194,8 +202,8 @@ impl FuseTable {
                     input_port,
                     ctx.clone(),
                     block_per_seg,
-                    self.operator.clone(),
-                    self.meta_location_generator().clone(),
+                    tbl.operator.clone(),
+                    tbl.meta_location_generator().clone(),
                     cluster_stats_gen.clone(),
                     None,
                 )?,
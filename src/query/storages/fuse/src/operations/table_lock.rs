@@ -0,0 +1,194 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use common_base::base::tokio;
+use common_base::base::tokio::sync::oneshot;
+use common_catalog::catalog::Catalog;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::CreateTableLockRevReq;
+use common_meta_app::schema::DeleteTableLockRevReq;
+use common_meta_app::schema::ExtendTableLockRevReq;
+use common_meta_app::schema::ListTableLocksReq;
+use common_meta_app::schema::TableInfo;
+use tracing::debug;
+use tracing::warn;
+
+/// A lock revision expires if it is not extended within this time.
+const TABLE_LOCK_EXPIRE_SECS: u64 = 10;
+
+/// The interval to extend a lock revision, well within `TABLE_LOCK_EXPIRE_SECS`.
+const TABLE_LOCK_HEARTBEAT_INTERVAL: Duration = Duration::from_secs(3);
+
+/// The interval to check if a waiting revision becomes the holder, if the locks can not be watched.
+const TABLE_LOCK_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// The interval to check if a waiting revision becomes the holder while watching the locks.
+///
+/// An expired revision is not deleted, thus it is found only by checking.
+const TABLE_LOCK_RECHECK_INTERVAL: Duration = Duration::from_secs(TABLE_LOCK_EXPIRE_SECS);
+
+/// A distributed lock of a table, held by a mutation until it is committed.
+///
+/// Every acquirer creates a revision in the meta service; the smallest alive revision holds the
+/// lock, the others wait in revision order. The revision is also the fencing token: the commit of
+/// a mutation fails if the revision expired, e.g. because the heartbeat was lost.
+///
+/// Dropping the lock releases it.
+pub struct TableLock {
+    table_id: u64,
+    revision: u64,
+    shutdown: Option<oneshot::Sender<()>>,
+}
+
+impl TableLock {
+    /// Waits until the lock of the table is held, for at most the `table_lock_timeout` setting.
+    pub async fn acquire(ctx: Arc<dyn TableContext>, table_info: &TableInfo) -> Result<TableLock> {
+        let catalog = ctx.get_catalog(table_info.catalog())?;
+        let table_id = table_info.ident.table_id;
+        let timeout = Duration::from_secs(ctx.get_settings().get_table_lock_timeout()?);
+
+        let user = ctx.get_current_user().map(|u| u.name).unwrap_or_default();
+        let req = CreateTableLockRevReq {
+            table_id,
+            expire_at: expire_at(),
+            user,
+            node: ctx.get_cluster().local_id.clone(),
+            query_id: ctx.get_id(),
+        };
+        let revision = catalog.create_table_lock_rev(req).await?.revision;
+
+        // Keeps the revision alive while waiting as well, otherwise it falls off the queue.
+        let (tx, rx) = oneshot::channel();
+        tokio::spawn(heartbeat(catalog.clone(), table_id, revision, rx));
+        let lock = TableLock {
+            table_id,
+            revision,
+            shutdown: Some(tx),
+        };
+
+        // Watches before listing, then a revision released in between is not missed.
+        let mut released = catalog
+            .watch_table_locks(table_id)
+            .await
+            .unwrap_or_else(|e| {
+                warn!("watch table lock {} failed, poll instead: {}", table_id, e);
+                None
+            });
+
+        let start = Instant::now();
+        loop {
+            let locks = catalog
+                .list_table_locks(ListTableLocksReq {
+                    table_ids: vec![table_id],
+                })
+                .await?;
+
+            match locks.first() {
+                Some(holder) if holder.revision == revision => {
+                    debug!("table lock {}/{} acquired", table_id, revision);
+                    return Ok(lock);
+                }
+                _ if !locks.iter().any(|l| l.revision == revision) => {
+                    return Err(ErrorCode::TableLockExpired(format!(
+                        "the lock revision {} of table {} expired while waiting",
+                        revision, table_id
+                    )));
+                }
+                _ => {}
+            }
+
+            let remaining = timeout.saturating_sub(start.elapsed());
+            if remaining.is_zero() {
+                return Err(ErrorCode::TableLockAcquireTimeout(format!(
+                    "can not acquire the lock of table {} in {} secs, held by revision {}",
+                    table_id,
+                    timeout.as_secs(),
+                    locks[0].revision
+                )));
+            }
+
+            match released.as_mut() {
+                Some(rx) => {
+                    let wait = remaining.min(TABLE_LOCK_RECHECK_INTERVAL);
+                    if let Ok(None) = tokio::time::timeout(wait, rx.recv()).await {
+                        debug!("table lock {} watch closed, poll instead", table_id);
+                        released = None;
+                    }
+                }
+                None => tokio::time::sleep(remaining.min(TABLE_LOCK_POLL_INTERVAL)).await,
+            }
+        }
+    }
+
+    /// The fencing token to commit a mutation with.
+    pub fn revision(&self) -> u64 {
+        self.revision
+    }
+}
+
+impl Drop for TableLock {
+    fn drop(&mut self) {
+        // The heartbeat task deletes the revision once it is told to stop.
+        if let Some(tx) = self.shutdown.take() {
+            let _ = tx.send(());
+        }
+    }
+}
+
+/// Extends the revision until shutdown, then deletes it.
+async fn heartbeat(
+    catalog: Arc<dyn Catalog>,
+    table_id: u64,
+    revision: u64,
+    mut shutdown: oneshot::Receiver<()>,
+) {
+    // A closed channel stops the heartbeat as well.
+    while tokio::time::timeout(TABLE_LOCK_HEARTBEAT_INTERVAL, &mut shutdown)
+        .await
+        .is_err()
+    {
+        let req = ExtendTableLockRevReq {
+            table_id,
+            revision,
+            expire_at: expire_at(),
+        };
+        if let Err(e) = catalog.extend_table_lock_rev(req).await {
+            warn!("extend table lock {}/{} failed: {}", table_id, revision, e);
+            if e.code() == ErrorCode::table_lock_expired_code() {
+                return;
+            }
+        }
+    }
+
+    let req = DeleteTableLockRevReq { table_id, revision };
+    if let Err(e) = catalog.delete_table_lock_rev(req).await {
+        // The revision expires anyway.
+        warn!("release table lock {}/{} failed: {}", table_id, revision, e);
+    }
+}
+
+fn expire_at() -> u64 {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system time before unix epoch");
+    now.as_secs() + TABLE_LOCK_EXPIRE_SECS
+}
@@ -14,7 +14,9 @@
 
 use std::sync::Arc;
 
+use common_catalog::table::TableExt;
 use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_fuse_meta::meta::TableSnapshot;
 use common_fuse_meta::meta::Versioned;
@@ -24,12 +26,45 @@ use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_types::MatchSeq;
 use uuid::Uuid;
 
+use crate::operations::TableLock;
 use crate::FuseTable;
 use crate::OPT_KEY_SNAPSHOT_LOCATION;
 
 impl FuseTable {
     #[inline]
     pub async fn do_truncate(&self, ctx: Arc<dyn TableContext>, purge: bool) -> Result<()> {
+        let table_lock = TableLock::acquire(ctx.clone(), &self.table_info).await?;
+        self.truncate_locked(ctx, purge, &table_lock).await
+    }
+
+    /// Truncates the latest version of the table, while `table_lock` is held.
+    ///
+    /// Appends commit without the lock, the truncation is retried on the version they commit.
+    pub async fn truncate_locked(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        purge: bool,
+        table_lock: &TableLock,
+    ) -> Result<()> {
+        loop {
+            let latest = self.refresh(ctx.as_ref()).await?;
+            let tbl = FuseTable::try_from_table(latest.as_ref())?;
+            match tbl
+                .try_truncate(ctx.clone(), purge, table_lock.revision())
+                .await
+            {
+                Err(e) if e.code() == ErrorCode::table_version_mismatched_code() => continue,
+                res => return res,
+            }
+        }
+    }
+
+    async fn try_truncate(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        purge: bool,
+        lock_revision: u64,
+    ) -> Result<()> {
         if let Some(prev_snapshot) = self.read_table_snapshot(ctx.clone()).await? {
             let prev_id = prev_snapshot.snapshot_id;
 
@@ -73,6 +108,7 @@ impl FuseTable {
                     table_id,
                     seq: MatchSeq::Exact(table_version),
                     new_table_meta,
+                    lock_revision: Some(lock_revision),
                 })
                 .await?;
 
@@ -12,6 +12,7 @@
 //  See the License for the specific language governing permissions and
 //  limitations under the License.
 
+use std::borrow::Cow;
 use std::future::Future;
 use std::pin::Pin;
 use std::sync::Arc;
@@ -22,12 +23,18 @@ use common_base::base::tokio::task::JoinHandle;
 use common_base::base::Runtime;
 use common_catalog::table_context::TableContext;
 use common_datavalues::DataSchemaRef;
+use common_datavalues::DataSchemaRefExt;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_fuse_meta::meta::BlockMeta;
 use common_fuse_meta::meta::Location;
 use common_fuse_meta::meta::SegmentInfo;
+use common_fuse_meta::meta::StatisticsOfColumns;
+use common_fuse_meta::meta::VirtualColumnsMeta;
+use common_legacy_expression::LegacyExpression;
+use common_legacy_expression::RequireColumnsVisitor;
 use common_legacy_planners::Extras;
+use common_legacy_planners::VirtualColumn;
 use futures::future;
 use opendal::Operator;
 use tracing::warn;
@@ -35,6 +42,7 @@ use tracing::Instrument;
 
 use super::pruner;
 use crate::io::MetaReaders;
+use crate::io::VirtualColumnReader;
 use crate::pruning::limiter;
 use crate::pruning::limiter::LimiterPruner;
 use crate::pruning::pruner::Pruner;
@@ -52,6 +60,29 @@ struct PruningContext {
     filter_pruner: Option<Arc<dyn Pruner + Send + Sync>>,
     rt: Arc<Runtime>,
     semaphore: Arc<Semaphore>,
+    schema: DataSchemaRef,
+    virtual_columns: Vec<VirtualColumn>,
+}
+
+impl PruningContext {
+    // the range pruner sees the virtual columns after the columns of the table
+    fn statistics<'a>(
+        &self,
+        col_stats: &'a StatisticsOfColumns,
+        virtual_columns_meta: Option<&VirtualColumnsMeta>,
+    ) -> Cow<'a, StatisticsOfColumns> {
+        if self.virtual_columns.is_empty() {
+            Cow::Borrowed(col_stats)
+        } else {
+            Cow::Owned(VirtualColumnReader::merge_statistics(
+                col_stats,
+                virtual_columns_meta,
+                &self.virtual_columns,
+                &self.schema,
+                self.schema.num_fields() as u32,
+            ))
+        }
+    }
 }
 
 pub struct BlockPruner;
@@ -82,14 +113,38 @@ impl BlockPruner {
         // prepare the limiter. in case that limit is none, an unlimited limiter will be returned
         let limiter = limiter::new_limiter(limit);
 
+        let virtual_columns = push_down
+            .as_ref()
+            .map(|extra| extra.virtual_columns.clone())
+            .unwrap_or_default();
+        let range_schema = if virtual_columns.is_empty() {
+            schema.clone()
+        } else {
+            let mut fields = schema.fields().clone();
+            fields.extend(virtual_columns.iter().map(|c| c.to_data_field()));
+            DataSchemaRefExt::create(fields)
+        };
+
         // prepare the range filter.
         // if filter_expression is none, an dummy pruner will be returned, which prunes nothing
-        let range_pruner = range_pruner::new_range_pruner(ctx, filter_expressions, &schema)?;
+        let range_pruner = range_pruner::new_range_pruner(ctx, filter_expressions, &range_schema)?;
 
         // prepare the filter.
         // None will be returned, if filter is not applicable (e.g. unsuitable filter expression, index not available, etc.)
-        let filter_pruner =
-            pruner::new_filter_pruner(ctx, filter_expressions, &schema, dal.clone())?;
+        // virtual columns have no bloom filters, so the expressions on them are left out
+        let bloom_filter_expressions = filter_expressions.map(|exprs| {
+            exprs
+                .iter()
+                .filter(|expr| !Self::has_virtual_column(expr, &virtual_columns))
+                .cloned()
+                .collect::<Vec<_>>()
+        });
+        let filter_pruner = pruner::new_filter_pruner(
+            ctx,
+            bloom_filter_expressions.as_deref(),
+            &schema,
+            dal.clone(),
+        )?;
 
         // 2. constraint the degree of parallelism
         let max_threads = ctx.get_settings().get_max_threads()? as usize;
@@ -121,6 +176,8 @@ impl BlockPruner {
             filter_pruner,
             rt: pruning_runtime.clone(),
             semaphore: semaphore.clone(),
+            schema: schema.clone(),
+            virtual_columns,
         });
 
         // 4. kick off
@@ -192,7 +249,7 @@ impl BlockPruner {
         drop(permit);
 
         let result = if pruning_ctx.range_pruner.should_keep(
-            &segment_info.summary.col_stats,
+            &pruning_ctx.statistics(&segment_info.summary.col_stats, None),
             segment_info.summary.row_count,
         ) {
             if let Some(filter_pruner) = &pruning_ctx.filter_pruner {
@@ -229,10 +286,9 @@ impl BlockPruner {
                 Box<dyn FnOnce(OwnedSemaphorePermit) -> BlockPruningFutureReturn + Send + 'static>;
             blocks.next().map(|(block_idx, block_meta)| {
                 let row_count = block_meta.row_count;
-                if pruning_ctx
-                    .range_pruner
-                    .should_keep(&block_meta.col_stats, row_count)
-                {
+                let col_stats = pruning_ctx
+                    .statistics(&block_meta.col_stats, block_meta.virtual_columns.as_ref());
+                if pruning_ctx.range_pruner.should_keep(&col_stats, row_count) {
                     // not pruned by block zone map index,
                     let ctx = pruning_ctx.clone();
                     let filter_pruner = filter_pruner.clone();
@@ -286,9 +342,9 @@ impl BlockPruner {
                 break;
             }
             let row_count = block_meta.row_count;
-            if pruning_ctx
-                .range_pruner
-                .should_keep(&block_meta.col_stats, row_count)
+            let col_stats =
+                pruning_ctx.statistics(&block_meta.col_stats, block_meta.virtual_columns.as_ref());
+            if pruning_ctx.range_pruner.should_keep(&col_stats, row_count)
                 && pruning_ctx.limiter.within_limit(row_count)
             {
                 result.push((segment_idx, block_meta.clone()))
@@ -297,6 +353,13 @@ impl BlockPruner {
         result
     }
 
+    fn has_virtual_column(expr: &LegacyExpression, virtual_columns: &[VirtualColumn]) -> bool {
+        match RequireColumnsVisitor::collect_columns_from_expr(expr) {
+            Ok(columns) => virtual_columns.iter().any(|c| columns.contains(&c.name)),
+            Err(_) => true,
+        }
+    }
+
     #[inline]
     #[tracing::instrument(level = "debug", skip_all)]
     async fn join_flatten_result(
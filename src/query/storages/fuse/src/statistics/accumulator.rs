@@ -22,6 +22,7 @@ use common_fuse_meta::meta::ColumnMeta;
 use common_fuse_meta::meta::Location;
 use common_fuse_meta::meta::StatisticsOfColumns;
 use common_fuse_meta::meta::Versioned;
+use common_fuse_meta::meta::VirtualColumnsMeta;
 
 use crate::operations::column_metas;
 use crate::statistics::block_statistics::BlockStatistics;
@@ -49,6 +50,7 @@ impl StatisticsAccumulator {
         block_statistics: BlockStatistics,
         bloom_filter_index_location: Option<Location>,
         bloom_filter_index_size: u64,
+        virtual_columns: Option<VirtualColumnsMeta>,
     ) -> Result<()> {
         let col_metas = column_metas(&file_meta)?;
         self.add(
@@ -57,6 +59,7 @@ impl StatisticsAccumulator {
             block_statistics,
             bloom_filter_index_location,
             bloom_filter_index_size,
+            virtual_columns,
         )
     }
 
@@ -69,6 +72,7 @@ impl StatisticsAccumulator {
         let bloom_filter_index_size = block_meta.bloom_filter_index_size;
         let file_size = block_meta.file_size;
         let col_metas = block_meta.col_metas;
+        let virtual_columns = block_meta.virtual_columns;
 
         self.add(
             file_size,
@@ -76,6 +80,7 @@ impl StatisticsAccumulator {
             block_statistics,
             bloom_filter_index_location,
             bloom_filter_index_size,
+            virtual_columns,
         )
     }
 
@@ -90,6 +95,7 @@ impl StatisticsAccumulator {
         block_statistics: BlockStatistics,
         bloom_filter_index_location: Option<Location>,
         bloom_filter_index_size: u64,
+        virtual_columns: Option<VirtualColumnsMeta>,
     ) -> Result<()> {
         self.file_size += file_size;
         self.index_size += bloom_filter_index_size;
@@ -105,7 +111,7 @@ impl StatisticsAccumulator {
         let data_location = (block_statistics.block_file_location, DataBlock::VERSION);
         let cluster_stats = block_statistics.block_cluster_statistics;
 
-        self.blocks_metas.push(BlockMeta::new(
+        let mut block_meta = BlockMeta::new(
             row_count,
             block_size,
             file_size,
@@ -115,7 +121,9 @@ impl StatisticsAccumulator {
             data_location,
             bloom_filter_index_location,
             bloom_filter_index_size,
-        ));
+        );
+        block_meta.virtual_columns = virtual_columns;
+        self.blocks_metas.push(block_meta);
 
         Ok(())
     }
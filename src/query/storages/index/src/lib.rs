@@ -59,6 +59,7 @@ pub trait SupportedType {
                 | TypeID::Int64
                 | TypeID::Float32
                 | TypeID::Float64
+                | TypeID::Decimal
                 | TypeID::Date
                 | TypeID::Timestamp
                 | TypeID::Interval
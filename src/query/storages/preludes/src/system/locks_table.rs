@@ -0,0 +1,142 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_catalog::catalog::CATALOG_DEFAULT;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_meta_app::schema::ListTableLocksReq;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+
+use crate::sessions::TableContext;
+use crate::storages::system::table::AsyncOneBlockSystemTable;
+use crate::storages::system::table::AsyncSystemTable;
+use crate::storages::Table;
+
+/// Lists the lock revisions of the tables of the current tenant.
+///
+/// The smallest alive revision of a table is `HOLDING` the lock, the others are `WAITING` for it.
+pub struct LocksTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for LocksTable {
+    const NAME: &'static str = "system.locks";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn get_full_data(&self, ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let catalog = ctx.get_catalog(CATALOG_DEFAULT)?;
+        let databases = catalog.list_databases(tenant.as_str()).await?;
+
+        let mut table_names = HashMap::new();
+        for database in databases {
+            let db_name = database.name();
+            let tables = catalog.list_tables(tenant.as_str(), db_name).await?;
+            for table in tables {
+                table_names.insert(
+                    table.get_id(),
+                    (db_name.to_string(), table.name().to_string()),
+                );
+            }
+        }
+
+        let mut table_ids = table_names.keys().copied().collect::<Vec<_>>();
+        table_ids.sort_unstable();
+        let locks = catalog
+            .list_table_locks(ListTableLocksReq { table_ids })
+            .await?;
+
+        let mut databases: Vec<Vec<u8>> = Vec::with_capacity(locks.len());
+        let mut tables: Vec<Vec<u8>> = Vec::with_capacity(locks.len());
+        let mut table_ids: Vec<u64> = Vec::with_capacity(locks.len());
+        let mut revisions: Vec<u64> = Vec::with_capacity(locks.len());
+        let mut statuses: Vec<&[u8]> = Vec::with_capacity(locks.len());
+        let mut users: Vec<Vec<u8>> = Vec::with_capacity(locks.len());
+        let mut nodes: Vec<Vec<u8>> = Vec::with_capacity(locks.len());
+        let mut query_ids: Vec<Vec<u8>> = Vec::with_capacity(locks.len());
+        let mut created_ons: Vec<Vec<u8>> = Vec::with_capacity(locks.len());
+        for lock in locks {
+            let (db_name, table_name) =
+                table_names.get(&lock.table_id).cloned().unwrap_or_default();
+            databases.push(db_name.into_bytes());
+            tables.push(table_name.into_bytes());
+            table_ids.push(lock.table_id);
+            revisions.push(lock.revision);
+            let status: &[u8] = if lock.holding { b"HOLDING" } else { b"WAITING" };
+            statuses.push(status);
+            users.push(lock.meta.user.into_bytes());
+            nodes.push(lock.meta.node.into_bytes());
+            query_ids.push(lock.meta.query_id.into_bytes());
+            created_ons.push(
+                lock.meta
+                    .created_on
+                    .format("%Y-%m-%d %H:%M:%S.%3f %z")
+                    .to_string()
+                    .into_bytes(),
+            );
+        }
+
+        Ok(DataBlock::create(self.table_info.schema(), vec![
+            Series::from_data(databases),
+            Series::from_data(tables),
+            Series::from_data(table_ids),
+            Series::from_data(revisions),
+            Series::from_data(statuses),
+            Series::from_data(users),
+            Series::from_data(nodes),
+            Series::from_data(query_ids),
+            Series::from_data(created_ons),
+        ]))
+    }
+}
+
+impl LocksTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new("database", Vu8::to_data_type()),
+            DataField::new("table", Vu8::to_data_type()),
+            DataField::new("table_id", u64::to_data_type()),
+            DataField::new("revision", u64::to_data_type()),
+            DataField::new("status", Vu8::to_data_type()),
+            DataField::new("user", Vu8::to_data_type()),
+            DataField::new("node", Vu8::to_data_type()),
+            DataField::new("query_id", Vu8::to_data_type()),
+            DataField::new("created_on", Vu8::to_data_type()),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'locks'".to_string(),
+            name: "locks".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemLocks".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        AsyncOneBlockSystemTable::create(LocksTable { table_info })
+    }
+}
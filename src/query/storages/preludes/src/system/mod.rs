@@ -20,9 +20,11 @@ mod credits_table;
 mod databases_table;
 mod engines_table;
 mod functions_table;
+mod locks_table;
 mod log_queue;
 mod metrics_table;
 mod one_table;
+mod pipes_table;
 mod processes_table;
 mod query_log_table;
 mod roles_table;
@@ -44,11 +46,13 @@ pub use credits_table::CreditsTable;
 pub use databases_table::DatabasesTable;
 pub use engines_table::EnginesTable;
 pub use functions_table::FunctionsTable;
+pub use locks_table::LocksTable;
 pub use log_queue::SystemLogElement;
 pub use log_queue::SystemLogQueue;
 pub use log_queue::SystemLogTable;
 pub use metrics_table::MetricsTable;
 pub use one_table::OneTable;
+pub use pipes_table::PipesTable;
 pub use processes_table::ProcessesTable;
 pub use query_log_table::LogType;
 pub use query_log_table::QueryLogElement;
@@ -0,0 +1,124 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_datavalues::DataSchemaRefExt;
+use common_exception::Result;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_users::UserApiProvider;
+
+use super::table::AsyncOneBlockSystemTable;
+use super::table::AsyncSystemTable;
+use crate::sessions::TableContext;
+use crate::storages::Table;
+
+pub struct PipesTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for PipesTable {
+    const NAME: &'static str = "system.pipes";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn get_full_data(&self, ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let pipes = UserApiProvider::instance().get_pipes(&tenant).await?;
+        let mut name: Vec<Vec<u8>> = Vec::with_capacity(pipes.len());
+        let mut copy_stmt: Vec<Vec<u8>> = Vec::with_capacity(pipes.len());
+        let mut auto_ingest: Vec<bool> = Vec::with_capacity(pipes.len());
+        let mut comment: Vec<Vec<u8>> = Vec::with_capacity(pipes.len());
+        let mut creator: Vec<Option<Vec<u8>>> = Vec::with_capacity(pipes.len());
+        let mut created_on: Vec<Option<Vec<u8>>> = Vec::with_capacity(pipes.len());
+        let mut last_run_on: Vec<Option<Vec<u8>>> = Vec::with_capacity(pipes.len());
+        let mut last_run_node: Vec<Vec<u8>> = Vec::with_capacity(pipes.len());
+        let mut last_loaded_files: Vec<u64> = Vec::with_capacity(pipes.len());
+        let mut total_loaded_files: Vec<u64> = Vec::with_capacity(pipes.len());
+        let mut last_error: Vec<Vec<u8>> = Vec::with_capacity(pipes.len());
+        for pipe in pipes.into_iter().map(|seqv| seqv.data) {
+            name.push(pipe.name.into_bytes());
+            copy_stmt.push(pipe.masked_copy_stmt().into_bytes());
+            auto_ingest.push(pipe.auto_ingest);
+            comment.push(pipe.comment.into_bytes());
+            creator.push(pipe.creator.map(|c| c.to_string().into_bytes()));
+            created_on.push(pipe.created_on.map(|v| {
+                v.format("%Y-%m-%d %H:%M:%S.%3f %z")
+                    .to_string()
+                    .into_bytes()
+            }));
+            last_run_on.push(pipe.status.last_run_on.map(|v| {
+                v.format("%Y-%m-%d %H:%M:%S.%3f %z")
+                    .to_string()
+                    .into_bytes()
+            }));
+            last_run_node.push(pipe.status.last_run_node.into_bytes());
+            last_loaded_files.push(pipe.status.last_loaded_files);
+            total_loaded_files.push(pipe.status.total_loaded_files);
+            last_error.push(pipe.status.last_error.into_bytes());
+        }
+        Ok(DataBlock::create(self.table_info.schema(), vec![
+            Series::from_data(name),
+            Series::from_data(copy_stmt),
+            Series::from_data(auto_ingest),
+            Series::from_data(comment),
+            Series::from_data(creator),
+            Series::from_data(created_on),
+            Series::from_data(last_run_on),
+            Series::from_data(last_run_node),
+            Series::from_data(last_loaded_files),
+            Series::from_data(total_loaded_files),
+            Series::from_data(last_error),
+        ]))
+    }
+}
+
+impl PipesTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new("name", Vu8::to_data_type()),
+            DataField::new("copy_stmt", Vu8::to_data_type()),
+            DataField::new("auto_ingest", bool::to_data_type()),
+            DataField::new("comment", Vu8::to_data_type()),
+            DataField::new_nullable("creator", Vu8::to_data_type()),
+            DataField::new_nullable("created_on", Vu8::to_data_type()),
+            // NULL if the pipe never ran
+            DataField::new_nullable("last_run_on", Vu8::to_data_type()),
+            DataField::new("last_run_node", Vu8::to_data_type()),
+            DataField::new("last_loaded_files", u64::to_data_type()),
+            DataField::new("total_loaded_files", u64::to_data_type()),
+            DataField::new("last_error", Vu8::to_data_type()),
+        ]);
+        let table_info = TableInfo {
+            desc: "'system'.'pipes'".to_string(),
+            name: "pipes".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemPipes".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        AsyncOneBlockSystemTable::create(PipesTable { table_info })
+    }
+}
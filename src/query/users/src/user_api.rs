@@ -17,6 +17,8 @@ use std::sync::Arc;
 use common_base::base::Singleton;
 use common_exception::Result;
 use common_grpc::RpcClientConf;
+use common_management::PipeApi;
+use common_management::PipeMgr;
 use common_management::QuotaApi;
 use common_management::QuotaMgr;
 use common_management::RoleApi;
@@ -96,6 +98,10 @@ impl UserApiProvider {
         Ok(Arc::new(UdfMgr::create(self.client.clone(), tenant)?))
     }
 
+    pub fn get_pipe_api_client(&self, tenant: &str) -> Result<Arc<dyn PipeApi>> {
+        Ok(Arc::new(PipeMgr::create(self.client.clone(), tenant)?))
+    }
+
     pub fn get_tenant_quota_api_client(&self, tenant: &str) -> Result<Arc<dyn QuotaApi>> {
         Ok(Arc::new(QuotaMgr::create(self.client.clone(), tenant)?))
     }
@@ -0,0 +1,83 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::PipeInfo;
+use common_meta_types::SeqV;
+
+use crate::UserApiProvider;
+
+/// Pipe operations.
+impl UserApiProvider {
+    // Add a new pipe.
+    pub async fn add_pipe(&self, tenant: &str, info: PipeInfo, if_not_exists: bool) -> Result<u64> {
+        let pipe_api_client = self.get_pipe_api_client(tenant)?;
+        let add_pipe = pipe_api_client.add_pipe(info);
+        match add_pipe.await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_not_exists && e.code() == ErrorCode::pipe_already_exists_code() {
+                    Ok(u64::MIN)
+                } else {
+                    Err(e)
+                }
+            }
+        }
+    }
+
+    // Update a pipe if its seq is still `seq`, returns the new seq.
+    pub async fn update_pipe(&self, tenant: &str, info: PipeInfo, seq: u64) -> Result<u64> {
+        let pipe_api_client = self.get_pipe_api_client(tenant)?;
+        let update_pipe = pipe_api_client.update_pipe(info, Some(seq));
+        match update_pipe.await {
+            Ok(res) => Ok(res),
+            Err(e) => Err(e.add_message_back("(while update pipe).")),
+        }
+    }
+
+    // Get a pipe by name.
+    pub async fn get_pipe(&self, tenant: &str, pipe_name: &str) -> Result<SeqV<PipeInfo>> {
+        let pipe_api_client = self.get_pipe_api_client(tenant)?;
+        let get_pipe = pipe_api_client.get_pipe(pipe_name, None);
+        get_pipe.await
+    }
+
+    // Get all pipes for the tenant.
+    pub async fn get_pipes(&self, tenant: &str) -> Result<Vec<SeqV<PipeInfo>>> {
+        let pipe_api_client = self.get_pipe_api_client(tenant)?;
+        let get_pipes = pipe_api_client.get_pipes();
+
+        match get_pipes.await {
+            Err(e) => Err(e.add_message_back("(while get pipes).")),
+            Ok(seq_pipes_info) => Ok(seq_pipes_info),
+        }
+    }
+
+    // Drop a pipe by name.
+    pub async fn drop_pipe(&self, tenant: &str, pipe_name: &str, if_exists: bool) -> Result<()> {
+        let pipe_api_client = self.get_pipe_api_client(tenant)?;
+        let drop_pipe = pipe_api_client.drop_pipe(pipe_name, None);
+        match drop_pipe.await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_exists && e.code() == ErrorCode::unknown_pipe_code() {
+                    Ok(())
+                } else {
+                    Err(e.add_message_back("(while drop pipe)"))
+                }
+            }
+        }
+    }
+}
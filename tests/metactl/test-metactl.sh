@@ -9,6 +9,8 @@ meta_dir="$SCRIPT_PATH/_meta_dir"
 meta_json="$SCRIPT_PATH/meta.txt"
 exported="$SCRIPT_PATH/exported"
 grpc_exported="$SCRIPT_PATH/exported"
+dangling_meta_dir="$SCRIPT_PATH/_dangling_meta_dir"
+check_out="$SCRIPT_PATH/check_out"
 
 chmod +x ./target/${BUILD_PROFILE}/databend-metactl
 
@@ -28,6 +30,44 @@ echo " === check backup date $meta_json and exported $exported"
 diff $meta_json $exported
 
 
+echo " === compare backup data $meta_json and exported $exported with metactl"
+./target/${BUILD_PROFILE}/databend-metactl --cmd diff --db $meta_json --diff-with $exported
+
+
+echo " === check $meta_dir, the id to name mappings and id lists are absent"
+if ./target/${BUILD_PROFILE}/databend-metactl --cmd check --raft-dir "$meta_dir" >$check_out; then
+    echo " === check is expected to fail"
+    exit 1
+fi
+cat $check_out
+grep -F "__fd_database_id_to_name/1: db 1 has no id to name mapping; repair:" $check_out
+grep -F "__fd_db_id_list/test_tenant/default: current db id 1 is absent in id list; repair:" $check_out
+grep -F "__fd_table_id_to_name/49: table 49 has no id to name mapping; repair:" $check_out
+grep -F "__fd_table_id_list/1/tt: current table id 49 is absent in id list; repair:" $check_out
+
+
+echo " === repair and check $meta_dir"
+./target/${BUILD_PROFILE}/databend-metactl --cmd repair --raft-dir "$meta_dir"
+./target/${BUILD_PROFILE}/databend-metactl --cmd check --raft-dir "$meta_dir"
+
+
+echo " === import into $dangling_meta_dir with a table name referring to an absent table"
+dangling_record='["state_machine/0",{"GenericKV":{"key":"__fd_table/1/dangling","value":{"seq":100,"meta":null,"data":[57,57,57]}}}]'
+{ cat $meta_json; echo "$dangling_record"; } |
+    ./target/${BUILD_PROFILE}/databend-metactl --import --raft-dir "$dangling_meta_dir"
+
+./target/${BUILD_PROFILE}/databend-metactl --cmd repair --raft-dir "$dangling_meta_dir" >$check_out
+cat $check_out
+grep -F "repaired: __fd_table/1/dangling: dangling table id 999; repair:" $check_out
+./target/${BUILD_PROFILE}/databend-metactl --cmd check --raft-dir "$dangling_meta_dir"
+
+./target/${BUILD_PROFILE}/databend-metactl --export --raft-dir "$dangling_meta_dir" >$exported
+if grep -F "__fd_table/1/dangling" $exported; then
+    echo " === dangling record is expected to be removed"
+    exit 1
+fi
+
+
 echo " === start a single node databend-meta"
 # test export from grpc
 chmod +x ./target/${BUILD_PROFILE}/databend-meta
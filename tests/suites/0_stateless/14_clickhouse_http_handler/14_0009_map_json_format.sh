@@ -0,0 +1,38 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+
+cat << EOF > /tmp/databend_test_ndjson.txt
+insert into t_map(id, m) format NDJSON
+{"id":1,"m":{"a":1,"b":2}}
+{"id":2,"m":{}}
+{"id":3,"m":{"b":3,"c":null}}
+
+EOF
+
+
+curl -s  -u 'root:' -XPOST "http://localhost:${QUERY_CLICKHOUSE_HTTP_HANDLER_PORT}" -d "drop table if exists t_map"
+curl -s  -u 'root:' -XPOST "http://localhost:${QUERY_CLICKHOUSE_HTTP_HANDLER_PORT}" -d "drop table if exists t_map_copy"
+curl -s  -u 'root:' -XPOST "http://localhost:${QUERY_CLICKHOUSE_HTTP_HANDLER_PORT}" -d "create table t_map (id int, m map(string, int32 null))"
+curl -s  -u 'root:' -XPOST "http://localhost:${QUERY_CLICKHOUSE_HTTP_HANDLER_PORT}" -d "create table t_map_copy (id int, m map(string, int32 null))"
+
+curl -s  -u 'root:' -XPOST "http://localhost:${QUERY_CLICKHOUSE_HTTP_HANDLER_PORT}" --data-binary @/tmp/databend_test_ndjson.txt
+
+echo "----NDJSON"
+curl -s  -u 'root:' -XPOST "http://localhost:${QUERY_CLICKHOUSE_HTTP_HANDLER_PORT}" -d "select * from t_map order by id format NDJSON"
+echo "----JSONEachRow"
+curl -s  -u 'root:' -XPOST "http://localhost:${QUERY_CLICKHOUSE_HTTP_HANDLER_PORT}" -d "select * from t_map order by id format JSONEachRow"
+
+echo "----round trip"
+echo "insert into t_map_copy(id, m) format NDJSON" > /tmp/databend_test_ndjson_copy.txt
+curl -s  -u 'root:' -XPOST "http://localhost:${QUERY_CLICKHOUSE_HTTP_HANDLER_PORT}" -d "select * from t_map order by id format NDJSON" >> /tmp/databend_test_ndjson_copy.txt
+curl -s  -u 'root:' -XPOST "http://localhost:${QUERY_CLICKHOUSE_HTTP_HANDLER_PORT}" --data-binary @/tmp/databend_test_ndjson_copy.txt
+curl -s  -u 'root:' -XPOST "http://localhost:${QUERY_CLICKHOUSE_HTTP_HANDLER_PORT}" -d "select * from t_map_copy order by id format NDJSON"
+
+curl -s  -u 'root:' -XPOST "http://localhost:${QUERY_CLICKHOUSE_HTTP_HANDLER_PORT}" -d "drop table t_map"
+curl -s  -u 'root:' -XPOST "http://localhost:${QUERY_CLICKHOUSE_HTTP_HANDLER_PORT}" -d "drop table t_map_copy"
+
+
+rm /tmp/databend_test*.txt
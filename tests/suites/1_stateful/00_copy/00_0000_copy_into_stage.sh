@@ -18,12 +18,13 @@ for i in `seq 1 10`;do
 done
 
 
-echo "copy into @s2 from test_table FILE_FORMAT = (type = 'CSV');" | $MYSQL_CLIENT_CONNECT
-echo "copy into @s2 from (select name, age, id from test_table limit 100) FILE_FORMAT = (type = 'PARQUET');" | $MYSQL_CLIENT_CONNECT
+## copy returns the written files, named by uuid
+echo "copy into @s2 from test_table FILE_FORMAT = (type = 'CSV');" | $MYSQL_CLIENT_CONNECT | awk -F'\t' '{print $3}'
+echo "copy into @s2 from (select name, age, id from test_table limit 100) FILE_FORMAT = (type = 'PARQUET');" | $MYSQL_CLIENT_CONNECT | awk -F'\t' '{print $3}'
 echo "list @s2;" | $MYSQL_CLIENT_CONNECT | wc -l | sed 's/ //g'
 
 
-echo "copy into @s2 from test_table FILE_FORMAT = (type = 'CSV') MAX_FILE_SIZE = 10;" | $MYSQL_CLIENT_CONNECT
+echo "copy into @s2 from test_table FILE_FORMAT = (type = 'CSV') MAX_FILE_SIZE = 10;" | $MYSQL_CLIENT_CONNECT > /dev/null
 
 lines=`echo "list @s2;" | $MYSQL_CLIENT_CONNECT | wc -l`
 
@@ -0,0 +1,27 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "drop table if exists test_validation;" | $MYSQL_CLIENT_CONNECT
+
+## Create table
+echo "create table test_validation(a int, b string);" | $MYSQL_CLIENT_CONNECT
+
+copy_location="'s3://testbucket/admin/data/validation.csv' connection=(aws_key_id='minioadmin' aws_secret_key='minioadmin' endpoint_url='${STORAGE_S3_ENDPOINT_URL}') FILE_FORMAT = (type = 'CSV')"
+
+echo "---return_rows"
+echo "copy into test_validation from $copy_location VALIDATION_MODE = RETURN_2_ROWS" | $MYSQL_CLIENT_CONNECT | sort
+
+## print line and column_name only, the file path and error message are verbose
+echo "---return_errors"
+echo "copy into test_validation from $copy_location VALIDATION_MODE = RETURN_ERRORS" | $MYSQL_CLIENT_CONNECT | awk -F'\t' '{print $2, $3}'
+
+echo "---return_all_errors"
+echo "copy into test_validation from $copy_location VALIDATION_MODE = RETURN_ALL_ERRORS" | $MYSQL_CLIENT_CONNECT | awk -F'\t' '{print $2, $3}'
+
+## nothing is loaded by the validation
+echo "select count(*) from test_validation" | $MYSQL_CLIENT_CONNECT
+
+## Drop table
+echo "drop table if exists test_validation;" | $MYSQL_CLIENT_CONNECT
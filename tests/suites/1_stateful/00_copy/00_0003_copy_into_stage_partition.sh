@@ -0,0 +1,38 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "drop table if exists test_partition;" | $MYSQL_CLIENT_CONNECT
+echo "drop table if exists test_partition_load;" | $MYSQL_CLIENT_CONNECT
+echo "drop STAGE if exists s3;" | $MYSQL_CLIENT_CONNECT
+echo "CREATE STAGE s3;" | $MYSQL_CLIENT_CONNECT
+
+echo "CREATE TABLE test_partition (id INT, b VARCHAR NULL);" | $MYSQL_CLIENT_CONNECT
+echo "CREATE TABLE test_partition_load (id INT, b VARCHAR NULL);" | $MYSQL_CLIENT_CONNECT
+echo "insert into test_partition values (1, 'x'), (2, 'x'), (3, 'y'), (4, NULL);" | $MYSQL_CLIENT_CONNECT
+
+## print the partition directory and row count of the written files, the file names are random
+echo "---partition_by"
+echo "set max_threads = 1; copy into @s3 from test_partition PARTITION BY b FILE_FORMAT = (type = 'CSV') HEADER = true;" | $MYSQL_CLIENT_CONNECT | awk -F'\t' '{n = split($1, p, "/"); print p[n - 1], $3}'
+
+echo "---load_partition"
+echo "copy into test_partition_load from @s3/b=x/ FILE_FORMAT = (type = 'CSV' skip_header = 1);" | $MYSQL_CLIENT_CONNECT
+echo "select * from test_partition_load order by id;" | $MYSQL_CLIENT_CONNECT
+
+echo "---overwrite"
+echo "copy into @s3 from test_partition FILE_FORMAT = (type = 'CSV') OVERWRITE = true;" | $MYSQL_CLIENT_CONNECT | awk -F'\t' '{print $3}'
+echo "list @s3;" | $MYSQL_CLIENT_CONNECT | wc -l | sed 's/ //g'
+
+## a failed overwrite keeps the previous files
+echo "---overwrite_failed"
+echo "copy into @s3 from (select to_uint8(b) from test_partition) FILE_FORMAT = (type = 'CSV') OVERWRITE = true;" | $MYSQL_CLIENT_CONNECT > /dev/null 2>&1
+echo "list @s3;" | $MYSQL_CLIENT_CONNECT | wc -l | sed 's/ //g'
+echo "copy into test_partition_load from @s3 FILE_FORMAT = (type = 'CSV') force = true;" | $MYSQL_CLIENT_CONNECT
+echo "select count(*) from test_partition_load;" | $MYSQL_CLIENT_CONNECT
+
+echo "drop STAGE s3;" | $MYSQL_CLIENT_CONNECT
+echo "drop table test_partition;" | $MYSQL_CLIENT_CONNECT
+echo "drop table test_partition_load;" | $MYSQL_CLIENT_CONNECT
+
+aws --endpoint-url http://127.0.0.1:9900/ s3 rm s3://testbucket/admin/stage/s3 --recursive  > /dev/null 2>&1
@@ -0,0 +1,23 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "drop table if exists test_fixed_width_xml;" | $MYSQL_CLIENT_CONNECT
+
+## Create table
+echo "create table test_fixed_width_xml(id int, name string, city string null);" | $MYSQL_CLIENT_CONNECT
+
+connection="connection=(aws_key_id='minioadmin' aws_secret_key='minioadmin' endpoint_url='${STORAGE_S3_ENDPOINT_URL}')"
+
+echo "---fixed_width"
+echo "copy into test_fixed_width_xml from 's3://testbucket/admin/data/fixed_width.txt' $connection FILE_FORMAT = (type = 'FIXEDWIDTH' column_offsets = '0, 5, 15')" | $MYSQL_CLIENT_CONNECT
+echo "select * from test_fixed_width_xml order by id" | $MYSQL_CLIENT_CONNECT
+echo "truncate table test_fixed_width_xml" | $MYSQL_CLIENT_CONNECT
+
+echo "---xml"
+echo "copy into test_fixed_width_xml from 's3://testbucket/admin/data/sample.xml' $connection FILE_FORMAT = (type = 'XML' row_path = '/feed/record' column_mapping = 'id = @id, city = address/city')" | $MYSQL_CLIENT_CONNECT
+echo "select * from test_fixed_width_xml order by id" | $MYSQL_CLIENT_CONNECT
+
+## Drop table
+echo "drop table if exists test_fixed_width_xml;" | $MYSQL_CLIENT_CONNECT
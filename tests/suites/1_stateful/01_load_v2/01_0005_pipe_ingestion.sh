@@ -0,0 +1,58 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "drop pipe if exists p_books;" | $MYSQL_CLIENT_CONNECT
+echo "drop pipe if exists p_books_db;" | $MYSQL_CLIENT_CONNECT
+echo "drop table if exists pipe_books;" | $MYSQL_CLIENT_CONNECT
+echo "drop stage if exists s_pipe_books;" | $MYSQL_CLIENT_CONNECT
+echo "drop database if exists pipe_db;" | $MYSQL_CLIENT_CONNECT
+
+echo "CREATE TABLE pipe_books
+(
+    title VARCHAR NULL,
+    author VARCHAR NULL,
+    date VARCHAR NULL,
+    publish_time TIMESTAMP NULL
+);" | $MYSQL_CLIENT_CONNECT
+echo "CREATE STAGE s_pipe_books FILE_FORMAT = (type = 'CSV');" | $MYSQL_CLIENT_CONNECT
+echo "CREATE PIPE p_books AS COPY INTO pipe_books FROM @s_pipe_books;" | $MYSQL_CLIENT_CONNECT
+
+# the pipe loads into the database that is current when it is created
+echo "CREATE DATABASE pipe_db;" | $MYSQL_CLIENT_CONNECT
+echo "CREATE TABLE pipe_db.pipe_books LIKE default.pipe_books;" | $MYSQL_CLIENT_CONNECT
+echo "USE pipe_db; CREATE PIPE p_books_db AS COPY INTO pipe_books FROM @s_pipe_books;" | $MYSQL_CLIENT_CONNECT
+
+# wait until the pipe loads the files staged, it runs every `pipe_poll_interval_secs`.
+wait_loaded() {
+	for _ in $(seq 1 30); do
+		count=$(echo "select count() from $2" | $MYSQL_CLIENT_CONNECT)
+		if [ "$count" = "$1" ]; then
+			break
+		fi
+		sleep 1
+	done
+	echo "select count() from $2" | $MYSQL_CLIENT_CONNECT
+}
+
+curl -u root: -XPUT -H "stage_name:s_pipe_books" -F "upload=@${CURDIR}/books.csv" "http://localhost:${QUERY_HTTP_HANDLER_PORT}/v1/upload_to_stage" > /dev/null 2>&1
+wait_loaded 3 pipe_books
+wait_loaded 3 pipe_db.pipe_books
+
+# only the new file is loaded by the next runs
+curl -u root: -XPUT -H "stage_name:s_pipe_books" -H "relative_path:more" -F "upload=@${CURDIR}/books.csv" "http://localhost:${QUERY_HTTP_HANDLER_PORT}/v1/upload_to_stage" > /dev/null 2>&1
+wait_loaded 6 pipe_books
+wait_loaded 6 pipe_db.pipe_books
+
+sleep 3
+echo "select count() from pipe_books" | $MYSQL_CLIENT_CONNECT
+echo "select count() from pipe_db.pipe_books" | $MYSQL_CLIENT_CONNECT
+echo "select total_loaded_files, last_error from system.pipes where name = 'p_books'" | $MYSQL_CLIENT_CONNECT
+echo "select total_loaded_files, last_error from system.pipes where name = 'p_books_db'" | $MYSQL_CLIENT_CONNECT
+
+echo "drop pipe p_books;" | $MYSQL_CLIENT_CONNECT
+echo "drop pipe p_books_db;" | $MYSQL_CLIENT_CONNECT
+echo "drop database pipe_db;" | $MYSQL_CLIENT_CONNECT
+echo "drop table pipe_books;" | $MYSQL_CLIENT_CONNECT
+echo "drop stage s_pipe_books;" | $MYSQL_CLIENT_CONNECT